@@ -1,7 +1,8 @@
 //! Defines the main context traits and IBC module callbacks
 
 use ibc_app_transfer_types::error::TokenTransferError;
-use ibc_app_transfer_types::{Memo, PrefixedCoin, PrefixedDenom};
+use ibc_app_transfer_types::{Amount, Memo, PrefixedCoin, PrefixedDenom};
+use ibc_core::channel::types::Version;
 use ibc_core::host::types::identifiers::{ChannelId, PortId};
 use ibc_core::primitives::prelude::*;
 use ibc_core::primitives::Signer;
@@ -13,6 +14,14 @@ pub trait TokenTransferValidationContext {
     /// get_port returns the portID for the transfer module.
     fn get_port(&self) -> Result<PortId, TokenTransferError>;
 
+    /// Returns the version negotiated on the given channel, which determines
+    /// the encoding of the packet data sent over it.
+    fn channel_version(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<Version, TokenTransferError>;
+
     /// Returns Ok() if the host chain supports sending coins.
     fn can_send_coins(&self) -> Result<(), TokenTransferError>;
 
@@ -59,15 +68,53 @@ pub trait TokenTransferValidationContext {
         memo: &Memo,
     ) -> Result<(), TokenTransferError>;
 
+    /// Returns the total amount of the native denomination held in escrow by
+    /// the host, across all channels.
+    ///
+    /// Returns zero if none of the denomination was ever escrowed.
+    fn total_escrow_for_denom(&self, denom: &PrefixedDenom) -> Result<Amount, TokenTransferError>;
+
     /// Returns a hash of the prefixed denom.
     /// Implement only if the host chain supports hashed denominations.
+    ///
+    /// The traces of the denominations of the minted vouchers are only
+    /// stored, under this hash, if it is implemented, e.g. by returning the
+    /// [`trace_hash`](PrefixedDenom::trace_hash) of the denominations with a
+    /// trace, as in ibc-go.
     fn denom_hash_string(&self, _denom: &PrefixedDenom) -> Option<String> {
         None
     }
 }
 
+/// Methods required to look up the traces of the denominations of the
+/// vouchers minted by the host, to be implemented by the host.
+pub trait DenomTraceValidationContext {
+    /// Returns the denomination whose trace is stored under the given hash.
+    fn denom_trace(&self, hash: &str) -> Result<Option<PrefixedDenom>, TokenTransferError>;
+
+    /// Returns all the stored denomination traces, along with their hashes.
+    fn denom_traces(&self) -> Result<Vec<(String, PrefixedDenom)>, TokenTransferError>;
+}
+
+/// Methods required to store the traces of the denominations of the vouchers
+/// minted by the host, to be implemented by the host.
+pub trait DenomTraceExecutionContext: DenomTraceValidationContext {
+    /// Stores the trace of the denomination under the given hash.
+    fn store_denom_trace(
+        &mut self,
+        hash: String,
+        denom: PrefixedDenom,
+    ) -> Result<(), TokenTransferError>;
+}
+
 /// Methods required in token transfer execution, to be implemented by the host.
-pub trait TokenTransferExecutionContext: TokenTransferValidationContext {
+///
+/// The traces of the denominations of the minted vouchers are stored with the
+/// [`DenomTraceExecutionContext`], so that they can be resolved from their
+/// hash.
+pub trait TokenTransferExecutionContext:
+    TokenTransferValidationContext + DenomTraceExecutionContext
+{
     /// Executes the escrow of the tokens in a user account.
     ///
     /// `memo` field allows to incorporate additional contextual details in the
@@ -107,4 +154,28 @@ pub trait TokenTransferExecutionContext: TokenTransferValidationContext {
         coin: &PrefixedCoin,
         memo: &Memo,
     ) -> Result<(), TokenTransferError>;
+
+    /// Stores the total amount of the native denomination held in escrow by
+    /// the host.
+    ///
+    /// It is called by the handlers after each escrow and unescrow, and should
+    /// not be updated by the host otherwise.
+    fn store_total_escrow_for_denom(
+        &mut self,
+        denom: PrefixedDenom,
+        amount: Amount,
+    ) -> Result<(), TokenTransferError>;
+}
+
+/// Methods required to check the total escrow invariant with
+/// [`check_total_escrow_invariant`](crate::handler::check_total_escrow_invariant),
+/// to be implemented by the host.
+pub trait TotalEscrowInvariantContext: TokenTransferValidationContext {
+    /// Returns the total escrow of all the denominations ever escrowed by the
+    /// host.
+    fn total_escrows(&self) -> Result<Vec<(PrefixedDenom, Amount)>, TokenTransferError>;
+
+    /// Returns the amount of the denomination actually held by all the escrow
+    /// accounts of the host.
+    fn escrowed_balance(&self, denom: &PrefixedDenom) -> Result<Amount, TokenTransferError>;
 }
@@ -3,47 +3,276 @@ mod on_recv_packet;
 mod send_transfer;
 
 use ibc_app_transfer_types::error::TokenTransferError;
-use ibc_app_transfer_types::is_sender_chain_source;
-use ibc_app_transfer_types::packet::PacketData;
+use ibc_app_transfer_types::events::TotalEscrowEvent;
+use ibc_app_transfer_types::packet::{FungibleTokenPacketDataV2, PacketData};
+use ibc_app_transfer_types::{is_sender_chain_source, Memo, PrefixedCoin, VERSION_V2};
 use ibc_core::channel::types::packet::Packet;
+use ibc_core::channel::types::Version;
+use ibc_core::host::types::identifiers::{ChannelId, PortId};
+use ibc_core::primitives::prelude::*;
+use ibc_core::router::types::module::ModuleExtras;
 pub use on_recv_packet::*;
 pub use send_transfer::*;
 
-use crate::context::{TokenTransferExecutionContext, TokenTransferValidationContext};
+use crate::context::{
+    TokenTransferExecutionContext, TokenTransferValidationContext, TotalEscrowInvariantContext,
+};
 
+/// Decodes the data of a token transfer packet according to the version of
+/// its channel: an `ics20-2` channel carries `FungibleTokenPacketDataV2`,
+/// while any other channel carries the `ics20-1` `PacketData`.
+pub fn decode_packet_data(
+    data: &[u8],
+    chan_version: &Version,
+) -> Result<FungibleTokenPacketDataV2, TokenTransferError> {
+    if chan_version.as_str() == VERSION_V2 {
+        serde_json::from_slice::<FungibleTokenPacketDataV2>(data)
+    } else {
+        serde_json::from_slice::<PacketData>(data).map(Into::into)
+    }
+    .map_err(|_| TokenTransferError::PacketDataDeserialization)
+}
+
+/// Decodes the data of a packet according to the version of the channel
+/// with the given end on the host.
+pub fn decode_channel_packet_data(
+    ctx: &impl TokenTransferValidationContext,
+    port_id: &PortId,
+    channel_id: &ChannelId,
+    data: &[u8],
+) -> Result<FungibleTokenPacketDataV2, TokenTransferError> {
+    let chan_version = ctx.channel_version(port_id, channel_id)?;
+
+    decode_packet_data(data, &chan_version)
+}
+
+/// Takes back the coins already credited to `account` when the tokens of a
+/// packet fail to be credited midway, i.e. escrows the unescrowed coins again
+/// and burns the minted vouchers, so that the packet credits either all of
+/// its tokens or none of them.
+///
+/// Every coin is reverted even if reverting another one failed, as the
+/// original failure is the one reported. Returns the events recording the new
+/// total escrows, along with the logs of the failed reversals.
+fn revert_credited_coins<Ctx>(
+    ctx: &mut Ctx,
+    account: &Ctx::AccountId,
+    port_id: &PortId,
+    channel_id: &ChannelId,
+    unescrowed_coins: &[PrefixedCoin],
+    minted_coins: &[PrefixedCoin],
+    memo: &Memo,
+) -> ModuleExtras
+where
+    Ctx: TokenTransferExecutionContext,
+{
+    let mut extras = ModuleExtras::empty();
+
+    for coin in unescrowed_coins {
+        match escrow_coins_execute(ctx, account, port_id, channel_id, coin, memo) {
+            Ok(total_escrow_event) => extras.events.push(total_escrow_event.into()),
+            Err(err) => extras
+                .log
+                .push(format!("failed to escrow back {coin}: {err}")),
+        }
+    }
+
+    for coin in minted_coins {
+        if let Err(err) = ctx.burn_coins_execute(account, coin, memo) {
+            extras
+                .log
+                .push(format!("failed to burn back {coin}: {err}"));
+        }
+    }
+
+    extras
+}
+
+/// Escrows the coin from `from_account`, and adds it to the total escrow of
+/// its denomination.
+///
+/// Returns the event recording the new total escrow. Any module escrowing
+/// tokens on behalf of ICS-20, e.g. a middleware, must escrow them with this
+/// function rather than with
+/// [`TokenTransferExecutionContext::escrow_coins_execute`], so that the total
+/// escrow stays in sync.
+pub fn escrow_coins_execute<Ctx>(
+    ctx: &mut Ctx,
+    from_account: &Ctx::AccountId,
+    port_id: &PortId,
+    channel_id: &ChannelId,
+    coin: &PrefixedCoin,
+    memo: &Memo,
+) -> Result<TotalEscrowEvent, TokenTransferError>
+where
+    Ctx: TokenTransferExecutionContext,
+{
+    ctx.escrow_coins_execute(from_account, port_id, channel_id, coin, memo)?;
+
+    increase_total_escrow(ctx, coin)
+}
+
+/// Unescrows the coin to `to_account`, and subtracts it from the total escrow
+/// of its denomination.
+///
+/// Returns the event recording the new total escrow. As for
+/// [`escrow_coins_execute`], this must be preferred over
+/// [`TokenTransferExecutionContext::unescrow_coins_execute`].
+pub fn unescrow_coins_execute<Ctx>(
+    ctx: &mut Ctx,
+    to_account: &Ctx::AccountId,
+    port_id: &PortId,
+    channel_id: &ChannelId,
+    coin: &PrefixedCoin,
+) -> Result<TotalEscrowEvent, TokenTransferError>
+where
+    Ctx: TokenTransferExecutionContext,
+{
+    ctx.unescrow_coins_execute(to_account, port_id, channel_id, coin)?;
+
+    decrease_total_escrow(ctx, coin)
+}
+
+/// Adds the escrowed coin to the total escrow of its denomination, and returns
+/// the event recording the new total.
+pub fn increase_total_escrow(
+    ctx: &mut impl TokenTransferExecutionContext,
+    coin: &PrefixedCoin,
+) -> Result<TotalEscrowEvent, TokenTransferError> {
+    let total_escrow = ctx
+        .total_escrow_for_denom(&coin.denom)?
+        .checked_add(coin.amount)
+        .ok_or_else(|| TokenTransferError::TotalEscrowOverflow {
+            denom: coin.denom.to_string(),
+        })?;
+
+    ctx.store_total_escrow_for_denom(coin.denom.clone(), total_escrow)?;
+
+    Ok(TotalEscrowEvent {
+        denom: coin.denom.clone(),
+        total_escrow,
+    })
+}
+
+/// Subtracts the unescrowed coin from the total escrow of its denomination,
+/// and returns the event recording the new total.
+///
+/// Fails if more tokens are unescrowed than the total escrow, which means
+/// that tokens were escrowed without being accounted for.
+pub fn decrease_total_escrow(
+    ctx: &mut impl TokenTransferExecutionContext,
+    coin: &PrefixedCoin,
+) -> Result<TotalEscrowEvent, TokenTransferError> {
+    let total_escrow = ctx
+        .total_escrow_for_denom(&coin.denom)?
+        .checked_sub(coin.amount)
+        .ok_or_else(|| TokenTransferError::TotalEscrowUnderflow {
+            denom: coin.denom.to_string(),
+        })?;
+
+    ctx.store_total_escrow_for_denom(coin.denom.clone(), total_escrow)?;
+
+    Ok(TotalEscrowEvent {
+        denom: coin.denom.clone(),
+        total_escrow,
+    })
+}
+
+/// Checks that the escrow accounts of the host hold at least the total escrow
+/// of each denomination, i.e. that every voucher still outstanding on a
+/// counterparty chain is backed by escrowed native tokens.
+///
+/// The escrow accounts may hold more than the total escrow, as anyone can
+/// send tokens to them directly.
+pub fn check_total_escrow_invariant(
+    ctx: &impl TotalEscrowInvariantContext,
+) -> Result<(), TokenTransferError> {
+    for (denom, total_escrow) in ctx.total_escrows()? {
+        let escrowed = ctx.escrowed_balance(&denom)?;
+
+        if escrowed < total_escrow {
+            return Err(TokenTransferError::TotalEscrowInvariantBroken {
+                denom: denom.to_string(),
+                total_escrow: total_escrow.to_string(),
+                escrowed: escrowed.to_string(),
+            });
+        }
+    }
+
+    Ok(())
+}
+
+/// Refunds all the tokens of the packet to the sender.
+///
+/// Returns the events recording the new total escrow of the unescrowed
+/// denominations.
+///
+/// The refund is all or nothing: if a token fails to be refunded, the tokens
+/// refunded before it are taken back from the sender.
+///
+/// A prior call to [`refund_packet_token_validate`] MUST have succeeded.
 pub fn refund_packet_token_execute(
     ctx_a: &mut impl TokenTransferExecutionContext,
     packet: &Packet,
-    data: &PacketData,
-) -> Result<(), TokenTransferError> {
+    data: &FungibleTokenPacketDataV2,
+) -> Result<ModuleExtras, TokenTransferError> {
     let sender = data
         .sender
         .clone()
         .try_into()
         .map_err(|_| TokenTransferError::ParseAccountFailure)?;
 
-    if is_sender_chain_source(
-        packet.port_id_on_a.clone(),
-        packet.chan_id_on_a.clone(),
-        &data.token.denom,
-    ) {
-        ctx_a.unescrow_coins_execute(
-            &sender,
-            &packet.port_id_on_a,
-            &packet.chan_id_on_a,
-            &data.token,
-        )
-    }
-    // mint vouchers back to sender
-    else {
-        ctx_a.mint_coins_execute(&sender, &data.token)
+    let mut extras = ModuleExtras::empty();
+    let mut unescrowed_coins = Vec::new();
+    let mut minted_coins = Vec::new();
+
+    for token in &data.tokens {
+        let result = if is_sender_chain_source(
+            packet.port_id_on_a.clone(),
+            packet.chan_id_on_a.clone(),
+            &token.denom,
+        ) {
+            unescrow_coins_execute(
+                ctx_a,
+                &sender,
+                &packet.port_id_on_a,
+                &packet.chan_id_on_a,
+                token,
+            )
+            .map(|total_escrow_event| {
+                extras.events.push(total_escrow_event.into());
+                unescrowed_coins.push(token.clone());
+            })
+        }
+        // mint vouchers back to sender
+        else {
+            ctx_a
+                .mint_coins_execute(&sender, token)
+                .map(|()| minted_coins.push(token.clone()))
+        };
+
+        if let Err(err) = result {
+            revert_credited_coins(
+                ctx_a,
+                &sender,
+                &packet.port_id_on_a,
+                &packet.chan_id_on_a,
+                &unescrowed_coins,
+                &minted_coins,
+                &data.memo,
+            );
+            return Err(err);
+        }
     }
+
+    Ok(extras)
 }
 
+/// Validates that all the tokens of the packet can be refunded to the sender.
 pub fn refund_packet_token_validate(
     ctx_a: &impl TokenTransferValidationContext,
     packet: &Packet,
-    data: &PacketData,
+    data: &FungibleTokenPacketDataV2,
 ) -> Result<(), TokenTransferError> {
     let sender = data
         .sender
@@ -51,18 +280,22 @@ pub fn refund_packet_token_validate(
         .try_into()
         .map_err(|_| TokenTransferError::ParseAccountFailure)?;
 
-    if is_sender_chain_source(
-        packet.port_id_on_a.clone(),
-        packet.chan_id_on_a.clone(),
-        &data.token.denom,
-    ) {
-        ctx_a.unescrow_coins_validate(
-            &sender,
-            &packet.port_id_on_a,
-            &packet.chan_id_on_a,
-            &data.token,
-        )
-    } else {
-        ctx_a.mint_coins_validate(&sender, &data.token)
+    for token in &data.tokens {
+        if is_sender_chain_source(
+            packet.port_id_on_a.clone(),
+            packet.chan_id_on_a.clone(),
+            &token.denom,
+        ) {
+            ctx_a.unescrow_coins_validate(
+                &sender,
+                &packet.port_id_on_a,
+                &packet.chan_id_on_a,
+                token,
+            )?;
+        } else {
+            ctx_a.mint_coins_validate(&sender, token)?;
+        }
     }
+
+    Ok(())
 }
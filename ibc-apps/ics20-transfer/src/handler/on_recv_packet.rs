@@ -1,15 +1,39 @@
 use ibc_app_transfer_types::error::TokenTransferError;
 use ibc_app_transfer_types::events::DenomTraceEvent;
-use ibc_app_transfer_types::packet::PacketData;
-use ibc_app_transfer_types::{is_receiver_chain_source, TracePrefix};
+use ibc_app_transfer_types::packet::FungibleTokenPacketDataV2;
+use ibc_app_transfer_types::{is_receiver_chain_source, PrefixedDenom, TracePrefix};
 use ibc_core::channel::types::packet::Packet;
 use ibc_core::primitives::prelude::*;
 use ibc_core::router::types::module::ModuleExtras;
 
+use super::{revert_credited_coins, unescrow_coins_execute};
 use crate::context::TokenTransferExecutionContext;
 
+/// Stores the trace of the denomination of minted vouchers, the first time
+/// they are minted.
+fn store_denom_trace<Ctx: TokenTransferExecutionContext>(
+    ctx_b: &mut Ctx,
+    denom: &PrefixedDenom,
+) -> Result<(), TokenTransferError> {
+    let hash = match ctx_b.denom_hash_string(denom) {
+        Some(hash) => hash,
+        None => return Ok(()),
+    };
+
+    if ctx_b.denom_trace(&hash)?.is_none() {
+        ctx_b.store_denom_trace(hash, denom.clone())?;
+    }
+
+    Ok(())
+}
+
 /// This function handles the transfer receiving logic.
 ///
+/// All the tokens of the packet are received atomically: each of them is
+/// validated before any of them is unescrowed or minted, and if one of them
+/// still fails to be credited, the tokens credited before it are taken back
+/// from the receiver.
+///
 /// Note that `send/mint_coins_validate` steps are performed on the host chain
 /// to validate accounts and token info. But the result is then used for
 /// execution on the IBC side, including storing acknowledgements and emitting
@@ -17,12 +41,19 @@ use crate::context::TokenTransferExecutionContext;
 pub fn process_recv_packet_execute<Ctx: TokenTransferExecutionContext>(
     ctx_b: &mut Ctx,
     packet: &Packet,
-    data: PacketData,
+    data: FungibleTokenPacketDataV2,
 ) -> Result<ModuleExtras, (ModuleExtras, TokenTransferError)> {
     ctx_b
         .can_receive_coins()
         .map_err(|err| (ModuleExtras::empty(), err))?;
 
+    if data.should_forward() {
+        return Err((
+            ModuleExtras::empty(),
+            TokenTransferError::ForwardingNotSupported,
+        ));
+    }
+
     let receiver_account = data.receiver.clone().try_into().map_err(|_| {
         (
             ModuleExtras::empty(),
@@ -30,87 +61,118 @@ pub fn process_recv_packet_execute<Ctx: TokenTransferExecutionContext>(
         )
     })?;
 
-    let extras = if is_receiver_chain_source(
-        packet.port_id_on_a.clone(),
-        packet.chan_id_on_a.clone(),
-        &data.token.denom,
-    ) {
-        // sender chain is not the source, unescrow tokens
-        let prefix = TracePrefix::new(packet.port_id_on_a.clone(), packet.chan_id_on_a.clone());
-        let coin = {
-            let mut c = data.token;
-            c.denom.remove_trace_prefix(&prefix);
-            c
-        };
-
-        // Note: it is correct to do the validation here because `recv_packet()`
-        // works slightly differently. We do not have a
-        // `on_recv_packet_validate()` callback because regardless of whether or
-        // not the app succeeds to receive the packet, we want to run the
-        // `execute()` phase. And this is because the app failing to receive
-        // does not constitute a failure of the message processing.
-        // Specifically, when the app fails to receive, we need to return
-        // a `TokenTransferAcknowledgement::Error` acknowledgement, which
-        // gets relayed back to the sender so that the escrowed tokens
-        // can be refunded.
-        ctx_b
-            .unescrow_coins_validate(
-                &receiver_account,
-                &packet.port_id_on_b,
-                &packet.chan_id_on_b,
-                &coin,
-            )
-            .map_err(|token_err| (ModuleExtras::empty(), token_err))?;
-        ctx_b
-            .unescrow_coins_execute(
-                &receiver_account,
-                &packet.port_id_on_b,
-                &packet.chan_id_on_b,
-                &coin,
-            )
-            .map_err(|token_err| (ModuleExtras::empty(), token_err))?;
-
-        ModuleExtras::empty()
-    } else {
-        // sender chain is the source, mint vouchers
-        let prefix = TracePrefix::new(packet.port_id_on_b.clone(), packet.chan_id_on_b.clone());
-        let coin = {
-            let mut c = data.token;
-            c.denom.add_trace_prefix(prefix);
-            c
-        };
-
-        let extras = {
+    let mut extras = ModuleExtras::empty();
+    let mut unescrowed_coins = Vec::new();
+    let mut minted_coins = Vec::new();
+
+    // Note: it is correct to do the validation here because `recv_packet()`
+    // works slightly differently. We do not have a
+    // `on_recv_packet_validate()` callback because regardless of whether or
+    // not the app succeeds to receive the packet, we want to run the
+    // `execute()` phase. And this is because the app failing to receive
+    // does not constitute a failure of the message processing.
+    // Specifically, when the app fails to receive, we need to return
+    // a `TokenTransferAcknowledgement::Error` acknowledgement, which
+    // gets relayed back to the sender so that the escrowed tokens
+    // can be refunded.
+    for token in data.tokens {
+        if is_receiver_chain_source(
+            packet.port_id_on_a.clone(),
+            packet.chan_id_on_a.clone(),
+            &token.denom,
+        ) {
+            // sender chain is not the source, unescrow tokens
+            let prefix = TracePrefix::new(packet.port_id_on_a.clone(), packet.chan_id_on_a.clone());
+            let coin = {
+                let mut c = token;
+                c.denom.remove_trace_prefix(&prefix);
+                c
+            };
+
+            ctx_b
+                .unescrow_coins_validate(
+                    &receiver_account,
+                    &packet.port_id_on_b,
+                    &packet.chan_id_on_b,
+                    &coin,
+                )
+                .map_err(|token_err| (extras.clone(), token_err))?;
+
+            unescrowed_coins.push(coin);
+        } else {
+            // sender chain is the source, mint vouchers
+            let prefix = TracePrefix::new(packet.port_id_on_b.clone(), packet.chan_id_on_b.clone());
+            let coin = {
+                let mut c = token;
+                c.denom.add_trace_prefix(prefix);
+                c
+            };
+
             let denom_trace_event = DenomTraceEvent {
                 trace_hash: ctx_b.denom_hash_string(&coin.denom),
                 denom: coin.denom.clone(),
             };
-            ModuleExtras {
-                events: vec![denom_trace_event.into()],
-                log: Vec::new(),
+            extras.events.push(denom_trace_event.into());
+
+            ctx_b
+                .mint_coins_validate(&receiver_account, &coin)
+                .map_err(|token_err| (extras.clone(), token_err))?;
+
+            minted_coins.push(coin);
+        }
+    }
+
+    for (i, coin) in unescrowed_coins.iter().enumerate() {
+        match unescrow_coins_execute(
+            ctx_b,
+            &receiver_account,
+            &packet.port_id_on_b,
+            &packet.chan_id_on_b,
+            coin,
+        ) {
+            Ok(total_escrow_event) => extras.events.push(total_escrow_event.into()),
+            Err(token_err) => {
+                let revert_extras = revert_credited_coins(
+                    ctx_b,
+                    &receiver_account,
+                    &packet.port_id_on_b,
+                    &packet.chan_id_on_b,
+                    &unescrowed_coins[..i],
+                    &[],
+                    &data.memo,
+                );
+                extras.events.extend(revert_extras.events);
+                extras.log.extend(revert_extras.log);
+                return Err((extras, token_err));
             }
-        };
-
-        // Note: it is correct to do the validation here because `recv_packet()`
-        // works slightly differently. We do not have a
-        // `on_recv_packet_validate()` callback because regardless of whether or
-        // not the app succeeds to receive the packet, we want to run the
-        // `execute()` phase. And this is because the app failing to receive
-        // does not constitute a failure of the message processing.
-        // Specifically, when the app fails to receive, we need to return
-        // a `TokenTransferAcknowledgement::Error` acknowledgement, which
-        // gets relayed back to the sender so that the escrowed tokens
-        // can be refunded.
-        ctx_b
-            .mint_coins_validate(&receiver_account, &coin)
-            .map_err(|token_err| (extras.clone(), token_err))?;
-
-        ctx_b
-            .mint_coins_execute(&receiver_account, &coin)
-            .map_err(|token_err| (extras.clone(), token_err))?;
-
-        extras
-    };
+        }
+    }
+
+    for (i, coin) in minted_coins.iter().enumerate() {
+        let result = ctx_b
+            .mint_coins_execute(&receiver_account, coin)
+            .map_err(|token_err| (i, token_err))
+            .and_then(|()| {
+                // the voucher is minted, hence reverted too, even if storing
+                // its trace fails
+                store_denom_trace(ctx_b, &coin.denom).map_err(|token_err| (i + 1, token_err))
+            });
+
+        if let Err((minted, token_err)) = result {
+            let revert_extras = revert_credited_coins(
+                ctx_b,
+                &receiver_account,
+                &packet.port_id_on_b,
+                &packet.chan_id_on_b,
+                &unescrowed_coins,
+                &minted_coins[..minted],
+                &data.memo,
+            );
+            extras.events.extend(revert_extras.events);
+            extras.log.extend(revert_extras.log);
+            return Err((extras, token_err));
+        }
+    }
 
     Ok(extras)
 }
@@ -1,27 +1,34 @@
 use ibc_app_transfer_types::error::TokenTransferError;
 use ibc_app_transfer_types::events::TransferEvent;
-use ibc_app_transfer_types::msgs::transfer::MsgTransfer;
-use ibc_app_transfer_types::{is_sender_chain_source, MODULE_ID_STR};
+use ibc_app_transfer_types::msgs::transfer::MsgTransferV2;
+use ibc_app_transfer_types::packet::{FungibleTokenPacketDataV2, PacketData};
+use ibc_app_transfer_types::{is_sender_chain_source, MODULE_ID_STR, VERSION_V2};
 use ibc_core::channel::context::{SendPacketExecutionContext, SendPacketValidationContext};
 use ibc_core::channel::handler::{send_packet_execute, send_packet_validate};
 use ibc_core::channel::types::packet::Packet;
+use ibc_core::channel::types::Version;
 use ibc_core::handler::types::events::MessageEvent;
 use ibc_core::host::types::path::{ChannelEndPath, SeqSendPath};
 use ibc_core::primitives::prelude::*;
 use ibc_core::router::types::event::ModuleEvent;
 
+use super::escrow_coins_execute;
 use crate::context::{TokenTransferExecutionContext, TokenTransferValidationContext};
 
 /// Initiate a token transfer. Equivalent to calling [`send_transfer_validate`], followed by [`send_transfer_execute`].
+///
+/// The message may be either a `MsgTransfer` or a `MsgTransferV2`. All of its
+/// tokens are transferred in a single packet.
 pub fn send_transfer<SendPacketCtx, TokenCtx>(
     send_packet_ctx_a: &mut SendPacketCtx,
     token_ctx_a: &mut TokenCtx,
-    msg: MsgTransfer,
+    msg: impl Into<MsgTransferV2>,
 ) -> Result<(), TokenTransferError>
 where
     SendPacketCtx: SendPacketExecutionContext,
     TokenCtx: TokenTransferExecutionContext,
 {
+    let msg = msg.into();
     send_transfer_validate(send_packet_ctx_a, token_ctx_a, msg.clone())?;
     send_transfer_execute(send_packet_ctx_a, token_ctx_a, msg)
 }
@@ -30,14 +37,18 @@ where
 pub fn send_transfer_validate<SendPacketCtx, TokenCtx>(
     send_packet_ctx_a: &SendPacketCtx,
     token_ctx_a: &TokenCtx,
-    msg: MsgTransfer,
+    msg: impl Into<MsgTransferV2>,
 ) -> Result<(), TokenTransferError>
 where
     SendPacketCtx: SendPacketValidationContext,
     TokenCtx: TokenTransferValidationContext,
 {
+    let msg = msg.into();
+
     token_ctx_a.can_send_coins()?;
 
+    msg.packet_data.validate_basic()?;
+
     let chan_end_path_on_a = ChannelEndPath::new(&msg.port_id_on_a, &msg.chan_id_on_a);
     let chan_end_on_a = send_packet_ctx_a.channel_end(&chan_end_path_on_a)?;
 
@@ -54,8 +65,6 @@ where
     let seq_send_path_on_a = SeqSendPath::new(&msg.port_id_on_a, &msg.chan_id_on_a);
     let sequence = send_packet_ctx_a.get_next_sequence_send(&seq_send_path_on_a)?;
 
-    let token = &msg.packet_data.token;
-
     let sender: TokenCtx::AccountId = msg
         .packet_data
         .sender
@@ -63,25 +72,26 @@ where
         .try_into()
         .map_err(|_| TokenTransferError::ParseAccountFailure)?;
 
-    if is_sender_chain_source(
-        msg.port_id_on_a.clone(),
-        msg.chan_id_on_a.clone(),
-        &token.denom,
-    ) {
-        token_ctx_a.escrow_coins_validate(
-            &sender,
-            &msg.port_id_on_a,
-            &msg.chan_id_on_a,
-            token,
-            &msg.packet_data.memo,
-        )?;
-    } else {
-        token_ctx_a.burn_coins_validate(&sender, token, &msg.packet_data.memo)?;
+    for token in &msg.packet_data.tokens {
+        if is_sender_chain_source(
+            msg.port_id_on_a.clone(),
+            msg.chan_id_on_a.clone(),
+            &token.denom,
+        ) {
+            token_ctx_a.escrow_coins_validate(
+                &sender,
+                &msg.port_id_on_a,
+                &msg.chan_id_on_a,
+                token,
+                &msg.packet_data.memo,
+            )?;
+        } else {
+            token_ctx_a.burn_coins_validate(&sender, token, &msg.packet_data.memo)?;
+        }
     }
 
     let packet = {
-        let data = serde_json::to_vec(&msg.packet_data)
-            .expect("PacketData's infallible Serialize impl failed");
+        let data = encode_packet_data(msg.packet_data, chan_end_on_a.version())?;
 
         Packet {
             seq_on_a: sequence,
@@ -104,12 +114,14 @@ where
 pub fn send_transfer_execute<SendPacketCtx, TokenCtx>(
     send_packet_ctx_a: &mut SendPacketCtx,
     token_ctx_a: &mut TokenCtx,
-    msg: MsgTransfer,
+    msg: impl Into<MsgTransferV2>,
 ) -> Result<(), TokenTransferError>
 where
     SendPacketCtx: SendPacketExecutionContext,
     TokenCtx: TokenTransferExecutionContext,
 {
+    let msg = msg.into();
+
     let chan_end_path_on_a = ChannelEndPath::new(&msg.port_id_on_a, &msg.chan_id_on_a);
     let chan_end_on_a = send_packet_ctx_a.channel_end(&chan_end_path_on_a)?;
 
@@ -127,8 +139,6 @@ where
     let seq_send_path_on_a = SeqSendPath::new(&msg.port_id_on_a, &msg.chan_id_on_a);
     let sequence = send_packet_ctx_a.get_next_sequence_send(&seq_send_path_on_a)?;
 
-    let token = &msg.packet_data.token;
-
     let sender = msg
         .packet_data
         .sender
@@ -136,27 +146,29 @@ where
         .try_into()
         .map_err(|_| TokenTransferError::ParseAccountFailure)?;
 
-    if is_sender_chain_source(
-        msg.port_id_on_a.clone(),
-        msg.chan_id_on_a.clone(),
-        &token.denom,
-    ) {
-        token_ctx_a.escrow_coins_execute(
-            &sender,
-            &msg.port_id_on_a,
-            &msg.chan_id_on_a,
-            token,
-            &msg.packet_data.memo,
-        )?;
-    } else {
-        token_ctx_a.burn_coins_execute(&sender, token, &msg.packet_data.memo)?;
+    let mut total_escrow_events = Vec::new();
+
+    for token in &msg.packet_data.tokens {
+        if is_sender_chain_source(
+            msg.port_id_on_a.clone(),
+            msg.chan_id_on_a.clone(),
+            &token.denom,
+        ) {
+            total_escrow_events.push(escrow_coins_execute(
+                token_ctx_a,
+                &sender,
+                &msg.port_id_on_a,
+                &msg.chan_id_on_a,
+                token,
+                &msg.packet_data.memo,
+            )?);
+        } else {
+            token_ctx_a.burn_coins_execute(&sender, token, &msg.packet_data.memo)?;
+        }
     }
 
     let packet = {
-        let data = {
-            serde_json::to_vec(&msg.packet_data)
-                .expect("PacketData's infallible Serialize impl failed")
-        };
+        let data = encode_packet_data(msg.packet_data.clone(), chan_end_on_a.version())?;
 
         Packet {
             seq_on_a: sequence,
@@ -173,22 +185,54 @@ where
     send_packet_execute(send_packet_ctx_a, packet)?;
 
     {
-        send_packet_ctx_a.log_message(format!(
-            "IBC fungible token transfer: {} --({})--> {}",
-            msg.packet_data.sender, token, msg.packet_data.receiver
-        ))?;
-
-        let transfer_event = TransferEvent {
-            sender: msg.packet_data.sender,
-            receiver: msg.packet_data.receiver,
-            amount: msg.packet_data.token.amount,
-            denom: msg.packet_data.token.denom,
-            memo: msg.packet_data.memo,
-        };
-        send_packet_ctx_a.emit_ibc_event(ModuleEvent::from(transfer_event).into())?;
+        let FungibleTokenPacketDataV2 {
+            tokens,
+            sender,
+            receiver,
+            memo,
+            ..
+        } = msg.packet_data;
+
+        for token in tokens {
+            send_packet_ctx_a.log_message(format!(
+                "IBC fungible token transfer: {} --({})--> {}",
+                sender, token, receiver
+            ))?;
+
+            let transfer_event = TransferEvent {
+                sender: sender.clone(),
+                receiver: receiver.clone(),
+                amount: token.amount,
+                denom: token.denom,
+                memo: memo.clone(),
+            };
+            send_packet_ctx_a.emit_ibc_event(ModuleEvent::from(transfer_event).into())?;
+        }
+
+        for total_escrow_event in total_escrow_events {
+            send_packet_ctx_a.emit_ibc_event(ModuleEvent::from(total_escrow_event).into())?;
+        }
 
         send_packet_ctx_a.emit_ibc_event(MessageEvent::Module(MODULE_ID_STR.to_string()).into())?;
     }
 
     Ok(())
 }
+
+/// Encodes the packet data according to the version of the channel: an
+/// `ics20-2` channel carries `FungibleTokenPacketDataV2`, while any other
+/// channel carries the `ics20-1` `PacketData`.
+fn encode_packet_data(
+    packet_data: FungibleTokenPacketDataV2,
+    chan_version: &Version,
+) -> Result<Vec<u8>, TokenTransferError> {
+    let data = if chan_version.as_str() == VERSION_V2 {
+        serde_json::to_vec(&packet_data)
+            .expect("FungibleTokenPacketDataV2's infallible Serialize impl failed")
+    } else {
+        serde_json::to_vec(&PacketData::try_from(packet_data)?)
+            .expect("PacketData's infallible Serialize impl failed")
+    };
+
+    Ok(data)
+}
@@ -1,12 +1,10 @@
 use ibc_app_transfer_types::error::TokenTransferError;
 use ibc_app_transfer_types::events::{AckEvent, AckStatusEvent, RecvEvent, TimeoutEvent};
-use ibc_app_transfer_types::packet::PacketData;
-use ibc_app_transfer_types::{ack_success_b64, VERSION};
+use ibc_app_transfer_types::{ack_success_b64, VERSION, VERSION_V2};
 use ibc_core::channel::types::acknowledgement::{Acknowledgement, AcknowledgementStatus};
 use ibc_core::channel::types::channel::{Counterparty, Order};
 use ibc_core::channel::types::packet::Packet;
 use ibc_core::channel::types::Version;
-use ibc_core::handler::types::error::ContextError;
 use ibc_core::host::types::identifiers::{ChannelId, ConnectionId, PortId};
 use ibc_core::primitives::prelude::*;
 use ibc_core::primitives::Signer;
@@ -14,9 +12,22 @@ use ibc_core::router::types::module::ModuleExtras;
 
 use crate::context::{TokenTransferExecutionContext, TokenTransferValidationContext};
 use crate::handler::{
-    process_recv_packet_execute, refund_packet_token_execute, refund_packet_token_validate,
+    decode_channel_packet_data, process_recv_packet_execute, refund_packet_token_execute,
+    refund_packet_token_validate,
 };
 
+/// Checks that the version is one of the supported `ics20-1` and `ics20-2`
+/// versions.
+fn verify_version_supported(version: &Version) -> Result<(), TokenTransferError> {
+    if version.as_str() != VERSION && version.as_str() != VERSION_V2 {
+        return Err(TokenTransferError::UnsupportedVersion {
+            version: version.clone(),
+        });
+    }
+
+    Ok(())
+}
+
 pub fn on_chan_open_init_validate(
     ctx: &impl TokenTransferValidationContext,
     order: Order,
@@ -41,9 +52,7 @@ pub fn on_chan_open_init_validate(
     }
 
     if !version.is_empty() {
-        version
-            .verify_is_expected(Version::new(VERSION.to_string()))
-            .map_err(ContextError::from)?;
+        verify_version_supported(version)?;
     }
 
     Ok(())
@@ -56,9 +65,17 @@ pub fn on_chan_open_init_execute(
     _port_id: &PortId,
     _channel_id: &ChannelId,
     _counterparty: &Counterparty,
-    _version: &Version,
+    version: &Version,
 ) -> Result<(ModuleExtras, Version), TokenTransferError> {
-    Ok((ModuleExtras::empty(), Version::new(VERSION.to_string())))
+    // An empty version lets us pick the version, for which `ics20-1` is
+    // proposed since every counterparty supports it.
+    let version = if version.is_empty() {
+        Version::new(VERSION.to_string())
+    } else {
+        version.clone()
+    };
+
+    Ok((ModuleExtras::empty(), version))
 }
 
 pub fn on_chan_open_try_validate(
@@ -77,9 +94,7 @@ pub fn on_chan_open_try_validate(
         });
     }
 
-    counterparty_version
-        .verify_is_expected(Version::new(VERSION.to_string()))
-        .map_err(ContextError::from)?;
+    verify_version_supported(counterparty_version)?;
 
     Ok(())
 }
@@ -91,9 +106,9 @@ pub fn on_chan_open_try_execute(
     _port_id: &PortId,
     _channel_id: &ChannelId,
     _counterparty: &Counterparty,
-    _counterparty_version: &Version,
+    counterparty_version: &Version,
 ) -> Result<(ModuleExtras, Version), TokenTransferError> {
-    Ok((ModuleExtras::empty(), Version::new(VERSION.to_string())))
+    Ok((ModuleExtras::empty(), counterparty_version.clone()))
 }
 
 pub fn on_chan_open_ack_validate(
@@ -102,9 +117,7 @@ pub fn on_chan_open_ack_validate(
     _channel_id: &ChannelId,
     counterparty_version: &Version,
 ) -> Result<(), TokenTransferError> {
-    counterparty_version
-        .verify_is_expected(Version::new(VERSION.to_string()))
-        .map_err(ContextError::from)?;
+    verify_version_supported(counterparty_version)?;
 
     Ok(())
 }
@@ -170,11 +183,17 @@ pub fn on_recv_packet_execute(
     ctx_b: &mut impl TokenTransferExecutionContext,
     packet: &Packet,
 ) -> (ModuleExtras, Acknowledgement) {
-    let data = match serde_json::from_slice::<PacketData>(&packet.data) {
+    let data = match decode_channel_packet_data(
+        ctx_b,
+        &packet.port_id_on_b,
+        &packet.chan_id_on_b,
+        &packet.data,
+    )
+    .and_then(|data| data.validate_basic().map(|()| data))
+    {
         Ok(data) => data,
-        Err(_) => {
-            let ack =
-                AcknowledgementStatus::error(TokenTransferError::PacketDataDeserialization.into());
+        Err(err) => {
+            let ack = AcknowledgementStatus::error(err.into());
             return (ModuleExtras::empty(), ack.into());
         }
     };
@@ -184,15 +203,17 @@ pub fn on_recv_packet_execute(
         Err((extras, error)) => (extras, AcknowledgementStatus::error(error.into())),
     };
 
-    let recv_event = RecvEvent {
-        sender: data.sender,
-        receiver: data.receiver,
-        denom: data.token.denom,
-        amount: data.token.amount,
-        memo: data.memo,
-        success: ack.is_successful(),
-    };
-    extras.events.push(recv_event.into());
+    for token in data.tokens {
+        let recv_event = RecvEvent {
+            sender: data.sender.clone(),
+            receiver: data.receiver.clone(),
+            denom: token.denom,
+            amount: token.amount,
+            memo: data.memo.clone(),
+            success: ack.is_successful(),
+        };
+        extras.events.push(recv_event.into());
+    }
 
     (extras, ack.into())
 }
@@ -206,8 +227,12 @@ pub fn on_acknowledgement_packet_validate<Ctx>(
 where
     Ctx: TokenTransferValidationContext,
 {
-    let data = serde_json::from_slice::<PacketData>(&packet.data)
-        .map_err(|_| TokenTransferError::PacketDataDeserialization)?;
+    let data = decode_channel_packet_data(
+        ctx,
+        &packet.port_id_on_a,
+        &packet.chan_id_on_a,
+        &packet.data,
+    )?;
 
     let acknowledgement = serde_json::from_slice::<AcknowledgementStatus>(acknowledgement.as_ref())
         .map_err(|_| TokenTransferError::AckDeserialization)?;
@@ -225,14 +250,14 @@ pub fn on_acknowledgement_packet_execute(
     acknowledgement: &Acknowledgement,
     _relayer: &Signer,
 ) -> (ModuleExtras, Result<(), TokenTransferError>) {
-    let data = match serde_json::from_slice::<PacketData>(&packet.data) {
+    let data = match decode_channel_packet_data(
+        ctx,
+        &packet.port_id_on_a,
+        &packet.chan_id_on_a,
+        &packet.data,
+    ) {
         Ok(data) => data,
-        Err(_) => {
-            return (
-                ModuleExtras::empty(),
-                Err(TokenTransferError::PacketDataDeserialization),
-            );
-        }
+        Err(err) => return (ModuleExtras::empty(), Err(err)),
     };
 
     let acknowledgement =
@@ -246,23 +271,30 @@ pub fn on_acknowledgement_packet_execute(
             }
         };
 
+    let mut events = Vec::with_capacity(data.tokens.len() + 1);
+
     if !acknowledgement.is_successful() {
-        if let Err(err) = refund_packet_token_execute(ctx, packet, &data) {
-            return (ModuleExtras::empty(), Err(err));
+        match refund_packet_token_execute(ctx, packet, &data) {
+            Ok(refund_extras) => events.extend(refund_extras.events),
+            Err(err) => return (ModuleExtras::empty(), Err(err)),
         }
     }
 
-    let ack_event = AckEvent {
-        sender: data.sender,
-        receiver: data.receiver,
-        denom: data.token.denom,
-        amount: data.token.amount,
-        memo: data.memo,
-        acknowledgement: acknowledgement.clone(),
-    };
+    for token in data.tokens {
+        let ack_event = AckEvent {
+            sender: data.sender.clone(),
+            receiver: data.receiver.clone(),
+            denom: token.denom,
+            amount: token.amount,
+            memo: data.memo.clone(),
+            acknowledgement: acknowledgement.clone(),
+        };
+        events.push(ack_event.into());
+    }
+    events.push(AckStatusEvent { acknowledgement }.into());
 
     let extras = ModuleExtras {
-        events: vec![ack_event.into(), AckStatusEvent { acknowledgement }.into()],
+        events,
         log: Vec::new(),
     };
 
@@ -277,8 +309,12 @@ pub fn on_timeout_packet_validate<Ctx>(
 where
     Ctx: TokenTransferValidationContext,
 {
-    let data = serde_json::from_slice::<PacketData>(&packet.data)
-        .map_err(|_| TokenTransferError::PacketDataDeserialization)?;
+    let data = decode_channel_packet_data(
+        ctx,
+        &packet.port_id_on_a,
+        &packet.chan_id_on_a,
+        &packet.data,
+    )?;
 
     refund_packet_token_validate(ctx, packet, &data)?;
 
@@ -290,29 +326,33 @@ pub fn on_timeout_packet_execute(
     packet: &Packet,
     _relayer: &Signer,
 ) -> (ModuleExtras, Result<(), TokenTransferError>) {
-    let data = match serde_json::from_slice::<PacketData>(&packet.data) {
+    let data = match decode_channel_packet_data(
+        ctx,
+        &packet.port_id_on_a,
+        &packet.chan_id_on_a,
+        &packet.data,
+    ) {
         Ok(data) => data,
-        Err(_) => {
-            return (
-                ModuleExtras::empty(),
-                Err(TokenTransferError::PacketDataDeserialization),
-            );
-        }
+        Err(err) => return (ModuleExtras::empty(), Err(err)),
     };
 
-    if let Err(err) = refund_packet_token_execute(ctx, packet, &data) {
-        return (ModuleExtras::empty(), Err(err));
-    }
-
-    let timeout_event = TimeoutEvent {
-        refund_receiver: data.sender,
-        refund_denom: data.token.denom,
-        refund_amount: data.token.amount,
-        memo: data.memo,
+    let mut events = match refund_packet_token_execute(ctx, packet, &data) {
+        Ok(refund_extras) => refund_extras.events,
+        Err(err) => return (ModuleExtras::empty(), Err(err)),
     };
 
+    events.extend(data.tokens.into_iter().map(|token| {
+        TimeoutEvent {
+            refund_receiver: data.sender.clone(),
+            refund_denom: token.denom,
+            refund_amount: token.amount,
+            memo: data.memo.clone(),
+        }
+        .into()
+    }));
+
     let extras = ModuleExtras {
-        events: vec![timeout_event.into()],
+        events,
         log: Vec::new(),
     };
 
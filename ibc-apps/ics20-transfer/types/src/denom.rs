@@ -82,6 +82,14 @@ impl TracePrefix {
             channel_id,
         }
     }
+
+    pub fn port_id(&self) -> &PortId {
+        &self.port_id
+    }
+
+    pub fn channel_id(&self) -> &ChannelId {
+        &self.channel_id
+    }
 }
 
 impl Display for TracePrefix {
@@ -133,6 +141,16 @@ impl TracePath {
     pub fn is_empty(&self) -> bool {
         self.0.is_empty()
     }
+
+    /// Returns the hops of the path, starting from the most recent one.
+    pub fn hops(&self) -> impl Iterator<Item = &TracePrefix> {
+        self.0.iter().rev()
+    }
+
+    /// Builds a path from its hops, starting from the most recent one.
+    pub fn from_hops(hops: Vec<TracePrefix>) -> Self {
+        Self(hops.into_iter().rev().collect())
+    }
 }
 
 impl<'a> TryFrom<Vec<&'a str>> for TracePath {
@@ -233,6 +251,22 @@ impl PrefixedDenom {
     pub fn add_trace_prefix(&mut self, prefix: TracePrefix) {
         self.trace_path.add_prefix(prefix)
     }
+
+    /// Returns the hash identifying the denomination, i.e. the upper-case hex
+    /// encoding of the SHA-256 hash of its full path, from which hosts such
+    /// as ibc-go derive the `ibc/{hash}` denomination of vouchers.
+    pub fn trace_hash(&self) -> String {
+        use core::fmt::Write;
+
+        use sha2::Digest;
+
+        sha2::Sha256::digest(self.to_string().as_bytes())
+            .iter()
+            .fold(String::new(), |mut hash, byte| {
+                let _ = write!(hash, "{byte:02X}");
+                hash
+            })
+    }
 }
 
 /// Returns true if the denomination originally came from the sender chain and
@@ -414,6 +448,16 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_denom_trace_hash() -> Result<(), TokenTransferError> {
+        assert_eq!(
+            PrefixedDenom::from_str("transfer/channel-0/uatom")?.trace_hash(),
+            "27394FB092D2ECCD56123C74F36E4C1F926001CEADA9CA97EA622B25F41E5EB2"
+        );
+
+        Ok(())
+    }
+
     #[test]
     fn test_denom_serde() -> Result<(), TokenTransferError> {
         let dt_str = "transfer/channel-0/uatom";
@@ -5,6 +5,7 @@ use core::str::Utf8Error;
 use displaydoc::Display;
 use ibc_core::channel::types::acknowledgement::StatusValue;
 use ibc_core::channel::types::channel::Order;
+use ibc_core::channel::types::Version;
 use ibc_core::handler::types::error::ContextError;
 use ibc_core::host::types::error::IdentifierError;
 use ibc_core::host::types::identifiers::{ChannelId, PortId};
@@ -73,6 +74,28 @@ pub enum TokenTransferError {
     UnknownMsgType { msg_type: String },
     /// invalid coin string: `{coin}`
     InvalidCoin { coin: String },
+    /// packet data must contain at least one token
+    EmptyTokens,
+    /// duplicate denomination `{denom}` in packet data
+    DuplicateDenom { denom: String },
+    /// invalid forwarding: `{reason}`
+    InvalidForwarding { reason: String },
+    /// forwarding tokens to another chain is not supported
+    ForwardingNotSupported,
+    /// packet data with several tokens or forwarding hops cannot be sent over an `ics20-1` channel
+    NotV1Compatible,
+    /// unsupported channel version `{version}`
+    UnsupportedVersion { version: Version },
+    /// total escrow of `{denom}` overflowed
+    TotalEscrowOverflow { denom: String },
+    /// total escrow of `{denom}` underflowed
+    TotalEscrowUnderflow { denom: String },
+    /// total escrow invariant broken: `{escrowed}` `{denom}` escrowed, less than the total escrow `{total_escrow}`
+    TotalEscrowInvariantBroken {
+        denom: String,
+        total_escrow: String,
+        escrowed: String,
+    },
     /// decoding raw bytes as UTF8 string error: `{0}`
     Utf8Decode(Utf8Error),
     /// other error: `{0}`
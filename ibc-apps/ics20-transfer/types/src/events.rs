@@ -11,6 +11,7 @@ const EVENT_TYPE_PACKET: &str = "fungible_token_packet";
 const EVENT_TYPE_TIMEOUT: &str = "timeout";
 const EVENT_TYPE_DENOM_TRACE: &str = "denomination_trace";
 const EVENT_TYPE_TRANSFER: &str = "ibc_transfer";
+const EVENT_TYPE_TOTAL_ESCROW: &str = "total_escrow";
 
 /// Contains all events variants that can be emitted from the token transfer application
 pub enum Event {
@@ -20,6 +21,7 @@ pub enum Event {
     Timeout(TimeoutEvent),
     DenomTrace(DenomTraceEvent),
     Transfer(TransferEvent),
+    TotalEscrow(TotalEscrowEvent),
 }
 
 /// Event emitted by the `onRecvPacket` module callback to indicate the that the
@@ -195,6 +197,31 @@ impl From<TransferEvent> for ModuleEvent {
     }
 }
 
+/// Event emitted whenever tokens are escrowed or unescrowed, recording the
+/// new total amount of the denomination held in escrow
+pub struct TotalEscrowEvent {
+    pub denom: PrefixedDenom,
+    pub total_escrow: Amount,
+}
+
+impl From<TotalEscrowEvent> for ModuleEvent {
+    fn from(ev: TotalEscrowEvent) -> Self {
+        let TotalEscrowEvent {
+            denom,
+            total_escrow,
+        } = ev;
+
+        Self {
+            kind: EVENT_TYPE_TOTAL_ESCROW.to_string(),
+            attributes: vec![
+                ("module", MODULE_ID_STR).into(),
+                ("denom", denom).into(),
+                ("total_escrow", total_escrow).into(),
+            ],
+        }
+    }
+}
+
 impl From<Event> for ModuleEvent {
     fn from(ev: Event) -> Self {
         match ev {
@@ -204,6 +231,7 @@ impl From<Event> for ModuleEvent {
             Event::Timeout(ev) => ev.into(),
             Event::DenomTrace(ev) => ev.into(),
             Event::Transfer(ev) => ev.into(),
+            Event::TotalEscrow(ev) => ev.into(),
         }
     }
 }
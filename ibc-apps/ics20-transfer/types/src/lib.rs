@@ -55,6 +55,10 @@ pub const PORT_ID_STR: &str = "transfer";
 /// ICS20 application current version.
 pub const VERSION: &str = "ics20-1";
 
+/// ICS20 application version that supports transferring several tokens in
+/// one packet and forwarding them through intermediate chains.
+pub const VERSION_V2: &str = "ics20-2";
+
 /// The successful string used for creating an acknowledgement status,
 /// equivalent to `base64::encode(0x01)`.
 pub const ACK_SUCCESS_B64: &str = "AQ==";
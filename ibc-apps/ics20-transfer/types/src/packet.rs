@@ -1,14 +1,23 @@
-//! Contains the `PacketData` type that defines the structure of token transfers' packet bytes
+//! Contains the `PacketData` and `FungibleTokenPacketDataV2` types that define
+//! the structure of token transfers' packet bytes
 
 use core::convert::TryFrom;
 use core::str::FromStr;
 
+use ibc_core::host::types::identifiers::{ChannelId, PortId};
 use ibc_core::primitives::prelude::*;
 use ibc_core::primitives::Signer;
 use ibc_proto::ibc::applications::transfer::v2::FungibleTokenPacketData as RawPacketData;
 
 use super::error::TokenTransferError;
-use super::{Amount, Memo, PrefixedCoin, PrefixedDenom};
+use super::{Amount, BaseDenom, Memo, PrefixedCoin, PrefixedDenom, TracePath, TracePrefix};
+
+/// Maximum number of chains that tokens can be forwarded through.
+pub const MAX_FORWARDING_HOPS: usize = 8;
+
+/// Maximum length of the memo passed to the final destination of forwarded
+/// tokens.
+pub const MAX_DESTINATION_MEMO_LENGTH: usize = 32768;
 
 /// Defines the structure of token transfers' packet bytes
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -61,6 +70,299 @@ impl From<PacketData> for RawPacketData {
     }
 }
 
+/// Defines the structure of the packet bytes of `ics20-2` token transfers,
+/// which can carry several tokens and forward them through other chains.
+///
+/// On the wire, the denomination of each token is encoded with a structured
+/// trace, i.e. a list of `{port_id, channel_id}` hops starting from the most
+/// recent one, rather than a path string.
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(parity_scale_codec::Encode, parity_scale_codec::Decode,)
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FungibleTokenPacketDataV2 {
+    pub tokens: Vec<PrefixedCoin>,
+    pub sender: Signer,
+    pub receiver: Signer,
+    pub memo: Memo,
+    pub forwarding: Option<Forwarding>,
+}
+
+impl FungibleTokenPacketDataV2 {
+    /// Checks that the packet data carries at least one token, that no
+    /// denomination appears twice, and that the forwarding, if any, is valid.
+    pub fn validate_basic(&self) -> Result<(), TokenTransferError> {
+        if self.tokens.is_empty() {
+            return Err(TokenTransferError::EmptyTokens);
+        }
+
+        for (i, token) in self.tokens.iter().enumerate() {
+            if self.tokens[..i].iter().any(|t| t.denom == token.denom) {
+                return Err(TokenTransferError::DuplicateDenom {
+                    denom: token.denom.to_string(),
+                });
+            }
+        }
+
+        if let Some(forwarding) = &self.forwarding {
+            forwarding.validate_basic()?;
+
+            if !forwarding.hops.is_empty() && !self.memo.as_ref().is_empty() {
+                return Err(TokenTransferError::InvalidForwarding {
+                    reason: "memo must be empty when forwarding tokens".to_string(),
+                });
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Returns true if the tokens are to be forwarded to another chain upon
+    /// receipt.
+    pub fn should_forward(&self) -> bool {
+        self.forwarding
+            .as_ref()
+            .map(|forwarding| !forwarding.hops.is_empty())
+            .unwrap_or(false)
+    }
+}
+
+impl From<PacketData> for FungibleTokenPacketDataV2 {
+    fn from(pkt_data: PacketData) -> Self {
+        Self {
+            tokens: vec![pkt_data.token],
+            sender: pkt_data.sender,
+            receiver: pkt_data.receiver,
+            memo: pkt_data.memo,
+            forwarding: None,
+        }
+    }
+}
+
+impl TryFrom<FungibleTokenPacketDataV2> for PacketData {
+    type Error = TokenTransferError;
+
+    fn try_from(pkt_data: FungibleTokenPacketDataV2) -> Result<Self, Self::Error> {
+        if pkt_data.forwarding.is_some() {
+            return Err(TokenTransferError::NotV1Compatible);
+        }
+
+        let mut tokens = pkt_data.tokens.into_iter();
+        match (tokens.next(), tokens.next()) {
+            (Some(token), None) => Ok(Self {
+                token,
+                sender: pkt_data.sender,
+                receiver: pkt_data.receiver,
+                memo: pkt_data.memo,
+            }),
+            (None, _) => Err(TokenTransferError::EmptyTokens),
+            (Some(_), Some(_)) => Err(TokenTransferError::NotV1Compatible),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for FungibleTokenPacketDataV2 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        PacketDataV2Json::from(self.clone()).serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for FungibleTokenPacketDataV2 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let json = PacketDataV2Json::deserialize(deserializer)?;
+        json.try_into_domain().map_err(serde::de::Error::custom)
+    }
+}
+
+/// The forwarding instructions of an `ics20-2` packet, which route the
+/// tokens through the given hops once they are received.
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(parity_scale_codec::Encode, parity_scale_codec::Decode,)
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Forwarding {
+    /// the hops the tokens go through after the receiving chain
+    pub hops: Vec<TracePrefix>,
+    /// the memo passed to the final destination of the tokens
+    pub destination_memo: Memo,
+}
+
+impl Forwarding {
+    pub fn validate_basic(&self) -> Result<(), TokenTransferError> {
+        if self.hops.len() > MAX_FORWARDING_HOPS {
+            return Err(TokenTransferError::InvalidForwarding {
+                reason: format!(
+                    "{} hops exceed the maximum of {MAX_FORWARDING_HOPS}",
+                    self.hops.len()
+                ),
+            });
+        }
+
+        let destination_memo = self.destination_memo.as_ref();
+        if !destination_memo.is_empty() && self.hops.is_empty() {
+            return Err(TokenTransferError::InvalidForwarding {
+                reason: "destination memo requires at least one hop".to_string(),
+            });
+        }
+        if destination_memo.len() > MAX_DESTINATION_MEMO_LENGTH {
+            return Err(TokenTransferError::InvalidForwarding {
+                reason: format!(
+                    "destination memo exceeds the maximum length of {MAX_DESTINATION_MEMO_LENGTH}"
+                ),
+            });
+        }
+
+        Ok(())
+    }
+}
+
+/// The JSON wire format of [`FungibleTokenPacketDataV2`].
+#[derive(serde::Serialize, serde::Deserialize)]
+struct PacketDataV2Json {
+    tokens: Vec<TokenJson>,
+    sender: String,
+    receiver: String,
+    #[serde(default)]
+    memo: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    forwarding: Option<ForwardingJson>,
+}
+
+#[derive(serde::Serialize, serde::Deserialize)]
+struct TokenJson {
+    denom: DenomJson,
+    amount: String,
+}
+
+#[derive(serde::Serialize, serde::Deserialize)]
+struct DenomJson {
+    base: String,
+    #[serde(default)]
+    trace: Vec<HopJson>,
+}
+
+#[derive(serde::Serialize, serde::Deserialize)]
+struct HopJson {
+    port_id: String,
+    channel_id: String,
+}
+
+#[derive(serde::Serialize, serde::Deserialize)]
+struct ForwardingJson {
+    #[serde(default)]
+    hops: Vec<HopJson>,
+    #[serde(default)]
+    destination_memo: String,
+}
+
+impl PacketDataV2Json {
+    fn try_into_domain(self) -> Result<FungibleTokenPacketDataV2, TokenTransferError> {
+        let tokens = self
+            .tokens
+            .into_iter()
+            .map(|token| {
+                Ok(PrefixedCoin {
+                    denom: PrefixedDenom {
+                        trace_path: TracePath::from_hops(hops_from_json(token.denom.trace)?),
+                        base_denom: BaseDenom::from_str(&token.denom.base)?,
+                    },
+                    amount: Amount::from_str(&token.amount)?,
+                })
+            })
+            .collect::<Result<_, TokenTransferError>>()?;
+
+        let forwarding = self
+            .forwarding
+            .map(|forwarding| {
+                Ok::<_, TokenTransferError>(Forwarding {
+                    hops: hops_from_json(forwarding.hops)?,
+                    destination_memo: forwarding.destination_memo.into(),
+                })
+            })
+            .transpose()?;
+
+        let pkt_data = FungibleTokenPacketDataV2 {
+            tokens,
+            sender: self.sender.into(),
+            receiver: self.receiver.into(),
+            memo: self.memo.into(),
+            forwarding,
+        };
+        pkt_data.validate_basic()?;
+
+        Ok(pkt_data)
+    }
+}
+
+impl From<FungibleTokenPacketDataV2> for PacketDataV2Json {
+    fn from(pkt_data: FungibleTokenPacketDataV2) -> Self {
+        Self {
+            tokens: pkt_data
+                .tokens
+                .into_iter()
+                .map(|token| TokenJson {
+                    denom: DenomJson {
+                        base: token.denom.base_denom.to_string(),
+                        trace: token.denom.trace_path.hops().map(HopJson::from).collect(),
+                    },
+                    amount: token.amount.to_string(),
+                })
+                .collect(),
+            sender: pkt_data.sender.to_string(),
+            receiver: pkt_data.receiver.to_string(),
+            memo: pkt_data.memo.to_string(),
+            forwarding: pkt_data.forwarding.map(|forwarding| ForwardingJson {
+                hops: forwarding.hops.iter().map(HopJson::from).collect(),
+                destination_memo: forwarding.destination_memo.to_string(),
+            }),
+        }
+    }
+}
+
+impl From<&TracePrefix> for HopJson {
+    fn from(hop: &TracePrefix) -> Self {
+        Self {
+            port_id: hop.port_id().to_string(),
+            channel_id: hop.channel_id().to_string(),
+        }
+    }
+}
+
+fn hops_from_json(hops: Vec<HopJson>) -> Result<Vec<TracePrefix>, TokenTransferError> {
+    hops.into_iter()
+        .enumerate()
+        .map(|(pos, hop)| {
+            let port_id = PortId::from_str(&hop.port_id).map_err(|e| {
+                TokenTransferError::InvalidTracePortId {
+                    pos: pos as u64,
+                    validation_error: e,
+                }
+            })?;
+            let channel_id = ChannelId::from_str(&hop.channel_id).map_err(|e| {
+                TokenTransferError::InvalidTraceChannelId {
+                    pos: pos as u64,
+                    validation_error: e,
+                }
+            })?;
+            Ok(TracePrefix::new(port_id, channel_id))
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use primitive_types::U256;
@@ -119,4 +421,92 @@ mod tests {
         PacketData::new_dummy().deser_json_assert_eq(dummy_json_packet_data());
         PacketData::new_dummy().deser_json_assert_eq(dummy_json_packet_data_without_memo());
     }
+
+    fn dummy_packet_data_v2() -> FungibleTokenPacketDataV2 {
+        let address: Signer = "cosmos1wxeyh7zgn4tctjzs0vtqpc6p5cxq5t2muzl7ng"
+            .to_string()
+            .into();
+
+        FungibleTokenPacketDataV2 {
+            tokens: vec![
+                PrefixedCoin {
+                    denom: "transfer/channel-1/transfer/channel-0/uatom"
+                        .parse()
+                        .unwrap(),
+                    amount: U256::from(10).into(),
+                },
+                PrefixedCoin {
+                    denom: "uosmo".parse().unwrap(),
+                    amount: U256::from(20).into(),
+                },
+            ],
+            sender: address.clone(),
+            receiver: address,
+            memo: "".to_string().into(),
+            forwarding: Some(Forwarding {
+                hops: vec![TracePrefix::new(
+                    "transfer".parse().unwrap(),
+                    "channel-2".parse().unwrap(),
+                )],
+                destination_memo: "memo".to_string().into(),
+            }),
+        }
+    }
+
+    fn dummy_json_packet_data_v2() -> &'static str {
+        r#"{"tokens":[{"denom":{"base":"uatom","trace":[{"port_id":"transfer","channel_id":"channel-1"},{"port_id":"transfer","channel_id":"channel-0"}]},"amount":"10"},{"denom":{"base":"uosmo","trace":[]},"amount":"20"}],"sender":"cosmos1wxeyh7zgn4tctjzs0vtqpc6p5cxq5t2muzl7ng","receiver":"cosmos1wxeyh7zgn4tctjzs0vtqpc6p5cxq5t2muzl7ng","memo":"","forwarding":{"hops":[{"port_id":"transfer","channel_id":"channel-2"}],"destination_memo":"memo"}}"#
+    }
+
+    #[test]
+    fn test_packet_data_v2_serde() {
+        let pkt_data = dummy_packet_data_v2();
+
+        let ser = serde_json::to_string(&pkt_data).unwrap();
+        assert_eq!(ser, dummy_json_packet_data_v2());
+
+        let deser: FungibleTokenPacketDataV2 = serde_json::from_str(&ser).unwrap();
+        assert_eq!(deser, pkt_data);
+    }
+
+    #[test]
+    fn test_packet_data_v2_validate_basic() {
+        let mut pkt_data = dummy_packet_data_v2();
+        assert!(pkt_data.validate_basic().is_ok());
+
+        pkt_data.memo = "memo".to_string().into();
+        assert!(pkt_data.validate_basic().is_err(), "memo with forwarding");
+
+        let mut pkt_data = dummy_packet_data_v2();
+        pkt_data.tokens.push(pkt_data.tokens[1].clone());
+        assert!(pkt_data.validate_basic().is_err(), "duplicate denom");
+
+        let mut pkt_data = dummy_packet_data_v2();
+        pkt_data.tokens.clear();
+        assert!(pkt_data.validate_basic().is_err(), "no tokens");
+
+        let mut pkt_data = dummy_packet_data_v2();
+        pkt_data.forwarding = Some(Forwarding {
+            hops: vec![],
+            destination_memo: "memo".to_string().into(),
+        });
+        assert!(
+            pkt_data.validate_basic().is_err(),
+            "destination memo without hops"
+        );
+
+        let empty_tokens = r#"{"tokens":[],"sender":"","receiver":""}"#;
+        assert!(serde_json::from_str::<FungibleTokenPacketDataV2>(empty_tokens).is_err());
+    }
+
+    #[test]
+    fn test_packet_data_v1_v2_conversion() {
+        let pkt_data = PacketData::new_dummy();
+        let pkt_data_v2 = FungibleTokenPacketDataV2::from(pkt_data.clone());
+        assert_eq!(PacketData::try_from(pkt_data_v2).unwrap(), pkt_data);
+
+        assert!(matches!(
+            PacketData::try_from(dummy_packet_data_v2()),
+            Err(TokenTransferError::NotV1Compatible)
+        ));
+    }
 }
@@ -0,0 +1,64 @@
+//! Defines the context shared by the controller and host submodules, which
+//! both keep track of the interchain accounts registered over their channels.
+use ibc_app_interchain_accounts_types::error::InterchainAccountsError;
+use ibc_core::host::types::identifiers::{ChannelId, ConnectionId, PortId};
+use ibc_core::primitives::Signer;
+
+/// Methods required in interchain accounts validation, on both the controller
+/// and the host.
+///
+/// Interchain accounts are identified by the connection between the
+/// controller and the host, and by the controller port of their owner. The
+/// controller and the host each keep their own registry of interchain
+/// accounts, so a host that implements both submodules must use distinct
+/// contexts for them.
+pub trait InterchainAccountValidationContext {
+    /// Returns the connection the given channel is built upon.
+    fn channel_connection_id(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<ConnectionId, InterchainAccountsError>;
+
+    /// Returns the channel over which the interchain account identified by
+    /// the connection and the controller port is controlled, if any.
+    ///
+    /// Since interchain accounts channels are ordered, a channel closes on
+    /// packet timeout. The active channel then remains set until the
+    /// interchain account is reopened on a new channel.
+    fn get_active_channel_id(
+        &self,
+        connection_id: &ConnectionId,
+        port_id: &PortId,
+    ) -> Option<ChannelId>;
+
+    /// Returns the address of the interchain account identified by the
+    /// connection and the controller port, if it is registered.
+    fn get_interchain_account_address(
+        &self,
+        connection_id: &ConnectionId,
+        port_id: &PortId,
+    ) -> Option<Signer>;
+}
+
+/// Methods required in interchain accounts execution, on both the controller
+/// and the host.
+pub trait InterchainAccountExecutionContext: InterchainAccountValidationContext {
+    /// Sets the channel over which the interchain account identified by the
+    /// connection and the controller port is controlled.
+    fn store_active_channel_id(
+        &mut self,
+        connection_id: ConnectionId,
+        port_id: PortId,
+        channel_id: ChannelId,
+    ) -> Result<(), InterchainAccountsError>;
+
+    /// Registers the address of the interchain account identified by the
+    /// connection and the controller port.
+    fn store_interchain_account_address(
+        &mut self,
+        connection_id: ConnectionId,
+        port_id: PortId,
+        address: Signer,
+    ) -> Result<(), InterchainAccountsError>;
+}
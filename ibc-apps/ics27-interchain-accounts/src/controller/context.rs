@@ -0,0 +1,16 @@
+//! Defines the required context for the controller submodule.
+use crate::context::{InterchainAccountExecutionContext, InterchainAccountValidationContext};
+
+/// Methods required in interchain accounts controller validation, to be
+/// implemented by the host.
+pub trait ControllerValidationContext: InterchainAccountValidationContext {
+    /// Returns whether the controller submodule is enabled.
+    fn is_controller_enabled(&self) -> bool;
+}
+
+/// Methods required in interchain accounts controller execution, to be
+/// implemented by the host.
+pub trait ControllerExecutionContext:
+    ControllerValidationContext + InterchainAccountExecutionContext
+{
+}
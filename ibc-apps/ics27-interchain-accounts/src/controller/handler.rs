@@ -0,0 +1,251 @@
+//! Implements the handlers of the messages of the controller submodule.
+use ibc_app_interchain_accounts_types::error::InterchainAccountsError;
+use ibc_app_interchain_accounts_types::msgs::{MsgRegisterInterchainAccount, MsgSendTx};
+use ibc_app_interchain_accounts_types::{controller_port_id, host_port_id, Metadata};
+use ibc_core::channel::handler::{
+    chan_open_init_execute, chan_open_init_validate, send_packet_execute, send_packet_validate,
+};
+use ibc_core::channel::types::msgs::MsgChannelOpenInit;
+use ibc_core::channel::types::packet::Packet;
+use ibc_core::channel::types::timeout::TimeoutHeight;
+use ibc_core::host::types::identifiers::{ChannelId, Sequence};
+use ibc_core::host::types::path::{ChannelEndPath, SeqSendPath};
+use ibc_core::host::{ExecutionContext, ValidationContext};
+use ibc_core::primitives::prelude::*;
+use ibc_core::router::module::Module;
+
+use super::context::{ControllerExecutionContext, ControllerValidationContext};
+use super::module::ControllerModule;
+
+/// Registers an interchain account on the host chain, by opening a new
+/// channel from the controller port of its owner. Equivalent to calling
+/// [`register_interchain_account_validate`], followed by
+/// [`register_interchain_account_execute`].
+///
+/// Returns the identifier of the channel, whose handshake relayers complete
+/// with the host chain.
+pub fn register_interchain_account<Ctx, C>(
+    ctx_a: &mut Ctx,
+    module: &mut ControllerModule<C>,
+    msg: MsgRegisterInterchainAccount,
+) -> Result<ChannelId, InterchainAccountsError>
+where
+    Ctx: ExecutionContext,
+    C: ControllerExecutionContext + core::fmt::Debug,
+{
+    register_interchain_account_validate(ctx_a, module.ctx(), module, msg.clone())?;
+    register_interchain_account_execute(ctx_a, module, msg)
+}
+
+/// Validates the registration of an interchain account.
+///
+/// `module` is the module bound to the controller port of the owner, that is
+/// the [`ControllerModule`] or a stack of middleware on top of it.
+pub fn register_interchain_account_validate<Ctx, IcaCtx>(
+    ctx_a: &Ctx,
+    ica_ctx_a: &IcaCtx,
+    module: &dyn Module,
+    msg: MsgRegisterInterchainAccount,
+) -> Result<(), InterchainAccountsError>
+where
+    Ctx: ValidationContext,
+    IcaCtx: ControllerValidationContext,
+{
+    if !ica_ctx_a.is_controller_enabled() {
+        return Err(InterchainAccountsError::ControllerDisabled);
+    }
+
+    let msg_chan_open_init = chan_open_init_msg(ctx_a, msg)?;
+    let port_id_on_a = &msg_chan_open_init.port_id_on_a;
+    let conn_id_on_a = &msg_chan_open_init.connection_hops_on_a[0];
+
+    // An interchain account is only reopened on a new channel once its
+    // active channel is closed, and with the same parameters.
+    if let Some(chan_id_on_a) = ica_ctx_a.get_active_channel_id(conn_id_on_a, port_id_on_a) {
+        let chan_end_on_a = ctx_a.channel_end(&ChannelEndPath::new(port_id_on_a, &chan_id_on_a))?;
+
+        if chan_end_on_a.is_open() {
+            return Err(InterchainAccountsError::ActiveChannelAlreadySet {
+                connection_id: conn_id_on_a.clone(),
+                port_id: port_id_on_a.clone(),
+                channel_id: chan_id_on_a,
+            });
+        }
+
+        let previous_metadata = Metadata::try_from(chan_end_on_a.version())?;
+        let metadata = Metadata::try_from(&msg_chan_open_init.version_proposal)?;
+
+        if !metadata.is_previous_metadata_equal(&previous_metadata) {
+            return Err(InterchainAccountsError::MetadataMismatch);
+        }
+    }
+
+    chan_open_init_validate(ctx_a, module, msg_chan_open_init)?;
+
+    Ok(())
+}
+
+/// Executes the registration of an interchain account.
+pub fn register_interchain_account_execute<Ctx>(
+    ctx_a: &mut Ctx,
+    module: &mut dyn Module,
+    msg: MsgRegisterInterchainAccount,
+) -> Result<ChannelId, InterchainAccountsError>
+where
+    Ctx: ExecutionContext,
+{
+    let msg_chan_open_init = chan_open_init_msg(ctx_a, msg)?;
+    let chan_id_on_a = ChannelId::new(ctx_a.channel_counter()?);
+
+    chan_open_init_execute(ctx_a, module, msg_chan_open_init)?;
+
+    Ok(chan_id_on_a)
+}
+
+/// Builds the `ChanOpenInit` message of the registration. An empty version
+/// defaults to the metadata of the current interchain accounts version.
+fn chan_open_init_msg<Ctx>(
+    ctx_a: &Ctx,
+    msg: MsgRegisterInterchainAccount,
+) -> Result<MsgChannelOpenInit, InterchainAccountsError>
+where
+    Ctx: ValidationContext,
+{
+    let port_id_on_a = controller_port_id(&msg.owner)?;
+
+    let version_proposal = if msg.version.is_empty() {
+        let conn_end_on_a = ctx_a.connection_end(&msg.conn_id_on_a)?;
+        let conn_id_on_b = conn_end_on_a
+            .counterparty()
+            .connection_id()
+            .ok_or_else(|| InterchainAccountsError::MissingCounterpartyConnection {
+                connection_id: msg.conn_id_on_a.clone(),
+            })?;
+
+        Metadata::new(msg.conn_id_on_a.clone(), conn_id_on_b.clone()).into()
+    } else {
+        msg.version
+    };
+
+    Ok(MsgChannelOpenInit {
+        port_id_on_a,
+        connection_hops_on_a: vec![msg.conn_id_on_a],
+        port_id_on_b: host_port_id(),
+        ordering: msg.ordering,
+        signer: msg.owner,
+        version_proposal,
+    })
+}
+
+/// Sends a transaction to be executed by the interchain account of the
+/// owner. Equivalent to calling [`send_tx_validate`], followed by
+/// [`send_tx_execute`].
+///
+/// Returns the sequence of the packet carrying the transaction.
+pub fn send_tx<Ctx, IcaCtx>(
+    ctx_a: &mut Ctx,
+    ica_ctx_a: &IcaCtx,
+    msg: MsgSendTx,
+) -> Result<Sequence, InterchainAccountsError>
+where
+    Ctx: ExecutionContext,
+    IcaCtx: ControllerValidationContext,
+{
+    send_tx_validate(ctx_a, ica_ctx_a, msg.clone())?;
+    send_tx_execute(ctx_a, ica_ctx_a, msg)
+}
+
+/// Validates the sending of a transaction to the interchain account.
+pub fn send_tx_validate<Ctx, IcaCtx>(
+    ctx_a: &Ctx,
+    ica_ctx_a: &IcaCtx,
+    msg: MsgSendTx,
+) -> Result<(), InterchainAccountsError>
+where
+    Ctx: ValidationContext,
+    IcaCtx: ControllerValidationContext,
+{
+    let packet = tx_packet(ctx_a, ica_ctx_a, msg)?;
+
+    send_packet_validate(ctx_a, &packet)?;
+
+    Ok(())
+}
+
+/// Executes the sending of a transaction to the interchain account.
+pub fn send_tx_execute<Ctx, IcaCtx>(
+    ctx_a: &mut Ctx,
+    ica_ctx_a: &IcaCtx,
+    msg: MsgSendTx,
+) -> Result<Sequence, InterchainAccountsError>
+where
+    Ctx: ExecutionContext,
+    IcaCtx: ControllerValidationContext,
+{
+    let packet = tx_packet(ctx_a, ica_ctx_a, msg)?;
+    let sequence = packet.seq_on_a;
+
+    send_packet_execute(ctx_a, packet)?;
+
+    Ok(sequence)
+}
+
+/// Builds the packet carrying the transaction over the active channel of the
+/// interchain account.
+fn tx_packet<Ctx, IcaCtx>(
+    ctx_a: &Ctx,
+    ica_ctx_a: &IcaCtx,
+    msg: MsgSendTx,
+) -> Result<Packet, InterchainAccountsError>
+where
+    Ctx: ValidationContext,
+    IcaCtx: ControllerValidationContext,
+{
+    if !ica_ctx_a.is_controller_enabled() {
+        return Err(InterchainAccountsError::ControllerDisabled);
+    }
+
+    let port_id_on_a = controller_port_id(&msg.owner)?;
+    let chan_id_on_a = ica_ctx_a
+        .get_active_channel_id(&msg.conn_id_on_a, &port_id_on_a)
+        .ok_or_else(|| InterchainAccountsError::ActiveChannelNotFound {
+            connection_id: msg.conn_id_on_a.clone(),
+            port_id: port_id_on_a.clone(),
+        })?;
+
+    let chan_end_on_a = ctx_a.channel_end(&ChannelEndPath::new(&port_id_on_a, &chan_id_on_a))?;
+
+    if !chan_end_on_a.is_open() {
+        return Err(InterchainAccountsError::ActiveChannelNotOpen {
+            connection_id: msg.conn_id_on_a,
+            port_id: port_id_on_a,
+            channel_id: chan_id_on_a,
+        });
+    }
+
+    let port_id_on_b = chan_end_on_a.counterparty().port_id().clone();
+    let chan_id_on_b = chan_end_on_a
+        .counterparty()
+        .channel_id()
+        .ok_or_else(|| InterchainAccountsError::MissingCounterpartyChannel {
+            port_id: port_id_on_a.clone(),
+            channel_id: chan_id_on_a.clone(),
+        })?
+        .clone();
+
+    let seq_on_a = ctx_a.get_next_sequence_send(&SeqSendPath::new(&port_id_on_a, &chan_id_on_a))?;
+
+    let timeout_timestamp_on_b = (ctx_a.host_timestamp()? + msg.relative_timeout)
+        .map_err(|_| InterchainAccountsError::TimeoutOverflow)?;
+
+    Ok(Packet {
+        seq_on_a,
+        port_id_on_a,
+        chan_id_on_a,
+        port_id_on_b,
+        chan_id_on_b,
+        data: msg.packet_data.to_packet_bytes(),
+        timeout_height_on_b: TimeoutHeight::no_timeout(),
+        timeout_timestamp_on_b,
+    })
+}
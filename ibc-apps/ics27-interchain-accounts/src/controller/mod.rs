@@ -0,0 +1,5 @@
+//! Implementation of the controller submodule of interchain accounts, which
+//! registers interchain accounts on host chains and sends them transactions.
+pub mod context;
+pub mod handler;
+pub mod module;
@@ -0,0 +1,345 @@
+//! Defines the callbacks of the controller submodule.
+use core::fmt::Debug;
+
+use ibc_app_interchain_accounts_types::error::InterchainAccountsError;
+use ibc_app_interchain_accounts_types::{
+    error_acknowledgement, verify_controller_port, verify_host_port, Metadata,
+};
+use ibc_core::channel::types::acknowledgement::Acknowledgement;
+use ibc_core::channel::types::channel::{Counterparty, Order};
+use ibc_core::channel::types::error::{ChannelError, PacketError};
+use ibc_core::channel::types::packet::Packet;
+use ibc_core::channel::types::Version;
+use ibc_core::host::types::identifiers::{ChannelId, ConnectionId, PortId};
+use ibc_core::primitives::prelude::*;
+use ibc_core::primitives::Signer;
+use ibc_core::router::module::Module;
+use ibc_core::router::types::module::ModuleExtras;
+
+use super::context::{ControllerExecutionContext, ControllerValidationContext};
+
+const SUBMODULE: &str = "controller";
+
+pub fn on_chan_open_init_validate(
+    ctx: &impl ControllerValidationContext,
+    order: Order,
+    connection_hops: &[ConnectionId],
+    port_id: &PortId,
+    _channel_id: &ChannelId,
+    counterparty: &Counterparty,
+    version: &Version,
+) -> Result<(), InterchainAccountsError> {
+    if !ctx.is_controller_enabled() {
+        return Err(InterchainAccountsError::ControllerDisabled);
+    }
+
+    if order != Order::Ordered {
+        return Err(InterchainAccountsError::InvalidChannelOrder {
+            expected: Order::Ordered,
+            actual: order,
+        });
+    }
+
+    verify_controller_port(port_id)?;
+    verify_host_port(counterparty.port_id())?;
+
+    let metadata = Metadata::try_from(version)?;
+    match connection_hops.first() {
+        Some(connection_id) => metadata.validate_controller(connection_id),
+        None => Err(ChannelError::InvalidConnectionHopsLength {
+            expected: 1,
+            actual: 0,
+        }
+        .into()),
+    }
+}
+
+pub fn on_chan_open_init_execute(
+    _ctx: &mut impl ControllerExecutionContext,
+    _order: Order,
+    _connection_hops: &[ConnectionId],
+    _port_id: &PortId,
+    _channel_id: &ChannelId,
+    _counterparty: &Counterparty,
+    version: &Version,
+) -> Result<(ModuleExtras, Version), InterchainAccountsError> {
+    Ok((ModuleExtras::empty(), version.clone()))
+}
+
+/// Returns the metadata of the host, which carries the address of the
+/// interchain account.
+fn counterparty_metadata(
+    ctx: &impl ControllerValidationContext,
+    port_id: &PortId,
+    channel_id: &ChannelId,
+    counterparty_version: &Version,
+) -> Result<(ConnectionId, Metadata), InterchainAccountsError> {
+    verify_controller_port(port_id)?;
+
+    let connection_id = ctx.channel_connection_id(port_id, channel_id)?;
+    let metadata = Metadata::try_from(counterparty_version)?;
+    metadata.validate_controller(&connection_id)?;
+
+    if metadata.address.trim().is_empty() {
+        return Err(InterchainAccountsError::EmptyAccountAddress);
+    }
+
+    Ok((connection_id, metadata))
+}
+
+pub fn on_chan_open_ack_validate(
+    ctx: &impl ControllerValidationContext,
+    port_id: &PortId,
+    channel_id: &ChannelId,
+    counterparty_version: &Version,
+) -> Result<(), InterchainAccountsError> {
+    counterparty_metadata(ctx, port_id, channel_id, counterparty_version).map(|_| ())
+}
+
+/// Sets the channel as the active channel of the interchain account, and
+/// registers the address chosen by the host.
+pub fn on_chan_open_ack_execute(
+    ctx: &mut impl ControllerExecutionContext,
+    port_id: &PortId,
+    channel_id: &ChannelId,
+    counterparty_version: &Version,
+) -> Result<ModuleExtras, InterchainAccountsError> {
+    let (connection_id, metadata) =
+        counterparty_metadata(ctx, port_id, channel_id, counterparty_version)?;
+
+    ctx.store_active_channel_id(connection_id.clone(), port_id.clone(), channel_id.clone())?;
+    ctx.store_interchain_account_address(connection_id, port_id.clone(), metadata.address.into())?;
+
+    Ok(ModuleExtras::empty())
+}
+
+/// Returns the acknowledgement written when a packet is received on the
+/// controller, which never happens with a well-behaved host.
+pub fn on_recv_packet_execute(
+    _ctx: &mut impl ControllerExecutionContext,
+    _packet: &Packet,
+) -> (ModuleExtras, Acknowledgement) {
+    (
+        ModuleExtras::empty(),
+        error_acknowledgement(&InterchainAccountsError::ControllerReceivedPacket).into(),
+    )
+}
+
+/// The controller submodule of interchain accounts, as an IBC [`Module`].
+///
+/// `ctx` is the host's implementation of the [`ControllerExecutionContext`].
+/// The module must be bound to the controller port of every interchain
+/// account owner, see
+/// [`controller_port_id`](ibc_app_interchain_accounts_types::controller_port_id).
+///
+/// Acknowledgements and timeouts are not processed by the controller: an
+/// application interested in the outcome of the transactions it sends can
+/// wrap the controller as a
+/// [`Middleware`](ibc_core::router::middleware::Middleware).
+#[derive(Debug)]
+pub struct ControllerModule<C> {
+    ctx: C,
+}
+
+impl<C> ControllerModule<C> {
+    pub fn new(ctx: C) -> Self {
+        Self { ctx }
+    }
+
+    pub fn ctx(&self) -> &C {
+        &self.ctx
+    }
+
+    pub fn ctx_mut(&mut self) -> &mut C {
+        &mut self.ctx
+    }
+
+    pub fn into_inner(self) -> C {
+        self.ctx
+    }
+}
+
+impl<C> Module for ControllerModule<C>
+where
+    C: ControllerExecutionContext + Debug,
+{
+    fn on_chan_open_init_validate(
+        &self,
+        order: Order,
+        connection_hops: &[ConnectionId],
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        counterparty: &Counterparty,
+        version: &Version,
+    ) -> Result<Version, ChannelError> {
+        on_chan_open_init_validate(
+            &self.ctx,
+            order,
+            connection_hops,
+            port_id,
+            channel_id,
+            counterparty,
+            version,
+        )?;
+        Ok(version.clone())
+    }
+
+    fn on_chan_open_init_execute(
+        &mut self,
+        order: Order,
+        connection_hops: &[ConnectionId],
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        counterparty: &Counterparty,
+        version: &Version,
+    ) -> Result<(ModuleExtras, Version), ChannelError> {
+        Ok(on_chan_open_init_execute(
+            &mut self.ctx,
+            order,
+            connection_hops,
+            port_id,
+            channel_id,
+            counterparty,
+            version,
+        )?)
+    }
+
+    fn on_chan_open_try_validate(
+        &self,
+        _order: Order,
+        _connection_hops: &[ConnectionId],
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+        _counterparty: &Counterparty,
+        _counterparty_version: &Version,
+    ) -> Result<Version, ChannelError> {
+        Err(InterchainAccountsError::InvalidHandshakeStep {
+            submodule: SUBMODULE,
+        }
+        .into())
+    }
+
+    fn on_chan_open_try_execute(
+        &mut self,
+        _order: Order,
+        _connection_hops: &[ConnectionId],
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+        _counterparty: &Counterparty,
+        _counterparty_version: &Version,
+    ) -> Result<(ModuleExtras, Version), ChannelError> {
+        Err(InterchainAccountsError::InvalidHandshakeStep {
+            submodule: SUBMODULE,
+        }
+        .into())
+    }
+
+    fn on_chan_open_ack_validate(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        counterparty_version: &Version,
+    ) -> Result<(), ChannelError> {
+        Ok(on_chan_open_ack_validate(
+            &self.ctx,
+            port_id,
+            channel_id,
+            counterparty_version,
+        )?)
+    }
+
+    fn on_chan_open_ack_execute(
+        &mut self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        counterparty_version: &Version,
+    ) -> Result<ModuleExtras, ChannelError> {
+        Ok(on_chan_open_ack_execute(
+            &mut self.ctx,
+            port_id,
+            channel_id,
+            counterparty_version,
+        )?)
+    }
+
+    fn on_chan_open_confirm_validate(
+        &self,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+    ) -> Result<(), ChannelError> {
+        Err(InterchainAccountsError::InvalidHandshakeStep {
+            submodule: SUBMODULE,
+        }
+        .into())
+    }
+
+    fn on_chan_open_confirm_execute(
+        &mut self,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+    ) -> Result<ModuleExtras, ChannelError> {
+        Err(InterchainAccountsError::InvalidHandshakeStep {
+            submodule: SUBMODULE,
+        }
+        .into())
+    }
+
+    fn on_chan_close_init_validate(
+        &self,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+    ) -> Result<(), ChannelError> {
+        Err(InterchainAccountsError::ChannelClosingNotAllowed.into())
+    }
+
+    fn on_chan_close_init_execute(
+        &mut self,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+    ) -> Result<ModuleExtras, ChannelError> {
+        Err(InterchainAccountsError::ChannelClosingNotAllowed.into())
+    }
+
+    fn on_recv_packet_execute(
+        &mut self,
+        packet: &Packet,
+        _relayer: &Signer,
+    ) -> (ModuleExtras, Option<Acknowledgement>) {
+        let (extras, ack) = on_recv_packet_execute(&mut self.ctx, packet);
+        (extras, Some(ack))
+    }
+
+    fn on_acknowledgement_packet_validate(
+        &self,
+        _packet: &Packet,
+        _acknowledgement: &Acknowledgement,
+        _relayer: &Signer,
+    ) -> Result<(), PacketError> {
+        Ok(())
+    }
+
+    fn on_acknowledgement_packet_execute(
+        &mut self,
+        _packet: &Packet,
+        _acknowledgement: &Acknowledgement,
+        _relayer: &Signer,
+    ) -> (ModuleExtras, Result<(), PacketError>) {
+        (ModuleExtras::empty(), Ok(()))
+    }
+
+    fn on_timeout_packet_validate(
+        &self,
+        _packet: &Packet,
+        _relayer: &Signer,
+    ) -> Result<(), PacketError> {
+        Ok(())
+    }
+
+    fn on_timeout_packet_execute(
+        &mut self,
+        _packet: &Packet,
+        _relayer: &Signer,
+    ) -> (ModuleExtras, Result<(), PacketError>) {
+        (ModuleExtras::empty(), Ok(()))
+    }
+}
@@ -0,0 +1,67 @@
+//! Defines the required context for the host submodule.
+use ibc_app_interchain_accounts_types::error::InterchainAccountsError;
+use ibc_core::host::types::identifiers::{ConnectionId, PortId};
+use ibc_core::primitives::prelude::*;
+use ibc_core::primitives::proto::Any;
+use ibc_core::primitives::Signer;
+
+use crate::context::{InterchainAccountExecutionContext, InterchainAccountValidationContext};
+
+/// The entry of the allow list that allows all messages.
+pub const ALLOW_ALL_MESSAGES: &str = "*";
+
+/// Methods required in interchain accounts host validation, to be implemented
+/// by the host.
+pub trait HostValidationContext: InterchainAccountValidationContext {
+    /// Returns whether the host submodule is enabled.
+    fn is_host_enabled(&self) -> bool;
+
+    /// Returns the type URLs of the messages interchain accounts are allowed
+    /// to execute. The [`ALLOW_ALL_MESSAGES`] entry allows any message.
+    fn allow_messages(&self) -> Vec<String>;
+
+    /// Returns the address of a new interchain account identified by the
+    /// connection and the controller port.
+    ///
+    /// The address must be derived deterministically, so that every node of
+    /// the host chain derives the same address, and must not collide with the
+    /// address of any existing account.
+    fn generate_account_address(
+        &self,
+        connection_id: &ConnectionId,
+        port_id: &PortId,
+    ) -> Result<Signer, InterchainAccountsError>;
+
+    /// Validates a message before its execution on behalf of the interchain
+    /// account. In particular, the host must check that the interchain
+    /// account is the only signer of the message.
+    fn validate_message(&self, account: &Signer, msg: &Any) -> Result<(), InterchainAccountsError>;
+
+    /// Returns whether the message of the given type is allowed by
+    /// [`allow_messages`](Self::allow_messages).
+    fn is_message_allowed(&self, type_url: &str) -> bool {
+        self.allow_messages()
+            .iter()
+            .any(|allowed| allowed == ALLOW_ALL_MESSAGES || allowed == type_url)
+    }
+}
+
+/// Methods required in interchain accounts host execution, to be implemented
+/// by the host.
+pub trait HostExecutionContext: HostValidationContext + InterchainAccountExecutionContext {
+    /// Creates the interchain account with the given address in the account
+    /// store of the host.
+    fn create_account(&mut self, address: &Signer) -> Result<(), InterchainAccountsError>;
+
+    /// Executes a message on behalf of the interchain account, and returns
+    /// the response of the message.
+    ///
+    /// The messages of a transaction are executed atomically: if one of them
+    /// fails, the host must discard the state changes of the messages of the
+    /// transaction executed before it.
+    fn execute_message(
+        &mut self,
+        account: &Signer,
+        msg: &Any,
+    ) -> Result<Any, InterchainAccountsError>;
+}
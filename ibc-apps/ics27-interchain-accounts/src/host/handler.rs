@@ -0,0 +1,57 @@
+//! Implements the execution of the transactions received by the host.
+use ibc_app_interchain_accounts_types::error::InterchainAccountsError;
+use ibc_app_interchain_accounts_types::{InterchainAccountPacketData, TxMsgData};
+use ibc_core::channel::types::packet::Packet;
+use ibc_core::primitives::prelude::*;
+
+use super::context::HostExecutionContext;
+
+/// Executes the transaction carried by a packet received from the controller
+/// on behalf of the interchain account of the channel, and returns the
+/// responses of its messages.
+///
+/// All the messages of the transaction are checked against the allow list and
+/// validated before any of them is executed.
+pub fn execute_tx<Ctx>(
+    ctx_b: &mut Ctx,
+    packet: &Packet,
+) -> Result<TxMsgData, InterchainAccountsError>
+where
+    Ctx: HostExecutionContext,
+{
+    if !ctx_b.is_host_enabled() {
+        return Err(InterchainAccountsError::HostDisabled);
+    }
+
+    let data = InterchainAccountPacketData::try_from(packet.data.as_slice())?;
+    data.validate_basic()?;
+
+    let messages = data.cosmos_tx()?.messages;
+    if messages.is_empty() {
+        return Err(InterchainAccountsError::EmptyTx);
+    }
+
+    let conn_id_on_b = ctx_b.channel_connection_id(&packet.port_id_on_b, &packet.chan_id_on_b)?;
+    let account = ctx_b
+        .get_interchain_account_address(&conn_id_on_b, &packet.port_id_on_a)
+        .ok_or_else(|| InterchainAccountsError::AccountNotFound {
+            connection_id: conn_id_on_b.clone(),
+            port_id: packet.port_id_on_a.clone(),
+        })?;
+
+    for msg in &messages {
+        if !ctx_b.is_message_allowed(&msg.type_url) {
+            return Err(InterchainAccountsError::MessageNotAllowed {
+                type_url: msg.type_url.clone(),
+            });
+        }
+        ctx_b.validate_message(&account, msg)?;
+    }
+
+    let msg_responses = messages
+        .iter()
+        .map(|msg| ctx_b.execute_message(&account, msg))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(TxMsgData::new(msg_responses))
+}
@@ -0,0 +1,6 @@
+//! Implementation of the host submodule of interchain accounts, which
+//! creates interchain accounts and executes the transactions sent by their
+//! controllers.
+pub mod context;
+pub mod handler;
+pub mod module;
@@ -0,0 +1,370 @@
+//! Defines the callbacks of the host submodule.
+use core::fmt::Debug;
+
+use ibc_app_interchain_accounts_types::error::InterchainAccountsError;
+use ibc_app_interchain_accounts_types::events::HostPacketEvent;
+use ibc_app_interchain_accounts_types::{
+    error_acknowledgement, verify_controller_port, verify_host_port, Metadata,
+};
+use ibc_core::channel::types::acknowledgement::Acknowledgement;
+use ibc_core::channel::types::channel::{Counterparty, Order};
+use ibc_core::channel::types::error::{ChannelError, PacketError};
+use ibc_core::channel::types::packet::Packet;
+use ibc_core::channel::types::Version;
+use ibc_core::host::types::identifiers::{ChannelId, ConnectionId, PortId};
+use ibc_core::primitives::prelude::*;
+use ibc_core::primitives::Signer;
+use ibc_core::router::module::Module;
+use ibc_core::router::types::module::ModuleExtras;
+
+use super::context::{HostExecutionContext, HostValidationContext};
+use super::handler::execute_tx;
+
+const SUBMODULE: &str = "host";
+
+/// Validates the metadata proposed by the controller, and returns it with the
+/// address of the interchain account filled in, along with whether the
+/// account must be created.
+fn negotiate_metadata(
+    ctx: &impl HostValidationContext,
+    order: Order,
+    connection_hops: &[ConnectionId],
+    port_id: &PortId,
+    counterparty: &Counterparty,
+    counterparty_version: &Version,
+) -> Result<(Metadata, bool), InterchainAccountsError> {
+    if !ctx.is_host_enabled() {
+        return Err(InterchainAccountsError::HostDisabled);
+    }
+
+    if order != Order::Ordered {
+        return Err(InterchainAccountsError::InvalidChannelOrder {
+            expected: Order::Ordered,
+            actual: order,
+        });
+    }
+
+    verify_host_port(port_id)?;
+    verify_controller_port(counterparty.port_id())?;
+
+    let connection_id = match connection_hops.first() {
+        Some(connection_id) => connection_id,
+        None => {
+            return Err(ChannelError::InvalidConnectionHopsLength {
+                expected: 1,
+                actual: 0,
+            }
+            .into())
+        }
+    };
+
+    let mut metadata = Metadata::try_from(counterparty_version)?;
+    metadata.validate_host(connection_id)?;
+
+    let (address, is_new_account) =
+        match ctx.get_interchain_account_address(connection_id, counterparty.port_id()) {
+            Some(address) => (address, false),
+            None => (
+                ctx.generate_account_address(connection_id, counterparty.port_id())?,
+                true,
+            ),
+        };
+
+    if address.as_ref().trim().is_empty() {
+        return Err(InterchainAccountsError::EmptyAccountAddress);
+    }
+
+    metadata.address = address.to_string();
+
+    Ok((metadata, is_new_account))
+}
+
+pub fn on_chan_open_try_validate(
+    ctx: &impl HostValidationContext,
+    order: Order,
+    connection_hops: &[ConnectionId],
+    port_id: &PortId,
+    _channel_id: &ChannelId,
+    counterparty: &Counterparty,
+    counterparty_version: &Version,
+) -> Result<Version, InterchainAccountsError> {
+    let (metadata, _) = negotiate_metadata(
+        ctx,
+        order,
+        connection_hops,
+        port_id,
+        counterparty,
+        counterparty_version,
+    )?;
+
+    Ok(metadata.into())
+}
+
+/// Registers the interchain account, if it doesn't exist yet, and sets the
+/// channel as its active channel.
+pub fn on_chan_open_try_execute(
+    ctx: &mut impl HostExecutionContext,
+    order: Order,
+    connection_hops: &[ConnectionId],
+    port_id: &PortId,
+    channel_id: &ChannelId,
+    counterparty: &Counterparty,
+    counterparty_version: &Version,
+) -> Result<(ModuleExtras, Version), InterchainAccountsError> {
+    let (metadata, is_new_account) = negotiate_metadata(
+        ctx,
+        order,
+        connection_hops,
+        port_id,
+        counterparty,
+        counterparty_version,
+    )?;
+
+    let connection_id = metadata.host_connection_id.clone();
+    let controller_port_id = counterparty.port_id().clone();
+
+    if is_new_account {
+        let address = Signer::from(metadata.address.clone());
+        ctx.create_account(&address)?;
+        ctx.store_interchain_account_address(
+            connection_id.clone(),
+            controller_port_id.clone(),
+            address,
+        )?;
+    }
+
+    ctx.store_active_channel_id(connection_id, controller_port_id, channel_id.clone())?;
+
+    Ok((ModuleExtras::empty(), metadata.into()))
+}
+
+/// Executes the transaction carried by the packet, and acknowledges it with
+/// the responses of its messages or with the reason of its failure.
+pub fn on_recv_packet_execute(
+    ctx: &mut impl HostExecutionContext,
+    packet: &Packet,
+) -> (ModuleExtras, Acknowledgement) {
+    let (ack, error) = match execute_tx(ctx, packet) {
+        Ok(tx_msg_data) => (tx_msg_data.into_acknowledgement(), None),
+        Err(e) => (error_acknowledgement(&e), Some(e.to_string())),
+    };
+
+    let packet_event = HostPacketEvent {
+        host_channel_id: packet.chan_id_on_b.clone(),
+        success: ack.is_successful(),
+        error,
+    };
+
+    let mut extras = ModuleExtras::empty();
+    extras.events.push(packet_event.into());
+
+    (extras, ack.into())
+}
+
+/// The host submodule of interchain accounts, as an IBC [`Module`].
+///
+/// `ctx` is the host's implementation of the [`HostExecutionContext`]. The
+/// module must be bound to the
+/// [`host_port_id`](ibc_app_interchain_accounts_types::host_port_id).
+#[derive(Debug)]
+pub struct HostModule<C> {
+    ctx: C,
+}
+
+impl<C> HostModule<C> {
+    pub fn new(ctx: C) -> Self {
+        Self { ctx }
+    }
+
+    pub fn ctx(&self) -> &C {
+        &self.ctx
+    }
+
+    pub fn ctx_mut(&mut self) -> &mut C {
+        &mut self.ctx
+    }
+
+    pub fn into_inner(self) -> C {
+        self.ctx
+    }
+}
+
+impl<C> Module for HostModule<C>
+where
+    C: HostExecutionContext + Debug,
+{
+    fn on_chan_open_init_validate(
+        &self,
+        _order: Order,
+        _connection_hops: &[ConnectionId],
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+        _counterparty: &Counterparty,
+        _version: &Version,
+    ) -> Result<Version, ChannelError> {
+        Err(InterchainAccountsError::InvalidHandshakeStep {
+            submodule: SUBMODULE,
+        }
+        .into())
+    }
+
+    fn on_chan_open_init_execute(
+        &mut self,
+        _order: Order,
+        _connection_hops: &[ConnectionId],
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+        _counterparty: &Counterparty,
+        _version: &Version,
+    ) -> Result<(ModuleExtras, Version), ChannelError> {
+        Err(InterchainAccountsError::InvalidHandshakeStep {
+            submodule: SUBMODULE,
+        }
+        .into())
+    }
+
+    fn on_chan_open_try_validate(
+        &self,
+        order: Order,
+        connection_hops: &[ConnectionId],
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        counterparty: &Counterparty,
+        counterparty_version: &Version,
+    ) -> Result<Version, ChannelError> {
+        Ok(on_chan_open_try_validate(
+            &self.ctx,
+            order,
+            connection_hops,
+            port_id,
+            channel_id,
+            counterparty,
+            counterparty_version,
+        )?)
+    }
+
+    fn on_chan_open_try_execute(
+        &mut self,
+        order: Order,
+        connection_hops: &[ConnectionId],
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        counterparty: &Counterparty,
+        counterparty_version: &Version,
+    ) -> Result<(ModuleExtras, Version), ChannelError> {
+        Ok(on_chan_open_try_execute(
+            &mut self.ctx,
+            order,
+            connection_hops,
+            port_id,
+            channel_id,
+            counterparty,
+            counterparty_version,
+        )?)
+    }
+
+    fn on_chan_open_ack_validate(
+        &self,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+        _counterparty_version: &Version,
+    ) -> Result<(), ChannelError> {
+        Err(InterchainAccountsError::InvalidHandshakeStep {
+            submodule: SUBMODULE,
+        }
+        .into())
+    }
+
+    fn on_chan_open_ack_execute(
+        &mut self,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+        _counterparty_version: &Version,
+    ) -> Result<ModuleExtras, ChannelError> {
+        Err(InterchainAccountsError::InvalidHandshakeStep {
+            submodule: SUBMODULE,
+        }
+        .into())
+    }
+
+    fn on_chan_open_confirm_validate(
+        &self,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+    ) -> Result<(), ChannelError> {
+        Ok(())
+    }
+
+    fn on_chan_open_confirm_execute(
+        &mut self,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+    ) -> Result<ModuleExtras, ChannelError> {
+        Ok(ModuleExtras::empty())
+    }
+
+    fn on_chan_close_init_validate(
+        &self,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+    ) -> Result<(), ChannelError> {
+        Err(InterchainAccountsError::ChannelClosingNotAllowed.into())
+    }
+
+    fn on_chan_close_init_execute(
+        &mut self,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+    ) -> Result<ModuleExtras, ChannelError> {
+        Err(InterchainAccountsError::ChannelClosingNotAllowed.into())
+    }
+
+    fn on_recv_packet_execute(
+        &mut self,
+        packet: &Packet,
+        _relayer: &Signer,
+    ) -> (ModuleExtras, Option<Acknowledgement>) {
+        let (extras, ack) = on_recv_packet_execute(&mut self.ctx, packet);
+        (extras, Some(ack))
+    }
+
+    fn on_acknowledgement_packet_validate(
+        &self,
+        _packet: &Packet,
+        _acknowledgement: &Acknowledgement,
+        _relayer: &Signer,
+    ) -> Result<(), PacketError> {
+        Err(InterchainAccountsError::HostDoesNotSendPackets.into())
+    }
+
+    fn on_acknowledgement_packet_execute(
+        &mut self,
+        _packet: &Packet,
+        _acknowledgement: &Acknowledgement,
+        _relayer: &Signer,
+    ) -> (ModuleExtras, Result<(), PacketError>) {
+        (
+            ModuleExtras::empty(),
+            Err(InterchainAccountsError::HostDoesNotSendPackets.into()),
+        )
+    }
+
+    fn on_timeout_packet_validate(
+        &self,
+        _packet: &Packet,
+        _relayer: &Signer,
+    ) -> Result<(), PacketError> {
+        Err(InterchainAccountsError::HostDoesNotSendPackets.into())
+    }
+
+    fn on_timeout_packet_execute(
+        &mut self,
+        _packet: &Packet,
+        _relayer: &Signer,
+    ) -> (ModuleExtras, Result<(), PacketError>) {
+        (
+            ModuleExtras::empty(),
+            Err(InterchainAccountsError::HostDoesNotSendPackets.into()),
+        )
+    }
+}
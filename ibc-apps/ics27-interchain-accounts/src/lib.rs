@@ -0,0 +1,37 @@
+//! Implementation of the IBC [Interchain Accounts](https://github.com/cosmos/ibc/blob/main/spec/app/ics-027-interchain-accounts/README.md) (ICS-27) application logic.
+//!
+//! The application is made of two submodules: the [`controller`], which lets
+//! accounts of the controller chain register and control interchain accounts
+//! on host chains, and the [`host`], which executes on the host chain the
+//! transactions sent by the controllers of its interchain accounts.
+#![no_std]
+#![forbid(unsafe_code)]
+#![cfg_attr(not(test), deny(clippy::unwrap_used))]
+#![cfg_attr(not(test), deny(clippy::disallowed_methods, clippy::disallowed_types))]
+#![deny(
+    warnings,
+    trivial_casts,
+    trivial_numeric_casts,
+    unused_import_braces,
+    unused_qualifications,
+    rust_2018_idioms
+)]
+#![allow(clippy::result_large_err)]
+
+#[cfg(any(test, feature = "std"))]
+extern crate std;
+
+/// Re-exports the implementation of the IBC [Interchain
+/// Accounts](https://github.com/cosmos/ibc/blob/main/spec/app/ics-027-interchain-accounts/README.md)
+/// (ICS-27) data structures.
+pub mod types {
+    #[doc(inline)]
+    pub use ibc_app_interchain_accounts_types::*;
+}
+
+#[cfg(feature = "serde")]
+pub mod context;
+#[cfg(feature = "serde")]
+pub mod controller;
+#[cfg(feature = "serde")]
+pub mod host;
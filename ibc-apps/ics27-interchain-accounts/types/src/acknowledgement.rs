@@ -0,0 +1,62 @@
+//! Defines the acknowledgements written by the host.
+use base64::prelude::BASE64_STANDARD;
+use base64::Engine;
+use ibc_core::channel::types::acknowledgement::{AcknowledgementStatus, StatusValue};
+use ibc_core::primitives::prelude::*;
+use ibc_proto::cosmos::base::abci::v1beta1::TxMsgData as RawTxMsgData;
+use ibc_proto::google::protobuf::Any;
+use ibc_proto::Protobuf;
+
+use crate::error::InterchainAccountsError;
+
+/// The result of a transaction executed by the host, made of the responses
+/// of its messages.
+///
+/// It is protobuf-encoded as a Cosmos SDK `TxMsgData` into the successful
+/// acknowledgement of the packet which carried the transaction.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TxMsgData {
+    pub msg_responses: Vec<Any>,
+}
+
+impl TxMsgData {
+    pub fn new(msg_responses: Vec<Any>) -> Self {
+        Self { msg_responses }
+    }
+
+    /// Returns the successful acknowledgement carrying this result.
+    pub fn into_acknowledgement(self) -> AcknowledgementStatus {
+        let result = BASE64_STANDARD.encode(self.encode_vec());
+        AcknowledgementStatus::success(
+            StatusValue::new(result).expect("Never fails because the encoded result is not empty"),
+        )
+    }
+}
+
+impl Protobuf<RawTxMsgData> for TxMsgData {}
+
+impl From<RawTxMsgData> for TxMsgData {
+    fn from(raw: RawTxMsgData) -> Self {
+        Self {
+            msg_responses: raw.msg_responses,
+        }
+    }
+}
+
+impl From<TxMsgData> for RawTxMsgData {
+    #[allow(deprecated)]
+    fn from(tx_msg_data: TxMsgData) -> Self {
+        Self {
+            data: vec![],
+            msg_responses: tx_msg_data.msg_responses,
+        }
+    }
+}
+
+/// Returns the error acknowledgement written by the host when it fails to
+/// process a packet.
+pub fn error_acknowledgement(err: &InterchainAccountsError) -> AcknowledgementStatus {
+    AcknowledgementStatus::error(
+        StatusValue::new(err.to_string()).expect("Never fails because errors are never empty"),
+    )
+}
@@ -0,0 +1,173 @@
+//! Defines the interchain accounts error type
+use core::convert::Infallible;
+
+use displaydoc::Display;
+use ibc_core::channel::types::channel::Order;
+use ibc_core::channel::types::error::{ChannelError, PacketError};
+use ibc_core::handler::types::error::ContextError;
+use ibc_core::host::types::error::IdentifierError;
+use ibc_core::host::types::identifiers::{ChannelId, ConnectionId, PortId};
+use ibc_core::primitives::prelude::*;
+
+#[derive(Display, Debug)]
+pub enum InterchainAccountsError {
+    /// context error: `{0}`
+    ContextError(ContextError),
+    /// invalid identifier: `{0}`
+    InvalidIdentifier(IdentifierError),
+    /// invalid channel: `{0}`
+    InvalidChannel(ChannelError),
+    /// invalid packet: `{0}`
+    InvalidPacket(PacketError),
+    /// the controller submodule is disabled
+    ControllerDisabled,
+    /// the host submodule is disabled
+    HostDisabled,
+    /// interchain account owner must not be empty
+    EmptyOwner,
+    /// port `{port_id}` is not an interchain accounts controller port
+    InvalidControllerPort { port_id: PortId },
+    /// port `{port_id}` is not the interchain accounts host port
+    InvalidHostPort { port_id: PortId },
+    /// expected `{expected}` channel, got `{actual}`
+    InvalidChannelOrder { expected: Order, actual: Order },
+    /// invalid metadata `{version}`: the version is not JSON-encoded interchain accounts metadata
+    InvalidMetadata { version: String },
+    /// unsupported interchain accounts version `{version}`, expected `{expected}`
+    UnsupportedVersion { version: String, expected: String },
+    /// unsupported encoding `{encoding}`
+    UnsupportedEncoding { encoding: String },
+    /// unsupported transaction type `{tx_type}`
+    UnsupportedTxType { tx_type: String },
+    /// metadata connection `{actual}` does not match the channel connection `{expected}`
+    ConnectionMismatch {
+        expected: ConnectionId,
+        actual: ConnectionId,
+    },
+    /// the interchain account address must not be empty
+    EmptyAccountAddress,
+    /// the metadata of the counterparty does not match the metadata of the previous active channel
+    MetadataMismatch,
+    /// an active channel `{channel_id}` is already set for connection `{connection_id}` and port `{port_id}`
+    ActiveChannelAlreadySet {
+        connection_id: ConnectionId,
+        port_id: PortId,
+        channel_id: ChannelId,
+    },
+    /// no active channel for connection `{connection_id}` and port `{port_id}`
+    ActiveChannelNotFound {
+        connection_id: ConnectionId,
+        port_id: PortId,
+    },
+    /// active channel `{channel_id}` for connection `{connection_id}` and port `{port_id}` is not open
+    ActiveChannelNotOpen {
+        connection_id: ConnectionId,
+        port_id: PortId,
+        channel_id: ChannelId,
+    },
+    /// missing the counterparty connection of connection `{connection_id}`
+    MissingCounterpartyConnection { connection_id: ConnectionId },
+    /// missing the counterparty channel of port `{port_id}` and channel `{channel_id}`
+    MissingCounterpartyChannel {
+        port_id: PortId,
+        channel_id: ChannelId,
+    },
+    /// no interchain account registered for connection `{connection_id}` and port `{port_id}`
+    AccountNotFound {
+        connection_id: ConnectionId,
+        port_id: PortId,
+    },
+    /// the packet data must not be empty
+    EmptyPacketData,
+    /// the memo must not be longer than `{max_length}` characters
+    MemoTooLong { max_length: usize },
+    /// unsupported packet data type `{packet_type}`
+    UnsupportedPacketDataType { packet_type: i32 },
+    /// failed to deserialize the packet data
+    PacketDataDeserialization,
+    /// failed to decode the transaction: `{reason}`
+    InvalidTx { reason: String },
+    /// the transaction must contain at least one message
+    EmptyTx,
+    /// message `{type_url}` is not allowed on the host
+    MessageNotAllowed { type_url: String },
+    /// failed to execute message `{type_url}`: `{reason}`
+    MessageExecutionFailed { type_url: String, reason: String },
+    /// the relative timeout must be non-zero
+    ZeroRelativeTimeout,
+    /// the packet timeout timestamp overflows
+    TimeoutOverflow,
+    /// closing an interchain accounts channel is not permitted
+    ChannelClosingNotAllowed,
+    /// the `{submodule}` submodule does not support this channel handshake step
+    InvalidHandshakeStep { submodule: &'static str },
+    /// the controller submodule does not receive packets
+    ControllerReceivedPacket,
+    /// the host submodule does not send packets
+    HostDoesNotSendPackets,
+    /// failed to decode raw msg: `{reason}`
+    DecodeRawMsg { reason: String },
+    /// unknown msg type: `{msg_type}`
+    UnknownMsgType { msg_type: String },
+    /// other error: `{0}`
+    Other(String),
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for InterchainAccountsError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match &self {
+            Self::ContextError(e) => Some(e),
+            Self::InvalidIdentifier(e) => Some(e),
+            Self::InvalidChannel(e) => Some(e),
+            Self::InvalidPacket(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<Infallible> for InterchainAccountsError {
+    fn from(e: Infallible) -> Self {
+        match e {}
+    }
+}
+
+impl From<ContextError> for InterchainAccountsError {
+    fn from(err: ContextError) -> InterchainAccountsError {
+        Self::ContextError(err)
+    }
+}
+
+impl From<IdentifierError> for InterchainAccountsError {
+    fn from(err: IdentifierError) -> InterchainAccountsError {
+        Self::InvalidIdentifier(err)
+    }
+}
+
+impl From<ChannelError> for InterchainAccountsError {
+    fn from(err: ChannelError) -> InterchainAccountsError {
+        Self::InvalidChannel(err)
+    }
+}
+
+impl From<PacketError> for InterchainAccountsError {
+    fn from(err: PacketError) -> InterchainAccountsError {
+        Self::InvalidPacket(err)
+    }
+}
+
+impl From<InterchainAccountsError> for ChannelError {
+    fn from(err: InterchainAccountsError) -> ChannelError {
+        ChannelError::AppModule {
+            description: err.to_string(),
+        }
+    }
+}
+
+impl From<InterchainAccountsError> for PacketError {
+    fn from(err: InterchainAccountsError) -> PacketError {
+        PacketError::AppModule {
+            description: err.to_string(),
+        }
+    }
+}
@@ -0,0 +1,53 @@
+//! Defines all interchain accounts event types
+use ibc_core::host::types::identifiers::ChannelId;
+use ibc_core::primitives::prelude::*;
+use ibc_core::router::types::event::ModuleEvent;
+
+use crate::MODULE_ID_STR;
+
+const EVENT_TYPE_PACKET: &str = "ics27_packet";
+
+/// Contains all events variants that can be emitted from the interchain
+/// accounts application
+pub enum Event {
+    HostPacket(HostPacketEvent),
+}
+
+/// Event emitted by the host after processing a packet received from the
+/// controller, whether the transaction it carries succeeded or not.
+pub struct HostPacketEvent {
+    pub host_channel_id: ChannelId,
+    pub success: bool,
+    /// The reason of the failure, if any.
+    pub error: Option<String>,
+}
+
+impl From<HostPacketEvent> for ModuleEvent {
+    fn from(ev: HostPacketEvent) -> Self {
+        let HostPacketEvent {
+            host_channel_id,
+            success,
+            error,
+        } = ev;
+        let mut attributes = vec![
+            ("module", MODULE_ID_STR).into(),
+            ("host_channel_id", host_channel_id).into(),
+            ("success", success).into(),
+        ];
+        if let Some(error) = error {
+            attributes.push(("error", error).into());
+        }
+        Self {
+            kind: EVENT_TYPE_PACKET.to_string(),
+            attributes,
+        }
+    }
+}
+
+impl From<Event> for ModuleEvent {
+    fn from(ev: Event) -> Self {
+        match ev {
+            Event::HostPacket(ev) => ev.into(),
+        }
+    }
+}
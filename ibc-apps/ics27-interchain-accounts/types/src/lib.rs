@@ -0,0 +1,60 @@
+//! Implementation of the IBC [Interchain Accounts](https://github.com/cosmos/ibc/blob/main/spec/app/ics-027-interchain-accounts/README.md) (ICS-27) data structures.
+#![no_std]
+#![forbid(unsafe_code)]
+#![cfg_attr(not(test), deny(clippy::unwrap_used))]
+#![cfg_attr(not(test), deny(clippy::disallowed_methods, clippy::disallowed_types))]
+#![deny(
+    warnings,
+    trivial_casts,
+    trivial_numeric_casts,
+    unused_import_braces,
+    unused_qualifications,
+    rust_2018_idioms
+)]
+
+#[cfg(any(test, feature = "std"))]
+extern crate std;
+
+#[cfg(feature = "serde")]
+mod acknowledgement;
+#[cfg(feature = "serde")]
+pub use acknowledgement::*;
+#[cfg(feature = "serde")]
+mod metadata;
+#[cfg(feature = "serde")]
+pub use metadata::*;
+#[cfg(feature = "serde")]
+mod packet;
+#[cfg(feature = "serde")]
+pub use packet::*;
+#[cfg(feature = "serde")]
+pub mod events;
+#[cfg(feature = "serde")]
+pub mod msgs;
+
+pub mod error;
+mod port;
+pub use port::*;
+
+/// Re-exports ICS-27 interchain accounts proto types from the `ibc-proto` crate.
+pub mod proto {
+    pub use ibc_proto::ibc::apps::interchain_accounts;
+}
+
+/// Module identifier for the ICS27 application.
+pub const MODULE_ID_STR: &str = "interchainaccounts";
+
+/// Module identifier for the ICS27 controller submodule.
+pub const CONTROLLER_MODULE_ID_STR: &str = "icacontroller";
+
+/// Module identifier for the ICS27 host submodule.
+pub const HOST_MODULE_ID_STR: &str = "icahost";
+
+/// ICS27 application current version.
+pub const VERSION: &str = "ics27-1";
+
+/// The protobuf encoding of the transactions carried by the packets.
+pub const ENCODING_PROTOBUF: &str = "proto3";
+
+/// The transaction type of a Cosmos SDK transaction made of several messages.
+pub const TX_TYPE_SDK_MULTI_MSG: &str = "sdk_multi_msg";
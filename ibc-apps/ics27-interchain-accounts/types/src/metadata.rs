@@ -0,0 +1,216 @@
+//! Defines the interchain accounts metadata negotiated in the channel version.
+use ibc_core::channel::types::Version;
+use ibc_core::host::types::identifiers::ConnectionId;
+use ibc_core::primitives::prelude::*;
+use ibc_proto::ibc::applications::interchain_accounts::v1::Metadata as RawMetadata;
+use ibc_proto::Protobuf;
+
+use crate::error::InterchainAccountsError;
+use crate::{ENCODING_PROTOBUF, TX_TYPE_SDK_MULTI_MSG, VERSION};
+
+/// The channel version of an interchain accounts channel.
+///
+/// It is JSON-encoded into the channel version string. The controller
+/// proposes it with an empty `address` when opening the channel, and the host
+/// fills in the address of the interchain account in its version.
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct Metadata {
+    pub version: String,
+    pub controller_connection_id: ConnectionId,
+    pub host_connection_id: ConnectionId,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub address: String,
+    pub encoding: String,
+    pub tx_type: String,
+}
+
+impl Metadata {
+    /// Returns the metadata proposed by default by the controller for a
+    /// channel built upon the given connections.
+    pub fn new(controller_connection_id: ConnectionId, host_connection_id: ConnectionId) -> Self {
+        Self {
+            version: VERSION.to_string(),
+            controller_connection_id,
+            host_connection_id,
+            address: String::new(),
+            encoding: ENCODING_PROTOBUF.to_string(),
+            tx_type: TX_TYPE_SDK_MULTI_MSG.to_string(),
+        }
+    }
+
+    /// Validates the metadata on the controller side of a channel built upon
+    /// the given controller connection.
+    pub fn validate_controller(
+        &self,
+        controller_connection_id: &ConnectionId,
+    ) -> Result<(), InterchainAccountsError> {
+        self.validate_basic()?;
+        verify_connection(controller_connection_id, &self.controller_connection_id)
+    }
+
+    /// Validates the metadata on the host side of a channel built upon the
+    /// given host connection.
+    pub fn validate_host(
+        &self,
+        host_connection_id: &ConnectionId,
+    ) -> Result<(), InterchainAccountsError> {
+        self.validate_basic()?;
+        verify_connection(host_connection_id, &self.host_connection_id)
+    }
+
+    /// Checks that the metadata negotiates the same parameters as the one of
+    /// a previous channel, which must hold when an interchain account is
+    /// reopened on a new channel. The address is not compared.
+    pub fn is_previous_metadata_equal(&self, previous: &Metadata) -> bool {
+        self.version == previous.version
+            && self.controller_connection_id == previous.controller_connection_id
+            && self.host_connection_id == previous.host_connection_id
+            && self.encoding == previous.encoding
+            && self.tx_type == previous.tx_type
+    }
+
+    fn validate_basic(&self) -> Result<(), InterchainAccountsError> {
+        if self.version != VERSION {
+            return Err(InterchainAccountsError::UnsupportedVersion {
+                version: self.version.clone(),
+                expected: VERSION.to_string(),
+            });
+        }
+        if self.encoding != ENCODING_PROTOBUF {
+            return Err(InterchainAccountsError::UnsupportedEncoding {
+                encoding: self.encoding.clone(),
+            });
+        }
+        if self.tx_type != TX_TYPE_SDK_MULTI_MSG {
+            return Err(InterchainAccountsError::UnsupportedTxType {
+                tx_type: self.tx_type.clone(),
+            });
+        }
+        Ok(())
+    }
+}
+
+fn verify_connection(
+    expected: &ConnectionId,
+    actual: &ConnectionId,
+) -> Result<(), InterchainAccountsError> {
+    if expected != actual {
+        return Err(InterchainAccountsError::ConnectionMismatch {
+            expected: expected.clone(),
+            actual: actual.clone(),
+        });
+    }
+    Ok(())
+}
+
+impl TryFrom<&Version> for Metadata {
+    type Error = InterchainAccountsError;
+
+    fn try_from(version: &Version) -> Result<Self, Self::Error> {
+        serde_json::from_str(version.as_str()).map_err(|_| {
+            InterchainAccountsError::InvalidMetadata {
+                version: version.to_string(),
+            }
+        })
+    }
+}
+
+impl From<Metadata> for Version {
+    fn from(metadata: Metadata) -> Self {
+        let version = serde_json::to_string(&metadata)
+            .expect("interchain accounts metadata is always serializable to JSON");
+        Version::new(version)
+    }
+}
+
+impl Protobuf<RawMetadata> for Metadata {}
+
+impl TryFrom<RawMetadata> for Metadata {
+    type Error = InterchainAccountsError;
+
+    fn try_from(raw: RawMetadata) -> Result<Self, Self::Error> {
+        Ok(Self {
+            version: raw.version,
+            controller_connection_id: raw.controller_connection_id.parse()?,
+            host_connection_id: raw.host_connection_id.parse()?,
+            address: raw.address,
+            encoding: raw.encoding,
+            tx_type: raw.tx_type,
+        })
+    }
+}
+
+impl From<Metadata> for RawMetadata {
+    fn from(metadata: Metadata) -> Self {
+        Self {
+            version: metadata.version,
+            controller_connection_id: metadata.controller_connection_id.to_string(),
+            host_connection_id: metadata.host_connection_id.to_string(),
+            address: metadata.address,
+            encoding: metadata.encoding,
+            tx_type: metadata.tx_type,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn metadata() -> Metadata {
+        Metadata::new(ConnectionId::new(0), ConnectionId::new(1))
+    }
+
+    #[test]
+    fn test_metadata_json_roundtrip() {
+        let version = Version::from(metadata());
+
+        assert_eq!(
+            version.as_str(),
+            r#"{"version":"ics27-1","controller_connection_id":"connection-0","host_connection_id":"connection-1","encoding":"proto3","tx_type":"sdk_multi_msg"}"#
+        );
+        assert_eq!(Metadata::try_from(&version).unwrap(), metadata());
+    }
+
+    #[test]
+    fn test_metadata_validation() {
+        let metadata = metadata();
+
+        assert!(metadata.validate_controller(&ConnectionId::new(0)).is_ok());
+        assert!(metadata.validate_host(&ConnectionId::new(1)).is_ok());
+        assert!(metadata.validate_controller(&ConnectionId::new(1)).is_err());
+        assert!(metadata.validate_host(&ConnectionId::new(0)).is_err());
+
+        let bad_encoding = Metadata {
+            encoding: "proto3json".to_string(),
+            ..metadata.clone()
+        };
+        assert!(bad_encoding
+            .validate_controller(&ConnectionId::new(0))
+            .is_err());
+
+        let bad_version = Metadata {
+            version: "ics27-2".to_string(),
+            ..metadata
+        };
+        assert!(bad_version.validate_host(&ConnectionId::new(1)).is_err());
+    }
+
+    #[test]
+    fn test_invalid_metadata() {
+        assert!(Metadata::try_from(&Version::new("ics27-1".to_string())).is_err());
+    }
+}
@@ -0,0 +1,36 @@
+//! Defines the interchain accounts controller message types
+mod register_interchain_account;
+mod send_tx;
+
+use ibc_core::primitives::prelude::*;
+use ibc_proto::google::protobuf::Any;
+pub use register_interchain_account::*;
+pub use send_tx::*;
+
+use crate::error::InterchainAccountsError;
+
+/// All the messages handled by the controller submodule.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ControllerMsg {
+    RegisterInterchainAccount(MsgRegisterInterchainAccount),
+    SendTx(MsgSendTx),
+}
+
+impl TryFrom<Any> for ControllerMsg {
+    type Error = InterchainAccountsError;
+
+    fn try_from(raw: Any) -> Result<Self, Self::Error> {
+        let msg = match raw.type_url.as_str() {
+            REGISTER_INTERCHAIN_ACCOUNT_TYPE_URL => {
+                Self::RegisterInterchainAccount(raw.try_into()?)
+            }
+            SEND_TX_TYPE_URL => Self::SendTx(raw.try_into()?),
+            _ => {
+                return Err(InterchainAccountsError::UnknownMsgType {
+                    msg_type: raw.type_url,
+                })
+            }
+        };
+        Ok(msg)
+    }
+}
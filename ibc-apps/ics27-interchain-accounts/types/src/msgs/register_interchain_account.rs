@@ -0,0 +1,94 @@
+//! Defines the message used to register an interchain account
+
+use ibc_core::channel::types::channel::Order;
+use ibc_core::channel::types::Version;
+use ibc_core::host::types::identifiers::ConnectionId;
+use ibc_core::primitives::prelude::*;
+use ibc_core::primitives::Signer;
+use ibc_proto::google::protobuf::Any;
+use ibc_proto::ibc::applications::interchain_accounts::controller::v1::MsgRegisterInterchainAccount as RawMsgRegisterInterchainAccount;
+use ibc_proto::Protobuf;
+
+use crate::error::InterchainAccountsError;
+
+pub const REGISTER_INTERCHAIN_ACCOUNT_TYPE_URL: &str =
+    "/ibc.applications.interchain_accounts.controller.v1.MsgRegisterInterchainAccount";
+
+/// Registers an interchain account for the `owner` on the host chain at the
+/// other end of the connection, by opening a channel from the controller port
+/// of the owner to the host port.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(parity_scale_codec::Encode, parity_scale_codec::Decode,)
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct MsgRegisterInterchainAccount {
+    pub owner: Signer,
+    pub conn_id_on_a: ConnectionId,
+    /// The JSON-encoded metadata proposed as the channel version. If empty,
+    /// the default metadata for the connection is proposed.
+    pub version: Version,
+    pub ordering: Order,
+}
+
+impl Protobuf<RawMsgRegisterInterchainAccount> for MsgRegisterInterchainAccount {}
+
+impl TryFrom<RawMsgRegisterInterchainAccount> for MsgRegisterInterchainAccount {
+    type Error = InterchainAccountsError;
+
+    fn try_from(raw_msg: RawMsgRegisterInterchainAccount) -> Result<Self, Self::Error> {
+        if raw_msg.owner.trim().is_empty() {
+            return Err(InterchainAccountsError::EmptyOwner);
+        }
+
+        // As in ibc-go, interchain accounts channels are ordered unless
+        // specified otherwise.
+        let ordering = match Order::from_i32(raw_msg.ordering)? {
+            Order::None => Order::Ordered,
+            ordering => ordering,
+        };
+
+        Ok(MsgRegisterInterchainAccount {
+            owner: raw_msg.owner.into(),
+            conn_id_on_a: raw_msg.connection_id.parse()?,
+            version: Version::new(raw_msg.version),
+            ordering,
+        })
+    }
+}
+
+impl From<MsgRegisterInterchainAccount> for RawMsgRegisterInterchainAccount {
+    fn from(domain_msg: MsgRegisterInterchainAccount) -> Self {
+        RawMsgRegisterInterchainAccount {
+            owner: domain_msg.owner.to_string(),
+            connection_id: domain_msg.conn_id_on_a.to_string(),
+            version: domain_msg.version.to_string(),
+            ordering: domain_msg.ordering as i32,
+        }
+    }
+}
+
+impl TryFrom<Any> for MsgRegisterInterchainAccount {
+    type Error = InterchainAccountsError;
+
+    fn try_from(raw: Any) -> Result<Self, Self::Error> {
+        match raw.type_url.as_str() {
+            REGISTER_INTERCHAIN_ACCOUNT_TYPE_URL => {
+                MsgRegisterInterchainAccount::decode_vec(&raw.value).map_err(|e| {
+                    InterchainAccountsError::DecodeRawMsg {
+                        reason: e.to_string(),
+                    }
+                })
+            }
+            _ => Err(InterchainAccountsError::UnknownMsgType {
+                msg_type: raw.type_url,
+            }),
+        }
+    }
+}
@@ -0,0 +1,87 @@
+//! Defines the message used by an interchain account owner to send a
+//! transaction to the host
+
+use core::time::Duration;
+
+use ibc_core::host::types::identifiers::ConnectionId;
+use ibc_core::primitives::prelude::*;
+use ibc_core::primitives::Signer;
+use ibc_proto::google::protobuf::Any;
+use ibc_proto::ibc::applications::interchain_accounts::controller::v1::MsgSendTx as RawMsgSendTx;
+use ibc_proto::Protobuf;
+
+use crate::error::InterchainAccountsError;
+use crate::InterchainAccountPacketData;
+
+pub const SEND_TX_TYPE_URL: &str = "/ibc.applications.interchain_accounts.controller.v1.MsgSendTx";
+
+/// Sends a transaction to be executed by the interchain account of the
+/// `owner` on the host chain at the other end of the connection.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct MsgSendTx {
+    pub owner: Signer,
+    pub conn_id_on_a: ConnectionId,
+    pub packet_data: InterchainAccountPacketData,
+    /// The timeout of the packet, relative to the current timestamp of the
+    /// controller chain.
+    pub relative_timeout: Duration,
+}
+
+impl Protobuf<RawMsgSendTx> for MsgSendTx {}
+
+impl TryFrom<RawMsgSendTx> for MsgSendTx {
+    type Error = InterchainAccountsError;
+
+    fn try_from(raw_msg: RawMsgSendTx) -> Result<Self, Self::Error> {
+        if raw_msg.owner.trim().is_empty() {
+            return Err(InterchainAccountsError::EmptyOwner);
+        }
+
+        if raw_msg.relative_timeout == 0 {
+            return Err(InterchainAccountsError::ZeroRelativeTimeout);
+        }
+
+        let packet_data: InterchainAccountPacketData = raw_msg
+            .packet_data
+            .ok_or(InterchainAccountsError::EmptyPacketData)?
+            .try_into()?;
+        packet_data.validate_basic()?;
+
+        Ok(MsgSendTx {
+            owner: raw_msg.owner.into(),
+            conn_id_on_a: raw_msg.connection_id.parse()?,
+            packet_data,
+            relative_timeout: Duration::from_nanos(raw_msg.relative_timeout),
+        })
+    }
+}
+
+impl From<MsgSendTx> for RawMsgSendTx {
+    fn from(domain_msg: MsgSendTx) -> Self {
+        RawMsgSendTx {
+            owner: domain_msg.owner.to_string(),
+            connection_id: domain_msg.conn_id_on_a.to_string(),
+            packet_data: Some(domain_msg.packet_data.into()),
+            relative_timeout: domain_msg.relative_timeout.as_nanos() as u64,
+        }
+    }
+}
+
+impl TryFrom<Any> for MsgSendTx {
+    type Error = InterchainAccountsError;
+
+    fn try_from(raw: Any) -> Result<Self, Self::Error> {
+        match raw.type_url.as_str() {
+            SEND_TX_TYPE_URL => MsgSendTx::decode_vec(&raw.value).map_err(|e| {
+                InterchainAccountsError::DecodeRawMsg {
+                    reason: e.to_string(),
+                }
+            }),
+            _ => Err(InterchainAccountsError::UnknownMsgType {
+                msg_type: raw.type_url,
+            }),
+        }
+    }
+}
@@ -0,0 +1,282 @@
+//! Defines the packet data sent by the controller to the host, and the
+//! transaction it carries.
+use base64::prelude::BASE64_STANDARD;
+use base64::Engine;
+use ibc_core::primitives::prelude::*;
+use ibc_proto::google::protobuf::Any;
+use ibc_proto::ibc::applications::interchain_accounts::v1::{
+    CosmosTx as RawCosmosTx, InterchainAccountPacketData as RawInterchainAccountPacketData,
+    Type as RawType,
+};
+use ibc_proto::Protobuf;
+
+use crate::error::InterchainAccountsError;
+
+/// Maximum length of the memo of the packet data, in characters.
+pub const MAX_MEMO_CHAR_LENGTH: usize = 32768;
+
+/// The kind of operation requested by the controller to the host.
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub enum PacketDataType {
+    /// Execute a transaction on the host with the interchain account.
+    ExecuteTx,
+}
+
+impl PacketDataType {
+    /// Yields the packet data type as in its protobuf definition.
+    pub fn as_str(&self) -> &'static str {
+        RawType::from(*self).as_str_name()
+    }
+}
+
+impl TryFrom<i32> for PacketDataType {
+    type Error = InterchainAccountsError;
+
+    fn try_from(packet_type: i32) -> Result<Self, Self::Error> {
+        match RawType::try_from(packet_type) {
+            Ok(RawType::ExecuteTx) => Ok(Self::ExecuteTx),
+            _ => Err(InterchainAccountsError::UnsupportedPacketDataType { packet_type }),
+        }
+    }
+}
+
+impl From<PacketDataType> for RawType {
+    fn from(packet_type: PacketDataType) -> Self {
+        match packet_type {
+            PacketDataType::ExecuteTx => RawType::ExecuteTx,
+        }
+    }
+}
+
+/// The data of the packets sent over interchain accounts channels, which
+/// carry a transaction to execute on the host.
+///
+/// On the wire, it is encoded as JSON with sorted keys, with the data
+/// base64-encoded, as in ibc-go.
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct InterchainAccountPacketData {
+    pub packet_type: PacketDataType,
+    pub data: Vec<u8>,
+    pub memo: String,
+}
+
+impl InterchainAccountPacketData {
+    /// Returns the packet data requesting the execution of the given
+    /// transaction.
+    pub fn execute_tx(tx: CosmosTx, memo: String) -> Self {
+        Self {
+            packet_type: PacketDataType::ExecuteTx,
+            data: tx.encode_vec(),
+            memo,
+        }
+    }
+
+    pub fn validate_basic(&self) -> Result<(), InterchainAccountsError> {
+        if self.data.is_empty() {
+            return Err(InterchainAccountsError::EmptyPacketData);
+        }
+        if self.memo.chars().count() > MAX_MEMO_CHAR_LENGTH {
+            return Err(InterchainAccountsError::MemoTooLong {
+                max_length: MAX_MEMO_CHAR_LENGTH,
+            });
+        }
+        Ok(())
+    }
+
+    /// Decodes the transaction carried by the packet data.
+    pub fn cosmos_tx(&self) -> Result<CosmosTx, InterchainAccountsError> {
+        CosmosTx::decode_vec(&self.data).map_err(|e| InterchainAccountsError::InvalidTx {
+            reason: e.to_string(),
+        })
+    }
+
+    /// Encodes the packet data into the bytes sent as the data of a packet.
+    pub fn to_packet_bytes(&self) -> Vec<u8> {
+        let json = PacketDataJson {
+            data: BASE64_STANDARD.encode(&self.data),
+            memo: self.memo.clone(),
+            packet_type: self.packet_type.as_str().to_string(),
+        };
+        serde_json::to_vec(&json).expect("packet data is always serializable to JSON")
+    }
+}
+
+/// The JSON wire format of [`InterchainAccountPacketData`], whose fields are
+/// declared in lexicographic order to produce sorted keys.
+#[derive(serde::Serialize, serde::Deserialize)]
+struct PacketDataJson {
+    data: String,
+    #[serde(default)]
+    memo: String,
+    #[serde(rename = "type")]
+    packet_type: String,
+}
+
+impl TryFrom<&[u8]> for InterchainAccountPacketData {
+    type Error = InterchainAccountsError;
+
+    /// Decodes the packet data from the data of a packet.
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        let json: PacketDataJson = serde_json::from_slice(bytes)
+            .map_err(|_| InterchainAccountsError::PacketDataDeserialization)?;
+
+        let packet_type = match RawType::from_str_name(&json.packet_type) {
+            Some(raw_type) => PacketDataType::try_from(raw_type as i32)?,
+            None => return Err(InterchainAccountsError::PacketDataDeserialization),
+        };
+        let data = BASE64_STANDARD
+            .decode(json.data)
+            .map_err(|_| InterchainAccountsError::PacketDataDeserialization)?;
+
+        Ok(Self {
+            packet_type,
+            data,
+            memo: json.memo,
+        })
+    }
+}
+
+impl Protobuf<RawInterchainAccountPacketData> for InterchainAccountPacketData {}
+
+impl TryFrom<RawInterchainAccountPacketData> for InterchainAccountPacketData {
+    type Error = InterchainAccountsError;
+
+    fn try_from(raw: RawInterchainAccountPacketData) -> Result<Self, Self::Error> {
+        Ok(Self {
+            packet_type: raw.r#type.try_into()?,
+            data: raw.data,
+            memo: raw.memo,
+        })
+    }
+}
+
+impl From<InterchainAccountPacketData> for RawInterchainAccountPacketData {
+    fn from(packet_data: InterchainAccountPacketData) -> Self {
+        Self {
+            r#type: RawType::from(packet_data.packet_type) as i32,
+            data: packet_data.data,
+            memo: packet_data.memo,
+        }
+    }
+}
+
+/// A Cosmos SDK transaction made of several messages, executed atomically by
+/// the host on behalf of the interchain account.
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CosmosTx {
+    pub messages: Vec<Any>,
+}
+
+impl CosmosTx {
+    pub fn new(messages: Vec<Any>) -> Self {
+        Self { messages }
+    }
+}
+
+impl Protobuf<RawCosmosTx> for CosmosTx {}
+
+impl From<RawCosmosTx> for CosmosTx {
+    fn from(raw: RawCosmosTx) -> Self {
+        Self {
+            messages: raw.messages,
+        }
+    }
+}
+
+impl From<CosmosTx> for RawCosmosTx {
+    fn from(tx: CosmosTx) -> Self {
+        Self {
+            messages: tx.messages,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn packet_data() -> InterchainAccountPacketData {
+        let tx = CosmosTx::new(vec![Any {
+            type_url: "/cosmos.bank.v1beta1.MsgSend".to_string(),
+            value: vec![1, 2, 3],
+        }]);
+
+        InterchainAccountPacketData::execute_tx(tx, "memo".to_string())
+    }
+
+    #[test]
+    fn test_packet_data_json_roundtrip() {
+        let packet_data = packet_data();
+        let bytes = packet_data.to_packet_bytes();
+        let json = core::str::from_utf8(&bytes).unwrap();
+
+        assert!(json.starts_with(r#"{"data":""#));
+        assert!(json.ends_with(r#","memo":"memo","type":"TYPE_EXECUTE_TX"}"#));
+        assert_eq!(
+            InterchainAccountPacketData::try_from(bytes.as_slice()).unwrap(),
+            packet_data
+        );
+    }
+
+    #[test]
+    fn test_packet_data_carries_tx() {
+        let packet_data = packet_data();
+        let tx = packet_data.cosmos_tx().unwrap();
+
+        assert_eq!(tx.messages.len(), 1);
+        assert_eq!(tx.messages[0].type_url, "/cosmos.bank.v1beta1.MsgSend");
+    }
+
+    #[test]
+    fn test_packet_data_validation() {
+        assert!(packet_data().validate_basic().is_ok());
+
+        let empty = InterchainAccountPacketData {
+            data: vec![],
+            ..packet_data()
+        };
+        assert!(empty.validate_basic().is_err());
+
+        let long_memo = InterchainAccountPacketData {
+            memo: "a".repeat(MAX_MEMO_CHAR_LENGTH + 1),
+            ..packet_data()
+        };
+        assert!(long_memo.validate_basic().is_err());
+
+        assert!(InterchainAccountPacketData::try_from(
+            br#"{"data":"","type":"TYPE_UNSPECIFIED"}"#.as_slice()
+        )
+        .is_err());
+    }
+}
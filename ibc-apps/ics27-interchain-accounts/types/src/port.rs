@@ -0,0 +1,74 @@
+//! Defines the ports bound by the controller and host submodules.
+use ibc_core::host::types::identifiers::PortId;
+use ibc_core::primitives::prelude::*;
+use ibc_core::primitives::Signer;
+
+use crate::error::InterchainAccountsError;
+
+/// Prefix of the ports bound by the controller submodule, one per
+/// interchain account owner.
+pub const CONTROLLER_PORT_PREFIX: &str = "icacontroller-";
+
+/// Port bound by the host submodule.
+pub const HOST_PORT_ID: &str = "icahost";
+
+/// Returns the controller port of the given interchain account owner, i.e.
+/// `icacontroller-{owner}`.
+pub fn controller_port_id(owner: &Signer) -> Result<PortId, InterchainAccountsError> {
+    if owner.as_ref().trim().is_empty() {
+        return Err(InterchainAccountsError::EmptyOwner);
+    }
+
+    Ok(PortId::new(format!("{CONTROLLER_PORT_PREFIX}{owner}"))?)
+}
+
+/// Returns the port bound by the host submodule.
+pub fn host_port_id() -> PortId {
+    PortId::new(HOST_PORT_ID.to_string()).expect("Never fails because the host port id is valid")
+}
+
+/// Checks that the port is a controller port, i.e. that it carries the
+/// controller port prefix followed by a non-empty owner.
+pub fn verify_controller_port(port_id: &PortId) -> Result<(), InterchainAccountsError> {
+    match port_id.as_str().strip_prefix(CONTROLLER_PORT_PREFIX) {
+        Some(owner) if !owner.is_empty() => Ok(()),
+        _ => Err(InterchainAccountsError::InvalidControllerPort {
+            port_id: port_id.clone(),
+        }),
+    }
+}
+
+/// Checks that the port is the host port.
+pub fn verify_host_port(port_id: &PortId) -> Result<(), InterchainAccountsError> {
+    if port_id.as_str() != HOST_PORT_ID {
+        return Err(InterchainAccountsError::InvalidHostPort {
+            port_id: port_id.clone(),
+        });
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_controller_port_id() {
+        let port_id = controller_port_id(&Signer::from("owner".to_string())).unwrap();
+
+        assert_eq!(port_id.as_str(), "icacontroller-owner");
+        assert!(verify_controller_port(&port_id).is_ok());
+        assert!(controller_port_id(&Signer::from(String::new())).is_err());
+    }
+
+    #[test]
+    fn test_verify_ports() {
+        assert!(verify_controller_port(&PortId::transfer()).is_err());
+        assert!(
+            verify_controller_port(&PortId::new(CONTROLLER_PORT_PREFIX.to_string()).unwrap())
+                .is_err()
+        );
+        assert!(verify_host_port(&host_port_id()).is_ok());
+        assert!(verify_host_port(&PortId::transfer()).is_err());
+    }
+}
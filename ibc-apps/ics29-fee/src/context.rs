@@ -0,0 +1,109 @@
+//! Defines the main context traits of the fee middleware
+
+use ibc_app_fee_types::error::FeeError;
+use ibc_app_fee_types::{IdentifiedPacketFees, PacketFee};
+use ibc_app_transfer_types::RawCoin;
+use ibc_core::channel::types::packet::PacketId;
+use ibc_core::host::types::identifiers::{ChannelId, PortId};
+use ibc_core::primitives::prelude::*;
+use ibc_core::primitives::Signer;
+
+/// Methods required in fee middleware validation, to be implemented by the host
+pub trait FeeValidationContext {
+    type AccountId: TryFrom<Signer>;
+
+    /// Returns true if fees were negotiated on the given channel.
+    fn is_fee_enabled(&self, port_id: &PortId, channel_id: &ChannelId) -> bool;
+
+    /// Returns the payee registered by `relayer` on the given channel, if any.
+    fn payee(&self, relayer: &Signer, channel_id: &ChannelId) -> Option<Signer>;
+
+    /// Returns the counterparty payee registered by `relayer` on the given
+    /// channel, if any.
+    fn counterparty_payee(&self, relayer: &Signer, channel_id: &ChannelId) -> Option<Signer>;
+
+    /// Returns the fees escrowed for the given packet, or an empty list if
+    /// the packet is not incentivized.
+    fn packet_fees(&self, packet_id: &PacketId) -> Result<Vec<PacketFee>, FeeError>;
+
+    /// Returns the forward relayer stored for the given received packet,
+    /// whose acknowledgement is written asynchronously, if any.
+    fn forward_relayer(&self, packet_id: &PacketId) -> Option<Signer>;
+
+    /// Returns the fees escrowed for all the incentivized packets in flight
+    /// on the given channel.
+    fn identified_packet_fees_for_channel(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<Vec<IdentifiedPacketFees>, FeeError>;
+
+    /// Validates that the coins can be moved from `payer` into the fee escrow
+    /// account, e.g. that the payer has sufficient funds.
+    fn escrow_fee_validate(
+        &self,
+        payer: &Self::AccountId,
+        coins: &[RawCoin],
+    ) -> Result<(), FeeError>;
+}
+
+/// Methods required in fee middleware execution, to be implemented by the host.
+pub trait FeeExecutionContext: FeeValidationContext {
+    /// Marks fees as negotiated on the given channel.
+    fn enable_fee(&mut self, port_id: &PortId, channel_id: &ChannelId) -> Result<(), FeeError>;
+
+    /// Marks fees as no longer negotiated on the given channel.
+    fn disable_fee(&mut self, port_id: &PortId, channel_id: &ChannelId) -> Result<(), FeeError>;
+
+    /// Stores the payee registered by `relayer` on the given channel.
+    fn store_payee(
+        &mut self,
+        relayer: &Signer,
+        channel_id: &ChannelId,
+        payee: &Signer,
+    ) -> Result<(), FeeError>;
+
+    /// Stores the counterparty payee registered by `relayer` on the given channel.
+    fn store_counterparty_payee(
+        &mut self,
+        relayer: &Signer,
+        channel_id: &ChannelId,
+        counterparty_payee: &Signer,
+    ) -> Result<(), FeeError>;
+
+    /// Stores the fees escrowed for the given packet, replacing any previous
+    /// entry.
+    fn store_packet_fees(
+        &mut self,
+        packet_id: &PacketId,
+        packet_fees: Vec<PacketFee>,
+    ) -> Result<(), FeeError>;
+
+    /// Deletes the fees escrowed for the given packet.
+    fn delete_packet_fees(&mut self, packet_id: &PacketId) -> Result<(), FeeError>;
+
+    /// Stores the forward relayer of the given received packet, until its
+    /// acknowledgement is written asynchronously.
+    fn store_forward_relayer(
+        &mut self,
+        packet_id: &PacketId,
+        forward_relayer: &Signer,
+    ) -> Result<(), FeeError>;
+
+    /// Deletes the forward relayer stored for the given received packet.
+    fn delete_forward_relayer(&mut self, packet_id: &PacketId) -> Result<(), FeeError>;
+
+    /// Moves the coins from `payer` into the fee escrow account.
+    fn escrow_fee_execute(
+        &mut self,
+        payer: &Self::AccountId,
+        coins: &[RawCoin],
+    ) -> Result<(), FeeError>;
+
+    /// Moves the coins from the fee escrow account to `receiver`.
+    fn distribute_fee_execute(
+        &mut self,
+        receiver: &Self::AccountId,
+        coins: &[RawCoin],
+    ) -> Result<(), FeeError>;
+}
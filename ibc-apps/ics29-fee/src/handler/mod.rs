@@ -0,0 +1,163 @@
+//! Implements the processing logic for ICS29 (fee payment) messages and the
+//! distribution of escrowed fees.
+mod pay_packet_fee;
+mod register_payee;
+
+use ibc_app_fee_types::add_coins;
+use ibc_app_fee_types::error::FeeError;
+use ibc_app_fee_types::events::DistributeFeeEvent;
+use ibc_app_transfer_types::RawCoin;
+use ibc_core::channel::types::packet::PacketId;
+use ibc_core::host::types::identifiers::{ChannelId, PortId};
+use ibc_core::primitives::prelude::*;
+use ibc_core::primitives::Signer;
+use ibc_core::router::types::event::ModuleEvent;
+pub use pay_packet_fee::*;
+pub use register_payee::*;
+
+use crate::context::FeeExecutionContext;
+
+/// Distributes the fees escrowed for an acknowledged packet: the receive fee
+/// goes to the forward relayer, the acknowledgement fee to the reverse
+/// relayer, and the timeout fee is refunded.
+///
+/// If the forward relayer address is empty, the receive fee is refunded too.
+pub fn distribute_packet_fees_on_acknowledgement(
+    fee_ctx_a: &mut impl FeeExecutionContext,
+    packet_id: &PacketId,
+    forward_relayer: &Signer,
+    reverse_relayer: &Signer,
+) -> Result<Vec<ModuleEvent>, FeeError> {
+    let packet_fees = fee_ctx_a.packet_fees(packet_id)?;
+
+    let mut events = Vec::new();
+
+    for packet_fee in packet_fees {
+        let refund_address = &packet_fee.refund_address;
+
+        let forward_relayer = if forward_relayer.as_ref().trim().is_empty() {
+            refund_address
+        } else {
+            forward_relayer
+        };
+
+        events.extend(distribute_fee(
+            fee_ctx_a,
+            forward_relayer,
+            refund_address,
+            &packet_fee.fee.recv_fee,
+        ));
+        events.extend(distribute_fee(
+            fee_ctx_a,
+            reverse_relayer,
+            refund_address,
+            &packet_fee.fee.ack_fee,
+        ));
+        events.extend(distribute_fee(
+            fee_ctx_a,
+            refund_address,
+            refund_address,
+            &packet_fee.fee.timeout_fee,
+        ));
+    }
+
+    fee_ctx_a.delete_packet_fees(packet_id)?;
+
+    Ok(events)
+}
+
+/// Distributes the fees escrowed for a timed out packet: the timeout fee goes
+/// to the timeout relayer, and the receive and acknowledgement fees are
+/// refunded.
+pub fn distribute_packet_fees_on_timeout(
+    fee_ctx_a: &mut impl FeeExecutionContext,
+    packet_id: &PacketId,
+    timeout_relayer: &Signer,
+) -> Result<Vec<ModuleEvent>, FeeError> {
+    let packet_fees = fee_ctx_a.packet_fees(packet_id)?;
+
+    let mut events = Vec::new();
+
+    for packet_fee in packet_fees {
+        let refund_address = &packet_fee.refund_address;
+
+        let mut refund = packet_fee.fee.recv_fee.clone();
+        add_coins(&mut refund, &packet_fee.fee.ack_fee)?;
+
+        events.extend(distribute_fee(
+            fee_ctx_a,
+            timeout_relayer,
+            refund_address,
+            &packet_fee.fee.timeout_fee,
+        ));
+        events.extend(distribute_fee(
+            fee_ctx_a,
+            refund_address,
+            refund_address,
+            &refund,
+        ));
+    }
+
+    fee_ctx_a.delete_packet_fees(packet_id)?;
+
+    Ok(events)
+}
+
+/// Refunds all the fees escrowed for the packets in flight on a channel, e.g.
+/// when the channel is closed.
+pub fn refund_fees_on_channel_closure(
+    fee_ctx_a: &mut impl FeeExecutionContext,
+    port_id: &PortId,
+    channel_id: &ChannelId,
+) -> Result<Vec<ModuleEvent>, FeeError> {
+    let identified_packet_fees =
+        fee_ctx_a.identified_packet_fees_for_channel(port_id, channel_id)?;
+
+    let mut events = Vec::new();
+
+    for identified in identified_packet_fees {
+        for packet_fee in identified.packet_fees {
+            let refund_address = &packet_fee.refund_address;
+
+            events.extend(distribute_fee(
+                fee_ctx_a,
+                refund_address,
+                refund_address,
+                &packet_fee.fee.total()?,
+            ));
+        }
+
+        fee_ctx_a.delete_packet_fees(&identified.packet_id)?;
+    }
+
+    Ok(events)
+}
+
+/// Pays `coins` out of the fee escrow account to `receiver`. If the payment
+/// fails, e.g. because `receiver` is not a valid account, the coins are
+/// refunded instead. A failed refund leaves the coins in escrow.
+fn distribute_fee<FeeCtx>(
+    fee_ctx_a: &mut FeeCtx,
+    receiver: &Signer,
+    refund_address: &Signer,
+    coins: &[RawCoin],
+) -> Option<ModuleEvent>
+where
+    FeeCtx: FeeExecutionContext,
+{
+    if coins.is_empty() {
+        return None;
+    }
+
+    [receiver, refund_address].into_iter().find_map(|address| {
+        let account: FeeCtx::AccountId = address.clone().try_into().ok()?;
+
+        fee_ctx_a.distribute_fee_execute(&account, coins).ok()?;
+
+        let event = DistributeFeeEvent {
+            receiver: address.clone(),
+            fee: coins.to_vec(),
+        };
+        Some(event.into())
+    })
+}
@@ -0,0 +1,202 @@
+use ibc_app_fee_types::error::FeeError;
+use ibc_app_fee_types::events::IncentivizedPacketEvent;
+use ibc_app_fee_types::msgs::{MsgPayPacketFee, MsgPayPacketFeeAsync};
+use ibc_app_fee_types::{IdentifiedPacketFees, PacketFee, MODULE_ID_STR};
+use ibc_core::channel::types::packet::PacketId;
+use ibc_core::handler::types::events::MessageEvent;
+use ibc_core::host::types::path::{CommitmentPath, SeqSendPath};
+use ibc_core::host::{ExecutionContext, ValidationContext};
+use ibc_core::primitives::prelude::*;
+use ibc_core::router::types::event::ModuleEvent;
+
+use crate::context::{FeeExecutionContext, FeeValidationContext};
+
+/// Escrows a fee for the next packet sent on a channel. Equivalent to calling
+/// [`pay_packet_fee_validate`], followed by [`pay_packet_fee_execute`].
+pub fn pay_packet_fee<Ctx, FeeCtx>(
+    ctx_a: &mut Ctx,
+    fee_ctx_a: &mut FeeCtx,
+    msg: MsgPayPacketFee,
+) -> Result<(), FeeError>
+where
+    Ctx: ExecutionContext,
+    FeeCtx: FeeExecutionContext,
+{
+    pay_packet_fee_validate(ctx_a, fee_ctx_a, msg.clone())?;
+    pay_packet_fee_execute(ctx_a, fee_ctx_a, msg)
+}
+
+/// Validates the escrow of a fee for the next packet sent on a channel.
+pub fn pay_packet_fee_validate<Ctx, FeeCtx>(
+    ctx_a: &Ctx,
+    fee_ctx_a: &FeeCtx,
+    msg: MsgPayPacketFee,
+) -> Result<(), FeeError>
+where
+    Ctx: ValidationContext,
+    FeeCtx: FeeValidationContext,
+{
+    if !fee_ctx_a.is_fee_enabled(&msg.port_id_on_a, &msg.chan_id_on_a) {
+        return Err(FeeError::FeeNotEnabled {
+            port_id: msg.port_id_on_a,
+            channel_id: msg.chan_id_on_a,
+        });
+    }
+
+    let seq_send_path_on_a = SeqSendPath::new(&msg.port_id_on_a, &msg.chan_id_on_a);
+    ctx_a.get_next_sequence_send(&seq_send_path_on_a)?;
+
+    let packet_fee = PacketFee::new(msg.fee, msg.signer, msg.relayers);
+
+    escrow_packet_fee_validate(fee_ctx_a, &packet_fee)
+}
+
+/// Executes the escrow of a fee for the next packet sent on a channel. A
+/// prior call to [`pay_packet_fee_validate`] MUST have succeeded.
+pub fn pay_packet_fee_execute<Ctx, FeeCtx>(
+    ctx_a: &mut Ctx,
+    fee_ctx_a: &mut FeeCtx,
+    msg: MsgPayPacketFee,
+) -> Result<(), FeeError>
+where
+    Ctx: ExecutionContext,
+    FeeCtx: FeeExecutionContext,
+{
+    let seq_send_path_on_a = SeqSendPath::new(&msg.port_id_on_a, &msg.chan_id_on_a);
+    let sequence = ctx_a.get_next_sequence_send(&seq_send_path_on_a)?;
+
+    let packet_id = PacketId::new(msg.port_id_on_a, msg.chan_id_on_a, sequence);
+    let packet_fee = PacketFee::new(msg.fee, msg.signer, msg.relayers);
+
+    escrow_packet_fee_execute(ctx_a, fee_ctx_a, packet_id, packet_fee)
+}
+
+/// Escrows a fee for a packet that was already sent. Equivalent to calling
+/// [`pay_packet_fee_async_validate`], followed by [`pay_packet_fee_async_execute`].
+pub fn pay_packet_fee_async<Ctx, FeeCtx>(
+    ctx_a: &mut Ctx,
+    fee_ctx_a: &mut FeeCtx,
+    msg: MsgPayPacketFeeAsync,
+) -> Result<(), FeeError>
+where
+    Ctx: ExecutionContext,
+    FeeCtx: FeeExecutionContext,
+{
+    pay_packet_fee_async_validate(ctx_a, fee_ctx_a, msg.clone())?;
+    pay_packet_fee_async_execute(ctx_a, fee_ctx_a, msg)
+}
+
+/// Validates the escrow of a fee for a packet that was already sent.
+pub fn pay_packet_fee_async_validate<Ctx, FeeCtx>(
+    ctx_a: &Ctx,
+    fee_ctx_a: &FeeCtx,
+    msg: MsgPayPacketFeeAsync,
+) -> Result<(), FeeError>
+where
+    Ctx: ValidationContext,
+    FeeCtx: FeeValidationContext,
+{
+    let packet_id = msg.packet_id;
+
+    if !fee_ctx_a.is_fee_enabled(&packet_id.port_id, &packet_id.chan_id) {
+        return Err(FeeError::FeeNotEnabled {
+            port_id: packet_id.port_id,
+            channel_id: packet_id.chan_id,
+        });
+    }
+
+    let seq_send_path_on_a = SeqSendPath::new(&packet_id.port_id, &packet_id.chan_id);
+    let next_sequence_send = ctx_a.get_next_sequence_send(&seq_send_path_on_a)?;
+
+    if packet_id.seq >= next_sequence_send {
+        return Err(FeeError::PacketNotSent {
+            sequence: packet_id.seq,
+            next_sequence_send,
+        });
+    }
+
+    // the packet must still be in flight, i.e. neither acknowledged nor timed out
+    let commitment_path_on_a =
+        CommitmentPath::new(&packet_id.port_id, &packet_id.chan_id, packet_id.seq);
+    if ctx_a.get_packet_commitment(&commitment_path_on_a).is_err() {
+        return Err(FeeError::PacketCommitmentNotFound { packet_id });
+    }
+
+    escrow_packet_fee_validate(fee_ctx_a, &msg.packet_fee)
+}
+
+/// Executes the escrow of a fee for a packet that was already sent. A prior
+/// call to [`pay_packet_fee_async_validate`] MUST have succeeded.
+pub fn pay_packet_fee_async_execute<Ctx, FeeCtx>(
+    ctx_a: &mut Ctx,
+    fee_ctx_a: &mut FeeCtx,
+    msg: MsgPayPacketFeeAsync,
+) -> Result<(), FeeError>
+where
+    Ctx: ExecutionContext,
+    FeeCtx: FeeExecutionContext,
+{
+    escrow_packet_fee_execute(ctx_a, fee_ctx_a, msg.packet_id, msg.packet_fee)
+}
+
+fn escrow_packet_fee_validate<FeeCtx>(
+    fee_ctx_a: &FeeCtx,
+    packet_fee: &PacketFee,
+) -> Result<(), FeeError>
+where
+    FeeCtx: FeeValidationContext,
+{
+    let payer: FeeCtx::AccountId = packet_fee
+        .refund_address
+        .clone()
+        .try_into()
+        .map_err(|_| FeeError::ParseAccountFailure)?;
+
+    fee_ctx_a.escrow_fee_validate(&payer, &packet_fee.fee.total()?)
+}
+
+fn escrow_packet_fee_execute<Ctx, FeeCtx>(
+    ctx_a: &mut Ctx,
+    fee_ctx_a: &mut FeeCtx,
+    packet_id: PacketId,
+    packet_fee: PacketFee,
+) -> Result<(), FeeError>
+where
+    Ctx: ExecutionContext,
+    FeeCtx: FeeExecutionContext,
+{
+    let payer: FeeCtx::AccountId = packet_fee
+        .refund_address
+        .clone()
+        .try_into()
+        .map_err(|_| FeeError::ParseAccountFailure)?;
+
+    fee_ctx_a.escrow_fee_execute(&payer, &packet_fee.fee.total()?)?;
+
+    let mut packet_fees = fee_ctx_a.packet_fees(&packet_id)?;
+    packet_fees.push(packet_fee);
+
+    let identified_packet_fees = IdentifiedPacketFees::new(packet_id, packet_fees);
+    let total_fee = identified_packet_fees.total_fee()?;
+
+    fee_ctx_a.store_packet_fees(
+        &identified_packet_fees.packet_id,
+        identified_packet_fees.packet_fees,
+    )?;
+
+    {
+        let packet_id = identified_packet_fees.packet_id;
+
+        ctx_a.log_message(format!("success: escrowed fee for packet {packet_id}"))?;
+
+        let event = IncentivizedPacketEvent {
+            packet_id,
+            total_fee,
+        };
+        ctx_a.emit_ibc_event(ModuleEvent::from(event).into())?;
+
+        ctx_a.emit_ibc_event(MessageEvent::Module(MODULE_ID_STR.to_string()).into())?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,157 @@
+use ibc_app_fee_types::error::FeeError;
+use ibc_app_fee_types::events::{RegisterCounterpartyPayeeEvent, RegisterPayeeEvent};
+use ibc_app_fee_types::msgs::{MsgRegisterCounterpartyPayee, MsgRegisterPayee};
+use ibc_app_fee_types::MODULE_ID_STR;
+use ibc_core::handler::types::events::MessageEvent;
+use ibc_core::host::types::identifiers::{ChannelId, PortId};
+use ibc_core::host::types::path::ChannelEndPath;
+use ibc_core::host::{ExecutionContext, ValidationContext};
+use ibc_core::primitives::prelude::*;
+use ibc_core::router::types::event::ModuleEvent;
+
+use crate::context::{FeeExecutionContext, FeeValidationContext};
+
+/// Registers the payee of a relayer on a channel. Equivalent to calling
+/// [`register_payee_validate`], followed by [`register_payee_execute`].
+pub fn register_payee<Ctx, FeeCtx>(
+    ctx: &mut Ctx,
+    fee_ctx: &mut FeeCtx,
+    msg: MsgRegisterPayee,
+) -> Result<(), FeeError>
+where
+    Ctx: ExecutionContext,
+    FeeCtx: FeeExecutionContext,
+{
+    register_payee_validate(ctx, fee_ctx, msg.clone())?;
+    register_payee_execute(ctx, fee_ctx, msg)
+}
+
+pub fn register_payee_validate<Ctx, FeeCtx>(
+    ctx: &Ctx,
+    fee_ctx: &FeeCtx,
+    msg: MsgRegisterPayee,
+) -> Result<(), FeeError>
+where
+    Ctx: ValidationContext,
+    FeeCtx: FeeValidationContext,
+{
+    verify_fee_enabled_channel(ctx, fee_ctx, &msg.port_id, &msg.chan_id)?;
+
+    let _: FeeCtx::AccountId = msg
+        .payee
+        .try_into()
+        .map_err(|_| FeeError::ParseAccountFailure)?;
+
+    Ok(())
+}
+
+pub fn register_payee_execute<Ctx, FeeCtx>(
+    ctx: &mut Ctx,
+    fee_ctx: &mut FeeCtx,
+    msg: MsgRegisterPayee,
+) -> Result<(), FeeError>
+where
+    Ctx: ExecutionContext,
+    FeeCtx: FeeExecutionContext,
+{
+    fee_ctx.store_payee(&msg.relayer, &msg.chan_id, &msg.payee)?;
+
+    {
+        ctx.log_message(format!(
+            "success: registered payee {} for relayer {} on channel {}",
+            msg.payee, msg.relayer, msg.chan_id
+        ))?;
+
+        let event = RegisterPayeeEvent {
+            relayer: msg.relayer,
+            payee: msg.payee,
+            channel_id: msg.chan_id,
+        };
+        ctx.emit_ibc_event(ModuleEvent::from(event).into())?;
+
+        ctx.emit_ibc_event(MessageEvent::Module(MODULE_ID_STR.to_string()).into())?;
+    }
+
+    Ok(())
+}
+
+/// Registers the counterparty payee of a relayer on a channel. Equivalent to
+/// calling [`register_counterparty_payee_validate`], followed by
+/// [`register_counterparty_payee_execute`].
+pub fn register_counterparty_payee<Ctx, FeeCtx>(
+    ctx: &mut Ctx,
+    fee_ctx: &mut FeeCtx,
+    msg: MsgRegisterCounterpartyPayee,
+) -> Result<(), FeeError>
+where
+    Ctx: ExecutionContext,
+    FeeCtx: FeeExecutionContext,
+{
+    register_counterparty_payee_validate(ctx, fee_ctx, msg.clone())?;
+    register_counterparty_payee_execute(ctx, fee_ctx, msg)
+}
+
+pub fn register_counterparty_payee_validate<Ctx, FeeCtx>(
+    ctx: &Ctx,
+    fee_ctx: &FeeCtx,
+    msg: MsgRegisterCounterpartyPayee,
+) -> Result<(), FeeError>
+where
+    Ctx: ValidationContext,
+    FeeCtx: FeeValidationContext,
+{
+    verify_fee_enabled_channel(ctx, fee_ctx, &msg.port_id, &msg.chan_id)
+}
+
+pub fn register_counterparty_payee_execute<Ctx, FeeCtx>(
+    ctx: &mut Ctx,
+    fee_ctx: &mut FeeCtx,
+    msg: MsgRegisterCounterpartyPayee,
+) -> Result<(), FeeError>
+where
+    Ctx: ExecutionContext,
+    FeeCtx: FeeExecutionContext,
+{
+    fee_ctx.store_counterparty_payee(&msg.relayer, &msg.chan_id, &msg.counterparty_payee)?;
+
+    {
+        ctx.log_message(format!(
+            "success: registered counterparty payee {} for relayer {} on channel {}",
+            msg.counterparty_payee, msg.relayer, msg.chan_id
+        ))?;
+
+        let event = RegisterCounterpartyPayeeEvent {
+            relayer: msg.relayer,
+            counterparty_payee: msg.counterparty_payee,
+            channel_id: msg.chan_id,
+        };
+        ctx.emit_ibc_event(ModuleEvent::from(event).into())?;
+
+        ctx.emit_ibc_event(MessageEvent::Module(MODULE_ID_STR.to_string()).into())?;
+    }
+
+    Ok(())
+}
+
+/// Checks that the channel exists and that fees were negotiated on it.
+fn verify_fee_enabled_channel<Ctx, FeeCtx>(
+    ctx: &Ctx,
+    fee_ctx: &FeeCtx,
+    port_id: &PortId,
+    chan_id: &ChannelId,
+) -> Result<(), FeeError>
+where
+    Ctx: ValidationContext,
+    FeeCtx: FeeValidationContext,
+{
+    ctx.channel_end(&ChannelEndPath::new(port_id, chan_id))?;
+
+    if !fee_ctx.is_fee_enabled(port_id, chan_id) {
+        return Err(FeeError::FeeNotEnabled {
+            port_id: port_id.clone(),
+            channel_id: chan_id.clone(),
+        });
+    }
+
+    Ok(())
+}
@@ -0,0 +1,1051 @@
+//! Defines the fee middleware callbacks, which wrap the callbacks of the
+//! underlying IBC application.
+use core::fmt::Debug;
+
+use ibc_app_fee_types::error::FeeError;
+use ibc_app_fee_types::{IncentivizedAcknowledgement, Metadata};
+use ibc_core::channel::types::acknowledgement::Acknowledgement;
+use ibc_core::channel::types::channel::{Counterparty, Order};
+use ibc_core::channel::types::error::{ChannelError, PacketError};
+use ibc_core::channel::types::packet::{Packet, PacketId};
+use ibc_core::channel::types::Version;
+use ibc_core::host::types::identifiers::{ChannelId, ConnectionId, PortId};
+use ibc_core::primitives::prelude::*;
+use ibc_core::primitives::Signer;
+use ibc_core::router::middleware::{IbcModuleWrapper, Middleware};
+use ibc_core::router::module::Module;
+use ibc_core::router::types::module::ModuleExtras;
+
+use crate::context::{FeeExecutionContext, FeeValidationContext};
+use crate::handler::{
+    distribute_packet_fees_on_acknowledgement, distribute_packet_fees_on_timeout,
+    refund_fees_on_channel_closure,
+};
+
+/// Returns the fee metadata carried by the version, or `None` if the version
+/// is not a fee version, in which case it belongs to the underlying
+/// application and is passed through untouched.
+fn fee_metadata(version: &Version) -> Result<Option<Metadata>, FeeError> {
+    match Metadata::try_from(version) {
+        Ok(metadata) => {
+            metadata.verify_fee_version()?;
+            Ok(Some(metadata))
+        }
+        Err(_) => Ok(None),
+    }
+}
+
+/// Same as [`fee_metadata`], except that an empty version enables fees on
+/// top of the default version of the underlying application.
+fn init_fee_metadata(version: &Version) -> Result<Option<Metadata>, FeeError> {
+    if version.is_empty() {
+        return Ok(Some(Metadata::new(version)));
+    }
+    fee_metadata(version)
+}
+
+fn wrap_app_version(metadata: Metadata, app_version: &Version) -> Version {
+    Metadata {
+        app_version: app_version.to_string(),
+        ..metadata
+    }
+    .into()
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn on_chan_open_init_validate(
+    _ctx: &impl FeeValidationContext,
+    app: &impl Module,
+    order: Order,
+    connection_hops: &[ConnectionId],
+    port_id: &PortId,
+    channel_id: &ChannelId,
+    counterparty: &Counterparty,
+    version: &Version,
+) -> Result<Version, ChannelError> {
+    match init_fee_metadata(version)? {
+        Some(metadata) => {
+            let app_version = app.on_chan_open_init_validate(
+                order,
+                connection_hops,
+                port_id,
+                channel_id,
+                counterparty,
+                &metadata.app_version(),
+            )?;
+            Ok(wrap_app_version(metadata, &app_version))
+        }
+        None => app.on_chan_open_init_validate(
+            order,
+            connection_hops,
+            port_id,
+            channel_id,
+            counterparty,
+            version,
+        ),
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn on_chan_open_init_execute(
+    ctx: &mut impl FeeExecutionContext,
+    app: &mut impl Module,
+    order: Order,
+    connection_hops: &[ConnectionId],
+    port_id: &PortId,
+    channel_id: &ChannelId,
+    counterparty: &Counterparty,
+    version: &Version,
+) -> Result<(ModuleExtras, Version), ChannelError> {
+    match init_fee_metadata(version)? {
+        Some(metadata) => {
+            let (extras, app_version) = app.on_chan_open_init_execute(
+                order,
+                connection_hops,
+                port_id,
+                channel_id,
+                counterparty,
+                &metadata.app_version(),
+            )?;
+            ctx.enable_fee(port_id, channel_id)?;
+            Ok((extras, wrap_app_version(metadata, &app_version)))
+        }
+        None => app.on_chan_open_init_execute(
+            order,
+            connection_hops,
+            port_id,
+            channel_id,
+            counterparty,
+            version,
+        ),
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn on_chan_open_try_validate(
+    _ctx: &impl FeeValidationContext,
+    app: &impl Module,
+    order: Order,
+    connection_hops: &[ConnectionId],
+    port_id: &PortId,
+    channel_id: &ChannelId,
+    counterparty: &Counterparty,
+    counterparty_version: &Version,
+) -> Result<Version, ChannelError> {
+    match fee_metadata(counterparty_version)? {
+        Some(metadata) => {
+            let app_version = app.on_chan_open_try_validate(
+                order,
+                connection_hops,
+                port_id,
+                channel_id,
+                counterparty,
+                &metadata.app_version(),
+            )?;
+            Ok(wrap_app_version(metadata, &app_version))
+        }
+        None => app.on_chan_open_try_validate(
+            order,
+            connection_hops,
+            port_id,
+            channel_id,
+            counterparty,
+            counterparty_version,
+        ),
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn on_chan_open_try_execute(
+    ctx: &mut impl FeeExecutionContext,
+    app: &mut impl Module,
+    order: Order,
+    connection_hops: &[ConnectionId],
+    port_id: &PortId,
+    channel_id: &ChannelId,
+    counterparty: &Counterparty,
+    counterparty_version: &Version,
+) -> Result<(ModuleExtras, Version), ChannelError> {
+    match fee_metadata(counterparty_version)? {
+        Some(metadata) => {
+            let (extras, app_version) = app.on_chan_open_try_execute(
+                order,
+                connection_hops,
+                port_id,
+                channel_id,
+                counterparty,
+                &metadata.app_version(),
+            )?;
+            ctx.enable_fee(port_id, channel_id)?;
+            Ok((extras, wrap_app_version(metadata, &app_version)))
+        }
+        None => app.on_chan_open_try_execute(
+            order,
+            connection_hops,
+            port_id,
+            channel_id,
+            counterparty,
+            counterparty_version,
+        ),
+    }
+}
+
+/// Returns the counterparty version to pass to the underlying application
+/// when the channel opening is acknowledged.
+fn open_ack_app_version(
+    ctx: &impl FeeValidationContext,
+    port_id: &PortId,
+    channel_id: &ChannelId,
+    counterparty_version: &Version,
+) -> Result<Version, FeeError> {
+    if !ctx.is_fee_enabled(port_id, channel_id) {
+        return Ok(counterparty_version.clone());
+    }
+
+    let metadata = Metadata::try_from(counterparty_version)?;
+    metadata.verify_fee_version()?;
+
+    Ok(metadata.app_version())
+}
+
+pub fn on_chan_open_ack_validate(
+    ctx: &impl FeeValidationContext,
+    app: &impl Module,
+    port_id: &PortId,
+    channel_id: &ChannelId,
+    counterparty_version: &Version,
+) -> Result<(), ChannelError> {
+    let app_version = open_ack_app_version(ctx, port_id, channel_id, counterparty_version)?;
+    app.on_chan_open_ack_validate(port_id, channel_id, &app_version)
+}
+
+pub fn on_chan_open_ack_execute(
+    ctx: &mut impl FeeExecutionContext,
+    app: &mut impl Module,
+    port_id: &PortId,
+    channel_id: &ChannelId,
+    counterparty_version: &Version,
+) -> Result<ModuleExtras, ChannelError> {
+    let app_version = open_ack_app_version(ctx, port_id, channel_id, counterparty_version)?;
+    app.on_chan_open_ack_execute(port_id, channel_id, &app_version)
+}
+
+pub fn on_chan_close_init_execute(
+    ctx: &mut impl FeeExecutionContext,
+    app: &mut impl Module,
+    port_id: &PortId,
+    channel_id: &ChannelId,
+) -> Result<ModuleExtras, ChannelError> {
+    let mut extras = app.on_chan_close_init_execute(port_id, channel_id)?;
+
+    if ctx.is_fee_enabled(port_id, channel_id) {
+        let events = refund_fees_on_channel_closure(ctx, port_id, channel_id)?;
+        extras.events.extend(events);
+    }
+
+    Ok(extras)
+}
+
+pub fn on_chan_close_confirm_execute(
+    ctx: &mut impl FeeExecutionContext,
+    app: &mut impl Module,
+    port_id: &PortId,
+    channel_id: &ChannelId,
+) -> Result<ModuleExtras, ChannelError> {
+    let mut extras = app.on_chan_close_confirm_execute(port_id, channel_id)?;
+
+    if ctx.is_fee_enabled(port_id, channel_id) {
+        let events = refund_fees_on_channel_closure(ctx, port_id, channel_id)?;
+        extras.events.extend(events);
+    }
+
+    Ok(extras)
+}
+
+pub fn on_chan_upgrade_init_validate(
+    _ctx: &impl FeeValidationContext,
+    app: &impl Module,
+    port_id: &PortId,
+    channel_id: &ChannelId,
+    proposed_order: Order,
+    proposed_connection_hops: &[ConnectionId],
+    proposed_version: &Version,
+) -> Result<Version, ChannelError> {
+    match fee_metadata(proposed_version)? {
+        Some(metadata) => {
+            let app_version = app.on_chan_upgrade_init_validate(
+                port_id,
+                channel_id,
+                proposed_order,
+                proposed_connection_hops,
+                &metadata.app_version(),
+            )?;
+            Ok(wrap_app_version(metadata, &app_version))
+        }
+        None => app.on_chan_upgrade_init_validate(
+            port_id,
+            channel_id,
+            proposed_order,
+            proposed_connection_hops,
+            proposed_version,
+        ),
+    }
+}
+
+pub fn on_chan_upgrade_init_execute(
+    _ctx: &mut impl FeeExecutionContext,
+    app: &mut impl Module,
+    port_id: &PortId,
+    channel_id: &ChannelId,
+    proposed_order: Order,
+    proposed_connection_hops: &[ConnectionId],
+    proposed_version: &Version,
+) -> Result<(ModuleExtras, Version), ChannelError> {
+    match fee_metadata(proposed_version)? {
+        Some(metadata) => {
+            let (extras, app_version) = app.on_chan_upgrade_init_execute(
+                port_id,
+                channel_id,
+                proposed_order,
+                proposed_connection_hops,
+                &metadata.app_version(),
+            )?;
+            Ok((extras, wrap_app_version(metadata, &app_version)))
+        }
+        None => app.on_chan_upgrade_init_execute(
+            port_id,
+            channel_id,
+            proposed_order,
+            proposed_connection_hops,
+            proposed_version,
+        ),
+    }
+}
+
+pub fn on_chan_upgrade_try_validate(
+    _ctx: &impl FeeValidationContext,
+    app: &impl Module,
+    port_id: &PortId,
+    channel_id: &ChannelId,
+    proposed_order: Order,
+    proposed_connection_hops: &[ConnectionId],
+    counterparty_version: &Version,
+) -> Result<Version, ChannelError> {
+    match fee_metadata(counterparty_version)? {
+        Some(metadata) => {
+            let app_version = app.on_chan_upgrade_try_validate(
+                port_id,
+                channel_id,
+                proposed_order,
+                proposed_connection_hops,
+                &metadata.app_version(),
+            )?;
+            Ok(wrap_app_version(metadata, &app_version))
+        }
+        None => app.on_chan_upgrade_try_validate(
+            port_id,
+            channel_id,
+            proposed_order,
+            proposed_connection_hops,
+            counterparty_version,
+        ),
+    }
+}
+
+pub fn on_chan_upgrade_try_execute(
+    _ctx: &mut impl FeeExecutionContext,
+    app: &mut impl Module,
+    port_id: &PortId,
+    channel_id: &ChannelId,
+    proposed_order: Order,
+    proposed_connection_hops: &[ConnectionId],
+    counterparty_version: &Version,
+) -> Result<(ModuleExtras, Version), ChannelError> {
+    match fee_metadata(counterparty_version)? {
+        Some(metadata) => {
+            let (extras, app_version) = app.on_chan_upgrade_try_execute(
+                port_id,
+                channel_id,
+                proposed_order,
+                proposed_connection_hops,
+                &metadata.app_version(),
+            )?;
+            Ok((extras, wrap_app_version(metadata, &app_version)))
+        }
+        None => app.on_chan_upgrade_try_execute(
+            port_id,
+            channel_id,
+            proposed_order,
+            proposed_connection_hops,
+            counterparty_version,
+        ),
+    }
+}
+
+/// Returns the application version wrapped by the version, if it is a fee
+/// version, or the version itself otherwise.
+fn app_version_of(version: &Version) -> Result<Version, FeeError> {
+    Ok(fee_metadata(version)?
+        .map(|metadata| metadata.app_version())
+        .unwrap_or_else(|| version.clone()))
+}
+
+pub fn on_chan_upgrade_ack_validate(
+    _ctx: &impl FeeValidationContext,
+    app: &impl Module,
+    port_id: &PortId,
+    channel_id: &ChannelId,
+    counterparty_version: &Version,
+) -> Result<(), ChannelError> {
+    let app_version = app_version_of(counterparty_version)?;
+    app.on_chan_upgrade_ack_validate(port_id, channel_id, &app_version)
+}
+
+pub fn on_chan_upgrade_ack_execute(
+    _ctx: &mut impl FeeExecutionContext,
+    app: &mut impl Module,
+    port_id: &PortId,
+    channel_id: &ChannelId,
+    counterparty_version: &Version,
+) -> Result<ModuleExtras, ChannelError> {
+    let app_version = app_version_of(counterparty_version)?;
+    app.on_chan_upgrade_ack_execute(port_id, channel_id, &app_version)
+}
+
+pub fn on_chan_upgrade_open_validate(
+    _ctx: &impl FeeValidationContext,
+    app: &impl Module,
+    port_id: &PortId,
+    channel_id: &ChannelId,
+    order: Order,
+    connection_hops: &[ConnectionId],
+    version: &Version,
+) -> Result<(), ChannelError> {
+    let app_version = app_version_of(version)?;
+    app.on_chan_upgrade_open_validate(port_id, channel_id, order, connection_hops, &app_version)
+}
+
+/// Enables or disables fees on the channel depending on whether the upgraded
+/// version is a fee version. When fees are disabled, all the fees escrowed
+/// for packets in flight are refunded.
+pub fn on_chan_upgrade_open_execute(
+    ctx: &mut impl FeeExecutionContext,
+    app: &mut impl Module,
+    port_id: &PortId,
+    channel_id: &ChannelId,
+    order: Order,
+    connection_hops: &[ConnectionId],
+    version: &Version,
+) -> Result<ModuleExtras, ChannelError> {
+    match fee_metadata(version)? {
+        Some(metadata) => {
+            ctx.enable_fee(port_id, channel_id)?;
+            app.on_chan_upgrade_open_execute(
+                port_id,
+                channel_id,
+                order,
+                connection_hops,
+                &metadata.app_version(),
+            )
+        }
+        None => {
+            let mut refund_events = Vec::new();
+            if ctx.is_fee_enabled(port_id, channel_id) {
+                refund_events = refund_fees_on_channel_closure(ctx, port_id, channel_id)?;
+                ctx.disable_fee(port_id, channel_id)?;
+            }
+
+            let mut extras = app.on_chan_upgrade_open_execute(
+                port_id,
+                channel_id,
+                order,
+                connection_hops,
+                version,
+            )?;
+            extras.events.extend(refund_events);
+
+            Ok(extras)
+        }
+    }
+}
+
+/// Wraps the acknowledgement of the underlying application into an
+/// [`IncentivizedAcknowledgement`] carrying the counterparty payee of the
+/// relayer, if fees are enabled on the channel.
+///
+/// If the application writes its acknowledgement asynchronously, the
+/// counterparty payee is stored instead, and the acknowledgement is wrapped
+/// once written, by [`write_acknowledgement_execute`].
+pub fn on_recv_packet_execute(
+    ctx: &mut impl FeeExecutionContext,
+    app: &mut impl Module,
+    packet: &Packet,
+    relayer: &Signer,
+) -> (ModuleExtras, Option<Acknowledgement>) {
+    if !ctx.is_fee_enabled(&packet.port_id_on_b, &packet.chan_id_on_b) {
+        return app.on_recv_packet_execute(packet, relayer);
+    }
+
+    let (mut extras, app_ack) = app.on_recv_packet_execute(packet, relayer);
+
+    let forward_relayer = ctx
+        .counterparty_payee(relayer, &packet.chan_id_on_b)
+        .unwrap_or_else(|| Signer::from(String::new()));
+
+    let app_ack = match app_ack {
+        Some(app_ack) => app_ack,
+        None => {
+            if let Err(e) = ctx.store_forward_relayer(&received_packet_id(packet), &forward_relayer)
+            {
+                extras.log.push(format!(
+                    "failed to store the forward relayer of the asynchronous acknowledgement: {e}"
+                ));
+            }
+            return (extras, None);
+        }
+    };
+
+    (
+        extras,
+        Some(IncentivizedAcknowledgement::new(app_ack, forward_relayer).into()),
+    )
+}
+
+/// Returns the identifier of a packet on its receiving end.
+fn received_packet_id(packet: &Packet) -> PacketId {
+    PacketId::new(
+        packet.port_id_on_b.clone(),
+        packet.chan_id_on_b.clone(),
+        packet.seq_on_a,
+    )
+}
+
+/// Returns the forward relayer stored when the packet was received, whose
+/// acknowledgement is written asynchronously.
+fn stored_forward_relayer(
+    ctx: &impl FeeValidationContext,
+    packet: &Packet,
+) -> Result<Signer, FeeError> {
+    let packet_id = received_packet_id(packet);
+
+    ctx.forward_relayer(&packet_id)
+        .ok_or(FeeError::ForwardRelayerNotFound { packet_id })
+}
+
+pub fn write_acknowledgement_validate(
+    ctx: &impl FeeValidationContext,
+    packet: &Packet,
+    acknowledgement: Acknowledgement,
+) -> Result<Acknowledgement, PacketError> {
+    if !ctx.is_fee_enabled(&packet.port_id_on_b, &packet.chan_id_on_b) {
+        return Ok(acknowledgement);
+    }
+
+    let forward_relayer = stored_forward_relayer(ctx, packet)?;
+
+    Ok(IncentivizedAcknowledgement::new(acknowledgement, forward_relayer).into())
+}
+
+/// Wraps the acknowledgement written asynchronously by the underlying
+/// application into an [`IncentivizedAcknowledgement`] carrying the forward
+/// relayer stored when the packet was received, which is then deleted.
+pub fn write_acknowledgement_execute(
+    ctx: &mut impl FeeExecutionContext,
+    packet: &Packet,
+    acknowledgement: Acknowledgement,
+) -> Result<(ModuleExtras, Acknowledgement), PacketError> {
+    if !ctx.is_fee_enabled(&packet.port_id_on_b, &packet.chan_id_on_b) {
+        return Ok((ModuleExtras::empty(), acknowledgement));
+    }
+
+    let forward_relayer = stored_forward_relayer(ctx, packet)?;
+    ctx.delete_forward_relayer(&received_packet_id(packet))?;
+
+    Ok((
+        ModuleExtras::empty(),
+        IncentivizedAcknowledgement::new(acknowledgement, forward_relayer).into(),
+    ))
+}
+
+pub fn on_acknowledgement_packet_validate(
+    ctx: &impl FeeValidationContext,
+    app: &impl Module,
+    packet: &Packet,
+    acknowledgement: &Acknowledgement,
+    relayer: &Signer,
+) -> Result<(), PacketError> {
+    if !ctx.is_fee_enabled(&packet.port_id_on_a, &packet.chan_id_on_a) {
+        return app.on_acknowledgement_packet_validate(packet, acknowledgement, relayer);
+    }
+
+    let ack = IncentivizedAcknowledgement::try_from(acknowledgement)?;
+
+    app.on_acknowledgement_packet_validate(packet, &ack.app_acknowledgement, relayer)
+}
+
+/// Pays out the fees escrowed for the packet, then passes the acknowledgement
+/// of the underlying application on to it.
+pub fn on_acknowledgement_packet_execute(
+    ctx: &mut impl FeeExecutionContext,
+    app: &mut impl Module,
+    packet: &Packet,
+    acknowledgement: &Acknowledgement,
+    relayer: &Signer,
+) -> (ModuleExtras, Result<(), PacketError>) {
+    if !ctx.is_fee_enabled(&packet.port_id_on_a, &packet.chan_id_on_a) {
+        return app.on_acknowledgement_packet_execute(packet, acknowledgement, relayer);
+    }
+
+    let ack = match IncentivizedAcknowledgement::try_from(acknowledgement) {
+        Ok(ack) => ack,
+        Err(e) => return (ModuleExtras::empty(), Err(e.into())),
+    };
+
+    let packet_id = PacketId::new(
+        packet.port_id_on_a.clone(),
+        packet.chan_id_on_a.clone(),
+        packet.seq_on_a,
+    );
+
+    let reverse_relayer = ctx
+        .payee(relayer, &packet.chan_id_on_a)
+        .unwrap_or_else(|| relayer.clone());
+
+    let fee_events = match distribute_packet_fees_on_acknowledgement(
+        ctx,
+        &packet_id,
+        &ack.forward_relayer_address,
+        &reverse_relayer,
+    ) {
+        Ok(events) => events,
+        Err(e) => return (ModuleExtras::empty(), Err(e.into())),
+    };
+
+    let (mut extras, result) =
+        app.on_acknowledgement_packet_execute(packet, &ack.app_acknowledgement, relayer);
+    extras.events.extend(fee_events);
+
+    (extras, result)
+}
+
+pub fn on_timeout_packet_validate(
+    _ctx: &impl FeeValidationContext,
+    app: &impl Module,
+    packet: &Packet,
+    relayer: &Signer,
+) -> Result<(), PacketError> {
+    app.on_timeout_packet_validate(packet, relayer)
+}
+
+/// Pays out the fees escrowed for the packet, then notifies the underlying
+/// application of the timeout.
+pub fn on_timeout_packet_execute(
+    ctx: &mut impl FeeExecutionContext,
+    app: &mut impl Module,
+    packet: &Packet,
+    relayer: &Signer,
+) -> (ModuleExtras, Result<(), PacketError>) {
+    if !ctx.is_fee_enabled(&packet.port_id_on_a, &packet.chan_id_on_a) {
+        return app.on_timeout_packet_execute(packet, relayer);
+    }
+
+    let packet_id = PacketId::new(
+        packet.port_id_on_a.clone(),
+        packet.chan_id_on_a.clone(),
+        packet.seq_on_a,
+    );
+
+    let timeout_relayer = ctx
+        .payee(relayer, &packet.chan_id_on_a)
+        .unwrap_or_else(|| relayer.clone());
+
+    let fee_events = match distribute_packet_fees_on_timeout(ctx, &packet_id, &timeout_relayer) {
+        Ok(events) => events,
+        Err(e) => return (ModuleExtras::empty(), Err(e.into())),
+    };
+
+    let (mut extras, result) = app.on_timeout_packet_execute(packet, relayer);
+    extras.events.extend(fee_events);
+
+    (extras, result)
+}
+
+/// The fee middleware, which incentivizes the relaying of the packets of the
+/// underlying IBC application (e.g. ICS-20 or ICS-721).
+///
+/// `ctx` is the host's implementation of the [`FeeExecutionContext`]. The
+/// middleware is stacked on top of the application with
+/// [`Middleware::wrap`], which yields a [`FeeModule`].
+#[derive(Debug)]
+pub struct FeeMiddleware<C> {
+    ctx: C,
+}
+
+impl<C> FeeMiddleware<C> {
+    pub fn new(ctx: C) -> Self {
+        Self { ctx }
+    }
+
+    pub fn ctx(&self) -> &C {
+        &self.ctx
+    }
+
+    pub fn ctx_mut(&mut self) -> &mut C {
+        &mut self.ctx
+    }
+
+    pub fn into_inner(self) -> C {
+        self.ctx
+    }
+}
+
+/// The IBC [`Module`] made of the fee middleware stacked on top of the
+/// application `M`.
+pub type FeeModule<C, M> = IbcModuleWrapper<FeeMiddleware<C>, M>;
+
+impl<C, N> Middleware<N> for FeeMiddleware<C>
+where
+    C: FeeExecutionContext + Debug,
+    N: Module,
+{
+    fn on_chan_open_init_validate(
+        &self,
+        next: &N,
+        order: Order,
+        connection_hops: &[ConnectionId],
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        counterparty: &Counterparty,
+        version: &Version,
+    ) -> Result<Version, ChannelError> {
+        on_chan_open_init_validate(
+            &self.ctx,
+            next,
+            order,
+            connection_hops,
+            port_id,
+            channel_id,
+            counterparty,
+            version,
+        )
+    }
+
+    fn on_chan_open_init_execute(
+        &mut self,
+        next: &mut N,
+        order: Order,
+        connection_hops: &[ConnectionId],
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        counterparty: &Counterparty,
+        version: &Version,
+    ) -> Result<(ModuleExtras, Version), ChannelError> {
+        on_chan_open_init_execute(
+            &mut self.ctx,
+            next,
+            order,
+            connection_hops,
+            port_id,
+            channel_id,
+            counterparty,
+            version,
+        )
+    }
+
+    fn on_chan_open_try_validate(
+        &self,
+        next: &N,
+        order: Order,
+        connection_hops: &[ConnectionId],
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        counterparty: &Counterparty,
+        counterparty_version: &Version,
+    ) -> Result<Version, ChannelError> {
+        on_chan_open_try_validate(
+            &self.ctx,
+            next,
+            order,
+            connection_hops,
+            port_id,
+            channel_id,
+            counterparty,
+            counterparty_version,
+        )
+    }
+
+    fn on_chan_open_try_execute(
+        &mut self,
+        next: &mut N,
+        order: Order,
+        connection_hops: &[ConnectionId],
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        counterparty: &Counterparty,
+        counterparty_version: &Version,
+    ) -> Result<(ModuleExtras, Version), ChannelError> {
+        on_chan_open_try_execute(
+            &mut self.ctx,
+            next,
+            order,
+            connection_hops,
+            port_id,
+            channel_id,
+            counterparty,
+            counterparty_version,
+        )
+    }
+
+    fn on_chan_open_ack_validate(
+        &self,
+        next: &N,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        counterparty_version: &Version,
+    ) -> Result<(), ChannelError> {
+        on_chan_open_ack_validate(&self.ctx, next, port_id, channel_id, counterparty_version)
+    }
+
+    fn on_chan_open_ack_execute(
+        &mut self,
+        next: &mut N,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        counterparty_version: &Version,
+    ) -> Result<ModuleExtras, ChannelError> {
+        on_chan_open_ack_execute(
+            &mut self.ctx,
+            next,
+            port_id,
+            channel_id,
+            counterparty_version,
+        )
+    }
+
+    fn on_chan_close_init_execute(
+        &mut self,
+        next: &mut N,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<ModuleExtras, ChannelError> {
+        on_chan_close_init_execute(&mut self.ctx, next, port_id, channel_id)
+    }
+
+    fn on_chan_close_confirm_execute(
+        &mut self,
+        next: &mut N,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<ModuleExtras, ChannelError> {
+        on_chan_close_confirm_execute(&mut self.ctx, next, port_id, channel_id)
+    }
+
+    fn on_chan_upgrade_init_validate(
+        &self,
+        next: &N,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        proposed_order: Order,
+        proposed_connection_hops: &[ConnectionId],
+        proposed_version: &Version,
+    ) -> Result<Version, ChannelError> {
+        on_chan_upgrade_init_validate(
+            &self.ctx,
+            next,
+            port_id,
+            channel_id,
+            proposed_order,
+            proposed_connection_hops,
+            proposed_version,
+        )
+    }
+
+    fn on_chan_upgrade_init_execute(
+        &mut self,
+        next: &mut N,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        proposed_order: Order,
+        proposed_connection_hops: &[ConnectionId],
+        proposed_version: &Version,
+    ) -> Result<(ModuleExtras, Version), ChannelError> {
+        on_chan_upgrade_init_execute(
+            &mut self.ctx,
+            next,
+            port_id,
+            channel_id,
+            proposed_order,
+            proposed_connection_hops,
+            proposed_version,
+        )
+    }
+
+    fn on_chan_upgrade_try_validate(
+        &self,
+        next: &N,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        proposed_order: Order,
+        proposed_connection_hops: &[ConnectionId],
+        counterparty_version: &Version,
+    ) -> Result<Version, ChannelError> {
+        on_chan_upgrade_try_validate(
+            &self.ctx,
+            next,
+            port_id,
+            channel_id,
+            proposed_order,
+            proposed_connection_hops,
+            counterparty_version,
+        )
+    }
+
+    fn on_chan_upgrade_try_execute(
+        &mut self,
+        next: &mut N,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        proposed_order: Order,
+        proposed_connection_hops: &[ConnectionId],
+        counterparty_version: &Version,
+    ) -> Result<(ModuleExtras, Version), ChannelError> {
+        on_chan_upgrade_try_execute(
+            &mut self.ctx,
+            next,
+            port_id,
+            channel_id,
+            proposed_order,
+            proposed_connection_hops,
+            counterparty_version,
+        )
+    }
+
+    fn on_chan_upgrade_ack_validate(
+        &self,
+        next: &N,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        counterparty_version: &Version,
+    ) -> Result<(), ChannelError> {
+        on_chan_upgrade_ack_validate(&self.ctx, next, port_id, channel_id, counterparty_version)
+    }
+
+    fn on_chan_upgrade_ack_execute(
+        &mut self,
+        next: &mut N,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        counterparty_version: &Version,
+    ) -> Result<ModuleExtras, ChannelError> {
+        on_chan_upgrade_ack_execute(
+            &mut self.ctx,
+            next,
+            port_id,
+            channel_id,
+            counterparty_version,
+        )
+    }
+
+    fn on_chan_upgrade_open_validate(
+        &self,
+        next: &N,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        order: Order,
+        connection_hops: &[ConnectionId],
+        version: &Version,
+    ) -> Result<(), ChannelError> {
+        on_chan_upgrade_open_validate(
+            &self.ctx,
+            next,
+            port_id,
+            channel_id,
+            order,
+            connection_hops,
+            version,
+        )
+    }
+
+    fn on_chan_upgrade_open_execute(
+        &mut self,
+        next: &mut N,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        order: Order,
+        connection_hops: &[ConnectionId],
+        version: &Version,
+    ) -> Result<ModuleExtras, ChannelError> {
+        on_chan_upgrade_open_execute(
+            &mut self.ctx,
+            next,
+            port_id,
+            channel_id,
+            order,
+            connection_hops,
+            version,
+        )
+    }
+
+    fn on_recv_packet_execute(
+        &mut self,
+        next: &mut N,
+        packet: &Packet,
+        relayer: &Signer,
+    ) -> (ModuleExtras, Option<Acknowledgement>) {
+        on_recv_packet_execute(&mut self.ctx, next, packet, relayer)
+    }
+
+    fn on_acknowledgement_packet_validate(
+        &self,
+        next: &N,
+        packet: &Packet,
+        acknowledgement: &Acknowledgement,
+        relayer: &Signer,
+    ) -> Result<(), PacketError> {
+        on_acknowledgement_packet_validate(&self.ctx, next, packet, acknowledgement, relayer)
+    }
+
+    fn on_acknowledgement_packet_execute(
+        &mut self,
+        next: &mut N,
+        packet: &Packet,
+        acknowledgement: &Acknowledgement,
+        relayer: &Signer,
+    ) -> (ModuleExtras, Result<(), PacketError>) {
+        on_acknowledgement_packet_execute(&mut self.ctx, next, packet, acknowledgement, relayer)
+    }
+
+    fn on_timeout_packet_validate(
+        &self,
+        next: &N,
+        packet: &Packet,
+        relayer: &Signer,
+    ) -> Result<(), PacketError> {
+        on_timeout_packet_validate(&self.ctx, next, packet, relayer)
+    }
+
+    fn on_timeout_packet_execute(
+        &mut self,
+        next: &mut N,
+        packet: &Packet,
+        relayer: &Signer,
+    ) -> (ModuleExtras, Result<(), PacketError>) {
+        on_timeout_packet_execute(&mut self.ctx, next, packet, relayer)
+    }
+
+    fn write_acknowledgement_validate(
+        &self,
+        packet: &Packet,
+        acknowledgement: Acknowledgement,
+    ) -> Result<Acknowledgement, PacketError> {
+        write_acknowledgement_validate(&self.ctx, packet, acknowledgement)
+    }
+
+    fn write_acknowledgement_execute(
+        &mut self,
+        packet: &Packet,
+        acknowledgement: Acknowledgement,
+    ) -> Result<(ModuleExtras, Acknowledgement), PacketError> {
+        write_acknowledgement_execute(&mut self.ctx, packet, acknowledgement)
+    }
+}
@@ -0,0 +1,115 @@
+//! Defines the acknowledgement written by the fee middleware on the receiving chain.
+use base64::prelude::BASE64_STANDARD;
+use base64::Engine;
+use ibc_core::channel::types::acknowledgement::{Acknowledgement, AcknowledgementStatus};
+use ibc_core::primitives::prelude::*;
+use ibc_core::primitives::Signer;
+
+use crate::error::FeeError;
+
+/// The acknowledgement of a packet received over a fee-enabled channel.
+///
+/// It wraps the acknowledgement of the underlying application together with
+/// the address of the relayer that delivered the packet, so that the sending
+/// chain can pay out the receive fee.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct IncentivizedAcknowledgement {
+    pub app_acknowledgement: Acknowledgement,
+    /// The counterparty payee address registered by the relayer that
+    /// delivered the packet, if any.
+    pub forward_relayer_address: Signer,
+    pub underlying_app_success: bool,
+}
+
+impl IncentivizedAcknowledgement {
+    /// Wraps the acknowledgement of the underlying application. The
+    /// application is deemed successful unless its acknowledgement is an
+    /// error [`AcknowledgementStatus`].
+    pub fn new(app_acknowledgement: Acknowledgement, forward_relayer_address: Signer) -> Self {
+        let underlying_app_success =
+            serde_json::from_slice::<AcknowledgementStatus>(app_acknowledgement.as_bytes())
+                .map(|status| status.is_successful())
+                .unwrap_or(true);
+
+        Self {
+            app_acknowledgement,
+            forward_relayer_address,
+            underlying_app_success,
+        }
+    }
+}
+
+/// JSON representation of [`IncentivizedAcknowledgement`], matching the
+/// encoding used by ibc-go.
+#[derive(serde::Serialize, serde::Deserialize)]
+struct JsonIncentivizedAcknowledgement {
+    app_acknowledgement: String,
+    forward_relayer_address: String,
+    underlying_app_success: bool,
+}
+
+impl From<IncentivizedAcknowledgement> for Acknowledgement {
+    fn from(ack: IncentivizedAcknowledgement) -> Self {
+        let json_ack = JsonIncentivizedAcknowledgement {
+            app_acknowledgement: BASE64_STANDARD.encode(ack.app_acknowledgement.as_bytes()),
+            forward_relayer_address: ack.forward_relayer_address.to_string(),
+            underlying_app_success: ack.underlying_app_success,
+        };
+
+        serde_json::to_vec(&json_ack)
+            .expect("incentivized acknowledgement is always serializable to JSON")
+            .try_into()
+            .expect("incentivized acknowledgement is never supposed to be empty")
+    }
+}
+
+impl TryFrom<&Acknowledgement> for IncentivizedAcknowledgement {
+    type Error = FeeError;
+
+    fn try_from(ack: &Acknowledgement) -> Result<Self, Self::Error> {
+        let json_ack: JsonIncentivizedAcknowledgement =
+            serde_json::from_slice(ack.as_bytes()).map_err(|_| FeeError::AckDeserialization)?;
+
+        let app_acknowledgement = BASE64_STANDARD
+            .decode(json_ack.app_acknowledgement)
+            .map_err(|_| FeeError::AckDeserialization)?
+            .try_into()
+            .map_err(|_| FeeError::AckDeserialization)?;
+
+        Ok(Self {
+            app_acknowledgement,
+            forward_relayer_address: json_ack.forward_relayer_address.into(),
+            underlying_app_success: json_ack.underlying_app_success,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ibc_core::channel::types::acknowledgement::StatusValue;
+
+    use super::*;
+
+    #[test]
+    fn test_incentivized_ack_roundtrip() {
+        let app_ack: Acknowledgement =
+            AcknowledgementStatus::success(StatusValue::new("AQ==").unwrap()).into();
+        let ack = IncentivizedAcknowledgement::new(app_ack, "relayer".to_string().into());
+        assert!(ack.underlying_app_success);
+
+        let raw: Acknowledgement = ack.clone().into();
+        assert_eq!(
+            raw.as_bytes(),
+            br#"{"app_acknowledgement":"eyJyZXN1bHQiOiJBUT09In0=","forward_relayer_address":"relayer","underlying_app_success":true}"#
+        );
+        assert_eq!(IncentivizedAcknowledgement::try_from(&raw).unwrap(), ack);
+    }
+
+    #[test]
+    fn test_incentivized_ack_error_app_ack() {
+        let app_ack: Acknowledgement =
+            AcknowledgementStatus::error(StatusValue::new("failed").unwrap()).into();
+        let ack = IncentivizedAcknowledgement::new(app_ack, Signer::from(String::new()));
+        assert!(!ack.underlying_app_success);
+    }
+}
@@ -0,0 +1,136 @@
+//! Defines the fee middleware error type
+use core::convert::Infallible;
+
+use displaydoc::Display;
+use ibc_app_transfer_types::error::TokenTransferError;
+use ibc_core::channel::types::error::{ChannelError, PacketError};
+use ibc_core::channel::types::packet::PacketId;
+use ibc_core::handler::types::error::ContextError;
+use ibc_core::host::types::error::IdentifierError;
+use ibc_core::host::types::identifiers::{ChannelId, PortId, Sequence};
+use ibc_core::primitives::prelude::*;
+
+#[derive(Display, Debug)]
+pub enum FeeError {
+    /// context error: `{0}`
+    ContextError(ContextError),
+    /// invalid identifier: `{0}`
+    InvalidIdentifier(IdentifierError),
+    /// invalid fee coin: `{0}`
+    InvalidCoin(TokenTransferError),
+    /// fees are not enabled on port_id `{port_id}` and channel_id `{channel_id}`
+    FeeNotEnabled {
+        port_id: PortId,
+        channel_id: ChannelId,
+    },
+    /// missing fee
+    MissingFee,
+    /// fee must contain at least one non-zero coin
+    EmptyFee,
+    /// fee coin `{coin}` must have a non-zero amount
+    ZeroAmountCoin { coin: String },
+    /// fee amount overflowed for denom `{denom}`
+    AmountOverflow { denom: String },
+    /// missing packet id
+    MissingPacketId,
+    /// missing packet fee
+    MissingPacketFee,
+    /// refund address must not be empty
+    EmptyRefundAddress,
+    /// signer address must not be empty
+    EmptySigner,
+    /// relayer address must not be empty
+    EmptyRelayerAddress,
+    /// payee address must not be empty
+    EmptyPayeeAddress,
+    /// counterparty payee address must not be empty
+    EmptyCounterpartyPayee,
+    /// relayer address and payee address must not be equal: `{address}`
+    RelayerIsPayee { address: String },
+    /// restricting the set of relayers is not supported, relayers must be empty
+    RelayersNotEmpty,
+    /// packet with sequence `{sequence}` has not been sent yet, next sequence send is `{next_sequence_send}`
+    PacketNotSent {
+        sequence: Sequence,
+        next_sequence_send: Sequence,
+    },
+    /// packet `{packet_id}` has already been acknowledged or timed out
+    PacketCommitmentNotFound { packet_id: PacketId },
+    /// no forward relayer stored for the asynchronous acknowledgement of packet `{packet_id}`
+    ForwardRelayerNotFound { packet_id: PacketId },
+    /// invalid fee version `{version}`, expected `{expected}`
+    InvalidVersion { version: String, expected: String },
+    /// failed to decode channel version `{version}` as fee metadata
+    InvalidMetadata { version: String },
+    /// failed to deserialize incentivized acknowledgement
+    AckDeserialization,
+    /// failed to parse as AccountId
+    ParseAccountFailure,
+    /// invalid packet: `{0}`
+    InvalidPacket(PacketError),
+    /// decoding raw msg error: `{reason}`
+    DecodeRawMsg { reason: String },
+    /// unknown msg type: `{msg_type}`
+    UnknownMsgType { msg_type: String },
+    /// other error: `{0}`
+    Other(String),
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for FeeError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match &self {
+            Self::ContextError(e) => Some(e),
+            Self::InvalidIdentifier(e) => Some(e),
+            Self::InvalidCoin(e) => Some(e),
+            Self::InvalidPacket(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<Infallible> for FeeError {
+    fn from(e: Infallible) -> Self {
+        match e {}
+    }
+}
+
+impl From<ContextError> for FeeError {
+    fn from(err: ContextError) -> FeeError {
+        Self::ContextError(err)
+    }
+}
+
+impl From<IdentifierError> for FeeError {
+    fn from(err: IdentifierError) -> FeeError {
+        Self::InvalidIdentifier(err)
+    }
+}
+
+impl From<TokenTransferError> for FeeError {
+    fn from(err: TokenTransferError) -> FeeError {
+        Self::InvalidCoin(err)
+    }
+}
+
+impl From<PacketError> for FeeError {
+    fn from(err: PacketError) -> FeeError {
+        Self::InvalidPacket(err)
+    }
+}
+
+impl From<FeeError> for ChannelError {
+    fn from(err: FeeError) -> ChannelError {
+        ChannelError::AppModule {
+            description: err.to_string(),
+        }
+    }
+}
+
+impl From<FeeError> for PacketError {
+    fn from(err: FeeError) -> PacketError {
+        PacketError::AppModule {
+            description: err.to_string(),
+        }
+    }
+}
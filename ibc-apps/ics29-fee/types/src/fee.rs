@@ -0,0 +1,273 @@
+//! Defines the fee types escrowed to incentivize the relaying of packets.
+use ibc_app_transfer_types::RawCoin;
+use ibc_core::channel::types::packet::PacketId;
+use ibc_core::primitives::prelude::*;
+use ibc_core::primitives::Signer;
+use ibc_proto::ibc::applications::fee::v1::{
+    Fee as RawFee, IdentifiedPacketFees as RawIdentifiedPacketFees, PacketFee as RawPacketFee,
+};
+use ibc_proto::Protobuf;
+
+use crate::error::FeeError;
+
+/// The receive, acknowledgement and timeout fees that a packet sender
+/// escrows to reward the relayers of that packet.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(parity_scale_codec::Encode, parity_scale_codec::Decode,)
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Fee {
+    /// Paid to the relayer that delivered the packet to the counterparty.
+    pub recv_fee: Vec<RawCoin>,
+    /// Paid to the relayer that relayed the acknowledgement back.
+    pub ack_fee: Vec<RawCoin>,
+    /// Paid to the relayer that relayed the timeout of the packet.
+    pub timeout_fee: Vec<RawCoin>,
+}
+
+impl Fee {
+    pub fn new(recv_fee: Vec<RawCoin>, ack_fee: Vec<RawCoin>, timeout_fee: Vec<RawCoin>) -> Self {
+        Self {
+            recv_fee,
+            ack_fee,
+            timeout_fee,
+        }
+    }
+
+    /// Returns the total amount to be escrowed for this fee, i.e. the sum of
+    /// the receive, acknowledgement and timeout fees, merged by denomination.
+    pub fn total(&self) -> Result<Vec<RawCoin>, FeeError> {
+        let mut total = Vec::new();
+        add_coins(&mut total, &self.recv_fee)?;
+        add_coins(&mut total, &self.ack_fee)?;
+        add_coins(&mut total, &self.timeout_fee)?;
+        Ok(total)
+    }
+
+    /// Checks that the fee carries at least one coin and that none of its
+    /// coins have a zero amount.
+    pub fn validate_basic(&self) -> Result<(), FeeError> {
+        if self.recv_fee.is_empty() && self.ack_fee.is_empty() && self.timeout_fee.is_empty() {
+            return Err(FeeError::EmptyFee);
+        }
+
+        self.recv_fee
+            .iter()
+            .chain(self.ack_fee.iter())
+            .chain(self.timeout_fee.iter())
+            .try_for_each(|coin| {
+                if coin.amount.as_ref().is_zero() {
+                    Err(FeeError::ZeroAmountCoin {
+                        coin: coin.to_string(),
+                    })
+                } else {
+                    Ok(())
+                }
+            })
+    }
+}
+
+/// Adds `coins` to `acc`, merging amounts of the same denomination.
+pub fn add_coins(acc: &mut Vec<RawCoin>, coins: &[RawCoin]) -> Result<(), FeeError> {
+    for coin in coins {
+        match acc.iter_mut().find(|c| c.denom == coin.denom) {
+            Some(existing) => {
+                existing.amount = existing.amount.checked_add(coin.amount).ok_or_else(|| {
+                    FeeError::AmountOverflow {
+                        denom: coin.denom.clone(),
+                    }
+                })?;
+            }
+            None => acc.push(coin.clone()),
+        }
+    }
+    Ok(())
+}
+
+/// Formats a list of coins the way the Cosmos SDK does, e.g. `10stake,5atom`.
+pub fn coins_to_string(coins: &[RawCoin]) -> String {
+    coins
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+fn parse_coins(
+    raw_coins: Vec<ibc_proto::cosmos::base::v1beta1::Coin>,
+) -> Result<Vec<RawCoin>, FeeError> {
+    raw_coins
+        .into_iter()
+        .map(|coin| RawCoin::try_from(coin).map_err(FeeError::from))
+        .collect()
+}
+
+impl Protobuf<RawFee> for Fee {}
+
+impl TryFrom<RawFee> for Fee {
+    type Error = FeeError;
+
+    fn try_from(raw_fee: RawFee) -> Result<Self, Self::Error> {
+        let fee = Fee {
+            recv_fee: parse_coins(raw_fee.recv_fee)?,
+            ack_fee: parse_coins(raw_fee.ack_fee)?,
+            timeout_fee: parse_coins(raw_fee.timeout_fee)?,
+        };
+
+        fee.validate_basic()?;
+
+        Ok(fee)
+    }
+}
+
+impl From<Fee> for RawFee {
+    fn from(fee: Fee) -> Self {
+        RawFee {
+            recv_fee: fee.recv_fee.into_iter().map(Into::into).collect(),
+            ack_fee: fee.ack_fee.into_iter().map(Into::into).collect(),
+            timeout_fee: fee.timeout_fee.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+/// A fee escrowed for a packet, together with the address to refund any
+/// unused part of it to.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(parity_scale_codec::Encode, parity_scale_codec::Decode,)
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PacketFee {
+    pub fee: Fee,
+    pub refund_address: Signer,
+    /// Optional list of relayers permitted to receive the fee.
+    ///
+    /// Note: restricting relayers is not supported yet, so this is always
+    /// empty for fees escrowed through this implementation.
+    pub relayers: Vec<Signer>,
+}
+
+impl PacketFee {
+    pub fn new(fee: Fee, refund_address: Signer, relayers: Vec<Signer>) -> Self {
+        Self {
+            fee,
+            refund_address,
+            relayers,
+        }
+    }
+}
+
+impl Protobuf<RawPacketFee> for PacketFee {}
+
+impl TryFrom<RawPacketFee> for PacketFee {
+    type Error = FeeError;
+
+    fn try_from(raw_packet_fee: RawPacketFee) -> Result<Self, Self::Error> {
+        let fee = raw_packet_fee.fee.ok_or(FeeError::MissingFee)?.try_into()?;
+
+        if raw_packet_fee.refund_address.trim().is_empty() {
+            return Err(FeeError::EmptyRefundAddress);
+        }
+
+        Ok(PacketFee {
+            fee,
+            refund_address: raw_packet_fee.refund_address.into(),
+            relayers: raw_packet_fee
+                .relayers
+                .into_iter()
+                .map(Into::into)
+                .collect(),
+        })
+    }
+}
+
+impl From<PacketFee> for RawPacketFee {
+    fn from(packet_fee: PacketFee) -> Self {
+        RawPacketFee {
+            fee: Some(packet_fee.fee.into()),
+            refund_address: packet_fee.refund_address.to_string(),
+            relayers: packet_fee
+                .relayers
+                .into_iter()
+                .map(|relayer| relayer.to_string())
+                .collect(),
+        }
+    }
+}
+
+/// All the fees escrowed for a given packet.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(parity_scale_codec::Encode, parity_scale_codec::Decode,)
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct IdentifiedPacketFees {
+    pub packet_id: PacketId,
+    pub packet_fees: Vec<PacketFee>,
+}
+
+impl IdentifiedPacketFees {
+    pub fn new(packet_id: PacketId, packet_fees: Vec<PacketFee>) -> Self {
+        Self {
+            packet_id,
+            packet_fees,
+        }
+    }
+
+    /// Returns the sum of the receive, acknowledgement and timeout fees of
+    /// all the fees escrowed for the packet.
+    pub fn total_fee(&self) -> Result<Fee, FeeError> {
+        let mut total = Fee::default();
+        for packet_fee in &self.packet_fees {
+            add_coins(&mut total.recv_fee, &packet_fee.fee.recv_fee)?;
+            add_coins(&mut total.ack_fee, &packet_fee.fee.ack_fee)?;
+            add_coins(&mut total.timeout_fee, &packet_fee.fee.timeout_fee)?;
+        }
+        Ok(total)
+    }
+}
+
+impl Protobuf<RawIdentifiedPacketFees> for IdentifiedPacketFees {}
+
+impl TryFrom<RawIdentifiedPacketFees> for IdentifiedPacketFees {
+    type Error = FeeError;
+
+    fn try_from(raw: RawIdentifiedPacketFees) -> Result<Self, Self::Error> {
+        Ok(IdentifiedPacketFees {
+            packet_id: raw.packet_id.ok_or(FeeError::MissingPacketId)?.try_into()?,
+            packet_fees: raw
+                .packet_fees
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+impl From<IdentifiedPacketFees> for RawIdentifiedPacketFees {
+    fn from(value: IdentifiedPacketFees) -> Self {
+        RawIdentifiedPacketFees {
+            packet_id: Some(value.packet_id.into()),
+            packet_fees: value.packet_fees.into_iter().map(Into::into).collect(),
+        }
+    }
+}
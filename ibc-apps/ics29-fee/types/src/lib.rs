@@ -0,0 +1,46 @@
+//! Implementation of the IBC [relayer incentivisation](https://github.com/cosmos/ibc/blob/main/spec/app/ics-029-fee-payment/README.md) (ICS-29) data structures.
+#![no_std]
+#![forbid(unsafe_code)]
+#![cfg_attr(not(test), deny(clippy::unwrap_used))]
+#![cfg_attr(not(test), deny(clippy::disallowed_methods, clippy::disallowed_types))]
+#![deny(
+    warnings,
+    trivial_casts,
+    trivial_numeric_casts,
+    unused_import_braces,
+    unused_qualifications,
+    rust_2018_idioms
+)]
+
+#[cfg(any(test, feature = "std"))]
+extern crate std;
+
+#[cfg(feature = "serde")]
+mod acknowledgement;
+#[cfg(feature = "serde")]
+pub use acknowledgement::*;
+#[cfg(feature = "serde")]
+mod fee;
+#[cfg(feature = "serde")]
+pub use fee::*;
+#[cfg(feature = "serde")]
+mod metadata;
+#[cfg(feature = "serde")]
+pub use metadata::*;
+#[cfg(feature = "serde")]
+pub mod events;
+#[cfg(feature = "serde")]
+pub mod msgs;
+
+pub mod error;
+
+/// Re-exports ICS-29 fee payment proto types from the `ibc-proto` crate.
+pub mod proto {
+    pub use ibc_proto::ibc::apps::fee;
+}
+
+/// Module identifier for the ICS29 middleware.
+pub const MODULE_ID_STR: &str = "feeibc";
+
+/// ICS29 middleware current version.
+pub const VERSION: &str = "ics29-1";
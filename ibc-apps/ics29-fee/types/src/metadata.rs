@@ -0,0 +1,118 @@
+//! Defines the fee metadata negotiated in the channel version.
+use ibc_core::channel::types::Version;
+use ibc_core::primitives::prelude::*;
+use ibc_proto::ibc::applications::fee::v1::Metadata as RawMetadata;
+use ibc_proto::Protobuf;
+
+use crate::error::FeeError;
+use crate::VERSION;
+
+/// The channel version of a fee-enabled channel, which wraps the version of
+/// the underlying application.
+///
+/// It is JSON-encoded into the channel version string, e.g.
+/// `{"fee_version":"ics29-1","app_version":"ics20-1"}`.
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct Metadata {
+    pub fee_version: String,
+    pub app_version: String,
+}
+
+impl Metadata {
+    /// Wraps the given application version with the current fee version.
+    pub fn new(app_version: &Version) -> Self {
+        Self {
+            fee_version: VERSION.to_string(),
+            app_version: app_version.to_string(),
+        }
+    }
+
+    /// Returns the version of the underlying application.
+    pub fn app_version(&self) -> Version {
+        Version::new(self.app_version.clone())
+    }
+
+    /// Checks that the fee version is the one supported by this implementation.
+    pub fn verify_fee_version(&self) -> Result<(), FeeError> {
+        if self.fee_version != VERSION {
+            return Err(FeeError::InvalidVersion {
+                version: self.fee_version.clone(),
+                expected: VERSION.to_string(),
+            });
+        }
+        Ok(())
+    }
+}
+
+impl TryFrom<&Version> for Metadata {
+    type Error = FeeError;
+
+    fn try_from(version: &Version) -> Result<Self, Self::Error> {
+        serde_json::from_str(version.as_str()).map_err(|_| FeeError::InvalidMetadata {
+            version: version.to_string(),
+        })
+    }
+}
+
+impl From<Metadata> for Version {
+    fn from(metadata: Metadata) -> Self {
+        let version =
+            serde_json::to_string(&metadata).expect("fee metadata is always serializable to JSON");
+        Version::new(version)
+    }
+}
+
+impl Protobuf<RawMetadata> for Metadata {}
+
+impl From<RawMetadata> for Metadata {
+    fn from(raw: RawMetadata) -> Self {
+        Self {
+            fee_version: raw.fee_version,
+            app_version: raw.app_version,
+        }
+    }
+}
+
+impl From<Metadata> for RawMetadata {
+    fn from(metadata: Metadata) -> Self {
+        Self {
+            fee_version: metadata.fee_version,
+            app_version: metadata.app_version,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_metadata_json_roundtrip() {
+        let metadata = Metadata::new(&Version::new("ics20-1".to_string()));
+        let version: Version = metadata.clone().into();
+
+        assert_eq!(
+            version.as_str(),
+            r#"{"fee_version":"ics29-1","app_version":"ics20-1"}"#
+        );
+        assert_eq!(Metadata::try_from(&version).unwrap(), metadata);
+    }
+
+    #[test]
+    fn test_metadata_from_plain_version_fails() {
+        assert!(Metadata::try_from(&Version::new("ics20-1".to_string())).is_err());
+    }
+}
@@ -0,0 +1,44 @@
+//! Defines the fee middleware message types
+mod pay_packet_fee;
+mod pay_packet_fee_async;
+mod register_counterparty_payee;
+mod register_payee;
+
+use ibc_core::primitives::prelude::*;
+use ibc_proto::google::protobuf::Any;
+pub use pay_packet_fee::*;
+pub use pay_packet_fee_async::*;
+pub use register_counterparty_payee::*;
+pub use register_payee::*;
+
+use crate::error::FeeError;
+
+/// All the messages handled by the fee middleware.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum FeeMsg {
+    PayPacketFee(MsgPayPacketFee),
+    PayPacketFeeAsync(MsgPayPacketFeeAsync),
+    RegisterPayee(MsgRegisterPayee),
+    RegisterCounterpartyPayee(MsgRegisterCounterpartyPayee),
+}
+
+impl TryFrom<Any> for FeeMsg {
+    type Error = FeeError;
+
+    fn try_from(raw: Any) -> Result<Self, Self::Error> {
+        let msg = match raw.type_url.as_str() {
+            PAY_PACKET_FEE_TYPE_URL => Self::PayPacketFee(raw.try_into()?),
+            PAY_PACKET_FEE_ASYNC_TYPE_URL => Self::PayPacketFeeAsync(raw.try_into()?),
+            REGISTER_PAYEE_TYPE_URL => Self::RegisterPayee(raw.try_into()?),
+            REGISTER_COUNTERPARTY_PAYEE_TYPE_URL => {
+                Self::RegisterCounterpartyPayee(raw.try_into()?)
+            }
+            _ => {
+                return Err(FeeError::UnknownMsgType {
+                    msg_type: raw.type_url,
+                })
+            }
+        };
+        Ok(msg)
+    }
+}
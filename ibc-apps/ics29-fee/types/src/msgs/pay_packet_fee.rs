@@ -0,0 +1,97 @@
+//! Defines the message used to escrow a fee for the next packet sent on a channel
+
+use ibc_core::host::types::identifiers::{ChannelId, PortId};
+use ibc_core::primitives::prelude::*;
+use ibc_core::primitives::Signer;
+use ibc_proto::google::protobuf::Any;
+use ibc_proto::ibc::applications::fee::v1::MsgPayPacketFee as RawMsgPayPacketFee;
+use ibc_proto::Protobuf;
+
+use crate::error::FeeError;
+use crate::Fee;
+
+pub const PAY_PACKET_FEE_TYPE_URL: &str = "/ibc.applications.fee.v1.MsgPayPacketFee";
+
+/// Escrows a fee for the next packet sent on the given channel.
+///
+/// It is expected to be submitted in the same transaction as the message
+/// that sends the packet (e.g. `MsgTransfer`), right before it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(parity_scale_codec::Encode, parity_scale_codec::Decode,)
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct MsgPayPacketFee {
+    pub fee: Fee,
+    /// the port on which the incentivized packet will be sent
+    pub port_id_on_a: PortId,
+    /// the channel on which the incentivized packet will be sent
+    pub chan_id_on_a: ChannelId,
+    /// the account paying the fee, which is also refunded any unused fee
+    pub signer: Signer,
+    /// optional list of relayers permitted to receive the fee; must be empty
+    pub relayers: Vec<Signer>,
+}
+
+impl Protobuf<RawMsgPayPacketFee> for MsgPayPacketFee {}
+
+impl TryFrom<RawMsgPayPacketFee> for MsgPayPacketFee {
+    type Error = FeeError;
+
+    fn try_from(raw_msg: RawMsgPayPacketFee) -> Result<Self, Self::Error> {
+        if raw_msg.signer.trim().is_empty() {
+            return Err(FeeError::EmptySigner);
+        }
+
+        if !raw_msg.relayers.is_empty() {
+            return Err(FeeError::RelayersNotEmpty);
+        }
+
+        Ok(MsgPayPacketFee {
+            fee: raw_msg.fee.ok_or(FeeError::MissingFee)?.try_into()?,
+            port_id_on_a: raw_msg.source_port_id.parse()?,
+            chan_id_on_a: raw_msg.source_channel_id.parse()?,
+            signer: raw_msg.signer.into(),
+            relayers: Vec::new(),
+        })
+    }
+}
+
+impl From<MsgPayPacketFee> for RawMsgPayPacketFee {
+    fn from(domain_msg: MsgPayPacketFee) -> Self {
+        RawMsgPayPacketFee {
+            fee: Some(domain_msg.fee.into()),
+            source_port_id: domain_msg.port_id_on_a.to_string(),
+            source_channel_id: domain_msg.chan_id_on_a.to_string(),
+            signer: domain_msg.signer.to_string(),
+            relayers: domain_msg
+                .relayers
+                .into_iter()
+                .map(|relayer| relayer.to_string())
+                .collect(),
+        }
+    }
+}
+
+impl TryFrom<Any> for MsgPayPacketFee {
+    type Error = FeeError;
+
+    fn try_from(raw: Any) -> Result<Self, Self::Error> {
+        match raw.type_url.as_str() {
+            PAY_PACKET_FEE_TYPE_URL => {
+                MsgPayPacketFee::decode_vec(&raw.value).map_err(|e| FeeError::DecodeRawMsg {
+                    reason: e.to_string(),
+                })
+            }
+            _ => Err(FeeError::UnknownMsgType {
+                msg_type: raw.type_url,
+            }),
+        }
+    }
+}
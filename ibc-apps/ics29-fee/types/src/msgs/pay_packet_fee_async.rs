@@ -0,0 +1,82 @@
+//! Defines the message used to escrow a fee for a packet that was already sent
+
+use ibc_core::channel::types::packet::PacketId;
+use ibc_core::primitives::prelude::*;
+use ibc_proto::google::protobuf::Any;
+use ibc_proto::ibc::applications::fee::v1::MsgPayPacketFeeAsync as RawMsgPayPacketFeeAsync;
+use ibc_proto::Protobuf;
+
+use crate::error::FeeError;
+use crate::PacketFee;
+
+pub const PAY_PACKET_FEE_ASYNC_TYPE_URL: &str = "/ibc.applications.fee.v1.MsgPayPacketFeeAsync";
+
+/// Escrows a fee for a packet that has already been sent but not yet
+/// acknowledged or timed out.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(parity_scale_codec::Encode, parity_scale_codec::Decode,)
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct MsgPayPacketFeeAsync {
+    /// identifies the incentivized packet on the sending chain
+    pub packet_id: PacketId,
+    pub packet_fee: PacketFee,
+}
+
+impl Protobuf<RawMsgPayPacketFeeAsync> for MsgPayPacketFeeAsync {}
+
+impl TryFrom<RawMsgPayPacketFeeAsync> for MsgPayPacketFeeAsync {
+    type Error = FeeError;
+
+    fn try_from(raw_msg: RawMsgPayPacketFeeAsync) -> Result<Self, Self::Error> {
+        let packet_fee: PacketFee = raw_msg
+            .packet_fee
+            .ok_or(FeeError::MissingPacketFee)?
+            .try_into()?;
+
+        if !packet_fee.relayers.is_empty() {
+            return Err(FeeError::RelayersNotEmpty);
+        }
+
+        Ok(MsgPayPacketFeeAsync {
+            packet_id: raw_msg
+                .packet_id
+                .ok_or(FeeError::MissingPacketId)?
+                .try_into()?,
+            packet_fee,
+        })
+    }
+}
+
+impl From<MsgPayPacketFeeAsync> for RawMsgPayPacketFeeAsync {
+    fn from(domain_msg: MsgPayPacketFeeAsync) -> Self {
+        RawMsgPayPacketFeeAsync {
+            packet_id: Some(domain_msg.packet_id.into()),
+            packet_fee: Some(domain_msg.packet_fee.into()),
+        }
+    }
+}
+
+impl TryFrom<Any> for MsgPayPacketFeeAsync {
+    type Error = FeeError;
+
+    fn try_from(raw: Any) -> Result<Self, Self::Error> {
+        match raw.type_url.as_str() {
+            PAY_PACKET_FEE_ASYNC_TYPE_URL => {
+                MsgPayPacketFeeAsync::decode_vec(&raw.value).map_err(|e| FeeError::DecodeRawMsg {
+                    reason: e.to_string(),
+                })
+            }
+            _ => Err(FeeError::UnknownMsgType {
+                msg_type: raw.type_url,
+            }),
+        }
+    }
+}
@@ -0,0 +1,90 @@
+//! Defines the message used by relayers to register a counterparty payee address
+
+use ibc_core::host::types::identifiers::{ChannelId, PortId};
+use ibc_core::primitives::prelude::*;
+use ibc_core::primitives::Signer;
+use ibc_proto::google::protobuf::Any;
+use ibc_proto::ibc::applications::fee::v1::MsgRegisterCounterpartyPayee as RawMsgRegisterCounterpartyPayee;
+use ibc_proto::Protobuf;
+
+use crate::error::FeeError;
+
+pub const REGISTER_COUNTERPARTY_PAYEE_TYPE_URL: &str =
+    "/ibc.applications.fee.v1.MsgRegisterCounterpartyPayee";
+
+/// Registers the address on the counterparty chain to which the receive fees
+/// earned by a relayer on a given channel are paid.
+///
+/// The counterparty payee is written into the acknowledgement of every packet
+/// the relayer delivers on the channel, and is therefore not validated.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(parity_scale_codec::Encode, parity_scale_codec::Decode,)
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct MsgRegisterCounterpartyPayee {
+    pub port_id: PortId,
+    pub chan_id: ChannelId,
+    /// the relayer address, which is also the signer of the message
+    pub relayer: Signer,
+    pub counterparty_payee: Signer,
+}
+
+impl Protobuf<RawMsgRegisterCounterpartyPayee> for MsgRegisterCounterpartyPayee {}
+
+impl TryFrom<RawMsgRegisterCounterpartyPayee> for MsgRegisterCounterpartyPayee {
+    type Error = FeeError;
+
+    fn try_from(raw_msg: RawMsgRegisterCounterpartyPayee) -> Result<Self, Self::Error> {
+        if raw_msg.relayer.trim().is_empty() {
+            return Err(FeeError::EmptyRelayerAddress);
+        }
+
+        if raw_msg.counterparty_payee.trim().is_empty() {
+            return Err(FeeError::EmptyCounterpartyPayee);
+        }
+
+        Ok(MsgRegisterCounterpartyPayee {
+            port_id: raw_msg.port_id.parse()?,
+            chan_id: raw_msg.channel_id.parse()?,
+            relayer: raw_msg.relayer.into(),
+            counterparty_payee: raw_msg.counterparty_payee.into(),
+        })
+    }
+}
+
+impl From<MsgRegisterCounterpartyPayee> for RawMsgRegisterCounterpartyPayee {
+    fn from(domain_msg: MsgRegisterCounterpartyPayee) -> Self {
+        RawMsgRegisterCounterpartyPayee {
+            port_id: domain_msg.port_id.to_string(),
+            channel_id: domain_msg.chan_id.to_string(),
+            relayer: domain_msg.relayer.to_string(),
+            counterparty_payee: domain_msg.counterparty_payee.to_string(),
+        }
+    }
+}
+
+impl TryFrom<Any> for MsgRegisterCounterpartyPayee {
+    type Error = FeeError;
+
+    fn try_from(raw: Any) -> Result<Self, Self::Error> {
+        match raw.type_url.as_str() {
+            REGISTER_COUNTERPARTY_PAYEE_TYPE_URL => {
+                MsgRegisterCounterpartyPayee::decode_vec(&raw.value).map_err(|e| {
+                    FeeError::DecodeRawMsg {
+                        reason: e.to_string(),
+                    }
+                })
+            }
+            _ => Err(FeeError::UnknownMsgType {
+                msg_type: raw.type_url,
+            }),
+        }
+    }
+}
@@ -0,0 +1,90 @@
+//! Defines the message used by relayers to register a payee address
+
+use ibc_core::host::types::identifiers::{ChannelId, PortId};
+use ibc_core::primitives::prelude::*;
+use ibc_core::primitives::Signer;
+use ibc_proto::google::protobuf::Any;
+use ibc_proto::ibc::applications::fee::v1::MsgRegisterPayee as RawMsgRegisterPayee;
+use ibc_proto::Protobuf;
+
+use crate::error::FeeError;
+
+pub const REGISTER_PAYEE_TYPE_URL: &str = "/ibc.applications.fee.v1.MsgRegisterPayee";
+
+/// Registers the address to which the acknowledgement and timeout fees earned
+/// by a relayer on a given channel are paid, instead of the relayer itself.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(parity_scale_codec::Encode, parity_scale_codec::Decode,)
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct MsgRegisterPayee {
+    pub port_id: PortId,
+    pub chan_id: ChannelId,
+    /// the relayer address, which is also the signer of the message
+    pub relayer: Signer,
+    pub payee: Signer,
+}
+
+impl Protobuf<RawMsgRegisterPayee> for MsgRegisterPayee {}
+
+impl TryFrom<RawMsgRegisterPayee> for MsgRegisterPayee {
+    type Error = FeeError;
+
+    fn try_from(raw_msg: RawMsgRegisterPayee) -> Result<Self, Self::Error> {
+        if raw_msg.relayer.trim().is_empty() {
+            return Err(FeeError::EmptyRelayerAddress);
+        }
+
+        if raw_msg.payee.trim().is_empty() {
+            return Err(FeeError::EmptyPayeeAddress);
+        }
+
+        if raw_msg.relayer == raw_msg.payee {
+            return Err(FeeError::RelayerIsPayee {
+                address: raw_msg.relayer,
+            });
+        }
+
+        Ok(MsgRegisterPayee {
+            port_id: raw_msg.port_id.parse()?,
+            chan_id: raw_msg.channel_id.parse()?,
+            relayer: raw_msg.relayer.into(),
+            payee: raw_msg.payee.into(),
+        })
+    }
+}
+
+impl From<MsgRegisterPayee> for RawMsgRegisterPayee {
+    fn from(domain_msg: MsgRegisterPayee) -> Self {
+        RawMsgRegisterPayee {
+            port_id: domain_msg.port_id.to_string(),
+            channel_id: domain_msg.chan_id.to_string(),
+            relayer: domain_msg.relayer.to_string(),
+            payee: domain_msg.payee.to_string(),
+        }
+    }
+}
+
+impl TryFrom<Any> for MsgRegisterPayee {
+    type Error = FeeError;
+
+    fn try_from(raw: Any) -> Result<Self, Self::Error> {
+        match raw.type_url.as_str() {
+            REGISTER_PAYEE_TYPE_URL => {
+                MsgRegisterPayee::decode_vec(&raw.value).map_err(|e| FeeError::DecodeRawMsg {
+                    reason: e.to_string(),
+                })
+            }
+            _ => Err(FeeError::UnknownMsgType {
+                msg_type: raw.type_url,
+            }),
+        }
+    }
+}
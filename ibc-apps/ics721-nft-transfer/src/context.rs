@@ -6,7 +6,8 @@ use ibc_core::primitives::Signer;
 
 use crate::types::error::NftTransferError;
 use crate::types::{
-    ClassData, ClassId, ClassUri, Memo, PrefixedClassId, TokenData, TokenId, TokenUri,
+    ClassData, ClassId, ClassUri, Memo, PrefixedClassId, TokenData, TokenId, TokenIds, TokenUri,
+    DEFAULT_MAX_BATCH_SIZE,
 };
 
 pub trait NftContext {
@@ -129,6 +130,26 @@ pub trait NftTransferValidationContext {
         None
     }
 
+    /// Returns the maximum number of NFTs that can be sent or received in a
+    /// single packet.
+    fn max_batch_size(&self) -> usize {
+        DEFAULT_MAX_BATCH_SIZE
+    }
+
+    /// Returns the hash of the prefixed class ID, under which its trace is
+    /// stored.
+    ///
+    /// Defaults to the [`trace_hash`](PrefixedClassId::trace_hash) of the
+    /// classes of relayed NFTs, as in ibc-go. Override it to return `None` if
+    /// the host chain does not support hashed class IDs.
+    fn class_hash_string(&self, class_id: &PrefixedClassId) -> Option<String> {
+        if class_id.trace_path.is_empty() {
+            None
+        } else {
+            Some(class_id.trace_hash())
+        }
+    }
+
     /// Returns the NFT
     fn get_nft(
         &self,
@@ -141,8 +162,55 @@ pub trait NftTransferValidationContext {
         -> Result<Self::NftClass, NftTransferError>;
 }
 
+/// Methods required to store and look up the traces of the classes of the
+/// NFTs relayed to the host, to be implemented by the host.
+pub trait NftClassTraceContext {
+    /// Returns the class whose trace is stored under the given hash.
+    fn class_trace(&self, hash: &str) -> Result<Option<PrefixedClassId>, NftTransferError>;
+
+    /// Returns all the stored class traces, along with their hashes.
+    fn class_traces(&self) -> Result<Vec<(String, PrefixedClassId)>, NftTransferError>;
+
+    /// Stores the trace of the class under the given hash.
+    fn store_class_trace(
+        &mut self,
+        hash: String,
+        class_id: PrefixedClassId,
+    ) -> Result<(), NftTransferError>;
+}
+
+/// Hook notifying the host that NFTs were received, e.g. to call the receiving
+/// contract or application along with the memo of the packet.
+pub trait NftReceiveHook: NftTransferValidationContext {
+    /// Called once all the NFTs of a received packet have been minted or
+    /// unescrowed into the receiver account.
+    ///
+    /// Returning an error vetoes the receive: all the NFTs of the packet are
+    /// burnt or escrowed back, and an error acknowledgement is written so
+    /// that they are refunded to the sender. Hence, the hook must not move
+    /// the received NFTs out of the receiver account.
+    ///
+    /// Does nothing by default.
+    fn on_nft_received(
+        &mut self,
+        _receiver: &Self::AccountId,
+        _class_id: &PrefixedClassId,
+        _token_ids: &TokenIds,
+        _memo: &Memo,
+    ) -> Result<(), NftTransferError> {
+        Ok(())
+    }
+}
+
 /// Read-write methods required in NFT transfer execution context.
-pub trait NftTransferExecutionContext: NftTransferValidationContext {
+///
+/// The traces of the classes of the minted NFTs are stored with the
+/// [`NftClassTraceContext`], so that they can be resolved from their hash,
+/// and the host is notified of the received NFTs with the
+/// [`NftReceiveHook`].
+pub trait NftTransferExecutionContext:
+    NftTransferValidationContext + NftClassTraceContext + NftReceiveHook
+{
     /// Creates a new NFT Class identified by classId. If the class ID already exists, it updates the class metadata.
     fn create_or_update_class_execute(
         &self,
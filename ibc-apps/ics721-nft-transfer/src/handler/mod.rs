@@ -12,6 +12,29 @@ use crate::types::error::NftTransferError;
 use crate::types::is_sender_chain_source;
 use crate::types::packet::PacketData;
 
+/// Checks that the packet carries at most the maximum number of NFTs allowed
+/// by the host, each of them only once.
+fn validate_batch(
+    ctx: &impl NftTransferValidationContext,
+    data: &PacketData,
+) -> Result<(), NftTransferError> {
+    let batch_size = data.token_ids.0.len();
+    let max_batch_size = ctx.max_batch_size();
+
+    if batch_size > max_batch_size {
+        return Err(NftTransferError::BatchTooLarge {
+            batch_size,
+            max_batch_size,
+        });
+    }
+
+    if data.token_ids.has_duplicates() {
+        return Err(NftTransferError::DuplicatedTokenIds);
+    }
+
+    Ok(())
+}
+
 pub fn refund_packet_nft_execute(
     ctx_a: &mut impl NftTransferExecutionContext,
     packet: &Packet,
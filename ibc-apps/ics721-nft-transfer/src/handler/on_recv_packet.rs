@@ -2,14 +2,40 @@ use ibc_core::channel::types::packet::Packet;
 use ibc_core::primitives::prelude::*;
 use ibc_core::router::types::module::ModuleExtras;
 
+use super::validate_batch;
 use crate::context::NftTransferExecutionContext;
 use crate::types::error::NftTransferError;
 use crate::types::events::TokenTraceEvent;
 use crate::types::packet::PacketData;
-use crate::types::{is_receiver_chain_source, TracePrefix};
+use crate::types::{is_receiver_chain_source, PrefixedClassId, TokenId, TracePrefix};
+
+/// Stores the trace of the class of minted NFTs, the first time the class is
+/// relayed with this prefix.
+fn store_class_trace<Ctx: NftTransferExecutionContext>(
+    ctx_b: &mut Ctx,
+    class_id: &PrefixedClassId,
+) -> Result<(), NftTransferError> {
+    let hash = match ctx_b.class_hash_string(class_id) {
+        Some(hash) => hash,
+        None => return Ok(()),
+    };
+
+    if ctx_b.class_trace(&hash)?.is_none() {
+        ctx_b.store_class_trace(hash, class_id.clone())?;
+    }
+
+    Ok(())
+}
 
 /// This function handles the transfer receiving logic.
 ///
+/// All the NFTs of the packet are received atomically: each of them is
+/// validated before any of them is unescrowed or minted, and the ones already
+/// unescrowed or minted are taken back if the receive of another one fails,
+/// or if the [`NftReceiveHook`](crate::context::NftReceiveHook) vetoes it.
+/// The class of minted NFTs is created or updated only once they are all
+/// minted and accepted by the hook.
+///
 /// Note that `send/mint_nft_validate` steps are performed on the host chain
 /// to validate accounts and NFT info. But the result is then used for execution
 /// on the IBC side, including storing acknowledgements and emitting events.
@@ -25,12 +51,16 @@ where
         .can_receive_nft()
         .map_err(|err| (ModuleExtras::empty(), err))?;
 
+    validate_batch(ctx_b, &data).map_err(|err| (ModuleExtras::empty(), err))?;
+
     let receiver_account = data
         .receiver
         .clone()
         .try_into()
         .map_err(|_| (ModuleExtras::empty(), NftTransferError::ParseAccountFailure))?;
 
+    let memo = data.memo.clone().unwrap_or_default();
+
     let extras = if is_receiver_chain_source(
         packet.port_id_on_a.clone(),
         packet.chan_id_on_a.clone(),
@@ -56,15 +86,48 @@ where
                     token_id,
                 )
                 .map_err(|nft_error| (ModuleExtras::empty(), nft_error))?;
-            ctx_b
-                .unescrow_nft_execute(
+        }
+
+        let mut unescrowed: Vec<&TokenId> = Vec::new();
+        let result = data
+            .token_ids
+            .0
+            .iter()
+            .try_for_each(|token_id| {
+                ctx_b.unescrow_nft_execute(
                     &receiver_account,
                     &packet.port_id_on_b,
                     &packet.chan_id_on_b,
                     &class_id,
                     token_id,
-                )
-                .map_err(|nft_error| (ModuleExtras::empty(), nft_error))?;
+                )?;
+                unescrowed.push(token_id);
+                Ok(())
+            })
+            .and_then(|()| {
+                ctx_b.on_nft_received(&receiver_account, &class_id, &data.token_ids, &memo)
+            });
+
+        if let Err(nft_error) = result {
+            // escrow back the NFTs already unescrowed, all of them even if
+            // one fails, as the original failure is the one reported
+            let mut extras = ModuleExtras::empty();
+            for token_id in unescrowed {
+                if let Err(err) = ctx_b.escrow_nft_execute(
+                    &receiver_account,
+                    &packet.port_id_on_b,
+                    &packet.chan_id_on_b,
+                    &class_id,
+                    token_id,
+                    &memo,
+                ) {
+                    extras.log.push(format!(
+                        "failed to escrow back {class_id}/{token_id}: {err}"
+                    ));
+                }
+            }
+
+            return Err(Box::new((extras, nft_error)));
         }
 
         ModuleExtras::empty()
@@ -81,6 +144,17 @@ where
             events: vec![],
             log: Vec::new(),
         };
+
+        ctx_b
+            .create_or_update_class_validate(
+                &class_id,
+                data.class_uri.as_ref(),
+                data.class_data.as_ref(),
+            )
+            .map_err(|nft_error| (ModuleExtras::empty(), nft_error))?;
+
+        // Note: the validation is called before the execution.
+        // Refer to ICS-20 `process_recv_packet_execute()`.
         for (i, token_id) in data.token_ids.0.iter().enumerate() {
             let token_uri = data.token_uris.as_ref().and_then(|uris| uris.get(i));
             let token_data = data.token_data.as_ref().and_then(|data| data.get(i));
@@ -92,24 +166,6 @@ where
             };
             extras.events.push(trace_event.into());
 
-            // Note: the validation is called before the execution.
-            // Refer to ICS-20 `process_recv_packet_execute()`.
-
-            ctx_b
-                .create_or_update_class_validate(
-                    &class_id,
-                    data.class_uri.as_ref(),
-                    data.class_data.as_ref(),
-                )
-                .map_err(|nft_error| (ModuleExtras::empty(), nft_error))?;
-            ctx_b
-                .create_or_update_class_execute(
-                    &class_id,
-                    data.class_uri.as_ref(),
-                    data.class_data.as_ref(),
-                )
-                .map_err(|nft_error| (ModuleExtras::empty(), nft_error))?;
-
             ctx_b
                 .mint_nft_validate(
                     &receiver_account,
@@ -119,17 +175,59 @@ where
                     token_data,
                 )
                 .map_err(|nft_error| (extras.clone(), nft_error))?;
-            ctx_b
-                .mint_nft_execute(
+        }
+
+        let mut minted: Vec<&TokenId> = Vec::new();
+        let result = data
+            .token_ids
+            .0
+            .iter()
+            .enumerate()
+            .try_for_each(|(i, token_id)| {
+                let token_uri = data.token_uris.as_ref().and_then(|uris| uris.get(i));
+                let token_data = data.token_data.as_ref().and_then(|data| data.get(i));
+
+                ctx_b.mint_nft_execute(
                     &receiver_account,
                     &class_id,
                     token_id,
                     token_uri,
                     token_data,
-                )
-                .map_err(|nft_error| (extras.clone(), nft_error))?;
+                )?;
+                minted.push(token_id);
+                Ok(())
+            })
+            .and_then(|()| {
+                ctx_b.on_nft_received(&receiver_account, &class_id, &data.token_ids, &memo)
+            });
+
+        if let Err(nft_error) = result {
+            // burn the NFTs already minted, all of them even if one fails, as
+            // the original failure is the one reported
+            for token_id in minted {
+                if let Err(err) =
+                    ctx_b.burn_nft_execute(&receiver_account, &class_id, token_id, &memo)
+                {
+                    extras
+                        .log
+                        .push(format!("failed to burn back {class_id}/{token_id}: {err}"));
+                }
+            }
+
+            return Err(Box::new((extras, nft_error)));
         }
 
+        // the class is only created or updated once the NFTs are accepted
+        ctx_b
+            .create_or_update_class_execute(
+                &class_id,
+                data.class_uri.as_ref(),
+                data.class_data.as_ref(),
+            )
+            .map_err(|nft_error| (extras.clone(), nft_error))?;
+
+        store_class_trace(ctx_b, &class_id).map_err(|nft_error| (extras.clone(), nft_error))?;
+
         extras
     };
 
@@ -6,6 +6,7 @@ use ibc_core::host::types::path::{ChannelEndPath, SeqSendPath};
 use ibc_core::primitives::prelude::*;
 use ibc_core::router::types::event::ModuleEvent;
 
+use super::validate_batch;
 use crate::context::{
     NftClassContext, NftContext, NftTransferExecutionContext, NftTransferValidationContext,
 };
@@ -40,6 +41,8 @@ where
 {
     transfer_ctx.can_send_nft()?;
 
+    validate_batch(transfer_ctx, &msg.packet_data)?;
+
     let chan_end_path_on_a = ChannelEndPath::new(&msg.port_id_on_a, &msg.chan_id_on_a);
     let chan_end_on_a = send_packet_ctx_a.channel_end(&chan_end_path_on_a)?;
 
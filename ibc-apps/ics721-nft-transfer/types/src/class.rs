@@ -230,6 +230,22 @@ impl PrefixedClassId {
     pub fn add_trace_prefix(&mut self, prefix: TracePrefix) {
         self.trace_path.add_prefix(prefix)
     }
+
+    /// Returns the hash identifying the class, i.e. the upper-case hex
+    /// encoding of the SHA-256 hash of its full path, from which hosts such
+    /// as ibc-go derive the `ibc/{hash}` class ID of relayed NFTs.
+    pub fn trace_hash(&self) -> String {
+        use core::fmt::Write;
+
+        use sha2::Digest;
+
+        sha2::Sha256::digest(self.to_string().as_bytes())
+            .iter()
+            .fold(String::new(), |mut hash, byte| {
+                let _ = write!(hash, "{byte:02X}");
+                hash
+            })
+    }
 }
 
 /// Returns true if the class ID originally came from the sender chain and false otherwise.
@@ -506,6 +522,16 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_class_id_trace_hash() -> Result<(), NftTransferError> {
+        assert_eq!(
+            PrefixedClassId::from_str("nft-transfer/channel-0/myclass")?.trace_hash(),
+            "AB8BAB2C754D2AA3345646A6AB448B4ADCC5D1FDBB9C847CF45D5AB7E4F50D79"
+        );
+
+        Ok(())
+    }
+
     #[test]
     fn test_class_id_serde() -> Result<(), NftTransferError> {
         let dt_str = "transfer/channel-0/myclass";
@@ -46,6 +46,13 @@ pub enum NftTransferError {
     InvalidTokenId,
     /// duplicated token IDs
     DuplicatedTokenIds,
+    /// batch of `{batch_size}` token IDs exceeds the maximum of `{max_batch_size}`
+    BatchTooLarge {
+        batch_size: usize,
+        max_batch_size: usize,
+    },
+    /// receive rejected by the receiver: `{reason}`
+    ReceiveRejected { reason: String },
     /// The length of token IDs mismatched that of token URIs or token data
     TokenMismatched,
     /// invalid json data
@@ -54,6 +54,10 @@ pub const PORT_ID_STR: &str = "nft-transfer";
 /// ICS-721 application current version.
 pub const VERSION: &str = "ics721-1";
 
+/// The maximum number of NFTs transferred in a single packet, unless
+/// configured otherwise by the host.
+pub const DEFAULT_MAX_BATCH_SIZE: usize = 100;
+
 /// The successful string used for creating an acknowledgement status,
 /// equivalent to `base64::encode(0x01)`.
 pub const ACK_SUCCESS_B64: &str = "AQ==";
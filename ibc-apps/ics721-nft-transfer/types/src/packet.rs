@@ -107,6 +107,9 @@ impl PacketData {
         if (num_uri != 0 && num_uri != num) || (num_data != 0 && num_data != num) {
             return Err(NftTransferError::TokenMismatched);
         }
+        if self.token_ids.has_duplicates() {
+            return Err(NftTransferError::DuplicatedTokenIds);
+        }
         Ok(())
     }
 }
@@ -320,4 +323,18 @@ mod tests {
             "no token ID"
         );
     }
+
+    #[test]
+    fn test_duplicated_token_ids() {
+        let mut packet_data = PacketData::new_min_dummy();
+        packet_data
+            .token_ids
+            .0
+            .push(packet_data.token_ids.0[0].clone());
+
+        assert!(matches!(
+            packet_data.validate_basic(),
+            Err(NftTransferError::DuplicatedTokenIds)
+        ));
+    }
 }
@@ -72,6 +72,14 @@ impl TokenIds {
     pub fn as_ref(&self) -> Vec<&TokenId> {
         self.0.iter().collect()
     }
+
+    /// Returns true if any of the token IDs appears more than once.
+    pub fn has_duplicates(&self) -> bool {
+        let mut ids = self.as_ref();
+        ids.sort();
+        ids.dedup();
+        ids.len() != self.0.len()
+    }
 }
 
 impl Display for TokenIds {
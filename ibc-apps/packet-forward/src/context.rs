@@ -0,0 +1,67 @@
+//! Defines the main context traits of the packet-forward middleware
+
+use ibc_app_packet_forward_types::error::PacketForwardError;
+use ibc_app_packet_forward_types::InFlightPacket;
+use ibc_core::channel::context::{SendPacketExecutionContext, SendPacketValidationContext};
+use ibc_core::channel::types::acknowledgement::Acknowledgement;
+use ibc_core::channel::types::packet::{Packet, PacketId};
+use ibc_core::host::types::identifiers::ChannelId;
+use ibc_core::primitives::{Signer, Timestamp};
+
+/// Methods required in packet-forward middleware validation, to be
+/// implemented by the host
+pub trait PacketForwardValidationContext: SendPacketValidationContext {
+    /// Returns the account which receives the tokens of a packet to be
+    /// forwarded, and sends them on to the next chain.
+    ///
+    /// It is derived from the channel on which the packet is received and
+    /// from the sender of the packet, so that nobody else can use it.
+    fn intermediate_receiver(
+        &self,
+        channel_id: &ChannelId,
+        original_sender: &Signer,
+    ) -> Result<Signer, PacketForwardError>;
+
+    /// Returns the current timestamp of the host, from which the timeout of
+    /// the forwarded packets is computed.
+    fn host_timestamp(&self) -> Result<Timestamp, PacketForwardError>;
+
+    /// Returns the metadata of the forwarded packet, if it is in flight.
+    fn in_flight_packet(
+        &self,
+        packet_id: &PacketId,
+    ) -> Result<Option<InFlightPacket>, PacketForwardError>;
+}
+
+/// Methods required in packet-forward middleware execution, to be implemented
+/// by the host.
+pub trait PacketForwardExecutionContext:
+    PacketForwardValidationContext + SendPacketExecutionContext
+{
+    /// Stores the metadata of a forwarded packet, until it is acknowledged or
+    /// timed out.
+    fn store_in_flight_packet(
+        &mut self,
+        packet_id: PacketId,
+        in_flight_packet: InFlightPacket,
+    ) -> Result<(), PacketForwardError>;
+
+    /// Deletes the metadata of a forwarded packet.
+    fn delete_in_flight_packet(&mut self, packet_id: &PacketId) -> Result<(), PacketForwardError>;
+
+    /// Writes the acknowledgement of a packet received earlier, whose
+    /// acknowledgement was held while it was forwarded.
+    ///
+    /// Hosts typically implement it with core IBC's
+    /// [`write_acknowledgement`](ibc_core::channel::handler::write_acknowledgement)
+    /// handler. If other middleware is stacked on top of this one, e.g. the
+    /// fee middleware, the acknowledgement must instead be written through
+    /// the stack with
+    /// [`write_acknowledgement_via_module`](ibc_core::channel::handler::write_acknowledgement_via_module),
+    /// so that the middleware can wrap it.
+    fn write_acknowledgement(
+        &mut self,
+        packet: &Packet,
+        acknowledgement: Acknowledgement,
+    ) -> Result<(), PacketForwardError>;
+}
@@ -0,0 +1,169 @@
+//! Implements the forwarding logic of the packet-forward middleware.
+use core::time::Duration;
+
+use ibc_app_packet_forward_types::error::PacketForwardError;
+use ibc_app_packet_forward_types::InFlightPacket;
+use ibc_app_transfer::context::TokenTransferExecutionContext;
+use ibc_app_transfer::handler::{decode_channel_packet_data, escrow_coins_execute, send_transfer};
+use ibc_app_transfer_types::error::TokenTransferError;
+use ibc_app_transfer_types::msgs::transfer::MsgTransferV2;
+use ibc_app_transfer_types::packet::FungibleTokenPacketDataV2;
+use ibc_app_transfer_types::{is_receiver_chain_source, PrefixedCoin, TracePrefix};
+use ibc_core::channel::types::packet::{Packet, PacketId};
+use ibc_core::channel::types::timeout::TimeoutHeight;
+use ibc_core::host::types::identifiers::{ChannelId, PortId};
+use ibc_core::host::types::path::SeqSendPath;
+use ibc_core::primitives::prelude::*;
+use ibc_core::primitives::Signer;
+
+use crate::context::{PacketForwardExecutionContext, PacketForwardValidationContext};
+
+/// Returns the tokens of a packet received by this chain, with their
+/// denominations as they are known on this chain.
+pub fn received_tokens(packet: &Packet, data: &FungibleTokenPacketDataV2) -> Vec<PrefixedCoin> {
+    data.tokens
+        .iter()
+        .cloned()
+        .map(|mut token| {
+            if is_receiver_chain_source(
+                packet.port_id_on_a.clone(),
+                packet.chan_id_on_a.clone(),
+                &token.denom,
+            ) {
+                let prefix =
+                    TracePrefix::new(packet.port_id_on_a.clone(), packet.chan_id_on_a.clone());
+                token.denom.remove_trace_prefix(&prefix);
+            } else {
+                let prefix =
+                    TracePrefix::new(packet.port_id_on_b.clone(), packet.chan_id_on_b.clone());
+                token.denom.add_trace_prefix(prefix);
+            }
+            token
+        })
+        .collect()
+}
+
+/// Builds the transfer forwarding the given packet data over the channel,
+/// timing out `timeout` after the current host time.
+pub fn forward_msg(
+    ctx: &impl PacketForwardValidationContext,
+    port_id: PortId,
+    channel_id: ChannelId,
+    packet_data: FungibleTokenPacketDataV2,
+    timeout: Duration,
+) -> Result<MsgTransferV2, PacketForwardError> {
+    let timeout_timestamp_on_b =
+        (ctx.host_timestamp()? + timeout).map_err(|_| PacketForwardError::TimeoutOverflow)?;
+
+    Ok(MsgTransferV2 {
+        port_id_on_a: port_id,
+        chan_id_on_a: channel_id,
+        packet_data,
+        timeout_height_on_b: TimeoutHeight::Never,
+        timeout_timestamp_on_b,
+    })
+}
+
+/// Sends the forwarded packet with `send_transfer`, and stores its metadata
+/// until it is acknowledged or timed out.
+pub fn forward_transfer<C, T>(
+    ctx: &mut C,
+    token_ctx: &mut T,
+    msg: MsgTransferV2,
+    in_flight_packet: InFlightPacket,
+) -> Result<(), PacketForwardError>
+where
+    C: PacketForwardExecutionContext,
+    T: TokenTransferExecutionContext,
+{
+    let seq_send_path = SeqSendPath::new(&msg.port_id_on_a, &msg.chan_id_on_a);
+    let sequence = ctx.get_next_sequence_send(&seq_send_path)?;
+    let packet_id = PacketId::new(msg.port_id_on_a.clone(), msg.chan_id_on_a.clone(), sequence);
+
+    send_transfer(ctx, token_ctx, msg)?;
+
+    ctx.store_in_flight_packet(packet_id, in_flight_packet)
+}
+
+/// Sends again a forwarded packet which timed out, whose tokens were refunded
+/// to the intermediate receiver beforehand.
+pub fn retry_forward_transfer<C, T>(
+    ctx: &mut C,
+    token_ctx: &mut T,
+    timed_out_packet: &Packet,
+    in_flight_packet: InFlightPacket,
+) -> Result<(), PacketForwardError>
+where
+    C: PacketForwardExecutionContext,
+    T: TokenTransferExecutionContext,
+{
+    let packet_data = decode_channel_packet_data(
+        token_ctx,
+        &timed_out_packet.port_id_on_a,
+        &timed_out_packet.chan_id_on_a,
+        &timed_out_packet.data,
+    )?;
+
+    let msg = forward_msg(
+        ctx,
+        timed_out_packet.port_id_on_a.clone(),
+        timed_out_packet.chan_id_on_a.clone(),
+        packet_data,
+        in_flight_packet.timeout,
+    )?;
+
+    let in_flight_packet = InFlightPacket {
+        retries_remaining: in_flight_packet.retries_remaining.saturating_sub(1),
+        ..in_flight_packet
+    };
+
+    forward_transfer(ctx, token_ctx, msg, in_flight_packet)
+}
+
+/// Takes back from `receiver` the tokens credited to it when the packet was
+/// received, i.e. escrows again the unescrowed tokens and burns the minted
+/// vouchers.
+///
+/// This is done when the tokens cannot be forwarded, so that the error
+/// acknowledgement of the packet refunds them on the previous chain.
+pub fn revert_received_tokens<T>(
+    token_ctx: &mut T,
+    packet: &Packet,
+    receiver: &Signer,
+) -> Result<(), PacketForwardError>
+where
+    T: TokenTransferExecutionContext,
+{
+    let data = decode_channel_packet_data(
+        token_ctx,
+        &packet.port_id_on_b,
+        &packet.chan_id_on_b,
+        &packet.data,
+    )?;
+
+    let receiver_account: T::AccountId = receiver
+        .clone()
+        .try_into()
+        .map_err(|_| TokenTransferError::ParseAccountFailure)?;
+
+    for (token, coin) in data.tokens.iter().zip(received_tokens(packet, &data)) {
+        if is_receiver_chain_source(
+            packet.port_id_on_a.clone(),
+            packet.chan_id_on_a.clone(),
+            &token.denom,
+        ) {
+            escrow_coins_execute(
+                token_ctx,
+                &receiver_account,
+                &packet.port_id_on_b,
+                &packet.chan_id_on_b,
+                &coin,
+                &data.memo,
+            )?;
+        } else {
+            token_ctx.burn_coins_execute(&receiver_account, &coin, &data.memo)?;
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,33 @@
+//! Implementation of the packet-forward middleware, which routes
+//! [ICS-20](https://github.com/cosmos/ibc/blob/main/spec/app/ics-020-fungible-token-transfer/README.md)
+//! token transfers over several chains by forwarding the tokens received
+//! with a packet according to the instructions of its memo.
+#![no_std]
+#![forbid(unsafe_code)]
+#![cfg_attr(not(test), deny(clippy::unwrap_used))]
+#![cfg_attr(not(test), deny(clippy::disallowed_methods, clippy::disallowed_types))]
+#![deny(
+    warnings,
+    trivial_casts,
+    trivial_numeric_casts,
+    unused_import_braces,
+    unused_qualifications,
+    rust_2018_idioms
+)]
+#![allow(clippy::result_large_err)]
+
+#[cfg(any(test, feature = "std"))]
+extern crate std;
+
+/// Re-exports the data structures of the packet-forward middleware.
+pub mod types {
+    #[doc(inline)]
+    pub use ibc_app_packet_forward_types::*;
+}
+
+#[cfg(feature = "serde")]
+pub mod context;
+#[cfg(feature = "serde")]
+pub mod handler;
+#[cfg(feature = "serde")]
+pub mod module;
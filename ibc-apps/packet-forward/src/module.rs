@@ -0,0 +1,481 @@
+//! Defines the packet-forward middleware callbacks, which wrap the callbacks
+//! of the underlying ICS-20 application.
+use core::fmt::Debug;
+use core::time::Duration;
+
+use ibc_app_packet_forward_types::error::PacketForwardError;
+use ibc_app_packet_forward_types::{
+    ForwardMetadata, InFlightPacket, DEFAULT_FORWARD_RETRIES, DEFAULT_FORWARD_TIMEOUT,
+};
+use ibc_app_transfer::context::TokenTransferExecutionContext;
+use ibc_app_transfer::handler::{
+    decode_channel_packet_data, decode_packet_data, refund_packet_token_execute,
+};
+use ibc_app_transfer_types::error::TokenTransferError;
+use ibc_app_transfer_types::packet::{FungibleTokenPacketDataV2, PacketData};
+use ibc_app_transfer_types::{Memo, VERSION_V2};
+use ibc_core::channel::types::acknowledgement::{Acknowledgement, AcknowledgementStatus};
+use ibc_core::channel::types::error::PacketError;
+use ibc_core::channel::types::packet::{Packet, PacketId};
+use ibc_core::channel::types::Version;
+use ibc_core::primitives::prelude::*;
+use ibc_core::primitives::Signer;
+use ibc_core::router::middleware::{IbcModuleWrapper, Middleware};
+use ibc_core::router::module::Module;
+use ibc_core::router::types::module::ModuleExtras;
+
+use crate::context::{PacketForwardExecutionContext, PacketForwardValidationContext};
+use crate::handler::{
+    forward_msg, forward_transfer, received_tokens, retry_forward_transfer, revert_received_tokens,
+};
+
+/// The settings of the packet-forward middleware, which apply to the packets
+/// whose memo does not specify them.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PacketForwardConfig {
+    /// The number of times a forwarded packet is sent again if it times out.
+    pub retries_on_timeout: u8,
+    /// The timeout of a forwarded packet, relative to the time it is sent.
+    pub forward_timeout: Duration,
+}
+
+impl Default for PacketForwardConfig {
+    fn default() -> Self {
+        Self {
+            retries_on_timeout: DEFAULT_FORWARD_RETRIES,
+            forward_timeout: DEFAULT_FORWARD_TIMEOUT,
+        }
+    }
+}
+
+fn packet_id_on_a(packet: &Packet) -> PacketId {
+    PacketId::new(
+        packet.port_id_on_a.clone(),
+        packet.chan_id_on_a.clone(),
+        packet.seq_on_a,
+    )
+}
+
+fn is_successful(acknowledgement: &Acknowledgement) -> bool {
+    serde_json::from_slice::<AcknowledgementStatus>(acknowledgement.as_ref())
+        .map(|ack| ack.is_successful())
+        .unwrap_or(false)
+}
+
+fn error_acknowledgement(err: PacketForwardError) -> Acknowledgement {
+    AcknowledgementStatus::error(err.into()).into()
+}
+
+/// Returns the packet passed to the application in place of the received
+/// packet: its tokens go to the intermediate receiver, and its memo is
+/// dropped. The packet data keeps the encoding of the channel version.
+fn intermediate_packet(
+    packet: &Packet,
+    chan_version: &Version,
+    data: FungibleTokenPacketDataV2,
+    intermediate_receiver: Signer,
+) -> Result<Packet, PacketForwardError> {
+    let data = FungibleTokenPacketDataV2 {
+        receiver: intermediate_receiver,
+        memo: Memo::from(String::new()),
+        ..data
+    };
+
+    let data = if chan_version.as_str() == VERSION_V2 {
+        serde_json::to_vec(&data)
+            .expect("FungibleTokenPacketDataV2's infallible Serialize impl failed")
+    } else {
+        serde_json::to_vec(&PacketData::try_from(data)?)
+            .expect("PacketData's infallible Serialize impl failed")
+    };
+
+    Ok(Packet {
+        data,
+        ..packet.clone()
+    })
+}
+
+/// Sends the tokens received with the intermediate packet on to the next
+/// chain, holding the acknowledgement of the original packet meanwhile.
+fn forward_received_tokens<C, N>(
+    ctx: &mut C,
+    app: &mut N,
+    config: &PacketForwardConfig,
+    original_packet: &Packet,
+    intermediate_packet: &Packet,
+    metadata: ForwardMetadata,
+) -> Result<(), PacketForwardError>
+where
+    C: PacketForwardExecutionContext,
+    N: TokenTransferExecutionContext,
+{
+    let data = decode_channel_packet_data(
+        app,
+        &intermediate_packet.port_id_on_b,
+        &intermediate_packet.chan_id_on_b,
+        &intermediate_packet.data,
+    )?;
+    let timeout = metadata.timeout.unwrap_or(config.forward_timeout);
+    let retries = metadata.retries.unwrap_or(config.retries_on_timeout);
+
+    let packet_data = FungibleTokenPacketDataV2 {
+        tokens: received_tokens(intermediate_packet, &data),
+        sender: data.receiver,
+        receiver: metadata.receiver.clone(),
+        memo: metadata.next_memo(),
+        forwarding: None,
+    };
+
+    let msg = forward_msg(
+        ctx,
+        metadata.port_id,
+        metadata.channel_id,
+        packet_data,
+        timeout,
+    )?;
+
+    forward_transfer(
+        ctx,
+        app,
+        msg,
+        InFlightPacket::new(original_packet.clone(), retries, timeout),
+    )
+}
+
+/// Relays the failure of a forwarded packet back to the previous chain: the
+/// tokens, which were refunded to the intermediate receiver, are taken back
+/// from it before the error acknowledgement of the original packet is
+/// written, so that the previous chain refunds them to the original sender.
+fn fail_forwarded_packet<C, N>(
+    ctx: &mut C,
+    app: &mut N,
+    forwarded_packet: &Packet,
+    in_flight_packet: &InFlightPacket,
+    acknowledgement: Acknowledgement,
+) -> Result<(), PacketForwardError>
+where
+    C: PacketForwardExecutionContext,
+    N: TokenTransferExecutionContext,
+{
+    let data = decode_channel_packet_data(
+        app,
+        &forwarded_packet.port_id_on_a,
+        &forwarded_packet.chan_id_on_a,
+        &forwarded_packet.data,
+    )?;
+
+    revert_received_tokens(app, &in_flight_packet.original_packet, &data.sender)?;
+
+    ctx.write_acknowledgement(&in_flight_packet.original_packet, acknowledgement)
+}
+
+/// Forwards the received tokens if the memo of the packet carries forward
+/// instructions, in which case no acknowledgement is returned: the
+/// acknowledgement of the packet is written once the forwarded packet is
+/// acknowledged or timed out.
+///
+/// Any other packet is passed on to the application untouched.
+pub fn on_recv_packet_execute<C, N>(
+    ctx: &mut C,
+    app: &mut N,
+    config: &PacketForwardConfig,
+    packet: &Packet,
+    relayer: &Signer,
+) -> (ModuleExtras, Option<Acknowledgement>)
+where
+    C: PacketForwardExecutionContext,
+    N: Module + TokenTransferExecutionContext,
+{
+    let chan_version = match app.channel_version(&packet.port_id_on_b, &packet.chan_id_on_b) {
+        Ok(chan_version) => chan_version,
+        Err(_) => return app.on_recv_packet_execute(packet, relayer),
+    };
+
+    let data = match decode_packet_data(&packet.data, &chan_version) {
+        Ok(data) => data,
+        Err(_) => return app.on_recv_packet_execute(packet, relayer),
+    };
+
+    let metadata = match ForwardMetadata::from_memo(&data.memo) {
+        Ok(Some(metadata)) => metadata,
+        Ok(None) => return app.on_recv_packet_execute(packet, relayer),
+        Err(err) => return (ModuleExtras::empty(), Some(error_acknowledgement(err))),
+    };
+
+    let intermediate_receiver = match ctx.intermediate_receiver(&packet.chan_id_on_b, &data.sender)
+    {
+        Ok(receiver) => receiver,
+        Err(err) => return (ModuleExtras::empty(), Some(error_acknowledgement(err))),
+    };
+
+    let intermediate_packet =
+        match intermediate_packet(packet, &chan_version, data, intermediate_receiver.clone()) {
+            Ok(intermediate_packet) => intermediate_packet,
+            Err(err) => return (ModuleExtras::empty(), Some(error_acknowledgement(err))),
+        };
+
+    let (mut extras, ack) = app.on_recv_packet_execute(&intermediate_packet, relayer);
+
+    match &ack {
+        Some(ack) if is_successful(ack) => {}
+        _ => return (extras, ack),
+    }
+
+    let forward_receiver = metadata.receiver.clone();
+    let forward_channel_id = metadata.channel_id.clone();
+
+    let result = forward_received_tokens(ctx, app, config, packet, &intermediate_packet, metadata);
+
+    match result {
+        Ok(()) => {
+            extras.log.push(format!(
+                "packet forward: forwarding packet {} to {} over {}",
+                packet.seq_on_a, forward_receiver, forward_channel_id
+            ));
+            (extras, None)
+        }
+        Err(err) => {
+            let err =
+                match revert_received_tokens(app, &intermediate_packet, &intermediate_receiver) {
+                    Ok(()) => err,
+                    Err(revert_err) => revert_err,
+                };
+            (extras, Some(error_acknowledgement(err)))
+        }
+    }
+}
+
+pub fn on_acknowledgement_packet_validate<C, N>(
+    ctx: &C,
+    app: &N,
+    packet: &Packet,
+    acknowledgement: &Acknowledgement,
+    relayer: &Signer,
+) -> Result<(), PacketError>
+where
+    C: PacketForwardValidationContext,
+    N: Module,
+{
+    if ctx.in_flight_packet(&packet_id_on_a(packet))?.is_none() {
+        return app.on_acknowledgement_packet_validate(packet, acknowledgement, relayer);
+    }
+
+    serde_json::from_slice::<AcknowledgementStatus>(acknowledgement.as_ref())
+        .map_err(|_| PacketForwardError::TokenTransfer(TokenTransferError::AckDeserialization))?;
+
+    Ok(())
+}
+
+/// Writes the acknowledgement of a forwarded packet as the acknowledgement of
+/// the original packet. If the forward failed, the tokens are refunded along
+/// the way back to the original sender.
+///
+/// The acknowledgement of any other packet is passed on to the application.
+pub fn on_acknowledgement_packet_execute<C, N>(
+    ctx: &mut C,
+    app: &mut N,
+    packet: &Packet,
+    acknowledgement: &Acknowledgement,
+    relayer: &Signer,
+) -> (ModuleExtras, Result<(), PacketError>)
+where
+    C: PacketForwardExecutionContext,
+    N: Module + TokenTransferExecutionContext,
+{
+    let packet_id = packet_id_on_a(packet);
+
+    let in_flight_packet = match ctx.in_flight_packet(&packet_id) {
+        Ok(Some(in_flight_packet)) => in_flight_packet,
+        Ok(None) => return app.on_acknowledgement_packet_execute(packet, acknowledgement, relayer),
+        Err(err) => return (ModuleExtras::empty(), Err(err.into())),
+    };
+
+    let result = ctx.delete_in_flight_packet(&packet_id).and_then(|()| {
+        if is_successful(acknowledgement) {
+            ctx.write_acknowledgement(&in_flight_packet.original_packet, acknowledgement.clone())
+        } else {
+            let data = decode_channel_packet_data(
+                app,
+                &packet.port_id_on_a,
+                &packet.chan_id_on_a,
+                &packet.data,
+            )?;
+            refund_packet_token_execute(app, packet, &data)?;
+            fail_forwarded_packet(ctx, app, packet, &in_flight_packet, acknowledgement.clone())
+        }
+    });
+
+    (ModuleExtras::empty(), result.map_err(Into::into))
+}
+
+pub fn on_timeout_packet_validate<C, N>(
+    ctx: &C,
+    app: &N,
+    packet: &Packet,
+    relayer: &Signer,
+) -> Result<(), PacketError>
+where
+    C: PacketForwardValidationContext,
+    N: Module,
+{
+    if ctx.in_flight_packet(&packet_id_on_a(packet))?.is_none() {
+        return app.on_timeout_packet_validate(packet, relayer);
+    }
+
+    Ok(())
+}
+
+/// Sends a forwarded packet which timed out again if it has retries left.
+/// Otherwise, an error acknowledgement is written for the original packet,
+/// and the tokens are refunded along the way back to the original sender.
+///
+/// The timeout of any other packet is passed on to the application.
+pub fn on_timeout_packet_execute<C, N>(
+    ctx: &mut C,
+    app: &mut N,
+    packet: &Packet,
+    relayer: &Signer,
+) -> (ModuleExtras, Result<(), PacketError>)
+where
+    C: PacketForwardExecutionContext,
+    N: Module + TokenTransferExecutionContext,
+{
+    let packet_id = packet_id_on_a(packet);
+
+    let in_flight_packet = match ctx.in_flight_packet(&packet_id) {
+        Ok(Some(in_flight_packet)) => in_flight_packet,
+        Ok(None) => return app.on_timeout_packet_execute(packet, relayer),
+        Err(err) => return (ModuleExtras::empty(), Err(err.into())),
+    };
+
+    let result = ctx.delete_in_flight_packet(&packet_id).and_then(|()| {
+        let data = decode_channel_packet_data(
+            app,
+            &packet.port_id_on_a,
+            &packet.chan_id_on_a,
+            &packet.data,
+        )?;
+        refund_packet_token_execute(app, packet, &data)?;
+
+        let err = if in_flight_packet.retries_remaining > 0 {
+            match retry_forward_transfer(ctx, app, packet, in_flight_packet.clone()) {
+                Ok(()) => return Ok(()),
+                Err(err) => err,
+            }
+        } else {
+            PacketForwardError::ForwardTimedOut
+        };
+
+        fail_forwarded_packet(
+            ctx,
+            app,
+            packet,
+            &in_flight_packet,
+            error_acknowledgement(err),
+        )
+    });
+
+    (ModuleExtras::empty(), result.map_err(Into::into))
+}
+
+/// The packet-forward middleware, which lets the ICS-20 tokens received with
+/// a packet be sent on to another chain, according to the `forward`
+/// instructions of the packet memo (see [`ForwardMetadata`]).
+///
+/// The tokens are first received by an intermediate account (see
+/// [`intermediate_receiver`](crate::context::PacketForwardValidationContext::intermediate_receiver)),
+/// which then sends them on with `send_transfer`. The acknowledgement of the
+/// received packet is held until the forwarded packet is acknowledged or
+/// timed out, and written with the same outcome.
+///
+/// The middleware must be stacked right on top of the ICS-20 application
+/// `N`, which also provides the [`TokenTransferExecutionContext`] used to
+/// forward the tokens.
+#[derive(Debug)]
+pub struct PacketForwardMiddleware<C> {
+    ctx: C,
+    config: PacketForwardConfig,
+}
+
+impl<C> PacketForwardMiddleware<C> {
+    pub fn new(ctx: C) -> Self {
+        Self::with_config(ctx, PacketForwardConfig::default())
+    }
+
+    pub fn with_config(ctx: C, config: PacketForwardConfig) -> Self {
+        Self { ctx, config }
+    }
+
+    pub fn ctx(&self) -> &C {
+        &self.ctx
+    }
+
+    pub fn ctx_mut(&mut self) -> &mut C {
+        &mut self.ctx
+    }
+
+    pub fn config(&self) -> &PacketForwardConfig {
+        &self.config
+    }
+
+    pub fn into_inner(self) -> C {
+        self.ctx
+    }
+}
+
+/// The IBC [`Module`] made of the packet-forward middleware stacked on top of
+/// the ICS-20 application `M`.
+pub type PacketForwardModule<C, M> = IbcModuleWrapper<PacketForwardMiddleware<C>, M>;
+
+impl<C, N> Middleware<N> for PacketForwardMiddleware<C>
+where
+    C: PacketForwardExecutionContext + Debug,
+    N: Module + TokenTransferExecutionContext,
+{
+    fn on_recv_packet_execute(
+        &mut self,
+        next: &mut N,
+        packet: &Packet,
+        relayer: &Signer,
+    ) -> (ModuleExtras, Option<Acknowledgement>) {
+        on_recv_packet_execute(&mut self.ctx, next, &self.config, packet, relayer)
+    }
+
+    fn on_acknowledgement_packet_validate(
+        &self,
+        next: &N,
+        packet: &Packet,
+        acknowledgement: &Acknowledgement,
+        relayer: &Signer,
+    ) -> Result<(), PacketError> {
+        on_acknowledgement_packet_validate(&self.ctx, next, packet, acknowledgement, relayer)
+    }
+
+    fn on_acknowledgement_packet_execute(
+        &mut self,
+        next: &mut N,
+        packet: &Packet,
+        acknowledgement: &Acknowledgement,
+        relayer: &Signer,
+    ) -> (ModuleExtras, Result<(), PacketError>) {
+        on_acknowledgement_packet_execute(&mut self.ctx, next, packet, acknowledgement, relayer)
+    }
+
+    fn on_timeout_packet_validate(
+        &self,
+        next: &N,
+        packet: &Packet,
+        relayer: &Signer,
+    ) -> Result<(), PacketError> {
+        on_timeout_packet_validate(&self.ctx, next, packet, relayer)
+    }
+
+    fn on_timeout_packet_execute(
+        &mut self,
+        next: &mut N,
+        packet: &Packet,
+        relayer: &Signer,
+    ) -> (ModuleExtras, Result<(), PacketError>) {
+        on_timeout_packet_execute(&mut self.ctx, next, packet, relayer)
+    }
+}
@@ -0,0 +1,86 @@
+//! Defines the packet-forward middleware error type
+use core::convert::Infallible;
+
+use displaydoc::Display;
+use ibc_app_transfer_types::error::TokenTransferError;
+use ibc_core::channel::types::acknowledgement::StatusValue;
+use ibc_core::channel::types::error::{ChannelError, PacketError};
+use ibc_core::handler::types::error::ContextError;
+use ibc_core::host::types::error::IdentifierError;
+use ibc_core::primitives::prelude::*;
+
+#[derive(Display, Debug)]
+pub enum PacketForwardError {
+    /// context error: `{0}`
+    ContextError(ContextError),
+    /// invalid identifier: `{0}`
+    InvalidIdentifier(IdentifierError),
+    /// token transfer error: `{0}`
+    TokenTransfer(TokenTransferError),
+    /// invalid forward metadata: `{reason}`
+    InvalidForwardMetadata { reason: String },
+    /// timestamp overflow while computing the timeout of the forwarded packet
+    TimeoutOverflow,
+    /// forwarded packet timed out
+    ForwardTimedOut,
+    /// other error: `{0}`
+    Other(String),
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for PacketForwardError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match &self {
+            Self::ContextError(e) => Some(e),
+            Self::InvalidIdentifier(e) => Some(e),
+            Self::TokenTransfer(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<Infallible> for PacketForwardError {
+    fn from(e: Infallible) -> Self {
+        match e {}
+    }
+}
+
+impl From<ContextError> for PacketForwardError {
+    fn from(err: ContextError) -> PacketForwardError {
+        Self::ContextError(err)
+    }
+}
+
+impl From<IdentifierError> for PacketForwardError {
+    fn from(err: IdentifierError) -> PacketForwardError {
+        Self::InvalidIdentifier(err)
+    }
+}
+
+impl From<TokenTransferError> for PacketForwardError {
+    fn from(err: TokenTransferError) -> PacketForwardError {
+        Self::TokenTransfer(err)
+    }
+}
+
+impl From<PacketForwardError> for StatusValue {
+    fn from(err: PacketForwardError) -> Self {
+        StatusValue::new(err.to_string()).expect("error message must not be empty")
+    }
+}
+
+impl From<PacketForwardError> for ChannelError {
+    fn from(err: PacketForwardError) -> ChannelError {
+        ChannelError::AppModule {
+            description: err.to_string(),
+        }
+    }
+}
+
+impl From<PacketForwardError> for PacketError {
+    fn from(err: PacketForwardError) -> PacketError {
+        PacketError::AppModule {
+            description: err.to_string(),
+        }
+    }
+}
@@ -0,0 +1,33 @@
+//! Defines the metadata of the packets being forwarded.
+use core::time::Duration;
+
+use ibc_core::channel::types::packet::Packet;
+use ibc_core::primitives::prelude::*;
+
+/// The metadata of a packet forwarded to the next chain, stored until the
+/// forwarded packet is acknowledged or timed out.
+///
+/// It is keyed by the identifier of the forwarded packet, and refers back to
+/// the packet received from the previous chain, whose acknowledgement is held
+/// in the meantime.
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct InFlightPacket {
+    /// The packet received from the previous chain.
+    pub original_packet: Packet,
+    /// The number of times the forwarded packet may still be sent again if
+    /// it times out.
+    pub retries_remaining: u8,
+    /// The timeout of the forwarded packet, relative to the time it is sent.
+    pub timeout: Duration,
+}
+
+impl InFlightPacket {
+    pub fn new(original_packet: Packet, retries_remaining: u8, timeout: Duration) -> Self {
+        Self {
+            original_packet,
+            retries_remaining,
+            timeout,
+        }
+    }
+}
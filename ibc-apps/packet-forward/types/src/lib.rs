@@ -0,0 +1,42 @@
+//! Implementation of the data structures of the packet-forward middleware,
+//! which routes [ICS-20](https://github.com/cosmos/ibc/blob/main/spec/app/ics-020-fungible-token-transfer/README.md)
+//! token transfers over several chains.
+#![no_std]
+#![forbid(unsafe_code)]
+#![cfg_attr(not(test), deny(clippy::unwrap_used))]
+#![cfg_attr(not(test), deny(clippy::disallowed_methods, clippy::disallowed_types))]
+#![deny(
+    warnings,
+    trivial_casts,
+    trivial_numeric_casts,
+    unused_import_braces,
+    unused_qualifications,
+    rust_2018_idioms
+)]
+
+#[cfg(any(test, feature = "std"))]
+extern crate std;
+
+use core::time::Duration;
+
+#[cfg(feature = "serde")]
+mod in_flight;
+#[cfg(feature = "serde")]
+pub use in_flight::*;
+#[cfg(feature = "serde")]
+mod metadata;
+#[cfg(feature = "serde")]
+pub use metadata::*;
+
+pub mod error;
+
+/// Module identifier for the packet-forward middleware.
+pub const MODULE_ID_STR: &str = "packetforward";
+
+/// Number of times a forwarded packet is sent again after timing out, unless
+/// the memo says otherwise.
+pub const DEFAULT_FORWARD_RETRIES: u8 = 1;
+
+/// Timeout of a forwarded packet, relative to the time it is sent, unless
+/// the memo says otherwise.
+pub const DEFAULT_FORWARD_TIMEOUT: Duration = Duration::from_secs(8 * 60 * 60);
@@ -0,0 +1,295 @@
+//! Defines the forward instructions carried by the memo of ICS-20 packets.
+use core::fmt::{Formatter, Result as FmtResult};
+use core::str::FromStr;
+use core::time::Duration;
+
+use ibc_app_transfer_types::Memo;
+use ibc_core::host::types::identifiers::{ChannelId, PortId};
+use ibc_core::primitives::prelude::*;
+use ibc_core::primitives::Signer;
+use serde::de::{Error as DeError, IgnoredAny, Visitor};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::error::PacketForwardError;
+
+/// The forward instructions of an ICS-20 packet, found under the `forward`
+/// key of its JSON memo, e.g.
+///
+/// ```json
+/// {
+///   "forward": {
+///     "receiver": "cosmos1...",
+///     "port": "transfer",
+///     "channel": "channel-1",
+///     "timeout": "10m",
+///     "retries": 2,
+///     "next": { "forward": { "receiver": "...", "port": "...", "channel": "..." } }
+///   }
+/// }
+/// ```
+///
+/// The tokens received with the packet are sent on to `receiver` over the
+/// given port and channel. The `timeout` is either a number of nanoseconds or
+/// a duration string such as `90s` or `1h30m`. The `next` object, if any,
+/// becomes the memo of the forwarded packet, so that the next chain forwards
+/// the tokens further.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ForwardMetadata {
+    pub receiver: Signer,
+    pub port_id: PortId,
+    pub channel_id: ChannelId,
+    pub timeout: Option<Duration>,
+    pub retries: Option<u8>,
+    pub next: Option<Memo>,
+}
+
+impl ForwardMetadata {
+    /// Extracts the forward instructions from the memo of a packet.
+    ///
+    /// Returns `None` if the memo is not a JSON object with a `forward` key,
+    /// in which case the packet is not meant to be forwarded.
+    pub fn from_memo(memo: &Memo) -> Result<Option<Self>, PacketForwardError> {
+        #[derive(Deserialize)]
+        struct ForwardKey {
+            forward: Option<IgnoredAny>,
+        }
+
+        match serde_json::from_str::<ForwardKey>(memo.as_ref()) {
+            Ok(ForwardKey { forward: Some(_) }) => {}
+            _ => return Ok(None),
+        }
+
+        let metadata = serde_json::from_str::<PacketMetadataJson>(memo.as_ref()).map_err(|e| {
+            PacketForwardError::InvalidForwardMetadata {
+                reason: e.to_string(),
+            }
+        })?;
+
+        metadata.forward.try_into().map(Some)
+    }
+
+    /// Returns the memo of the forwarded packet.
+    pub fn next_memo(&self) -> Memo {
+        self.next
+            .clone()
+            .unwrap_or_else(|| Memo::from(String::new()))
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct PacketMetadataJson {
+    forward: ForwardJson,
+}
+
+#[derive(Serialize, Deserialize)]
+struct ForwardJson {
+    receiver: String,
+    port: String,
+    channel: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    timeout: Option<TimeoutJson>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    retries: Option<u8>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    next: Option<Box<PacketMetadataJson>>,
+}
+
+impl TryFrom<ForwardJson> for ForwardMetadata {
+    type Error = PacketForwardError;
+
+    fn try_from(json: ForwardJson) -> Result<Self, Self::Error> {
+        if json.receiver.trim().is_empty() {
+            return Err(PacketForwardError::InvalidForwardMetadata {
+                reason: "receiver must not be empty".to_string(),
+            });
+        }
+
+        let next = json
+            .next
+            .map(|next| {
+                serde_json::to_string(&next).map(Memo::from).map_err(|e| {
+                    PacketForwardError::InvalidForwardMetadata {
+                        reason: e.to_string(),
+                    }
+                })
+            })
+            .transpose()?;
+
+        Ok(Self {
+            receiver: json.receiver.into(),
+            port_id: PortId::from_str(&json.port)?,
+            channel_id: ChannelId::from_str(&json.channel)?,
+            timeout: json.timeout.map(|timeout| timeout.0),
+            retries: json.retries,
+            next,
+        })
+    }
+}
+
+/// The timeout of the memo, encoded as a number of nanoseconds but also
+/// decoded from a duration string.
+struct TimeoutJson(Duration);
+
+impl Serialize for TimeoutJson {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u64(u64::try_from(self.0.as_nanos()).unwrap_or(u64::MAX))
+    }
+}
+
+impl<'de> Deserialize<'de> for TimeoutJson {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct TimeoutVisitor;
+
+        impl<'de> Visitor<'de> for TimeoutVisitor {
+            type Value = TimeoutJson;
+
+            fn expecting(&self, f: &mut Formatter<'_>) -> FmtResult {
+                write!(f, "a number of nanoseconds or a duration string")
+            }
+
+            fn visit_u64<E: DeError>(self, nanos: u64) -> Result<Self::Value, E> {
+                Ok(TimeoutJson(Duration::from_nanos(nanos)))
+            }
+
+            fn visit_str<E: DeError>(self, s: &str) -> Result<Self::Value, E> {
+                parse_duration(s).map(TimeoutJson).map_err(E::custom)
+            }
+        }
+
+        deserializer.deserialize_any(TimeoutVisitor)
+    }
+}
+
+/// Parses a duration made of a sequence of integers, each followed by a unit
+/// (`ns`, `us`, `ms`, `s`, `m` or `h`), e.g. `300ms` or `1h30m`.
+fn parse_duration(s: &str) -> Result<Duration, String> {
+    let invalid = || format!("invalid duration `{s}`");
+
+    if s.is_empty() {
+        return Err(invalid());
+    }
+    if s == "0" {
+        return Ok(Duration::ZERO);
+    }
+
+    let mut nanos: u64 = 0;
+    let mut rest = s;
+
+    while !rest.is_empty() {
+        let digits_len = rest
+            .find(|c: char| !c.is_ascii_digit())
+            .ok_or_else(invalid)?;
+        let value = rest[..digits_len].parse::<u64>().map_err(|_| invalid())?;
+        rest = &rest[digits_len..];
+
+        let unit_len = rest
+            .find(|c: char| c.is_ascii_digit())
+            .unwrap_or(rest.len());
+        let unit_nanos: u64 = match &rest[..unit_len] {
+            "ns" => 1,
+            "us" | "µs" => 1_000,
+            "ms" => 1_000_000,
+            "s" => 1_000_000_000,
+            "m" => 60 * 1_000_000_000,
+            "h" => 60 * 60 * 1_000_000_000,
+            _ => return Err(invalid()),
+        };
+        rest = &rest[unit_len..];
+
+        nanos = value
+            .checked_mul(unit_nanos)
+            .and_then(|value_nanos| nanos.checked_add(value_nanos))
+            .ok_or_else(invalid)?;
+    }
+
+    Ok(Duration::from_nanos(nanos))
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    fn memo(s: &str) -> Memo {
+        Memo::from(s.to_string())
+    }
+
+    #[test]
+    fn test_forward_metadata_from_memo() {
+        let metadata = ForwardMetadata::from_memo(&memo(
+            r#"{"forward":{"receiver":"cosmos1receiver","port":"transfer","channel":"channel-1","timeout":"1h30m","retries":2,"next":{"forward":{"receiver":"osmo1receiver","port":"transfer","channel":"channel-7"}}}}"#,
+        ))
+        .unwrap()
+        .unwrap();
+
+        assert_eq!(metadata.receiver, "cosmos1receiver".to_string().into());
+        assert_eq!(metadata.port_id, PortId::transfer());
+        assert_eq!(metadata.channel_id, ChannelId::new(1));
+        assert_eq!(metadata.timeout, Some(Duration::from_secs(90 * 60)));
+        assert_eq!(metadata.retries, Some(2));
+        assert_eq!(
+            metadata.next_memo(),
+            memo(
+                r#"{"forward":{"receiver":"osmo1receiver","port":"transfer","channel":"channel-7"}}"#
+            )
+        );
+    }
+
+    #[test]
+    fn test_forward_metadata_with_nanos_timeout() {
+        let metadata = ForwardMetadata::from_memo(&memo(
+            r#"{"forward":{"receiver":"cosmos1receiver","port":"transfer","channel":"channel-1","timeout":600000000000}}"#,
+        ))
+        .unwrap()
+        .unwrap();
+
+        assert_eq!(metadata.timeout, Some(Duration::from_secs(600)));
+        assert_eq!(metadata.retries, None);
+        assert_eq!(metadata.next_memo(), memo(""));
+    }
+
+    #[rstest]
+    #[case::empty("")]
+    #[case::plain_text("a plain memo")]
+    #[case::other_keys(r#"{"wasm":{"contract":"cosmos1contract"}}"#)]
+    #[case::null_forward(r#"{"forward":null}"#)]
+    fn test_memo_without_forward(#[case] s: &str) {
+        assert_eq!(ForwardMetadata::from_memo(&memo(s)).unwrap(), None);
+    }
+
+    #[rstest]
+    #[case::missing_channel(r#"{"forward":{"receiver":"cosmos1receiver","port":"transfer"}}"#)]
+    #[case::empty_receiver(
+        r#"{"forward":{"receiver":"","port":"transfer","channel":"channel-1"}}"#
+    )]
+    #[case::invalid_channel(
+        r#"{"forward":{"receiver":"cosmos1receiver","port":"transfer","channel":"chan"}}"#
+    )]
+    #[case::invalid_timeout(
+        r#"{"forward":{"receiver":"cosmos1receiver","port":"transfer","channel":"channel-1","timeout":"10 minutes"}}"#
+    )]
+    fn test_invalid_forward_metadata(#[case] s: &str) {
+        assert!(ForwardMetadata::from_memo(&memo(s)).is_err());
+    }
+
+    #[rstest]
+    #[case("0", Duration::ZERO)]
+    #[case("300ms", Duration::from_millis(300))]
+    #[case("45s", Duration::from_secs(45))]
+    #[case("1h30m", Duration::from_secs(90 * 60))]
+    #[case("2m10s500us", Duration::from_micros(130_000_500))]
+    fn test_parse_duration(#[case] s: &str, #[case] expected: Duration) {
+        assert_eq!(parse_duration(s).unwrap(), expected);
+    }
+
+    #[rstest]
+    #[case("")]
+    #[case("10")]
+    #[case("m")]
+    #[case("1d")]
+    #[case("1.5h")]
+    fn test_parse_invalid_duration(#[case] s: &str) {
+        assert!(parse_duration(s).is_err());
+    }
+}
@@ -0,0 +1,80 @@
+//! Defines the main context traits of the rate-limit middleware
+
+use ibc_app_rate_limit_types::error::RateLimitError;
+use ibc_app_rate_limit_types::{RateLimit, RateLimitPath, RecordedFlow};
+use ibc_app_transfer_types::{Amount, PrefixedDenom};
+use ibc_core::channel::types::Version;
+use ibc_core::host::types::identifiers::{ChannelId, PortId, Sequence};
+use ibc_core::primitives::prelude::*;
+use ibc_core::primitives::{Signer, Timestamp};
+
+/// Methods required in rate-limit middleware validation, to be implemented
+/// by the host
+pub trait RateLimitValidationContext {
+    /// Returns the account allowed to add and remove rate limits.
+    fn authority(&self) -> Result<Signer, RateLimitError>;
+
+    /// Returns the current timestamp of the host, which drives the rate-limit
+    /// windows.
+    fn host_timestamp(&self) -> Result<Timestamp, RateLimitError>;
+
+    /// Returns the total supply of the denomination on the host, from which
+    /// the percentage quotas are computed.
+    fn denom_supply(&self, denom: &PrefixedDenom) -> Result<Amount, RateLimitError>;
+
+    /// Returns the rate limits set on the given path.
+    fn rate_limits(&self, path: &RateLimitPath) -> Result<Vec<RateLimit>, RateLimitError>;
+
+    /// Returns the version negotiated on the given channel, which determines
+    /// the encoding of the ICS-20 packet data sent over it.
+    fn channel_version(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<Version, RateLimitError>;
+
+    /// Returns the flows counted by the packet with the given sequence sent
+    /// over the channel, which is neither acknowledged nor timed out yet.
+    /// Returns an empty list if the packet counted no flow.
+    fn pending_send_flows(
+        &self,
+        channel_id: &ChannelId,
+        sequence: Sequence,
+    ) -> Result<Vec<RecordedFlow>, RateLimitError>;
+}
+
+/// Methods required in rate-limit middleware execution, to be implemented by
+/// the host.
+pub trait RateLimitExecutionContext: RateLimitValidationContext {
+    /// Stores the rate limit on the given path, replacing the one with the
+    /// same quota name, if any.
+    fn store_rate_limit(
+        &mut self,
+        path: RateLimitPath,
+        rate_limit: RateLimit,
+    ) -> Result<(), RateLimitError>;
+
+    /// Deletes the rate limit with the given quota name from the path.
+    fn delete_rate_limit(
+        &mut self,
+        path: &RateLimitPath,
+        quota_name: &str,
+    ) -> Result<(), RateLimitError>;
+
+    /// Stores the flows counted by the packet with the given sequence sent
+    /// over the channel.
+    fn store_pending_send_flows(
+        &mut self,
+        channel_id: ChannelId,
+        sequence: Sequence,
+        flows: Vec<RecordedFlow>,
+    ) -> Result<(), RateLimitError>;
+
+    /// Deletes the flows counted by the packet with the given sequence sent
+    /// over the channel, once the packet is acknowledged or timed out.
+    fn delete_pending_send_flows(
+        &mut self,
+        channel_id: &ChannelId,
+        sequence: Sequence,
+    ) -> Result<(), RateLimitError>;
+}
@@ -0,0 +1,366 @@
+//! Implements the administration of the rate limits, and the accounting of
+//! the flows counted against them.
+use ibc_app_rate_limit_types::error::RateLimitError;
+use ibc_app_rate_limit_types::msgs::{MsgAddRateLimit, MsgRemoveRateLimit};
+use ibc_app_rate_limit_types::{FlowDirection, RateLimit, RateLimitPath, RecordedFlow};
+use ibc_app_transfer::context::{TokenTransferExecutionContext, TokenTransferValidationContext};
+use ibc_app_transfer::handler::{
+    send_transfer_execute as transfer_execute, send_transfer_validate as transfer_validate,
+};
+use ibc_app_transfer_types::msgs::transfer::MsgTransferV2;
+use ibc_app_transfer_types::packet::FungibleTokenPacketDataV2;
+use ibc_app_transfer_types::{is_receiver_chain_source, Amount, PrefixedCoin, TracePrefix};
+use ibc_core::channel::context::{SendPacketExecutionContext, SendPacketValidationContext};
+use ibc_core::channel::types::packet::Packet;
+use ibc_core::host::types::identifiers::{ChannelId, Sequence};
+use ibc_core::host::types::path::SeqSendPath;
+use ibc_core::primitives::prelude::*;
+use ibc_core::primitives::Signer;
+
+use crate::context::{RateLimitExecutionContext, RateLimitValidationContext};
+
+fn validate_authority(
+    ctx: &impl RateLimitValidationContext,
+    signer: &Signer,
+) -> Result<(), RateLimitError> {
+    let authority = ctx.authority()?;
+
+    if signer != &authority {
+        return Err(RateLimitError::Unauthorized {
+            signer: signer.clone(),
+            authority,
+        });
+    }
+
+    Ok(())
+}
+
+fn find_rate_limit(
+    ctx: &impl RateLimitValidationContext,
+    path: &RateLimitPath,
+    quota_name: &str,
+) -> Result<Option<RateLimit>, RateLimitError> {
+    Ok(ctx
+        .rate_limits(path)?
+        .into_iter()
+        .find(|rate_limit| rate_limit.quota.name == quota_name))
+}
+
+/// Adds a rate limit. Equivalent to calling [`add_rate_limit_validate`],
+/// followed by [`add_rate_limit_execute`].
+pub fn add_rate_limit(
+    ctx: &mut impl RateLimitExecutionContext,
+    msg: MsgAddRateLimit,
+) -> Result<(), RateLimitError> {
+    add_rate_limit_validate(ctx, &msg)?;
+    add_rate_limit_execute(ctx, msg)
+}
+
+pub fn add_rate_limit_validate(
+    ctx: &impl RateLimitValidationContext,
+    msg: &MsgAddRateLimit,
+) -> Result<(), RateLimitError> {
+    validate_authority(ctx, &msg.signer)?;
+
+    msg.quota.validate_basic()?;
+
+    if find_rate_limit(ctx, &msg.path, &msg.quota.name)?.is_some() {
+        return Err(RateLimitError::RateLimitExists {
+            channel_id: msg.path.channel_id.clone(),
+            denom: msg.path.denom.clone(),
+            quota_name: msg.quota.name.clone(),
+        });
+    }
+
+    Ok(())
+}
+
+/// Adds the rate limit, whose first window starts at the current host time.
+pub fn add_rate_limit_execute(
+    ctx: &mut impl RateLimitExecutionContext,
+    msg: MsgAddRateLimit,
+) -> Result<(), RateLimitError> {
+    let supply = ctx.denom_supply(&msg.path.denom)?;
+    let now = ctx.host_timestamp()?;
+
+    let rate_limit = RateLimit::new(msg.quota, supply, now)?;
+
+    ctx.store_rate_limit(msg.path, rate_limit)
+}
+
+/// Removes a rate limit. Equivalent to calling
+/// [`remove_rate_limit_validate`], followed by [`remove_rate_limit_execute`].
+pub fn remove_rate_limit(
+    ctx: &mut impl RateLimitExecutionContext,
+    msg: MsgRemoveRateLimit,
+) -> Result<(), RateLimitError> {
+    remove_rate_limit_validate(ctx, &msg)?;
+    remove_rate_limit_execute(ctx, msg)
+}
+
+pub fn remove_rate_limit_validate(
+    ctx: &impl RateLimitValidationContext,
+    msg: &MsgRemoveRateLimit,
+) -> Result<(), RateLimitError> {
+    validate_authority(ctx, &msg.signer)?;
+
+    if find_rate_limit(ctx, &msg.path, &msg.quota_name)?.is_none() {
+        return Err(RateLimitError::RateLimitNotFound {
+            channel_id: msg.path.channel_id.clone(),
+            denom: msg.path.denom.clone(),
+            quota_name: msg.quota_name.clone(),
+        });
+    }
+
+    Ok(())
+}
+
+pub fn remove_rate_limit_execute(
+    ctx: &mut impl RateLimitExecutionContext,
+    msg: MsgRemoveRateLimit,
+) -> Result<(), RateLimitError> {
+    ctx.delete_rate_limit(&msg.path, &msg.quota_name)
+}
+
+/// Returns the rate limits of the path with `amount` counted in the given
+/// direction, or an error if any of their quotas would be exceeded.
+///
+/// The windows which have ended are started over beforehand.
+pub fn check_flow(
+    ctx: &impl RateLimitValidationContext,
+    path: &RateLimitPath,
+    direction: FlowDirection,
+    amount: Amount,
+) -> Result<Vec<RateLimit>, RateLimitError> {
+    let mut rate_limits = ctx.rate_limits(path)?;
+
+    if rate_limits.is_empty() {
+        return Ok(rate_limits);
+    }
+
+    let supply = ctx.denom_supply(&path.denom)?;
+    let now = ctx.host_timestamp()?;
+
+    for rate_limit in rate_limits.iter_mut() {
+        rate_limit.refresh(supply, now)?;
+        rate_limit.check_and_add(direction, amount)?;
+    }
+
+    Ok(rate_limits)
+}
+
+/// Counts the coins in the given direction against the rate limits of their
+/// denomination on the channel, failing if any quota would be exceeded.
+///
+/// Returns the flows counted against each rate limit.
+pub fn record_flows(
+    ctx: &mut impl RateLimitExecutionContext,
+    channel_id: &ChannelId,
+    direction: FlowDirection,
+    coins: &[PrefixedCoin],
+) -> Result<Vec<RecordedFlow>, RateLimitError> {
+    validate_flows(ctx, channel_id, direction, coins)?;
+
+    let mut recorded_flows = Vec::new();
+
+    for coin in coins {
+        let path = RateLimitPath::new(channel_id.clone(), coin.denom.clone());
+
+        for rate_limit in check_flow(ctx, &path, direction, coin.amount)? {
+            recorded_flows.push(RecordedFlow {
+                path: path.clone(),
+                quota_name: rate_limit.quota.name.clone(),
+                period_end: rate_limit.flow.period_end,
+                amount: coin.amount,
+            });
+            ctx.store_rate_limit(path.clone(), rate_limit)?;
+        }
+    }
+
+    Ok(recorded_flows)
+}
+
+/// Counts the coins of the packet with the given sequence sent over the
+/// channel in its outflows, and keeps track of the counted flows until the
+/// packet is acknowledged or timed out.
+pub fn record_outflow(
+    ctx: &mut impl RateLimitExecutionContext,
+    channel_id: &ChannelId,
+    sequence: Sequence,
+    coins: &[PrefixedCoin],
+) -> Result<(), RateLimitError> {
+    let recorded_flows = record_flows(ctx, channel_id, FlowDirection::Outflow, coins)?;
+
+    if !recorded_flows.is_empty() {
+        ctx.store_pending_send_flows(channel_id.clone(), sequence, recorded_flows)?;
+    }
+
+    Ok(())
+}
+
+/// Removes the flows counted by the packet with the given sequence sent over
+/// the channel from its outflows, as the tokens of the packet are refunded.
+///
+/// A flow is only undone if the rate limit it was counted against still
+/// exists and is still in the same window, as the flows counted before a
+/// rate limit was set, or during a window which has ended, no longer count.
+pub fn undo_outflow(
+    ctx: &mut impl RateLimitExecutionContext,
+    channel_id: &ChannelId,
+    sequence: Sequence,
+) -> Result<(), RateLimitError> {
+    for recorded_flow in ctx.pending_send_flows(channel_id, sequence)? {
+        let rate_limit = find_rate_limit(ctx, &recorded_flow.path, &recorded_flow.quota_name)?
+            .filter(|rate_limit| rate_limit.flow.period_end == recorded_flow.period_end);
+
+        if let Some(mut rate_limit) = rate_limit {
+            rate_limit.undo(FlowDirection::Outflow, recorded_flow.amount);
+            ctx.store_rate_limit(recorded_flow.path, rate_limit)?;
+        }
+    }
+
+    ctx.delete_pending_send_flows(channel_id, sequence)
+}
+
+/// Checks that the coins can be counted in the given direction without
+/// exceeding any quota.
+pub fn validate_flows(
+    ctx: &impl RateLimitValidationContext,
+    channel_id: &ChannelId,
+    direction: FlowDirection,
+    coins: &[PrefixedCoin],
+) -> Result<(), RateLimitError> {
+    for coin in coins {
+        let path = RateLimitPath::new(channel_id.clone(), coin.denom.clone());
+        check_flow(ctx, &path, direction, coin.amount)?;
+    }
+
+    Ok(())
+}
+
+/// Removes the coins from the flows in the given direction, e.g. when the
+/// packet carrying them gets an error acknowledgement.
+pub fn undo_flows(
+    ctx: &mut impl RateLimitExecutionContext,
+    channel_id: &ChannelId,
+    direction: FlowDirection,
+    coins: &[PrefixedCoin],
+) -> Result<(), RateLimitError> {
+    for coin in coins {
+        let path = RateLimitPath::new(channel_id.clone(), coin.denom.clone());
+
+        for mut rate_limit in ctx.rate_limits(&path)? {
+            rate_limit.undo(direction, coin.amount);
+            ctx.store_rate_limit(path.clone(), rate_limit)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Returns the tokens of a packet received by this chain, with their
+/// denominations as they are known on this chain.
+pub fn received_tokens(packet: &Packet, data: &FungibleTokenPacketDataV2) -> Vec<PrefixedCoin> {
+    data.tokens
+        .iter()
+        .cloned()
+        .map(|mut token| {
+            if is_receiver_chain_source(
+                packet.port_id_on_a.clone(),
+                packet.chan_id_on_a.clone(),
+                &token.denom,
+            ) {
+                let prefix =
+                    TracePrefix::new(packet.port_id_on_a.clone(), packet.chan_id_on_a.clone());
+                token.denom.remove_trace_prefix(&prefix);
+            } else {
+                let prefix =
+                    TracePrefix::new(packet.port_id_on_b.clone(), packet.chan_id_on_b.clone());
+                token.denom.add_trace_prefix(prefix);
+            }
+            token
+        })
+        .collect()
+}
+
+/// Initiates a rate-limited token transfer. Equivalent to calling
+/// [`send_transfer_validate`], followed by [`send_transfer_execute`].
+///
+/// The ICS-20 `send_transfer` sends its packet straight to core IBC, without
+/// going through the outbound hooks of the middleware stack. Hosts stacking
+/// the rate-limit middleware on top of the ICS-20 application use this one
+/// in its place, so that the transfers exceeding the quotas of the channel
+/// are rejected.
+pub fn send_transfer<SendPacketCtx, TokenCtx, RateLimitCtx>(
+    send_packet_ctx_a: &mut SendPacketCtx,
+    token_ctx_a: &mut TokenCtx,
+    rate_limit_ctx_a: &mut RateLimitCtx,
+    msg: impl Into<MsgTransferV2>,
+) -> Result<(), RateLimitError>
+where
+    SendPacketCtx: SendPacketExecutionContext,
+    TokenCtx: TokenTransferExecutionContext,
+    RateLimitCtx: RateLimitExecutionContext,
+{
+    let msg = msg.into();
+    send_transfer_validate(
+        send_packet_ctx_a,
+        token_ctx_a,
+        rate_limit_ctx_a,
+        msg.clone(),
+    )?;
+    send_transfer_execute(send_packet_ctx_a, token_ctx_a, rate_limit_ctx_a, msg)
+}
+
+/// Validates the token transfer with the ICS-20 `send_transfer_validate`,
+/// and checks that its tokens do not exceed the send quotas of the channel.
+pub fn send_transfer_validate<SendPacketCtx, TokenCtx, RateLimitCtx>(
+    send_packet_ctx_a: &SendPacketCtx,
+    token_ctx_a: &TokenCtx,
+    rate_limit_ctx_a: &RateLimitCtx,
+    msg: impl Into<MsgTransferV2>,
+) -> Result<(), RateLimitError>
+where
+    SendPacketCtx: SendPacketValidationContext,
+    TokenCtx: TokenTransferValidationContext,
+    RateLimitCtx: RateLimitValidationContext,
+{
+    let msg = msg.into();
+
+    validate_flows(
+        rate_limit_ctx_a,
+        &msg.chan_id_on_a,
+        FlowDirection::Outflow,
+        &msg.packet_data.tokens,
+    )?;
+
+    Ok(transfer_validate(send_packet_ctx_a, token_ctx_a, msg)?)
+}
+
+/// Executes the token transfer with the ICS-20 `send_transfer_execute`, and
+/// counts its tokens in the outflows of the channel.
+pub fn send_transfer_execute<SendPacketCtx, TokenCtx, RateLimitCtx>(
+    send_packet_ctx_a: &mut SendPacketCtx,
+    token_ctx_a: &mut TokenCtx,
+    rate_limit_ctx_a: &mut RateLimitCtx,
+    msg: impl Into<MsgTransferV2>,
+) -> Result<(), RateLimitError>
+where
+    SendPacketCtx: SendPacketExecutionContext,
+    TokenCtx: TokenTransferExecutionContext,
+    RateLimitCtx: RateLimitExecutionContext,
+{
+    let msg = msg.into();
+
+    let seq_send_path_on_a = SeqSendPath::new(&msg.port_id_on_a, &msg.chan_id_on_a);
+    let sequence = send_packet_ctx_a.get_next_sequence_send(&seq_send_path_on_a)?;
+
+    record_outflow(
+        rate_limit_ctx_a,
+        &msg.chan_id_on_a,
+        sequence,
+        &msg.packet_data.tokens,
+    )?;
+
+    Ok(transfer_execute(send_packet_ctx_a, token_ctx_a, msg)?)
+}
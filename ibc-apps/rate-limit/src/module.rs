@@ -0,0 +1,299 @@
+//! Defines the rate-limit middleware callbacks, which wrap the callbacks of
+//! the underlying ICS-20 application.
+use core::fmt::Debug;
+
+use ibc_app_rate_limit_types::error::RateLimitError;
+use ibc_app_rate_limit_types::FlowDirection;
+use ibc_app_transfer::handler::decode_packet_data;
+use ibc_app_transfer_types::packet::FungibleTokenPacketDataV2;
+use ibc_core::channel::types::acknowledgement::{Acknowledgement, AcknowledgementStatus};
+use ibc_core::channel::types::error::PacketError;
+use ibc_core::channel::types::packet::Packet;
+use ibc_core::host::types::identifiers::{ChannelId, PortId};
+use ibc_core::primitives::prelude::*;
+use ibc_core::primitives::Signer;
+use ibc_core::router::middleware::{IbcModuleWrapper, Middleware};
+use ibc_core::router::module::Module;
+use ibc_core::router::types::module::ModuleExtras;
+
+use crate::context::{RateLimitExecutionContext, RateLimitValidationContext};
+use crate::handler::{
+    received_tokens, record_flows, record_outflow, undo_flows, undo_outflow, validate_flows,
+};
+
+fn is_successful(acknowledgement: &Acknowledgement) -> bool {
+    serde_json::from_slice::<AcknowledgementStatus>(acknowledgement.as_ref())
+        .map(|ack| ack.is_successful())
+        .unwrap_or(false)
+}
+
+fn error_acknowledgement(err: RateLimitError) -> Acknowledgement {
+    AcknowledgementStatus::error(err.into()).into()
+}
+
+/// Decodes the ICS-20 data of a packet according to the version of the
+/// channel with the given end on the host.
+///
+/// Returns `None` if the packet does not carry ICS-20 packet data.
+fn decode_transfer_data(
+    ctx: &impl RateLimitValidationContext,
+    port_id: &PortId,
+    channel_id: &ChannelId,
+    packet: &Packet,
+) -> Result<Option<FungibleTokenPacketDataV2>, RateLimitError> {
+    let chan_version = ctx.channel_version(port_id, channel_id)?;
+
+    Ok(decode_packet_data(&packet.data, &chan_version).ok())
+}
+
+/// Removes the tokens of a packet received by this chain from the inflows
+/// of the channel, as they are not received after all.
+fn undo_inflow(
+    ctx: &mut impl RateLimitExecutionContext,
+    packet: &Packet,
+) -> Result<(), RateLimitError> {
+    match decode_transfer_data(ctx, &packet.port_id_on_b, &packet.chan_id_on_b, packet)? {
+        Some(data) => undo_flows(
+            ctx,
+            &packet.chan_id_on_b,
+            FlowDirection::Inflow,
+            &received_tokens(packet, &data),
+        ),
+        None => Ok(()),
+    }
+}
+
+/// Counts the received tokens in the inflows of the channel before passing
+/// the packet on to the application. A packet exceeding any quota gets an
+/// error acknowledgement instead.
+///
+/// Packets which do not carry ICS-20 packet data are passed on untouched.
+pub fn on_recv_packet_execute<C, N>(
+    ctx: &mut C,
+    app: &mut N,
+    packet: &Packet,
+    relayer: &Signer,
+) -> (ModuleExtras, Option<Acknowledgement>)
+where
+    C: RateLimitExecutionContext,
+    N: Module,
+{
+    let data = match decode_transfer_data(ctx, &packet.port_id_on_b, &packet.chan_id_on_b, packet) {
+        Ok(Some(data)) => data,
+        Ok(None) => return app.on_recv_packet_execute(packet, relayer),
+        Err(err) => return (ModuleExtras::empty(), Some(error_acknowledgement(err))),
+    };
+
+    let tokens = received_tokens(packet, &data);
+
+    if let Err(err) = record_flows(ctx, &packet.chan_id_on_b, FlowDirection::Inflow, &tokens) {
+        return (ModuleExtras::empty(), Some(error_acknowledgement(err)));
+    }
+
+    let (extras, ack) = app.on_recv_packet_execute(packet, relayer);
+
+    match ack {
+        Some(ack) if !is_successful(&ack) => {
+            match undo_flows(ctx, &packet.chan_id_on_b, FlowDirection::Inflow, &tokens) {
+                Ok(()) => (extras, Some(ack)),
+                Err(err) => (extras, Some(error_acknowledgement(err))),
+            }
+        }
+        ack => (extras, ack),
+    }
+}
+
+/// Removes the tokens of a packet which failed on the counterparty chain from
+/// the outflows of the channel, or stops keeping track of the flows counted
+/// by a packet which succeeded, after passing the acknowledgement on to the
+/// application.
+pub fn on_acknowledgement_packet_execute<C, N>(
+    ctx: &mut C,
+    app: &mut N,
+    packet: &Packet,
+    acknowledgement: &Acknowledgement,
+    relayer: &Signer,
+) -> (ModuleExtras, Result<(), PacketError>)
+where
+    C: RateLimitExecutionContext,
+    N: Module,
+{
+    let (extras, result) = app.on_acknowledgement_packet_execute(packet, acknowledgement, relayer);
+
+    if result.is_err() {
+        return (extras, result);
+    }
+
+    let result = if is_successful(acknowledgement) {
+        ctx.delete_pending_send_flows(&packet.chan_id_on_a, packet.seq_on_a)
+    } else {
+        undo_outflow(ctx, &packet.chan_id_on_a, packet.seq_on_a)
+    };
+
+    (extras, result.map_err(Into::into))
+}
+
+/// Removes the tokens of a timed out packet from the outflows of the channel,
+/// after passing the timeout on to the application.
+pub fn on_timeout_packet_execute<C, N>(
+    ctx: &mut C,
+    app: &mut N,
+    packet: &Packet,
+    relayer: &Signer,
+) -> (ModuleExtras, Result<(), PacketError>)
+where
+    C: RateLimitExecutionContext,
+    N: Module,
+{
+    let (extras, result) = app.on_timeout_packet_execute(packet, relayer);
+
+    if result.is_err() {
+        return (extras, result);
+    }
+
+    (
+        extras,
+        undo_outflow(ctx, &packet.chan_id_on_a, packet.seq_on_a).map_err(Into::into),
+    )
+}
+
+/// Checks that the tokens of a packet sent by the modules below the
+/// middleware do not exceed the send quotas of the channel.
+///
+/// Packets which do not carry ICS-20 packet data are passed on untouched.
+pub fn send_packet_validate<C>(ctx: &C, packet: &Packet) -> Result<(), PacketError>
+where
+    C: RateLimitValidationContext,
+{
+    match decode_transfer_data(ctx, &packet.port_id_on_a, &packet.chan_id_on_a, packet)? {
+        Some(data) => Ok(validate_flows(
+            ctx,
+            &packet.chan_id_on_a,
+            FlowDirection::Outflow,
+            &data.tokens,
+        )?),
+        None => Ok(()),
+    }
+}
+
+/// Counts the tokens of a packet sent by the modules below the middleware
+/// in the outflows of the channel.
+pub fn send_packet_execute<C>(ctx: &mut C, packet: &Packet) -> Result<ModuleExtras, PacketError>
+where
+    C: RateLimitExecutionContext,
+{
+    if let Some(data) =
+        decode_transfer_data(ctx, &packet.port_id_on_a, &packet.chan_id_on_a, packet)?
+    {
+        record_outflow(ctx, &packet.chan_id_on_a, packet.seq_on_a, &data.tokens)?;
+    }
+
+    Ok(ModuleExtras::empty())
+}
+
+/// Removes the tokens of a packet whose acknowledgement, written
+/// asynchronously by the modules below the middleware, is an error from the
+/// inflows of the channel.
+pub fn write_acknowledgement_execute<C>(
+    ctx: &mut C,
+    packet: &Packet,
+    acknowledgement: Acknowledgement,
+) -> Result<(ModuleExtras, Acknowledgement), PacketError>
+where
+    C: RateLimitExecutionContext,
+{
+    if !is_successful(&acknowledgement) {
+        undo_inflow(ctx, packet)?;
+    }
+
+    Ok((ModuleExtras::empty(), acknowledgement))
+}
+
+/// The rate-limit middleware, which enforces the quotas set on the flows of
+/// each denomination over each channel (see
+/// [`RateLimit`](ibc_app_rate_limit_types::RateLimit)).
+///
+/// Received packets exceeding a quota get an error acknowledgement. Sent
+/// packets exceeding a quota are rejected, both when they go through the
+/// outbound hooks of the stack and when they are sent with the rate-limited
+/// [`send_transfer`](crate::handler::send_transfer), which hosts use in place
+/// of the ICS-20 one. The flows of the packets which time out or fail on the
+/// counterparty chain are undone.
+///
+/// The middleware is meant to be stacked on top of the ICS-20 application.
+#[derive(Debug)]
+pub struct RateLimitMiddleware<C> {
+    ctx: C,
+}
+
+impl<C> RateLimitMiddleware<C> {
+    pub fn new(ctx: C) -> Self {
+        Self { ctx }
+    }
+
+    pub fn ctx(&self) -> &C {
+        &self.ctx
+    }
+
+    pub fn ctx_mut(&mut self) -> &mut C {
+        &mut self.ctx
+    }
+
+    pub fn into_inner(self) -> C {
+        self.ctx
+    }
+}
+
+/// The IBC [`Module`] made of the rate-limit middleware stacked on top of the
+/// ICS-20 application `M`.
+pub type RateLimitModule<C, M> = IbcModuleWrapper<RateLimitMiddleware<C>, M>;
+
+impl<C, N> Middleware<N> for RateLimitMiddleware<C>
+where
+    C: RateLimitExecutionContext + Debug,
+    N: Module,
+{
+    fn on_recv_packet_execute(
+        &mut self,
+        next: &mut N,
+        packet: &Packet,
+        relayer: &Signer,
+    ) -> (ModuleExtras, Option<Acknowledgement>) {
+        on_recv_packet_execute(&mut self.ctx, next, packet, relayer)
+    }
+
+    fn on_acknowledgement_packet_execute(
+        &mut self,
+        next: &mut N,
+        packet: &Packet,
+        acknowledgement: &Acknowledgement,
+        relayer: &Signer,
+    ) -> (ModuleExtras, Result<(), PacketError>) {
+        on_acknowledgement_packet_execute(&mut self.ctx, next, packet, acknowledgement, relayer)
+    }
+
+    fn on_timeout_packet_execute(
+        &mut self,
+        next: &mut N,
+        packet: &Packet,
+        relayer: &Signer,
+    ) -> (ModuleExtras, Result<(), PacketError>) {
+        on_timeout_packet_execute(&mut self.ctx, next, packet, relayer)
+    }
+
+    fn send_packet_validate(&self, packet: &Packet) -> Result<(), PacketError> {
+        send_packet_validate(&self.ctx, packet)
+    }
+
+    fn send_packet_execute(&mut self, packet: &Packet) -> Result<ModuleExtras, PacketError> {
+        send_packet_execute(&mut self.ctx, packet)
+    }
+
+    fn write_acknowledgement_execute(
+        &mut self,
+        packet: &Packet,
+        acknowledgement: Acknowledgement,
+    ) -> Result<(ModuleExtras, Acknowledgement), PacketError> {
+        write_acknowledgement_execute(&mut self.ctx, packet, acknowledgement)
+    }
+}
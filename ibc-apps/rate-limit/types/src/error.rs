@@ -0,0 +1,98 @@
+//! Defines the rate-limit middleware error type
+use core::convert::Infallible;
+
+use displaydoc::Display;
+use ibc_app_transfer_types::error::TokenTransferError;
+use ibc_app_transfer_types::PrefixedDenom;
+use ibc_core::channel::types::acknowledgement::StatusValue;
+use ibc_core::channel::types::error::{ChannelError, PacketError};
+use ibc_core::handler::types::error::ContextError;
+use ibc_core::host::types::identifiers::ChannelId;
+use ibc_core::primitives::prelude::*;
+use ibc_core::primitives::Signer;
+
+use crate::FlowDirection;
+
+#[derive(Display, Debug)]
+pub enum RateLimitError {
+    /// context error: `{0}`
+    ContextError(ContextError),
+    /// token transfer error: `{0}`
+    TokenTransfer(TokenTransferError),
+    /// invalid quota `{name}`: `{reason}`
+    InvalidQuota { name: String, reason: String },
+    /// `{direction}` quota `{quota_name}` exceeded
+    QuotaExceeded {
+        quota_name: String,
+        direction: FlowDirection,
+    },
+    /// rate limit `{quota_name}` not found for denom `{denom}` on channel `{channel_id}`
+    RateLimitNotFound {
+        channel_id: ChannelId,
+        denom: PrefixedDenom,
+        quota_name: String,
+    },
+    /// rate limit `{quota_name}` already exists for denom `{denom}` on channel `{channel_id}`
+    RateLimitExists {
+        channel_id: ChannelId,
+        denom: PrefixedDenom,
+        quota_name: String,
+    },
+    /// signer `{signer}` is not the rate-limit authority `{authority}`
+    Unauthorized { signer: Signer, authority: Signer },
+    /// timestamp overflow while computing the end of the rate-limit window
+    TimestampOverflow,
+    /// other error: `{0}`
+    Other(String),
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for RateLimitError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match &self {
+            Self::ContextError(e) => Some(e),
+            Self::TokenTransfer(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<Infallible> for RateLimitError {
+    fn from(e: Infallible) -> Self {
+        match e {}
+    }
+}
+
+impl From<ContextError> for RateLimitError {
+    fn from(err: ContextError) -> RateLimitError {
+        Self::ContextError(err)
+    }
+}
+
+impl From<TokenTransferError> for RateLimitError {
+    fn from(err: TokenTransferError) -> RateLimitError {
+        Self::TokenTransfer(err)
+    }
+}
+
+impl From<RateLimitError> for StatusValue {
+    fn from(err: RateLimitError) -> Self {
+        StatusValue::new(err.to_string()).expect("error message must not be empty")
+    }
+}
+
+impl From<RateLimitError> for ChannelError {
+    fn from(err: RateLimitError) -> ChannelError {
+        ChannelError::AppModule {
+            description: err.to_string(),
+        }
+    }
+}
+
+impl From<RateLimitError> for PacketError {
+    fn from(err: RateLimitError) -> PacketError {
+        PacketError::AppModule {
+            description: err.to_string(),
+        }
+    }
+}
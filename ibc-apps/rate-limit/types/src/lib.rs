@@ -0,0 +1,36 @@
+//! Implementation of the data structures of the rate-limit middleware, which
+//! enforces quotas on the inflows and outflows of
+//! [ICS-20](https://github.com/cosmos/ibc/blob/main/spec/app/ics-020-fungible-token-transfer/README.md)
+//! tokens over each channel.
+#![no_std]
+#![forbid(unsafe_code)]
+#![cfg_attr(not(test), deny(clippy::unwrap_used))]
+#![cfg_attr(not(test), deny(clippy::disallowed_methods, clippy::disallowed_types))]
+#![deny(
+    warnings,
+    trivial_casts,
+    trivial_numeric_casts,
+    unused_import_braces,
+    unused_qualifications,
+    rust_2018_idioms
+)]
+
+#[cfg(any(test, feature = "std"))]
+extern crate std;
+
+#[cfg(feature = "serde")]
+mod quota;
+#[cfg(feature = "serde")]
+pub use quota::*;
+#[cfg(feature = "serde")]
+mod rate_limit;
+#[cfg(feature = "serde")]
+pub use rate_limit::*;
+
+#[cfg(feature = "serde")]
+pub mod error;
+#[cfg(feature = "serde")]
+pub mod msgs;
+
+/// Module identifier for the rate-limit middleware.
+pub const MODULE_ID_STR: &str = "ratelimit";
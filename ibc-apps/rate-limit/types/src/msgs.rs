@@ -0,0 +1,29 @@
+//! Defines the messages administering the rate limits.
+use ibc_core::primitives::prelude::*;
+use ibc_core::primitives::Signer;
+
+use crate::{Quota, RateLimitPath};
+
+/// Message adding a quota on the flows of a denomination over a channel.
+///
+/// It must be signed by the rate-limit authority of the host.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MsgAddRateLimit {
+    pub signer: Signer,
+    pub path: RateLimitPath,
+    pub quota: Quota,
+}
+
+/// Message removing a quota from the flows of a denomination over a channel.
+///
+/// It must be signed by the rate-limit authority of the host.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MsgRemoveRateLimit {
+    pub signer: Signer,
+    pub path: RateLimitPath,
+    pub quota_name: String,
+}
@@ -0,0 +1,88 @@
+//! Defines the quotas enforced by the rate-limit middleware.
+use core::time::Duration;
+
+use ibc_app_transfer_types::{Amount, U256};
+use ibc_core::primitives::prelude::*;
+
+use crate::error::RateLimitError;
+
+/// The maximum net flow of a denomination over a channel during a rate-limit
+/// window.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum QuotaLimit {
+    /// A percentage, between 0 and 100, of the supply of the denomination at
+    /// the start of the window.
+    Percent(u32),
+    /// An absolute amount of the denomination.
+    Absolute(Amount),
+}
+
+impl QuotaLimit {
+    /// Returns the amount allowed by the limit, given the supply of the
+    /// denomination at the start of the window.
+    pub fn threshold(&self, supply: Amount) -> Amount {
+        match self {
+            Self::Percent(percent) => {
+                let percent = U256::from(*percent);
+                let hundred = U256::from(100);
+                let supply = *supply.as_ref();
+
+                // computed so that it cannot overflow for percents up to 100
+                Amount::from(supply / hundred * percent + supply % hundred * percent / hundred)
+            }
+            Self::Absolute(amount) => *amount,
+        }
+    }
+
+    fn validate_basic(&self) -> Result<(), String> {
+        match self {
+            Self::Percent(percent) if *percent > 100 => {
+                Err(format!("percent {percent} is greater than 100"))
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+/// A quota on the flows of a denomination over a channel, which limits both
+/// what is sent and what is received during a rolling window.
+///
+/// As in Osmosis' rate limiter, the flows are netted: tokens received during
+/// the window raise the amount which may be sent, and conversely.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Quota {
+    /// The name of the quota, unique among the quotas of a rate-limit path.
+    pub name: String,
+    /// The duration of the window over which the flows are counted.
+    pub duration: Duration,
+    /// The maximum net outflow during the window.
+    pub max_send: QuotaLimit,
+    /// The maximum net inflow during the window.
+    pub max_recv: QuotaLimit,
+}
+
+impl Quota {
+    pub fn validate_basic(&self) -> Result<(), RateLimitError> {
+        let invalid = |reason: String| RateLimitError::InvalidQuota {
+            name: self.name.clone(),
+            reason,
+        };
+
+        if self.name.trim().is_empty() {
+            return Err(invalid("empty name".to_string()));
+        }
+
+        if self.duration.is_zero() {
+            return Err(invalid("zero duration".to_string()));
+        }
+
+        self.max_send.validate_basic().map_err(invalid)?;
+        self.max_recv.validate_basic().map_err(invalid)?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,263 @@
+//! Defines the rate limits and the flows they count.
+use displaydoc::Display;
+use ibc_app_transfer_types::{Amount, PrefixedDenom};
+use ibc_core::host::types::identifiers::ChannelId;
+use ibc_core::primitives::prelude::*;
+use ibc_core::primitives::Timestamp;
+
+use crate::error::RateLimitError;
+use crate::Quota;
+
+/// Identifies the flows of a denomination over a channel, on which rate
+/// limits are set.
+///
+/// The denomination is the one of the tokens on this chain, i.e. the
+/// denomination of the tokens sent, and the denomination of the received
+/// tokens once they are unescrowed or minted.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct RateLimitPath {
+    pub channel_id: ChannelId,
+    pub denom: PrefixedDenom,
+}
+
+impl RateLimitPath {
+    pub fn new(channel_id: ChannelId, denom: PrefixedDenom) -> Self {
+        Self { channel_id, denom }
+    }
+}
+
+/// The direction of a flow of tokens.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Display)]
+pub enum FlowDirection {
+    /// inflow
+    Inflow,
+    /// outflow
+    Outflow,
+}
+
+/// The flows counted during the current window of a rate limit.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Flow {
+    /// The amount received during the window.
+    pub inflow: Amount,
+    /// The amount sent during the window.
+    pub outflow: Amount,
+    /// The supply of the denomination at the start of the window, from which
+    /// the percentage quotas are computed.
+    pub supply: Amount,
+    /// The time at which the window ends.
+    pub period_end: Timestamp,
+}
+
+/// An amount counted against a rate limit by a sent packet, to be undone if
+/// the packet times out or fails on the counterparty chain.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RecordedFlow {
+    pub path: RateLimitPath,
+    pub quota_name: String,
+    /// The end of the window in which the amount was counted.
+    pub period_end: Timestamp,
+    pub amount: Amount,
+}
+
+/// A quota, together with the flows counted against it during the current
+/// window.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RateLimit {
+    pub quota: Quota,
+    pub flow: Flow,
+}
+
+impl RateLimit {
+    /// Creates a rate limit whose first window starts at `now`.
+    pub fn new(quota: Quota, supply: Amount, now: Timestamp) -> Result<Self, RateLimitError> {
+        let period_end = (now + quota.duration).map_err(|_| RateLimitError::TimestampOverflow)?;
+
+        Ok(Self {
+            quota,
+            flow: Flow {
+                inflow: Amount::from(0),
+                outflow: Amount::from(0),
+                supply,
+                period_end,
+            },
+        })
+    }
+
+    /// Starts a new window at `now`, with a fresh supply snapshot, if the
+    /// current window has ended.
+    pub fn refresh(&mut self, supply: Amount, now: Timestamp) -> Result<(), RateLimitError> {
+        if now >= self.flow.period_end {
+            *self = Self::new(self.quota.clone(), supply, now)?;
+        }
+
+        Ok(())
+    }
+
+    /// Counts `amount` in the given direction, unless it exceeds the quota.
+    ///
+    /// The net flow in the given direction may not exceed the threshold of
+    /// the quota, e.g. the amount sent may not exceed the amount received
+    /// plus the threshold of `max_send`.
+    pub fn check_and_add(
+        &mut self,
+        direction: FlowDirection,
+        amount: Amount,
+    ) -> Result<(), RateLimitError> {
+        let (flow, counter_flow, limit) = match direction {
+            FlowDirection::Inflow => (
+                &mut self.flow.inflow,
+                self.flow.outflow,
+                &self.quota.max_recv,
+            ),
+            FlowDirection::Outflow => (
+                &mut self.flow.outflow,
+                self.flow.inflow,
+                &self.quota.max_send,
+            ),
+        };
+
+        let exceeded = || RateLimitError::QuotaExceeded {
+            quota_name: self.quota.name.clone(),
+            direction,
+        };
+
+        let new_flow = flow.checked_add(amount).ok_or_else(exceeded)?;
+        let allowed = counter_flow.checked_add(limit.threshold(self.flow.supply));
+
+        match allowed {
+            Some(allowed) if new_flow > allowed => Err(exceeded()),
+            _ => {
+                *flow = new_flow;
+                Ok(())
+            }
+        }
+    }
+
+    /// Removes `amount` from the flow in the given direction, e.g. when a
+    /// packet is timed out or fails on the counterparty chain.
+    pub fn undo(&mut self, direction: FlowDirection, amount: Amount) {
+        let flow = match direction {
+            FlowDirection::Inflow => &mut self.flow.inflow,
+            FlowDirection::Outflow => &mut self.flow.outflow,
+        };
+
+        *flow = flow.checked_sub(amount).unwrap_or_else(|| Amount::from(0));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::time::Duration;
+
+    use rstest::rstest;
+
+    use super::*;
+    use crate::QuotaLimit;
+
+    fn rate_limit(max_send: QuotaLimit, max_recv: QuotaLimit) -> RateLimit {
+        let quota = Quota {
+            name: "daily".to_string(),
+            duration: Duration::from_secs(60),
+            max_send,
+            max_recv,
+        };
+
+        RateLimit::new(
+            quota,
+            Amount::from(1000),
+            Timestamp::from_nanoseconds(1).unwrap(),
+        )
+        .unwrap()
+    }
+
+    #[rstest]
+    #[case(QuotaLimit::Percent(10), 100)]
+    #[case(QuotaLimit::Percent(0), 0)]
+    #[case(QuotaLimit::Percent(100), 1000)]
+    #[case(QuotaLimit::Absolute(Amount::from(42)), 42)]
+    fn test_threshold(#[case] limit: QuotaLimit, #[case] expected: u64) {
+        assert_eq!(limit.threshold(Amount::from(1000)), Amount::from(expected));
+    }
+
+    #[test]
+    fn test_check_and_add_nets_flows() {
+        let mut rate_limit = rate_limit(QuotaLimit::Percent(10), QuotaLimit::Percent(5));
+
+        rate_limit
+            .check_and_add(FlowDirection::Outflow, Amount::from(100))
+            .unwrap();
+        assert!(rate_limit
+            .check_and_add(FlowDirection::Outflow, Amount::from(1))
+            .is_err());
+
+        // receiving tokens allows sending as many more
+        rate_limit
+            .check_and_add(FlowDirection::Inflow, Amount::from(30))
+            .unwrap();
+        rate_limit
+            .check_and_add(FlowDirection::Outflow, Amount::from(30))
+            .unwrap();
+
+        assert_eq!(rate_limit.flow.inflow, Amount::from(30));
+        assert_eq!(rate_limit.flow.outflow, Amount::from(130));
+    }
+
+    #[test]
+    fn test_undo_and_refresh() {
+        let mut rate_limit = rate_limit(QuotaLimit::Percent(10), QuotaLimit::Percent(10));
+
+        rate_limit
+            .check_and_add(FlowDirection::Outflow, Amount::from(100))
+            .unwrap();
+        rate_limit.undo(FlowDirection::Outflow, Amount::from(150));
+        assert_eq!(rate_limit.flow.outflow, Amount::from(0));
+
+        rate_limit
+            .check_and_add(FlowDirection::Inflow, Amount::from(100))
+            .unwrap();
+
+        // the window is still open
+        let now = Timestamp::from_nanoseconds(2).unwrap();
+        rate_limit.refresh(Amount::from(2000), now).unwrap();
+        assert_eq!(rate_limit.flow.inflow, Amount::from(100));
+
+        let now = rate_limit.flow.period_end;
+        rate_limit.refresh(Amount::from(2000), now).unwrap();
+        assert_eq!(rate_limit.flow.inflow, Amount::from(0));
+        assert_eq!(rate_limit.flow.supply, Amount::from(2000));
+        assert_eq!(
+            rate_limit.flow.period_end,
+            (now + Duration::from_secs(60)).unwrap()
+        );
+    }
+
+    #[rstest]
+    #[case("", QuotaLimit::Percent(10), false)]
+    #[case("daily", QuotaLimit::Percent(101), false)]
+    #[case("daily", QuotaLimit::Percent(100), true)]
+    fn test_quota_validate_basic(
+        #[case] name: &str,
+        #[case] max_send: QuotaLimit,
+        #[case] expect_ok: bool,
+    ) {
+        let quota = Quota {
+            name: name.to_string(),
+            duration: Duration::from_secs(60),
+            max_send,
+            max_recv: QuotaLimit::Absolute(Amount::from(1)),
+        };
+
+        assert_eq!(quota.validate_basic().is_ok(), expect_ok);
+    }
+}
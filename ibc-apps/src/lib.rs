@@ -27,3 +27,37 @@ pub mod nft_transfer {
     #[cfg(feature = "nft-transfer")]
     pub use ibc_app_nft_transfer::*;
 }
+
+/// Re-exports the implementation of the IBC [relayer
+/// incentivisation](https://github.com/cosmos/ibc/blob/main/spec/app/ics-029-fee-payment/README.md)
+/// (ICS-29) middleware logic.
+pub mod fee {
+    #[doc(inline)]
+    #[cfg(feature = "fee")]
+    pub use ibc_app_fee::*;
+}
+
+/// Re-exports the implementation of the IBC [Interchain
+/// Accounts](https://github.com/cosmos/ibc/blob/main/spec/app/ics-027-interchain-accounts/README.md)
+/// (ICS-27) controller and host logic.
+pub mod interchain_accounts {
+    #[doc(inline)]
+    #[cfg(feature = "interchain-accounts")]
+    pub use ibc_app_interchain_accounts::*;
+}
+
+/// Re-exports the implementation of the packet-forward middleware, which
+/// routes ICS-20 token transfers over several chains.
+pub mod packet_forward {
+    #[doc(inline)]
+    #[cfg(feature = "packet-forward")]
+    pub use ibc_app_packet_forward::*;
+}
+
+/// Re-exports the implementation of the rate-limit middleware, which enforces
+/// quotas on the ICS-20 token flows of each channel.
+pub mod rate_limit {
+    #[doc(inline)]
+    #[cfg(feature = "rate-limit")]
+    pub use ibc_app_rate_limit::*;
+}
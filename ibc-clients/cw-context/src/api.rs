@@ -0,0 +1,42 @@
+//! Defines the trait a light client implements to be deployed as a contract
+
+use ibc_client_tendermint::client_state::ClientState as TmClientState;
+use ibc_client_tendermint::consensus_state::ConsensusState as TmConsensusState;
+use ibc_client_tendermint::types::TENDERMINT_MISBEHAVIOUR_TYPE_URL;
+use ibc_core_client::context::client_state::ClientState;
+use ibc_core_client::context::consensus_state::ConsensusState;
+use ibc_core_client::types::error::ClientError;
+use ibc_primitives::proto::Any;
+
+use crate::context::Context;
+
+/// Ties together the client and consensus state types of a light client
+/// contract.
+///
+/// The client state must implement the client traits over the [`Context`]
+/// storage adapter, which persists the client and consensus states in the
+/// contract's client store, as ibc-go expects.
+pub trait ClientType<'a>: Sized {
+    type ClientState: ClientState<Context<'a, Self>, Context<'a, Self>>
+        + TryFrom<Any, Error = ClientError>
+        + Into<Any>
+        + Clone;
+    type ConsensusState: ConsensusState + TryFrom<Any, Error = ClientError> + Into<Any> + Clone;
+
+    /// The type URL of the misbehaviour type of the client.
+    ///
+    /// ibc-go does not tell the contract whether a client message is a header
+    /// or a misbehaviour, so the contract derives it from the type URL of the
+    /// `Any`-encoded client message.
+    const MISBEHAVIOUR_TYPE_URL: &'static str;
+}
+
+/// The ICS-07 Tendermint light client
+pub struct TendermintClient;
+
+impl<'a> ClientType<'a> for TendermintClient {
+    type ClientState = TmClientState;
+    type ConsensusState = TmConsensusState;
+
+    const MISBEHAVIOUR_TYPE_URL: &'static str = TENDERMINT_MISBEHAVIOUR_TYPE_URL;
+}
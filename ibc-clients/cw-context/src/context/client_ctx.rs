@@ -0,0 +1,203 @@
+//! Implements the client contexts for the contract [`Context`].
+
+use ibc_client_tendermint::consensus_state::ConsensusState as TmConsensusState;
+use ibc_client_tendermint::context::{
+    CommonContext as TmCommonContext, ValidationContext as TmValidationContext,
+};
+use ibc_core_client::context::{ClientExecutionContext, ClientValidationContext};
+use ibc_core_client::types::error::ClientError;
+use ibc_core_client::types::Height;
+use ibc_core_handler_types::error::ContextError;
+use ibc_core_host::types::identifiers::ClientId;
+use ibc_core_host::types::path::{ClientConsensusStatePath, ClientStatePath};
+use ibc_primitives::prelude::*;
+use ibc_primitives::Timestamp;
+
+use super::{processed_height_key, processed_time_key, Context};
+use crate::api::ClientType;
+
+fn consensus_state_height(path: &ClientConsensusStatePath) -> Result<Height, ClientError> {
+    Height::new(path.revision_number, path.revision_height)
+}
+
+impl<'a, C: ClientType<'a>> ClientValidationContext for Context<'a, C> {
+    fn client_update_time(
+        &self,
+        client_id: &ClientId,
+        height: &Height,
+    ) -> Result<Timestamp, ContextError> {
+        let processed_time = self
+            .retrieve(processed_time_key(height))
+            .and_then(|bytes| bytes.try_into().ok())
+            .map(u64::from_be_bytes)
+            .ok_or(ClientError::ProcessedTimeNotFound {
+                client_id: client_id.clone(),
+                height: *height,
+            })?;
+
+        Ok(Timestamp::from_nanoseconds(processed_time)
+            .map_err(ClientError::InvalidPacketTimestamp)?)
+    }
+
+    fn client_update_height(
+        &self,
+        client_id: &ClientId,
+        height: &Height,
+    ) -> Result<Height, ContextError> {
+        let processed_height = self
+            .retrieve(processed_height_key(height))
+            .and_then(|bytes| String::from_utf8(bytes).ok())
+            .and_then(|height| height.parse().ok())
+            .ok_or(ClientError::ProcessedHeightNotFound {
+                client_id: client_id.clone(),
+                height: *height,
+            })?;
+
+        Ok(processed_height)
+    }
+}
+
+impl<'a, C: ClientType<'a>> ClientExecutionContext for Context<'a, C> {
+    type V = Self;
+    type AnyClientState = C::ClientState;
+    type AnyConsensusState = C::ConsensusState;
+
+    fn store_client_state(
+        &mut self,
+        _client_state_path: ClientStatePath,
+        client_state: Self::AnyClientState,
+    ) -> Result<(), ContextError> {
+        Ok(self.set_client_state(client_state)?)
+    }
+
+    fn store_consensus_state(
+        &mut self,
+        consensus_state_path: ClientConsensusStatePath,
+        consensus_state: Self::AnyConsensusState,
+    ) -> Result<(), ContextError> {
+        let height = consensus_state_height(&consensus_state_path)?;
+
+        Ok(self.set_consensus_state(&height, consensus_state)?)
+    }
+
+    fn delete_consensus_state(
+        &mut self,
+        consensus_state_path: ClientConsensusStatePath,
+    ) -> Result<(), ContextError> {
+        let height = consensus_state_height(&consensus_state_path)?;
+
+        Ok(self.remove_consensus_state(&height)?)
+    }
+
+    fn store_update_time(
+        &mut self,
+        _client_id: ClientId,
+        height: Height,
+        host_timestamp: Timestamp,
+    ) -> Result<(), ContextError> {
+        self.insert(
+            processed_time_key(&height),
+            host_timestamp.nanoseconds().to_be_bytes(),
+        )
+        .map_err(ClientError::from)?;
+
+        Ok(())
+    }
+
+    fn store_update_height(
+        &mut self,
+        _client_id: ClientId,
+        height: Height,
+        host_height: Height,
+    ) -> Result<(), ContextError> {
+        self.insert(processed_height_key(&height), host_height.to_string())
+            .map_err(ClientError::from)?;
+
+        Ok(())
+    }
+
+    fn delete_update_time(
+        &mut self,
+        _client_id: ClientId,
+        height: Height,
+    ) -> Result<(), ContextError> {
+        self.remove(processed_time_key(&height))
+            .map_err(ClientError::from)?;
+
+        Ok(())
+    }
+
+    fn delete_update_height(
+        &mut self,
+        _client_id: ClientId,
+        height: Height,
+    ) -> Result<(), ContextError> {
+        self.remove(processed_height_key(&height))
+            .map_err(ClientError::from)?;
+
+        Ok(())
+    }
+}
+
+impl<'a, C: ClientType<'a>> TmCommonContext for Context<'a, C>
+where
+    C::ConsensusState: TryInto<TmConsensusState>,
+    <C::ConsensusState as TryInto<TmConsensusState>>::Error: ToString,
+{
+    type ConversionError = <C::ConsensusState as TryInto<TmConsensusState>>::Error;
+    type AnyConsensusState = C::ConsensusState;
+
+    fn host_timestamp(&self) -> Result<Timestamp, ContextError> {
+        Ok(Context::host_timestamp(self)?)
+    }
+
+    fn host_height(&self) -> Result<Height, ContextError> {
+        Ok(Context::host_height(self)?)
+    }
+
+    fn consensus_state(
+        &self,
+        client_cons_state_path: &ClientConsensusStatePath,
+    ) -> Result<Self::AnyConsensusState, ContextError> {
+        let height = consensus_state_height(client_cons_state_path)?;
+
+        Ok(self.get_consensus_state(&height)?)
+    }
+
+    fn consensus_state_heights(&self, _client_id: &ClientId) -> Result<Vec<Height>, ContextError> {
+        Ok(Context::consensus_state_heights(self)?)
+    }
+
+    fn first_consensus_state_height(
+        &self,
+        _client_id: &ClientId,
+    ) -> Result<Option<Height>, ContextError> {
+        Ok(Context::first_consensus_state_height(self)?)
+    }
+}
+
+impl<'a, C: ClientType<'a>> TmValidationContext for Context<'a, C>
+where
+    C::ConsensusState: TryInto<TmConsensusState>,
+    <C::ConsensusState as TryInto<TmConsensusState>>::Error: ToString,
+{
+    fn next_consensus_state(
+        &self,
+        _client_id: &ClientId,
+        height: &Height,
+    ) -> Result<Option<Self::AnyConsensusState>, ContextError> {
+        self.next_consensus_state_height(height)?
+            .map(|height| Ok(self.get_consensus_state(&height)?))
+            .transpose()
+    }
+
+    fn prev_consensus_state(
+        &self,
+        _client_id: &ClientId,
+        height: &Height,
+    ) -> Result<Option<Self::AnyConsensusState>, ContextError> {
+        self.prev_consensus_state_height(height)?
+            .map(|height| Ok(self.get_consensus_state(&height)?))
+            .transpose()
+    }
+}
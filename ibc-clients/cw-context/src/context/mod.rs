@@ -0,0 +1,369 @@
+//! Defines the storage adapter the light clients run on within a contract.
+//!
+//! The contract is given access to the client store of its client by the
+//! host, so the store layout mirrors the one of ibc-go's `08-wasm` module:
+//! the client state is kept under `clientState`, the consensus states under
+//! `consensusStates/{height}`, along with their processed time and height,
+//! and an index sorted by height under `iterateConsensusStates`.
+
+mod client_ctx;
+
+use core::marker::PhantomData;
+
+use cosmwasm_std::{Deps, DepsMut, Env, Order, Storage};
+use ibc_client_wasm_types::client_state::ClientState as WasmClientState;
+use ibc_client_wasm_types::consensus_state::ConsensusState as WasmConsensusState;
+use ibc_client_wasm_types::{Bytes, WASM_CLIENT_TYPE};
+use ibc_core_client::context::client_state::ClientStateCommon;
+use ibc_core_client::types::error::ClientError;
+use ibc_core_client::types::Height;
+use ibc_core_host::types::identifiers::{ChainId, ClientId};
+use ibc_primitives::prelude::*;
+use ibc_primitives::proto::{Any, Protobuf};
+use ibc_primitives::Timestamp;
+use prost::Message;
+
+use crate::api::ClientType;
+use crate::error::ContractError;
+
+/// The key of the client state in the client store
+pub const CLIENT_STATE_KEY: &[u8] = b"clientState";
+
+/// The prefix of the index of the consensus states sorted by height
+pub const ITERATE_CONSENSUS_STATE_PREFIX: &[u8] = b"iterateConsensusStates";
+
+/// Returns the key of the consensus state at the given height
+pub fn consensus_state_key(height: &Height) -> Vec<u8> {
+    format!("consensusStates/{height}").into_bytes()
+}
+
+/// Returns the key of the time the consensus state at the given height was
+/// processed at
+pub fn processed_time_key(height: &Height) -> Vec<u8> {
+    format!("consensusStates/{height}/processedTime").into_bytes()
+}
+
+/// Returns the key of the host height the consensus state at the given
+/// height was processed at
+pub fn processed_height_key(height: &Height) -> Vec<u8> {
+    format!("consensusStates/{height}/processedHeight").into_bytes()
+}
+
+/// Returns the key of the given height in the consensus state index. Heights
+/// are encoded big-endian so that the keys sort by height.
+pub fn iteration_key(height: &Height) -> Vec<u8> {
+    let mut key = ITERATE_CONSENSUS_STATE_PREFIX.to_vec();
+    key.extend(height.revision_number().to_be_bytes());
+    key.extend(height.revision_height().to_be_bytes());
+    key
+}
+
+enum StorageRef<'a> {
+    Ref(&'a dyn Storage),
+    Mut(&'a mut dyn Storage),
+}
+
+/// The context of a contract call, implementing the client contexts over the
+/// CosmWasm [`Storage`] of the client store.
+///
+/// Storage is read-only in queries. While migrating a client store, the keys
+/// are prefixed with either the subject or the substitute prefix, as ibc-go
+/// merges both client stores into one.
+pub struct Context<'a, C> {
+    storage: StorageRef<'a>,
+    env: Env,
+    checksum: Option<Bytes>,
+    prefix: &'static [u8],
+    client_type: PhantomData<C>,
+}
+
+impl<'a, C: ClientType<'a>> Context<'a, C> {
+    /// Creates a read-only context, as given to the `query` entry point.
+    pub fn new_ref(deps: Deps<'a>, env: Env) -> Self {
+        Self {
+            storage: StorageRef::Ref(deps.storage),
+            env,
+            checksum: None,
+            prefix: &[],
+            client_type: PhantomData,
+        }
+    }
+
+    /// Creates a mutable context, as given to the `instantiate` and `sudo`
+    /// entry points.
+    pub fn new_mut(deps: DepsMut<'a>, env: Env) -> Self {
+        Self {
+            storage: StorageRef::Mut(deps.storage),
+            env,
+            checksum: None,
+            prefix: &[],
+            client_type: PhantomData,
+        }
+    }
+
+    pub fn env(&self) -> &Env {
+        &self.env
+    }
+
+    /// Sets the checksum of the contract, which is stored along with the
+    /// client state. Only needed before the client state is first stored,
+    /// i.e. upon instantiation.
+    pub fn set_checksum(&mut self, checksum: Bytes) {
+        self.checksum = Some(checksum);
+    }
+
+    /// Prefixes all the keys with the given prefix, e.g. `subject/` or
+    /// `substitute/` during a client store migration.
+    pub fn set_prefix(&mut self, prefix: &'static [u8]) {
+        self.prefix = prefix;
+    }
+
+    /// Returns the identifier the client is given in the client traits. The
+    /// contract only has access to the store of its own client, so the
+    /// identifier is not part of any key.
+    pub fn client_id(&self) -> ClientId {
+        ClientId::new(WASM_CLIENT_TYPE, 0).expect("Never fails because it's valid")
+    }
+
+    fn prefixed_key(&self, key: impl AsRef<[u8]>) -> Vec<u8> {
+        let mut prefixed_key = self.prefix.to_vec();
+        prefixed_key.extend_from_slice(key.as_ref());
+        prefixed_key
+    }
+
+    fn storage(&self) -> &dyn Storage {
+        match &self.storage {
+            StorageRef::Ref(storage) => *storage,
+            StorageRef::Mut(storage) => &**storage,
+        }
+    }
+
+    fn storage_mut(&mut self) -> Result<&mut dyn Storage, ContractError> {
+        match &mut self.storage {
+            StorageRef::Ref(_) => Err(ContractError::ReadOnlyStorage),
+            StorageRef::Mut(storage) => Ok(&mut **storage),
+        }
+    }
+
+    /// Returns the value stored under the given key
+    pub fn retrieve(&self, key: impl AsRef<[u8]>) -> Option<Vec<u8>> {
+        self.storage().get(&self.prefixed_key(key))
+    }
+
+    /// Stores the value under the given key
+    pub fn insert(
+        &mut self,
+        key: impl AsRef<[u8]>,
+        value: impl AsRef<[u8]>,
+    ) -> Result<(), ContractError> {
+        let key = self.prefixed_key(key);
+        self.storage_mut()?.set(&key, value.as_ref());
+        Ok(())
+    }
+
+    /// Removes the value stored under the given key
+    pub fn remove(&mut self, key: impl AsRef<[u8]>) -> Result<(), ContractError> {
+        let key = self.prefixed_key(key);
+        self.storage_mut()?.remove(&key);
+        Ok(())
+    }
+
+    /// Returns the current height of the host, whose revision number is
+    /// derived from the chain identifier.
+    pub fn host_height(&self) -> Result<Height, ClientError> {
+        let revision_number = ChainId::new(&self.env.block.chain_id)
+            .map(|chain_id| chain_id.revision_number())
+            .unwrap_or(0);
+
+        Height::new(revision_number, self.env.block.height)
+    }
+
+    /// Returns the current timestamp of the host
+    pub fn host_timestamp(&self) -> Result<Timestamp, ClientError> {
+        Timestamp::from_nanoseconds(self.env.block.time.nanos())
+            .map_err(ClientError::InvalidPacketTimestamp)
+    }
+
+    /// Returns the Wasm client state, which wraps the client state of the
+    /// contract.
+    pub fn wasm_client_state(&self) -> Result<WasmClientState, ClientError> {
+        let bytes = self
+            .retrieve(CLIENT_STATE_KEY)
+            .ok_or(ClientError::ClientStateNotFound {
+                client_id: self.client_id(),
+            })?;
+
+        Protobuf::<Any>::decode_vec(&bytes).map_err(|e| ClientError::Other {
+            description: e.to_string(),
+        })
+    }
+
+    /// Returns the checksum of the contract
+    pub fn checksum(&self) -> Result<Bytes, ClientError> {
+        match &self.checksum {
+            Some(checksum) => Ok(checksum.clone()),
+            None => Ok(self.wasm_client_state()?.checksum),
+        }
+    }
+
+    /// Returns the client state of the contract
+    pub fn get_client_state(&self) -> Result<C::ClientState, ClientError> {
+        decode_any(&self.wasm_client_state()?.data)?.try_into()
+    }
+
+    /// Stores the client state of the contract, wrapped into the Wasm client
+    /// state.
+    pub fn set_client_state(&mut self, client_state: C::ClientState) -> Result<(), ClientError> {
+        let wasm_client_state = WasmClientState {
+            checksum: self.checksum()?,
+            latest_height: client_state.latest_height(),
+            data: client_state.into().encode_to_vec(),
+        };
+
+        self.insert(
+            CLIENT_STATE_KEY,
+            Protobuf::<Any>::encode_vec(wasm_client_state),
+        )?;
+
+        Ok(())
+    }
+
+    /// Returns the consensus state of the contract at the given height
+    pub fn get_consensus_state(&self, height: &Height) -> Result<C::ConsensusState, ClientError> {
+        let bytes = self.retrieve(consensus_state_key(height)).ok_or(
+            ClientError::ConsensusStateNotFound {
+                client_id: self.client_id(),
+                height: *height,
+            },
+        )?;
+
+        let wasm_consensus_state: WasmConsensusState = Protobuf::<Any>::decode_vec(&bytes)
+            .map_err(|e| ClientError::Other {
+                description: e.to_string(),
+            })?;
+
+        decode_any(&wasm_consensus_state.data)?.try_into()
+    }
+
+    /// Stores the consensus state of the contract at the given height,
+    /// wrapped into the Wasm consensus state, and adds it to the index.
+    pub fn set_consensus_state(
+        &mut self,
+        height: &Height,
+        consensus_state: C::ConsensusState,
+    ) -> Result<(), ClientError> {
+        let wasm_consensus_state = WasmConsensusState::new(consensus_state.into().encode_to_vec());
+
+        self.insert(
+            consensus_state_key(height),
+            Protobuf::<Any>::encode_vec(wasm_consensus_state),
+        )?;
+        self.insert(iteration_key(height), consensus_state_key(height))?;
+
+        Ok(())
+    }
+
+    /// Removes the consensus state at the given height, along with its index
+    /// entry.
+    pub fn remove_consensus_state(&mut self, height: &Height) -> Result<(), ClientError> {
+        self.remove(consensus_state_key(height))?;
+        self.remove(iteration_key(height))?;
+
+        Ok(())
+    }
+
+    /// Returns the heights of the stored consensus states, in ascending order
+    pub fn consensus_state_heights(&self) -> Result<Vec<Height>, ClientError> {
+        self.iterate_heights(None, None, Order::Ascending).collect()
+    }
+
+    /// Returns the lowest height of a stored consensus state, if any.
+    pub fn first_consensus_state_height(&self) -> Result<Option<Height>, ClientError> {
+        self.iterate_heights(None, None, Order::Ascending)
+            .next()
+            .transpose()
+    }
+
+    /// Returns the lowest height of a stored consensus state higher than
+    /// `height`.
+    pub fn next_consensus_state_height(
+        &self,
+        height: &Height,
+    ) -> Result<Option<Height>, ClientError> {
+        self.iterate_heights(Some(height), None, Order::Ascending)
+            .find(|h| !matches!(h, Ok(h) if h == height))
+            .transpose()
+    }
+
+    /// Returns the highest height of a stored consensus state lower than
+    /// `height`.
+    pub fn prev_consensus_state_height(
+        &self,
+        height: &Height,
+    ) -> Result<Option<Height>, ClientError> {
+        self.iterate_heights(None, Some(height), Order::Descending)
+            .next()
+            .transpose()
+    }
+
+    /// Iterates over the heights of the consensus state index from `start`,
+    /// inclusive, to `end`, exclusive. Bounds left open span the whole index.
+    fn iterate_heights(
+        &self,
+        start: Option<&Height>,
+        end: Option<&Height>,
+        order: Order,
+    ) -> impl Iterator<Item = Result<Height, ClientError>> + '_ {
+        let prefix = self.prefixed_key(ITERATE_CONSENSUS_STATE_PREFIX);
+        let start = match start {
+            Some(height) => self.prefixed_key(iteration_key(height)),
+            None => prefix.clone(),
+        };
+        let end = match end {
+            Some(height) => self.prefixed_key(iteration_key(height)),
+            None => prefix_end(&prefix),
+        };
+
+        self.storage()
+            .range_keys(Some(&start), Some(&end), order)
+            .map(move |key| height_from_iteration_key(&key[prefix.len()..]))
+    }
+}
+
+/// Returns the smallest key greater than all the keys starting with
+/// `prefix`.
+fn prefix_end(prefix: &[u8]) -> Vec<u8> {
+    let mut end = prefix.to_vec();
+    while let Some(last) = end.pop() {
+        if last < u8::MAX {
+            end.push(last + 1);
+            return end;
+        }
+    }
+    end
+}
+
+fn height_from_iteration_key(key: &[u8]) -> Result<Height, ClientError> {
+    let invalid_key = || ClientError::Other {
+        description: "invalid consensus state index key".to_string(),
+    };
+
+    let revision_number = key
+        .get(..8)
+        .and_then(|bytes| bytes.try_into().ok())
+        .map(u64::from_be_bytes)
+        .ok_or_else(invalid_key)?;
+    let revision_height = key
+        .get(8..16)
+        .and_then(|bytes| bytes.try_into().ok())
+        .map(u64::from_be_bytes)
+        .ok_or_else(invalid_key)?;
+
+    Height::new(revision_number, revision_height)
+}
+
+pub(crate) fn decode_any(bytes: &[u8]) -> Result<Any, ClientError> {
+    Any::decode(bytes).map_err(|e| ClientError::Other {
+        description: e.to_string(),
+    })
+}
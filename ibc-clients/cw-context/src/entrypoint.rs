@@ -0,0 +1,911 @@
+//! Implements the entry points of the light client contracts.
+//!
+//! Contracts forward their `instantiate`, `sudo` and `query` entry points to
+//! the functions of this module, which run the light client of the given
+//! [`ClientType`] over the client store. The results of `sudo` and `query`
+//! are returned as a JSON-encoded [`ContractResult`], in the `data` of the
+//! response for `sudo`.
+
+use core::str::FromStr;
+
+use cosmwasm_std::{to_json_binary, Binary, Deps, DepsMut, Env, MessageInfo, Response};
+use ibc_client_wasm_types::contract::{
+    CheckForMisbehaviourMsg, ContractResult, InstantiateMsg, MerklePath, QueryMsg, SudoMsg,
+    TimestampAtHeightMsg, UpdateStateMsg, UpdateStateOnMisbehaviourMsg, VerifyClientMessageMsg,
+    VerifyMembershipMsg, VerifyNonMembershipMsg, VerifyUpgradeAndUpdateStateMsg,
+};
+use ibc_client_wasm_types::{SUBJECT_PREFIX, SUBSTITUTE_PREFIX};
+use ibc_core_client::context::client_state::{
+    ClientStateCommon, ClientStateExecution, ClientStateValidation,
+};
+use ibc_core_client::context::consensus_state::ConsensusState;
+use ibc_core_client::context::{ClientExecutionContext, ClientValidationContext};
+use ibc_core_client::types::error::ClientError;
+use ibc_core_client::types::{Height, UpdateKind};
+use ibc_core_commitment_types::commitment::{CommitmentPrefix, CommitmentProofBytes};
+use ibc_core_host::types::path::{ClientConsensusStatePath, ClientStatePath, Path};
+use ibc_primitives::prelude::*;
+use ibc_primitives::proto::Any;
+
+use crate::api::ClientType;
+use crate::context::{decode_any, Context};
+use crate::error::ContractError;
+
+/// Handles the `instantiate` entry point: initialises the client with the
+/// given client and consensus states.
+pub fn instantiate<'a, C: ClientType<'a>>(
+    deps: DepsMut<'a>,
+    env: Env,
+    _info: MessageInfo,
+    msg: InstantiateMsg,
+) -> Result<Response, ContractError> {
+    let mut ctx = Context::<C>::new_mut(deps, env);
+    ctx.set_checksum(msg.checksum);
+
+    let client_state = C::ClientState::try_from(decode_any(&msg.client_state)?)?;
+    let consensus_state = decode_any(&msg.consensus_state)?;
+
+    client_state.verify_consensus_state(consensus_state.clone())?;
+
+    let client_id = ctx.client_id();
+    client_state.initialise(&mut ctx, &client_id, consensus_state)?;
+
+    Ok(Response::default())
+}
+
+/// Handles the `sudo` entry point
+pub fn sudo<'a, C: ClientType<'a>>(
+    deps: DepsMut<'a>,
+    env: Env,
+    msg: SudoMsg,
+) -> Result<Response, ContractError> {
+    let mut ctx = Context::<C>::new_mut(deps, env);
+
+    let result = match msg {
+        SudoMsg::UpdateState(msg) => update_state(&mut ctx, msg)?,
+        SudoMsg::UpdateStateOnMisbehaviour(msg) => update_state_on_misbehaviour(&mut ctx, msg)?,
+        SudoMsg::VerifyUpgradeAndUpdateState(msg) => {
+            verify_upgrade_and_update_state(&mut ctx, msg)?
+        }
+        SudoMsg::VerifyMembership(msg) => verify_membership(&ctx, msg)?,
+        SudoMsg::VerifyNonMembership(msg) => verify_non_membership(&ctx, msg)?,
+        SudoMsg::MigrateClientStore(_) => migrate_client_store(&mut ctx)?,
+    };
+
+    Ok(Response::default().set_data(to_json_binary(&result)?))
+}
+
+/// Handles the `query` entry point
+pub fn query<'a, C: ClientType<'a>>(
+    deps: Deps<'a>,
+    env: Env,
+    msg: QueryMsg,
+) -> Result<Binary, ContractError> {
+    let ctx = Context::<C>::new_ref(deps, env);
+
+    let result = match msg {
+        QueryMsg::Status(_) => status(&ctx)?,
+        QueryMsg::TimestampAtHeight(msg) => timestamp_at_height(&ctx, msg)?,
+        QueryMsg::VerifyClientMessage(msg) => verify_client_message(&ctx, msg)?,
+        QueryMsg::CheckForMisbehaviour(msg) => check_for_misbehaviour(&ctx, msg)?,
+    };
+
+    Ok(to_json_binary(&result)?)
+}
+
+fn update_state<'a, C: ClientType<'a>>(
+    ctx: &mut Context<'a, C>,
+    msg: UpdateStateMsg,
+) -> Result<ContractResult, ContractError> {
+    let client_id = ctx.client_id();
+    let client_state = ctx.get_client_state()?;
+
+    let heights = client_state.update_state(ctx, &client_id, decode_any(&msg.client_message)?)?;
+
+    Ok(ContractResult::heights(heights))
+}
+
+fn update_state_on_misbehaviour<'a, C: ClientType<'a>>(
+    ctx: &mut Context<'a, C>,
+    msg: UpdateStateOnMisbehaviourMsg,
+) -> Result<ContractResult, ContractError> {
+    let client_id = ctx.client_id();
+    let client_state = ctx.get_client_state()?;
+    let client_message = decode_any(&msg.client_message)?;
+    let update_kind = update_kind::<C>(&client_message);
+
+    client_state.update_state_on_misbehaviour(ctx, &client_id, client_message, &update_kind)?;
+
+    Ok(ContractResult::default())
+}
+
+fn verify_upgrade_and_update_state<'a, C: ClientType<'a>>(
+    ctx: &mut Context<'a, C>,
+    msg: VerifyUpgradeAndUpdateStateMsg,
+) -> Result<ContractResult, ContractError> {
+    let client_id = ctx.client_id();
+    let client_state = ctx.get_client_state()?;
+    let consensus_state = ctx.get_consensus_state(&client_state.latest_height())?;
+
+    let upgrade_client_state = decode_any(&msg.upgrade_client_state)?;
+    let upgrade_consensus_state = decode_any(&msg.upgrade_consensus_state)?;
+
+    client_state.verify_upgrade_client(
+        upgrade_client_state.clone(),
+        upgrade_consensus_state.clone(),
+        decode_proof(msg.proof_upgrade_client)?,
+        decode_proof(msg.proof_upgrade_consensus_state)?,
+        consensus_state.root(),
+    )?;
+
+    client_state.update_state_on_upgrade(
+        ctx,
+        &client_id,
+        upgrade_client_state,
+        upgrade_consensus_state,
+    )?;
+
+    Ok(ContractResult::default())
+}
+
+fn verify_membership<'a, C: ClientType<'a>>(
+    ctx: &Context<'a, C>,
+    msg: VerifyMembershipMsg,
+) -> Result<ContractResult, ContractError> {
+    let client_id = ctx.client_id();
+    let client_state = ctx.get_client_state()?;
+
+    client_state.validate_proof_height(msg.height)?;
+    verify_delay_period_passed(
+        ctx,
+        msg.height,
+        msg.delay_time_period,
+        msg.delay_block_period,
+    )?;
+
+    let consensus_state = ctx.get_consensus_state(&msg.height)?;
+    let (prefix, path) = decode_merkle_path(msg.merkle_path)?;
+
+    client_state.verify_membership_with_context(
+        ctx,
+        &client_id,
+        &prefix,
+        &decode_proof(msg.proof)?,
+        consensus_state.root(),
+        msg.height,
+        path,
+        msg.value,
+    )?;
+
+    Ok(ContractResult::default())
+}
+
+fn verify_non_membership<'a, C: ClientType<'a>>(
+    ctx: &Context<'a, C>,
+    msg: VerifyNonMembershipMsg,
+) -> Result<ContractResult, ContractError> {
+    let client_id = ctx.client_id();
+    let client_state = ctx.get_client_state()?;
+
+    client_state.validate_proof_height(msg.height)?;
+    verify_delay_period_passed(
+        ctx,
+        msg.height,
+        msg.delay_time_period,
+        msg.delay_block_period,
+    )?;
+
+    let consensus_state = ctx.get_consensus_state(&msg.height)?;
+    let (prefix, path) = decode_merkle_path(msg.merkle_path)?;
+
+    client_state.verify_non_membership_with_context(
+        ctx,
+        &client_id,
+        &prefix,
+        &decode_proof(msg.proof)?,
+        consensus_state.root(),
+        msg.height,
+        path,
+    )?;
+
+    Ok(ContractResult::default())
+}
+
+/// Replaces the subject client with the substitute client, whose stores
+/// ibc-go merges under the subject and substitute prefixes: the substitute
+/// client state is copied over, along with its latest consensus state.
+fn migrate_client_store<'a, C: ClientType<'a>>(
+    ctx: &mut Context<'a, C>,
+) -> Result<ContractResult, ContractError> {
+    let client_id = ctx.client_id();
+
+    ctx.set_prefix(SUBSTITUTE_PREFIX);
+    let substitute_client_state = ctx.get_client_state()?;
+    let substitute_height = substitute_client_state.latest_height();
+    let substitute_consensus_state = ctx.get_consensus_state(&substitute_height)?;
+    let processed_time = ctx.client_update_time(&client_id, &substitute_height)?;
+    let processed_height = ctx.client_update_height(&client_id, &substitute_height)?;
+
+    ctx.set_prefix(SUBJECT_PREFIX);
+    let subject_client_state = ctx.get_client_state()?;
+
+    if subject_client_state.client_type() != substitute_client_state.client_type() {
+        return Err(ClientError::ClientArgsTypeMismatch {
+            client_type: substitute_client_state.client_type(),
+        }
+        .into());
+    }
+
+    ctx.store_client_state(ClientStatePath::new(&client_id), substitute_client_state)?;
+    ctx.store_consensus_state(
+        ClientConsensusStatePath::new(
+            client_id.clone(),
+            substitute_height.revision_number(),
+            substitute_height.revision_height(),
+        ),
+        substitute_consensus_state,
+    )?;
+    ctx.store_update_time(client_id.clone(), substitute_height, processed_time)?;
+    ctx.store_update_height(client_id, substitute_height, processed_height)?;
+
+    Ok(ContractResult::default())
+}
+
+fn status<'a, C: ClientType<'a>>(ctx: &Context<'a, C>) -> Result<ContractResult, ContractError> {
+    let client_id = ctx.client_id();
+    let client_state = ctx.get_client_state()?;
+
+    Ok(ContractResult::status(
+        client_state.status(ctx, &client_id)?,
+    ))
+}
+
+fn timestamp_at_height<'a, C: ClientType<'a>>(
+    ctx: &Context<'a, C>,
+    msg: TimestampAtHeightMsg,
+) -> Result<ContractResult, ContractError> {
+    let consensus_state = ctx.get_consensus_state(&msg.height)?;
+
+    Ok(ContractResult::timestamp(
+        consensus_state.timestamp().nanoseconds(),
+    ))
+}
+
+fn verify_client_message<'a, C: ClientType<'a>>(
+    ctx: &Context<'a, C>,
+    msg: VerifyClientMessageMsg,
+) -> Result<ContractResult, ContractError> {
+    let client_id = ctx.client_id();
+    let client_state = ctx.get_client_state()?;
+    let client_message = decode_any(&msg.client_message)?;
+    let update_kind = update_kind::<C>(&client_message);
+
+    client_state.verify_client_message(ctx, &client_id, client_message, &update_kind)?;
+
+    Ok(ContractResult::default())
+}
+
+fn check_for_misbehaviour<'a, C: ClientType<'a>>(
+    ctx: &Context<'a, C>,
+    msg: CheckForMisbehaviourMsg,
+) -> Result<ContractResult, ContractError> {
+    let client_id = ctx.client_id();
+    let client_state = ctx.get_client_state()?;
+    let client_message = decode_any(&msg.client_message)?;
+    let update_kind = update_kind::<C>(&client_message);
+
+    let found_misbehaviour =
+        client_state.check_for_misbehaviour(ctx, &client_id, client_message, &update_kind)?;
+
+    Ok(ContractResult::found_misbehaviour(found_misbehaviour))
+}
+
+/// Checks that the delay period of the connection has passed since the
+/// consensus state at `proof_height` was processed, as ibc-go leaves it to
+/// the light client. There is nothing to check without delay period.
+fn verify_delay_period_passed<'a, C: ClientType<'a>>(
+    ctx: &Context<'a, C>,
+    proof_height: Height,
+    delay_time_period: u64,
+    delay_block_period: u64,
+) -> Result<(), ContractError> {
+    if delay_time_period == 0 && delay_block_period == 0 {
+        return Ok(());
+    }
+
+    let client_id = ctx.client_id();
+
+    let current_timestamp = ctx.host_timestamp()?;
+    let processed_time = ctx.client_update_time(&client_id, &proof_height)?;
+    let earliest_timestamp = processed_time
+        .nanoseconds()
+        .saturating_add(delay_time_period);
+    if current_timestamp.nanoseconds() < earliest_timestamp {
+        return Err(ContractError::NotEnoughTimeElapsed {
+            current_timestamp: current_timestamp.nanoseconds(),
+            earliest_timestamp,
+        });
+    }
+
+    let current_height = ctx.host_height()?;
+    let processed_height = ctx.client_update_height(&client_id, &proof_height)?;
+    let earliest_height = processed_height.add(delay_block_period);
+    if current_height < earliest_height {
+        return Err(ContractError::NotEnoughBlocksElapsed {
+            current_height,
+            earliest_height,
+        });
+    }
+
+    Ok(())
+}
+
+/// Returns whether the client message is a header or a misbehaviour
+fn update_kind<'a, C: ClientType<'a>>(client_message: &Any) -> UpdateKind {
+    if client_message.type_url == C::MISBEHAVIOUR_TYPE_URL {
+        UpdateKind::SubmitMisbehaviour
+    } else {
+        UpdateKind::UpdateClient
+    }
+}
+
+/// Decodes the path ibc-go verifies values at, made of the commitment prefix
+/// followed by the path within the counterparty's store.
+fn decode_merkle_path(merkle_path: MerklePath) -> Result<(CommitmentPrefix, Path), ContractError> {
+    let (prefix, path) = match merkle_path.key_path.as_slice() {
+        [prefix, path] => (prefix, path),
+        _ => {
+            return Err(ContractError::InvalidMerklePath {
+                reason: format!(
+                    "expected a prefix and a path, got {} keys",
+                    merkle_path.key_path.len()
+                ),
+            })
+        }
+    };
+
+    let prefix = CommitmentPrefix::try_from(prefix.as_bytes().to_vec()).map_err(|e| {
+        ContractError::InvalidMerklePath {
+            reason: e.to_string(),
+        }
+    })?;
+    let path = Path::from_str(path).map_err(|e| ContractError::InvalidMerklePath {
+        reason: e.to_string(),
+    })?;
+
+    Ok((prefix, path))
+}
+
+fn decode_proof(proof: Vec<u8>) -> Result<CommitmentProofBytes, ClientError> {
+    CommitmentProofBytes::try_from(proof).map_err(ClientError::InvalidCommitmentProof)
+}
+
+#[cfg(test)]
+mod tests {
+    use core::convert::Infallible;
+
+    use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
+    use cosmwasm_std::{from_json, Deps, DepsMut, Env, Timestamp as CwTimestamp};
+    use ibc_client_tendermint::types::ConsensusState as ConsensusStateType;
+    use ibc_client_wasm_types::contract::{MigrateClientStoreMsg, StatusMsg};
+    use ibc_client_wasm_types::Bytes;
+    use ibc_core_commitment_types::commitment::CommitmentRoot;
+    use ibc_core_handler_types::error::ContextError;
+    use ibc_core_host::types::identifiers::ChainId;
+    use ibc_primitives::Timestamp;
+    use ibc_testkit::fixtures::clients::tendermint::ClientStateConfig;
+    use ibc_testkit::testapp::ibc::clients::mock::client_state::{
+        MockClientContext, MockClientState,
+    };
+    use ibc_testkit::testapp::ibc::clients::mock::consensus_state::MockConsensusState;
+    use ibc_testkit::testapp::ibc::clients::mock::header::MockHeader;
+    use ibc_testkit::testapp::ibc::clients::mock::misbehaviour::{
+        Misbehaviour, MOCK_MISBEHAVIOUR_TYPE_URL,
+    };
+    use prost::Message;
+
+    use super::*;
+    use crate::api::TendermintClient;
+
+    struct MockClient;
+
+    impl<'a> ClientType<'a> for MockClient {
+        type ClientState = MockClientState;
+        type ConsensusState = MockConsensusState;
+
+        const MISBEHAVIOUR_TYPE_URL: &'static str = MOCK_MISBEHAVIOUR_TYPE_URL;
+    }
+
+    impl<'a> MockClientContext for Context<'a, MockClient> {
+        type ConversionError = Infallible;
+        type AnyConsensusState = MockConsensusState;
+
+        fn host_timestamp(&self) -> Result<Timestamp, ContextError> {
+            Ok(Context::host_timestamp(self)?)
+        }
+
+        fn host_height(&self) -> Result<Height, ContextError> {
+            Ok(Context::host_height(self)?)
+        }
+
+        fn consensus_state(
+            &self,
+            client_cons_state_path: &ClientConsensusStatePath,
+        ) -> Result<Self::AnyConsensusState, ContextError> {
+            let height = Height::new(
+                client_cons_state_path.revision_number,
+                client_cons_state_path.revision_height,
+            )?;
+
+            Ok(self.get_consensus_state(&height)?)
+        }
+    }
+
+    const CHECKSUM: [u8; 32] = [7; 32];
+
+    /// The block time of `mock_env`, in nanoseconds
+    const BLOCK_TIME: u64 = 1_571_797_419_879_305_533;
+
+    fn height(revision_height: u64) -> Height {
+        Height::new(0, revision_height).unwrap()
+    }
+
+    fn header(revision_height: u64) -> MockHeader {
+        MockHeader::new(height(revision_height)).with_timestamp(
+            Timestamp::from_nanoseconds(BLOCK_TIME - 1_000_000_000 + revision_height).unwrap(),
+        )
+    }
+
+    fn encode(value: impl Into<Any>) -> Bytes {
+        value.into().encode_to_vec()
+    }
+
+    fn env_at(block_time: u64) -> Env {
+        let mut env = mock_env();
+        env.block.time = CwTimestamp::from_nanos(block_time);
+        env
+    }
+
+    fn instantiate_mock_client(deps: DepsMut<'_>, revision_height: u64) {
+        let msg = InstantiateMsg {
+            client_state: encode(MockClientState::new(header(revision_height))),
+            consensus_state: encode(MockConsensusState::new(header(revision_height))),
+            checksum: CHECKSUM.to_vec(),
+        };
+
+        instantiate::<MockClient>(deps, mock_env(), mock_info("creator", &[]), msg).unwrap();
+    }
+
+    fn sudo_mock_client(
+        deps: DepsMut<'_>,
+        env: Env,
+        msg: SudoMsg,
+    ) -> Result<ContractResult, ContractError> {
+        let response = sudo::<MockClient>(deps, env, msg)?;
+
+        Ok(from_json(response.data.unwrap()).unwrap())
+    }
+
+    fn query_mock_client(deps: Deps<'_>, msg: QueryMsg) -> Result<ContractResult, ContractError> {
+        Ok(from_json(query::<MockClient>(deps, mock_env(), msg)?).unwrap())
+    }
+
+    fn status_of_mock_client(deps: Deps<'_>) -> String {
+        query_mock_client(deps, QueryMsg::Status(StatusMsg {}))
+            .unwrap()
+            .status
+            .unwrap()
+    }
+
+    fn verify_membership_msg(proof_height: u64, key_path: &[&str]) -> VerifyMembershipMsg {
+        VerifyMembershipMsg {
+            height: height(proof_height),
+            delay_time_period: 0,
+            delay_block_period: 0,
+            proof: vec![1],
+            merkle_path: MerklePath {
+                key_path: key_path.iter().map(ToString::to_string).collect(),
+            },
+            value: vec![2],
+        }
+    }
+
+    #[test]
+    fn test_instantiate() {
+        let mut deps = mock_dependencies();
+        instantiate_mock_client(deps.as_mut(), 10);
+
+        let ctx = Context::<MockClient>::new_ref(deps.as_ref(), mock_env());
+        let wasm_client_state = ctx.wasm_client_state().unwrap();
+        assert_eq!(wasm_client_state.checksum, CHECKSUM.to_vec());
+        assert_eq!(wasm_client_state.latest_height, height(10));
+        assert_eq!(ctx.consensus_state_heights().unwrap(), vec![height(10)]);
+
+        assert_eq!(status_of_mock_client(deps.as_ref()), "Active");
+
+        let result = query_mock_client(
+            deps.as_ref(),
+            QueryMsg::TimestampAtHeight(TimestampAtHeightMsg { height: height(10) }),
+        )
+        .unwrap();
+        assert_eq!(result.timestamp, Some(header(10).timestamp.nanoseconds()));
+    }
+
+    #[test]
+    fn test_instantiate_with_invalid_consensus_state() {
+        let mut deps = mock_dependencies();
+        let msg = InstantiateMsg {
+            client_state: encode(MockClientState::new(header(10))),
+            consensus_state: encode(MockClientState::new(header(10))),
+            checksum: CHECKSUM.to_vec(),
+        };
+
+        let res = instantiate::<MockClient>(deps.as_mut(), mock_env(), mock_info("", &[]), msg);
+
+        assert!(res.is_err());
+    }
+
+    #[test]
+    fn test_update_state() {
+        let mut deps = mock_dependencies();
+        instantiate_mock_client(deps.as_mut(), 10);
+
+        let res = query_mock_client(
+            deps.as_ref(),
+            QueryMsg::VerifyClientMessage(VerifyClientMessageMsg {
+                client_message: encode(header(5)),
+            }),
+        );
+        assert!(res.is_err(), "a header below the latest height is rejected");
+
+        query_mock_client(
+            deps.as_ref(),
+            QueryMsg::VerifyClientMessage(VerifyClientMessageMsg {
+                client_message: encode(header(20)),
+            }),
+        )
+        .unwrap();
+
+        let result = sudo_mock_client(
+            deps.as_mut(),
+            mock_env(),
+            SudoMsg::UpdateState(UpdateStateMsg {
+                client_message: encode(header(20)),
+            }),
+        )
+        .unwrap();
+        assert_eq!(result.heights, vec![height(20)]);
+
+        let ctx = Context::<MockClient>::new_ref(deps.as_ref(), mock_env());
+        let client_id = ctx.client_id();
+        assert_eq!(ctx.get_client_state().unwrap().latest_height(), height(20));
+        assert_eq!(
+            ctx.consensus_state_heights().unwrap(),
+            vec![height(10), height(20)]
+        );
+        assert_eq!(
+            ctx.client_update_time(&client_id, &height(20))
+                .unwrap()
+                .nanoseconds(),
+            BLOCK_TIME
+        );
+        assert_eq!(
+            ctx.client_update_height(&client_id, &height(20)).unwrap(),
+            Height::new(14002, 12_345).unwrap()
+        );
+
+        assert_eq!(status_of_mock_client(deps.as_ref()), "Active");
+    }
+
+    #[test]
+    fn test_misbehaviour() {
+        let mut deps = mock_dependencies();
+        instantiate_mock_client(deps.as_mut(), 10);
+
+        let misbehaviour = encode(Misbehaviour {
+            client_id: Default::default(),
+            header1: header(20),
+            header2: header(20),
+        });
+
+        let result = query_mock_client(
+            deps.as_ref(),
+            QueryMsg::CheckForMisbehaviour(CheckForMisbehaviourMsg {
+                client_message: encode(header(20)),
+            }),
+        )
+        .unwrap();
+        assert!(!result.found_misbehaviour);
+
+        let result = query_mock_client(
+            deps.as_ref(),
+            QueryMsg::CheckForMisbehaviour(CheckForMisbehaviourMsg {
+                client_message: misbehaviour.clone(),
+            }),
+        )
+        .unwrap();
+        assert!(result.found_misbehaviour);
+
+        sudo_mock_client(
+            deps.as_mut(),
+            mock_env(),
+            SudoMsg::UpdateStateOnMisbehaviour(UpdateStateOnMisbehaviourMsg {
+                client_message: misbehaviour,
+            }),
+        )
+        .unwrap();
+
+        assert_eq!(status_of_mock_client(deps.as_ref()), "Frozen");
+    }
+
+    #[test]
+    fn test_verify_membership() {
+        let mut deps = mock_dependencies();
+        instantiate_mock_client(deps.as_mut(), 10);
+
+        let key_path = ["ibc", "clients/07-tendermint-0/clientState"];
+
+        sudo_mock_client(
+            deps.as_mut(),
+            mock_env(),
+            SudoMsg::VerifyMembership(verify_membership_msg(10, &key_path)),
+        )
+        .unwrap();
+
+        let res = sudo_mock_client(
+            deps.as_mut(),
+            mock_env(),
+            SudoMsg::VerifyMembership(verify_membership_msg(11, &key_path)),
+        );
+        assert!(res.is_err(), "the proof height is above the latest height");
+
+        let res = sudo_mock_client(
+            deps.as_mut(),
+            mock_env(),
+            SudoMsg::VerifyMembership(verify_membership_msg(10, &key_path[1..])),
+        );
+        assert!(matches!(res, Err(ContractError::InvalidMerklePath { .. })));
+
+        sudo_mock_client(
+            deps.as_mut(),
+            mock_env(),
+            SudoMsg::VerifyNonMembership(VerifyNonMembershipMsg {
+                height: height(10),
+                delay_time_period: 0,
+                delay_block_period: 0,
+                proof: vec![1],
+                merkle_path: MerklePath {
+                    key_path: key_path.iter().map(ToString::to_string).collect(),
+                },
+            }),
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn test_verify_membership_delay_period() {
+        let mut deps = mock_dependencies();
+        instantiate_mock_client(deps.as_mut(), 10);
+
+        sudo_mock_client(
+            deps.as_mut(),
+            mock_env(),
+            SudoMsg::UpdateState(UpdateStateMsg {
+                client_message: encode(header(20)),
+            }),
+        )
+        .unwrap();
+
+        let key_path = ["ibc", "clients/07-tendermint-0/clientState"];
+        let msg = VerifyMembershipMsg {
+            delay_time_period: 1_000,
+            ..verify_membership_msg(20, &key_path)
+        };
+
+        let res = sudo_mock_client(
+            deps.as_mut(),
+            mock_env(),
+            SudoMsg::VerifyMembership(msg.clone()),
+        );
+        assert!(matches!(
+            res,
+            Err(ContractError::NotEnoughTimeElapsed { .. })
+        ));
+
+        sudo_mock_client(
+            deps.as_mut(),
+            env_at(BLOCK_TIME + 1_000),
+            SudoMsg::VerifyMembership(msg.clone()),
+        )
+        .unwrap();
+
+        let res = sudo_mock_client(
+            deps.as_mut(),
+            env_at(BLOCK_TIME + 1_000),
+            SudoMsg::VerifyMembership(VerifyMembershipMsg {
+                delay_block_period: 1,
+                ..msg
+            }),
+        );
+        assert!(matches!(
+            res,
+            Err(ContractError::NotEnoughBlocksElapsed { .. })
+        ));
+    }
+
+    #[test]
+    fn test_query_storage_is_read_only() {
+        let mut deps = mock_dependencies();
+        instantiate_mock_client(deps.as_mut(), 10);
+
+        let mut ctx = Context::<MockClient>::new_ref(deps.as_ref(), mock_env());
+
+        assert!(matches!(
+            ctx.insert(b"key", b"value"),
+            Err(ContractError::ReadOnlyStorage)
+        ));
+    }
+
+    #[test]
+    fn test_migrate_client_store() {
+        let mut deps = mock_dependencies();
+
+        for (prefix, revision_height) in [(SUBJECT_PREFIX, 10), (SUBSTITUTE_PREFIX, 20)] {
+            let mut ctx = Context::<MockClient>::new_mut(deps.as_mut(), mock_env());
+            ctx.set_prefix(prefix);
+            ctx.set_checksum(CHECKSUM.to_vec());
+
+            let client_id = ctx.client_id();
+            let client_state = MockClientState::new(header(revision_height));
+            client_state
+                .initialise(
+                    &mut ctx,
+                    &client_id,
+                    MockConsensusState::new(header(revision_height)).into(),
+                )
+                .unwrap();
+            ctx.store_update_time(
+                client_id.clone(),
+                height(revision_height),
+                Timestamp::from_nanoseconds(BLOCK_TIME).unwrap(),
+            )
+            .unwrap();
+            ctx.store_update_height(client_id, height(revision_height), height(100))
+                .unwrap();
+        }
+
+        sudo_mock_client(
+            deps.as_mut(),
+            mock_env(),
+            SudoMsg::MigrateClientStore(MigrateClientStoreMsg {}),
+        )
+        .unwrap();
+
+        let mut ctx = Context::<MockClient>::new_ref(deps.as_ref(), mock_env());
+        ctx.set_prefix(SUBJECT_PREFIX);
+        let client_id = ctx.client_id();
+
+        assert_eq!(ctx.get_client_state().unwrap().latest_height(), height(20));
+        assert_eq!(ctx.checksum().unwrap(), CHECKSUM.to_vec());
+        assert_eq!(
+            ctx.consensus_state_heights().unwrap(),
+            vec![height(10), height(20)]
+        );
+        assert_eq!(
+            ctx.client_update_height(&client_id, &height(20)).unwrap(),
+            height(100)
+        );
+    }
+
+    #[test]
+    fn test_consensus_state_iteration() {
+        let mut deps = mock_dependencies();
+        let mut ctx = Context::<MockClient>::new_mut(deps.as_mut(), mock_env());
+
+        let revision_one = Height::new(1, 2).unwrap();
+        for h in [height(7), revision_one, height(5), height(10)] {
+            ctx.set_consensus_state(&h, MockConsensusState::new(MockHeader::new(h)))
+                .unwrap();
+        }
+
+        assert_eq!(
+            ctx.consensus_state_heights().unwrap(),
+            vec![height(5), height(7), height(10), revision_one]
+        );
+
+        assert_eq!(
+            ctx.next_consensus_state_height(&height(7)).unwrap(),
+            Some(height(10))
+        );
+        assert_eq!(
+            ctx.next_consensus_state_height(&height(8)).unwrap(),
+            Some(height(10))
+        );
+        assert_eq!(
+            ctx.next_consensus_state_height(&height(10)).unwrap(),
+            Some(revision_one)
+        );
+        assert_eq!(
+            ctx.next_consensus_state_height(&revision_one).unwrap(),
+            None
+        );
+
+        assert_eq!(
+            ctx.prev_consensus_state_height(&height(7)).unwrap(),
+            Some(height(5))
+        );
+        assert_eq!(
+            ctx.prev_consensus_state_height(&revision_one).unwrap(),
+            Some(height(10))
+        );
+        assert_eq!(ctx.prev_consensus_state_height(&height(5)).unwrap(), None);
+
+        ctx.remove_consensus_state(&height(10)).unwrap();
+
+        assert_eq!(
+            ctx.next_consensus_state_height(&height(7)).unwrap(),
+            Some(revision_one)
+        );
+        assert!(ctx.get_consensus_state(&height(10)).is_err());
+    }
+
+    #[test]
+    fn test_tendermint_client() {
+        let mut deps = mock_dependencies();
+
+        let client_state: ibc_client_tendermint::client_state::ClientState =
+            ClientStateConfig::builder()
+                .chain_id(ChainId::new("ibc-0").unwrap())
+                .latest_height(height(10))
+                .build()
+                .try_into()
+                .unwrap();
+        let timestamp =
+            tendermint::Time::from_unix_timestamp((BLOCK_TIME / 1_000_000_000 - 3_600) as i64, 0)
+                .unwrap();
+        let consensus_state = ConsensusStateType::new(
+            CommitmentRoot::from_bytes(&[1; 32]),
+            timestamp,
+            tendermint::Hash::Sha256([2; 32]),
+        );
+
+        let msg = InstantiateMsg {
+            client_state: encode(client_state),
+            consensus_state: encode(
+                ibc_client_tendermint::consensus_state::ConsensusState::from(consensus_state),
+            ),
+            checksum: CHECKSUM.to_vec(),
+        };
+        instantiate::<TendermintClient>(deps.as_mut(), mock_env(), mock_info("", &[]), msg)
+            .unwrap();
+
+        let status: ContractResult = from_json(
+            query::<TendermintClient>(deps.as_ref(), mock_env(), QueryMsg::Status(StatusMsg {}))
+                .unwrap(),
+        )
+        .unwrap();
+        assert_eq!(status.status.as_deref(), Some("Active"));
+
+        let expired_env = env_at(BLOCK_TIME + 64_000 * 1_000_000_000);
+        let status: ContractResult = from_json(
+            query::<TendermintClient>(deps.as_ref(), expired_env, QueryMsg::Status(StatusMsg {}))
+                .unwrap(),
+        )
+        .unwrap();
+        assert_eq!(status.status.as_deref(), Some("Expired"));
+
+        let result: ContractResult = from_json(
+            query::<TendermintClient>(
+                deps.as_ref(),
+                mock_env(),
+                QueryMsg::TimestampAtHeight(TimestampAtHeightMsg { height: height(10) }),
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        assert_eq!(
+            result.timestamp,
+            Some((BLOCK_TIME / 1_000_000_000 - 3_600) * 1_000_000_000)
+        );
+    }
+}
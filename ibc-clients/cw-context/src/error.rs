@@ -0,0 +1,83 @@
+//! Defines the error type of the light client contracts
+
+use cosmwasm_std::StdError;
+use displaydoc::Display;
+use ibc_client_wasm_types::error::Error as WasmClientError;
+use ibc_core_client::types::error::ClientError;
+use ibc_core_client::types::Height;
+use ibc_core_handler_types::error::ContextError;
+use ibc_primitives::prelude::*;
+
+/// The error returned by the contract entry points
+#[derive(Debug, Display)]
+pub enum ContractError {
+    /// cosmwasm error: `{0}`
+    Std(StdError),
+    /// client error: `{0}`
+    Client(ClientError),
+    /// context error: `{0}`
+    Context(ContextError),
+    /// wasm client error: `{0}`
+    Wasm(WasmClientError),
+    /// invalid merkle path: `{reason}`
+    InvalidMerklePath { reason: String },
+    /// the storage is read-only in queries
+    ReadOnlyStorage,
+    /// not enough time elapsed, current timestamp `{current_timestamp}` is still less than earliest acceptable timestamp `{earliest_timestamp}`
+    NotEnoughTimeElapsed {
+        current_timestamp: u64,
+        earliest_timestamp: u64,
+    },
+    /// not enough blocks elapsed, current height `{current_height}` is still less than earliest acceptable height `{earliest_height}`
+    NotEnoughBlocksElapsed {
+        current_height: Height,
+        earliest_height: Height,
+    },
+}
+
+impl std::error::Error for ContractError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match &self {
+            Self::Std(e) => Some(e),
+            Self::Client(e) => Some(e),
+            Self::Context(e) => Some(e),
+            Self::Wasm(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<StdError> for ContractError {
+    fn from(e: StdError) -> Self {
+        Self::Std(e)
+    }
+}
+
+impl From<ClientError> for ContractError {
+    fn from(e: ClientError) -> Self {
+        Self::Client(e)
+    }
+}
+
+impl From<ContextError> for ContractError {
+    fn from(e: ContextError) -> Self {
+        Self::Context(e)
+    }
+}
+
+impl From<WasmClientError> for ContractError {
+    fn from(e: WasmClientError) -> Self {
+        Self::Wasm(e)
+    }
+}
+
+impl From<ContractError> for ClientError {
+    fn from(e: ContractError) -> Self {
+        match e {
+            ContractError::Client(e) => e,
+            _ => Self::Other {
+                description: e.to_string(),
+            },
+        }
+    }
+}
@@ -0,0 +1,65 @@
+//! A framework for deploying ibc-rs light clients as ICS-08 Wasm light client
+//! contracts.
+//!
+//! Any light client whose client state implements
+//! [`ClientStateCommon`](ibc_core_client::context::client_state::ClientStateCommon),
+//! [`ClientStateValidation`](ibc_core_client::context::client_state::ClientStateValidation)
+//! and [`ClientStateExecution`](ibc_core_client::context::client_state::ClientStateExecution)
+//! over the [`Context`](context::Context) storage adapter can be turned into
+//! a CosmWasm contract by implementing [`ClientType`](api::ClientType) and
+//! forwarding the contract entry points to [`instantiate`], [`sudo`] and
+//! [`query`]:
+//!
+//! ```ignore
+//! use cosmwasm_std::{entry_point, Binary, Deps, DepsMut, Env, MessageInfo, Response};
+//! use ibc_client_cw::api::TendermintClient;
+//! use ibc_client_cw::types::contract::{InstantiateMsg, QueryMsg, SudoMsg};
+//! use ibc_client_cw::types::ContractError;
+//!
+//! #[entry_point]
+//! pub fn instantiate(
+//!     deps: DepsMut<'_>,
+//!     env: Env,
+//!     info: MessageInfo,
+//!     msg: InstantiateMsg,
+//! ) -> Result<Response, ContractError> {
+//!     ibc_client_cw::instantiate::<TendermintClient>(deps, env, info, msg)
+//! }
+//!
+//! #[entry_point]
+//! pub fn sudo(deps: DepsMut<'_>, env: Env, msg: SudoMsg) -> Result<Response, ContractError> {
+//!     ibc_client_cw::sudo::<TendermintClient>(deps, env, msg)
+//! }
+//!
+//! #[entry_point]
+//! pub fn query(deps: Deps<'_>, env: Env, msg: QueryMsg) -> Result<Binary, ContractError> {
+//!     ibc_client_cw::query::<TendermintClient>(deps, env, msg)
+//! }
+//! ```
+#![forbid(unsafe_code)]
+#![cfg_attr(not(test), deny(clippy::unwrap_used))]
+#![cfg_attr(not(test), deny(clippy::disallowed_methods, clippy::disallowed_types))]
+#![deny(
+    warnings,
+    trivial_casts,
+    trivial_numeric_casts,
+    unused_import_braces,
+    unused_qualifications,
+    rust_2018_idioms
+)]
+
+pub mod api;
+pub mod context;
+pub mod entrypoint;
+pub mod error;
+
+pub use entrypoint::{instantiate, query, sudo};
+
+/// Re-exports the contract messages and the error type of the light client
+/// contracts.
+pub mod types {
+    #[doc(inline)]
+    pub use ibc_client_wasm_types::*;
+
+    pub use crate::error::ContractError;
+}
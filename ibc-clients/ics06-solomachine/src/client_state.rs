@@ -0,0 +1,729 @@
+//! This module includes trait implementations for the
+//! `ibc_client_solomachine_types::ClientState` type. Implemented traits include
+//! `ClientStateCommon`, `ClientStateValidation`, and `ClientStateExecution`.
+//!
+//! Note that this crate defines a newtype wrapper around the
+//! `ibc_client_solomachine_types::ClientState` type in order to enable
+//! implementing a foreign trait on a foreign type (i.e. the orphan rule in
+//! Rust). As such, this module also includes some trait implementations that
+//! serve to pass through traits implemented on the wrapped `ClientState` type.
+
+use ibc_client_solomachine_types::error::Error;
+use ibc_client_solomachine_types::proto::v3::ClientState as RawSolomachineClientState;
+use ibc_client_solomachine_types::{
+    client_type as solomachine_client_type, merkle_path_bytes, ClientState as ClientStateType,
+    ConsensusState as ConsensusStateType, Header, Misbehaviour, PublicKey, SignBytes,
+    SignatureAndData, SignatureData, TimestampedSignatureData, SENTINEL_HEADER_PATH,
+};
+use ibc_core_client::context::client_state::{
+    ClientStateCommon, ClientStateExecution, ClientStateValidation,
+};
+use ibc_core_client::context::{ClientExecutionContext, ClientValidationContext};
+use ibc_core_client::types::error::ClientError;
+use ibc_core_client::types::{Height, Status, UpdateKind};
+use ibc_core_commitment_types::commitment::{
+    CommitmentPrefix, CommitmentProofBytes, CommitmentRoot,
+};
+use ibc_core_host::types::identifiers::{ClientId, ClientType};
+use ibc_core_host::types::path::{ClientConsensusStatePath, ClientStatePath, Path};
+use ibc_primitives::prelude::*;
+use ibc_primitives::proto::{Any, Protobuf};
+use ibc_primitives::Timestamp;
+
+use super::consensus_state::ConsensusState as SolomachineConsensusState;
+use crate::context::{
+    CommonContext, ExecutionContext as SolomachineExecutionContext,
+    ValidationContext as SolomachineValidationContext,
+};
+
+/// Newtype wrapper around the `ClientState` type imported from the
+/// `ibc-client-solomachine-types` crate. This wrapper exists so that we can
+/// bypass Rust's orphan rules and implement traits from
+/// `ibc::core::client::context` on the `ClientState` type.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ClientState(ClientStateType);
+
+impl ClientState {
+    pub fn new(
+        sequence: u64,
+        is_frozen: bool,
+        consensus_state: ConsensusStateType,
+    ) -> Result<Self, Error> {
+        Ok(Self(ClientStateType::new(
+            sequence,
+            is_frozen,
+            consensus_state,
+        )?))
+    }
+
+    pub fn inner(&self) -> &ClientStateType {
+        &self.0
+    }
+
+    /// Returns the bytes the solo machine must have signed over at the
+    /// current sequence for the given path and data.
+    fn sign_bytes(&self, timestamp: u64, path: Vec<u8>, data: Vec<u8>) -> Vec<u8> {
+        SignBytes {
+            sequence: self.0.sequence(),
+            timestamp,
+            diversifier: self.0.consensus_state().diversifier.clone(),
+            path,
+            data,
+        }
+        .encode_vec()
+    }
+
+    /// Decodes the signature carried by the given proof and checks that it
+    /// was not produced before the current consensus state.
+    fn decode_proof(
+        &self,
+        proof: &CommitmentProofBytes,
+    ) -> Result<TimestampedSignatureData, ClientError> {
+        let signature = TimestampedSignatureData::decode_vec(proof.as_ref()).map_err(|e| {
+            Error::InvalidSignatureData {
+                reason: e.to_string(),
+            }
+        })?;
+        self.check_timestamp(signature.timestamp)?;
+
+        Ok(signature)
+    }
+
+    fn check_timestamp(&self, timestamp: u64) -> Result<(), Error> {
+        let consensus_timestamp = self.0.consensus_state().timestamp.nanoseconds();
+        if timestamp < consensus_timestamp {
+            return Err(Error::TimestampTooLow {
+                timestamp,
+                consensus_timestamp,
+            });
+        }
+        Ok(())
+    }
+
+    fn verify_header<V>(&self, ctx: &V, header: &Header) -> Result<(), ClientError>
+    where
+        V: SolomachineValidationContext,
+    {
+        self.check_timestamp(header.timestamp)?;
+
+        let sign_bytes = self.sign_bytes(
+            header.timestamp,
+            SENTINEL_HEADER_PATH.as_bytes().to_vec(),
+            header.header_data().encode_vec(),
+        );
+
+        verify_signature(
+            ctx,
+            &self.0.consensus_state().public_key,
+            &sign_bytes,
+            &header.signature,
+        )
+    }
+
+    fn verify_misbehaviour<V>(
+        &self,
+        ctx: &V,
+        misbehaviour: &Misbehaviour,
+    ) -> Result<(), ClientError>
+    where
+        V: SolomachineValidationContext,
+    {
+        let verify_signature_and_data = |signature_and_data: &SignatureAndData| {
+            self.check_timestamp(signature_and_data.timestamp)?;
+
+            let sign_bytes = SignBytes {
+                sequence: misbehaviour.sequence,
+                timestamp: signature_and_data.timestamp,
+                diversifier: self.0.consensus_state().diversifier.clone(),
+                path: signature_and_data.path.clone(),
+                data: signature_and_data.data.clone(),
+            }
+            .encode_vec();
+
+            verify_signature(
+                ctx,
+                &self.0.consensus_state().public_key,
+                &sign_bytes,
+                &signature_and_data.signature,
+            )
+        };
+
+        verify_signature_and_data(&misbehaviour.signature_one)?;
+        verify_signature_and_data(&misbehaviour.signature_two)
+    }
+}
+
+impl From<ClientStateType> for ClientState {
+    fn from(client_state: ClientStateType) -> Self {
+        Self(client_state)
+    }
+}
+
+impl Protobuf<RawSolomachineClientState> for ClientState {}
+
+impl TryFrom<RawSolomachineClientState> for ClientState {
+    type Error = Error;
+
+    fn try_from(raw: RawSolomachineClientState) -> Result<Self, Self::Error> {
+        Ok(Self(ClientStateType::try_from(raw)?))
+    }
+}
+
+impl From<ClientState> for RawSolomachineClientState {
+    fn from(client_state: ClientState) -> Self {
+        client_state.0.into()
+    }
+}
+
+impl Protobuf<Any> for ClientState {}
+
+impl TryFrom<Any> for ClientState {
+    type Error = ClientError;
+
+    fn try_from(raw: Any) -> Result<Self, Self::Error> {
+        Ok(Self(ClientStateType::try_from(raw)?))
+    }
+}
+
+impl From<ClientState> for Any {
+    fn from(client_state: ClientState) -> Self {
+        client_state.0.into()
+    }
+}
+
+impl ClientStateCommon for ClientState {
+    fn verify_consensus_state(&self, consensus_state: Any) -> Result<(), ClientError> {
+        SolomachineConsensusState::try_from(consensus_state)?;
+
+        Ok(())
+    }
+
+    fn client_type(&self) -> ClientType {
+        solomachine_client_type()
+    }
+
+    /// The latest height of a solo machine client is its current sequence,
+    /// at revision number zero.
+    fn latest_height(&self) -> Height {
+        self.0.latest_height()
+    }
+
+    /// Solo machine proofs are signatures at the current sequence, so proofs
+    /// are only valid at the latest height.
+    fn validate_proof_height(&self, proof_height: Height) -> Result<(), ClientError> {
+        if self.latest_height() != proof_height {
+            return Err(ClientError::InvalidProofHeight {
+                latest_height: self.latest_height(),
+                proof_height,
+            });
+        }
+        Ok(())
+    }
+
+    fn verify_upgrade_client(
+        &self,
+        _upgraded_client_state: Any,
+        _upgraded_consensus_state: Any,
+        _proof_upgrade_client: CommitmentProofBytes,
+        _proof_upgrade_consensus_state: CommitmentProofBytes,
+        _root: &CommitmentRoot,
+    ) -> Result<(), ClientError> {
+        Err(Error::UnsupportedOperation {
+            operation: "client upgrades".into(),
+        })?
+    }
+
+    /// Always fails, since verifying the signature of the solo machine
+    /// requires the host. IBC handlers call
+    /// [`ClientStateValidation::verify_membership_with_context`] instead.
+    fn verify_membership(
+        &self,
+        _prefix: &CommitmentPrefix,
+        _proof: &CommitmentProofBytes,
+        _root: &CommitmentRoot,
+        _path: Path,
+        _value: Vec<u8>,
+    ) -> Result<(), ClientError> {
+        Err(Error::MissingHostContext)?
+    }
+
+    /// Always fails, since verifying the signature of the solo machine
+    /// requires the host. IBC handlers call
+    /// [`ClientStateValidation::verify_non_membership_with_context`] instead.
+    fn verify_non_membership(
+        &self,
+        _prefix: &CommitmentPrefix,
+        _proof: &CommitmentProofBytes,
+        _root: &CommitmentRoot,
+        _path: Path,
+    ) -> Result<(), ClientError> {
+        Err(Error::MissingHostContext)?
+    }
+}
+
+impl<V> ClientStateValidation<V> for ClientState
+where
+    V: ClientValidationContext + SolomachineValidationContext,
+{
+    /// Verifies that a header is signed by the current public key, or that
+    /// both signatures of a misbehaviour are.
+    fn verify_client_message(
+        &self,
+        ctx: &V,
+        _client_id: &ClientId,
+        client_message: Any,
+        update_kind: &UpdateKind,
+    ) -> Result<(), ClientError> {
+        match update_kind {
+            UpdateKind::UpdateClient => {
+                let header = Header::try_from(client_message)?;
+                self.verify_header(ctx, &header)
+            }
+            UpdateKind::SubmitMisbehaviour => {
+                let misbehaviour = Misbehaviour::try_from(client_message)?;
+                self.verify_misbehaviour(ctx, &misbehaviour)
+            }
+        }
+    }
+
+    /// A verified misbehaviour always proves that the solo machine signed two
+    /// different messages at the same sequence.
+    fn check_for_misbehaviour(
+        &self,
+        _ctx: &V,
+        _client_id: &ClientId,
+        _client_message: Any,
+        update_kind: &UpdateKind,
+    ) -> Result<bool, ClientError> {
+        Ok(matches!(update_kind, UpdateKind::SubmitMisbehaviour))
+    }
+
+    /// The solo machine client does not expire, it is active until frozen.
+    fn status(&self, _ctx: &V, _client_id: &ClientId) -> Result<Status, ClientError> {
+        if self.0.is_frozen() {
+            return Ok(Status::Frozen);
+        }
+        Ok(Status::Active)
+    }
+
+    /// Verifies that the solo machine signed `value` at `path`, at the
+    /// current sequence.
+    ///
+    /// Unlike ibc-go, the sequence is not incremented after a successful
+    /// verification, since verification has no access to the client store.
+    /// Solo machines therefore move to a new sequence by submitting a
+    /// header.
+    fn verify_membership_with_context(
+        &self,
+        ctx: &V,
+        _client_id: &ClientId,
+        prefix: &CommitmentPrefix,
+        proof: &CommitmentProofBytes,
+        _root: &CommitmentRoot,
+        _proof_height: Height,
+        path: Path,
+        value: Vec<u8>,
+    ) -> Result<(), ClientError> {
+        let signature = self.decode_proof(proof)?;
+        let sign_bytes = self.sign_bytes(
+            signature.timestamp,
+            merkle_path_bytes(prefix, &path.to_string()),
+            value,
+        );
+
+        verify_signature(
+            ctx,
+            &self.0.consensus_state().public_key,
+            &sign_bytes,
+            &signature.signature_data,
+        )
+    }
+
+    /// Verifies that the solo machine signed the absence of a value at
+    /// `path`, i.e. an empty value, at the current sequence.
+    fn verify_non_membership_with_context(
+        &self,
+        ctx: &V,
+        client_id: &ClientId,
+        prefix: &CommitmentPrefix,
+        proof: &CommitmentProofBytes,
+        root: &CommitmentRoot,
+        proof_height: Height,
+        path: Path,
+    ) -> Result<(), ClientError> {
+        self.verify_membership_with_context(
+            ctx,
+            client_id,
+            prefix,
+            proof,
+            root,
+            proof_height,
+            path,
+            Vec::new(),
+        )
+    }
+}
+
+impl<E> ClientStateExecution<E> for ClientState
+where
+    E: SolomachineExecutionContext + SolomachineValidationContext,
+    <E as ClientExecutionContext>::AnyClientState: From<ClientState>,
+    <E as ClientExecutionContext>::AnyConsensusState: From<SolomachineConsensusState>,
+{
+    /// Stores the client state along with its consensus state at the height
+    /// of the initial sequence. The given consensus state must be the one
+    /// embedded in the client state.
+    fn initialise(
+        &self,
+        ctx: &mut E,
+        client_id: &ClientId,
+        consensus_state: Any,
+    ) -> Result<(), ClientError> {
+        let solomachine_consensus_state = SolomachineConsensusState::try_from(consensus_state)?;
+        if solomachine_consensus_state.inner() != self.0.consensus_state() {
+            return Err(Error::ConsensusStateMismatch.into());
+        }
+
+        let host_timestamp = CommonContext::host_timestamp(ctx)?;
+        let host_height = CommonContext::host_height(ctx)?;
+        let latest_height = self.latest_height();
+
+        ctx.store_client_state(ClientStatePath::new(client_id), self.clone().into())?;
+        ctx.store_consensus_state(
+            ClientConsensusStatePath::new(
+                client_id.clone(),
+                latest_height.revision_number(),
+                latest_height.revision_height(),
+            ),
+            solomachine_consensus_state.into(),
+        )?;
+        ctx.store_update_time(client_id.clone(), latest_height, host_timestamp)?;
+        ctx.store_update_height(client_id.clone(), latest_height, host_height)?;
+
+        Ok(())
+    }
+
+    /// Moves the client to the next sequence, with the public key and
+    /// diversifier of the header.
+    fn update_state(
+        &self,
+        ctx: &mut E,
+        client_id: &ClientId,
+        header: Any,
+    ) -> Result<Vec<Height>, ClientError> {
+        let header = Header::try_from(header)?;
+
+        let new_consensus_state = ConsensusStateType::new(
+            header.new_public_key,
+            header.new_diversifier,
+            Timestamp::from_nanoseconds(header.timestamp).map_err(|e| ClientError::Other {
+                description: e.to_string(),
+            })?,
+        )?;
+        let new_client_state = self.0.with_consensus_state(new_consensus_state.clone())?;
+        let new_height = new_client_state.latest_height();
+
+        let host_timestamp = CommonContext::host_timestamp(ctx)?;
+        let host_height = CommonContext::host_height(ctx)?;
+
+        ctx.store_consensus_state(
+            ClientConsensusStatePath::new(
+                client_id.clone(),
+                new_height.revision_number(),
+                new_height.revision_height(),
+            ),
+            SolomachineConsensusState::from(new_consensus_state).into(),
+        )?;
+        ctx.store_client_state(
+            ClientStatePath::new(client_id),
+            ClientState::from(new_client_state).into(),
+        )?;
+        ctx.store_update_time(client_id.clone(), new_height, host_timestamp)?;
+        ctx.store_update_height(client_id.clone(), new_height, host_height)?;
+
+        Ok(vec![new_height])
+    }
+
+    fn update_state_on_misbehaviour(
+        &self,
+        ctx: &mut E,
+        client_id: &ClientId,
+        _client_message: Any,
+        _update_kind: &UpdateKind,
+    ) -> Result<(), ClientError> {
+        let frozen_client_state = ClientState::from(self.0.clone().with_frozen());
+
+        ctx.store_client_state(ClientStatePath::new(client_id), frozen_client_state.into())?;
+
+        Ok(())
+    }
+
+    fn update_state_on_upgrade(
+        &self,
+        _ctx: &mut E,
+        _client_id: &ClientId,
+        _upgraded_client_state: Any,
+        _upgraded_consensus_state: Any,
+    ) -> Result<Height, ClientError> {
+        Err(Error::UnsupportedOperation {
+            operation: "client upgrades".into(),
+        })?
+    }
+
+    /// Takes over the sequence and consensus state of the substitute, which
+    /// must sign with a different public key, as done by ibc-go.
+    fn check_substitute_and_update_state(
+        &self,
+        ctx: &mut E,
+        subject_client_id: &ClientId,
+        _substitute_client_id: &ClientId,
+        substitute_client_state: Self,
+    ) -> Result<(), ClientError> {
+        let substitute_consensus_state = substitute_client_state.0.consensus_state().clone();
+
+        if self.0.consensus_state().public_key == substitute_consensus_state.public_key {
+            return Err(ClientError::SubstituteMismatch {
+                description: "subject and substitute have the same public key".into(),
+            });
+        }
+
+        let new_client_state = ClientStateType::new(
+            substitute_client_state.0.sequence(),
+            false,
+            substitute_consensus_state.clone(),
+        )?;
+        let latest_height = new_client_state.latest_height();
+
+        let host_timestamp = CommonContext::host_timestamp(ctx)?;
+        let host_height = CommonContext::host_height(ctx)?;
+
+        ctx.store_client_state(
+            ClientStatePath::new(subject_client_id),
+            ClientState::from(new_client_state).into(),
+        )?;
+        ctx.store_consensus_state(
+            ClientConsensusStatePath::new(
+                subject_client_id.clone(),
+                latest_height.revision_number(),
+                latest_height.revision_height(),
+            ),
+            SolomachineConsensusState::from(substitute_consensus_state).into(),
+        )?;
+        ctx.store_update_time(subject_client_id.clone(), latest_height, host_timestamp)?;
+        ctx.store_update_height(subject_client_id.clone(), latest_height, host_height)?;
+
+        Ok(())
+    }
+}
+
+/// Verifies `signature` of `message` by `public_key`, delegating the
+/// verification of single signatures to the host.
+///
+/// A multisig signature must carry the signatures of at least `threshold`
+/// of its keys, each of which is verified against the same message.
+fn verify_signature<V>(
+    ctx: &V,
+    public_key: &PublicKey,
+    message: &[u8],
+    signature: &SignatureData,
+) -> Result<(), ClientError>
+where
+    V: SolomachineValidationContext,
+{
+    match (public_key, signature) {
+        (PublicKey::Secp256k1(public_key), SignatureData::Single { signature, .. }) => {
+            ctx.verify_secp256k1_signature(public_key, message, signature)
+        }
+        (PublicKey::Ed25519(public_key), SignatureData::Single { signature, .. }) => {
+            ctx.verify_ed25519_signature(public_key, message, signature)
+        }
+        (
+            PublicKey::Multisig(multisig),
+            SignatureData::Multi {
+                signers,
+                signatures,
+            },
+        ) => {
+            if signers.len() != multisig.public_keys.len() {
+                return Err(Error::SignatureVerification {
+                    reason: format!(
+                        "expected {} signers, got {}",
+                        multisig.public_keys.len(),
+                        signers.len()
+                    ),
+                }
+                .into());
+            }
+
+            let signing_keys: Vec<&PublicKey> = multisig
+                .public_keys
+                .iter()
+                .zip(signers)
+                .filter_map(|(public_key, signed)| signed.then_some(public_key))
+                .collect();
+
+            if signing_keys.len() != signatures.len() {
+                return Err(Error::SignatureVerification {
+                    reason: format!(
+                        "expected {} signatures, got {}",
+                        signing_keys.len(),
+                        signatures.len()
+                    ),
+                }
+                .into());
+            }
+            if signing_keys.len() < multisig.threshold as usize {
+                return Err(Error::SignatureVerification {
+                    reason: format!(
+                        "{} signatures do not meet the multisig threshold {}",
+                        signing_keys.len(),
+                        multisig.threshold
+                    ),
+                }
+                .into());
+            }
+
+            signing_keys
+                .into_iter()
+                .zip(signatures)
+                .try_for_each(|(public_key, signature)| {
+                    verify_signature(ctx, public_key, message, signature)
+                })
+        }
+        _ => Err(Error::SignatureVerification {
+            reason: "signature data does not match the public key type".into(),
+        })?,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ibc_client_solomachine_types::MultisigPublicKey;
+    use ibc_core_handler_types::error::ContextError;
+
+    use super::*;
+
+    /// Accepts a signature if it equals the public key followed by the
+    /// message.
+    struct DummyVerifier;
+
+    impl CommonContext for DummyVerifier {
+        fn host_timestamp(&self) -> Result<Timestamp, ContextError> {
+            Ok(Timestamp::none())
+        }
+
+        fn host_height(&self) -> Result<Height, ContextError> {
+            Ok(Height::min(0))
+        }
+    }
+
+    impl SolomachineValidationContext for DummyVerifier {
+        fn verify_secp256k1_signature(
+            &self,
+            public_key: &[u8],
+            message: &[u8],
+            signature: &[u8],
+        ) -> Result<(), ClientError> {
+            self.verify_ed25519_signature(public_key, message, signature)
+        }
+
+        fn verify_ed25519_signature(
+            &self,
+            public_key: &[u8],
+            message: &[u8],
+            signature: &[u8],
+        ) -> Result<(), ClientError> {
+            if [public_key, message].concat() != signature {
+                return Err(Error::SignatureVerification {
+                    reason: "invalid signature".into(),
+                }
+                .into());
+            }
+            Ok(())
+        }
+    }
+
+    fn single(public_key: &[u8], message: &[u8]) -> SignatureData {
+        SignatureData::Single {
+            mode: 1,
+            signature: [public_key, message].concat(),
+        }
+    }
+
+    #[test]
+    fn verify_single_signature() {
+        let message = b"message";
+
+        assert!(verify_signature(
+            &DummyVerifier,
+            &PublicKey::Ed25519(vec![1]),
+            message,
+            &single(&[1], message)
+        )
+        .is_ok());
+        assert!(verify_signature(
+            &DummyVerifier,
+            &PublicKey::Secp256k1(vec![1]),
+            message,
+            &single(&[2], message)
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn verify_multisig_signature() {
+        let message = b"message";
+        let public_key = PublicKey::Multisig(
+            MultisigPublicKey::new(
+                2,
+                vec![
+                    PublicKey::Ed25519(vec![1]),
+                    PublicKey::Secp256k1(vec![2]),
+                    PublicKey::Ed25519(vec![3]),
+                ],
+            )
+            .unwrap(),
+        );
+
+        let multi = |signers: Vec<bool>, signatures: Vec<SignatureData>| SignatureData::Multi {
+            signers,
+            signatures,
+        };
+
+        // Two out of three keys signed
+        assert!(verify_signature(
+            &DummyVerifier,
+            &public_key,
+            message,
+            &multi(
+                vec![true, false, true],
+                vec![single(&[1], message), single(&[3], message)]
+            )
+        )
+        .is_ok());
+        // Below the threshold
+        assert!(verify_signature(
+            &DummyVerifier,
+            &public_key,
+            message,
+            &multi(vec![false, true, false], vec![single(&[2], message)])
+        )
+        .is_err());
+        // Signatures don't match the signers
+        assert!(verify_signature(
+            &DummyVerifier,
+            &public_key,
+            message,
+            &multi(
+                vec![true, true, false],
+                vec![single(&[1], message), single(&[3], message)]
+            )
+        )
+        .is_err());
+        // Single signature for a multisig public key
+        assert!(
+            verify_signature(&DummyVerifier, &public_key, message, &single(&[1], message)).is_err()
+        );
+    }
+}
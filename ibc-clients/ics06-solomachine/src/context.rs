@@ -0,0 +1,50 @@
+use ibc_core_client::context::ClientExecutionContext;
+use ibc_core_client::types::error::ClientError;
+use ibc_core_client::types::Height;
+use ibc_core_handler_types::error::ContextError;
+use ibc_primitives::Timestamp;
+
+/// Client's context required during both validation and execution
+pub trait CommonContext {
+    /// Returns the current timestamp of the local chain.
+    fn host_timestamp(&self) -> Result<Timestamp, ContextError>;
+
+    /// Returns the current height of the local chain.
+    fn host_height(&self) -> Result<Height, ContextError>;
+}
+
+/// Client's context required during validation.
+///
+/// Signature verification is left to the host, so that it can rely on the
+/// cryptographic primitives it already ships with. Both methods receive the
+/// encoded `SignBytes` as the message; hashing it, if the signature scheme
+/// requires it, is up to the implementation. Multisig public keys are
+/// handled by the client, which calls these methods for each of the
+/// participating keys.
+pub trait ValidationContext: CommonContext {
+    /// Verifies a secp256k1 `signature` of `message` by the given compressed
+    /// `public_key`, the way the Cosmos SDK does, i.e. over the SHA-256 digest
+    /// of `message`.
+    fn verify_secp256k1_signature(
+        &self,
+        public_key: &[u8],
+        message: &[u8],
+        signature: &[u8],
+    ) -> Result<(), ClientError>;
+
+    /// Verifies an ed25519 `signature` of `message` by the given `public_key`.
+    fn verify_ed25519_signature(
+        &self,
+        public_key: &[u8],
+        message: &[u8],
+        signature: &[u8],
+    ) -> Result<(), ClientError>;
+}
+
+/// Client's context required during execution.
+///
+/// This trait is automatically implemented for all types that implement
+/// [`CommonContext`] and [`ClientExecutionContext`]
+pub trait ExecutionContext: CommonContext + ClientExecutionContext {}
+
+impl<T> ExecutionContext for T where T: CommonContext + ClientExecutionContext {}
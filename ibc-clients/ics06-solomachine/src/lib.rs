@@ -0,0 +1,34 @@
+//! ICS 06: Solo Machine light client implementation along with re-exporting
+//! data structures and implementations of IBC core client module.
+//!
+//! A solo machine is a standalone device, such as a custodian or a hardware
+//! wallet, which has no consensus of its own. Its state is authenticated by
+//! signatures produced with its current public key, checked through the
+//! [`context::ValidationContext`] trait.
+#![no_std]
+#![forbid(unsafe_code)]
+#![cfg_attr(not(test), deny(clippy::unwrap_used))]
+#![cfg_attr(not(test), deny(clippy::disallowed_methods, clippy::disallowed_types))]
+#![deny(
+    warnings,
+    trivial_casts,
+    trivial_numeric_casts,
+    unused_import_braces,
+    unused_qualifications,
+    rust_2018_idioms
+)]
+
+#[cfg(any(test, feature = "std"))]
+extern crate std;
+
+pub mod client_state;
+pub mod consensus_state;
+pub mod context;
+
+pub const SOLOMACHINE_CLIENT_TYPE: &str = "06-solomachine";
+
+/// Re-export of Solo Machine light client data structures from `ibc-client-solomachine` crate.
+pub mod types {
+    #[doc(inline)]
+    pub use ibc_client_solomachine_types::*;
+}
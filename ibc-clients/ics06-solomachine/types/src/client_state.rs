@@ -0,0 +1,173 @@
+//! Defines the solo machine's `ClientState` type
+
+use ibc_core_client_types::error::ClientError;
+use ibc_core_client_types::Height;
+use ibc_primitives::prelude::*;
+use ibc_proto::google::protobuf::Any;
+use ibc_proto::ibc::lightclients::solomachine::v3::ClientState as RawClientState;
+use ibc_proto::Protobuf;
+
+use crate::consensus_state::ConsensusState;
+use crate::error::Error;
+
+pub const SOLOMACHINE_CLIENT_STATE_TYPE_URL: &str = "/ibc.lightclients.solomachine.v3.ClientState";
+
+/// Defines the solo machine light client's state.
+///
+/// The sequence is the nonce of the next header the solo machine is expected
+/// to sign, and is exposed as the client's latest height, at revision number
+/// zero. It is therefore never zero.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ClientState {
+    sequence: u64,
+    is_frozen: bool,
+    consensus_state: ConsensusState,
+}
+
+impl ClientState {
+    pub fn new(
+        sequence: u64,
+        is_frozen: bool,
+        consensus_state: ConsensusState,
+    ) -> Result<Self, Error> {
+        if sequence == 0 {
+            return Err(Error::ZeroSequence);
+        }
+
+        Ok(Self {
+            sequence,
+            is_frozen,
+            consensus_state,
+        })
+    }
+
+    pub fn sequence(&self) -> u64 {
+        self.sequence
+    }
+
+    pub fn is_frozen(&self) -> bool {
+        self.is_frozen
+    }
+
+    pub fn consensus_state(&self) -> &ConsensusState {
+        &self.consensus_state
+    }
+
+    pub fn latest_height(&self) -> Height {
+        Height::new(0, self.sequence).expect("Never fails because the sequence is non-zero")
+    }
+
+    /// Returns the client state following a successful update to the given
+    /// consensus state, which consumes the current sequence.
+    pub fn with_consensus_state(&self, consensus_state: ConsensusState) -> Result<Self, Error> {
+        Self::new(
+            self.sequence
+                .checked_add(1)
+                .ok_or(Error::InvalidRawClientState {
+                    reason: "sequence overflow".into(),
+                })?,
+            self.is_frozen,
+            consensus_state,
+        )
+    }
+
+    pub fn with_frozen(self) -> Self {
+        Self {
+            is_frozen: true,
+            ..self
+        }
+    }
+}
+
+impl Protobuf<RawClientState> for ClientState {}
+
+impl TryFrom<RawClientState> for ClientState {
+    type Error = Error;
+
+    fn try_from(raw: RawClientState) -> Result<Self, Self::Error> {
+        let consensus_state = raw
+            .consensus_state
+            .ok_or(Error::MissingConsensusState)?
+            .try_into()?;
+
+        Self::new(raw.sequence, raw.is_frozen, consensus_state)
+    }
+}
+
+impl From<ClientState> for RawClientState {
+    fn from(value: ClientState) -> Self {
+        Self {
+            sequence: value.sequence,
+            is_frozen: value.is_frozen,
+            consensus_state: Some(value.consensus_state.into()),
+        }
+    }
+}
+
+impl Protobuf<Any> for ClientState {}
+
+impl TryFrom<Any> for ClientState {
+    type Error = ClientError;
+
+    fn try_from(raw: Any) -> Result<Self, Self::Error> {
+        fn decode_client_state(value: &[u8]) -> Result<ClientState, ClientError> {
+            let client_state =
+                Protobuf::<RawClientState>::decode(value).map_err(|e| ClientError::Other {
+                    description: e.to_string(),
+                })?;
+            Ok(client_state)
+        }
+
+        match raw.type_url.as_str() {
+            SOLOMACHINE_CLIENT_STATE_TYPE_URL => decode_client_state(&raw.value),
+            _ => Err(ClientError::UnknownClientStateType {
+                client_state_type: raw.type_url,
+            }),
+        }
+    }
+}
+
+impl From<ClientState> for Any {
+    fn from(client_state: ClientState) -> Self {
+        Any {
+            type_url: SOLOMACHINE_CLIENT_STATE_TYPE_URL.to_string(),
+            value: Protobuf::<RawClientState>::encode_vec(client_state),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ibc_primitives::Timestamp;
+
+    use super::*;
+    use crate::public_key::PublicKey;
+
+    fn consensus_state() -> ConsensusState {
+        ConsensusState::new(
+            PublicKey::Ed25519(vec![1; 32]),
+            "diversifier".into(),
+            Timestamp::from_nanoseconds(10).unwrap(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn client_state_any_roundtrip() {
+        let client_state = ClientState::new(1, false, consensus_state()).unwrap();
+
+        let any: Any = client_state.clone().into();
+        assert_eq!(any.type_url, SOLOMACHINE_CLIENT_STATE_TYPE_URL);
+        assert_eq!(ClientState::try_from(any).unwrap(), client_state);
+        assert_eq!(client_state.latest_height(), Height::new(0, 1).unwrap());
+    }
+
+    #[test]
+    fn client_state_zero_sequence() {
+        assert!(matches!(
+            ClientState::new(0, false, consensus_state()),
+            Err(Error::ZeroSequence)
+        ));
+    }
+}
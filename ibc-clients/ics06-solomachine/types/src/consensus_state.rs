@@ -0,0 +1,142 @@
+//! Defines the solo machine's `ConsensusState` type
+
+use ibc_core_client_types::error::ClientError;
+use ibc_core_commitment_types::commitment::CommitmentRoot;
+use ibc_primitives::prelude::*;
+use ibc_primitives::Timestamp;
+use ibc_proto::google::protobuf::Any;
+use ibc_proto::ibc::lightclients::solomachine::v3::ConsensusState as RawConsensusState;
+use ibc_proto::Protobuf;
+
+use crate::error::Error;
+use crate::public_key::PublicKey;
+
+pub const SOLOMACHINE_CONSENSUS_STATE_TYPE_URL: &str =
+    "/ibc.lightclients.solomachine.v3.ConsensusState";
+
+/// Defines the solo machine light client's consensus state: the public key
+/// the solo machine currently signs with, along with the diversifier and the
+/// timestamp its signatures must commit to.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ConsensusState {
+    pub public_key: PublicKey,
+    /// Allows the same public key to be reused across different solo machine
+    /// clients, while preventing signatures from being replayed across them.
+    pub diversifier: String,
+    pub timestamp: Timestamp,
+    /// Always empty; solo machine proofs are signatures rather than Merkle
+    /// proofs against a root.
+    pub root: CommitmentRoot,
+}
+
+impl ConsensusState {
+    pub fn new(
+        public_key: PublicKey,
+        diversifier: String,
+        timestamp: Timestamp,
+    ) -> Result<Self, Error> {
+        validate_diversifier(&diversifier)?;
+        if !timestamp.is_set() {
+            return Err(Error::ZeroTimestamp);
+        }
+
+        Ok(Self {
+            public_key,
+            diversifier,
+            timestamp,
+            root: CommitmentRoot::from_bytes(&[]),
+        })
+    }
+
+    pub fn timestamp(&self) -> Timestamp {
+        self.timestamp
+    }
+
+    pub fn root(&self) -> &CommitmentRoot {
+        &self.root
+    }
+}
+
+/// A diversifier may be empty, but may not consist of spaces only.
+pub(crate) fn validate_diversifier(diversifier: &str) -> Result<(), Error> {
+    if !diversifier.is_empty() && diversifier.trim().is_empty() {
+        return Err(Error::BlankDiversifier);
+    }
+    Ok(())
+}
+
+impl Protobuf<RawConsensusState> for ConsensusState {}
+
+impl TryFrom<RawConsensusState> for ConsensusState {
+    type Error = Error;
+
+    fn try_from(raw: RawConsensusState) -> Result<Self, Self::Error> {
+        let public_key = raw.public_key.ok_or(Error::MissingPublicKey)?.try_into()?;
+        let timestamp = Timestamp::from_nanoseconds(raw.timestamp).map_err(|e| {
+            Error::InvalidRawConsensusState {
+                reason: e.to_string(),
+            }
+        })?;
+
+        Self::new(public_key, raw.diversifier, timestamp)
+    }
+}
+
+impl From<ConsensusState> for RawConsensusState {
+    fn from(value: ConsensusState) -> Self {
+        Self {
+            public_key: Some(value.public_key.into()),
+            diversifier: value.diversifier,
+            timestamp: value.timestamp.nanoseconds(),
+        }
+    }
+}
+
+impl Protobuf<Any> for ConsensusState {}
+
+impl TryFrom<Any> for ConsensusState {
+    type Error = ClientError;
+
+    fn try_from(raw: Any) -> Result<Self, Self::Error> {
+        fn decode_consensus_state(value: &[u8]) -> Result<ConsensusState, ClientError> {
+            let consensus_state =
+                Protobuf::<RawConsensusState>::decode(value).map_err(|e| ClientError::Other {
+                    description: e.to_string(),
+                })?;
+            Ok(consensus_state)
+        }
+
+        match raw.type_url.as_str() {
+            SOLOMACHINE_CONSENSUS_STATE_TYPE_URL => decode_consensus_state(&raw.value),
+            _ => Err(ClientError::UnknownConsensusStateType {
+                consensus_state_type: raw.type_url,
+            }),
+        }
+    }
+}
+
+impl From<ConsensusState> for Any {
+    fn from(consensus_state: ConsensusState) -> Self {
+        Any {
+            type_url: SOLOMACHINE_CONSENSUS_STATE_TYPE_URL.to_string(),
+            value: Protobuf::<RawConsensusState>::encode_vec(consensus_state),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn consensus_state_validation() {
+        let public_key = PublicKey::Ed25519(vec![1; 32]);
+        let timestamp = Timestamp::from_nanoseconds(10).unwrap();
+
+        assert!(ConsensusState::new(public_key.clone(), "".into(), timestamp).is_ok());
+        assert!(ConsensusState::new(public_key.clone(), "diversifier".into(), timestamp).is_ok());
+        assert!(ConsensusState::new(public_key.clone(), "   ".into(), timestamp).is_err());
+        assert!(ConsensusState::new(public_key, "".into(), Timestamp::none()).is_err());
+    }
+}
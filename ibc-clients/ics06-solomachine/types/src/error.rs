@@ -0,0 +1,60 @@
+//! Defines the Solo Machine light client's error type
+
+use displaydoc::Display;
+use ibc_core_client_types::error::ClientError;
+use ibc_primitives::prelude::*;
+
+/// The main error type
+#[derive(Debug, Display)]
+pub enum Error {
+    /// invalid raw client state: `{reason}`
+    InvalidRawClientState { reason: String },
+    /// invalid raw consensus state: `{reason}`
+    InvalidRawConsensusState { reason: String },
+    /// invalid raw header: `{reason}`
+    InvalidRawHeader { reason: String },
+    /// invalid raw misbehaviour: `{reason}`
+    InvalidRawMisbehaviour { reason: String },
+    /// invalid public key: `{reason}`
+    InvalidPublicKey { reason: String },
+    /// unknown public key type: `{type_url}`
+    UnknownPublicKeyType { type_url: String },
+    /// invalid signature data: `{reason}`
+    InvalidSignatureData { reason: String },
+    /// missing consensus state
+    MissingConsensusState,
+    /// missing public key
+    MissingPublicKey,
+    /// missing signature data
+    MissingSignatureData,
+    /// sequence cannot be zero
+    ZeroSequence,
+    /// timestamp cannot be zero
+    ZeroTimestamp,
+    /// diversifier cannot contain only spaces
+    BlankDiversifier,
+    /// timestamp `{timestamp}` is lower than the consensus state timestamp `{consensus_timestamp}`
+    TimestampTooLow {
+        timestamp: u64,
+        consensus_timestamp: u64,
+    },
+    /// signature verification failed: `{reason}`
+    SignatureVerification { reason: String },
+    /// the initial consensus state does not match the one of the client state
+    ConsensusStateMismatch,
+    /// verification requires a signature verifier and cannot be performed without context
+    MissingHostContext,
+    /// the solo machine client does not support `{operation}`
+    UnsupportedOperation { operation: String },
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Error {}
+
+impl From<Error> for ClientError {
+    fn from(e: Error) -> Self {
+        Self::ClientSpecific {
+            description: e.to_string(),
+        }
+    }
+}
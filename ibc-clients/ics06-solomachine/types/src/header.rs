@@ -0,0 +1,109 @@
+//! Defines the solo machine's `Header` type, which updates the public key and
+//! diversifier of the solo machine.
+
+use ibc_core_client_types::error::ClientError;
+use ibc_primitives::prelude::*;
+use ibc_proto::cosmos::tx::signing::v1beta1::signature_descriptor::Data as RawSignatureData;
+use ibc_proto::google::protobuf::Any;
+use ibc_proto::ibc::lightclients::solomachine::v3::Header as RawHeader;
+use ibc_proto::Protobuf;
+
+use crate::consensus_state::validate_diversifier;
+use crate::error::Error;
+use crate::public_key::PublicKey;
+use crate::sign_bytes::HeaderData;
+use crate::signature::SignatureData;
+
+pub const SOLOMACHINE_HEADER_TYPE_URL: &str = "/ibc.lightclients.solomachine.v3.Header";
+
+/// Defines the solo machine light client's header, signed with the current
+/// public key over the new public key and diversifier.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Header {
+    pub timestamp: u64,
+    pub signature: SignatureData,
+    pub new_public_key: PublicKey,
+    pub new_diversifier: String,
+}
+
+impl Header {
+    /// Returns the data signed over by the header.
+    pub fn header_data(&self) -> HeaderData {
+        HeaderData {
+            new_public_key: self.new_public_key.clone(),
+            new_diversifier: self.new_diversifier.clone(),
+        }
+    }
+}
+
+impl Protobuf<RawHeader> for Header {}
+
+impl TryFrom<RawHeader> for Header {
+    type Error = Error;
+
+    fn try_from(raw: RawHeader) -> Result<Self, Self::Error> {
+        if raw.timestamp == 0 {
+            return Err(Error::ZeroTimestamp);
+        }
+        validate_diversifier(&raw.new_diversifier)?;
+
+        let signature = Protobuf::<RawSignatureData>::decode_vec(&raw.signature).map_err(|e| {
+            Error::InvalidRawHeader {
+                reason: e.to_string(),
+            }
+        })?;
+
+        Ok(Self {
+            timestamp: raw.timestamp,
+            signature,
+            new_public_key: raw
+                .new_public_key
+                .ok_or(Error::MissingPublicKey)?
+                .try_into()?,
+            new_diversifier: raw.new_diversifier,
+        })
+    }
+}
+
+impl From<Header> for RawHeader {
+    fn from(value: Header) -> Self {
+        Self {
+            timestamp: value.timestamp,
+            signature: Protobuf::<RawSignatureData>::encode_vec(value.signature),
+            new_public_key: Some(value.new_public_key.into()),
+            new_diversifier: value.new_diversifier,
+        }
+    }
+}
+
+impl Protobuf<Any> for Header {}
+
+impl TryFrom<Any> for Header {
+    type Error = ClientError;
+
+    fn try_from(raw: Any) -> Result<Self, Self::Error> {
+        fn decode_header(value: &[u8]) -> Result<Header, ClientError> {
+            let header = Protobuf::<RawHeader>::decode(value).map_err(|e| ClientError::Other {
+                description: e.to_string(),
+            })?;
+            Ok(header)
+        }
+
+        match raw.type_url.as_str() {
+            SOLOMACHINE_HEADER_TYPE_URL => decode_header(&raw.value),
+            _ => Err(ClientError::UnknownHeaderType {
+                header_type: raw.type_url,
+            }),
+        }
+    }
+}
+
+impl From<Header> for Any {
+    fn from(header: Header) -> Self {
+        Any {
+            type_url: SOLOMACHINE_HEADER_TYPE_URL.to_string(),
+            value: Protobuf::<RawHeader>::encode_vec(header),
+        }
+    }
+}
@@ -0,0 +1,114 @@
+//! Defines the solo machine's `Misbehaviour` type, which proves that the solo
+//! machine signed two different messages at the same sequence.
+
+use ibc_core_client_types::error::ClientError;
+use ibc_primitives::prelude::*;
+use ibc_proto::google::protobuf::Any;
+use ibc_proto::ibc::lightclients::solomachine::v3::Misbehaviour as RawMisbehaviour;
+use ibc_proto::Protobuf;
+
+use crate::error::Error;
+use crate::signature::SignatureAndData;
+
+pub const SOLOMACHINE_MISBEHAVIOUR_TYPE_URL: &str = "/ibc.lightclients.solomachine.v3.Misbehaviour";
+
+/// Two conflicting signatures produced by the solo machine at the same
+/// sequence.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Misbehaviour {
+    pub sequence: u64,
+    pub signature_one: SignatureAndData,
+    pub signature_two: SignatureAndData,
+}
+
+impl Misbehaviour {
+    pub fn validate_basic(&self) -> Result<(), Error> {
+        if self.sequence == 0 {
+            return Err(Error::ZeroSequence);
+        }
+        if self.signature_one.signature == self.signature_two.signature {
+            return Err(Error::InvalidRawMisbehaviour {
+                reason: "misbehaviour signatures cannot be equal".into(),
+            });
+        }
+        if self.signature_one.path == self.signature_two.path
+            && self.signature_one.data == self.signature_two.data
+        {
+            return Err(Error::InvalidRawMisbehaviour {
+                reason: "misbehaviour signatures must be over different messages".into(),
+            });
+        }
+
+        Ok(())
+    }
+}
+
+impl Protobuf<RawMisbehaviour> for Misbehaviour {}
+
+impl TryFrom<RawMisbehaviour> for Misbehaviour {
+    type Error = Error;
+
+    fn try_from(raw: RawMisbehaviour) -> Result<Self, Self::Error> {
+        let missing_signature = || Error::InvalidRawMisbehaviour {
+            reason: "missing signature".into(),
+        };
+
+        let misbehaviour = Self {
+            sequence: raw.sequence,
+            signature_one: raw
+                .signature_one
+                .ok_or_else(missing_signature)?
+                .try_into()?,
+            signature_two: raw
+                .signature_two
+                .ok_or_else(missing_signature)?
+                .try_into()?,
+        };
+        misbehaviour.validate_basic()?;
+
+        Ok(misbehaviour)
+    }
+}
+
+impl From<Misbehaviour> for RawMisbehaviour {
+    fn from(value: Misbehaviour) -> Self {
+        Self {
+            sequence: value.sequence,
+            signature_one: Some(value.signature_one.into()),
+            signature_two: Some(value.signature_two.into()),
+        }
+    }
+}
+
+impl Protobuf<Any> for Misbehaviour {}
+
+impl TryFrom<Any> for Misbehaviour {
+    type Error = ClientError;
+
+    fn try_from(raw: Any) -> Result<Self, Self::Error> {
+        fn decode_misbehaviour(value: &[u8]) -> Result<Misbehaviour, ClientError> {
+            let misbehaviour =
+                Protobuf::<RawMisbehaviour>::decode(value).map_err(|e| ClientError::Other {
+                    description: e.to_string(),
+                })?;
+            Ok(misbehaviour)
+        }
+
+        match raw.type_url.as_str() {
+            SOLOMACHINE_MISBEHAVIOUR_TYPE_URL => decode_misbehaviour(&raw.value),
+            _ => Err(ClientError::UnknownMisbehaviourType {
+                misbehaviour_type: raw.type_url,
+            }),
+        }
+    }
+}
+
+impl From<Misbehaviour> for Any {
+    fn from(misbehaviour: Misbehaviour) -> Self {
+        Any {
+            type_url: SOLOMACHINE_MISBEHAVIOUR_TYPE_URL.to_string(),
+            value: Protobuf::<RawMisbehaviour>::encode_vec(misbehaviour),
+        }
+    }
+}
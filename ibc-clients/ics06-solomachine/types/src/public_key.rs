@@ -0,0 +1,154 @@
+//! Defines the public keys a solo machine may sign with
+
+use ibc_primitives::prelude::*;
+use ibc_proto::cosmos::crypto::ed25519::PubKey as RawEd25519PubKey;
+use ibc_proto::cosmos::crypto::multisig::LegacyAminoPubKey as RawMultisigPubKey;
+use ibc_proto::cosmos::crypto::secp256k1::PubKey as RawSecp256k1PubKey;
+use ibc_proto::google::protobuf::Any;
+use prost::Message;
+
+use crate::error::Error;
+
+pub const SECP256K1_PUBLIC_KEY_TYPE_URL: &str = "/cosmos.crypto.secp256k1.PubKey";
+pub const ED25519_PUBLIC_KEY_TYPE_URL: &str = "/cosmos.crypto.ed25519.PubKey";
+pub const MULTISIG_PUBLIC_KEY_TYPE_URL: &str = "/cosmos.crypto.multisig.LegacyAminoPubKey";
+
+/// The public key of a solo machine, as encoded by the Cosmos SDK.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum PublicKey {
+    /// A compressed secp256k1 public key
+    Secp256k1(Vec<u8>),
+    /// An ed25519 public key
+    Ed25519(Vec<u8>),
+    /// A threshold multisig public key
+    Multisig(MultisigPublicKey),
+}
+
+/// A `threshold`-of-`public_keys.len()` multisig public key.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MultisigPublicKey {
+    pub threshold: u32,
+    pub public_keys: Vec<PublicKey>,
+}
+
+impl MultisigPublicKey {
+    pub fn new(threshold: u32, public_keys: Vec<PublicKey>) -> Result<Self, Error> {
+        if threshold == 0 {
+            return Err(Error::InvalidPublicKey {
+                reason: "multisig threshold cannot be zero".into(),
+            });
+        }
+        if threshold as usize > public_keys.len() {
+            return Err(Error::InvalidPublicKey {
+                reason: format!(
+                    "multisig threshold {threshold} exceeds the number of public keys {}",
+                    public_keys.len()
+                ),
+            });
+        }
+
+        Ok(Self {
+            threshold,
+            public_keys,
+        })
+    }
+}
+
+impl TryFrom<Any> for PublicKey {
+    type Error = Error;
+
+    fn try_from(raw: Any) -> Result<Self, Self::Error> {
+        let decode_err = |e: prost::DecodeError| Error::InvalidPublicKey {
+            reason: e.to_string(),
+        };
+
+        match raw.type_url.as_str() {
+            SECP256K1_PUBLIC_KEY_TYPE_URL => {
+                let key = RawSecp256k1PubKey::decode(raw.value.as_slice())
+                    .map_err(decode_err)?
+                    .key;
+                Ok(Self::Secp256k1(key))
+            }
+            ED25519_PUBLIC_KEY_TYPE_URL => {
+                let key = RawEd25519PubKey::decode(raw.value.as_slice())
+                    .map_err(decode_err)?
+                    .key;
+                Ok(Self::Ed25519(key))
+            }
+            MULTISIG_PUBLIC_KEY_TYPE_URL => {
+                let multisig =
+                    RawMultisigPubKey::decode(raw.value.as_slice()).map_err(decode_err)?;
+                let public_keys = multisig
+                    .public_keys
+                    .into_iter()
+                    .map(PublicKey::try_from)
+                    .collect::<Result<_, _>>()?;
+                Ok(Self::Multisig(MultisigPublicKey::new(
+                    multisig.threshold,
+                    public_keys,
+                )?))
+            }
+            _ => Err(Error::UnknownPublicKeyType {
+                type_url: raw.type_url,
+            }),
+        }
+    }
+}
+
+impl From<PublicKey> for Any {
+    fn from(public_key: PublicKey) -> Self {
+        match public_key {
+            PublicKey::Secp256k1(key) => Any {
+                type_url: SECP256K1_PUBLIC_KEY_TYPE_URL.to_string(),
+                value: RawSecp256k1PubKey { key }.encode_to_vec(),
+            },
+            PublicKey::Ed25519(key) => Any {
+                type_url: ED25519_PUBLIC_KEY_TYPE_URL.to_string(),
+                value: RawEd25519PubKey { key }.encode_to_vec(),
+            },
+            PublicKey::Multisig(multisig) => Any {
+                type_url: MULTISIG_PUBLIC_KEY_TYPE_URL.to_string(),
+                value: RawMultisigPubKey {
+                    threshold: multisig.threshold,
+                    public_keys: multisig.public_keys.into_iter().map(Into::into).collect(),
+                }
+                .encode_to_vec(),
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn public_key_any_roundtrip() {
+        let multisig = PublicKey::Multisig(
+            MultisigPublicKey::new(
+                2,
+                vec![
+                    PublicKey::Ed25519(vec![1; 32]),
+                    PublicKey::Secp256k1(vec![2; 33]),
+                    PublicKey::Ed25519(vec![3; 32]),
+                ],
+            )
+            .unwrap(),
+        );
+
+        let any: Any = multisig.clone().into();
+        assert_eq!(any.type_url, MULTISIG_PUBLIC_KEY_TYPE_URL);
+        assert_eq!(PublicKey::try_from(any).unwrap(), multisig);
+    }
+
+    #[test]
+    fn multisig_threshold_validation() {
+        let keys = vec![PublicKey::Ed25519(vec![1; 32])];
+
+        assert!(MultisigPublicKey::new(0, keys.clone()).is_err());
+        assert!(MultisigPublicKey::new(2, keys.clone()).is_err());
+        assert!(MultisigPublicKey::new(1, keys).is_ok());
+    }
+}
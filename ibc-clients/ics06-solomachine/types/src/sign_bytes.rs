@@ -0,0 +1,149 @@
+//! Defines the bytes a solo machine signs over
+
+use core::fmt::Write;
+
+use ibc_core_commitment_types::commitment::CommitmentPrefix;
+use ibc_primitives::prelude::*;
+use ibc_proto::ibc::lightclients::solomachine::v3::{
+    HeaderData as RawHeaderData, SignBytes as RawSignBytes,
+};
+use ibc_proto::Protobuf;
+
+use crate::error::Error;
+use crate::public_key::PublicKey;
+
+/// The path signed over by a solo machine when updating its public key and
+/// diversifier with a header.
+pub const SENTINEL_HEADER_PATH: &str = "solomachine:header";
+
+/// Returns the path signed over by a solo machine for a value stored at `path`
+/// under the counterparty's commitment `prefix`.
+///
+/// This matches the string representation of the corresponding `MerklePath`
+/// in ibc-go, i.e. each key is percent-encoded as a URL path segment and
+/// prepended with a `/`, so that solo machines can sign for both
+/// implementations alike.
+pub fn merkle_path_bytes(prefix: &CommitmentPrefix, path: &str) -> Vec<u8> {
+    let mut merkle_path = String::new();
+    for key in [prefix.as_bytes(), path.as_bytes()] {
+        merkle_path.push('/');
+        escape_path_segment(&mut merkle_path, key);
+    }
+    merkle_path.into_bytes()
+}
+
+/// Percent-encodes `segment` the same way as Go's `url.PathEscape`.
+fn escape_path_segment(out: &mut String, segment: &[u8]) {
+    for &byte in segment {
+        match byte {
+            b'A'..=b'Z'
+            | b'a'..=b'z'
+            | b'0'..=b'9'
+            | b'-'
+            | b'_'
+            | b'.'
+            | b'~'
+            | b'$'
+            | b'&'
+            | b'+'
+            | b':'
+            | b'='
+            | b'@' => out.push(byte as char),
+            _ => {
+                write!(out, "%{byte:02X}")
+                    .expect("Never fails because writing to a String is infallible");
+            }
+        }
+    }
+}
+
+/// The data signed over by a solo machine. Binding the sequence, timestamp
+/// and diversifier prevents a signature from being replayed in another
+/// context than the one it was produced for.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SignBytes {
+    pub sequence: u64,
+    pub timestamp: u64,
+    pub diversifier: String,
+    /// The prefixed path of the value, see [`merkle_path_bytes`], or the
+    /// sentinel header path
+    pub path: Vec<u8>,
+    /// The value, empty when proving non-membership
+    pub data: Vec<u8>,
+}
+
+impl Protobuf<RawSignBytes> for SignBytes {}
+
+impl From<RawSignBytes> for SignBytes {
+    fn from(raw: RawSignBytes) -> Self {
+        Self {
+            sequence: raw.sequence,
+            timestamp: raw.timestamp,
+            diversifier: raw.diversifier,
+            path: raw.path,
+            data: raw.data,
+        }
+    }
+}
+
+impl From<SignBytes> for RawSignBytes {
+    fn from(value: SignBytes) -> Self {
+        Self {
+            sequence: value.sequence,
+            timestamp: value.timestamp,
+            diversifier: value.diversifier,
+            path: value.path,
+            data: value.data,
+        }
+    }
+}
+
+/// The data signed over by a solo machine in a header.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct HeaderData {
+    pub new_public_key: PublicKey,
+    pub new_diversifier: String,
+}
+
+impl Protobuf<RawHeaderData> for HeaderData {}
+
+impl TryFrom<RawHeaderData> for HeaderData {
+    type Error = Error;
+
+    fn try_from(raw: RawHeaderData) -> Result<Self, Self::Error> {
+        Ok(Self {
+            new_public_key: raw.new_pub_key.ok_or(Error::MissingPublicKey)?.try_into()?,
+            new_diversifier: raw.new_diversifier,
+        })
+    }
+}
+
+impl From<HeaderData> for RawHeaderData {
+    fn from(value: HeaderData) -> Self {
+        Self {
+            new_pub_key: Some(value.new_public_key.into()),
+            new_diversifier: value.new_diversifier,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn merkle_path_bytes_escapes_keys() {
+        let prefix = CommitmentPrefix::try_from(b"ibc".to_vec()).unwrap();
+
+        assert_eq!(
+            merkle_path_bytes(&prefix, "channelEnds/ports/transfer/channels/channel-0"),
+            b"/ibc/channelEnds%2Fports%2Ftransfer%2Fchannels%2Fchannel-0".to_vec()
+        );
+        assert_eq!(
+            merkle_path_bytes(&prefix, "clients/06-solomachine-0/consensusStates/0-1"),
+            b"/ibc/clients%2F06-solomachine-0%2FconsensusStates%2F0-1".to_vec()
+        );
+    }
+}
@@ -0,0 +1,251 @@
+//! Defines the signatures a solo machine authenticates its state with
+
+use ibc_primitives::prelude::*;
+use ibc_proto::cosmos::crypto::multisig::v1beta1::CompactBitArray as RawCompactBitArray;
+use ibc_proto::cosmos::tx::signing::v1beta1::signature_descriptor::data::{
+    Multi as RawMulti, Single as RawSingle, Sum as RawSum,
+};
+use ibc_proto::cosmos::tx::signing::v1beta1::signature_descriptor::Data as RawSignatureData;
+use ibc_proto::ibc::lightclients::solomachine::v3::{
+    SignatureAndData as RawSignatureAndData,
+    TimestampedSignatureData as RawTimestampedSignatureData,
+};
+use ibc_proto::Protobuf;
+
+use crate::error::Error;
+
+/// The signature data of a solo machine, mirroring the Cosmos SDK's
+/// `SignatureDescriptor.Data`.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SignatureData {
+    /// A signature produced by a single key
+    Single { mode: i32, signature: Vec<u8> },
+    /// The signatures of the participating keys of a multisig public key.
+    /// `signers[i]` tells whether the `i`-th key of the multisig signed, and
+    /// `signatures` holds the signatures of the signing keys, in order.
+    Multi {
+        signers: Vec<bool>,
+        signatures: Vec<SignatureData>,
+    },
+}
+
+impl Protobuf<RawSignatureData> for SignatureData {}
+
+impl TryFrom<RawSignatureData> for SignatureData {
+    type Error = Error;
+
+    fn try_from(raw: RawSignatureData) -> Result<Self, Self::Error> {
+        match raw.sum.ok_or(Error::MissingSignatureData)? {
+            RawSum::Single(single) => {
+                if single.signature.is_empty() {
+                    return Err(Error::InvalidSignatureData {
+                        reason: "empty signature".into(),
+                    });
+                }
+                Ok(Self::Single {
+                    mode: single.mode,
+                    signature: single.signature,
+                })
+            }
+            RawSum::Multi(multi) => {
+                let bitarray = multi.bitarray.ok_or(Error::InvalidSignatureData {
+                    reason: "missing multisig bit array".into(),
+                })?;
+                let signatures = multi
+                    .signatures
+                    .into_iter()
+                    .map(SignatureData::try_from)
+                    .collect::<Result<_, _>>()?;
+                Ok(Self::Multi {
+                    signers: decode_bit_array(&bitarray)?,
+                    signatures,
+                })
+            }
+        }
+    }
+}
+
+impl From<SignatureData> for RawSignatureData {
+    fn from(value: SignatureData) -> Self {
+        let sum = match value {
+            SignatureData::Single { mode, signature } => {
+                RawSum::Single(RawSingle { mode, signature })
+            }
+            SignatureData::Multi {
+                signers,
+                signatures,
+            } => RawSum::Multi(RawMulti {
+                bitarray: Some(encode_bit_array(&signers)),
+                signatures: signatures.into_iter().map(Into::into).collect(),
+            }),
+        };
+
+        Self { sum: Some(sum) }
+    }
+}
+
+/// Decodes a Cosmos SDK `CompactBitArray`, whose bits are stored most
+/// significant first and whose last byte holds `extra_bits_stored` bits.
+fn decode_bit_array(raw: &RawCompactBitArray) -> Result<Vec<bool>, Error> {
+    let len = match raw.extra_bits_stored {
+        0 => raw.elems.len() * 8,
+        extra if extra < 8 && !raw.elems.is_empty() => (raw.elems.len() - 1) * 8 + extra as usize,
+        _ => {
+            return Err(Error::InvalidSignatureData {
+                reason: "malformed multisig bit array".into(),
+            })
+        }
+    };
+
+    Ok((0..len)
+        .map(|i| raw.elems[i / 8] & (1 << (7 - (i % 8))) != 0)
+        .collect())
+}
+
+fn encode_bit_array(bits: &[bool]) -> RawCompactBitArray {
+    let mut elems = vec![0u8; (bits.len() + 7) / 8];
+    for (i, _) in bits.iter().enumerate().filter(|(_, bit)| **bit) {
+        elems[i / 8] |= 1 << (7 - (i % 8));
+    }
+
+    RawCompactBitArray {
+        extra_bits_stored: (bits.len() % 8) as u32,
+        elems,
+    }
+}
+
+/// The proof of a solo machine for a membership or non-membership
+/// verification: a signature along with the timestamp it was produced at.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TimestampedSignatureData {
+    pub signature_data: SignatureData,
+    pub timestamp: u64,
+}
+
+impl Protobuf<RawTimestampedSignatureData> for TimestampedSignatureData {}
+
+impl TryFrom<RawTimestampedSignatureData> for TimestampedSignatureData {
+    type Error = Error;
+
+    fn try_from(raw: RawTimestampedSignatureData) -> Result<Self, Self::Error> {
+        let signature_data = Protobuf::<RawSignatureData>::decode_vec(&raw.signature_data)
+            .map_err(|e| Error::InvalidSignatureData {
+                reason: e.to_string(),
+            })?;
+
+        Ok(Self {
+            signature_data,
+            timestamp: raw.timestamp,
+        })
+    }
+}
+
+impl From<TimestampedSignatureData> for RawTimestampedSignatureData {
+    fn from(value: TimestampedSignatureData) -> Self {
+        Self {
+            signature_data: Protobuf::<RawSignatureData>::encode_vec(value.signature_data),
+            timestamp: value.timestamp,
+        }
+    }
+}
+
+/// A signature over the given path and data, as submitted in a solo machine
+/// misbehaviour.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SignatureAndData {
+    pub signature: SignatureData,
+    pub path: Vec<u8>,
+    pub data: Vec<u8>,
+    pub timestamp: u64,
+}
+
+impl Protobuf<RawSignatureAndData> for SignatureAndData {}
+
+impl TryFrom<RawSignatureAndData> for SignatureAndData {
+    type Error = Error;
+
+    fn try_from(raw: RawSignatureAndData) -> Result<Self, Self::Error> {
+        if raw.path.is_empty() {
+            return Err(Error::InvalidRawMisbehaviour {
+                reason: "signed path cannot be empty".into(),
+            });
+        }
+        if raw.data.is_empty() {
+            return Err(Error::InvalidRawMisbehaviour {
+                reason: "signed data cannot be empty".into(),
+            });
+        }
+        if raw.timestamp == 0 {
+            return Err(Error::ZeroTimestamp);
+        }
+
+        let signature = Protobuf::<RawSignatureData>::decode_vec(&raw.signature).map_err(|e| {
+            Error::InvalidSignatureData {
+                reason: e.to_string(),
+            }
+        })?;
+
+        Ok(Self {
+            signature,
+            path: raw.path,
+            data: raw.data,
+            timestamp: raw.timestamp,
+        })
+    }
+}
+
+impl From<SignatureAndData> for RawSignatureAndData {
+    fn from(value: SignatureAndData) -> Self {
+        Self {
+            signature: Protobuf::<RawSignatureData>::encode_vec(value.signature),
+            path: value.path,
+            data: value.data,
+            timestamp: value.timestamp,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bit_array_roundtrip() {
+        for bits in [
+            vec![],
+            vec![true],
+            vec![true, false, true],
+            vec![false, true, false, false, true, true, false, true],
+            vec![
+                true, false, false, false, false, false, false, false, true, true,
+            ],
+        ] {
+            let raw = encode_bit_array(&bits);
+            assert_eq!(decode_bit_array(&raw).unwrap(), bits);
+        }
+    }
+
+    #[test]
+    fn signature_data_roundtrip() {
+        let data = SignatureData::Multi {
+            signers: vec![true, false, true],
+            signatures: vec![
+                SignatureData::Single {
+                    mode: 1,
+                    signature: vec![1; 64],
+                },
+                SignatureData::Single {
+                    mode: 1,
+                    signature: vec![2; 64],
+                },
+            ],
+        };
+
+        let encoded = Protobuf::<RawSignatureData>::encode_vec(data.clone());
+        let decoded: SignatureData = Protobuf::<RawSignatureData>::decode_vec(&encoded).unwrap();
+        assert_eq!(decoded, data);
+    }
+}
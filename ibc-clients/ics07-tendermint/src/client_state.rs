@@ -12,7 +12,8 @@ use ibc_client_tendermint_types::error::Error;
 use ibc_client_tendermint_types::proto::v1::ClientState as RawTmClientState;
 use ibc_client_tendermint_types::{
     client_type as tm_client_type, ClientState as ClientStateType,
-    ConsensusState as ConsensusStateType, Header as TmHeader, Misbehaviour as TmMisbehaviour,
+    ConsensusState as ConsensusStateType, Header as TmHeader, HeaderBundle, HostFunctions,
+    Misbehaviour as TmMisbehaviour, ProdHostFunctions, TENDERMINT_HEADER_BUNDLE_TYPE_URL,
 };
 use ibc_core_client::context::client_state::{
     ClientStateCommon, ClientStateExecution, ClientStateValidation,
@@ -20,7 +21,7 @@ use ibc_core_client::context::client_state::{
 use ibc_core_client::context::consensus_state::ConsensusState;
 use ibc_core_client::context::{ClientExecutionContext, ClientValidationContext};
 use ibc_core_client::types::error::{ClientError, UpgradeClientError};
-use ibc_core_client::types::{Height, Status, UpdateKind};
+use ibc_core_client::types::{Height, Status, StatusDetails, UpdateKind};
 use ibc_core_commitment_types::commitment::{
     CommitmentPrefix, CommitmentProofBytes, CommitmentRoot,
 };
@@ -29,10 +30,9 @@ use ibc_core_host::types::identifiers::{ClientId, ClientType};
 use ibc_core_host::types::path::{
     ClientConsensusStatePath, ClientStatePath, Path, UpgradeClientPath,
 };
-use ibc_core_host::ExecutionContext;
 use ibc_primitives::prelude::*;
 use ibc_primitives::proto::{Any, Protobuf};
-use ibc_primitives::ToVec;
+use ibc_primitives::{Timestamp, ToVec};
 
 use super::consensus_state::ConsensusState as TmConsensusState;
 use crate::context::{
@@ -46,25 +46,32 @@ mod update_client;
 /// `ibc-client-tendermint-types` crate. This wrapper exists so that we can
 /// bypass Rust's orphan rules and implement traits from
 /// `ibc::core::client::context` on the `ClientState` type.
+///
+/// The host functions `H` provide the cryptographic primitives used to verify
+/// headers and proofs, and default to [`ProdHostFunctions`].
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(serialize = "", deserialize = "H: Default"))
+)]
 #[derive(Clone, Debug, PartialEq)]
-pub struct ClientState(ClientStateType);
+pub struct ClientState<H = ProdHostFunctions>(ClientStateType<H>);
 
-impl ClientState {
-    pub fn inner(&self) -> &ClientStateType {
+impl<H> ClientState<H> {
+    pub fn inner(&self) -> &ClientStateType<H> {
         &self.0
     }
 }
 
-impl From<ClientStateType> for ClientState {
-    fn from(client_state: ClientStateType) -> Self {
+impl<H> From<ClientStateType<H>> for ClientState<H> {
+    fn from(client_state: ClientStateType<H>) -> Self {
         Self(client_state)
     }
 }
 
-impl Protobuf<RawTmClientState> for ClientState {}
+impl<H: HostFunctions> Protobuf<RawTmClientState> for ClientState<H> {}
 
-impl TryFrom<RawTmClientState> for ClientState {
+impl<H: HostFunctions> TryFrom<RawTmClientState> for ClientState<H> {
     type Error = Error;
 
     fn try_from(raw: RawTmClientState) -> Result<Self, Self::Error> {
@@ -72,15 +79,15 @@ impl TryFrom<RawTmClientState> for ClientState {
     }
 }
 
-impl From<ClientState> for RawTmClientState {
-    fn from(client_state: ClientState) -> Self {
+impl<H> From<ClientState<H>> for RawTmClientState {
+    fn from(client_state: ClientState<H>) -> Self {
         client_state.0.into()
     }
 }
 
-impl Protobuf<Any> for ClientState {}
+impl<H: HostFunctions> Protobuf<Any> for ClientState<H> {}
 
-impl TryFrom<Any> for ClientState {
+impl<H: HostFunctions> TryFrom<Any> for ClientState<H> {
     type Error = ClientError;
 
     fn try_from(raw: Any) -> Result<Self, Self::Error> {
@@ -88,13 +95,13 @@ impl TryFrom<Any> for ClientState {
     }
 }
 
-impl From<ClientState> for Any {
-    fn from(client_state: ClientState) -> Self {
+impl<H: HostFunctions> From<ClientState<H>> for Any {
+    fn from(client_state: ClientState<H>) -> Self {
         client_state.0.into()
     }
 }
 
-impl ClientStateCommon for ClientState {
+impl<H: HostFunctions> ClientStateCommon for ClientState<H> {
     fn verify_consensus_state(&self, consensus_state: Any) -> Result<(), ClientError> {
         let tm_consensus_state = TmConsensusState::try_from(consensus_state)?;
         if tm_consensus_state.root().is_empty() {
@@ -202,7 +209,7 @@ impl ClientStateCommon for ClientState {
             MerkleProof::try_from(proof).map_err(ClientError::InvalidCommitmentProof)?;
 
         merkle_proof
-            .verify_membership(
+            .verify_membership::<H>(
                 &self.0.proof_specs,
                 root.clone().into(),
                 merkle_path,
@@ -224,13 +231,14 @@ impl ClientStateCommon for ClientState {
             MerkleProof::try_from(proof).map_err(ClientError::InvalidCommitmentProof)?;
 
         merkle_proof
-            .verify_non_membership(&self.0.proof_specs, root.clone().into(), merkle_path)
+            .verify_non_membership::<H>(&self.0.proof_specs, root.clone().into(), merkle_path)
             .map_err(ClientError::Ics23Verification)
     }
 }
 
-impl<V> ClientStateValidation<V> for ClientState
+impl<V, H> ClientStateValidation<V> for ClientState<H>
 where
+    H: HostFunctions,
     V: ClientValidationContext + TmValidationContext,
     V::AnyConsensusState: TryInto<TmConsensusState>,
     ClientError: From<<V::AnyConsensusState as TryInto<TmConsensusState>>::Error>,
@@ -243,10 +251,16 @@ where
         update_kind: &UpdateKind,
     ) -> Result<(), ClientError> {
         match update_kind {
-            UpdateKind::UpdateClient => {
-                let header = TmHeader::try_from(client_message)?;
-                self.verify_header(ctx, client_id, header)
-            }
+            UpdateKind::UpdateClient => match client_message.type_url.as_str() {
+                TENDERMINT_HEADER_BUNDLE_TYPE_URL => {
+                    let header_bundle = HeaderBundle::try_from(client_message)?;
+                    self.verify_header_bundle(ctx, client_id, header_bundle)
+                }
+                _ => {
+                    let header = TmHeader::try_from(client_message)?;
+                    self.verify_header(ctx, client_id, header)
+                }
+            },
             UpdateKind::SubmitMisbehaviour => {
                 let misbehaviour = TmMisbehaviour::try_from(client_message)?;
                 self.verify_misbehaviour(ctx, client_id, misbehaviour)
@@ -262,10 +276,16 @@ where
         update_kind: &UpdateKind,
     ) -> Result<bool, ClientError> {
         match update_kind {
-            UpdateKind::UpdateClient => {
-                let header = TmHeader::try_from(client_message)?;
-                self.check_for_misbehaviour_update_client(ctx, client_id, header)
-            }
+            UpdateKind::UpdateClient => match client_message.type_url.as_str() {
+                TENDERMINT_HEADER_BUNDLE_TYPE_URL => {
+                    let header_bundle = HeaderBundle::try_from(client_message)?;
+                    self.check_for_misbehaviour_header_bundle(ctx, client_id, header_bundle)
+                }
+                _ => {
+                    let header = TmHeader::try_from(client_message)?;
+                    self.check_for_misbehaviour_update_client(ctx, client_id, header)
+                }
+            },
             UpdateKind::SubmitMisbehaviour => {
                 let misbehaviour = TmMisbehaviour::try_from(client_message)?;
                 self.check_for_misbehaviour_misbehavior(&misbehaviour)
@@ -308,12 +328,48 @@ where
 
         Ok(Status::Active)
     }
+
+    fn status_details(&self, ctx: &V, client_id: &ClientId) -> Result<StatusDetails, ClientError> {
+        let status = self.status(ctx, client_id)?;
+
+        let latest_consensus_timestamp: Option<Timestamp> =
+            match ctx.consensus_state(&ClientConsensusStatePath::new(
+                client_id.clone(),
+                self.0.latest_height.revision_number(),
+                self.0.latest_height.revision_height(),
+            )) {
+                Ok(cs) => {
+                    let latest_consensus_state: TmConsensusState = cs.try_into()?;
+                    Some(latest_consensus_state.timestamp().into())
+                }
+                Err(_) => None,
+            };
+
+        // The client expires once the trusting period of its latest
+        // consensus state has elapsed.
+        let expiry_deadline = latest_consensus_timestamp
+            .and_then(|timestamp| (timestamp + self.0.trusting_period).ok());
+
+        let now = ctx.host_timestamp()?;
+        let time_until_expiry = expiry_deadline.and_then(|deadline| deadline.duration_since(&now));
+
+        Ok(StatusDetails {
+            status,
+            latest_consensus_timestamp,
+            expiry_deadline,
+            time_until_expiry,
+            frozen_height: self.0.frozen_height,
+            allow_update_after_expiry: self.0.allow_update.after_expiry,
+            allow_update_after_misbehaviour: self.0.allow_update.after_misbehaviour,
+        })
+    }
 }
 
-impl<E> ClientStateExecution<E> for ClientState
+impl<E, H> ClientStateExecution<E> for ClientState<H>
 where
-    E: TmExecutionContext + ExecutionContext,
-    <E as ClientExecutionContext>::AnyClientState: From<ClientState>,
+    H: HostFunctions,
+    E: TmExecutionContext,
+    <E as ClientExecutionContext>::AnyClientState: From<ClientState<H>>,
     <E as ClientExecutionContext>::AnyConsensusState: From<TmConsensusState>,
 {
     fn initialise(
@@ -342,38 +398,44 @@ where
         Ok(())
     }
 
+    /// Stores the consensus state of every header of the client message,
+    /// which is either a single header or a [`HeaderBundle`], and returns
+    /// their heights.
     fn update_state(
         &self,
         ctx: &mut E,
         client_id: &ClientId,
         header: Any,
     ) -> Result<Vec<Height>, ClientError> {
-        let header = TmHeader::try_from(header)?;
-        let header_height = header.height();
+        let headers = match header.type_url.as_str() {
+            TENDERMINT_HEADER_BUNDLE_TYPE_URL => HeaderBundle::try_from(header)?.into_headers(),
+            _ => vec![TmHeader::try_from(header)?],
+        };
+
+        let host_timestamp = CommonContext::host_timestamp(ctx)?;
+        let host_height = CommonContext::host_height(ctx)?;
 
-        self.prune_oldest_consensus_state(ctx, client_id)?;
+        let mut new_client_state = self.0.clone();
+        let mut header_heights = Vec::with_capacity(headers.len());
 
-        let maybe_existing_consensus_state = {
+        for header in headers {
+            let header_height = header.height();
             let path_at_header_height = ClientConsensusStatePath::new(
                 client_id.clone(),
                 header_height.revision_number(),
                 header_height.revision_height(),
             );
 
-            CommonContext::consensus_state(ctx, &path_at_header_height).ok()
-        };
+            header_heights.push(header_height);
 
-        if maybe_existing_consensus_state.is_some() {
-            // if we already had the header installed by a previous relayer
-            // then this is a no-op.
-            //
-            // Do nothing.
-        } else {
-            let host_timestamp = CommonContext::host_timestamp(ctx)?;
-            let host_height = CommonContext::host_height(ctx)?;
+            if CommonContext::consensus_state(ctx, &path_at_header_height).is_ok() {
+                // if we already had the header installed by a previous relayer
+                // then this is a no-op.
+                continue;
+            }
 
             let new_consensus_state = ConsensusStateType::from(header.clone());
-            let new_client_state = self.0.clone().with_header(header)?;
+            new_client_state = new_client_state.with_header(header)?;
 
             ctx.store_consensus_state(
                 ClientConsensusStatePath::new(
@@ -383,15 +445,22 @@ where
                 ),
                 TmConsensusState::from(new_consensus_state).into(),
             )?;
+            ctx.store_update_time(client_id.clone(), header_height, host_timestamp)?;
+            ctx.store_update_height(client_id.clone(), header_height, host_height)?;
+        }
+
+        let new_client_state = Self::from(new_client_state);
+
+        if &new_client_state != self {
             ctx.store_client_state(
                 ClientStatePath::new(client_id),
-                ClientState::from(new_client_state).into(),
+                new_client_state.clone().into(),
             )?;
-            ctx.store_update_time(client_id.clone(), header_height, host_timestamp)?;
-            ctx.store_update_height(client_id.clone(), header_height, host_height)?;
         }
 
-        Ok(vec![header_height])
+        new_client_state.prune_oldest_consensus_state(ctx, client_id)?;
+
+        Ok(header_heights)
     }
 
     fn update_state_on_misbehaviour(
@@ -403,7 +472,7 @@ where
     ) -> Result<(), ClientError> {
         let frozen_client_state = self.0.clone().with_frozen_height(Height::min(0));
 
-        let wrapped_frozen_client_state = ClientState::from(frozen_client_state);
+        let wrapped_frozen_client_state = Self::from(frozen_client_state);
 
         ctx.store_client_state(
             ClientStatePath::new(client_id),
@@ -430,7 +499,7 @@ where
         // parameters are ignored. All chain-chosen parameters come from
         // committed client, all client-chosen parameters come from current
         // client.
-        let new_client_state = ClientStateType::new(
+        let new_client_state = ClientStateType::<H>::new(
             upgraded_tm_client_state.0.chain_id,
             self.0.trust_level,
             self.0.trusting_period,
@@ -468,7 +537,7 @@ where
 
         ctx.store_client_state(
             ClientStatePath::new(client_id),
-            ClientState::from(new_client_state).into(),
+            Self::from(new_client_state).into(),
         )?;
         ctx.store_consensus_state(
             ClientConsensusStatePath::new(
@@ -483,6 +552,103 @@ where
 
         Ok(latest_height)
     }
+
+    fn check_substitute_and_update_state(
+        &self,
+        ctx: &mut E,
+        subject_client_id: &ClientId,
+        substitute_client_id: &ClientId,
+        substitute_client_state: Self,
+    ) -> Result<(), ClientError> {
+        check_substitute(&self.0, &substitute_client_state.0)?;
+
+        let latest_height = substitute_client_state.0.latest_height;
+
+        let substitute_consensus_state: TmConsensusState = CommonContext::consensus_state(
+            ctx,
+            &ClientConsensusStatePath::new(
+                substitute_client_id.clone(),
+                latest_height.revision_number(),
+                latest_height.revision_height(),
+            ),
+        )?
+        .try_into()
+        .map_err(|err| ClientError::Other {
+            description: err.to_string(),
+        })?;
+
+        // The subject keeps its own parameters, except for the ones tied to
+        // the substitute's latest height, and is unfrozen.
+        let new_client_state = ClientStateType {
+            latest_height,
+            trusting_period: substitute_client_state.0.trusting_period,
+            frozen_height: None,
+            ..self.0.clone()
+        };
+
+        // The substitute's processed time and height are not accessible from
+        // the client execution context, so the consensus state is recorded as
+        // processed now, which can only lengthen the delay before it is used.
+        let host_timestamp = CommonContext::host_timestamp(ctx)?;
+        let host_height = CommonContext::host_height(ctx)?;
+
+        ctx.store_client_state(
+            ClientStatePath::new(subject_client_id),
+            Self::from(new_client_state).into(),
+        )?;
+        ctx.store_consensus_state(
+            ClientConsensusStatePath::new(
+                subject_client_id.clone(),
+                latest_height.revision_number(),
+                latest_height.revision_height(),
+            ),
+            substitute_consensus_state.into(),
+        )?;
+        ctx.store_update_time(subject_client_id.clone(), latest_height, host_timestamp)?;
+        ctx.store_update_height(subject_client_id.clone(), latest_height, host_height)?;
+
+        Ok(())
+    }
+}
+
+/// Checks that the substitute client state only differs from the subject
+/// client state in its latest height, frozen height and trusting period.
+fn check_substitute<H>(
+    subject: &ClientStateType<H>,
+    substitute: &ClientStateType<H>,
+) -> Result<(), ClientError> {
+    let mismatches = [
+        ("chain id", subject.chain_id == substitute.chain_id),
+        ("trust level", subject.trust_level == substitute.trust_level),
+        (
+            "unbonding period",
+            subject.unbonding_period == substitute.unbonding_period,
+        ),
+        (
+            "max clock drift",
+            subject.max_clock_drift == substitute.max_clock_drift,
+        ),
+        ("proof specs", subject.proof_specs == substitute.proof_specs),
+        (
+            "upgrade path",
+            subject.upgrade_path == substitute.upgrade_path,
+        ),
+        (
+            "allow update",
+            subject.allow_update == substitute.allow_update,
+        ),
+    ]
+    .into_iter()
+    .filter_map(|(field, matches)| (!matches).then_some(field))
+    .collect::<Vec<_>>();
+
+    if !mismatches.is_empty() {
+        return Err(ClientError::SubstituteMismatch {
+            description: format!("mismatched {}", mismatches.join(", ")),
+        });
+    }
+
+    Ok(())
 }
 
 #[cfg(test)]
@@ -581,4 +747,41 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn client_state_check_substitute() {
+        let subject: ClientStateType = ClientStateType::new(
+            ChainId::new("ibc-1").unwrap(),
+            TrustThreshold::ONE_THIRD,
+            Duration::new(64000, 0),
+            Duration::new(128000, 0),
+            Duration::new(3, 0),
+            Height::new(1, 10).expect("Never fails"),
+            ProofSpecs::default(),
+            Default::default(),
+            AllowUpdate {
+                after_expiry: false,
+                after_misbehaviour: false,
+            },
+        )
+        .expect("Never fails");
+
+        let substitute = ClientStateType {
+            latest_height: Height::new(1, 20).expect("Never fails"),
+            trusting_period: Duration::new(32000, 0),
+            ..subject.clone()
+        };
+        assert!(check_substitute(&subject, &substitute).is_ok());
+
+        let substitute = ClientStateType {
+            chain_id: ChainId::new("ibc-2").unwrap(),
+            trust_level: TrustThreshold::TWO_THIRDS,
+            ..subject.clone()
+        };
+        let err = check_substitute(&subject, &substitute).unwrap_err();
+        assert!(
+            matches!(&err, ClientError::SubstituteMismatch { description } if description == "mismatched chain id, trust level"),
+            "unexpected error: {err}"
+        );
+    }
 }
@@ -1,5 +1,7 @@
 use ibc_client_tendermint_types::error::{Error, IntoResult};
-use ibc_client_tendermint_types::{Header as TmHeader, Misbehaviour as TmMisbehaviour};
+use ibc_client_tendermint_types::{
+    Header as TmHeader, HostFunctions, Misbehaviour as TmMisbehaviour,
+};
 use ibc_core_client::types::error::ClientError;
 use ibc_core_host::types::identifiers::ClientId;
 use ibc_core_host::types::path::ClientConsensusStatePath;
@@ -10,7 +12,7 @@ use tendermint_light_client_verifier::Verifier;
 use super::{ClientState as TmClientState, TmValidationContext};
 use crate::consensus_state::ConsensusState as TmConsensusState;
 
-impl TmClientState {
+impl<H: HostFunctions> TmClientState<H> {
     // verify_misbehaviour determines whether or not two conflicting headers at
     // the same height would have convinced the light client.
     pub fn verify_misbehaviour<ClientValidationContext>(
@@ -22,7 +24,7 @@ impl TmClientState {
     where
         ClientValidationContext: TmValidationContext,
     {
-        misbehaviour.validate_basic()?;
+        misbehaviour.validate_basic::<H>()?;
 
         let header_1 = misbehaviour.header1();
         let trusted_consensus_state_1 = {
@@ -68,7 +70,7 @@ impl TmClientState {
         current_timestamp: Timestamp,
     ) -> Result<(), ClientError> {
         // ensure correctness of the trusted next validator set provided by the relayer
-        header.check_trusted_next_validator_set(trusted_consensus_state.inner())?;
+        header.check_trusted_next_validator_set::<H>(trusted_consensus_state.inner())?;
 
         // ensure trusted consensus state is within trusting period
         {
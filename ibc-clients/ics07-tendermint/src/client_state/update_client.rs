@@ -1,5 +1,7 @@
 use ibc_client_tendermint_types::error::{Error, IntoResult};
-use ibc_client_tendermint_types::{ConsensusState as ConsensusStateType, Header as TmHeader};
+use ibc_client_tendermint_types::{
+    ConsensusState as ConsensusStateType, Header as TmHeader, HeaderBundle, HostFunctions,
+};
 use ibc_core_client::context::ClientExecutionContext;
 use ibc_core_client::types::error::ClientError;
 use ibc_core_host::types::identifiers::ClientId;
@@ -12,18 +14,74 @@ use super::ClientState;
 use crate::consensus_state::ConsensusState as TmConsensusState;
 use crate::context::{CommonContext, ValidationContext as TmValidationContext};
 
-impl ClientState {
+impl<H: HostFunctions> ClientState<H> {
     pub fn verify_header<ClientValidationContext>(
         &self,
         ctx: &ClientValidationContext,
         client_id: &ClientId,
         header: TmHeader,
     ) -> Result<(), ClientError>
+    where
+        ClientValidationContext: TmValidationContext,
+    {
+        let trusted_client_cons_state_path = ClientConsensusStatePath::new(
+            client_id.clone(),
+            header.trusted_height.revision_number(),
+            header.trusted_height.revision_height(),
+        );
+        let trusted_consensus_state: TmConsensusState = ctx
+            .consensus_state(&trusted_client_cons_state_path)?
+            .try_into()
+            .map_err(|err| ClientError::Other {
+                description: err.to_string(),
+            })?;
+
+        self.verify_header_against(ctx, header, &trusted_consensus_state)
+    }
+
+    /// Verifies the headers of the bundle in order: the first one against
+    /// the consensus state it trusts, and every following one against the
+    /// consensus state derived from the header preceding it.
+    pub fn verify_header_bundle<ClientValidationContext>(
+        &self,
+        ctx: &ClientValidationContext,
+        client_id: &ClientId,
+        header_bundle: HeaderBundle,
+    ) -> Result<(), ClientError>
+    where
+        ClientValidationContext: TmValidationContext,
+    {
+        let mut previous_consensus_state: Option<TmConsensusState> = None;
+
+        for header in header_bundle.into_headers() {
+            let header_consensus_state =
+                TmConsensusState::from(ConsensusStateType::from(header.clone()));
+
+            match &previous_consensus_state {
+                Some(trusted_consensus_state) => {
+                    self.verify_header_against(ctx, header, trusted_consensus_state)?
+                }
+                None => self.verify_header(ctx, client_id, header)?,
+            }
+
+            previous_consensus_state = Some(header_consensus_state);
+        }
+
+        Ok(())
+    }
+
+    /// Verifies the header against the consensus state at its trusted height.
+    fn verify_header_against<ClientValidationContext>(
+        &self,
+        ctx: &ClientValidationContext,
+        header: TmHeader,
+        trusted_consensus_state: &TmConsensusState,
+    ) -> Result<(), ClientError>
     where
         ClientValidationContext: TmValidationContext,
     {
         // Checks that the header fields are valid.
-        header.validate_basic()?;
+        header.validate_basic::<H>()?;
 
         // The tendermint-light-client crate though works on heights that are assumed
         // to have the same revision number. We ensure this here.
@@ -32,41 +90,28 @@ impl ClientState {
         // Delegate to tendermint-light-client, which contains the required checks
         // of the new header against the trusted consensus state.
         {
-            let trusted_state =
-                {
-                    let trusted_client_cons_state_path = ClientConsensusStatePath::new(
-                        client_id.clone(),
-                        header.trusted_height.revision_number(),
-                        header.trusted_height.revision_height(),
-                    );
-                    let trusted_consensus_state: TmConsensusState = ctx
-                        .consensus_state(&trusted_client_cons_state_path)?
-                        .try_into()
-                        .map_err(|err| ClientError::Other {
-                            description: err.to_string(),
-                        })?;
-
-                    header.check_trusted_next_validator_set(trusted_consensus_state.inner())?;
-
-                    TrustedBlockState {
-                        chain_id: &self.0.chain_id.to_string().try_into().map_err(|e| {
-                            ClientError::Other {
-                                description: format!("failed to parse chain id: {}", e),
-                            }
-                        })?,
-                        header_time: trusted_consensus_state.timestamp(),
-                        height: header.trusted_height.revision_height().try_into().map_err(
-                            |_| ClientError::ClientSpecific {
-                                description: Error::InvalidHeaderHeight {
-                                    height: header.trusted_height.revision_height(),
-                                }
-                                .to_string(),
-                            },
-                        )?,
-                        next_validators: &header.trusted_next_validator_set,
-                        next_validators_hash: trusted_consensus_state.next_validators_hash(),
+            header.check_trusted_next_validator_set::<H>(trusted_consensus_state.inner())?;
+
+            let trusted_state = TrustedBlockState {
+                chain_id: &self.0.chain_id.to_string().try_into().map_err(|e| {
+                    ClientError::Other {
+                        description: format!("failed to parse chain id: {}", e),
                     }
-                };
+                })?,
+                header_time: trusted_consensus_state.timestamp(),
+                height: header
+                    .trusted_height
+                    .revision_height()
+                    .try_into()
+                    .map_err(|_| ClientError::ClientSpecific {
+                        description: Error::InvalidHeaderHeight {
+                            height: header.trusted_height.revision_height(),
+                        }
+                        .to_string(),
+                    })?,
+                next_validators: &header.trusted_next_validator_set,
+                next_validators_hash: trusted_consensus_state.next_validators_hash(),
+            };
 
             let untrusted_state = UntrustedBlockState {
                 signed_header: &header.signed_header,
@@ -174,6 +219,34 @@ impl ClientState {
         }
     }
 
+    /// Checks every header of the bundle for misbehaviour, as
+    /// [`check_for_misbehaviour_update_client`](Self::check_for_misbehaviour_update_client)
+    /// does for a single header.
+    pub fn check_for_misbehaviour_header_bundle<ClientValidationContext>(
+        &self,
+        ctx: &ClientValidationContext,
+        client_id: &ClientId,
+        header_bundle: HeaderBundle,
+    ) -> Result<bool, ClientError>
+    where
+        ClientValidationContext: TmValidationContext,
+    {
+        for header in header_bundle.into_headers() {
+            if self.check_for_misbehaviour_update_client(ctx, client_id, header)? {
+                return Ok(true);
+            }
+        }
+
+        Ok(false)
+    }
+
+    /// Prunes the oldest consensus states of the client, following the
+    /// [`PruningPolicy`](crate::context::PruningPolicy) of the host.
+    ///
+    /// The consensus states are visited from the lowest height, and pruned
+    /// while they are expired, older than the maximum age, or in excess of
+    /// the maximum number retained. The consensus state at the latest height
+    /// of the client is never pruned.
     pub fn prune_oldest_consensus_state<E>(
         &self,
         ctx: &mut E,
@@ -182,11 +255,30 @@ impl ClientState {
     where
         E: ClientExecutionContext + CommonContext,
     {
-        let mut heights = ctx.consensus_state_heights(client_id)?;
+        let policy = ctx.pruning_policy();
 
-        heights.sort();
+        let host_timestamp =
+            ctx.host_timestamp()?
+                .into_tm_time()
+                .ok_or_else(|| ClientError::Other {
+                    description: String::from("host timestamp is not a valid TM timestamp"),
+                })?;
+
+        let mut retained = match policy.max_consensus_states {
+            Some(_) => ctx.consensus_state_count(client_id)?,
+            None => 0,
+        };
+        let mut pruned = 0;
+
+        while let Some(height) = ctx.first_consensus_state_height(client_id)? {
+            if height >= self.0.latest_height
+                || policy
+                    .max_pruned_per_update
+                    .map_or(false, |max_pruned| pruned >= max_pruned)
+            {
+                break;
+            }
 
-        for height in heights {
             let client_consensus_state_path = ClientConsensusStatePath::new(
                 client_id.clone(),
                 height.revision_number(),
@@ -201,30 +293,36 @@ impl ClientState {
                         description: err.to_string(),
                     })?;
 
-            let host_timestamp =
-                ctx.host_timestamp()?
-                    .into_tm_time()
-                    .ok_or_else(|| ClientError::Other {
-                        description: String::from("host timestamp is not a valid TM timestamp"),
-                    })?;
             let tm_consensus_state_timestamp = tm_consensus_state.timestamp();
-            let tm_consensus_state_expiry = (tm_consensus_state_timestamp
-                + self.0.trusting_period)
-                .map_err(|_| ClientError::Other {
-                    description: String::from(
-                        "Timestamp overflow error occurred while attempting to parse TmConsensusState",
-                    ),
-                })?;
+            let is_older_than = |period| {
+                (tm_consensus_state_timestamp + period)
+                    .map(|deadline| deadline <= host_timestamp)
+                    .map_err(|_| ClientError::Other {
+                        description: String::from(
+                            "Timestamp overflow error occurred while attempting to parse TmConsensusState",
+                        ),
+                    })
+            };
 
-            if tm_consensus_state_expiry > host_timestamp {
-                break;
-            } else {
-                let client_id = client_id.clone();
+            let is_expired = is_older_than(self.0.trusting_period)?;
+            let is_too_old = match policy.max_age {
+                Some(max_age) => is_older_than(max_age)?,
+                None => false,
+            };
+            let is_in_excess = policy
+                .max_consensus_states
+                .map_or(false, |max_retained| retained > max_retained);
 
-                ctx.delete_consensus_state(client_consensus_state_path)?;
-                ctx.delete_update_time(client_id.clone(), height)?;
-                ctx.delete_update_height(client_id, height)?;
+            if !(is_expired || is_too_old || is_in_excess) {
+                break;
             }
+
+            ctx.delete_consensus_state(client_consensus_state_path)?;
+            ctx.delete_update_time(client_id.clone(), height)?;
+            ctx.delete_update_height(client_id.clone(), height)?;
+
+            retained = retained.saturating_sub(1);
+            pruned += 1;
         }
 
         Ok(())
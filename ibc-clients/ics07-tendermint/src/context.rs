@@ -1,4 +1,5 @@
 use alloc::string::ToString;
+use core::time::Duration;
 
 use ibc_core_client::context::ClientExecutionContext;
 use ibc_core_client::types::Height;
@@ -32,6 +33,55 @@ pub trait CommonContext {
 
     /// Returns all the heights at which a consensus state is stored
     fn consensus_state_heights(&self, client_id: &ClientId) -> Result<Vec<Height>, ContextError>;
+
+    /// Returns the lowest height at which a consensus state is stored, if
+    /// any.
+    ///
+    /// Pruning calls it once per pruned consensus state. The default
+    /// implementation scans all the heights returned by
+    /// [`consensus_state_heights`](Self::consensus_state_heights), hence hosts
+    /// storing the consensus states ordered by height should override it.
+    fn first_consensus_state_height(
+        &self,
+        client_id: &ClientId,
+    ) -> Result<Option<Height>, ContextError> {
+        Ok(self.consensus_state_heights(client_id)?.into_iter().min())
+    }
+
+    /// Returns the number of consensus states stored for the client.
+    ///
+    /// Only needed when the [`PruningPolicy`] bounds the number of consensus
+    /// states retained. The default implementation counts the heights
+    /// returned by [`consensus_state_heights`](Self::consensus_state_heights).
+    fn consensus_state_count(&self, client_id: &ClientId) -> Result<u64, ContextError> {
+        Ok(self.consensus_state_heights(client_id)?.len() as u64)
+    }
+
+    /// Returns the policy followed to prune the consensus states of the
+    /// clients on update.
+    ///
+    /// Defaults to only pruning the expired consensus states.
+    fn pruning_policy(&self) -> PruningPolicy {
+        PruningPolicy::default()
+    }
+}
+
+/// Defines which consensus states are pruned when a Tendermint client is
+/// updated, starting from the lowest height.
+///
+/// The expired consensus states, i.e. older than the trusting period of the
+/// client, are pruned regardless of `max_consensus_states` and `max_age`, but
+/// no more than `max_pruned_per_update` of them on each update. The consensus
+/// state at the latest height of the client is never pruned.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct PruningPolicy {
+    /// The maximum number of consensus states retained per client.
+    pub max_consensus_states: Option<u64>,
+    /// The maximum number of consensus states pruned per update.
+    pub max_pruned_per_update: Option<u64>,
+    /// The age from which consensus states are pruned, even when still
+    /// within the trusting period.
+    pub max_age: Option<Duration>,
 }
 
 /// Client's context required during validation
@@ -18,10 +18,10 @@ use ibc_proto::Protobuf;
 use tendermint::chain::id::MAX_LENGTH as MaxChainIdLen;
 use tendermint::trust_threshold::TrustThresholdFraction as TendermintTrustThresholdFraction;
 use tendermint_light_client_verifier::options::Options;
-use tendermint_light_client_verifier::ProdVerifier;
 
 use crate::error::Error;
 use crate::header::Header as TmHeader;
+use crate::host_functions::{HostFunctions, HostVerifier, ProdHostFunctions};
 use crate::trust_threshold::TrustThreshold;
 
 pub const TENDERMINT_CLIENT_STATE_TYPE_URL: &str = "/ibc.lightclients.tendermint.v1.ClientState";
@@ -34,9 +34,12 @@ pub struct AllowUpdate {
 }
 
 /// Defines data structure for Tendermint client state.
+///
+/// Headers are verified with the cryptographic primitives of the host
+/// functions `H`, which default to [`ProdHostFunctions`].
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Clone, Debug, PartialEq)]
-pub struct ClientState {
+pub struct ClientState<H = ProdHostFunctions> {
     pub chain_id: ChainId,
     pub trust_level: TrustThreshold,
     pub trusting_period: Duration,
@@ -48,10 +51,10 @@ pub struct ClientState {
     pub allow_update: AllowUpdate,
     pub frozen_height: Option<Height>,
     #[cfg_attr(feature = "serde", serde(skip))]
-    pub verifier: ProdVerifier,
+    pub verifier: HostVerifier<H>,
 }
 
-impl ClientState {
+impl<H: HostFunctions> ClientState<H> {
     #[allow(clippy::too_many_arguments)]
     fn new_without_validation(
         chain_id: ChainId,
@@ -75,7 +78,7 @@ impl ClientState {
             upgrade_path,
             allow_update,
             frozen_height: None,
-            verifier: ProdVerifier::default(),
+            verifier: HostVerifier::default(),
         }
     }
 
@@ -236,9 +239,9 @@ impl ClientState {
     }
 }
 
-impl Protobuf<RawTmClientState> for ClientState {}
+impl<H: HostFunctions> Protobuf<RawTmClientState> for ClientState<H> {}
 
-impl TryFrom<RawTmClientState> for ClientState {
+impl<H: HostFunctions> TryFrom<RawTmClientState> for ClientState<H> {
     type Error = Error;
 
     fn try_from(raw: RawTmClientState) -> Result<Self, Self::Error> {
@@ -315,8 +318,8 @@ impl TryFrom<RawTmClientState> for ClientState {
     }
 }
 
-impl From<ClientState> for RawTmClientState {
-    fn from(value: ClientState) -> Self {
+impl<H> From<ClientState<H>> for RawTmClientState {
+    fn from(value: ClientState<H>) -> Self {
         #[allow(deprecated)]
         Self {
             chain_id: value.chain_id.to_string(),
@@ -339,13 +342,15 @@ impl From<ClientState> for RawTmClientState {
     }
 }
 
-impl Protobuf<Any> for ClientState {}
+impl<H: HostFunctions> Protobuf<Any> for ClientState<H> {}
 
-impl TryFrom<Any> for ClientState {
+impl<H: HostFunctions> TryFrom<Any> for ClientState<H> {
     type Error = ClientError;
 
     fn try_from(raw: Any) -> Result<Self, Self::Error> {
-        fn decode_client_state(value: &[u8]) -> Result<ClientState, ClientError> {
+        fn decode_client_state<H: HostFunctions>(
+            value: &[u8],
+        ) -> Result<ClientState<H>, ClientError> {
             let client_state =
                 Protobuf::<RawTmClientState>::decode(value).map_err(|e| ClientError::Other {
                     description: e.to_string(),
@@ -362,8 +367,8 @@ impl TryFrom<Any> for ClientState {
     }
 }
 
-impl From<ClientState> for Any {
-    fn from(client_state: ClientState) -> Self {
+impl<H: HostFunctions> From<ClientState<H>> for Any {
+    fn from(client_state: ClientState<H>) -> Self {
         Any {
             type_url: TENDERMINT_CLIENT_STATE_TYPE_URL.to_string(),
             value: Protobuf::<RawTmClientState>::encode_vec(client_state),
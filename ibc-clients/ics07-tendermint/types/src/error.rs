@@ -55,6 +55,8 @@ pub enum Error {
     InvalidRawHeader(TendermintError),
     /// invalid raw misbehaviour: `{reason}`
     InvalidRawMisbehaviour { reason: String },
+    /// invalid header bundle: `{reason}`
+    InvalidHeaderBundle { reason: String },
     /// given other previous updates, header timestamp should be at most `{max}`, but was `{actual}`
     HeaderTimestampTooHigh { actual: String, max: String },
     /// given other previous updates, header timestamp should be at least `{min}`, but was `{actual}`
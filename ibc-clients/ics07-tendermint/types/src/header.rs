@@ -20,6 +20,7 @@ use tendermint_light_client_verifier::types::{TrustedBlockState, UntrustedBlockS
 
 use crate::consensus_state::ConsensusState as TmConsensusState;
 use crate::error::Error;
+use crate::host_functions::HostFunctions;
 
 pub const TENDERMINT_HEADER_TYPE_URL: &str = "/ibc.lightclients.tendermint.v1.Header";
 
@@ -101,11 +102,13 @@ impl Header {
     // `header.trusted_validator_set` was given to us by the relayer. Thus, we
     // need to ensure that the relayer gave us the right set, i.e. by ensuring
     // that it matches the hash we have stored on chain.
-    pub fn check_trusted_next_validator_set(
+    pub fn check_trusted_next_validator_set<H: HostFunctions>(
         &self,
         trusted_consensus_state: &TmConsensusState,
     ) -> Result<(), ClientError> {
-        if self.trusted_next_validator_set.hash() == trusted_consensus_state.next_validators_hash {
+        if self.trusted_next_validator_set.hash_with::<H::Sha256>()
+            == trusted_consensus_state.next_validators_hash
+        {
             Ok(())
         } else {
             Err(ClientError::HeaderVerificationFailure {
@@ -117,7 +120,7 @@ impl Header {
     }
 
     /// Checks if the fields of a given header are consistent with the trusted fields of this header.
-    pub fn validate_basic(&self) -> Result<(), Error> {
+    pub fn validate_basic<H: HostFunctions>(&self) -> Result<(), Error> {
         if self.height().revision_number() != self.trusted_height.revision_number() {
             return Err(Error::MismatchHeightRevisions {
                 trusted_revision: self.trusted_height.revision_number(),
@@ -135,10 +138,11 @@ impl Header {
             });
         }
 
-        if self.validator_set.hash() != self.signed_header.header.validators_hash {
+        let validators_hash = self.validator_set.hash_with::<H::Sha256>();
+        if validators_hash != self.signed_header.header.validators_hash {
             return Err(Error::MismatchValidatorsHashes {
                 signed_header_validators_hash: self.signed_header.header.validators_hash,
-                validators_hash: self.validator_set.hash(),
+                validators_hash,
             });
         }
 
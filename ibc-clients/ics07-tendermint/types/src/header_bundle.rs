@@ -0,0 +1,147 @@
+//! Defines the domain type for bundles of sequential tendermint headers
+
+use alloc::string::ToString;
+
+use ibc_core_client_types::error::ClientError;
+use ibc_primitives::prelude::*;
+use ibc_proto::google::protobuf::Any;
+use ibc_proto::ibc::lightclients::tendermint::v1::Header as RawHeader;
+use ibc_proto::Protobuf;
+
+use crate::error::Error;
+use crate::header::Header;
+
+/// The type URL of a [`HeaderBundle`]. As header bundles are not part of the
+/// ibc-go protobuf definitions, they live in a package owned by ibc-rs.
+pub const TENDERMINT_HEADER_BUNDLE_TYPE_URL: &str =
+    "/ibc_rs.lightclients.tendermint.v1.HeaderBundle";
+
+/// The raw encoding of a [`HeaderBundle`].
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct RawHeaderBundle {
+    #[prost(message, repeated, tag = "1")]
+    pub headers: Vec<RawHeader>,
+}
+
+impl prost::Name for RawHeaderBundle {
+    const NAME: &'static str = "HeaderBundle";
+    const PACKAGE: &'static str = "ibc_rs.lightclients.tendermint.v1";
+
+    fn full_name() -> String {
+        format!("{}.{}", Self::PACKAGE, Self::NAME)
+    }
+}
+
+/// An ordered list of Tendermint headers updating a client in one message.
+///
+/// The first header is verified against a consensus state stored by the
+/// client, while every following header is verified against the header
+/// preceding it in the bundle. This allows relayers to bridge validator set
+/// changes, which a single header could not, within a single update.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct HeaderBundle {
+    headers: Vec<Header>,
+}
+
+impl HeaderBundle {
+    /// Builds a bundle from non-empty headers of strictly increasing heights,
+    /// where every header trusts the height of the header preceding it.
+    pub fn new(headers: Vec<Header>) -> Result<Self, Error> {
+        if headers.is_empty() {
+            return Err(Error::InvalidHeaderBundle {
+                reason: "a header bundle cannot be empty".to_string(),
+            });
+        }
+
+        for pair in headers.windows(2) {
+            let (previous, header) = (&pair[0], &pair[1]);
+
+            if header.height() <= previous.height() {
+                return Err(Error::InvalidHeaderBundle {
+                    reason: format!(
+                        "header heights must be strictly increasing ({} <= {})",
+                        header.height(),
+                        previous.height()
+                    ),
+                });
+            }
+
+            if header.trusted_height != previous.height() {
+                return Err(Error::InvalidHeaderBundle {
+                    reason: format!(
+                        "header at height {} must trust the preceding header at height {}, but trusts height {}",
+                        header.height(),
+                        previous.height(),
+                        header.trusted_height
+                    ),
+                });
+            }
+        }
+
+        Ok(Self { headers })
+    }
+
+    pub fn headers(&self) -> &[Header] {
+        &self.headers
+    }
+
+    pub fn into_headers(self) -> Vec<Header> {
+        self.headers
+    }
+}
+
+impl Protobuf<RawHeaderBundle> for HeaderBundle {}
+
+impl TryFrom<RawHeaderBundle> for HeaderBundle {
+    type Error = Error;
+
+    fn try_from(raw: RawHeaderBundle) -> Result<Self, Self::Error> {
+        let headers = raw
+            .headers
+            .into_iter()
+            .map(Header::try_from)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Self::new(headers)
+    }
+}
+
+impl From<HeaderBundle> for RawHeaderBundle {
+    fn from(value: HeaderBundle) -> Self {
+        RawHeaderBundle {
+            headers: value.headers.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl Protobuf<Any> for HeaderBundle {}
+
+impl TryFrom<Any> for HeaderBundle {
+    type Error = ClientError;
+
+    fn try_from(raw: Any) -> Result<Self, Self::Error> {
+        fn decode_header_bundle(value: &[u8]) -> Result<HeaderBundle, ClientError> {
+            let header_bundle =
+                Protobuf::<RawHeaderBundle>::decode(value).map_err(|e| ClientError::Other {
+                    description: e.to_string(),
+                })?;
+            Ok(header_bundle)
+        }
+        match raw.type_url.as_str() {
+            TENDERMINT_HEADER_BUNDLE_TYPE_URL => decode_header_bundle(&raw.value),
+            _ => Err(ClientError::UnknownHeaderType {
+                header_type: raw.type_url,
+            }),
+        }
+    }
+}
+
+impl From<HeaderBundle> for Any {
+    fn from(header_bundle: HeaderBundle) -> Self {
+        Any {
+            type_url: TENDERMINT_HEADER_BUNDLE_TYPE_URL.to_string(),
+            value: Protobuf::<RawHeaderBundle>::encode_vec(header_bundle),
+        }
+    }
+}
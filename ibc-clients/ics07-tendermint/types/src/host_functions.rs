@@ -0,0 +1,109 @@
+//! Defines the cryptographic primitives the Tendermint light client relies on.
+
+use core::fmt::Debug;
+use core::marker::PhantomData;
+
+use ibc_core_commitment_types::merkle::{HostFunctionsManager, HostFunctionsProvider};
+use tendermint::crypto::signature::Verifier as SignatureVerifier;
+use tendermint::crypto::Sha256;
+use tendermint::merkle::MerkleHash;
+use tendermint_light_client_verifier::errors::VerificationError;
+use tendermint_light_client_verifier::operations::{
+    ProdCommitValidator, ProvidedVotingPowerCalculator, VotingPowerCalculator, VotingPowerTally,
+};
+use tendermint_light_client_verifier::predicates::VerificationPredicates;
+use tendermint_light_client_verifier::types::{SignedHeader, TrustThreshold, ValidatorSet};
+use tendermint_light_client_verifier::PredicateVerifier;
+
+/// The cryptographic primitives used to verify Tendermint headers, as well as
+/// the Merkle proofs of the counterparty chain state.
+///
+/// Hosts where these primitives are slow, or provided natively (e.g. as the
+/// host functions of a Substrate runtime or the precompiles of a zkVM), can
+/// implement this trait themselves. [`ProdHostFunctions`] implements it with
+/// pure Rust cryptography.
+pub trait HostFunctions:
+    HostFunctionsProvider + Clone + Debug + Default + PartialEq + Send + Sync
+{
+    /// The SHA-256 implementation used to hash headers and validator sets.
+    type Sha256: MerkleHash + Sha256 + Default;
+
+    /// The verifier of the validators' signatures on commits.
+    type SignatureVerifier: SignatureVerifier;
+}
+
+/// The default [`HostFunctions`], which rely on pure Rust cryptography.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ProdHostFunctions;
+
+impl HostFunctionsProvider for ProdHostFunctions {
+    fn sha2_256(message: &[u8]) -> [u8; 32] {
+        HostFunctionsManager::sha2_256(message)
+    }
+
+    fn sha2_512(message: &[u8]) -> [u8; 64] {
+        HostFunctionsManager::sha2_512(message)
+    }
+
+    fn sha2_512_truncated(message: &[u8]) -> [u8; 32] {
+        HostFunctionsManager::sha2_512_truncated(message)
+    }
+
+    fn keccak_256(message: &[u8]) -> [u8; 32] {
+        HostFunctionsManager::keccak_256(message)
+    }
+
+    fn ripemd160(message: &[u8]) -> [u8; 20] {
+        HostFunctionsManager::ripemd160(message)
+    }
+
+    fn blake2b_512(message: &[u8]) -> [u8; 64] {
+        HostFunctionsManager::blake2b_512(message)
+    }
+
+    fn blake2s_256(message: &[u8]) -> [u8; 32] {
+        HostFunctionsManager::blake2s_256(message)
+    }
+
+    fn blake3(message: &[u8]) -> [u8; 32] {
+        HostFunctionsManager::blake3(message)
+    }
+}
+
+impl HostFunctions for ProdHostFunctions {
+    type Sha256 = tendermint::crypto::default::Sha256;
+    type SignatureVerifier = tendermint::crypto::default::signature::Verifier;
+}
+
+/// The Tendermint light client verifier relying on the host functions `H`.
+pub type HostVerifier<H> =
+    PredicateVerifier<HostPredicates<H>, HostVotingPowerCalculator<H>, ProdCommitValidator>;
+
+/// The default verification predicates, hashing headers and validator sets
+/// with the SHA-256 implementation of the host functions `H`.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct HostPredicates<H>(PhantomData<H>);
+
+impl<H: HostFunctions> VerificationPredicates for HostPredicates<H> {
+    type Sha256 = H::Sha256;
+}
+
+/// Computes the voting power in commits, verifying the signatures of the
+/// validators with the signature verifier of the host functions `H`.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct HostVotingPowerCalculator<H>(PhantomData<H>);
+
+impl<H: HostFunctions> VotingPowerCalculator for HostVotingPowerCalculator<H> {
+    fn voting_power_in(
+        &self,
+        signed_header: &SignedHeader,
+        validator_set: &ValidatorSet,
+        trust_threshold: TrustThreshold,
+    ) -> Result<VotingPowerTally, VerificationError> {
+        ProvidedVotingPowerCalculator::<H::SignatureVerifier>::default().voting_power_in(
+            signed_header,
+            validator_set,
+            trust_threshold,
+        )
+    }
+}
@@ -25,13 +25,19 @@ extern crate std;
 mod client_state;
 mod consensus_state;
 mod header;
+mod header_bundle;
+mod host_functions;
 mod misbehaviour;
+mod misbehaviour_detection;
 mod trust_threshold;
 
 pub use client_state::*;
 pub use consensus_state::*;
 pub use header::*;
+pub use header_bundle::*;
+pub use host_functions::*;
 pub use misbehaviour::*;
+pub use misbehaviour_detection::*;
 pub use trust_threshold::*;
 
 pub mod error;
@@ -0,0 +1,156 @@
+//! Detects the misbehaviour of a Tendermint chain from the headers observed
+//! by its light clients, and builds the corresponding [`Misbehaviour`].
+
+use core::cmp::Ordering;
+use core::fmt::{Display, Error as FmtError, Formatter};
+
+use ibc_core_client_types::Height;
+use ibc_core_host_types::identifiers::ClientId;
+use ibc_primitives::prelude::*;
+use tendermint::validator::Set as ValidatorSet;
+use tendermint_light_client_verifier::types::LightBlock;
+
+use crate::header::Header;
+use crate::misbehaviour::Misbehaviour;
+
+/// The kind of misbehaviour evidenced by two conflicting headers.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MisbehaviourKind {
+    /// Two different blocks were committed at the same height, i.e. the chain
+    /// forked.
+    DuplicateHeight,
+    /// A block was committed with a time no later than the time of a block at
+    /// a lower height, violating the monotonicity of BFT time.
+    BftTimeViolation,
+}
+
+impl Display for MisbehaviourKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), FmtError> {
+        match self {
+            Self::DuplicateHeight => write!(f, "duplicate height"),
+            Self::BftTimeViolation => write!(f, "BFT time violation"),
+        }
+    }
+}
+
+/// The evidence of misbehaviour found among conflicting headers.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MisbehaviourEvidence {
+    kind: MisbehaviourKind,
+    misbehaviour: Misbehaviour,
+}
+
+impl MisbehaviourEvidence {
+    pub fn kind(&self) -> MisbehaviourKind {
+        self.kind
+    }
+
+    pub fn misbehaviour(&self) -> &Misbehaviour {
+        &self.misbehaviour
+    }
+
+    /// Returns the [`Misbehaviour`], ready to be submitted to the client.
+    pub fn into_misbehaviour(self) -> Misbehaviour {
+        self.misbehaviour
+    }
+}
+
+/// Looks for misbehaviour among two sets of headers of the chain tracked by
+/// the client `client_id`, e.g. as reported by two different full nodes.
+///
+/// Every header of the first set is compared with every header of the second
+/// one. Conflicting headers at the same height are looked for first, and then
+/// headers violating the monotonicity of BFT time. Each header must trust a
+/// consensus state of the client, as the headers of the returned
+/// [`Misbehaviour`] are verified against the consensus states at their
+/// trusted heights.
+pub fn detect_misbehaviour(
+    client_id: &ClientId,
+    headers_1: &[Header],
+    headers_2: &[Header],
+) -> Option<MisbehaviourEvidence> {
+    // Each set is assumed to be consistent on its own, hence only the headers
+    // of different sets are compared.
+    let conflicting_pairs = || {
+        headers_1.iter().flat_map(|header_a| {
+            headers_2
+                .iter()
+                .map(move |header_b| (header_a, header_b))
+                .filter(|(header_a, header_b)| {
+                    header_a.signed_header.header.chain_id == header_b.signed_header.header.chain_id
+                })
+        })
+    };
+
+    let duplicate_height = conflicting_pairs().find_map(|(header_a, header_b)| {
+        let is_duplicate = header_a.height() == header_b.height()
+            && header_a.signed_header.commit.block_id.hash
+                != header_b.signed_header.commit.block_id.hash;
+
+        is_duplicate.then(|| MisbehaviourEvidence {
+            kind: MisbehaviourKind::DuplicateHeight,
+            misbehaviour: Misbehaviour::new(client_id.clone(), header_a.clone(), header_b.clone()),
+        })
+    });
+
+    duplicate_height.or_else(|| {
+        conflicting_pairs().find_map(|(header_a, header_b)| {
+            // The first header of a `Misbehaviour` must be the highest one.
+            let (header_1, header_2) = match header_a.height().cmp(&header_b.height()) {
+                Ordering::Greater => (header_a, header_b),
+                Ordering::Less => (header_b, header_a),
+                Ordering::Equal => return None,
+            };
+
+            let is_bft_time_violation =
+                header_1.signed_header.header.time <= header_2.signed_header.header.time;
+
+            is_bft_time_violation.then(|| MisbehaviourEvidence {
+                kind: MisbehaviourKind::BftTimeViolation,
+                misbehaviour: Misbehaviour::new(
+                    client_id.clone(),
+                    header_1.clone(),
+                    header_2.clone(),
+                ),
+            })
+        })
+    })
+}
+
+/// Looks for misbehaviour among two sets of light blocks of the chain tracked
+/// by the client `client_id`, as [`detect_misbehaviour`] does for headers.
+///
+/// The headers of the returned [`Misbehaviour`] trust the consensus state of
+/// the client at `trusted_height`, whose next validator set is
+/// `trusted_next_validator_set`. The light blocks at or below the trusted
+/// height are skipped, as they cannot be verified against it.
+pub fn detect_light_block_misbehaviour(
+    client_id: &ClientId,
+    trusted_height: Height,
+    trusted_next_validator_set: &ValidatorSet,
+    light_blocks_1: &[LightBlock],
+    light_blocks_2: &[LightBlock],
+) -> Option<MisbehaviourEvidence> {
+    let into_headers = |light_blocks: &[LightBlock]| -> Vec<Header> {
+        light_blocks
+            .iter()
+            .filter(|light_block| {
+                light_block.signed_header.header.height.value() > trusted_height.revision_height()
+            })
+            .map(|light_block| Header {
+                signed_header: light_block.signed_header.clone(),
+                validator_set: light_block.validators.clone(),
+                trusted_height,
+                trusted_next_validator_set: trusted_next_validator_set.clone(),
+            })
+            .collect()
+    };
+
+    detect_misbehaviour(
+        client_id,
+        &into_headers(light_blocks_1),
+        &into_headers(light_blocks_2),
+    )
+}
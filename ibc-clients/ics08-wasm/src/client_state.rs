@@ -0,0 +1,485 @@
+//! This module includes trait implementations for the
+//! `ibc_client_wasm_types::client_state::ClientState` type. Implemented traits
+//! include `ClientStateCommon`, `ClientStateValidation`, and
+//! `ClientStateExecution`.
+//!
+//! Note that this crate defines a newtype wrapper around the
+//! `ibc_client_wasm_types::client_state::ClientState` type in order to enable
+//! implementing a foreign trait on a foreign type (i.e. the orphan rule in
+//! Rust). As such, this module also includes some trait implementations that
+//! serve to pass through traits implemented on the wrapped `ClientState` type.
+//!
+//! Every client specific operation is forwarded to the light client contract
+//! the client state points to, through the host's [`WasmEngine`].
+
+use ibc_client_wasm_types::client_message::ClientMessage;
+use ibc_client_wasm_types::client_state::ClientState as ClientStateType;
+use ibc_client_wasm_types::consensus_state::ConsensusState as ConsensusStateType;
+use ibc_client_wasm_types::contract::{
+    CheckForMisbehaviourMsg, InstantiateMsg, MerklePath, QueryMsg, StatusMsg, SudoMsg,
+    UpdateStateMsg, UpdateStateOnMisbehaviourMsg, VerifyClientMessageMsg, VerifyMembershipMsg,
+    VerifyNonMembershipMsg,
+};
+use ibc_client_wasm_types::error::Error;
+use ibc_client_wasm_types::proto::v1::ClientState as RawWasmClientState;
+use ibc_client_wasm_types::{client_type as wasm_client_type, Bytes};
+use ibc_core_client::context::client_state::{
+    ClientStateCommon, ClientStateExecution, ClientStateValidation,
+};
+use ibc_core_client::context::{ClientExecutionContext, ClientValidationContext};
+use ibc_core_client::types::error::ClientError;
+use ibc_core_client::types::{Height, Status, UpdateKind};
+use ibc_core_commitment_types::commitment::{
+    CommitmentPrefix, CommitmentProofBytes, CommitmentRoot,
+};
+use ibc_core_commitment_types::merkle::apply_prefix;
+use ibc_core_host::types::identifiers::{ClientId, ClientType};
+use ibc_core_host::types::path::{ClientConsensusStatePath, ClientStatePath, Path};
+use ibc_primitives::prelude::*;
+use ibc_primitives::proto::{Any, Protobuf};
+
+use crate::consensus_state::ConsensusState as WasmConsensusState;
+use crate::context::{
+    CommonContext, ExecutionContext as WasmExecutionContext,
+    ValidationContext as WasmValidationContext,
+};
+use crate::handler::checksum_hex;
+
+/// Newtype wrapper around the `ClientState` type imported from the
+/// `ibc-client-wasm-types` crate. This wrapper exists so that we can bypass
+/// Rust's orphan rules and implement traits from `ibc::core::client::context`
+/// on the `ClientState` type.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ClientState(ClientStateType);
+
+impl ClientState {
+    pub fn new(data: Bytes, checksum: Bytes, latest_height: Height) -> Self {
+        Self(ClientStateType {
+            data,
+            checksum,
+            latest_height,
+        })
+    }
+
+    pub fn inner(&self) -> &ClientStateType {
+        &self.0
+    }
+
+    pub fn checksum(&self) -> &[u8] {
+        &self.0.checksum
+    }
+
+    /// Returns a copy of the client state pointing to the contract with the
+    /// given checksum.
+    pub fn with_checksum(&self, checksum: Bytes) -> Self {
+        Self(ClientStateType {
+            checksum,
+            ..self.0.clone()
+        })
+    }
+}
+
+impl From<ClientStateType> for ClientState {
+    fn from(client_state: ClientStateType) -> Self {
+        Self(client_state)
+    }
+}
+
+impl Protobuf<RawWasmClientState> for ClientState {}
+
+impl TryFrom<RawWasmClientState> for ClientState {
+    type Error = Error;
+
+    fn try_from(raw: RawWasmClientState) -> Result<Self, Self::Error> {
+        Ok(Self(ClientStateType::try_from(raw)?))
+    }
+}
+
+impl From<ClientState> for RawWasmClientState {
+    fn from(client_state: ClientState) -> Self {
+        client_state.0.into()
+    }
+}
+
+impl Protobuf<Any> for ClientState {}
+
+impl TryFrom<Any> for ClientState {
+    type Error = ClientError;
+
+    fn try_from(raw: Any) -> Result<Self, Self::Error> {
+        Ok(Self(ClientStateType::try_from(raw)?))
+    }
+}
+
+impl From<ClientState> for Any {
+    fn from(client_state: ClientState) -> Self {
+        client_state.0.into()
+    }
+}
+
+impl ClientStateCommon for ClientState {
+    fn verify_consensus_state(&self, consensus_state: Any) -> Result<(), ClientError> {
+        WasmConsensusState::try_from(consensus_state)?;
+
+        Ok(())
+    }
+
+    fn client_type(&self) -> ClientType {
+        wasm_client_type()
+    }
+
+    fn latest_height(&self) -> Height {
+        self.0.latest_height
+    }
+
+    fn validate_proof_height(&self, proof_height: Height) -> Result<(), ClientError> {
+        if self.latest_height() < proof_height {
+            return Err(ClientError::InvalidProofHeight {
+                latest_height: self.latest_height(),
+                proof_height,
+            });
+        }
+        Ok(())
+    }
+
+    /// Always fails: the upgrade proofs must be handed over to the contract
+    /// along with the upgraded states, which the client upgrade handler does
+    /// not allow for.
+    fn verify_upgrade_client(
+        &self,
+        _upgraded_client_state: Any,
+        _upgraded_consensus_state: Any,
+        _proof_upgrade_client: CommitmentProofBytes,
+        _proof_upgrade_consensus_state: CommitmentProofBytes,
+        _root: &CommitmentRoot,
+    ) -> Result<(), ClientError> {
+        Err(Error::UnsupportedOperation {
+            operation: "client upgrades".into(),
+        })?
+    }
+
+    /// Always fails, since proofs are verified by the contract. IBC handlers
+    /// call [`ClientStateValidation::verify_membership_with_context`] instead.
+    fn verify_membership(
+        &self,
+        _prefix: &CommitmentPrefix,
+        _proof: &CommitmentProofBytes,
+        _root: &CommitmentRoot,
+        _path: Path,
+        _value: Vec<u8>,
+    ) -> Result<(), ClientError> {
+        Err(Error::MissingHostContext)?
+    }
+
+    /// Always fails, since proofs are verified by the contract. IBC handlers
+    /// call [`ClientStateValidation::verify_non_membership_with_context`]
+    /// instead.
+    fn verify_non_membership(
+        &self,
+        _prefix: &CommitmentPrefix,
+        _proof: &CommitmentProofBytes,
+        _root: &CommitmentRoot,
+        _path: Path,
+    ) -> Result<(), ClientError> {
+        Err(Error::MissingHostContext)?
+    }
+}
+
+impl<V> ClientStateValidation<V> for ClientState
+where
+    V: ClientValidationContext + WasmValidationContext,
+{
+    fn verify_client_message(
+        &self,
+        ctx: &V,
+        client_id: &ClientId,
+        client_message: Any,
+        _update_kind: &UpdateKind,
+    ) -> Result<(), ClientError> {
+        let client_message = ClientMessage::try_from(client_message)?;
+
+        ctx.query(
+            client_id,
+            self.checksum(),
+            QueryMsg::VerifyClientMessage(VerifyClientMessageMsg {
+                client_message: client_message.data,
+            }),
+        )?;
+
+        Ok(())
+    }
+
+    fn check_for_misbehaviour(
+        &self,
+        ctx: &V,
+        client_id: &ClientId,
+        client_message: Any,
+        _update_kind: &UpdateKind,
+    ) -> Result<bool, ClientError> {
+        let client_message = ClientMessage::try_from(client_message)?;
+
+        let result = ctx.query(
+            client_id,
+            self.checksum(),
+            QueryMsg::CheckForMisbehaviour(CheckForMisbehaviourMsg {
+                client_message: client_message.data,
+            }),
+        )?;
+
+        Ok(result.found_misbehaviour)
+    }
+
+    /// Returns [`Status::Unauthorized`] if the contract of the client is not
+    /// allowed anymore, and the status reported by the contract otherwise.
+    fn status(&self, ctx: &V, client_id: &ClientId) -> Result<Status, ClientError> {
+        if !ctx.has_checksum(self.checksum())? {
+            return Ok(Status::Unauthorized);
+        }
+
+        let result = ctx.query(client_id, self.checksum(), QueryMsg::Status(StatusMsg {}))?;
+
+        match result.status.as_deref() {
+            Some("Active") => Ok(Status::Active),
+            Some("Frozen") => Ok(Status::Frozen),
+            Some("Expired") => Ok(Status::Expired),
+            Some("Unauthorized") => Ok(Status::Unauthorized),
+            Some(status) => Err(Error::InvalidStatus {
+                status: status.to_string(),
+            })?,
+            None => Err(Error::MissingContractResult {
+                field: "status".into(),
+            })?,
+        }
+    }
+
+    fn verify_membership_with_context(
+        &self,
+        ctx: &V,
+        client_id: &ClientId,
+        prefix: &CommitmentPrefix,
+        proof: &CommitmentProofBytes,
+        _root: &CommitmentRoot,
+        proof_height: Height,
+        path: Path,
+        value: Vec<u8>,
+    ) -> Result<(), ClientError> {
+        ctx.sudo_read_only(
+            client_id,
+            self.checksum(),
+            SudoMsg::VerifyMembership(VerifyMembershipMsg {
+                height: proof_height,
+                delay_time_period: 0,
+                delay_block_period: 0,
+                proof: proof.clone().into(),
+                merkle_path: merkle_path(prefix, &path),
+                value,
+            }),
+        )?;
+
+        Ok(())
+    }
+
+    fn verify_non_membership_with_context(
+        &self,
+        ctx: &V,
+        client_id: &ClientId,
+        prefix: &CommitmentPrefix,
+        proof: &CommitmentProofBytes,
+        _root: &CommitmentRoot,
+        proof_height: Height,
+        path: Path,
+    ) -> Result<(), ClientError> {
+        ctx.sudo_read_only(
+            client_id,
+            self.checksum(),
+            SudoMsg::VerifyNonMembership(VerifyNonMembershipMsg {
+                height: proof_height,
+                delay_time_period: 0,
+                delay_block_period: 0,
+                proof: proof.clone().into(),
+                merkle_path: merkle_path(prefix, &path),
+            }),
+        )?;
+
+        Ok(())
+    }
+}
+
+impl<E> ClientStateExecution<E> for ClientState
+where
+    E: WasmExecutionContext,
+    <E as ClientExecutionContext>::AnyClientState: From<ClientState>,
+    <E as ClientExecutionContext>::AnyConsensusState: From<WasmConsensusState>,
+{
+    /// Stores the initial client and consensus states, then instantiates the
+    /// contract with their `data`, which the contract may overwrite.
+    fn initialise(
+        &self,
+        ctx: &mut E,
+        client_id: &ClientId,
+        consensus_state: Any,
+    ) -> Result<(), ClientError> {
+        if !ctx.has_checksum(self.checksum())? {
+            return Err(Error::ChecksumNotFound {
+                checksum: checksum_hex(self.checksum()),
+            }
+            .into());
+        }
+
+        let consensus_state = ConsensusStateType::try_from(consensus_state)?;
+        let latest_height = self.latest_height();
+
+        ctx.store_client_state(ClientStatePath::new(client_id), self.clone().into())?;
+        ctx.store_consensus_state(
+            ClientConsensusStatePath::new(
+                client_id.clone(),
+                latest_height.revision_number(),
+                latest_height.revision_height(),
+            ),
+            WasmConsensusState::from(consensus_state.clone()).into(),
+        )?;
+
+        ctx.instantiate(
+            client_id,
+            self.checksum(),
+            InstantiateMsg {
+                client_state: self.0.data.clone(),
+                consensus_state: consensus_state.data,
+                checksum: self.0.checksum.clone(),
+            },
+        )?;
+
+        let host_timestamp = CommonContext::host_timestamp(ctx)?;
+        let host_height = CommonContext::host_height(ctx)?;
+        ctx.store_update_time(client_id.clone(), latest_height, host_timestamp)?;
+        ctx.store_update_height(client_id.clone(), latest_height, host_height)?;
+
+        Ok(())
+    }
+
+    /// Lets the contract update the client and consensus states, and records
+    /// the processed time and height of each consensus height it reports.
+    fn update_state(
+        &self,
+        ctx: &mut E,
+        client_id: &ClientId,
+        header: Any,
+    ) -> Result<Vec<Height>, ClientError> {
+        let client_message = ClientMessage::try_from(header)?;
+
+        let result = ctx.sudo(
+            client_id,
+            self.checksum(),
+            SudoMsg::UpdateState(UpdateStateMsg {
+                client_message: client_message.data,
+            }),
+        )?;
+
+        let host_timestamp = CommonContext::host_timestamp(ctx)?;
+        let host_height = CommonContext::host_height(ctx)?;
+        for height in &result.heights {
+            ctx.store_update_time(client_id.clone(), *height, host_timestamp)?;
+            ctx.store_update_height(client_id.clone(), *height, host_height)?;
+        }
+
+        Ok(result.heights)
+    }
+
+    fn update_state_on_misbehaviour(
+        &self,
+        ctx: &mut E,
+        client_id: &ClientId,
+        client_message: Any,
+        _update_kind: &UpdateKind,
+    ) -> Result<(), ClientError> {
+        let client_message = ClientMessage::try_from(client_message)?;
+
+        ctx.sudo(
+            client_id,
+            self.checksum(),
+            SudoMsg::UpdateStateOnMisbehaviour(UpdateStateOnMisbehaviourMsg {
+                client_message: client_message.data,
+            }),
+        )?;
+
+        Ok(())
+    }
+
+    fn update_state_on_upgrade(
+        &self,
+        _ctx: &mut E,
+        _client_id: &ClientId,
+        _upgraded_client_state: Any,
+        _upgraded_consensus_state: Any,
+    ) -> Result<Height, ClientError> {
+        Err(Error::UnsupportedOperation {
+            operation: "client upgrades".into(),
+        })?
+    }
+
+    /// Lets the contract overwrite the subject client store with the
+    /// substitute client store, which requires both clients to run the same
+    /// contract.
+    fn check_substitute_and_update_state(
+        &self,
+        ctx: &mut E,
+        subject_client_id: &ClientId,
+        substitute_client_id: &ClientId,
+        substitute_client_state: Self,
+    ) -> Result<(), ClientError> {
+        if self.checksum() != substitute_client_state.checksum() {
+            return Err(ClientError::SubstituteMismatch {
+                description: format!(
+                    "mismatched checksums: subject {}, substitute {}",
+                    checksum_hex(self.checksum()),
+                    checksum_hex(substitute_client_state.checksum())
+                ),
+            });
+        }
+
+        ctx.migrate_client_store(subject_client_id, substitute_client_id, self.checksum())?;
+
+        let latest_height = substitute_client_state.latest_height();
+        let host_timestamp = CommonContext::host_timestamp(ctx)?;
+        let host_height = CommonContext::host_height(ctx)?;
+        ctx.store_update_time(subject_client_id.clone(), latest_height, host_timestamp)?;
+        ctx.store_update_height(subject_client_id.clone(), latest_height, host_height)?;
+
+        Ok(())
+    }
+}
+
+/// Builds the path the contract verifies a value at, with the commitment
+/// prefix as its first key, as ibc-go does.
+fn merkle_path(prefix: &CommitmentPrefix, path: &Path) -> MerklePath {
+    MerklePath {
+        key_path: apply_prefix(prefix, vec![path.to_string()]).key_path,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn client_state_verify_proof_height() {
+        let client_state = ClientState::new(vec![1], vec![2; 32], Height::new(0, 10).unwrap());
+
+        assert!(client_state
+            .validate_proof_height(Height::new(0, 10).unwrap())
+            .is_ok());
+        assert!(client_state
+            .validate_proof_height(Height::new(0, 11).unwrap())
+            .is_err());
+    }
+
+    #[test]
+    fn client_state_with_checksum() {
+        let client_state = ClientState::new(vec![1], vec![2; 32], Height::new(0, 10).unwrap());
+        let migrated = client_state.with_checksum(vec![3; 32]);
+
+        assert_eq!(migrated.checksum(), &[3; 32]);
+        assert_eq!(migrated.inner().data, client_state.inner().data);
+        assert_eq!(migrated.latest_height(), client_state.latest_height());
+    }
+}
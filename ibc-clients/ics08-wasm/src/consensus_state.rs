@@ -0,0 +1,78 @@
+//! This module includes trait implementations for the
+//! `ibc_client_wasm_types::consensus_state::ConsensusState` type. It
+//! implements the `ConsensusStateTrait` for `ConsensusState` by defining a
+//! wrapper in order to circumvent Rust's orphan rule, which disallows foreign
+//! traits from being implemented on foreign types.
+
+use ibc_client_wasm_types::consensus_state::ConsensusState as ConsensusStateType;
+use ibc_client_wasm_types::Bytes;
+use ibc_core_client::context::consensus_state::ConsensusState as ConsensusStateTrait;
+use ibc_core_client::types::error::ClientError;
+use ibc_core_commitment_types::commitment::CommitmentRoot;
+use ibc_primitives::prelude::*;
+use ibc_primitives::proto::{Any, Protobuf};
+use ibc_primitives::Timestamp;
+
+/// Wrapper around the `ConsensusState` type imported from the
+/// `ibc-client-wasm-types` crate. This wrapper exists so that we can bypass
+/// Rust's orphan rules and implement traits from `ibc::core::client::context`
+/// on the `ConsensusState` type.
+///
+/// The consensus state is opaque to the host: its `data` is only understood by
+/// the light client contract. Hence, its commitment root is empty and its
+/// timestamp is [`Timestamp::none`], which notably means that packet timeouts
+/// by timestamp cannot be proven against a Wasm client.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ConsensusState {
+    inner: ConsensusStateType,
+    root: CommitmentRoot,
+}
+
+impl ConsensusState {
+    pub fn new(data: Bytes) -> Self {
+        ConsensusStateType::new(data).into()
+    }
+
+    pub fn inner(&self) -> &ConsensusStateType {
+        &self.inner
+    }
+}
+
+impl From<ConsensusStateType> for ConsensusState {
+    fn from(consensus_state: ConsensusStateType) -> Self {
+        Self {
+            inner: consensus_state,
+            root: CommitmentRoot::from_bytes(&[]),
+        }
+    }
+}
+
+impl Protobuf<Any> for ConsensusState {}
+
+impl TryFrom<Any> for ConsensusState {
+    type Error = ClientError;
+
+    fn try_from(raw: Any) -> Result<Self, Self::Error> {
+        Ok(ConsensusStateType::try_from(raw)?.into())
+    }
+}
+
+impl From<ConsensusState> for Any {
+    fn from(consensus_state: ConsensusState) -> Self {
+        consensus_state.inner.into()
+    }
+}
+
+impl ConsensusStateTrait for ConsensusState {
+    fn root(&self) -> &CommitmentRoot {
+        &self.root
+    }
+
+    fn timestamp(&self) -> Timestamp {
+        Timestamp::none()
+    }
+
+    fn encode_vec(self) -> Vec<u8> {
+        <Self as Protobuf<Any>>::encode_vec(self)
+    }
+}
@@ -0,0 +1,111 @@
+use ibc_client_wasm_types::client_state::ClientState as ClientStateType;
+use ibc_client_wasm_types::contract::{ContractResult, InstantiateMsg, QueryMsg, SudoMsg};
+use ibc_client_wasm_types::Bytes;
+use ibc_core_client::context::ClientExecutionContext;
+use ibc_core_client::types::error::ClientError;
+use ibc_core_client::types::Height;
+use ibc_core_handler_types::error::ContextError;
+use ibc_core_host::types::identifiers::ClientId;
+use ibc_primitives::prelude::*;
+use ibc_primitives::{Signer, Timestamp};
+
+/// The virtual machine the host runs light client contracts in.
+///
+/// Each call is made on behalf of a client, identified by `client_id`, against
+/// the contract whose byte code hashes to `checksum`. As in ibc-go, contracts
+/// own the client store: the engine is expected to give the contract access
+/// to the store of `client_id`, so that the contract can persist the client
+/// and consensus states itself, wrapped into the Wasm [`ClientStateType`] and
+/// `ConsensusState` types.
+pub trait WasmEngine {
+    /// Stores the given byte code and returns its checksum, i.e. its SHA-256
+    /// hash.
+    fn store_code(&mut self, wasm_byte_code: &[u8]) -> Result<Bytes, ClientError>;
+
+    /// Calls the `instantiate` entry point of the contract upon client creation.
+    fn instantiate(
+        &mut self,
+        client_id: &ClientId,
+        checksum: &[u8],
+        msg: InstantiateMsg,
+    ) -> Result<(), ClientError>;
+
+    /// Calls the `sudo` entry point of the contract.
+    fn sudo(
+        &mut self,
+        client_id: &ClientId,
+        checksum: &[u8],
+        msg: SudoMsg,
+    ) -> Result<ContractResult, ClientError>;
+
+    /// Calls the `sudo` entry point of the contract without persisting any
+    /// state change. Used for the messages which only verify proofs, i.e.
+    /// [`SudoMsg::VerifyMembership`] and [`SudoMsg::VerifyNonMembership`].
+    fn sudo_read_only(
+        &self,
+        client_id: &ClientId,
+        checksum: &[u8],
+        msg: SudoMsg,
+    ) -> Result<ContractResult, ClientError>;
+
+    /// Calls the `query` entry point of the contract.
+    fn query(
+        &self,
+        client_id: &ClientId,
+        checksum: &[u8],
+        msg: QueryMsg,
+    ) -> Result<ContractResult, ClientError>;
+
+    /// Calls the `migrate` entry point of the contract with the given
+    /// `checksum`, which the client is being migrated to.
+    fn migrate(
+        &mut self,
+        client_id: &ClientId,
+        checksum: &[u8],
+        msg: &[u8],
+    ) -> Result<(), ClientError>;
+
+    /// Calls the `sudo` entry point of the contract with
+    /// [`SudoMsg::MigrateClientStore`] upon client recovery. The contract is
+    /// given access to the store of the subject client under the `subject/`
+    /// prefix and to the store of the substitute client under the
+    /// `substitute/` prefix.
+    fn migrate_client_store(
+        &mut self,
+        subject_client_id: &ClientId,
+        substitute_client_id: &ClientId,
+        checksum: &[u8],
+    ) -> Result<ContractResult, ClientError>;
+}
+
+/// Client's context required during both validation and execution
+pub trait CommonContext: WasmEngine {
+    /// Returns the current timestamp of the local chain.
+    fn host_timestamp(&self) -> Result<Timestamp, ContextError>;
+
+    /// Returns the current height of the local chain.
+    fn host_height(&self) -> Result<Height, ContextError>;
+}
+
+/// Client's context required during validation
+pub trait ValidationContext: CommonContext {
+    /// Returns whether the given checksum belongs to the allowed light client
+    /// contracts.
+    fn has_checksum(&self, checksum: &[u8]) -> Result<bool, ContextError>;
+
+    /// Returns the account allowed to store, migrate and remove light client
+    /// contracts, typically the governance module.
+    fn authority(&self) -> Signer;
+
+    /// Returns the Wasm client state of the given client.
+    fn wasm_client_state(&self, client_id: &ClientId) -> Result<ClientStateType, ContextError>;
+}
+
+/// Client's context required during execution
+pub trait ExecutionContext: ValidationContext + ClientExecutionContext {
+    /// Adds the given checksum to the allowed light client contracts.
+    fn store_checksum(&mut self, checksum: Bytes) -> Result<(), ContextError>;
+
+    /// Removes the given checksum from the allowed light client contracts.
+    fn delete_checksum(&mut self, checksum: &[u8]) -> Result<(), ContextError>;
+}
@@ -0,0 +1,47 @@
+//! Protocol logic for processing `MsgMigrateContract`.
+
+use ibc_client_wasm_types::error::Error;
+use ibc_client_wasm_types::msgs::migrate_contract::MsgMigrateContract;
+use ibc_core_client::context::ClientExecutionContext;
+use ibc_core_client::types::error::ClientError;
+use ibc_core_handler_types::error::ContextError;
+use ibc_core_host::types::path::ClientStatePath;
+
+use super::{checksum_hex, ensure_checksum_exists, validate_authority, validate_checksum};
+use crate::client_state::ClientState;
+use crate::context::{ExecutionContext, ValidationContext};
+
+pub fn validate<Ctx>(ctx: &Ctx, msg: MsgMigrateContract) -> Result<(), ContextError>
+where
+    Ctx: ValidationContext,
+{
+    validate_authority(ctx, &msg.signer)?;
+    validate_checksum(&msg.checksum)?;
+    ensure_checksum_exists(ctx, &msg.checksum)?;
+
+    let client_state = ctx.wasm_client_state(&msg.client_id)?;
+    if client_state.checksum == msg.checksum {
+        return Err(ClientError::from(Error::SameChecksum {
+            checksum: checksum_hex(&msg.checksum),
+        })
+        .into());
+    }
+
+    Ok(())
+}
+
+/// Calls the `migrate` entry point of the new contract, then points the
+/// client state, as possibly modified by the migration, to the new contract.
+pub fn execute<Ctx>(ctx: &mut Ctx, msg: MsgMigrateContract) -> Result<(), ContextError>
+where
+    Ctx: ExecutionContext,
+    <Ctx as ClientExecutionContext>::AnyClientState: From<ClientState>,
+{
+    ctx.migrate(&msg.client_id, &msg.checksum, &msg.msg)?;
+
+    let client_state = ClientState::from(ctx.wasm_client_state(&msg.client_id)?);
+    ctx.store_client_state(
+        ClientStatePath::new(&msg.client_id),
+        client_state.with_checksum(msg.checksum).into(),
+    )
+}
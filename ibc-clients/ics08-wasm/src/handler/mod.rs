@@ -0,0 +1,70 @@
+//! Implements the processing logic of the messages managing the light client
+//! contracts: [`MsgStoreCode`](ibc_client_wasm_types::msgs::store_code::MsgStoreCode),
+//! [`MsgMigrateContract`](ibc_client_wasm_types::msgs::migrate_contract::MsgMigrateContract)
+//! and [`MsgRemoveChecksum`](ibc_client_wasm_types::msgs::remove_checksum::MsgRemoveChecksum).
+
+pub mod migrate_contract;
+pub mod remove_checksum;
+pub mod store_code;
+
+use core::fmt::Write;
+
+use ibc_client_wasm_types::error::Error;
+use ibc_core_client::types::error::ClientError;
+use ibc_core_handler_types::error::ContextError;
+use ibc_primitives::prelude::*;
+use ibc_primitives::Signer;
+
+use crate::context::ValidationContext;
+
+/// The maximum size of the byte code of a light client contract, as in ibc-go.
+pub const MAX_WASM_BYTE_CODE_SIZE: usize = 3 * 1024 * 1024;
+
+/// The size of a checksum, i.e. a SHA-256 hash.
+pub const CHECKSUM_LENGTH: usize = 32;
+
+/// Formats a checksum as a lowercase hex string.
+pub(crate) fn checksum_hex(checksum: &[u8]) -> String {
+    checksum.iter().fold(String::new(), |mut hex, byte| {
+        let _ = write!(hex, "{byte:02x}");
+        hex
+    })
+}
+
+fn validate_authority<Ctx>(ctx: &Ctx, signer: &Signer) -> Result<(), ContextError>
+where
+    Ctx: ValidationContext,
+{
+    let authority = ctx.authority();
+    if signer != &authority {
+        return Err(ClientError::from(Error::UnauthorizedSigner {
+            signer: signer.to_string(),
+            authority: authority.to_string(),
+        })
+        .into());
+    }
+    Ok(())
+}
+
+fn validate_checksum(checksum: &[u8]) -> Result<(), ContextError> {
+    if checksum.len() != CHECKSUM_LENGTH {
+        return Err(ClientError::from(Error::InvalidChecksum {
+            length: checksum.len(),
+        })
+        .into());
+    }
+    Ok(())
+}
+
+fn ensure_checksum_exists<Ctx>(ctx: &Ctx, checksum: &[u8]) -> Result<(), ContextError>
+where
+    Ctx: ValidationContext,
+{
+    if !ctx.has_checksum(checksum)? {
+        return Err(ClientError::from(Error::ChecksumNotFound {
+            checksum: checksum_hex(checksum),
+        })
+        .into());
+    }
+    Ok(())
+}
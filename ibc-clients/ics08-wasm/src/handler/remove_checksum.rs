@@ -0,0 +1,26 @@
+//! Protocol logic for processing `MsgRemoveChecksum`.
+
+use ibc_client_wasm_types::msgs::remove_checksum::MsgRemoveChecksum;
+use ibc_core_handler_types::error::ContextError;
+
+use super::{ensure_checksum_exists, validate_authority, validate_checksum};
+use crate::context::{ExecutionContext, ValidationContext};
+
+pub fn validate<Ctx>(ctx: &Ctx, msg: MsgRemoveChecksum) -> Result<(), ContextError>
+where
+    Ctx: ValidationContext,
+{
+    validate_authority(ctx, &msg.signer)?;
+    validate_checksum(&msg.checksum)?;
+    ensure_checksum_exists(ctx, &msg.checksum)
+}
+
+/// Disallows the checksum. Clients still pointing to the contract become
+/// [`Unauthorized`](ibc_core_client::types::Status::Unauthorized) until they
+/// are migrated.
+pub fn execute<Ctx>(ctx: &mut Ctx, msg: MsgRemoveChecksum) -> Result<(), ContextError>
+where
+    Ctx: ExecutionContext,
+{
+    ctx.delete_checksum(&msg.checksum)
+}
@@ -0,0 +1,52 @@
+//! Protocol logic for processing `MsgStoreCode`.
+
+use ibc_client_wasm_types::error::Error;
+use ibc_client_wasm_types::msgs::store_code::MsgStoreCode;
+use ibc_client_wasm_types::Bytes;
+use ibc_core_client::types::error::ClientError;
+use ibc_core_handler_types::error::ContextError;
+
+use super::{checksum_hex, validate_authority, validate_checksum, MAX_WASM_BYTE_CODE_SIZE};
+use crate::context::{ExecutionContext, ValidationContext};
+
+pub fn validate<Ctx>(ctx: &Ctx, msg: MsgStoreCode) -> Result<(), ContextError>
+where
+    Ctx: ValidationContext,
+{
+    validate_authority(ctx, &msg.signer)?;
+
+    let size = msg.wasm_byte_code.len();
+    if size == 0 {
+        return Err(ClientError::from(Error::EmptyWasmCode).into());
+    }
+    if size > MAX_WASM_BYTE_CODE_SIZE {
+        return Err(ClientError::from(Error::WasmCodeTooLarge {
+            size,
+            max_size: MAX_WASM_BYTE_CODE_SIZE,
+        })
+        .into());
+    }
+
+    Ok(())
+}
+
+/// Stores the byte code through the engine and allows the resulting
+/// checksum, which is returned.
+pub fn execute<Ctx>(ctx: &mut Ctx, msg: MsgStoreCode) -> Result<Bytes, ContextError>
+where
+    Ctx: ExecutionContext,
+{
+    let checksum = ctx.store_code(&msg.wasm_byte_code)?;
+    validate_checksum(&checksum)?;
+
+    if ctx.has_checksum(&checksum)? {
+        return Err(ClientError::from(Error::ChecksumAlreadyExists {
+            checksum: checksum_hex(&checksum),
+        })
+        .into());
+    }
+
+    ctx.store_checksum(checksum.clone())?;
+
+    Ok(checksum)
+}
@@ -0,0 +1,33 @@
+//! ICS 08: Wasm light client implementation along with re-exporting data
+//! structures and implementations of IBC core client module.
+//!
+//! The Wasm client is a proxy client: it does not verify anything itself and
+//! instead forwards every call to a light client contract, identified by the
+//! checksum of its byte code, which the host executes through the
+//! [`context::WasmEngine`] trait.
+#![no_std]
+#![forbid(unsafe_code)]
+#![cfg_attr(not(test), deny(clippy::unwrap_used))]
+#![cfg_attr(not(test), deny(clippy::disallowed_methods, clippy::disallowed_types))]
+#![deny(
+    warnings,
+    trivial_casts,
+    trivial_numeric_casts,
+    unused_import_braces,
+    unused_qualifications,
+    rust_2018_idioms
+)]
+
+#[cfg(any(test, feature = "std"))]
+extern crate std;
+
+pub mod client_state;
+pub mod consensus_state;
+pub mod context;
+pub mod handler;
+
+/// Re-export of Wasm light client data structures from `ibc-client-wasm-types` crate.
+pub mod types {
+    #[doc(inline)]
+    pub use ibc_client_wasm_types::*;
+}
@@ -1,8 +1,10 @@
 //! Defines the client message type for the ICS-08 Wasm light client.
 
-use ibc_primitives::proto::Protobuf;
+use ibc_primitives::prelude::*;
+use ibc_primitives::proto::{Any, Protobuf};
 use ibc_proto::ibc::lightclients::wasm::v1::ClientMessage as RawClientMessage;
 
+use crate::error::Error;
 use crate::Bytes;
 
 pub const WASM_CLIENT_MESSAGE_TYPE_URL: &str = "/ibc.lightclients.wasm.v1.ClientMessage";
@@ -27,6 +29,36 @@ impl From<ClientMessage> for RawClientMessage {
     }
 }
 
+impl Protobuf<Any> for ClientMessage {}
+
+impl From<ClientMessage> for Any {
+    fn from(value: ClientMessage) -> Self {
+        Self {
+            type_url: WASM_CLIENT_MESSAGE_TYPE_URL.to_string(),
+            value: Protobuf::<RawClientMessage>::encode_vec(value),
+        }
+    }
+}
+
+impl TryFrom<Any> for ClientMessage {
+    type Error = Error;
+
+    fn try_from(any: Any) -> Result<Self, Self::Error> {
+        match any.type_url.as_str() {
+            WASM_CLIENT_MESSAGE_TYPE_URL => {
+                Protobuf::<RawClientMessage>::decode(any.value.as_slice()).map_err(|e| {
+                    Error::DecodeError {
+                        reason: e.to_string(),
+                    }
+                })
+            }
+            _ => Err(Error::DecodeError {
+                reason: "type_url does not match".into(),
+            }),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use rstest::rstest;
@@ -42,6 +74,11 @@ mod tests {
         assert_eq!(
             RawClientMessage::from(ClientMessage::from(raw_msg.clone())),
             raw_msg,
-        )
+        );
+        let msg = ClientMessage::from(raw_msg);
+        assert_eq!(
+            ClientMessage::try_from(Any::from(msg.clone())).unwrap(),
+            msg
+        );
     }
 }
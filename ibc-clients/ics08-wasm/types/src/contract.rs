@@ -0,0 +1,215 @@
+//! Defines the messages exchanged between the ICS-08 Wasm light client and the
+//! light client contracts, mirroring the contract API of ibc-go's `08-wasm`
+//! module.
+//!
+//! Byte fields hold the contract-specific encoding of the light client's
+//! types, i.e. the `data` of the Wasm `ClientState`, `ConsensusState` and
+//! `ClientMessage`.
+
+#[cfg(feature = "cosmwasm")]
+use cosmwasm_schema::cw_serde;
+use ibc_core_client::types::Height;
+use ibc_primitives::prelude::*;
+
+#[cfg(feature = "cosmwasm")]
+use crate::serializer::Base64;
+use crate::Bytes;
+
+/// The message a light client contract is instantiated with upon client
+/// creation.
+#[cfg_attr(feature = "cosmwasm", cw_serde)]
+#[cfg_attr(not(feature = "cosmwasm"), derive(Clone, Debug, PartialEq))]
+#[derive(Eq)]
+pub struct InstantiateMsg {
+    #[cfg_attr(feature = "cosmwasm", schemars(with = "String"))]
+    #[cfg_attr(feature = "cosmwasm", serde(with = "Base64", default))]
+    pub client_state: Bytes,
+    #[cfg_attr(feature = "cosmwasm", schemars(with = "String"))]
+    #[cfg_attr(feature = "cosmwasm", serde(with = "Base64", default))]
+    pub consensus_state: Bytes,
+    #[cfg_attr(feature = "cosmwasm", schemars(with = "String"))]
+    #[cfg_attr(feature = "cosmwasm", serde(with = "Base64", default))]
+    pub checksum: Bytes,
+}
+
+/// The messages handled by the `sudo` entry point of a light client
+/// contract, which may modify the client store.
+#[cfg_attr(feature = "cosmwasm", cw_serde)]
+#[cfg_attr(not(feature = "cosmwasm"), derive(Clone, Debug, PartialEq))]
+#[derive(Eq)]
+pub enum SudoMsg {
+    UpdateState(UpdateStateMsg),
+    UpdateStateOnMisbehaviour(UpdateStateOnMisbehaviourMsg),
+    VerifyUpgradeAndUpdateState(VerifyUpgradeAndUpdateStateMsg),
+    VerifyMembership(VerifyMembershipMsg),
+    VerifyNonMembership(VerifyNonMembershipMsg),
+    MigrateClientStore(MigrateClientStoreMsg),
+}
+
+/// The messages handled by the `query` entry point of a light client
+/// contract.
+#[cfg_attr(feature = "cosmwasm", cw_serde)]
+#[cfg_attr(not(feature = "cosmwasm"), derive(Clone, Debug, PartialEq))]
+#[derive(Eq)]
+pub enum QueryMsg {
+    Status(StatusMsg),
+    TimestampAtHeight(TimestampAtHeightMsg),
+    VerifyClientMessage(VerifyClientMessageMsg),
+    CheckForMisbehaviour(CheckForMisbehaviourMsg),
+}
+
+#[cfg_attr(feature = "cosmwasm", cw_serde)]
+#[cfg_attr(not(feature = "cosmwasm"), derive(Clone, Debug, PartialEq))]
+#[derive(Eq)]
+pub struct UpdateStateMsg {
+    #[cfg_attr(feature = "cosmwasm", schemars(with = "String"))]
+    #[cfg_attr(feature = "cosmwasm", serde(with = "Base64", default))]
+    pub client_message: Bytes,
+}
+
+#[cfg_attr(feature = "cosmwasm", cw_serde)]
+#[cfg_attr(not(feature = "cosmwasm"), derive(Clone, Debug, PartialEq))]
+#[derive(Eq)]
+pub struct UpdateStateOnMisbehaviourMsg {
+    #[cfg_attr(feature = "cosmwasm", schemars(with = "String"))]
+    #[cfg_attr(feature = "cosmwasm", serde(with = "Base64", default))]
+    pub client_message: Bytes,
+}
+
+#[cfg_attr(feature = "cosmwasm", cw_serde)]
+#[cfg_attr(not(feature = "cosmwasm"), derive(Clone, Debug, PartialEq))]
+#[derive(Eq)]
+pub struct VerifyUpgradeAndUpdateStateMsg {
+    #[cfg_attr(feature = "cosmwasm", schemars(with = "String"))]
+    #[cfg_attr(feature = "cosmwasm", serde(with = "Base64", default))]
+    pub upgrade_client_state: Bytes,
+    #[cfg_attr(feature = "cosmwasm", schemars(with = "String"))]
+    #[cfg_attr(feature = "cosmwasm", serde(with = "Base64", default))]
+    pub upgrade_consensus_state: Bytes,
+    #[cfg_attr(feature = "cosmwasm", schemars(with = "String"))]
+    #[cfg_attr(feature = "cosmwasm", serde(with = "Base64", default))]
+    pub proof_upgrade_client: Bytes,
+    #[cfg_attr(feature = "cosmwasm", schemars(with = "String"))]
+    #[cfg_attr(feature = "cosmwasm", serde(with = "Base64", default))]
+    pub proof_upgrade_consensus_state: Bytes,
+}
+
+/// The path of a value in the counterparty's store, with the commitment
+/// prefix as its first key.
+#[cfg_attr(feature = "cosmwasm", cw_serde)]
+#[cfg_attr(not(feature = "cosmwasm"), derive(Clone, Debug, PartialEq))]
+#[derive(Eq)]
+pub struct MerklePath {
+    pub key_path: Vec<String>,
+}
+
+#[cfg_attr(feature = "cosmwasm", cw_serde)]
+#[cfg_attr(not(feature = "cosmwasm"), derive(Clone, Debug, PartialEq))]
+#[derive(Eq)]
+pub struct VerifyMembershipMsg {
+    pub height: Height,
+    pub delay_time_period: u64,
+    pub delay_block_period: u64,
+    #[cfg_attr(feature = "cosmwasm", schemars(with = "String"))]
+    #[cfg_attr(feature = "cosmwasm", serde(with = "Base64", default))]
+    pub proof: Bytes,
+    pub merkle_path: MerklePath,
+    #[cfg_attr(feature = "cosmwasm", schemars(with = "String"))]
+    #[cfg_attr(feature = "cosmwasm", serde(with = "Base64", default))]
+    pub value: Bytes,
+}
+
+#[cfg_attr(feature = "cosmwasm", cw_serde)]
+#[cfg_attr(not(feature = "cosmwasm"), derive(Clone, Debug, PartialEq))]
+#[derive(Eq)]
+pub struct VerifyNonMembershipMsg {
+    pub height: Height,
+    pub delay_time_period: u64,
+    pub delay_block_period: u64,
+    #[cfg_attr(feature = "cosmwasm", schemars(with = "String"))]
+    #[cfg_attr(feature = "cosmwasm", serde(with = "Base64", default))]
+    pub proof: Bytes,
+    pub merkle_path: MerklePath,
+}
+
+#[cfg_attr(feature = "cosmwasm", cw_serde)]
+#[cfg_attr(not(feature = "cosmwasm"), derive(Clone, Debug, PartialEq))]
+#[derive(Eq)]
+pub struct MigrateClientStoreMsg {}
+
+#[cfg_attr(feature = "cosmwasm", cw_serde)]
+#[cfg_attr(not(feature = "cosmwasm"), derive(Clone, Debug, PartialEq))]
+#[derive(Eq)]
+pub struct StatusMsg {}
+
+#[cfg_attr(feature = "cosmwasm", cw_serde)]
+#[cfg_attr(not(feature = "cosmwasm"), derive(Clone, Debug, PartialEq))]
+#[derive(Eq)]
+pub struct TimestampAtHeightMsg {
+    pub height: Height,
+}
+
+#[cfg_attr(feature = "cosmwasm", cw_serde)]
+#[cfg_attr(not(feature = "cosmwasm"), derive(Clone, Debug, PartialEq))]
+#[derive(Eq)]
+pub struct VerifyClientMessageMsg {
+    #[cfg_attr(feature = "cosmwasm", schemars(with = "String"))]
+    #[cfg_attr(feature = "cosmwasm", serde(with = "Base64", default))]
+    pub client_message: Bytes,
+}
+
+#[cfg_attr(feature = "cosmwasm", cw_serde)]
+#[cfg_attr(not(feature = "cosmwasm"), derive(Clone, Debug, PartialEq))]
+#[derive(Eq)]
+pub struct CheckForMisbehaviourMsg {
+    #[cfg_attr(feature = "cosmwasm", schemars(with = "String"))]
+    #[cfg_attr(feature = "cosmwasm", serde(with = "Base64", default))]
+    pub client_message: Bytes,
+}
+
+/// The result of a contract call. Only the fields relevant to the call are
+/// set, e.g. `heights` for [`SudoMsg::UpdateState`] or `status` for
+/// [`QueryMsg::Status`].
+#[cfg_attr(feature = "cosmwasm", cw_serde)]
+#[cfg_attr(not(feature = "cosmwasm"), derive(Clone, Debug, PartialEq))]
+#[derive(Eq, Default)]
+pub struct ContractResult {
+    #[cfg_attr(feature = "cosmwasm", serde(default))]
+    pub heights: Vec<Height>,
+    #[cfg_attr(feature = "cosmwasm", serde(default))]
+    pub found_misbehaviour: bool,
+    /// One of `Active`, `Frozen`, `Expired` or `Unauthorized`
+    pub status: Option<String>,
+    /// The timestamp at the queried height, in nanoseconds
+    pub timestamp: Option<u64>,
+}
+
+impl ContractResult {
+    pub fn heights(heights: Vec<Height>) -> Self {
+        Self {
+            heights,
+            ..Default::default()
+        }
+    }
+
+    pub fn found_misbehaviour(found_misbehaviour: bool) -> Self {
+        Self {
+            found_misbehaviour,
+            ..Default::default()
+        }
+    }
+
+    pub fn status(status: impl ToString) -> Self {
+        Self {
+            status: Some(status.to_string()),
+            ..Default::default()
+        }
+    }
+
+    pub fn timestamp(timestamp: u64) -> Self {
+        Self {
+            timestamp: Some(timestamp),
+            ..Default::default()
+        }
+    }
+}
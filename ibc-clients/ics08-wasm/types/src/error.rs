@@ -1,6 +1,7 @@
 //! Defines the error type for the ICS-08 Wasm light client.
 
 use displaydoc::Display;
+use ibc_core_client::types::error::ClientError;
 use ibc_core_host_types::error::IdentifierError;
 use ibc_primitives::prelude::*;
 
@@ -13,6 +14,28 @@ pub enum Error {
     DecodeError { reason: String },
     /// invalid client state latest height: `{reason}`
     InvalidLatestHeight { reason: String },
+    /// invalid checksum: expected 32 bytes, got `{length}`
+    InvalidChecksum { length: usize },
+    /// empty wasm code
+    EmptyWasmCode,
+    /// wasm code of `{size}` bytes exceeds the maximum of `{max_size}` bytes
+    WasmCodeTooLarge { size: usize, max_size: usize },
+    /// checksum `{checksum}` not found
+    ChecksumNotFound { checksum: String },
+    /// checksum `{checksum}` already exists
+    ChecksumAlreadyExists { checksum: String },
+    /// the client is already using checksum `{checksum}`
+    SameChecksum { checksum: String },
+    /// signer `{signer}` is not the authority `{authority}`
+    UnauthorizedSigner { signer: String, authority: String },
+    /// the contract returned an invalid status: `{status}`
+    InvalidStatus { status: String },
+    /// the contract did not return a `{field}`
+    MissingContractResult { field: String },
+    /// verification is performed by the contract and cannot be performed without context
+    MissingHostContext,
+    /// the wasm client does not support `{operation}`
+    UnsupportedOperation { operation: String },
 }
 
 #[cfg(feature = "std")]
@@ -30,3 +53,11 @@ impl From<IdentifierError> for Error {
         Self::InvalidIdentifier(e)
     }
 }
+
+impl From<Error> for ClientError {
+    fn from(e: Error) -> Self {
+        Self::ClientSpecific {
+            description: e.to_string(),
+        }
+    }
+}
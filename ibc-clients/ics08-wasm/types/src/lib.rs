@@ -15,6 +15,7 @@
 pub mod client_message;
 pub mod client_state;
 pub mod consensus_state;
+pub mod contract;
 pub mod error;
 pub mod msgs;
 
@@ -0,0 +1,424 @@
+//! This module includes trait implementations for the
+//! `ibc_client_localhost_types::ClientState` type. Implemented traits include
+//! `ClientStateCommon`, `ClientStateValidation`, and `ClientStateExecution`.
+//!
+//! Note that this crate defines a newtype wrapper around the
+//! `ibc_client_localhost_types::ClientState` type in order to enable
+//! implementing a foreign trait on a foreign type (i.e. the orphan rule in
+//! Rust). As such, this module also includes some trait implementations that
+//! serve to pass through traits implemented on the wrapped `ClientState` type.
+
+use ibc_client_localhost_types::error::Error;
+use ibc_client_localhost_types::proto::v2::ClientState as RawLocalhostClientState;
+use ibc_client_localhost_types::{
+    client_type as localhost_client_type, ClientState as ClientStateType, SENTINEL_PROOF,
+};
+use ibc_core_client::context::client_state::{
+    ClientStateCommon, ClientStateExecution, ClientStateValidation,
+};
+use ibc_core_client::context::{ClientExecutionContext, ClientValidationContext};
+use ibc_core_client::types::error::ClientError;
+use ibc_core_client::types::{Height, Status, UpdateKind};
+use ibc_core_commitment_types::commitment::{
+    CommitmentPrefix, CommitmentProofBytes, CommitmentRoot,
+};
+use ibc_core_host::types::identifiers::{ClientId, ClientType};
+use ibc_core_host::types::path::{ClientConsensusStatePath, ClientStatePath, Path};
+use ibc_primitives::prelude::*;
+use ibc_primitives::proto::{Any, Protobuf};
+
+use super::consensus_state::ConsensusState as LocalhostConsensusState;
+use crate::context::{
+    CommonContext, ExecutionContext as LocalhostExecutionContext,
+    ValidationContext as LocalhostValidationContext,
+};
+
+/// Newtype wrapper around the `ClientState` type imported from the
+/// `ibc-client-localhost-types` crate. This wrapper exists so that we can
+/// bypass Rust's orphan rules and implement traits from
+/// `ibc::core::client::context` on the `ClientState` type.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ClientState(ClientStateType);
+
+impl ClientState {
+    pub fn new(latest_height: Height) -> Self {
+        Self(ClientStateType::new(latest_height))
+    }
+
+    pub fn inner(&self) -> &ClientStateType {
+        &self.0
+    }
+}
+
+impl From<ClientStateType> for ClientState {
+    fn from(client_state: ClientStateType) -> Self {
+        Self(client_state)
+    }
+}
+
+impl Protobuf<RawLocalhostClientState> for ClientState {}
+
+impl TryFrom<RawLocalhostClientState> for ClientState {
+    type Error = Error;
+
+    fn try_from(raw: RawLocalhostClientState) -> Result<Self, Self::Error> {
+        Ok(Self(ClientStateType::try_from(raw)?))
+    }
+}
+
+impl From<ClientState> for RawLocalhostClientState {
+    fn from(client_state: ClientState) -> Self {
+        client_state.0.into()
+    }
+}
+
+impl Protobuf<Any> for ClientState {}
+
+impl TryFrom<Any> for ClientState {
+    type Error = ClientError;
+
+    fn try_from(raw: Any) -> Result<Self, Self::Error> {
+        Ok(Self(ClientStateType::try_from(raw)?))
+    }
+}
+
+impl From<ClientState> for Any {
+    fn from(client_state: ClientState) -> Self {
+        client_state.0.into()
+    }
+}
+
+impl ClientStateCommon for ClientState {
+    fn verify_consensus_state(&self, consensus_state: Any) -> Result<(), ClientError> {
+        LocalhostConsensusState::try_from(consensus_state)?;
+
+        Ok(())
+    }
+
+    fn client_type(&self) -> ClientType {
+        localhost_client_type()
+    }
+
+    fn latest_height(&self) -> Height {
+        self.0.latest_height
+    }
+
+    /// Note that the localhost client only keeps the consensus state at its
+    /// latest height, so handlers which look up the consensus state at the
+    /// proof height will only succeed for proofs at the latest height.
+    fn validate_proof_height(&self, proof_height: Height) -> Result<(), ClientError> {
+        if self.latest_height() < proof_height {
+            return Err(ClientError::InvalidProofHeight {
+                latest_height: self.latest_height(),
+                proof_height,
+            });
+        }
+        Ok(())
+    }
+
+    fn verify_upgrade_client(
+        &self,
+        _upgraded_client_state: Any,
+        _upgraded_consensus_state: Any,
+        _proof_upgrade_client: CommitmentProofBytes,
+        _proof_upgrade_consensus_state: CommitmentProofBytes,
+        _root: &CommitmentRoot,
+    ) -> Result<(), ClientError> {
+        Err(Error::UnsupportedOperation {
+            operation: "client upgrades".into(),
+        })?
+    }
+
+    /// Always fails, since the localhost client verifies values against the
+    /// host store. IBC handlers call
+    /// [`ClientStateValidation::verify_membership_with_context`] instead.
+    fn verify_membership(
+        &self,
+        _prefix: &CommitmentPrefix,
+        _proof: &CommitmentProofBytes,
+        _root: &CommitmentRoot,
+        _path: Path,
+        _value: Vec<u8>,
+    ) -> Result<(), ClientError> {
+        Err(Error::MissingHostContext)?
+    }
+
+    /// Always fails, since the localhost client verifies values against the
+    /// host store. IBC handlers call
+    /// [`ClientStateValidation::verify_non_membership_with_context`] instead.
+    fn verify_non_membership(
+        &self,
+        _prefix: &CommitmentPrefix,
+        _proof: &CommitmentProofBytes,
+        _root: &CommitmentRoot,
+        _path: Path,
+    ) -> Result<(), ClientError> {
+        Err(Error::MissingHostContext)?
+    }
+}
+
+impl<V> ClientStateValidation<V> for ClientState
+where
+    V: ClientValidationContext + LocalhostValidationContext,
+{
+    /// The localhost client tracks the host chain itself, so any update
+    /// message is accepted and its content is ignored. Misbehaviour cannot
+    /// happen and is therefore rejected.
+    fn verify_client_message(
+        &self,
+        _ctx: &V,
+        _client_id: &ClientId,
+        _client_message: Any,
+        update_kind: &UpdateKind,
+    ) -> Result<(), ClientError> {
+        match update_kind {
+            UpdateKind::UpdateClient => Ok(()),
+            UpdateKind::SubmitMisbehaviour => Err(Error::UnsupportedOperation {
+                operation: "misbehaviour submission".into(),
+            })?,
+        }
+    }
+
+    fn check_for_misbehaviour(
+        &self,
+        _ctx: &V,
+        _client_id: &ClientId,
+        _client_message: Any,
+        _update_kind: &UpdateKind,
+    ) -> Result<bool, ClientError> {
+        Ok(false)
+    }
+
+    /// The localhost client can neither be frozen nor expire.
+    fn status(&self, _ctx: &V, _client_id: &ClientId) -> Result<Status, ClientError> {
+        Ok(Status::Active)
+    }
+
+    fn verify_membership_with_context(
+        &self,
+        ctx: &V,
+        _client_id: &ClientId,
+        _prefix: &CommitmentPrefix,
+        proof: &CommitmentProofBytes,
+        _root: &CommitmentRoot,
+        _proof_height: Height,
+        path: Path,
+        value: Vec<u8>,
+    ) -> Result<(), ClientError> {
+        verify_sentinel_proof(proof)?;
+
+        match ctx.stored_value(&path).map_err(|e| ClientError::Other {
+            description: e.to_string(),
+        })? {
+            Some(stored_value) if stored_value == value => Ok(()),
+            Some(_) => Err(Error::ValueMismatch {
+                path: path.to_string(),
+            })?,
+            None => Err(Error::ValueNotFound {
+                path: path.to_string(),
+            })?,
+        }
+    }
+
+    fn verify_non_membership_with_context(
+        &self,
+        ctx: &V,
+        _client_id: &ClientId,
+        _prefix: &CommitmentPrefix,
+        proof: &CommitmentProofBytes,
+        _root: &CommitmentRoot,
+        _proof_height: Height,
+        path: Path,
+    ) -> Result<(), ClientError> {
+        verify_sentinel_proof(proof)?;
+
+        match ctx.stored_value(&path).map_err(|e| ClientError::Other {
+            description: e.to_string(),
+        })? {
+            Some(_) => Err(Error::UnexpectedValue {
+                path: path.to_string(),
+            })?,
+            None => Ok(()),
+        }
+    }
+}
+
+impl<E> ClientStateExecution<E> for ClientState
+where
+    E: LocalhostExecutionContext + LocalhostValidationContext,
+    <E as ClientExecutionContext>::AnyClientState: From<ClientState>,
+    <E as ClientExecutionContext>::AnyConsensusState: From<LocalhostConsensusState>,
+{
+    /// Stores the localhost client state along with a consensus state holding
+    /// the current host timestamp.
+    ///
+    /// Only the `09-localhost` client identifier is accepted. Since the
+    /// identifiers of clients created through `MsgCreateClient` always carry a
+    /// counter suffix, the localhost client can only be created by the host
+    /// itself, see [`create_localhost_client`].
+    fn initialise(
+        &self,
+        ctx: &mut E,
+        client_id: &ClientId,
+        consensus_state: Any,
+    ) -> Result<(), ClientError> {
+        if !client_id.is_localhost() {
+            return Err(Error::InvalidClientId {
+                client_id: client_id.clone(),
+            }
+            .into());
+        }
+
+        let host_timestamp = CommonContext::host_timestamp(ctx)?;
+        let host_height = CommonContext::host_height(ctx)?;
+
+        let localhost_consensus_state = LocalhostConsensusState::try_from(consensus_state)?;
+
+        ctx.store_client_state(ClientStatePath::new(client_id), self.clone().into())?;
+        ctx.store_consensus_state(
+            ClientConsensusStatePath::new(
+                client_id.clone(),
+                self.0.latest_height.revision_number(),
+                self.0.latest_height.revision_height(),
+            ),
+            localhost_consensus_state.into(),
+        )?;
+        ctx.store_update_time(client_id.clone(), self.latest_height(), host_timestamp)?;
+        ctx.store_update_height(client_id.clone(), self.latest_height(), host_height)?;
+
+        Ok(())
+    }
+
+    /// Moves the client to the current host height. The consensus state at the
+    /// previous latest height is replaced by one holding the current host
+    /// timestamp.
+    fn update_state(
+        &self,
+        ctx: &mut E,
+        client_id: &ClientId,
+        _header: Any,
+    ) -> Result<Vec<Height>, ClientError> {
+        let host_timestamp = CommonContext::host_timestamp(ctx)?;
+        let host_height = CommonContext::host_height(ctx)?;
+
+        let previous_height = self.latest_height();
+        ctx.delete_consensus_state(ClientConsensusStatePath::new(
+            client_id.clone(),
+            previous_height.revision_number(),
+            previous_height.revision_height(),
+        ))?;
+        ctx.delete_update_time(client_id.clone(), previous_height)?;
+        ctx.delete_update_height(client_id.clone(), previous_height)?;
+
+        ctx.store_consensus_state(
+            ClientConsensusStatePath::new(
+                client_id.clone(),
+                host_height.revision_number(),
+                host_height.revision_height(),
+            ),
+            LocalhostConsensusState::new(host_timestamp).into(),
+        )?;
+        ctx.store_client_state(
+            ClientStatePath::new(client_id),
+            ClientState::from(self.0.with_latest_height(host_height)).into(),
+        )?;
+        ctx.store_update_time(client_id.clone(), host_height, host_timestamp)?;
+        ctx.store_update_height(client_id.clone(), host_height, host_height)?;
+
+        Ok(vec![host_height])
+    }
+
+    fn update_state_on_misbehaviour(
+        &self,
+        _ctx: &mut E,
+        _client_id: &ClientId,
+        _client_message: Any,
+        _update_kind: &UpdateKind,
+    ) -> Result<(), ClientError> {
+        Err(Error::UnsupportedOperation {
+            operation: "misbehaviour submission".into(),
+        })?
+    }
+
+    fn update_state_on_upgrade(
+        &self,
+        _ctx: &mut E,
+        _client_id: &ClientId,
+        _upgraded_client_state: Any,
+        _upgraded_consensus_state: Any,
+    ) -> Result<Height, ClientError> {
+        Err(Error::UnsupportedOperation {
+            operation: "client upgrades".into(),
+        })?
+    }
+
+    fn check_substitute_and_update_state(
+        &self,
+        _ctx: &mut E,
+        _subject_client_id: &ClientId,
+        _substitute_client_id: &ClientId,
+        _substitute_client_state: Self,
+    ) -> Result<(), ClientError> {
+        Err(Error::UnsupportedOperation {
+            operation: "client recovery".into(),
+        })?
+    }
+}
+
+/// Creates the localhost client at the current host height under the
+/// `09-localhost` client identifier.
+///
+/// Hosts which want to support same-chain IBC are expected to call this once,
+/// e.g. at genesis, together with
+/// `ibc_core_connection::localhost::create_localhost_connection`.
+pub fn create_localhost_client<E>(ctx: &mut E) -> Result<ClientState, ClientError>
+where
+    E: LocalhostExecutionContext + LocalhostValidationContext,
+    <E as ClientExecutionContext>::AnyClientState: From<ClientState>,
+    <E as ClientExecutionContext>::AnyConsensusState: From<LocalhostConsensusState>,
+{
+    let host_timestamp = CommonContext::host_timestamp(ctx)?;
+    let host_height = CommonContext::host_height(ctx)?;
+
+    let client_state = ClientState::new(host_height);
+    let consensus_state = LocalhostConsensusState::new(host_timestamp);
+
+    client_state.initialise(ctx, &ClientId::localhost(), consensus_state.into())?;
+
+    Ok(client_state)
+}
+
+fn verify_sentinel_proof(proof: &CommitmentProofBytes) -> Result<(), ClientError> {
+    let proof: &[u8] = proof.as_ref();
+    if proof != SENTINEL_PROOF {
+        return Err(Error::InvalidProof.into());
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn client_state_verify_proof_height() {
+        let client_state = ClientState::new(Height::new(0, 10).unwrap());
+
+        assert!(client_state
+            .validate_proof_height(Height::new(0, 10).unwrap())
+            .is_ok());
+        assert!(client_state
+            .validate_proof_height(Height::new(0, 11).unwrap())
+            .is_err());
+    }
+
+    #[test]
+    fn client_state_sentinel_proof() {
+        let sentinel = CommitmentProofBytes::try_from(SENTINEL_PROOF.to_vec()).unwrap();
+        let other = CommitmentProofBytes::try_from(vec![0x02]).unwrap();
+
+        assert!(verify_sentinel_proof(&sentinel).is_ok());
+        assert!(verify_sentinel_proof(&other).is_err());
+    }
+}
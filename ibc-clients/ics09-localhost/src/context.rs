@@ -0,0 +1,34 @@
+use ibc_core_client::context::ClientExecutionContext;
+use ibc_core_client::types::Height;
+use ibc_core_handler_types::error::ContextError;
+use ibc_core_host::types::path::Path;
+use ibc_primitives::prelude::*;
+use ibc_primitives::Timestamp;
+
+/// Client's context required during both validation and execution
+pub trait CommonContext {
+    /// Returns the current timestamp of the local chain.
+    fn host_timestamp(&self) -> Result<Timestamp, ContextError>;
+
+    /// Returns the current height of the local chain.
+    fn host_height(&self) -> Result<Height, ContextError>;
+}
+
+/// Client's context required during validation
+pub trait ValidationContext: CommonContext {
+    /// Returns the value committed at the given path in the host store,
+    /// encoded exactly as the IBC handlers expect it to be proven, or `None`
+    /// if nothing is stored at that path.
+    ///
+    /// The localhost client compares this value against the one being
+    /// verified in place of checking a commitment proof.
+    fn stored_value(&self, path: &Path) -> Result<Option<Vec<u8>>, ContextError>;
+}
+
+/// Client's context required during execution.
+///
+/// This trait is automatically implemented for all types that implement
+/// [`CommonContext`] and [`ClientExecutionContext`]
+pub trait ExecutionContext: CommonContext + ClientExecutionContext {}
+
+impl<T> ExecutionContext for T where T: CommonContext + ClientExecutionContext {}
@@ -0,0 +1,34 @@
+//! ICS 09: Localhost light client implementation along with re-exporting data
+//! structures and implementations of IBC core client module.
+//!
+//! The localhost client allows modules on the same chain to communicate with
+//! each other using IBC semantics. Instead of verifying proofs against a
+//! commitment root, it reads the expected values directly from the host store
+//! through the [`context::ValidationContext`] trait.
+#![no_std]
+#![forbid(unsafe_code)]
+#![cfg_attr(not(test), deny(clippy::unwrap_used))]
+#![cfg_attr(not(test), deny(clippy::disallowed_methods, clippy::disallowed_types))]
+#![deny(
+    warnings,
+    trivial_casts,
+    trivial_numeric_casts,
+    unused_import_braces,
+    unused_qualifications,
+    rust_2018_idioms
+)]
+
+#[cfg(any(test, feature = "std"))]
+extern crate std;
+
+pub mod client_state;
+pub mod consensus_state;
+pub mod context;
+
+pub const LOCALHOST_CLIENT_TYPE: &str = "09-localhost";
+
+/// Re-export of Localhost light client data structures from `ibc-client-localhost` crate.
+pub mod types {
+    #[doc(inline)]
+    pub use ibc_client_localhost_types::*;
+}
@@ -0,0 +1,119 @@
+//! Defines the localhost client state type
+
+use ibc_core_client_types::error::ClientError;
+use ibc_core_client_types::Height;
+use ibc_primitives::prelude::*;
+use ibc_proto::google::protobuf::Any;
+use ibc_proto::ibc::lightclients::localhost::v2::ClientState as RawClientState;
+use ibc_proto::Protobuf;
+
+use crate::error::Error;
+
+pub const LOCALHOST_CLIENT_STATE_TYPE_URL: &str = "/ibc.lightclients.localhost.v2.ClientState";
+
+/// Contains the latest height of the host chain, which is the only state the
+/// localhost client needs to track.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ClientState {
+    pub latest_height: Height,
+}
+
+impl ClientState {
+    pub fn new(latest_height: Height) -> Self {
+        Self { latest_height }
+    }
+
+    pub fn latest_height(&self) -> Height {
+        self.latest_height
+    }
+
+    /// Returns a copy of the client state with the latest height set to the
+    /// given height.
+    pub fn with_latest_height(&self, latest_height: Height) -> Self {
+        Self { latest_height }
+    }
+}
+
+impl Protobuf<RawClientState> for ClientState {}
+
+impl TryFrom<RawClientState> for ClientState {
+    type Error = Error;
+
+    fn try_from(raw: RawClientState) -> Result<Self, Self::Error> {
+        let latest_height = raw
+            .latest_height
+            .ok_or(Error::MissingLatestHeight)?
+            .try_into()
+            .map_err(|e: ClientError| Error::InvalidRawClientState {
+                reason: e.to_string(),
+            })?;
+
+        Ok(Self::new(latest_height))
+    }
+}
+
+impl From<ClientState> for RawClientState {
+    fn from(value: ClientState) -> Self {
+        Self {
+            latest_height: Some(value.latest_height.into()),
+        }
+    }
+}
+
+impl Protobuf<Any> for ClientState {}
+
+impl TryFrom<Any> for ClientState {
+    type Error = ClientError;
+
+    fn try_from(raw: Any) -> Result<Self, Self::Error> {
+        fn decode_client_state(value: &[u8]) -> Result<ClientState, ClientError> {
+            let client_state =
+                Protobuf::<RawClientState>::decode(value).map_err(|e| ClientError::Other {
+                    description: e.to_string(),
+                })?;
+            Ok(client_state)
+        }
+
+        match raw.type_url.as_str() {
+            LOCALHOST_CLIENT_STATE_TYPE_URL => decode_client_state(&raw.value),
+            _ => Err(ClientError::UnknownClientStateType {
+                client_state_type: raw.type_url,
+            }),
+        }
+    }
+}
+
+impl From<ClientState> for Any {
+    fn from(client_state: ClientState) -> Self {
+        Any {
+            type_url: LOCALHOST_CLIENT_STATE_TYPE_URL.to_string(),
+            value: Protobuf::<RawClientState>::encode_vec(client_state),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn client_state_any_roundtrip() {
+        let client_state = ClientState::new(Height::new(0, 10).unwrap());
+
+        let any: Any = client_state.clone().into();
+        assert_eq!(any.type_url, LOCALHOST_CLIENT_STATE_TYPE_URL);
+        assert_eq!(ClientState::try_from(any).unwrap(), client_state);
+    }
+
+    #[test]
+    fn client_state_missing_latest_height() {
+        let raw = RawClientState {
+            latest_height: None,
+        };
+        assert!(matches!(
+            ClientState::try_from(raw),
+            Err(Error::MissingLatestHeight)
+        ));
+    }
+}
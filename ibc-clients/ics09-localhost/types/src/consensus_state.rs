@@ -0,0 +1,132 @@
+//! Defines the localhost client consensus state type
+
+use ibc_core_client_types::error::ClientError;
+use ibc_core_commitment_types::commitment::CommitmentRoot;
+use ibc_primitives::prelude::*;
+use ibc_primitives::Timestamp;
+use ibc_proto::google::protobuf::{Any, Timestamp as RawTimestamp};
+use ibc_proto::Protobuf;
+
+use crate::error::Error;
+
+/// The localhost light client has no consensus state of its own in the IBC
+/// specification. IBC handlers nevertheless look up the consensus state at the
+/// proof height to obtain its timestamp, so `ibc-rs` stores a consensus state
+/// holding only the host timestamp at the latest height. It is encoded as a
+/// `google.protobuf.Timestamp` under this type URL.
+pub const LOCALHOST_CONSENSUS_STATE_TYPE_URL: &str =
+    "/ibc.lightclients.localhost.v2.ConsensusState";
+
+const NANOS_PER_SECOND: u64 = 1_000_000_000;
+
+/// Defines the localhost light client's consensus state
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ConsensusState {
+    pub timestamp: Timestamp,
+    /// Always empty; state is read from the host store rather than proven
+    /// against a root.
+    pub root: CommitmentRoot,
+}
+
+impl ConsensusState {
+    pub fn new(timestamp: Timestamp) -> Self {
+        Self {
+            timestamp,
+            root: CommitmentRoot::from_bytes(&[]),
+        }
+    }
+
+    pub fn timestamp(&self) -> Timestamp {
+        self.timestamp
+    }
+
+    pub fn root(&self) -> &CommitmentRoot {
+        &self.root
+    }
+}
+
+impl Protobuf<RawTimestamp> for ConsensusState {}
+
+impl TryFrom<RawTimestamp> for ConsensusState {
+    type Error = Error;
+
+    fn try_from(raw: RawTimestamp) -> Result<Self, Self::Error> {
+        let seconds = u64::try_from(raw.seconds).map_err(|_| Error::InvalidRawConsensusState {
+            reason: "negative timestamp seconds".into(),
+        })?;
+        let nanos = u64::try_from(raw.nanos).map_err(|_| Error::InvalidRawConsensusState {
+            reason: "negative timestamp nanos".into(),
+        })?;
+        let nanoseconds = seconds
+            .checked_mul(NANOS_PER_SECOND)
+            .and_then(|s| s.checked_add(nanos))
+            .ok_or(Error::InvalidRawConsensusState {
+                reason: "timestamp overflow".into(),
+            })?;
+        let timestamp = Timestamp::from_nanoseconds(nanoseconds).map_err(|e| {
+            Error::InvalidRawConsensusState {
+                reason: e.to_string(),
+            }
+        })?;
+
+        Ok(Self::new(timestamp))
+    }
+}
+
+impl From<ConsensusState> for RawTimestamp {
+    fn from(value: ConsensusState) -> Self {
+        let nanoseconds = value.timestamp.nanoseconds();
+        Self {
+            seconds: (nanoseconds / NANOS_PER_SECOND) as i64,
+            nanos: (nanoseconds % NANOS_PER_SECOND) as i32,
+        }
+    }
+}
+
+impl Protobuf<Any> for ConsensusState {}
+
+impl TryFrom<Any> for ConsensusState {
+    type Error = ClientError;
+
+    fn try_from(raw: Any) -> Result<Self, Self::Error> {
+        fn decode_consensus_state(value: &[u8]) -> Result<ConsensusState, ClientError> {
+            let consensus_state =
+                Protobuf::<RawTimestamp>::decode(value).map_err(|e| ClientError::Other {
+                    description: e.to_string(),
+                })?;
+            Ok(consensus_state)
+        }
+
+        match raw.type_url.as_str() {
+            LOCALHOST_CONSENSUS_STATE_TYPE_URL => decode_consensus_state(&raw.value),
+            _ => Err(ClientError::UnknownConsensusStateType {
+                consensus_state_type: raw.type_url,
+            }),
+        }
+    }
+}
+
+impl From<ConsensusState> for Any {
+    fn from(consensus_state: ConsensusState) -> Self {
+        Any {
+            type_url: LOCALHOST_CONSENSUS_STATE_TYPE_URL.to_string(),
+            value: Protobuf::<RawTimestamp>::encode_vec(consensus_state),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn consensus_state_any_roundtrip() {
+        let consensus_state =
+            ConsensusState::new(Timestamp::from_nanoseconds(1_700_000_000_123_456_789).unwrap());
+
+        let any: Any = consensus_state.clone().into();
+        assert_eq!(any.type_url, LOCALHOST_CONSENSUS_STATE_TYPE_URL);
+        assert_eq!(ConsensusState::try_from(any).unwrap(), consensus_state);
+    }
+}
@@ -0,0 +1,48 @@
+//! Defines the Localhost light client's error type
+
+use displaydoc::Display;
+use ibc_core_client_types::error::ClientError;
+use ibc_core_client_types::Height;
+use ibc_core_host_types::identifiers::ClientId;
+use ibc_primitives::prelude::*;
+
+/// The main error type
+#[derive(Debug, Display)]
+pub enum Error {
+    /// missing latest height
+    MissingLatestHeight,
+    /// invalid raw client state: `{reason}`
+    InvalidRawClientState { reason: String },
+    /// invalid raw consensus state: `{reason}`
+    InvalidRawConsensusState { reason: String },
+    /// the localhost client can only be created with the client identifier `09-localhost`, got `{client_id}`
+    InvalidClientId { client_id: ClientId },
+    /// invalid proof: expected the localhost sentinel proof
+    InvalidProof,
+    /// proof height `{proof_height}` is greater than the latest height `{latest_height}`
+    InvalidProofHeight {
+        proof_height: Height,
+        latest_height: Height,
+    },
+    /// value stored at path `{path}` does not match the expected value
+    ValueMismatch { path: String },
+    /// no value is stored at path `{path}`
+    ValueNotFound { path: String },
+    /// a value is stored at path `{path}` where none was expected
+    UnexpectedValue { path: String },
+    /// verification requires access to the host store and cannot be performed without context
+    MissingHostContext,
+    /// the localhost client does not support `{operation}`
+    UnsupportedOperation { operation: String },
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Error {}
+
+impl From<Error> for ClientError {
+    fn from(e: Error) -> Self {
+        Self::ClientSpecific {
+            description: e.to_string(),
+        }
+    }
+}
@@ -0,0 +1,59 @@
+//! ICS-09: Localhost Client implements a client verification algorithm for the
+//! chain it is running on, allowing modules on the same chain to communicate
+//! with each other over IBC semantics.
+#![no_std]
+#![forbid(unsafe_code)]
+#![cfg_attr(not(test), deny(clippy::unwrap_used))]
+#![cfg_attr(not(test), deny(clippy::disallowed_methods, clippy::disallowed_types,))]
+#![deny(
+    warnings,
+    trivial_casts,
+    trivial_numeric_casts,
+    unused_import_braces,
+    unused_qualifications,
+    rust_2018_idioms
+)]
+
+use core::str::FromStr;
+
+use ibc_core_host_types::identifiers::ClientType;
+
+#[cfg(any(test, feature = "std"))]
+extern crate std;
+
+mod client_state;
+mod consensus_state;
+
+pub use client_state::*;
+pub use consensus_state::*;
+
+pub mod error;
+
+/// Re-exports ICS-09 Localhost light client from `ibc-proto` crate.
+pub mod proto {
+    pub use ibc_proto::ibc::lightclients::localhost::*;
+}
+
+pub const LOCALHOST_CLIENT_TYPE: &str = "09-localhost";
+
+/// The proof that must accompany every message verified by the localhost
+/// client. Since the state is read directly from the host store, no real
+/// proof is needed; the sentinel only guards against accidentally routing a
+/// proof meant for another client type to the localhost client.
+pub const SENTINEL_PROOF: &[u8] = &[0x01];
+
+/// Returns the localhost `ClientType`
+pub fn client_type() -> ClientType {
+    ClientType::from_str(LOCALHOST_CLIENT_TYPE).expect("Never fails because it's valid")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Ensures that the validation in `ClientType::from_str` doesn't fail for the localhost client type
+    #[test]
+    pub fn test_localhost_client_type() {
+        let _ = ClientType::from_str(LOCALHOST_CLIENT_TYPE).unwrap();
+    }
+}
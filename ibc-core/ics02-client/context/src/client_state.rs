@@ -3,7 +3,7 @@
 use core::marker::{Send, Sync};
 
 use ibc_core_client_types::error::ClientError;
-use ibc_core_client_types::{Height, Status, UpdateKind};
+use ibc_core_client_types::{Height, Status, StatusDetails, UpdateKind};
 use ibc_core_commitment_types::commitment::{
     CommitmentPrefix, CommitmentProofBytes, CommitmentRoot,
 };
@@ -122,6 +122,64 @@ where
 
     /// Returns the status of the client. Only Active clients are allowed to process packets.
     fn status(&self, ctx: &V, client_id: &ClientId) -> Result<Status, ClientError>;
+
+    /// Returns the status of the client, detailing why it is not active or
+    /// when it expires.
+    ///
+    /// The default implementation only reports the [`status`](Self::status).
+    fn status_details(&self, ctx: &V, client_id: &ClientId) -> Result<StatusDetails, ClientError> {
+        Ok(self.status(ctx, client_id)?.into())
+    }
+
+    /// Verifies a proof of the existence of a value at a given path, with
+    /// access to the client's validation context.
+    ///
+    /// This is the method called by the IBC handlers, where `client_id` is the
+    /// identifier of this client and `root` is the commitment root of its
+    /// consensus state at `proof_height`. It defaults
+    /// to [`ClientStateCommon::verify_membership`], and is only meant to be
+    /// overridden by clients which need the host to verify the value, such as
+    /// the localhost client.
+    #[allow(clippy::too_many_arguments)]
+    fn verify_membership_with_context(
+        &self,
+        _ctx: &V,
+        _client_id: &ClientId,
+        prefix: &CommitmentPrefix,
+        proof: &CommitmentProofBytes,
+        root: &CommitmentRoot,
+        _proof_height: Height,
+        path: Path,
+        value: Vec<u8>,
+    ) -> Result<(), ClientError>
+    where
+        Self: ClientStateCommon,
+    {
+        self.verify_membership(prefix, proof, root, path, value)
+    }
+
+    /// Verifies the absence of a value at a given path, with access to the
+    /// client's validation context.
+    ///
+    /// This is the method called by the IBC handlers. It defaults to
+    /// [`ClientStateCommon::verify_non_membership`], see
+    /// [`verify_membership_with_context`](Self::verify_membership_with_context).
+    #[allow(clippy::too_many_arguments)]
+    fn verify_non_membership_with_context(
+        &self,
+        _ctx: &V,
+        _client_id: &ClientId,
+        prefix: &CommitmentPrefix,
+        proof: &CommitmentProofBytes,
+        root: &CommitmentRoot,
+        _proof_height: Height,
+        path: Path,
+    ) -> Result<(), ClientError>
+    where
+        Self: ClientStateCommon,
+    {
+        self.verify_non_membership(prefix, proof, root, path)
+    }
 }
 
 /// `ClientState` methods which require access to the client's
@@ -179,6 +237,24 @@ where
         upgraded_client_state: Any,
         upgraded_consensus_state: Any,
     ) -> Result<Height, ClientError>;
+
+    /// Checks that the substitute client state is a valid replacement for this
+    /// (subject) client, then overwrites the subject client with the
+    /// substitute's latest client and consensus states.
+    ///
+    /// Clients are expected to only accept a substitute whose parameters, other
+    /// than its latest height and the fields bound to it, are identical to
+    /// those of the subject. The substitute's consensus state can be read
+    /// from `ctx` under `substitute_client_id`.
+    fn check_substitute_and_update_state(
+        &self,
+        ctx: &mut E,
+        subject_client_id: &ClientId,
+        substitute_client_id: &ClientId,
+        substitute_client_state: Self,
+    ) -> Result<(), ClientError>
+    where
+        Self: Sized;
 }
 
 use crate::context::{ClientExecutionContext, ClientValidationContext};
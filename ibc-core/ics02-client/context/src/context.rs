@@ -2,7 +2,7 @@ use ibc_core_client_types::Height;
 use ibc_core_handler_types::error::ContextError;
 use ibc_core_host_types::identifiers::ClientId;
 use ibc_core_host_types::path::{ClientConsensusStatePath, ClientStatePath};
-use ibc_primitives::Timestamp;
+use ibc_primitives::{Signer, Timestamp};
 
 use super::client_state::ClientState;
 use super::consensus_state::ConsensusState;
@@ -25,6 +25,15 @@ pub trait ClientValidationContext {
         client_id: &ClientId,
         height: &Height,
     ) -> Result<Height, ContextError>;
+
+    /// Returns `true` if `signer` is the governance authority of the host,
+    /// which alone may perform privileged client operations, such as
+    /// recovering a client or updating the client parameters.
+    ///
+    /// By default, the host has no such authority.
+    fn is_client_authority(&self, _signer: &Signer) -> bool {
+        false
+    }
 }
 
 /// Defines the methods that all client `ExecutionContext`s (precisely the
@@ -25,10 +25,15 @@ where
     let id_counter = ctx.client_counter()?;
 
     let client_state = ctx.decode_client_state(client_state)?;
+    let client_type = client_state.client_type();
+
+    if !ctx.allowed_clients()?.is_allowed(&client_type) {
+        return Err(ClientError::ClientTypeNotAllowed { client_type }.into());
+    }
 
     client_state.verify_consensus_state(consensus_state)?;
 
-    let client_id = client_state.client_type().build_client_id(id_counter);
+    let client_id = client_type.build_client_id(id_counter);
 
     if ctx.client_state(&client_id).is_ok() {
         return Err(ClientError::ClientStateAlreadyExists { client_id }.into());
@@ -1,5 +1,7 @@
 //! This module implements the processing logic for ICS2 (client abstractions and functions) msgs.
 
 pub mod create_client;
+pub mod recover_client;
 pub mod update_client;
+pub mod update_params;
 pub mod upgrade_client;
@@ -0,0 +1,102 @@
+//! Protocol logic specific to processing ICS2 messages of type `MsgRecoverClient`.
+
+use ibc_core_client_context::client_state::{ClientStateCommon, ClientStateExecution};
+use ibc_core_client_context::ClientValidationContext;
+use ibc_core_client_types::error::ClientError;
+use ibc_core_client_types::events::RecoverClient;
+use ibc_core_client_types::msgs::MsgRecoverClient;
+use ibc_core_handler_types::error::ContextError;
+use ibc_core_handler_types::events::{IbcEvent, MessageEvent};
+use ibc_core_host::{ExecutionContext, ValidationContext};
+use ibc_primitives::prelude::*;
+
+/// Validates a `MsgRecoverClient`, which only the client authority of the host
+/// can submit.
+pub fn validate<Ctx>(ctx: &Ctx, msg: MsgRecoverClient) -> Result<(), ContextError>
+where
+    Ctx: ValidationContext,
+{
+    let MsgRecoverClient {
+        subject_client_id,
+        substitute_client_id,
+        signer,
+    } = msg;
+
+    ctx.validate_message_signer(&signer)?;
+
+    if !ctx
+        .get_client_validation_context()
+        .is_client_authority(&signer)
+    {
+        return Err(ClientError::UnauthorizedSigner { signer }.into());
+    }
+
+    let subject_client_state = ctx.client_state(&subject_client_id)?;
+
+    if ctx
+        .client_status(&subject_client_state, &subject_client_id)?
+        .is_active()
+    {
+        return Err(ClientError::RecoverActiveClient {
+            client_id: subject_client_id,
+        }
+        .into());
+    }
+
+    let substitute_client_state = ctx.client_state(&substitute_client_id)?;
+
+    ctx.client_status(&substitute_client_state, &substitute_client_id)?
+        .verify_is_active()?;
+
+    let subject_height = subject_client_state.latest_height();
+    let substitute_height = substitute_client_state.latest_height();
+
+    if subject_height >= substitute_height {
+        return Err(ClientError::InvalidSubstituteHeight {
+            subject_height,
+            substitute_height,
+        }
+        .into());
+    }
+
+    if subject_client_state.client_type() != substitute_client_state.client_type() {
+        return Err(ClientError::ClientArgsTypeMismatch {
+            client_type: substitute_client_state.client_type(),
+        }
+        .into());
+    }
+
+    Ok(())
+}
+
+/// Executes a `MsgRecoverClient`, replacing the state of the subject client
+/// with the state of the substitute client.
+pub fn execute<Ctx>(ctx: &mut Ctx, msg: MsgRecoverClient) -> Result<(), ContextError>
+where
+    Ctx: ExecutionContext,
+{
+    let MsgRecoverClient {
+        subject_client_id,
+        substitute_client_id,
+        ..
+    } = msg;
+
+    let subject_client_state = ctx.client_state(&subject_client_id)?;
+    let substitute_client_state = ctx.client_state(&substitute_client_id)?;
+
+    subject_client_state.check_substitute_and_update_state(
+        ctx.get_client_execution_context(),
+        &subject_client_id,
+        &substitute_client_id,
+        substitute_client_state,
+    )?;
+
+    let event = IbcEvent::RecoverClient(RecoverClient::new(
+        subject_client_id,
+        subject_client_state.client_type(),
+    ));
+    ctx.emit_ibc_event(IbcEvent::Message(MessageEvent::Client))?;
+    ctx.emit_ibc_event(event)?;
+
+    Ok(())
+}
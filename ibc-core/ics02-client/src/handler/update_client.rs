@@ -28,8 +28,7 @@ where
     // Read client state from the host chain store. The client should already exist.
     let client_state = ctx.client_state(&client_id)?;
 
-    client_state
-        .status(ctx.get_client_validation_context(), &client_id)?
+    ctx.client_status(&client_state, &client_id)?
         .verify_is_active()?;
 
     let client_message = msg.client_message();
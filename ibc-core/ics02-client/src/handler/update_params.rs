@@ -0,0 +1,47 @@
+//! Protocol logic specific to processing ICS2 messages of type `MsgUpdateParams`.
+
+use ibc_core_client_context::ClientValidationContext;
+use ibc_core_client_types::error::ClientError;
+use ibc_core_client_types::events::UpdateClientParams;
+use ibc_core_client_types::msgs::MsgUpdateParams;
+use ibc_core_handler_types::error::ContextError;
+use ibc_core_handler_types::events::{IbcEvent, MessageEvent};
+use ibc_core_host::{ExecutionContext, ValidationContext};
+use ibc_primitives::prelude::*;
+
+/// Validates a `MsgUpdateParams`, which only the client authority of the host
+/// can submit.
+pub fn validate<Ctx>(ctx: &Ctx, msg: MsgUpdateParams) -> Result<(), ContextError>
+where
+    Ctx: ValidationContext,
+{
+    ctx.validate_message_signer(&msg.signer)?;
+
+    if !ctx
+        .get_client_validation_context()
+        .is_client_authority(&msg.signer)
+    {
+        return Err(ClientError::UnauthorizedSigner { signer: msg.signer }.into());
+    }
+
+    Ok(())
+}
+
+/// Executes a `MsgUpdateParams`, replacing the client types allowed on the
+/// host.
+pub fn execute<Ctx>(ctx: &mut Ctx, msg: MsgUpdateParams) -> Result<(), ContextError>
+where
+    Ctx: ExecutionContext,
+{
+    let allowed_clients = msg.params.allowed_clients;
+
+    let event =
+        IbcEvent::UpdateClientParams(UpdateClientParams::new(allowed_clients.as_slice().to_vec()));
+
+    ctx.store_allowed_clients(allowed_clients)?;
+
+    ctx.emit_ibc_event(IbcEvent::Message(MessageEvent::Client))?;
+    ctx.emit_ibc_event(event)?;
+
+    Ok(())
+}
@@ -1,8 +1,6 @@
 //! Protocol logic specific to processing ICS2 messages of type `MsgUpgradeAnyClient`.
 //!
-use ibc_core_client_context::client_state::{
-    ClientStateCommon, ClientStateExecution, ClientStateValidation,
-};
+use ibc_core_client_context::client_state::{ClientStateCommon, ClientStateExecution};
 use ibc_core_client_context::consensus_state::ConsensusState;
 use ibc_core_client_types::error::ClientError;
 use ibc_core_client_types::events::UpgradeClient;
@@ -27,8 +25,7 @@ where
     let old_client_state = ctx.client_state(&client_id)?;
 
     // Check if the client is active.
-    old_client_state
-        .status(ctx.get_client_validation_context(), &client_id)?
+    ctx.client_status(&old_client_state, &client_id)?
         .verify_is_active()?;
 
     // Read the latest consensus state from the host chain store.
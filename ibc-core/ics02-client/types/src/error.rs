@@ -1,12 +1,14 @@
 //! Defines the client error type
 
+use core::convert::Infallible;
+
 use displaydoc::Display;
 // use ibc::core::ContextError;
 use ibc_core_commitment_types::error::CommitmentError;
 use ibc_core_host_types::error::IdentifierError;
 use ibc_core_host_types::identifiers::{ClientId, ClientType};
 use ibc_primitives::prelude::*;
-use ibc_primitives::Timestamp;
+use ibc_primitives::{Signer, Timestamp};
 
 use super::status::Status;
 use crate::height::Height;
@@ -50,6 +52,8 @@ pub enum ClientError {
     MissingRawClientState,
     /// missing raw client consensus state
     MissingRawConsensusState,
+    /// missing raw client params
+    MissingRawParams,
     /// invalid client id in the update client message: `{0}`
     InvalidMsgUpdateClientId(IdentifierError),
     /// invalid client identifier error: `{0}`
@@ -77,6 +81,19 @@ pub enum ClientError {
     InvalidPacketTimestamp(ibc_primitives::ParseTimestampError),
     /// mismatch between client and arguments types
     ClientArgsTypeMismatch { client_type: ClientType },
+    /// client type `{client_type}` is not allowed by the host
+    ClientTypeNotAllowed { client_type: ClientType },
+    /// invalid allowed clients: `{description}`
+    InvalidAllowedClients { description: String },
+    /// cannot recover client `{client_id}` as it is still active
+    RecoverActiveClient { client_id: ClientId },
+    /// substitute client latest height `{substitute_height}` must be greater than subject client latest height `{subject_height}`
+    InvalidSubstituteHeight {
+        subject_height: Height,
+        substitute_height: Height,
+    },
+    /// substitute client state does not match the subject client state: `{description}`
+    SubstituteMismatch { description: String },
     /// received header height (`{header_height}`) is lower than (or equal to) client latest height (`{latest_height}`)
     LowHeaderHeight {
         header_height: Height,
@@ -88,6 +105,8 @@ pub enum ClientError {
     MissingLocalConsensusState { height: Height },
     /// invalid signer error: `{reason}`
     InvalidSigner { reason: String },
+    /// signer `{signer}` is not the client authority
+    UnauthorizedSigner { signer: Signer },
     /// ics23 verification failure error: `{0}`
     Ics23Verification(CommitmentError),
     /// misbehaviour handling failed with reason: `{reason}`
@@ -108,6 +127,12 @@ impl From<&'static str> for ClientError {
     }
 }
 
+impl From<Infallible> for ClientError {
+    fn from(e: Infallible) -> Self {
+        match e {}
+    }
+}
+
 #[cfg(feature = "std")]
 impl std::error::Error for ClientError {
     fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
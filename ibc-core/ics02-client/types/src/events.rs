@@ -12,10 +12,15 @@ pub const CREATE_CLIENT_EVENT: &str = "create_client";
 pub const UPDATE_CLIENT_EVENT: &str = "update_client";
 pub const CLIENT_MISBEHAVIOUR_EVENT: &str = "client_misbehaviour";
 pub const UPGRADE_CLIENT_EVENT: &str = "upgrade_client";
+pub const RECOVER_CLIENT_EVENT: &str = "recover_client";
+pub const UPDATE_CLIENT_PARAMS_EVENT: &str = "update_client_params";
 
 /// The content of the `key` field for the attribute containing the client identifier.
 pub const CLIENT_ID_ATTRIBUTE_KEY: &str = "client_id";
 
+/// The content of the `key` field for the attribute containing the identifier of the recovered client.
+pub const SUBJECT_CLIENT_ID_ATTRIBUTE_KEY: &str = "subject_client_id";
+
 /// The content of the `key` field for the attribute containing the client type.
 pub const CLIENT_TYPE_ATTRIBUTE_KEY: &str = "client_type";
 
@@ -28,6 +33,9 @@ pub const CONSENSUS_HEIGHTS_ATTRIBUTE_KEY: &str = "consensus_heights";
 /// The content of the `key` field for the header in update client event.
 pub const HEADER_ATTRIBUTE_KEY: &str = "header";
 
+/// The content of the `key` field for the attribute containing the allowed client types.
+pub const ALLOWED_CLIENTS_ATTRIBUTE_KEY: &str = "allowed_clients";
+
 #[cfg_attr(
     feature = "parity-scale-codec",
     derive(
@@ -52,6 +60,34 @@ impl From<ClientIdAttribute> for abci::EventAttribute {
     }
 }
 
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, From, PartialEq, Eq)]
+struct SubjectClientIdAttribute {
+    subject_client_id: ClientId,
+}
+
+impl From<SubjectClientIdAttribute> for abci::EventAttribute {
+    fn from(attr: SubjectClientIdAttribute) -> Self {
+        (
+            SUBJECT_CLIENT_ID_ATTRIBUTE_KEY,
+            attr.subject_client_id.as_str(),
+        )
+            .into()
+    }
+}
+
 #[cfg_attr(
     feature = "parity-scale-codec",
     derive(
@@ -160,6 +196,34 @@ impl From<HeaderAttribute> for abci::EventAttribute {
     }
 }
 
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, From, PartialEq, Eq)]
+struct AllowedClientsAttribute {
+    allowed_clients: Vec<String>,
+}
+
+impl From<AllowedClientsAttribute> for abci::EventAttribute {
+    fn from(attr: AllowedClientsAttribute) -> Self {
+        (
+            ALLOWED_CLIENTS_ATTRIBUTE_KEY,
+            attr.allowed_clients.join(","),
+        )
+            .into()
+    }
+}
+
 /// CreateClient event signals the creation of a new on-chain client (IBC client).
 #[cfg_attr(
     feature = "parity-scale-codec",
@@ -416,3 +480,98 @@ impl From<UpgradeClient> for abci::Event {
         }
     }
 }
+
+/// Signals the recovery of a frozen or expired on-chain client (IBC Client)
+/// with the state of a substitute client.
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RecoverClient {
+    subject_client_id: SubjectClientIdAttribute,
+    client_type: ClientTypeAttribute,
+}
+
+impl RecoverClient {
+    pub fn new(subject_client_id: ClientId, client_type: ClientType) -> Self {
+        Self {
+            subject_client_id: SubjectClientIdAttribute::from(subject_client_id),
+            client_type: ClientTypeAttribute::from(client_type),
+        }
+    }
+
+    pub fn subject_client_id(&self) -> &ClientId {
+        &self.subject_client_id.subject_client_id
+    }
+
+    pub fn client_type(&self) -> &ClientType {
+        &self.client_type.client_type
+    }
+
+    pub fn event_type(&self) -> &str {
+        RECOVER_CLIENT_EVENT
+    }
+}
+
+impl From<RecoverClient> for abci::Event {
+    fn from(r: RecoverClient) -> Self {
+        Self {
+            kind: RECOVER_CLIENT_EVENT.to_owned(),
+            attributes: vec![r.subject_client_id.into(), r.client_type.into()],
+        }
+    }
+}
+
+/// Signals an update of the client parameters of the host.
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UpdateClientParams {
+    allowed_clients: AllowedClientsAttribute,
+}
+
+impl UpdateClientParams {
+    pub fn new(allowed_clients: Vec<String>) -> Self {
+        Self {
+            allowed_clients: AllowedClientsAttribute::from(allowed_clients),
+        }
+    }
+
+    pub fn allowed_clients(&self) -> &[String] {
+        &self.allowed_clients.allowed_clients
+    }
+
+    pub fn event_type(&self) -> &str {
+        UPDATE_CLIENT_PARAMS_EVENT
+    }
+}
+
+impl From<UpdateClientParams> for abci::Event {
+    fn from(u: UpdateClientParams) -> Self {
+        Self {
+            kind: UPDATE_CLIENT_PARAMS_EVENT.to_owned(),
+            attributes: vec![u.allowed_clients.into()],
+        }
+    }
+}
@@ -18,9 +18,11 @@ pub mod error;
 pub mod events;
 mod height;
 pub mod msgs;
+mod params;
 mod status;
 
 pub use height::*;
+pub use params::*;
 pub use status::*;
 
 /// Re-exports ICS-02 proto types from the `ibc-proto` crate for added convenience.
@@ -6,12 +6,16 @@ use ibc_proto::google::protobuf::Any;
 
 mod create_client;
 mod misbehaviour;
+mod recover_client;
 mod update_client;
+mod update_params;
 mod upgrade_client;
 
 pub use create_client::*;
 pub use misbehaviour::*;
+pub use recover_client::*;
 pub use update_client::*;
+pub use update_params::*;
 pub use upgrade_client::*;
 
 /// Encodes all the different client messages
@@ -27,6 +31,8 @@ pub enum ClientMsg {
     UpdateClient(MsgUpdateClient),
     Misbehaviour(MsgSubmitMisbehaviour),
     UpgradeClient(MsgUpgradeClient),
+    RecoverClient(MsgRecoverClient),
+    UpdateParams(MsgUpdateParams),
 }
 
 pub enum MsgUpdateOrMisbehaviour {
@@ -0,0 +1,51 @@
+//! Definition of domain type message `MsgUpdateParams`.
+
+use ibc_primitives::prelude::*;
+use ibc_primitives::Signer;
+use ibc_proto::ibc::core::client::v1::MsgUpdateParams as RawMsgUpdateParams;
+use ibc_proto::Protobuf;
+
+use crate::error::ClientError;
+use crate::params::Params;
+
+pub const UPDATE_PARAMS_TYPE_URL: &str = "/ibc.core.client.v1.MsgUpdateParams";
+
+/// A type of message that replaces the parameters of the client submodule,
+/// such as the list of allowed client types.
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MsgUpdateParams {
+    /// signer address, which must be the host's governance authority
+    pub signer: Signer,
+    /// the new parameters
+    pub params: Params,
+}
+
+impl Protobuf<RawMsgUpdateParams> for MsgUpdateParams {}
+
+impl TryFrom<RawMsgUpdateParams> for MsgUpdateParams {
+    type Error = ClientError;
+
+    fn try_from(raw: RawMsgUpdateParams) -> Result<Self, Self::Error> {
+        Ok(MsgUpdateParams {
+            signer: raw.signer.into(),
+            params: raw
+                .params
+                .ok_or(ClientError::MissingRawParams)?
+                .try_into()?,
+        })
+    }
+}
+
+impl From<MsgUpdateParams> for RawMsgUpdateParams {
+    fn from(ics_msg: MsgUpdateParams) -> Self {
+        RawMsgUpdateParams {
+            signer: ics_msg.signer.to_string(),
+            params: Some(ics_msg.params.into()),
+        }
+    }
+}
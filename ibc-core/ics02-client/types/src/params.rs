@@ -0,0 +1,107 @@
+//! Defines the parameters of the client submodule.
+
+use ibc_core_host_types::identifiers::ClientType;
+use ibc_primitives::prelude::*;
+use ibc_proto::ibc::core::client::v1::Params as RawParams;
+use ibc_proto::Protobuf;
+
+use crate::error::ClientError;
+
+/// Wildcard entry of the allowed clients, which allows every client type.
+pub const ALLOW_ALL_CLIENTS: &str = "*";
+
+/// The list of client types which can be created and interacted with on the
+/// host.
+///
+/// A client whose type is removed from the list keeps its state, but cannot
+/// be used until its type is added again.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AllowedClients(Vec<String>);
+
+impl AllowedClients {
+    /// Builds the list of allowed clients, which must not contain blank or
+    /// duplicate entries.
+    pub fn new(allowed_clients: Vec<String>) -> Result<Self, ClientError> {
+        for (i, client_type) in allowed_clients.iter().enumerate() {
+            if client_type.trim().is_empty() {
+                return Err(ClientError::InvalidAllowedClients {
+                    description: format!("client type {i} cannot be blank"),
+                });
+            }
+            if allowed_clients[..i].contains(client_type) {
+                return Err(ClientError::InvalidAllowedClients {
+                    description: format!("duplicate client type `{client_type}`"),
+                });
+            }
+        }
+
+        Ok(Self(allowed_clients))
+    }
+
+    /// Allows every client type.
+    pub fn allow_all() -> Self {
+        Self(vec![ALLOW_ALL_CLIENTS.to_string()])
+    }
+
+    /// Returns whether the given client type is allowed.
+    pub fn is_allowed(&self, client_type: &ClientType) -> bool {
+        self.0
+            .iter()
+            .any(|allowed| allowed == ALLOW_ALL_CLIENTS || allowed == client_type.as_str())
+    }
+
+    pub fn as_slice(&self) -> &[String] {
+        &self.0
+    }
+}
+
+impl Default for AllowedClients {
+    fn default() -> Self {
+        Self::allow_all()
+    }
+}
+
+/// The parameters of the client submodule, which governance can change with
+/// a `MsgUpdateParams`.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Params {
+    pub allowed_clients: AllowedClients,
+}
+
+impl Params {
+    pub fn new(allowed_clients: AllowedClients) -> Self {
+        Self { allowed_clients }
+    }
+}
+
+impl Protobuf<RawParams> for Params {}
+
+impl TryFrom<RawParams> for Params {
+    type Error = ClientError;
+
+    fn try_from(raw: RawParams) -> Result<Self, Self::Error> {
+        Ok(Self {
+            allowed_clients: AllowedClients::new(raw.allowed_clients)?,
+        })
+    }
+}
+
+impl From<Params> for RawParams {
+    fn from(params: Params) -> Self {
+        RawParams {
+            allowed_clients: params.allowed_clients.0,
+        }
+    }
+}
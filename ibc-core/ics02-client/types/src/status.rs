@@ -1,6 +1,10 @@
 use core::fmt::{Debug, Display, Formatter};
+use core::time::Duration;
+
+use ibc_primitives::Timestamp;
 
 use crate::error::ClientError;
+use crate::height::Height;
 
 /// `UpdateKind` represents the 2 ways that a client can be updated
 /// in IBC: either through a `MsgUpdateClient`, or a `MsgSubmitMisbehaviour`.
@@ -16,7 +20,7 @@ pub enum UpdateKind {
 }
 
 /// Represents the status of a client
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum Status {
     /// The client is active and allowed to be used
     Active,
@@ -55,3 +59,40 @@ impl Display for Status {
         write!(f, "{self:?}")
     }
 }
+
+/// Details the status of a client: why it is not active, or when it expires.
+///
+/// Clients only fill in the details that apply to them, the others being left
+/// to `None`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StatusDetails {
+    /// The status of the client
+    pub status: Status,
+    /// The timestamp of the consensus state at the latest height of the client
+    pub latest_consensus_timestamp: Option<Timestamp>,
+    /// The time from which the client is expired, e.g. the end of the
+    /// trusting period of its latest consensus state
+    pub expiry_deadline: Option<Timestamp>,
+    /// The time remaining until the client expires, according to the host
+    pub time_until_expiry: Option<Duration>,
+    /// The height at which the client was frozen
+    pub frozen_height: Option<Height>,
+    /// Whether the client can be updated once expired
+    pub allow_update_after_expiry: bool,
+    /// Whether the client can be updated once frozen by misbehaviour
+    pub allow_update_after_misbehaviour: bool,
+}
+
+impl From<Status> for StatusDetails {
+    fn from(status: Status) -> Self {
+        Self {
+            status,
+            latest_consensus_timestamp: None,
+            expiry_deadline: None,
+            time_until_expiry: None,
+            frozen_height: None,
+            allow_update_after_expiry: false,
+            allow_update_after_misbehaviour: false,
+        }
+    }
+}
@@ -33,6 +33,10 @@ where
 {
     ctx_a.validate_message_signer(&msg.signer)?;
 
+    if msg.conn_id_on_a.is_localhost() {
+        return Err(ConnectionError::LocalhostHandshakeNotAllowed.into());
+    }
+
     let host_height = ctx_a.host_height().map_err(|_| ConnectionError::Other {
         description: "failed to get host height".to_string(),
     })?;
@@ -55,8 +59,8 @@ where
     {
         let client_state_of_b_on_a = ctx_a.client_state(vars.client_id_on_a())?;
 
-        client_state_of_b_on_a
-            .status(ctx_a.get_client_validation_context(), vars.client_id_on_a())?
+        ctx_a
+            .client_status(&client_state_of_b_on_a, vars.client_id_on_a())?
             .verify_is_active()?;
         client_state_of_b_on_a.validate_proof_height(msg.proofs_height_on_b)?;
 
@@ -85,10 +89,13 @@ where
             )?;
 
             client_state_of_b_on_a
-                .verify_membership(
+                .verify_membership_with_context(
+                    ctx_a.get_client_validation_context(),
+                    vars.client_id_on_a(),
                     prefix_on_b,
                     &msg.proof_conn_end_on_b,
                     consensus_state_of_b_on_a.root(),
+                    msg.proofs_height_on_b,
                     Path::Connection(ConnectionPath::new(&msg.conn_id_on_b)),
                     expected_conn_end_on_b.encode_vec(),
                 )
@@ -96,10 +103,13 @@ where
         }
 
         client_state_of_b_on_a
-            .verify_membership(
+            .verify_membership_with_context(
+                ctx_a.get_client_validation_context(),
+                vars.client_id_on_a(),
                 prefix_on_b,
                 &msg.proof_client_state_of_a_on_b,
                 consensus_state_of_b_on_a.root(),
+                msg.proofs_height_on_b,
                 Path::ClientState(ClientStatePath::new(vars.client_id_on_b())),
                 msg.client_state_of_a_on_b.to_vec(),
             )
@@ -118,10 +128,13 @@ where
         );
 
         client_state_of_b_on_a
-            .verify_membership(
+            .verify_membership_with_context(
+                ctx_a.get_client_validation_context(),
+                vars.client_id_on_a(),
                 prefix_on_b,
                 &msg.proof_consensus_state_of_a_on_b,
                 consensus_state_of_b_on_a.root(),
+                msg.proofs_height_on_b,
                 Path::ClientConsensusState(client_cons_state_path_on_b),
                 expected_consensus_state_of_a_on_b.encode_vec(),
             )
@@ -32,6 +32,10 @@ where
 {
     ctx_b.validate_message_signer(&msg.signer)?;
 
+    if msg.conn_id_on_b.is_localhost() {
+        return Err(ConnectionError::LocalhostHandshakeNotAllowed.into());
+    }
+
     let conn_end_on_b = vars.conn_end_on_b();
 
     conn_end_on_b.verify_state_matches(&State::TryOpen)?;
@@ -44,8 +48,8 @@ where
     {
         let client_state_of_a_on_b = ctx_b.client_state(client_id_on_b)?;
 
-        client_state_of_a_on_b
-            .status(ctx_b.get_client_validation_context(), client_id_on_b)?
+        ctx_b
+            .client_status(&client_state_of_a_on_b, client_id_on_b)?
             .verify_is_active()?;
         client_state_of_a_on_b.validate_proof_height(msg.proof_height_on_a)?;
 
@@ -72,10 +76,13 @@ where
         )?;
 
         client_state_of_a_on_b
-            .verify_membership(
+            .verify_membership_with_context(
+                ctx_b.get_client_validation_context(),
+                client_id_on_b,
                 prefix_on_a,
                 &msg.proof_conn_end_on_a,
                 consensus_state_of_a_on_b.root(),
+                msg.proof_height_on_a,
                 Path::Connection(ConnectionPath::new(conn_id_on_a)),
                 expected_conn_end_on_a.encode_vec(),
             )
@@ -1,5 +1,5 @@
 //! Protocol logic specific to ICS3 messages of type `MsgConnectionOpenInit`.
-use ibc_core_client::context::client_state::ClientStateValidation;
+use ibc_core_connection_types::error::ConnectionError;
 use ibc_core_connection_types::events::OpenInit;
 use ibc_core_connection_types::msgs::MsgConnectionOpenInit;
 use ibc_core_connection_types::{ConnectionEnd, Counterparty, State};
@@ -16,11 +16,15 @@ where
 {
     ctx_a.validate_message_signer(&msg.signer)?;
 
+    if msg.client_id_on_a.is_localhost() {
+        return Err(ConnectionError::LocalhostHandshakeNotAllowed.into());
+    }
+
     // An IBC client running on the local (host) chain should exist.
     let client_state_of_b_on_a = ctx_a.client_state(&msg.client_id_on_a)?;
 
-    client_state_of_b_on_a
-        .status(ctx_a.get_client_validation_context(), &msg.client_id_on_a)?
+    ctx_a
+        .client_status(&client_state_of_b_on_a, &msg.client_id_on_a)?
         .verify_is_active()?;
 
     if let Some(version) = msg.version {
@@ -34,6 +34,10 @@ where
 {
     ctx_b.validate_message_signer(&msg.signer)?;
 
+    if msg.client_id_on_b.is_localhost() {
+        return Err(ConnectionError::LocalhostHandshakeNotAllowed.into());
+    }
+
     ctx_b.validate_self_client(msg.client_state_of_b_on_a.clone())?;
 
     let host_height = ctx_b.host_height().map_err(|_| ConnectionError::Other {
@@ -54,8 +58,8 @@ where
     {
         let client_state_of_a_on_b = ctx_b.client_state(vars.conn_end_on_b.client_id())?;
 
-        client_state_of_a_on_b
-            .status(ctx_b.get_client_validation_context(), &msg.client_id_on_b)?
+        ctx_b
+            .client_status(&client_state_of_a_on_b, &msg.client_id_on_b)?
             .verify_is_active()?;
         client_state_of_a_on_b.validate_proof_height(msg.proofs_height_on_a)?;
 
@@ -80,10 +84,13 @@ where
             )?;
 
             client_state_of_a_on_b
-                .verify_membership(
+                .verify_membership_with_context(
+                    ctx_b.get_client_validation_context(),
+                    &msg.client_id_on_b,
                     prefix_on_a,
                     &msg.proof_conn_end_on_a,
                     consensus_state_of_a_on_b.root(),
+                    msg.proofs_height_on_a,
                     Path::Connection(ConnectionPath::new(&vars.conn_id_on_a)),
                     expected_conn_end_on_a.encode_vec(),
                 )
@@ -91,10 +98,13 @@ where
         }
 
         client_state_of_a_on_b
-            .verify_membership(
+            .verify_membership_with_context(
+                ctx_b.get_client_validation_context(),
+                &msg.client_id_on_b,
                 prefix_on_a,
                 &msg.proof_client_state_of_b_on_a,
                 consensus_state_of_a_on_b.root(),
+                msg.proofs_height_on_a,
                 Path::ClientState(ClientStatePath::new(client_id_on_a)),
                 msg.client_state_of_b_on_a.to_vec(),
             )
@@ -113,10 +123,13 @@ where
         );
 
         client_state_of_a_on_b
-            .verify_membership(
+            .verify_membership_with_context(
+                ctx_b.get_client_validation_context(),
+                &msg.client_id_on_b,
                 prefix_on_a,
                 &msg.proof_consensus_state_of_b_on_a,
                 consensus_state_of_a_on_b.root(),
+                msg.proofs_height_on_a,
                 Path::ClientConsensusState(client_cons_state_path_on_a),
                 expected_consensus_state_of_b_on_a.encode_vec(),
             )
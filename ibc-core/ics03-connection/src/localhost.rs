@@ -0,0 +1,51 @@
+//! Defines the sentinel connection of the localhost client.
+//!
+//! The localhost client connects a host to itself, over a single connection
+//! which never goes through the connection handshake: the sentinel connection,
+//! identified by [`ConnectionId::localhost`], is created in the `Open` state
+//! alongside the localhost client, and handshake messages targeting either of
+//! them are rejected.
+use ibc_core_connection_types::{ConnectionEnd, Counterparty, State};
+use ibc_core_handler_types::error::ContextError;
+use ibc_core_host::types::identifiers::{ClientId, ConnectionId};
+use ibc_core_host::types::path::{ClientConnectionPath, ConnectionPath};
+use ibc_core_host::{ExecutionContext, ValidationContext};
+use ibc_primitives::prelude::*;
+
+/// Returns the end of the sentinel localhost connection of the host, whose
+/// counterparty is the host itself.
+pub fn localhost_connection_end<Ctx>(ctx: &Ctx) -> Result<ConnectionEnd, ContextError>
+where
+    Ctx: ValidationContext,
+{
+    Ok(ConnectionEnd::new(
+        State::Open,
+        ClientId::localhost(),
+        Counterparty::new(
+            ClientId::localhost(),
+            Some(ConnectionId::localhost()),
+            ctx.commitment_prefix(),
+        ),
+        ctx.get_compatible_versions(),
+        Default::default(),
+    )?)
+}
+
+/// Stores the sentinel localhost connection of the host.
+///
+/// Hosts supporting the localhost client must call this function once, for
+/// instance at genesis, after creating the localhost client.
+pub fn create_localhost_connection<Ctx>(ctx: &mut Ctx) -> Result<(), ContextError>
+where
+    Ctx: ExecutionContext,
+{
+    let conn_end = localhost_connection_end(ctx)?;
+
+    ctx.store_connection_to_client(
+        &ClientConnectionPath::new(&ClientId::localhost()),
+        ConnectionId::localhost(),
+    )?;
+    ctx.store_connection(&ConnectionPath::new(&ConnectionId::localhost()), conn_end)?;
+
+    Ok(())
+}
@@ -83,6 +83,8 @@ pub enum ConnectionError {
     TimestampOverflow(TimestampOverflowError),
     /// connection counter overflow error
     CounterOverflow,
+    /// connection handshakes are not allowed for the localhost client and its sentinel connection
+    LocalhostHandshakeNotAllowed,
     /// other error: `{description}`
     Other { description: String },
 }
@@ -51,6 +51,19 @@ impl Version {
         Ok(())
     }
 
+    /// Returns the default version, which additionally supports the
+    /// `ORDER_ORDERED_ALLOW_TIMEOUT` channel ordering.
+    ///
+    /// The ordering is opt-in: hosts enable it by returning this version
+    /// from `ValidationContext::get_compatible_versions`.
+    pub fn with_ordered_allow_timeout() -> Self {
+        let mut version = Self::default();
+        version
+            .features
+            .push("ORDER_ORDERED_ALLOW_TIMEOUT".to_string());
+        version
+    }
+
     /// Checks whether the given feature is supported in this version
     pub fn verify_feature_supported(&self, feature: String) -> Result<(), ConnectionError> {
         if !self.features.contains(&feature) {
@@ -349,6 +362,20 @@ mod tests {
                 picked: Ok(Version::default()),
                 want_pass: true,
             },
+            Test {
+                name: "Ordered allow timeout supported by one side only".to_string(),
+                supported: vec![Version::with_ordered_allow_timeout()],
+                counterparty: get_compatible_versions(),
+                picked: Ok(Version::default()),
+                want_pass: true,
+            },
+            Test {
+                name: "Ordered allow timeout supported by both sides".to_string(),
+                supported: vec![Version::with_ordered_allow_timeout()],
+                counterparty: vec![Version::with_ordered_allow_timeout()],
+                picked: Ok(Version::with_ordered_allow_timeout()),
+                want_pass: true,
+            },
             Test {
                 name: "Overlapping versions".to_string(),
                 supported: overlapping().0,
@@ -5,6 +5,8 @@ use ibc_core_channel_types::commitment::PacketCommitment;
 use ibc_core_client::context::client_state::ClientState;
 use ibc_core_client::context::consensus_state::ConsensusState;
 use ibc_core_client::context::{ClientExecutionContext, ClientValidationContext};
+use ibc_core_client::types::Status;
+use ibc_core_commitment_types::merkle::HostFunctionsProvider;
 use ibc_core_connection::types::ConnectionEnd;
 use ibc_core_handler_types::error::ContextError;
 use ibc_core_handler_types::events::IbcEvent;
@@ -21,6 +23,8 @@ pub trait SendPacketValidationContext {
     type E: ClientExecutionContext;
     type AnyConsensusState: ConsensusState;
     type AnyClientState: ClientState<Self::V, Self::E>;
+    /// The hashing primitives used to compute packet commitments.
+    type HostFunctions: HostFunctionsProvider;
 
     /// Retrieve the context that implements all clients' `ValidationContext`.
     fn get_client_validation_context(&self) -> &Self::V;
@@ -35,6 +39,14 @@ pub trait SendPacketValidationContext {
     /// proof verification.
     fn client_state(&self, client_id: &ClientId) -> Result<Self::AnyClientState, ContextError>;
 
+    /// Returns the status of the given client, taking the client types
+    /// allowed on the host into account.
+    fn client_status(
+        &self,
+        client_state: &Self::AnyClientState,
+        client_id: &ClientId,
+    ) -> Result<Status, ContextError>;
+
     fn client_consensus_state(
         &self,
         client_cons_state_path: &ClientConsensusStatePath,
@@ -52,6 +64,7 @@ where
     type E = T::E;
     type AnyConsensusState = T::AnyConsensusState;
     type AnyClientState = T::AnyClientState;
+    type HostFunctions = T::HostFunctions;
 
     fn get_client_validation_context(&self) -> &Self::V {
         self.get_client_validation_context()
@@ -69,6 +82,14 @@ where
         self.client_state(client_id)
     }
 
+    fn client_status(
+        &self,
+        client_state: &T::AnyClientState,
+        client_id: &ClientId,
+    ) -> Result<Status, ContextError> {
+        ValidationContext::client_status(self, client_state, client_id)
+    }
+
     fn client_consensus_state(
         &self,
         client_cons_state_path: &ClientConsensusStatePath,
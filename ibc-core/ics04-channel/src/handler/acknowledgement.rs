@@ -16,6 +16,8 @@ use ibc_core_host::{ExecutionContext, ValidationContext};
 use ibc_core_router::module::Module;
 use ibc_primitives::prelude::*;
 
+use super::upgrade::handle_flush_state;
+
 pub fn acknowledgement_packet_validate<ValCtx>(
     ctx_a: &ValCtx,
     module: &dyn Module,
@@ -82,13 +84,20 @@ where
         };
         ctx_a.delete_packet_commitment(&commitment_path_on_a)?;
 
-        if let Order::Ordered = chan_end_on_a.ordering {
+        if matches!(
+            chan_end_on_a.ordering,
+            Order::Ordered | Order::OrderedAllowTimeout
+        ) {
             // Note: in validation, we verified that `msg.packet.sequence == nextSeqRecv`
             // (where `nextSeqRecv` is the value in the store)
             let seq_ack_path_on_a =
                 SeqAckPath::new(&msg.packet.port_id_on_a, &msg.packet.chan_id_on_a);
             ctx_a.store_next_sequence_ack(&seq_ack_path_on_a, msg.packet.seq_on_a.increment())?;
         }
+
+        if chan_end_on_a.state() == &ChannelState::Flushing {
+            handle_flush_state(ctx_a, &msg.packet.port_id_on_a, &msg.packet.chan_id_on_a)?;
+        }
     }
 
     // emit events and logs
@@ -119,7 +128,8 @@ where
     let chan_end_path_on_a = ChannelEndPath::new(&packet.port_id_on_a, &packet.chan_id_on_a);
     let chan_end_on_a = ctx_a.channel_end(&chan_end_path_on_a)?;
 
-    chan_end_on_a.verify_state_matches(&ChannelState::Open)?;
+    // In-flight packets are still acknowledged while the channel is flushing.
+    chan_end_on_a.verify_state_matches_any(&[ChannelState::Open, ChannelState::Flushing])?;
 
     let counterparty = Counterparty::new(
         packet.port_id_on_b.clone(),
@@ -148,7 +158,7 @@ where
     };
 
     if commitment_on_a
-        != compute_packet_commitment(
+        != compute_packet_commitment::<Ctx::HostFunctions>(
             &packet.data,
             &packet.timeout_height_on_b,
             &packet.timeout_timestamp_on_b,
@@ -160,7 +170,10 @@ where
         .into());
     }
 
-    if let Order::Ordered = chan_end_on_a.ordering {
+    if matches!(
+        chan_end_on_a.ordering,
+        Order::Ordered | Order::OrderedAllowTimeout
+    ) {
         let seq_ack_path_on_a = SeqAckPath::new(&packet.port_id_on_a, &packet.chan_id_on_a);
         let next_seq_ack = ctx_a.get_next_sequence_ack(&seq_ack_path_on_a)?;
         if packet.seq_on_a != next_seq_ack {
@@ -177,8 +190,8 @@ where
         let client_id_on_a = conn_end_on_a.client_id();
         let client_state_of_b_on_a = ctx_a.client_state(client_id_on_a)?;
 
-        client_state_of_b_on_a
-            .status(ctx_a.get_client_validation_context(), client_id_on_a)?
+        ctx_a
+            .client_status(&client_state_of_b_on_a, client_id_on_a)?
             .verify_is_active()?;
         client_state_of_b_on_a.validate_proof_height(msg.proof_height_on_b)?;
 
@@ -188,7 +201,7 @@ where
             msg.proof_height_on_b.revision_height(),
         );
         let consensus_state_of_b_on_a = ctx_a.consensus_state(&client_cons_state_path_on_a)?;
-        let ack_commitment = compute_ack_commitment(&msg.acknowledgement);
+        let ack_commitment = compute_ack_commitment::<Ctx::HostFunctions>(&msg.acknowledgement);
         let ack_path_on_b =
             AckPath::new(&packet.port_id_on_b, &packet.chan_id_on_b, packet.seq_on_a);
 
@@ -196,10 +209,13 @@ where
 
         // Verify the proof for the packet against the chain store.
         client_state_of_b_on_a
-            .verify_membership(
+            .verify_membership_with_context(
+                ctx_a.get_client_validation_context(),
+                client_id_on_a,
                 conn_end_on_a.counterparty().prefix(),
                 &msg.proof_acked_on_b,
                 consensus_state_of_b_on_a.root(),
+                msg.proof_height_on_b,
                 Path::Ack(ack_path_on_b),
                 ack_commitment.into_vec(),
             )
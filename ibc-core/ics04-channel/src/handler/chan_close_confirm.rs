@@ -114,8 +114,8 @@ where
         let client_id_on_b = conn_end_on_b.client_id();
         let client_state_of_a_on_b = ctx_b.client_state(client_id_on_b)?;
 
-        client_state_of_a_on_b
-            .status(ctx_b.get_client_validation_context(), client_id_on_b)?
+        ctx_b
+            .client_status(&client_state_of_a_on_b, client_id_on_b)?
             .verify_is_active()?;
         client_state_of_a_on_b.validate_proof_height(msg.proof_height_on_a)?;
 
@@ -137,22 +137,29 @@ where
             },
         )?;
 
-        let expected_chan_end_on_a = ChannelEnd::new(
-            ChannelState::Closed,
-            *chan_end_on_b.ordering(),
-            Counterparty::new(msg.port_id_on_b.clone(), Some(msg.chan_id_on_b.clone())),
-            vec![conn_id_on_a.clone()],
-            chan_end_on_b.version().clone(),
-        )?;
+        let expected_chan_end_on_a = {
+            let mut chan_end = ChannelEnd::new(
+                ChannelState::Closed,
+                *chan_end_on_b.ordering(),
+                Counterparty::new(msg.port_id_on_b.clone(), Some(msg.chan_id_on_b.clone())),
+                vec![conn_id_on_a.clone()],
+                chan_end_on_b.version().clone(),
+            )?;
+            chan_end.set_upgrade_sequence(msg.counterparty_upgrade_sequence);
+            chan_end
+        };
         let chan_end_path_on_a = ChannelEndPath::new(port_id_on_a, chan_id_on_a);
 
         // Verify the proof for the channel state against the expected channel end.
         // A counterparty channel id of None in not possible, and is checked by validate_basic in msg.
         client_state_of_a_on_b
-            .verify_membership(
+            .verify_membership_with_context(
+                ctx_b.get_client_validation_context(),
+                client_id_on_b,
                 prefix_on_a,
                 &msg.proof_chan_end_on_a,
                 consensus_state_of_a_on_b.root(),
+                msg.proof_height_on_a,
                 Path::ChannelEnd(chan_end_path_on_a),
                 expected_chan_end_on_a.encode_vec(),
             )
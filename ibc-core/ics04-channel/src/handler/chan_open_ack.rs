@@ -111,8 +111,8 @@ where
         let client_id_on_a = conn_end_on_a.client_id();
         let client_state_of_b_on_a = ctx_a.client_state(client_id_on_a)?;
 
-        client_state_of_b_on_a
-            .status(ctx_a.get_client_validation_context(), client_id_on_a)?
+        ctx_a
+            .client_status(&client_state_of_b_on_a, client_id_on_a)?
             .verify_is_active()?;
         client_state_of_b_on_a.validate_proof_height(msg.proof_height_on_b)?;
 
@@ -144,10 +144,13 @@ where
         // Verify the proof for the channel state against the expected channel end.
         // A counterparty channel id of None in not possible, and is checked by validate_basic in msg.
         client_state_of_b_on_a
-            .verify_membership(
+            .verify_membership_with_context(
+                ctx_a.get_client_validation_context(),
+                client_id_on_a,
                 prefix_on_b,
                 &msg.proof_chan_end_on_b,
                 consensus_state_of_b_on_a.root(),
+                msg.proof_height_on_b,
                 Path::ChannelEnd(chan_end_path_on_b),
                 expected_chan_end_on_b.encode_vec(),
             )
@@ -137,8 +137,8 @@ where
         let client_id_on_b = conn_end_on_b.client_id();
         let client_state_of_a_on_b = ctx_b.client_state(client_id_on_b)?;
 
-        client_state_of_a_on_b
-            .status(ctx_b.get_client_validation_context(), client_id_on_b)?
+        ctx_b
+            .client_status(&client_state_of_a_on_b, client_id_on_b)?
             .verify_is_active()?;
 
         client_state_of_a_on_b.validate_proof_height(msg.proof_height_on_a)?;
@@ -170,10 +170,13 @@ where
         // Verify the proof for the channel state against the expected channel end.
         // A counterparty channel id of None in not possible, and is checked by validate_basic in msg.
         client_state_of_a_on_b
-            .verify_membership(
+            .verify_membership_with_context(
+                ctx_b.get_client_validation_context(),
+                client_id_on_b,
                 prefix_on_a,
                 &msg.proof_chan_end_on_a,
                 consensus_state_of_a_on_b.root(),
+                msg.proof_height_on_a,
                 Path::ChannelEnd(chan_end_path_on_a),
                 expected_chan_end_on_a.encode_vec(),
             )
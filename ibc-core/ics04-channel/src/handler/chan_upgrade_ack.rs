@@ -0,0 +1,285 @@
+//! Protocol logic specific to ICS4 messages of type `MsgChannelUpgradeAck`.
+
+use ibc_core_channel_types::channel::{ChannelEnd, Counterparty, State};
+use ibc_core_channel_types::error::ChannelError;
+use ibc_core_channel_types::events::UpgradeAck;
+use ibc_core_channel_types::msgs::MsgChannelUpgradeAck;
+use ibc_core_client::context::client_state::{ClientStateCommon, ClientStateValidation};
+use ibc_core_client::context::consensus_state::ConsensusState;
+use ibc_core_connection::types::State as ConnectionState;
+use ibc_core_handler_types::error::ContextError;
+use ibc_core_handler_types::events::{IbcEvent, MessageEvent};
+use ibc_core_host::types::path::{
+    ChannelEndPath, ChannelUpgradePath, ClientConsensusStatePath, Path, SeqSendPath,
+};
+use ibc_core_host::{ExecutionContext, ValidationContext};
+use ibc_core_router::module::Module;
+use ibc_primitives::prelude::*;
+use ibc_primitives::proto::Protobuf;
+
+use super::upgrade::{
+    abort_upgrade, counterparty_ids, upgrade_timeout, verify_upgrade_compatibility,
+};
+
+pub fn chan_upgrade_ack_validate<ValCtx>(
+    ctx_a: &ValCtx,
+    module: &dyn Module,
+    msg: MsgChannelUpgradeAck,
+) -> Result<(), ContextError>
+where
+    ValCtx: ValidationContext,
+{
+    validate(ctx_a, &msg)?;
+
+    // An incompatible or expired upgrade is aborted in execution, without
+    // involving the application.
+    if upgrade_error(ctx_a, &msg)?.is_some() {
+        return Ok(());
+    }
+
+    module.on_chan_upgrade_ack_validate(
+        &msg.port_id_on_a,
+        &msg.chan_id_on_a,
+        &msg.upgrade_on_b.fields.version,
+    )?;
+
+    Ok(())
+}
+
+pub fn chan_upgrade_ack_execute<ExecCtx>(
+    ctx_a: &mut ExecCtx,
+    module: &mut dyn Module,
+    msg: MsgChannelUpgradeAck,
+) -> Result<(), ContextError>
+where
+    ExecCtx: ExecutionContext,
+{
+    if let Some(error) = upgrade_error(ctx_a, &msg)? {
+        ctx_a.emit_ibc_event(IbcEvent::Message(MessageEvent::Channel))?;
+
+        abort_upgrade(
+            ctx_a,
+            &msg.port_id_on_a,
+            &msg.chan_id_on_a,
+            error.to_string(),
+        )?;
+
+        ctx_a.log_message(format!("channel upgrade ack aborted: {error}"))?;
+
+        return Ok(());
+    }
+
+    let extras = module.on_chan_upgrade_ack_execute(
+        &msg.port_id_on_a,
+        &msg.chan_id_on_a,
+        &msg.upgrade_on_b.fields.version,
+    )?;
+    let chan_end_path_on_a = ChannelEndPath::new(&msg.port_id_on_a, &msg.chan_id_on_a);
+    let chan_end_on_a = ctx_a.channel_end(&chan_end_path_on_a)?;
+    let upgrade_path_on_a = ChannelUpgradePath::new(&msg.port_id_on_a, &msg.chan_id_on_a);
+
+    // If the channel is still open, this end did not go through
+    // `ChanUpgradeTry` (i.e. this is not a crossing hello), hence the version
+    // selected by the counterparty application is adopted and the flushing of
+    // in-flight packets starts now.
+    let upgrade_on_a = {
+        let mut upgrade_on_a = ctx_a.channel_upgrade(&upgrade_path_on_a)?;
+        if chan_end_on_a.is_open() {
+            upgrade_on_a.fields.version = msg.upgrade_on_b.fields.version.clone();
+            upgrade_on_a.timeout = upgrade_timeout(ctx_a)?;
+            upgrade_on_a.next_sequence_send = ctx_a
+                .get_next_sequence_send(&SeqSendPath::new(&msg.port_id_on_a, &msg.chan_id_on_a))?;
+        }
+        upgrade_on_a
+    };
+    let has_inflight_packets = ctx_a.has_inflight_packets(&msg.port_id_on_a, &msg.chan_id_on_a)?;
+
+    // state changes
+    {
+        let chan_end_on_a = {
+            let mut chan_end_on_a = chan_end_on_a.clone();
+            if has_inflight_packets {
+                chan_end_on_a.set_state(State::Flushing);
+            } else {
+                chan_end_on_a.set_state(State::FlushComplete);
+            }
+            chan_end_on_a
+        };
+        ctx_a.store_channel(&chan_end_path_on_a, chan_end_on_a)?;
+
+        ctx_a.store_channel_upgrade(&upgrade_path_on_a, upgrade_on_a.clone())?;
+        ctx_a.store_counterparty_channel_upgrade(&upgrade_path_on_a, msg.upgrade_on_b.clone())?;
+    }
+
+    // emit events and logs
+    {
+        ctx_a.log_message("success: channel upgrade ack".to_string())?;
+
+        let core_event = {
+            let (port_id_on_b, chan_id_on_b) = counterparty_ids(&chan_end_on_a)?;
+            let fields = upgrade_on_a.fields;
+
+            IbcEvent::UpgradeAckChannel(UpgradeAck::new(
+                msg.port_id_on_a.clone(),
+                msg.chan_id_on_a.clone(),
+                port_id_on_b,
+                chan_id_on_b,
+                chan_end_on_a.upgrade_sequence(),
+                fields.connection_hops,
+                fields.version,
+                fields.ordering,
+            ))
+        };
+        ctx_a.emit_ibc_event(IbcEvent::Message(MessageEvent::Channel))?;
+        ctx_a.emit_ibc_event(core_event)?;
+
+        for module_event in extras.events {
+            ctx_a.emit_ibc_event(IbcEvent::Module(module_event))?;
+        }
+
+        for log_message in extras.log {
+            ctx_a.log_message(log_message)?;
+        }
+    }
+
+    Ok(())
+}
+
+fn validate<Ctx>(ctx_a: &Ctx, msg: &MsgChannelUpgradeAck) -> Result<(), ContextError>
+where
+    Ctx: ValidationContext,
+{
+    ctx_a.validate_message_signer(&msg.signer)?;
+
+    let chan_end_path_on_a = ChannelEndPath::new(&msg.port_id_on_a, &msg.chan_id_on_a);
+    let chan_end_on_a = ctx_a.channel_end(&chan_end_path_on_a)?;
+
+    // The channel is flushing if both ends initiated the upgrade at the same
+    // time (crossing hellos).
+    chan_end_on_a.verify_state_matches_any(&[State::Open, State::Flushing])?;
+
+    // Makes sure an upgrade is ongoing on this end.
+    ctx_a.channel_upgrade(&ChannelUpgradePath::new(
+        &msg.port_id_on_a,
+        &msg.chan_id_on_a,
+    ))?;
+
+    let conn_end_on_a = ctx_a.connection_end(&chan_end_on_a.connection_hops()[0])?;
+
+    conn_end_on_a.verify_state_matches(&ConnectionState::Open)?;
+
+    // Verify proofs
+    {
+        let client_id_on_a = conn_end_on_a.client_id();
+        let client_state_of_b_on_a = ctx_a.client_state(client_id_on_a)?;
+
+        ctx_a
+            .client_status(&client_state_of_b_on_a, client_id_on_a)?
+            .verify_is_active()?;
+        client_state_of_b_on_a.validate_proof_height(msg.proof_height_on_b)?;
+
+        let client_cons_state_path_on_a = ClientConsensusStatePath::new(
+            client_id_on_a.clone(),
+            msg.proof_height_on_b.revision_number(),
+            msg.proof_height_on_b.revision_height(),
+        );
+        let consensus_state_of_b_on_a = ctx_a.consensus_state(&client_cons_state_path_on_a)?;
+        let prefix_on_b = conn_end_on_a.counterparty().prefix();
+        let port_id_on_b = &chan_end_on_a.counterparty().port_id;
+        let chan_id_on_b = chan_end_on_a
+            .counterparty()
+            .channel_id()
+            .ok_or(ChannelError::MissingCounterparty)?;
+        let conn_id_on_b = conn_end_on_a.counterparty().connection_id().ok_or(
+            ChannelError::UndefinedConnectionCounterparty {
+                connection_id: chan_end_on_a.connection_hops()[0].clone(),
+            },
+        )?;
+
+        let expected_chan_end_on_b = {
+            let mut chan_end = ChannelEnd::new(
+                State::Flushing,
+                *chan_end_on_a.ordering(),
+                Counterparty::new(msg.port_id_on_a.clone(), Some(msg.chan_id_on_a.clone())),
+                vec![conn_id_on_b.clone()],
+                chan_end_on_a.version().clone(),
+            )?;
+            chan_end.set_upgrade_sequence(chan_end_on_a.upgrade_sequence());
+            chan_end
+        };
+
+        // Verify the proof for the channel state against the expected channel end.
+        client_state_of_b_on_a
+            .verify_membership_with_context(
+                ctx_a.get_client_validation_context(),
+                client_id_on_a,
+                prefix_on_b,
+                &msg.proof_chan_end_on_b,
+                consensus_state_of_b_on_a.root(),
+                msg.proof_height_on_b,
+                Path::ChannelEnd(ChannelEndPath::new(port_id_on_b, chan_id_on_b)),
+                expected_chan_end_on_b.encode_vec(),
+            )
+            .map_err(ChannelError::VerifyChannelFailed)?;
+
+        // Verify the proof for the upgrade stored on B.
+        client_state_of_b_on_a
+            .verify_membership_with_context(
+                ctx_a.get_client_validation_context(),
+                client_id_on_a,
+                prefix_on_b,
+                &msg.proof_upgrade_on_b,
+                consensus_state_of_b_on_a.root(),
+                msg.proof_height_on_b,
+                Path::ChannelUpgrade(ChannelUpgradePath::new(port_id_on_b, chan_id_on_b)),
+                msg.upgrade_on_b.clone().encode_vec(),
+            )
+            .map_err(ChannelError::VerifyUpgradeFailed)?;
+    }
+
+    Ok(())
+}
+
+/// Returns the error for which the upgrade must be aborted, i.e. the reason
+/// why the upgrade of the counterparty is not compatible with the one
+/// proposed on this end, or the expiry of its timeout, if any.
+fn upgrade_error<Ctx>(
+    ctx_a: &Ctx,
+    msg: &MsgChannelUpgradeAck,
+) -> Result<Option<ContextError>, ContextError>
+where
+    Ctx: ValidationContext,
+{
+    let chan_end_on_a =
+        ctx_a.channel_end(&ChannelEndPath::new(&msg.port_id_on_a, &msg.chan_id_on_a))?;
+    let upgrade_on_a = ctx_a.channel_upgrade(&ChannelUpgradePath::new(
+        &msg.port_id_on_a,
+        &msg.chan_id_on_a,
+    ))?;
+
+    // In the crossing hellos case, both ends must have selected the same
+    // version in `ChanUpgradeTry`.
+    let proposed_fields_on_a = {
+        let mut fields = upgrade_on_a.fields;
+        if chan_end_on_a.is_open() {
+            fields.version = msg.upgrade_on_b.fields.version.clone();
+        }
+        fields
+    };
+
+    if let Err(e) =
+        verify_upgrade_compatibility(ctx_a, &proposed_fields_on_a, &msg.upgrade_on_b.fields)
+    {
+        return Ok(Some(e));
+    }
+
+    if msg
+        .upgrade_on_b
+        .timeout
+        .has_expired(ctx_a.host_height()?, ctx_a.host_timestamp()?)
+    {
+        return Ok(Some(ChannelError::UpgradeTimedOut.into()));
+    }
+
+    Ok(None)
+}
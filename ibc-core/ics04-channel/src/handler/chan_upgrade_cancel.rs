@@ -0,0 +1,188 @@
+//! Protocol logic specific to ICS4 messages of type `MsgChannelUpgradeCancel`.
+
+use ibc_core_channel_types::channel::{ChannelEnd, State};
+use ibc_core_channel_types::error::ChannelError;
+use ibc_core_channel_types::events::{UpgradeCancelled, UpgradeError};
+use ibc_core_channel_types::msgs::MsgChannelUpgradeCancel;
+use ibc_core_channel_types::upgrade::ErrorReceipt;
+use ibc_core_client::context::client_state::{ClientStateCommon, ClientStateValidation};
+use ibc_core_client::context::consensus_state::ConsensusState;
+use ibc_core_connection::types::State as ConnectionState;
+use ibc_core_handler_types::error::ContextError;
+use ibc_core_handler_types::events::{IbcEvent, MessageEvent};
+use ibc_core_host::types::path::{
+    ChannelEndPath, ChannelUpgradeErrorPath, ChannelUpgradePath, ClientConsensusStatePath, Path,
+};
+use ibc_core_host::{ExecutionContext, ValidationContext};
+use ibc_primitives::prelude::*;
+use ibc_primitives::proto::Protobuf;
+
+use super::upgrade::counterparty_ids;
+
+pub fn chan_upgrade_cancel_validate<ValCtx>(
+    ctx_a: &ValCtx,
+    msg: MsgChannelUpgradeCancel,
+) -> Result<(), ContextError>
+where
+    ValCtx: ValidationContext,
+{
+    ctx_a.validate_message_signer(&msg.signer)?;
+
+    let chan_end_path_on_a = ChannelEndPath::new(&msg.port_id_on_a, &msg.chan_id_on_a);
+    let chan_end_on_a = ctx_a.channel_end(&chan_end_path_on_a)?;
+
+    // Makes sure an upgrade is ongoing on this end.
+    ctx_a.channel_upgrade(&ChannelUpgradePath::new(
+        &msg.port_id_on_a,
+        &msg.chan_id_on_a,
+    ))?;
+
+    // The authority can cancel the upgrade on its own, unless this end is done
+    // flushing, as the counterparty may have completed the upgrade already.
+    if is_authority_cancel(ctx_a, &msg, &chan_end_on_a) {
+        return Ok(());
+    }
+
+    // The error receipt must refer to the current upgrade attempt, or to a
+    // later one if the counterparty moved on already.
+    if msg.error_receipt_on_b.sequence < chan_end_on_a.upgrade_sequence() {
+        return Err(ChannelError::InvalidUpgradeSequence {
+            expected: chan_end_on_a.upgrade_sequence(),
+            actual: msg.error_receipt_on_b.sequence,
+        }
+        .into());
+    }
+
+    let conn_end_on_a = ctx_a.connection_end(&chan_end_on_a.connection_hops()[0])?;
+
+    conn_end_on_a.verify_state_matches(&ConnectionState::Open)?;
+
+    // Verify proofs
+    {
+        let client_id_on_a = conn_end_on_a.client_id();
+        let client_state_of_b_on_a = ctx_a.client_state(client_id_on_a)?;
+
+        ctx_a
+            .client_status(&client_state_of_b_on_a, client_id_on_a)?
+            .verify_is_active()?;
+        client_state_of_b_on_a.validate_proof_height(msg.proof_height_on_b)?;
+
+        let client_cons_state_path_on_a = ClientConsensusStatePath::new(
+            client_id_on_a.clone(),
+            msg.proof_height_on_b.revision_number(),
+            msg.proof_height_on_b.revision_height(),
+        );
+        let consensus_state_of_b_on_a = ctx_a.consensus_state(&client_cons_state_path_on_a)?;
+        let prefix_on_b = conn_end_on_a.counterparty().prefix();
+        let port_id_on_b = &chan_end_on_a.counterparty().port_id;
+        let chan_id_on_b = chan_end_on_a
+            .counterparty()
+            .channel_id()
+            .ok_or(ChannelError::MissingCounterparty)?;
+
+        // Verify the proof for the error receipt written by the counterparty.
+        client_state_of_b_on_a
+            .verify_membership_with_context(
+                ctx_a.get_client_validation_context(),
+                client_id_on_a,
+                prefix_on_b,
+                &msg.proof_error_receipt_on_b,
+                consensus_state_of_b_on_a.root(),
+                msg.proof_height_on_b,
+                Path::ChannelUpgradeError(ChannelUpgradeErrorPath::new(port_id_on_b, chan_id_on_b)),
+                msg.error_receipt_on_b.clone().encode_vec(),
+            )
+            .map_err(ChannelError::VerifyUpgradeErrorReceiptFailed)?;
+    }
+
+    Ok(())
+}
+
+pub fn chan_upgrade_cancel_execute<ExecCtx>(
+    ctx_a: &mut ExecCtx,
+    msg: MsgChannelUpgradeCancel,
+) -> Result<(), ContextError>
+where
+    ExecCtx: ExecutionContext,
+{
+    let chan_end_path_on_a = ChannelEndPath::new(&msg.port_id_on_a, &msg.chan_id_on_a);
+    let chan_end_on_a = ctx_a.channel_end(&chan_end_path_on_a)?;
+    let upgrade_path_on_a = ChannelUpgradePath::new(&msg.port_id_on_a, &msg.chan_id_on_a);
+
+    // Fast-forward to the sequence of the counterparty so that both ends
+    // start the next upgrade attempt at the same sequence. The error receipt
+    // given by the authority is not verified, hence ignored.
+    let error_receipt = if is_authority_cancel(ctx_a, &msg, &chan_end_on_a) {
+        ErrorReceipt::new(
+            chan_end_on_a.upgrade_sequence(),
+            "upgrade cancelled by the authority".to_string(),
+        )
+    } else {
+        ErrorReceipt::new(
+            chan_end_on_a
+                .upgrade_sequence()
+                .max(msg.error_receipt_on_b.sequence),
+            "upgrade cancelled by the counterparty".to_string(),
+        )
+    };
+    let upgrade_sequence = error_receipt.sequence;
+
+    // state changes
+    {
+        let chan_end_on_a = {
+            let mut chan_end_on_a = chan_end_on_a.clone();
+            chan_end_on_a.set_upgrade_sequence(upgrade_sequence);
+            chan_end_on_a.set_state(State::Open);
+            chan_end_on_a
+        };
+        ctx_a.store_channel(&chan_end_path_on_a, chan_end_on_a)?;
+
+        ctx_a.delete_channel_upgrade(&upgrade_path_on_a)?;
+        ctx_a.delete_counterparty_channel_upgrade(&upgrade_path_on_a)?;
+
+        ctx_a.store_channel_upgrade_error_receipt(
+            &ChannelUpgradeErrorPath::new(&msg.port_id_on_a, &msg.chan_id_on_a),
+            error_receipt.clone(),
+        )?;
+    }
+
+    // emit events and logs
+    {
+        ctx_a.log_message("success: channel upgrade cancel".to_string())?;
+
+        let (port_id_on_b, chan_id_on_b) = counterparty_ids(&chan_end_on_a)?;
+
+        ctx_a.emit_ibc_event(IbcEvent::Message(MessageEvent::Channel))?;
+        ctx_a.emit_ibc_event(IbcEvent::UpgradeErrorChannel(UpgradeError::new(
+            msg.port_id_on_a.clone(),
+            msg.chan_id_on_a.clone(),
+            port_id_on_b.clone(),
+            chan_id_on_b.clone(),
+            upgrade_sequence,
+            error_receipt.message,
+        )))?;
+        ctx_a.emit_ibc_event(IbcEvent::UpgradeCancelledChannel(UpgradeCancelled::new(
+            msg.port_id_on_a.clone(),
+            msg.chan_id_on_a.clone(),
+            port_id_on_b,
+            chan_id_on_b,
+            upgrade_sequence,
+        )))?;
+    }
+
+    Ok(())
+}
+
+/// Returns `true` if the upgrade is cancelled by the authority of the host,
+/// which does not need to prove the error receipt of the counterparty.
+fn is_authority_cancel<Ctx>(
+    ctx_a: &Ctx,
+    msg: &MsgChannelUpgradeCancel,
+    chan_end_on_a: &ChannelEnd,
+) -> bool
+where
+    Ctx: ValidationContext,
+{
+    ctx_a.is_channel_upgrade_authority(&msg.signer)
+        && chan_end_on_a.state() != &State::FlushComplete
+}
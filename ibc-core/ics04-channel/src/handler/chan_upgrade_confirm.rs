@@ -0,0 +1,244 @@
+//! Protocol logic specific to ICS4 messages of type `MsgChannelUpgradeConfirm`.
+
+use ibc_core_channel_types::channel::{ChannelEnd, Counterparty, State};
+use ibc_core_channel_types::error::ChannelError;
+use ibc_core_channel_types::events::UpgradeConfirm;
+use ibc_core_channel_types::msgs::MsgChannelUpgradeConfirm;
+use ibc_core_client::context::client_state::{ClientStateCommon, ClientStateValidation};
+use ibc_core_client::context::consensus_state::ConsensusState;
+use ibc_core_connection::types::State as ConnectionState;
+use ibc_core_handler_types::error::ContextError;
+use ibc_core_handler_types::events::{IbcEvent, MessageEvent};
+use ibc_core_host::types::path::{
+    ChannelEndPath, ChannelUpgradePath, ClientConsensusStatePath, Path,
+};
+use ibc_core_host::{ExecutionContext, ValidationContext};
+use ibc_core_router::module::Module;
+use ibc_primitives::prelude::*;
+use ibc_primitives::proto::Protobuf;
+
+use super::upgrade::{abort_upgrade, counterparty_ids, open_upgrade};
+
+pub fn chan_upgrade_confirm_validate<ValCtx>(
+    ctx_b: &ValCtx,
+    module: &dyn Module,
+    msg: MsgChannelUpgradeConfirm,
+) -> Result<(), ContextError>
+where
+    ValCtx: ValidationContext,
+{
+    validate(ctx_b, &msg)?;
+
+    // An expired upgrade is aborted in execution.
+    if upgrade_error(ctx_b, &msg)?.is_some() {
+        return Ok(());
+    }
+
+    // The upgrade is completed right away if no packets are left to be
+    // flushed on either end.
+    if msg.chan_state_on_a == State::FlushComplete
+        && !ctx_b.has_inflight_packets(&msg.port_id_on_b, &msg.chan_id_on_b)?
+    {
+        let upgrade_on_b = ctx_b.channel_upgrade(&ChannelUpgradePath::new(
+            &msg.port_id_on_b,
+            &msg.chan_id_on_b,
+        ))?;
+
+        module.on_chan_upgrade_open_validate(
+            &msg.port_id_on_b,
+            &msg.chan_id_on_b,
+            upgrade_on_b.fields.ordering,
+            &upgrade_on_b.fields.connection_hops,
+            &upgrade_on_b.fields.version,
+        )?;
+    }
+
+    Ok(())
+}
+
+pub fn chan_upgrade_confirm_execute<ExecCtx>(
+    ctx_b: &mut ExecCtx,
+    module: &mut dyn Module,
+    msg: MsgChannelUpgradeConfirm,
+) -> Result<(), ContextError>
+where
+    ExecCtx: ExecutionContext,
+{
+    if let Some(error) = upgrade_error(ctx_b, &msg)? {
+        ctx_b.emit_ibc_event(IbcEvent::Message(MessageEvent::Channel))?;
+
+        abort_upgrade(
+            ctx_b,
+            &msg.port_id_on_b,
+            &msg.chan_id_on_b,
+            error.to_string(),
+        )?;
+
+        ctx_b.log_message(format!("channel upgrade confirm aborted: {error}"))?;
+
+        return Ok(());
+    }
+
+    let chan_end_path_on_b = ChannelEndPath::new(&msg.port_id_on_b, &msg.chan_id_on_b);
+    let chan_end_on_b = ctx_b.channel_end(&chan_end_path_on_b)?;
+    let has_inflight_packets = ctx_b.has_inflight_packets(&msg.port_id_on_b, &msg.chan_id_on_b)?;
+
+    // state changes
+    let chan_end_on_b = {
+        let chan_end_on_b = {
+            let mut chan_end_on_b = chan_end_on_b.clone();
+            if !has_inflight_packets {
+                chan_end_on_b.set_state(State::FlushComplete);
+            }
+            chan_end_on_b
+        };
+        ctx_b.store_channel(&chan_end_path_on_b, chan_end_on_b.clone())?;
+
+        ctx_b.store_counterparty_channel_upgrade(
+            &ChannelUpgradePath::new(&msg.port_id_on_b, &msg.chan_id_on_b),
+            msg.upgrade_on_a.clone(),
+        )?;
+
+        chan_end_on_b
+    };
+
+    // emit events and logs
+    {
+        ctx_b.log_message("success: channel upgrade confirm".to_string())?;
+
+        let core_event = {
+            let (port_id_on_a, chan_id_on_a) = counterparty_ids(&chan_end_on_b)?;
+
+            IbcEvent::UpgradeConfirmChannel(UpgradeConfirm::new(
+                msg.port_id_on_b.clone(),
+                msg.chan_id_on_b.clone(),
+                port_id_on_a,
+                chan_id_on_a,
+                *chan_end_on_b.state(),
+                chan_end_on_b.upgrade_sequence(),
+            ))
+        };
+        ctx_b.emit_ibc_event(IbcEvent::Message(MessageEvent::Channel))?;
+        ctx_b.emit_ibc_event(core_event)?;
+    }
+
+    if msg.chan_state_on_a == State::FlushComplete && chan_end_on_b.state() == &State::FlushComplete
+    {
+        open_upgrade(ctx_b, module, &msg.port_id_on_b, &msg.chan_id_on_b)?;
+    }
+
+    Ok(())
+}
+
+fn validate<Ctx>(ctx_b: &Ctx, msg: &MsgChannelUpgradeConfirm) -> Result<(), ContextError>
+where
+    Ctx: ValidationContext,
+{
+    ctx_b.validate_message_signer(&msg.signer)?;
+
+    let chan_end_path_on_b = ChannelEndPath::new(&msg.port_id_on_b, &msg.chan_id_on_b);
+    let chan_end_on_b = ctx_b.channel_end(&chan_end_path_on_b)?;
+
+    chan_end_on_b.verify_state_matches(&State::Flushing)?;
+
+    // Makes sure an upgrade is ongoing on this end.
+    ctx_b.channel_upgrade(&ChannelUpgradePath::new(
+        &msg.port_id_on_b,
+        &msg.chan_id_on_b,
+    ))?;
+
+    let conn_end_on_b = ctx_b.connection_end(&chan_end_on_b.connection_hops()[0])?;
+
+    conn_end_on_b.verify_state_matches(&ConnectionState::Open)?;
+
+    // Verify proofs
+    {
+        let client_id_on_b = conn_end_on_b.client_id();
+        let client_state_of_a_on_b = ctx_b.client_state(client_id_on_b)?;
+
+        ctx_b
+            .client_status(&client_state_of_a_on_b, client_id_on_b)?
+            .verify_is_active()?;
+        client_state_of_a_on_b.validate_proof_height(msg.proof_height_on_a)?;
+
+        let client_cons_state_path_on_b = ClientConsensusStatePath::new(
+            client_id_on_b.clone(),
+            msg.proof_height_on_a.revision_number(),
+            msg.proof_height_on_a.revision_height(),
+        );
+        let consensus_state_of_a_on_b = ctx_b.consensus_state(&client_cons_state_path_on_b)?;
+        let prefix_on_a = conn_end_on_b.counterparty().prefix();
+        let port_id_on_a = &chan_end_on_b.counterparty().port_id;
+        let chan_id_on_a = chan_end_on_b
+            .counterparty()
+            .channel_id()
+            .ok_or(ChannelError::MissingCounterparty)?;
+        let conn_id_on_a = conn_end_on_b.counterparty().connection_id().ok_or(
+            ChannelError::UndefinedConnectionCounterparty {
+                connection_id: chan_end_on_b.connection_hops()[0].clone(),
+            },
+        )?;
+
+        let expected_chan_end_on_a = {
+            let mut chan_end = ChannelEnd::new(
+                msg.chan_state_on_a,
+                *chan_end_on_b.ordering(),
+                Counterparty::new(msg.port_id_on_b.clone(), Some(msg.chan_id_on_b.clone())),
+                vec![conn_id_on_a.clone()],
+                chan_end_on_b.version().clone(),
+            )?;
+            chan_end.set_upgrade_sequence(chan_end_on_b.upgrade_sequence());
+            chan_end
+        };
+
+        // Verify the proof for the channel state against the expected channel end.
+        client_state_of_a_on_b
+            .verify_membership_with_context(
+                ctx_b.get_client_validation_context(),
+                client_id_on_b,
+                prefix_on_a,
+                &msg.proof_chan_end_on_a,
+                consensus_state_of_a_on_b.root(),
+                msg.proof_height_on_a,
+                Path::ChannelEnd(ChannelEndPath::new(port_id_on_a, chan_id_on_a)),
+                expected_chan_end_on_a.encode_vec(),
+            )
+            .map_err(ChannelError::VerifyChannelFailed)?;
+
+        // Verify the proof for the upgrade stored on A.
+        client_state_of_a_on_b
+            .verify_membership_with_context(
+                ctx_b.get_client_validation_context(),
+                client_id_on_b,
+                prefix_on_a,
+                &msg.proof_upgrade_on_a,
+                consensus_state_of_a_on_b.root(),
+                msg.proof_height_on_a,
+                Path::ChannelUpgrade(ChannelUpgradePath::new(port_id_on_a, chan_id_on_a)),
+                msg.upgrade_on_a.clone().encode_vec(),
+            )
+            .map_err(ChannelError::VerifyUpgradeFailed)?;
+    }
+
+    Ok(())
+}
+
+/// Returns the error for which the upgrade must be aborted, i.e. the expiry
+/// of the timeout of the counterparty upgrade, if any.
+fn upgrade_error<Ctx>(
+    ctx_b: &Ctx,
+    msg: &MsgChannelUpgradeConfirm,
+) -> Result<Option<ContextError>, ContextError>
+where
+    Ctx: ValidationContext,
+{
+    if msg
+        .upgrade_on_a
+        .timeout
+        .has_expired(ctx_b.host_height()?, ctx_b.host_timestamp()?)
+    {
+        return Ok(Some(ChannelError::UpgradeTimedOut.into()));
+    }
+
+    Ok(None)
+}
@@ -0,0 +1,168 @@
+//! Protocol logic specific to ICS4 messages of type `MsgChannelUpgradeInit`.
+
+use ibc_core_channel_types::channel::State;
+use ibc_core_channel_types::error::ChannelError;
+use ibc_core_channel_types::events::UpgradeInit;
+use ibc_core_channel_types::msgs::MsgChannelUpgradeInit;
+use ibc_core_channel_types::upgrade::{Upgrade, UpgradeFields, UpgradeTimeout};
+use ibc_core_connection::types::State as ConnectionState;
+use ibc_core_handler_types::error::ContextError;
+use ibc_core_handler_types::events::{IbcEvent, MessageEvent};
+use ibc_core_host::types::identifiers::Sequence;
+use ibc_core_host::types::path::{ChannelEndPath, ChannelUpgradePath};
+use ibc_core_host::{ExecutionContext, ValidationContext};
+use ibc_core_router::module::Module;
+use ibc_primitives::prelude::*;
+
+use super::upgrade::counterparty_ids;
+
+pub fn chan_upgrade_init_validate<ValCtx>(
+    ctx_a: &ValCtx,
+    module: &dyn Module,
+    msg: MsgChannelUpgradeInit,
+) -> Result<(), ContextError>
+where
+    ValCtx: ValidationContext,
+{
+    validate(ctx_a, &msg)?;
+
+    module.on_chan_upgrade_init_validate(
+        &msg.port_id_on_a,
+        &msg.chan_id_on_a,
+        msg.fields.ordering,
+        &msg.fields.connection_hops,
+        &msg.fields.version,
+    )?;
+
+    Ok(())
+}
+
+pub fn chan_upgrade_init_execute<ExecCtx>(
+    ctx_a: &mut ExecCtx,
+    module: &mut dyn Module,
+    msg: MsgChannelUpgradeInit,
+) -> Result<(), ContextError>
+where
+    ExecCtx: ExecutionContext,
+{
+    let (extras, version) = module.on_chan_upgrade_init_execute(
+        &msg.port_id_on_a,
+        &msg.chan_id_on_a,
+        msg.fields.ordering,
+        &msg.fields.connection_hops,
+        &msg.fields.version,
+    )?;
+    let chan_end_path_on_a = ChannelEndPath::new(&msg.port_id_on_a, &msg.chan_id_on_a);
+    let chan_end_on_a = ctx_a.channel_end(&chan_end_path_on_a)?;
+    let upgrade_sequence = chan_end_on_a
+        .upgrade_sequence()
+        .checked_add(1)
+        .ok_or(ChannelError::CounterOverflow)?;
+    let fields = UpgradeFields::new(
+        msg.fields.ordering,
+        msg.fields.connection_hops.clone(),
+        version,
+    );
+
+    // state changes
+    {
+        let chan_end_on_a = {
+            let mut chan_end_on_a = chan_end_on_a.clone();
+            chan_end_on_a.set_upgrade_sequence(upgrade_sequence);
+            chan_end_on_a
+        };
+        ctx_a.store_channel(&chan_end_path_on_a, chan_end_on_a)?;
+
+        // The timeout and the next sequence send are only set once the
+        // channel starts flushing.
+        let upgrade = Upgrade::new(fields.clone(), UpgradeTimeout::default(), Sequence::from(0));
+        ctx_a.store_channel_upgrade(
+            &ChannelUpgradePath::new(&msg.port_id_on_a, &msg.chan_id_on_a),
+            upgrade,
+        )?;
+    }
+
+    // emit events and logs
+    {
+        ctx_a.log_message("success: channel upgrade init".to_string())?;
+
+        let core_event = {
+            let (port_id_on_b, chan_id_on_b) = counterparty_ids(&chan_end_on_a)?;
+
+            IbcEvent::UpgradeInitChannel(UpgradeInit::new(
+                msg.port_id_on_a.clone(),
+                msg.chan_id_on_a.clone(),
+                port_id_on_b,
+                chan_id_on_b,
+                upgrade_sequence,
+                fields.connection_hops,
+                fields.version,
+                fields.ordering,
+            ))
+        };
+        ctx_a.emit_ibc_event(IbcEvent::Message(MessageEvent::Channel))?;
+        ctx_a.emit_ibc_event(core_event)?;
+
+        for module_event in extras.events {
+            ctx_a.emit_ibc_event(IbcEvent::Module(module_event))?;
+        }
+
+        for log_message in extras.log {
+            ctx_a.log_message(log_message)?;
+        }
+    }
+
+    Ok(())
+}
+
+fn validate<Ctx>(ctx_a: &Ctx, msg: &MsgChannelUpgradeInit) -> Result<(), ContextError>
+where
+    Ctx: ValidationContext,
+{
+    ctx_a.validate_message_signer(&msg.signer)?;
+
+    // Only the authority of the host can initiate the upgrade of a channel.
+    if !ctx_a.is_channel_upgrade_authority(&msg.signer) {
+        return Err(ChannelError::UnauthorizedUpgrade {
+            signer: msg.signer.clone(),
+        }
+        .into());
+    }
+
+    msg.fields.validate_basic()?;
+
+    let chan_end_path_on_a = ChannelEndPath::new(&msg.port_id_on_a, &msg.chan_id_on_a);
+    let chan_end_on_a = ctx_a.channel_end(&chan_end_path_on_a)?;
+
+    // Only an open channel can be upgraded.
+    chan_end_on_a.verify_state_matches(&State::Open)?;
+
+    // An ongoing upgrade must be cancelled before a new one is initiated.
+    if ctx_a
+        .channel_upgrade(&ChannelUpgradePath::new(
+            &msg.port_id_on_a,
+            &msg.chan_id_on_a,
+        ))
+        .is_ok()
+    {
+        return Err(ChannelError::UpgradeInProgress {
+            port_id: msg.port_id_on_a.clone(),
+            channel_id: msg.chan_id_on_a.clone(),
+        }
+        .into());
+    }
+
+    if chan_end_on_a.upgrade_fields() == msg.fields {
+        return Err(ChannelError::UpgradeFieldsUnchanged {
+            fields: msg.fields.to_string(),
+        }
+        .into());
+    }
+
+    // The connection the channel is upgraded to must be open.
+    let conn_end_on_a = ctx_a.connection_end(&msg.fields.connection_hops[0])?;
+
+    conn_end_on_a.verify_state_matches(&ConnectionState::Open)?;
+
+    Ok(())
+}
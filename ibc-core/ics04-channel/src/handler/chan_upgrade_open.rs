@@ -0,0 +1,176 @@
+//! Protocol logic specific to ICS4 messages of type `MsgChannelUpgradeOpen`.
+
+use ibc_core_channel_types::channel::{ChannelEnd, Counterparty, State};
+use ibc_core_channel_types::error::ChannelError;
+use ibc_core_channel_types::msgs::MsgChannelUpgradeOpen;
+use ibc_core_client::context::client_state::{ClientStateCommon, ClientStateValidation};
+use ibc_core_client::context::consensus_state::ConsensusState;
+use ibc_core_connection::types::State as ConnectionState;
+use ibc_core_handler_types::error::ContextError;
+use ibc_core_handler_types::events::{IbcEvent, MessageEvent};
+use ibc_core_host::types::path::{
+    ChannelEndPath, ChannelUpgradePath, ClientConsensusStatePath, Path,
+};
+use ibc_core_host::{ExecutionContext, ValidationContext};
+use ibc_core_router::module::Module;
+use ibc_primitives::prelude::*;
+use ibc_primitives::proto::Protobuf;
+
+use super::upgrade::open_upgrade;
+
+pub fn chan_upgrade_open_validate<ValCtx>(
+    ctx_a: &ValCtx,
+    module: &dyn Module,
+    msg: MsgChannelUpgradeOpen,
+) -> Result<(), ContextError>
+where
+    ValCtx: ValidationContext,
+{
+    validate(ctx_a, &msg)?;
+
+    let upgrade_on_a = ctx_a.channel_upgrade(&ChannelUpgradePath::new(
+        &msg.port_id_on_a,
+        &msg.chan_id_on_a,
+    ))?;
+
+    module.on_chan_upgrade_open_validate(
+        &msg.port_id_on_a,
+        &msg.chan_id_on_a,
+        upgrade_on_a.fields.ordering,
+        &upgrade_on_a.fields.connection_hops,
+        &upgrade_on_a.fields.version,
+    )?;
+
+    Ok(())
+}
+
+pub fn chan_upgrade_open_execute<ExecCtx>(
+    ctx_a: &mut ExecCtx,
+    module: &mut dyn Module,
+    msg: MsgChannelUpgradeOpen,
+) -> Result<(), ContextError>
+where
+    ExecCtx: ExecutionContext,
+{
+    ctx_a.emit_ibc_event(IbcEvent::Message(MessageEvent::Channel))?;
+
+    open_upgrade(ctx_a, module, &msg.port_id_on_a, &msg.chan_id_on_a)
+}
+
+fn validate<Ctx>(ctx_a: &Ctx, msg: &MsgChannelUpgradeOpen) -> Result<(), ContextError>
+where
+    Ctx: ValidationContext,
+{
+    ctx_a.validate_message_signer(&msg.signer)?;
+
+    let chan_end_path_on_a = ChannelEndPath::new(&msg.port_id_on_a, &msg.chan_id_on_a);
+    let chan_end_on_a = ctx_a.channel_end(&chan_end_path_on_a)?;
+
+    chan_end_on_a.verify_state_matches(&State::FlushComplete)?;
+
+    let upgrade_on_a = ctx_a.channel_upgrade(&ChannelUpgradePath::new(
+        &msg.port_id_on_a,
+        &msg.chan_id_on_a,
+    ))?;
+
+    let conn_end_on_a = ctx_a.connection_end(&chan_end_on_a.connection_hops()[0])?;
+
+    conn_end_on_a.verify_state_matches(&ConnectionState::Open)?;
+
+    // If the counterparty already opened the upgraded channel, its channel
+    // end is expected to carry the upgraded fields. Otherwise, it must have
+    // flushed all of its packets as well.
+    let expected_chan_end_on_b = match msg.chan_state_on_b {
+        State::Open => {
+            if msg.upgrade_sequence_on_b < chan_end_on_a.upgrade_sequence() {
+                return Err(ChannelError::InvalidUpgradeSequence {
+                    expected: chan_end_on_a.upgrade_sequence(),
+                    actual: msg.upgrade_sequence_on_b,
+                }
+                .into());
+            }
+
+            let upgrade_conn_end_on_a =
+                ctx_a.connection_end(&upgrade_on_a.fields.connection_hops[0])?;
+            let upgrade_conn_id_on_b = upgrade_conn_end_on_a.counterparty().connection_id().ok_or(
+                ChannelError::UndefinedConnectionCounterparty {
+                    connection_id: upgrade_on_a.fields.connection_hops[0].clone(),
+                },
+            )?;
+
+            let mut chan_end = ChannelEnd::new(
+                State::Open,
+                upgrade_on_a.fields.ordering,
+                Counterparty::new(msg.port_id_on_a.clone(), Some(msg.chan_id_on_a.clone())),
+                vec![upgrade_conn_id_on_b.clone()],
+                upgrade_on_a.fields.version.clone(),
+            )?;
+            chan_end.set_upgrade_sequence(msg.upgrade_sequence_on_b);
+            chan_end
+        }
+        State::FlushComplete => {
+            let conn_id_on_b = conn_end_on_a.counterparty().connection_id().ok_or(
+                ChannelError::UndefinedConnectionCounterparty {
+                    connection_id: chan_end_on_a.connection_hops()[0].clone(),
+                },
+            )?;
+
+            let mut chan_end = ChannelEnd::new(
+                State::FlushComplete,
+                *chan_end_on_a.ordering(),
+                Counterparty::new(msg.port_id_on_a.clone(), Some(msg.chan_id_on_a.clone())),
+                vec![conn_id_on_b.clone()],
+                chan_end_on_a.version().clone(),
+            )?;
+            chan_end.set_upgrade_sequence(chan_end_on_a.upgrade_sequence());
+            chan_end
+        }
+        state => {
+            return Err(ChannelError::InvalidState {
+                expected: "Counterparty channel state must be Open or FlushComplete".to_string(),
+                actual: state.to_string(),
+            }
+            .into())
+        }
+    };
+
+    // Verify proofs
+    {
+        let client_id_on_a = conn_end_on_a.client_id();
+        let client_state_of_b_on_a = ctx_a.client_state(client_id_on_a)?;
+
+        ctx_a
+            .client_status(&client_state_of_b_on_a, client_id_on_a)?
+            .verify_is_active()?;
+        client_state_of_b_on_a.validate_proof_height(msg.proof_height_on_b)?;
+
+        let client_cons_state_path_on_a = ClientConsensusStatePath::new(
+            client_id_on_a.clone(),
+            msg.proof_height_on_b.revision_number(),
+            msg.proof_height_on_b.revision_height(),
+        );
+        let consensus_state_of_b_on_a = ctx_a.consensus_state(&client_cons_state_path_on_a)?;
+        let prefix_on_b = conn_end_on_a.counterparty().prefix();
+        let port_id_on_b = &chan_end_on_a.counterparty().port_id;
+        let chan_id_on_b = chan_end_on_a
+            .counterparty()
+            .channel_id()
+            .ok_or(ChannelError::MissingCounterparty)?;
+
+        // Verify the proof for the channel state against the expected channel end.
+        client_state_of_b_on_a
+            .verify_membership_with_context(
+                ctx_a.get_client_validation_context(),
+                client_id_on_a,
+                prefix_on_b,
+                &msg.proof_chan_end_on_b,
+                consensus_state_of_b_on_a.root(),
+                msg.proof_height_on_b,
+                Path::ChannelEnd(ChannelEndPath::new(port_id_on_b, chan_id_on_b)),
+                expected_chan_end_on_b.encode_vec(),
+            )
+            .map_err(ChannelError::VerifyChannelFailed)?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,168 @@
+//! Protocol logic specific to ICS4 messages of type `MsgChannelUpgradeTimeout`.
+
+use ibc_core_channel_types::channel::State;
+use ibc_core_channel_types::error::ChannelError;
+use ibc_core_channel_types::events::UpgradeTimeout;
+use ibc_core_channel_types::msgs::MsgChannelUpgradeTimeout;
+use ibc_core_client::context::client_state::{ClientStateCommon, ClientStateValidation};
+use ibc_core_client::context::consensus_state::ConsensusState;
+use ibc_core_connection::types::State as ConnectionState;
+use ibc_core_handler_types::error::ContextError;
+use ibc_core_handler_types::events::{IbcEvent, MessageEvent};
+use ibc_core_host::types::path::{
+    ChannelEndPath, ChannelUpgradePath, ClientConsensusStatePath, Path,
+};
+use ibc_core_host::{ExecutionContext, ValidationContext};
+use ibc_primitives::prelude::*;
+use ibc_primitives::proto::Protobuf;
+
+use super::upgrade::{abort_upgrade, counterparty_ids};
+
+pub fn chan_upgrade_timeout_validate<ValCtx>(
+    ctx_a: &ValCtx,
+    msg: MsgChannelUpgradeTimeout,
+) -> Result<(), ContextError>
+where
+    ValCtx: ValidationContext,
+{
+    ctx_a.validate_message_signer(&msg.signer)?;
+
+    let chan_end_path_on_a = ChannelEndPath::new(&msg.port_id_on_a, &msg.chan_id_on_a);
+    let chan_end_on_a = ctx_a.channel_end(&chan_end_path_on_a)?;
+
+    // The upgrade can only time out once this end started flushing.
+    chan_end_on_a.verify_state_matches_any(&[State::Flushing, State::FlushComplete])?;
+
+    let upgrade_on_a = ctx_a.channel_upgrade(&ChannelUpgradePath::new(
+        &msg.port_id_on_a,
+        &msg.chan_id_on_a,
+    ))?;
+
+    let conn_end_on_a = ctx_a.connection_end(&chan_end_on_a.connection_hops()[0])?;
+
+    conn_end_on_a.verify_state_matches(&ConnectionState::Open)?;
+
+    let client_id_on_a = conn_end_on_a.client_id();
+    let client_state_of_b_on_a = ctx_a.client_state(client_id_on_a)?;
+
+    ctx_a
+        .client_status(&client_state_of_b_on_a, client_id_on_a)?
+        .verify_is_active()?;
+    client_state_of_b_on_a.validate_proof_height(msg.proof_height_on_b)?;
+
+    let client_cons_state_path_on_a = ClientConsensusStatePath::new(
+        client_id_on_a.clone(),
+        msg.proof_height_on_b.revision_number(),
+        msg.proof_height_on_b.revision_height(),
+    );
+    let consensus_state_of_b_on_a = ctx_a.consensus_state(&client_cons_state_path_on_a)?;
+
+    // The timeout is expressed on the counterparty chain, hence it is checked
+    // against the height and timestamp of the proof.
+    if !upgrade_on_a
+        .timeout
+        .has_expired(msg.proof_height_on_b, consensus_state_of_b_on_a.timestamp())
+    {
+        return Err(ChannelError::UpgradeTimeoutNotReached.into());
+    }
+
+    let chan_end_on_b = &msg.chan_end_on_b;
+
+    chan_end_on_b.verify_state_matches_any(&[State::Open, State::Flushing])?;
+
+    // If the counterparty already opened the upgraded channel, the upgrade
+    // cannot be aborted anymore.
+    if chan_end_on_b.is_open() {
+        let upgrade_conn_end_on_a =
+            ctx_a.connection_end(&upgrade_on_a.fields.connection_hops[0])?;
+
+        if chan_end_on_b.version() == &upgrade_on_a.fields.version
+            && chan_end_on_b.ordering() == &upgrade_on_a.fields.ordering
+            && upgrade_conn_end_on_a.counterparty().connection_id()
+                == Some(&chan_end_on_b.connection_hops()[0])
+        {
+            return Err(ChannelError::InvalidUpgrade {
+                reason: "counterparty channel end is already upgraded".to_string(),
+            }
+            .into());
+        }
+    }
+
+    if chan_end_on_b.upgrade_sequence() < chan_end_on_a.upgrade_sequence() {
+        return Err(ChannelError::InvalidUpgradeSequence {
+            expected: chan_end_on_a.upgrade_sequence(),
+            actual: chan_end_on_b.upgrade_sequence(),
+        }
+        .into());
+    }
+
+    let prefix_on_b = conn_end_on_a.counterparty().prefix();
+    let port_id_on_b = &chan_end_on_a.counterparty().port_id;
+    let chan_id_on_b = chan_end_on_a
+        .counterparty()
+        .channel_id()
+        .ok_or(ChannelError::MissingCounterparty)?;
+
+    // Verify the proof for the channel state of the counterparty.
+    client_state_of_b_on_a
+        .verify_membership_with_context(
+            ctx_a.get_client_validation_context(),
+            client_id_on_a,
+            prefix_on_b,
+            &msg.proof_chan_end_on_b,
+            consensus_state_of_b_on_a.root(),
+            msg.proof_height_on_b,
+            Path::ChannelEnd(ChannelEndPath::new(port_id_on_b, chan_id_on_b)),
+            chan_end_on_b.clone().encode_vec(),
+        )
+        .map_err(ChannelError::VerifyChannelFailed)?;
+
+    Ok(())
+}
+
+pub fn chan_upgrade_timeout_execute<ExecCtx>(
+    ctx_a: &mut ExecCtx,
+    msg: MsgChannelUpgradeTimeout,
+) -> Result<(), ContextError>
+where
+    ExecCtx: ExecutionContext,
+{
+    let chan_end_on_a =
+        ctx_a.channel_end(&ChannelEndPath::new(&msg.port_id_on_a, &msg.chan_id_on_a))?;
+    let upgrade_on_a = ctx_a.channel_upgrade(&ChannelUpgradePath::new(
+        &msg.port_id_on_a,
+        &msg.chan_id_on_a,
+    ))?;
+
+    ctx_a.emit_ibc_event(IbcEvent::Message(MessageEvent::Channel))?;
+
+    // state changes
+    abort_upgrade(
+        ctx_a,
+        &msg.port_id_on_a,
+        &msg.chan_id_on_a,
+        "upgrade timed out".to_string(),
+    )?;
+
+    // emit events and logs
+    {
+        ctx_a.log_message("success: channel upgrade timeout".to_string())?;
+
+        let core_event = {
+            let (port_id_on_b, chan_id_on_b) = counterparty_ids(&chan_end_on_a)?;
+
+            IbcEvent::UpgradeTimeoutChannel(UpgradeTimeout::new(
+                msg.port_id_on_a.clone(),
+                msg.chan_id_on_a.clone(),
+                port_id_on_b,
+                chan_id_on_b,
+                chan_end_on_a.upgrade_sequence(),
+                upgrade_on_a.timeout.height,
+                upgrade_on_a.timeout.timestamp,
+            ))
+        };
+        ctx_a.emit_ibc_event(core_event)?;
+    }
+
+    Ok(())
+}
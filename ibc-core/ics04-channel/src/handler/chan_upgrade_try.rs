@@ -0,0 +1,336 @@
+//! Protocol logic specific to ICS4 messages of type `MsgChannelUpgradeTry`.
+
+use ibc_core_channel_types::channel::{ChannelEnd, Counterparty, State};
+use ibc_core_channel_types::error::ChannelError;
+use ibc_core_channel_types::events::UpgradeTry;
+use ibc_core_channel_types::msgs::MsgChannelUpgradeTry;
+use ibc_core_channel_types::upgrade::{Upgrade, UpgradeFields, UpgradeTimeout};
+use ibc_core_client::context::client_state::{ClientStateCommon, ClientStateValidation};
+use ibc_core_client::context::consensus_state::ConsensusState;
+use ibc_core_connection::types::State as ConnectionState;
+use ibc_core_handler_types::error::ContextError;
+use ibc_core_handler_types::events::{IbcEvent, MessageEvent};
+use ibc_core_host::types::identifiers::Sequence;
+use ibc_core_host::types::path::{
+    ChannelEndPath, ChannelUpgradePath, ClientConsensusStatePath, Path, SeqSendPath,
+};
+use ibc_core_host::{ExecutionContext, ValidationContext};
+use ibc_core_router::module::Module;
+use ibc_primitives::prelude::*;
+use ibc_primitives::proto::Protobuf;
+
+use super::upgrade::{
+    abort_upgrade, counterparty_ids, upgrade_timeout, verify_upgrade_compatibility,
+};
+
+pub fn chan_upgrade_try_validate<ValCtx>(
+    ctx_b: &ValCtx,
+    module: &dyn Module,
+    msg: MsgChannelUpgradeTry,
+) -> Result<(), ContextError>
+where
+    ValCtx: ValidationContext,
+{
+    validate(ctx_b, &msg)?;
+
+    // An incompatible upgrade is aborted in execution, without involving the
+    // application.
+    if upgrade_error(ctx_b, &msg)?.is_some() {
+        return Ok(());
+    }
+
+    module.on_chan_upgrade_try_validate(
+        &msg.port_id_on_b,
+        &msg.chan_id_on_b,
+        msg.upgrade_fields_on_a.ordering,
+        &msg.proposed_upgrade_connection_hops_on_b,
+        &msg.upgrade_fields_on_a.version,
+    )?;
+
+    Ok(())
+}
+
+pub fn chan_upgrade_try_execute<ExecCtx>(
+    ctx_b: &mut ExecCtx,
+    module: &mut dyn Module,
+    msg: MsgChannelUpgradeTry,
+) -> Result<(), ContextError>
+where
+    ExecCtx: ExecutionContext,
+{
+    if let Some(error) = upgrade_error(ctx_b, &msg)? {
+        return abort(ctx_b, &msg, error);
+    }
+
+    let (extras, version) = module.on_chan_upgrade_try_execute(
+        &msg.port_id_on_b,
+        &msg.chan_id_on_b,
+        msg.upgrade_fields_on_a.ordering,
+        &msg.proposed_upgrade_connection_hops_on_b,
+        &msg.upgrade_fields_on_a.version,
+    )?;
+    let chan_end_path_on_b = ChannelEndPath::new(&msg.port_id_on_b, &msg.chan_id_on_b);
+    let chan_end_on_b = ctx_b.channel_end(&chan_end_path_on_b)?;
+    let fields = UpgradeFields::new(
+        msg.upgrade_fields_on_a.ordering,
+        msg.proposed_upgrade_connection_hops_on_b.clone(),
+        version,
+    );
+    let next_seq_send_on_b =
+        ctx_b.get_next_sequence_send(&SeqSendPath::new(&msg.port_id_on_b, &msg.chan_id_on_b))?;
+    let timeout = upgrade_timeout(ctx_b)?;
+
+    // state changes
+    {
+        // In validation, we checked that the upgrade sequence on A is at least
+        // the one of this upgrade attempt, so we fast-forward to it.
+        let chan_end_on_b = {
+            let mut chan_end_on_b = chan_end_on_b.clone();
+            chan_end_on_b.set_upgrade_sequence(msg.upgrade_sequence_on_a);
+            chan_end_on_b.set_state(State::Flushing);
+            chan_end_on_b
+        };
+        ctx_b.store_channel(&chan_end_path_on_b, chan_end_on_b)?;
+
+        let upgrade = Upgrade::new(fields.clone(), timeout, next_seq_send_on_b);
+        ctx_b.store_channel_upgrade(
+            &ChannelUpgradePath::new(&msg.port_id_on_b, &msg.chan_id_on_b),
+            upgrade,
+        )?;
+    }
+
+    // emit events and logs
+    {
+        ctx_b.log_message("success: channel upgrade try".to_string())?;
+
+        let core_event = {
+            let (port_id_on_a, chan_id_on_a) = counterparty_ids(&chan_end_on_b)?;
+
+            IbcEvent::UpgradeTryChannel(UpgradeTry::new(
+                msg.port_id_on_b.clone(),
+                msg.chan_id_on_b.clone(),
+                port_id_on_a,
+                chan_id_on_a,
+                msg.upgrade_sequence_on_a,
+                fields.connection_hops,
+                fields.version,
+                fields.ordering,
+            ))
+        };
+        ctx_b.emit_ibc_event(IbcEvent::Message(MessageEvent::Channel))?;
+        ctx_b.emit_ibc_event(core_event)?;
+
+        for module_event in extras.events {
+            ctx_b.emit_ibc_event(IbcEvent::Module(module_event))?;
+        }
+
+        for log_message in extras.log {
+            ctx_b.log_message(log_message)?;
+        }
+    }
+
+    Ok(())
+}
+
+fn validate<Ctx>(ctx_b: &Ctx, msg: &MsgChannelUpgradeTry) -> Result<(), ContextError>
+where
+    Ctx: ValidationContext,
+{
+    ctx_b.validate_message_signer(&msg.signer)?;
+
+    msg.verify_connection_hops_length()?;
+    msg.upgrade_fields_on_a.validate_basic()?;
+
+    let chan_end_path_on_b = ChannelEndPath::new(&msg.port_id_on_b, &msg.chan_id_on_b);
+    let chan_end_on_b = ctx_b.channel_end(&chan_end_path_on_b)?;
+
+    chan_end_on_b.verify_state_matches(&State::Open)?;
+
+    let (_, upgrade_sequence_on_b) = proposed_upgrade_on_b(ctx_b, msg, &chan_end_on_b)?;
+
+    if msg.upgrade_sequence_on_a < upgrade_sequence_on_b {
+        return Err(ChannelError::InvalidUpgradeSequence {
+            expected: upgrade_sequence_on_b,
+            actual: msg.upgrade_sequence_on_a,
+        }
+        .into());
+    }
+
+    let conn_end_on_b = ctx_b.connection_end(&chan_end_on_b.connection_hops()[0])?;
+
+    conn_end_on_b.verify_state_matches(&ConnectionState::Open)?;
+
+    // Verify proofs
+    {
+        let client_id_on_b = conn_end_on_b.client_id();
+        let client_state_of_a_on_b = ctx_b.client_state(client_id_on_b)?;
+
+        ctx_b
+            .client_status(&client_state_of_a_on_b, client_id_on_b)?
+            .verify_is_active()?;
+        client_state_of_a_on_b.validate_proof_height(msg.proof_height_on_a)?;
+
+        let client_cons_state_path_on_b = ClientConsensusStatePath::new(
+            client_id_on_b.clone(),
+            msg.proof_height_on_a.revision_number(),
+            msg.proof_height_on_a.revision_height(),
+        );
+        let consensus_state_of_a_on_b = ctx_b.consensus_state(&client_cons_state_path_on_b)?;
+        let prefix_on_a = conn_end_on_b.counterparty().prefix();
+        let port_id_on_a = &chan_end_on_b.counterparty().port_id;
+        let chan_id_on_a = chan_end_on_b
+            .counterparty()
+            .channel_id()
+            .ok_or(ChannelError::MissingCounterparty)?;
+        let conn_id_on_a = conn_end_on_b.counterparty().connection_id().ok_or(
+            ChannelError::UndefinedConnectionCounterparty {
+                connection_id: chan_end_on_b.connection_hops()[0].clone(),
+            },
+        )?;
+
+        let expected_chan_end_on_a = {
+            let mut chan_end = ChannelEnd::new(
+                State::Open,
+                *chan_end_on_b.ordering(),
+                Counterparty::new(msg.port_id_on_b.clone(), Some(msg.chan_id_on_b.clone())),
+                vec![conn_id_on_a.clone()],
+                chan_end_on_b.version().clone(),
+            )?;
+            chan_end.set_upgrade_sequence(msg.upgrade_sequence_on_a);
+            chan_end
+        };
+
+        // Verify the proof for the channel state against the expected channel end.
+        client_state_of_a_on_b
+            .verify_membership_with_context(
+                ctx_b.get_client_validation_context(),
+                client_id_on_b,
+                prefix_on_a,
+                &msg.proof_chan_end_on_a,
+                consensus_state_of_a_on_b.root(),
+                msg.proof_height_on_a,
+                Path::ChannelEnd(ChannelEndPath::new(port_id_on_a, chan_id_on_a)),
+                expected_chan_end_on_a.encode_vec(),
+            )
+            .map_err(ChannelError::VerifyChannelFailed)?;
+
+        // The upgrade stored on A by `ChanUpgradeInit` has neither a timeout
+        // nor a next sequence send yet.
+        let expected_upgrade_on_a = Upgrade::new(
+            msg.upgrade_fields_on_a.clone(),
+            UpgradeTimeout::default(),
+            Sequence::from(0),
+        );
+
+        // Verify the proof for the upgrade proposed on A.
+        client_state_of_a_on_b
+            .verify_membership_with_context(
+                ctx_b.get_client_validation_context(),
+                client_id_on_b,
+                prefix_on_a,
+                &msg.proof_upgrade_on_a,
+                consensus_state_of_a_on_b.root(),
+                msg.proof_height_on_a,
+                Path::ChannelUpgrade(ChannelUpgradePath::new(port_id_on_a, chan_id_on_a)),
+                expected_upgrade_on_a.encode_vec(),
+            )
+            .map_err(ChannelError::VerifyUpgradeFailed)?;
+    }
+
+    Ok(())
+}
+
+/// Returns the upgrade fields proposed on this end, along with the sequence
+/// of the upgrade attempt.
+///
+/// If no upgrade was initiated on this end, the counterparty proposal is
+/// adopted as if a `ChanUpgradeInit` had been processed.
+fn proposed_upgrade_on_b<Ctx>(
+    ctx_b: &Ctx,
+    msg: &MsgChannelUpgradeTry,
+    chan_end_on_b: &ChannelEnd,
+) -> Result<(UpgradeFields, u64), ContextError>
+where
+    Ctx: ValidationContext,
+{
+    let upgrade_path_on_b = ChannelUpgradePath::new(&msg.port_id_on_b, &msg.chan_id_on_b);
+
+    match ctx_b.channel_upgrade(&upgrade_path_on_b) {
+        Ok(upgrade_on_b) => {
+            if upgrade_on_b.fields.connection_hops != msg.proposed_upgrade_connection_hops_on_b {
+                return Err(ChannelError::InvalidUpgrade {
+                    reason: "proposed connection hops do not match the ongoing upgrade".to_string(),
+                }
+                .into());
+            }
+            Ok((upgrade_on_b.fields, chan_end_on_b.upgrade_sequence()))
+        }
+        Err(_) => {
+            let fields = UpgradeFields::new(
+                msg.upgrade_fields_on_a.ordering,
+                msg.proposed_upgrade_connection_hops_on_b.clone(),
+                msg.upgrade_fields_on_a.version.clone(),
+            );
+
+            if chan_end_on_b.upgrade_fields() == fields {
+                return Err(ChannelError::UpgradeFieldsUnchanged {
+                    fields: fields.to_string(),
+                }
+                .into());
+            }
+
+            let upgrade_sequence = chan_end_on_b
+                .upgrade_sequence()
+                .checked_add(1)
+                .ok_or(ChannelError::CounterOverflow)?;
+
+            Ok((fields, upgrade_sequence))
+        }
+    }
+}
+
+/// Returns the error for which the upgrade must be aborted, i.e. the reason
+/// why the upgrade proposed by the counterparty is not compatible with the
+/// one proposed on this end, if any.
+fn upgrade_error<Ctx>(
+    ctx_b: &Ctx,
+    msg: &MsgChannelUpgradeTry,
+) -> Result<Option<ContextError>, ContextError>
+where
+    Ctx: ValidationContext,
+{
+    let chan_end_on_b =
+        ctx_b.channel_end(&ChannelEndPath::new(&msg.port_id_on_b, &msg.chan_id_on_b))?;
+    let (proposed_fields_on_b, _) = proposed_upgrade_on_b(ctx_b, msg, &chan_end_on_b)?;
+
+    Ok(verify_upgrade_compatibility(ctx_b, &proposed_fields_on_b, &msg.upgrade_fields_on_a).err())
+}
+
+/// Aborts the upgrade attempt of the counterparty, at its upgrade sequence so
+/// that it can cancel its own upgrade with the written error receipt.
+fn abort<Ctx>(
+    ctx_b: &mut Ctx,
+    msg: &MsgChannelUpgradeTry,
+    error: ContextError,
+) -> Result<(), ContextError>
+where
+    Ctx: ExecutionContext,
+{
+    ctx_b.emit_ibc_event(IbcEvent::Message(MessageEvent::Channel))?;
+
+    let chan_end_path_on_b = ChannelEndPath::new(&msg.port_id_on_b, &msg.chan_id_on_b);
+    let mut chan_end_on_b = ctx_b.channel_end(&chan_end_path_on_b)?;
+    chan_end_on_b.set_upgrade_sequence(msg.upgrade_sequence_on_a);
+    ctx_b.store_channel(&chan_end_path_on_b, chan_end_on_b)?;
+
+    abort_upgrade(
+        ctx_b,
+        &msg.port_id_on_b,
+        &msg.chan_id_on_b,
+        error.to_string(),
+    )?;
+
+    ctx_b.log_message(format!("channel upgrade try aborted: {error}"))?;
+
+    Ok(())
+}
@@ -1,4 +1,4 @@
-use ibc_core_channel_types::channel::{Counterparty, Order, State as ChannelState};
+use ibc_core_channel_types::channel::{ChannelEnd, Counterparty, Order, State as ChannelState};
 use ibc_core_channel_types::commitment::{compute_ack_commitment, compute_packet_commitment};
 use ibc_core_channel_types::error::{ChannelError, PacketError};
 use ibc_core_channel_types::events::{ReceivePacket, WriteAcknowledgement};
@@ -11,8 +11,8 @@ use ibc_core_connection::types::State as ConnectionState;
 use ibc_core_handler_types::error::ContextError;
 use ibc_core_handler_types::events::{IbcEvent, MessageEvent};
 use ibc_core_host::types::path::{
-    AckPath, ChannelEndPath, ClientConsensusStatePath, CommitmentPath, Path, ReceiptPath,
-    SeqRecvPath,
+    AckPath, ChannelEndPath, ChannelUpgradePath, ClientConsensusStatePath, CommitmentPath, Path,
+    ReceiptPath, SeqRecvPath,
 };
 use ibc_core_host::{ExecutionContext, ValidationContext};
 use ibc_core_router::module::Module;
@@ -54,7 +54,7 @@ where
                     ReceiptPath::new(&packet.port_id_on_b, &packet.chan_id_on_b, packet.seq_on_a);
                 ctx_b.get_packet_receipt(&receipt_path_on_b).is_ok()
             }
-            Order::Ordered => {
+            Order::Ordered | Order::OrderedAllowTimeout => {
                 let seq_recv_path_on_b =
                     SeqRecvPath::new(&msg.packet.port_id_on_b, &msg.packet.chan_id_on_b);
                 let next_seq_recv = ctx_b.get_next_sequence_recv(&seq_recv_path_on_b)?;
@@ -70,6 +70,17 @@ where
         }
     }
 
+    // A packet which timed out on a channel allowing timeouts is not passed
+    // to the module: its sequence is skipped and a timeout receipt is written
+    // for the sender to prove the timeout.
+    if chan_end_on_b.order_matches(&Order::OrderedAllowTimeout)
+        && msg
+            .packet
+            .timed_out(&ctx_b.host_timestamp()?, ctx_b.host_height()?)
+    {
+        return skip_timed_out_packet(ctx_b, &chan_end_on_b, msg);
+    }
+
     let (extras, acknowledgement) = module.on_recv_packet_execute(&msg.packet, &msg.signer);
 
     // state changes
@@ -85,30 +96,33 @@ where
 
                 ctx_b.store_packet_receipt(&receipt_path_on_b, Receipt::Ok)?;
             }
-            Order::Ordered => {
+            Order::Ordered | Order::OrderedAllowTimeout => {
                 let seq_recv_path_on_b =
                     SeqRecvPath::new(&msg.packet.port_id_on_b, &msg.packet.chan_id_on_b);
                 let next_seq_recv = ctx_b.get_next_sequence_recv(&seq_recv_path_on_b)?;
                 ctx_b.store_next_sequence_recv(&seq_recv_path_on_b, next_seq_recv.increment())?;
             }
-            _ => {}
+            Order::None => {}
+        }
+
+        // `writeAcknowledgement` handler state changes, unless the module
+        // acknowledges the packet asynchronously
+        if let Some(acknowledgement) = &acknowledgement {
+            let ack_path_on_b = AckPath::new(
+                &msg.packet.port_id_on_b,
+                &msg.packet.chan_id_on_b,
+                msg.packet.seq_on_a,
+            );
+            ctx_b.store_packet_acknowledgement(
+                &ack_path_on_b,
+                compute_ack_commitment::<ExecCtx::HostFunctions>(acknowledgement),
+            )?;
         }
-        let ack_path_on_b = AckPath::new(
-            &msg.packet.port_id_on_b,
-            &msg.packet.chan_id_on_b,
-            msg.packet.seq_on_a,
-        );
-        // `writeAcknowledgement` handler state changes
-        ctx_b.store_packet_acknowledgement(
-            &ack_path_on_b,
-            compute_ack_commitment(&acknowledgement),
-        )?;
     }
 
     // emit events and logs
     {
         ctx_b.log_message("success: packet receive".to_string())?;
-        ctx_b.log_message("success: packet write acknowledgement".to_string())?;
 
         let conn_id_on_b = &chan_end_on_b.connection_hops()[0];
         let event = IbcEvent::ReceivePacket(ReceivePacket::new(
@@ -118,13 +132,18 @@ where
         ));
         ctx_b.emit_ibc_event(IbcEvent::Message(MessageEvent::Channel))?;
         ctx_b.emit_ibc_event(event)?;
-        let event = IbcEvent::WriteAcknowledgement(WriteAcknowledgement::new(
-            msg.packet,
-            acknowledgement,
-            conn_id_on_b.clone(),
-        ));
-        ctx_b.emit_ibc_event(IbcEvent::Message(MessageEvent::Channel))?;
-        ctx_b.emit_ibc_event(event)?;
+
+        if let Some(acknowledgement) = acknowledgement {
+            ctx_b.log_message("success: packet write acknowledgement".to_string())?;
+
+            let event = IbcEvent::WriteAcknowledgement(WriteAcknowledgement::new(
+                msg.packet,
+                acknowledgement,
+                conn_id_on_b.clone(),
+            ));
+            ctx_b.emit_ibc_event(IbcEvent::Message(MessageEvent::Channel))?;
+            ctx_b.emit_ibc_event(event)?;
+        }
 
         for module_event in extras.events {
             ctx_b.emit_ibc_event(IbcEvent::Module(module_event))?;
@@ -138,6 +157,40 @@ where
     Ok(())
 }
 
+/// Skips the sequence of a packet which timed out on a channel of order
+/// `OrderedAllowTimeout`, and writes a timeout receipt in its place.
+fn skip_timed_out_packet<ExecCtx>(
+    ctx_b: &mut ExecCtx,
+    chan_end_on_b: &ChannelEnd,
+    msg: MsgRecvPacket,
+) -> Result<(), ContextError>
+where
+    ExecCtx: ExecutionContext,
+{
+    let seq_recv_path_on_b = SeqRecvPath::new(&msg.packet.port_id_on_b, &msg.packet.chan_id_on_b);
+    ctx_b.store_next_sequence_recv(&seq_recv_path_on_b, msg.packet.seq_on_a.increment())?;
+
+    let receipt_path_on_b = ReceiptPath::new(
+        &msg.packet.port_id_on_b,
+        &msg.packet.chan_id_on_b,
+        msg.packet.seq_on_a,
+    );
+    ctx_b.store_packet_receipt(&receipt_path_on_b, Receipt::Timeout)?;
+
+    ctx_b.log_message("success: packet receive timed out".to_string())?;
+
+    let conn_id_on_b = &chan_end_on_b.connection_hops()[0];
+    let event = IbcEvent::ReceivePacket(ReceivePacket::new(
+        msg.packet,
+        chan_end_on_b.ordering,
+        conn_id_on_b.clone(),
+    ));
+    ctx_b.emit_ibc_event(IbcEvent::Message(MessageEvent::Channel))?;
+    ctx_b.emit_ibc_event(event)?;
+
+    Ok(())
+}
+
 fn validate<Ctx>(ctx_b: &Ctx, msg: &MsgRecvPacket) -> Result<(), ContextError>
 where
     Ctx: ValidationContext,
@@ -148,7 +201,26 @@ where
         ChannelEndPath::new(&msg.packet.port_id_on_b, &msg.packet.chan_id_on_b);
     let chan_end_on_b = ctx_b.channel_end(&chan_end_path_on_b)?;
 
-    chan_end_on_b.verify_state_matches(&ChannelState::Open)?;
+    // Packets sent before the counterparty started flushing are still
+    // received while the channel is being upgraded.
+    chan_end_on_b.verify_state_matches_any(&[
+        ChannelState::Open,
+        ChannelState::Flushing,
+        ChannelState::FlushComplete,
+    ])?;
+
+    if let Ok(counterparty_upgrade) = ctx_b.counterparty_channel_upgrade(&ChannelUpgradePath::new(
+        &msg.packet.port_id_on_b,
+        &msg.packet.chan_id_on_b,
+    )) {
+        if msg.packet.seq_on_a >= counterparty_upgrade.next_sequence_send {
+            return Err(PacketError::PacketSentAfterFlushStarted {
+                sequence: msg.packet.seq_on_a,
+                counterparty_next_sequence_send: counterparty_upgrade.next_sequence_send,
+            }
+            .into());
+        }
+    }
 
     let counterparty = Counterparty::new(
         msg.packet.port_id_on_a.clone(),
@@ -162,18 +234,24 @@ where
 
     conn_end_on_b.verify_state_matches(&ConnectionState::Open)?;
 
+    // Channels allowing timeouts receive timed out packets to skip their
+    // sequence.
     let latest_height = ctx_b.host_height()?;
-    if msg.packet.timeout_height_on_b.has_expired(latest_height) {
-        return Err(PacketError::LowPacketHeight {
-            chain_height: latest_height,
-            timeout_height: msg.packet.timeout_height_on_b,
+    let latest_timestamp = ctx_b.host_timestamp()?;
+    let packet_timed_out = msg.packet.timed_out(&latest_timestamp, latest_height);
+
+    if !chan_end_on_b.order_matches(&Order::OrderedAllowTimeout) {
+        if msg.packet.timeout_height_on_b.has_expired(latest_height) {
+            return Err(PacketError::LowPacketHeight {
+                chain_height: latest_height,
+                timeout_height: msg.packet.timeout_height_on_b,
+            }
+            .into());
         }
-        .into());
-    }
 
-    let latest_timestamp = ctx_b.host_timestamp()?;
-    if let Expiry::Expired = latest_timestamp.check_expiry(&msg.packet.timeout_timestamp_on_b) {
-        return Err(PacketError::LowPacketTimestamp.into());
+        if let Expiry::Expired = latest_timestamp.check_expiry(&msg.packet.timeout_timestamp_on_b) {
+            return Err(PacketError::LowPacketTimestamp.into());
+        }
     }
 
     // Verify proofs
@@ -181,8 +259,8 @@ where
         let client_id_on_b = conn_end_on_b.client_id();
         let client_state_of_a_on_b = ctx_b.client_state(client_id_on_b)?;
 
-        client_state_of_a_on_b
-            .status(ctx_b.get_client_validation_context(), client_id_on_b)?
+        ctx_b
+            .client_status(&client_state_of_a_on_b, client_id_on_b)?
             .verify_is_active()?;
 
         client_state_of_a_on_b.validate_proof_height(msg.proof_height_on_a)?;
@@ -195,7 +273,7 @@ where
 
         let consensus_state_of_a_on_b = ctx_b.consensus_state(&client_cons_state_path_on_b)?;
 
-        let expected_commitment_on_a = compute_packet_commitment(
+        let expected_commitment_on_a = compute_packet_commitment::<Ctx::HostFunctions>(
             &msg.packet.data,
             &msg.packet.timeout_height_on_b,
             &msg.packet.timeout_timestamp_on_b,
@@ -210,10 +288,13 @@ where
 
         // Verify the proof for the packet against the chain store.
         client_state_of_a_on_b
-            .verify_membership(
+            .verify_membership_with_context(
+                ctx_b.get_client_validation_context(),
+                client_id_on_b,
                 conn_end_on_b.counterparty().prefix(),
                 &msg.proof_commitment_on_a,
                 consensus_state_of_a_on_b.root(),
+                msg.proof_height_on_a,
                 Path::Commitment(commitment_path_on_a),
                 expected_commitment_on_a.into_vec(),
             )
@@ -224,7 +305,10 @@ where
             .map_err(PacketError::Channel)?;
     }
 
-    if chan_end_on_b.order_matches(&Order::Ordered) {
+    if matches!(
+        chan_end_on_b.ordering,
+        Order::Ordered | Order::OrderedAllowTimeout
+    ) {
         let seq_recv_path_on_b =
             SeqRecvPath::new(&msg.packet.port_id_on_b, &msg.packet.chan_id_on_b);
         let next_seq_recv = ctx_b.get_next_sequence_recv(&seq_recv_path_on_b)?;
@@ -236,7 +320,7 @@ where
             .into());
         }
 
-        if msg.packet.seq_on_a == next_seq_recv {
+        if msg.packet.seq_on_a == next_seq_recv && !packet_timed_out {
             // Case where the recvPacket is successful and an
             // acknowledgement will be written (not a no-op)
             validate_write_acknowledgement(ctx_b, msg)?;
@@ -3,13 +3,14 @@ use ibc_core_channel_types::commitment::compute_packet_commitment;
 use ibc_core_channel_types::error::PacketError;
 use ibc_core_channel_types::events::SendPacket;
 use ibc_core_channel_types::packet::Packet;
-use ibc_core_client::context::client_state::{ClientStateCommon, ClientStateValidation};
+use ibc_core_client::context::client_state::ClientStateCommon;
 use ibc_core_client::context::consensus_state::ConsensusState;
 use ibc_core_handler_types::error::ContextError;
 use ibc_core_handler_types::events::{IbcEvent, MessageEvent};
 use ibc_core_host::types::path::{
     ChannelEndPath, ClientConsensusStatePath, CommitmentPath, SeqSendPath,
 };
+use ibc_core_router::module::Module;
 use ibc_primitives::prelude::*;
 use ibc_primitives::Expiry;
 
@@ -26,6 +27,34 @@ pub fn send_packet(
     send_packet_execute(ctx_a, packet)
 }
 
+/// Send the given packet on behalf of `module`, the module (or stack of
+/// middleware) bound to the source port of the packet.
+///
+/// The packet first goes through the outbound hooks of the module, which lets
+/// the middleware of the stack intercept or reject it, before being sent as
+/// with [`send_packet`].
+pub fn send_packet_via_module(
+    ctx_a: &mut impl SendPacketExecutionContext,
+    module: &mut dyn Module,
+    packet: Packet,
+) -> Result<(), ContextError> {
+    module.send_packet_validate(&packet)?;
+    send_packet_validate(ctx_a, &packet)?;
+
+    let extras = module.send_packet_execute(&packet)?;
+    send_packet_execute(ctx_a, packet)?;
+
+    for module_event in extras.events {
+        ctx_a.emit_ibc_event(IbcEvent::Module(module_event))?;
+    }
+
+    for log_message in extras.log {
+        ctx_a.log_message(log_message)?;
+    }
+
+    Ok(())
+}
+
 /// Validate that sending the given packet would succeed.
 pub fn send_packet_validate(
     ctx_a: &impl SendPacketValidationContext,
@@ -38,6 +67,15 @@ pub fn send_packet_validate(
     // This allows for optimistic packet processing before a channel opens
     chan_end_on_a.verify_not_closed()?;
 
+    // No new packets can be sent while the channel is being upgraded.
+    if chan_end_on_a.state().is_upgrading() {
+        return Err(PacketError::InvalidChannelState {
+            channel_id: packet.chan_id_on_a.clone(),
+            state: *chan_end_on_a.state(),
+        }
+        .into());
+    }
+
     let counterparty = Counterparty::new(
         packet.port_id_on_b.clone(),
         Some(packet.chan_id_on_b.clone()),
@@ -53,8 +91,8 @@ pub fn send_packet_validate(
 
     let client_state_of_b_on_a = ctx_a.client_state(client_id_on_a)?;
 
-    client_state_of_b_on_a
-        .status(ctx_a.get_client_validation_context(), client_id_on_a)?
+    ctx_a
+        .client_status(&client_state_of_b_on_a, client_id_on_a)?
         .verify_is_active()?;
 
     let latest_height_on_a = client_state_of_b_on_a.latest_height();
@@ -96,10 +134,10 @@ pub fn send_packet_validate(
 /// Send the packet without any validation.
 ///
 /// A prior call to [`send_packet_validate`] MUST have succeeded.
-pub fn send_packet_execute(
-    ctx_a: &mut impl SendPacketExecutionContext,
-    packet: Packet,
-) -> Result<(), ContextError> {
+pub fn send_packet_execute<ExecCtx>(ctx_a: &mut ExecCtx, packet: Packet) -> Result<(), ContextError>
+where
+    ExecCtx: SendPacketExecutionContext,
+{
     {
         let seq_send_path_on_a = SeqSendPath::new(&packet.port_id_on_a, &packet.chan_id_on_a);
         let next_seq_send_on_a = ctx_a.get_next_sequence_send(&seq_send_path_on_a)?;
@@ -109,7 +147,7 @@ pub fn send_packet_execute(
 
     ctx_a.store_packet_commitment(
         &CommitmentPath::new(&packet.port_id_on_a, &packet.chan_id_on_a, packet.seq_on_a),
-        compute_packet_commitment(
+        compute_packet_commitment::<ExecCtx::HostFunctions>(
             &packet.data,
             &packet.timeout_height_on_b,
             &packet.timeout_timestamp_on_b,
@@ -3,19 +3,22 @@ use ibc_core_channel_types::commitment::compute_packet_commitment;
 use ibc_core_channel_types::error::{ChannelError, PacketError};
 use ibc_core_channel_types::events::{ChannelClosed, TimeoutPacket};
 use ibc_core_channel_types::msgs::{MsgTimeout, MsgTimeoutOnClose};
+use ibc_core_channel_types::packet::Receipt;
 use ibc_core_client::context::client_state::{ClientStateCommon, ClientStateValidation};
 use ibc_core_client::context::consensus_state::ConsensusState;
 use ibc_core_connection::delay::verify_conn_delay_passed;
 use ibc_core_handler_types::error::ContextError;
 use ibc_core_handler_types::events::{IbcEvent, MessageEvent};
 use ibc_core_host::types::path::{
-    ChannelEndPath, ClientConsensusStatePath, CommitmentPath, Path, ReceiptPath, SeqRecvPath,
+    ChannelEndPath, ClientConsensusStatePath, CommitmentPath, Path, ReceiptPath, SeqAckPath,
+    SeqRecvPath,
 };
 use ibc_core_host::{ExecutionContext, ValidationContext};
 use ibc_core_router::module::Module;
 use ibc_primitives::prelude::*;
 
 use super::timeout_on_close;
+use super::upgrade::{abort_upgrade, handle_flush_state};
 
 pub enum TimeoutMsgType {
     Timeout(MsgTimeout),
@@ -91,12 +94,34 @@ where
         ctx_a.delete_packet_commitment(&commitment_path_on_a)?;
 
         if let Order::Ordered = chan_end_on_a.ordering {
-            let mut chan_end_on_a = chan_end_on_a;
+            // A timed out packet closes an ordered channel, hence an ongoing
+            // upgrade has to be aborted first.
+            if chan_end_on_a.state() == &State::Flushing {
+                abort_upgrade(
+                    ctx_a,
+                    &packet.port_id_on_a,
+                    &packet.chan_id_on_a,
+                    "ordered channel closed by a packet timeout".to_string(),
+                )?;
+            }
+
+            let mut chan_end_on_a = ctx_a.channel_end(&chan_end_path_on_a)?;
             chan_end_on_a.state = State::Closed;
             ctx_a.store_channel(&chan_end_path_on_a, chan_end_on_a.clone())?;
 
             chan_end_on_a
         } else {
+            if let Order::OrderedAllowTimeout = chan_end_on_a.ordering {
+                // Note: in validation, we verified that `packet.sequence == nextSeqAck`
+                // (where `nextSeqAck` is the value in the store)
+                let seq_ack_path_on_a = SeqAckPath::new(&packet.port_id_on_a, &packet.chan_id_on_a);
+                ctx_a.store_next_sequence_ack(&seq_ack_path_on_a, packet.seq_on_a.increment())?;
+            }
+
+            if chan_end_on_a.state() == &State::Flushing {
+                handle_flush_state(ctx_a, &packet.port_id_on_a, &packet.chan_id_on_a)?;
+            }
+
             chan_end_on_a
         }
     };
@@ -143,7 +168,8 @@ where
         &msg.packet.chan_id_on_a,
     ))?;
 
-    chan_end_on_a.verify_state_matches(&State::Open)?;
+    // In-flight packets can still time out while the channel is flushing.
+    chan_end_on_a.verify_state_matches_any(&[State::Open, State::Flushing])?;
 
     let counterparty = Counterparty::new(
         msg.packet.port_id_on_b.clone(),
@@ -171,7 +197,7 @@ where
         Err(_) => return Ok(()),
     };
 
-    let expected_commitment_on_a = compute_packet_commitment(
+    let expected_commitment_on_a = compute_packet_commitment::<Ctx::HostFunctions>(
         &msg.packet.data,
         &msg.packet.timeout_height_on_b,
         &msg.packet.timeout_timestamp_on_b,
@@ -188,8 +214,8 @@ where
         let client_id_on_a = conn_end_on_a.client_id();
         let client_state_of_b_on_a = ctx_a.client_state(client_id_on_a)?;
 
-        client_state_of_b_on_a
-            .status(ctx_a.get_client_validation_context(), client_id_on_a)?
+        ctx_a
+            .client_status(&client_state_of_b_on_a, client_id_on_a)?
             .verify_is_active()?;
 
         client_state_of_b_on_a.validate_proof_height(msg.proof_height_on_b)?;
@@ -215,37 +241,77 @@ where
 
         verify_conn_delay_passed(ctx_a, msg.proof_height_on_b, &conn_end_on_a)?;
 
-        let next_seq_recv_verification_result = if chan_end_on_a.order_matches(&Order::Ordered) {
-            if msg.packet.seq_on_a < msg.next_seq_recv_on_b {
-                return Err(PacketError::InvalidPacketSequence {
-                    given_sequence: msg.packet.seq_on_a,
-                    next_sequence: msg.next_seq_recv_on_b,
+        let next_seq_recv_verification_result = match chan_end_on_a.ordering {
+            Order::Ordered => {
+                if msg.packet.seq_on_a < msg.next_seq_recv_on_b {
+                    return Err(PacketError::InvalidPacketSequence {
+                        given_sequence: msg.packet.seq_on_a,
+                        next_sequence: msg.next_seq_recv_on_b,
+                    }
+                    .into());
                 }
-                .into());
+                let seq_recv_path_on_b =
+                    SeqRecvPath::new(&msg.packet.port_id_on_b, &msg.packet.chan_id_on_b);
+
+                client_state_of_b_on_a.verify_membership_with_context(
+                    ctx_a.get_client_validation_context(),
+                    client_id_on_a,
+                    conn_end_on_a.counterparty().prefix(),
+                    &msg.proof_unreceived_on_b,
+                    consensus_state_of_b_on_a.root(),
+                    msg.proof_height_on_b,
+                    Path::SeqRecv(seq_recv_path_on_b),
+                    msg.packet.seq_on_a.to_vec(),
+                )
+            }
+            // The counterparty skipped the sequence of the packet upon
+            // receiving it after its timeout, and wrote a timeout receipt.
+            Order::OrderedAllowTimeout => {
+                let seq_ack_path_on_a =
+                    SeqAckPath::new(&msg.packet.port_id_on_a, &msg.packet.chan_id_on_a);
+                let next_seq_ack = ctx_a.get_next_sequence_ack(&seq_ack_path_on_a)?;
+                if msg.packet.seq_on_a != next_seq_ack {
+                    return Err(PacketError::InvalidPacketSequence {
+                        given_sequence: msg.packet.seq_on_a,
+                        next_sequence: next_seq_ack,
+                    }
+                    .into());
+                }
+
+                let receipt_path_on_b = ReceiptPath::new(
+                    &msg.packet.port_id_on_b,
+                    &msg.packet.chan_id_on_b,
+                    msg.packet.seq_on_a,
+                );
+
+                client_state_of_b_on_a.verify_membership_with_context(
+                    ctx_a.get_client_validation_context(),
+                    client_id_on_a,
+                    conn_end_on_a.counterparty().prefix(),
+                    &msg.proof_unreceived_on_b,
+                    consensus_state_of_b_on_a.root(),
+                    msg.proof_height_on_b,
+                    Path::Receipt(receipt_path_on_b),
+                    Receipt::Timeout.as_bytes().to_vec(),
+                )
+            }
+            _ => {
+                let receipt_path_on_b = ReceiptPath::new(
+                    &msg.packet.port_id_on_b,
+                    &msg.packet.chan_id_on_b,
+                    msg.packet.seq_on_a,
+                );
+
+                client_state_of_b_on_a.verify_non_membership_with_context(
+                    ctx_a.get_client_validation_context(),
+                    client_id_on_a,
+                    conn_end_on_a.counterparty().prefix(),
+                    &msg.proof_unreceived_on_b,
+                    consensus_state_of_b_on_a.root(),
+                    msg.proof_height_on_b,
+                    Path::Receipt(receipt_path_on_b),
+                )
             }
-            let seq_recv_path_on_b =
-                SeqRecvPath::new(&msg.packet.port_id_on_b, &msg.packet.chan_id_on_b);
-
-            client_state_of_b_on_a.verify_membership(
-                conn_end_on_a.counterparty().prefix(),
-                &msg.proof_unreceived_on_b,
-                consensus_state_of_b_on_a.root(),
-                Path::SeqRecv(seq_recv_path_on_b),
-                msg.packet.seq_on_a.to_vec(),
-            )
-        } else {
-            let receipt_path_on_b = ReceiptPath::new(
-                &msg.packet.port_id_on_b,
-                &msg.packet.chan_id_on_b,
-                msg.packet.seq_on_a,
-            );
-
-            client_state_of_b_on_a.verify_non_membership(
-                conn_end_on_a.counterparty().prefix(),
-                &msg.proof_unreceived_on_b,
-                consensus_state_of_b_on_a.root(),
-                Path::Receipt(receipt_path_on_b),
-            )
         };
         next_seq_recv_verification_result
             .map_err(|e| ChannelError::PacketVerificationFailed {
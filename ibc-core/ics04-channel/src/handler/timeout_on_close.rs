@@ -47,7 +47,7 @@ where
         Err(_) => return Ok(()),
     };
 
-    let expected_commitment_on_a = compute_packet_commitment(
+    let expected_commitment_on_a = compute_packet_commitment::<Ctx::HostFunctions>(
         &packet.data,
         &packet.timeout_height_on_b,
         &packet.timeout_timestamp_on_b,
@@ -67,8 +67,8 @@ where
         let client_id_on_a = conn_end_on_a.client_id();
         let client_state_of_b_on_a = ctx_a.client_state(client_id_on_a)?;
 
-        client_state_of_b_on_a
-            .status(ctx_a.get_client_validation_context(), client_id_on_a)?
+        ctx_a
+            .client_status(&client_state_of_b_on_a, client_id_on_a)?
             .verify_is_active()?;
 
         client_state_of_b_on_a.validate_proof_height(msg.proof_height_on_b)?;
@@ -95,23 +95,30 @@ where
             packet.port_id_on_a.clone(),
             Some(packet.chan_id_on_a.clone()),
         );
-        let expected_chan_end_on_b = ChannelEnd::new(
-            State::Closed,
-            *chan_end_on_a.ordering(),
-            expected_counterparty,
-            expected_conn_hops_on_b,
-            chan_end_on_a.version().clone(),
-        )?;
+        let expected_chan_end_on_b = {
+            let mut chan_end = ChannelEnd::new(
+                State::Closed,
+                *chan_end_on_a.ordering(),
+                expected_counterparty,
+                expected_conn_hops_on_b,
+                chan_end_on_a.version().clone(),
+            )?;
+            chan_end.set_upgrade_sequence(msg.counterparty_upgrade_sequence);
+            chan_end
+        };
 
         let chan_end_path_on_b = ChannelEndPath(port_id_on_b, chan_id_on_b.clone());
 
         // Verify the proof for the channel state against the expected channel end.
         // A counterparty channel id of None in not possible, and is checked by validate_basic in msg.
         client_state_of_b_on_a
-            .verify_membership(
+            .verify_membership_with_context(
+                ctx_a.get_client_validation_context(),
+                client_id_on_a,
                 prefix_on_b,
                 &msg.proof_unreceived_on_b,
                 consensus_state_of_b_on_a.root(),
+                msg.proof_height_on_b,
                 Path::ChannelEnd(chan_end_path_on_b),
                 expected_chan_end_on_b.encode_vec(),
             )
@@ -120,7 +127,12 @@ where
 
         verify_conn_delay_passed(ctx_a, msg.proof_height_on_b, &conn_end_on_a)?;
 
-        let next_seq_recv_verification_result = if chan_end_on_a.order_matches(&Order::Ordered) {
+        // Channels allowing timeouts cannot skip sequences once closed, hence
+        // are treated as ordered channels.
+        let next_seq_recv_verification_result = if matches!(
+            chan_end_on_a.ordering,
+            Order::Ordered | Order::OrderedAllowTimeout
+        ) {
             if packet.seq_on_a < msg.next_seq_recv_on_b {
                 return Err(PacketError::InvalidPacketSequence {
                     given_sequence: packet.seq_on_a,
@@ -130,10 +142,13 @@ where
             }
             let seq_recv_path_on_b = SeqRecvPath::new(&packet.port_id_on_b, &packet.chan_id_on_b);
 
-            client_state_of_b_on_a.verify_membership(
+            client_state_of_b_on_a.verify_membership_with_context(
+                ctx_a.get_client_validation_context(),
+                client_id_on_a,
                 conn_end_on_a.counterparty().prefix(),
                 &msg.proof_unreceived_on_b,
                 consensus_state_of_b_on_a.root(),
+                msg.proof_height_on_b,
                 Path::SeqRecv(seq_recv_path_on_b),
                 packet.seq_on_a.to_vec(),
             )
@@ -144,10 +159,13 @@ where
                 msg.packet.seq_on_a,
             );
 
-            client_state_of_b_on_a.verify_non_membership(
+            client_state_of_b_on_a.verify_non_membership_with_context(
+                ctx_a.get_client_validation_context(),
+                client_id_on_a,
                 conn_end_on_a.counterparty().prefix(),
                 &msg.proof_unreceived_on_b,
                 consensus_state_of_b_on_a.root(),
+                msg.proof_height_on_b,
                 Path::Receipt(receipt_path_on_b),
             )
         };
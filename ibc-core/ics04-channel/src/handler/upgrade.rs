@@ -0,0 +1,255 @@
+//! Helpers shared by the channel upgrade handlers, and by the packet handlers
+//! which need to take care of flushing in-flight packets while a channel is
+//! being upgraded.
+
+use ibc_core_channel_types::channel::{ChannelEnd, Order, State};
+use ibc_core_channel_types::error::ChannelError;
+use ibc_core_channel_types::events::{UpgradeError, UpgradeOpen};
+use ibc_core_channel_types::timeout::TimeoutHeight;
+use ibc_core_channel_types::upgrade::{ErrorReceipt, UpgradeFields, UpgradeTimeout};
+use ibc_core_connection::types::State as ConnectionState;
+use ibc_core_handler_types::error::ContextError;
+use ibc_core_handler_types::events::IbcEvent;
+use ibc_core_host::types::identifiers::{ChannelId, PortId};
+use ibc_core_host::types::path::{
+    ChannelEndPath, ChannelUpgradeErrorPath, ChannelUpgradePath, SeqAckPath, SeqRecvPath,
+};
+use ibc_core_host::{ExecutionContext, ValidationContext};
+use ibc_core_router::module::Module;
+use ibc_primitives::prelude::*;
+
+/// Checks that the upgrade fields proposed on this end are compatible with the
+/// ones proposed on the counterparty end.
+pub(super) fn verify_upgrade_compatibility<Ctx>(
+    ctx: &Ctx,
+    proposed_fields: &UpgradeFields,
+    counterparty_fields: &UpgradeFields,
+) -> Result<(), ContextError>
+where
+    Ctx: ValidationContext,
+{
+    if proposed_fields.ordering != counterparty_fields.ordering
+        || proposed_fields.version != counterparty_fields.version
+    {
+        return Err(ChannelError::IncompatibleCounterpartyUpgrade {
+            expected: proposed_fields.to_string(),
+            actual: counterparty_fields.to_string(),
+        }
+        .into());
+    }
+
+    let proposed_conn_end = ctx.connection_end(&proposed_fields.connection_hops[0])?;
+
+    proposed_conn_end.verify_state_matches(&ConnectionState::Open)?;
+
+    if proposed_conn_end.counterparty().connection_id()
+        != Some(&counterparty_fields.connection_hops[0])
+    {
+        return Err(ChannelError::IncompatibleCounterpartyUpgrade {
+            expected: proposed_fields.to_string(),
+            actual: counterparty_fields.to_string(),
+        }
+        .into());
+    }
+
+    Ok(())
+}
+
+/// Computes the absolute timeout given to the counterparty to complete the
+/// upgrade, starting from the current host timestamp.
+pub(super) fn upgrade_timeout<Ctx>(ctx: &Ctx) -> Result<UpgradeTimeout, ContextError>
+where
+    Ctx: ValidationContext,
+{
+    let timestamp = (ctx.host_timestamp()? + ctx.channel_upgrade_timeout()).map_err(|e| {
+        ChannelError::InvalidUpgrade {
+            reason: e.to_string(),
+        }
+    })?;
+
+    Ok(UpgradeTimeout::new(TimeoutHeight::Never, timestamp))
+}
+
+/// Aborts the ongoing upgrade of the channel end: the channel is restored to
+/// its `OPEN` state, all the upgrade related state is deleted and an error
+/// receipt is written so that the counterparty can cancel its own upgrade.
+pub(super) fn abort_upgrade<Ctx>(
+    ctx: &mut Ctx,
+    port_id: &PortId,
+    channel_id: &ChannelId,
+    message: String,
+) -> Result<(), ContextError>
+where
+    Ctx: ExecutionContext,
+{
+    let chan_end_path = ChannelEndPath::new(port_id, channel_id);
+    let mut chan_end = ctx.channel_end(&chan_end_path)?;
+    let upgrade_sequence = chan_end.upgrade_sequence();
+
+    chan_end.set_state(State::Open);
+    ctx.store_channel(&chan_end_path, chan_end.clone())?;
+
+    let upgrade_path = ChannelUpgradePath::new(port_id, channel_id);
+    ctx.delete_channel_upgrade(&upgrade_path)?;
+    ctx.delete_counterparty_channel_upgrade(&upgrade_path)?;
+
+    let error_receipt = ErrorReceipt::new(upgrade_sequence, message);
+    ctx.store_channel_upgrade_error_receipt(
+        &ChannelUpgradeErrorPath::new(port_id, channel_id),
+        error_receipt.clone(),
+    )?;
+
+    let (port_id_on_b, chan_id_on_b) = counterparty_ids(&chan_end)?;
+
+    ctx.emit_ibc_event(IbcEvent::UpgradeErrorChannel(UpgradeError::new(
+        port_id.clone(),
+        channel_id.clone(),
+        port_id_on_b,
+        chan_id_on_b,
+        upgrade_sequence,
+        error_receipt.message,
+    )))?;
+
+    Ok(())
+}
+
+/// Called by the acknowledgement and timeout handlers once a packet was
+/// removed from a `FLUSHING` channel. Aborts the upgrade if the counterparty
+/// timeout elapsed, otherwise moves the channel to `FLUSHCOMPLETE` once all
+/// in-flight packets were flushed.
+pub(super) fn handle_flush_state<Ctx>(
+    ctx: &mut Ctx,
+    port_id: &PortId,
+    channel_id: &ChannelId,
+) -> Result<(), ContextError>
+where
+    Ctx: ExecutionContext,
+{
+    let upgrade_path = ChannelUpgradePath::new(port_id, channel_id);
+
+    // The counterparty upgrade is only known once the `ChanUpgradeAck` or the
+    // `ChanUpgradeConfirm` message was processed.
+    let counterparty_upgrade = match ctx.counterparty_channel_upgrade(&upgrade_path) {
+        Ok(upgrade) => upgrade,
+        Err(_) => return Ok(()),
+    };
+
+    if counterparty_upgrade
+        .timeout
+        .has_expired(ctx.host_height()?, ctx.host_timestamp()?)
+    {
+        abort_upgrade(
+            ctx,
+            port_id,
+            channel_id,
+            "counterparty upgrade timeout elapsed while flushing packets".to_string(),
+        )?;
+    } else if !ctx.has_inflight_packets(port_id, channel_id)? {
+        let chan_end_path = ChannelEndPath::new(port_id, channel_id);
+        let mut chan_end = ctx.channel_end(&chan_end_path)?;
+        chan_end.set_state(State::FlushComplete);
+        ctx.store_channel(&chan_end_path, chan_end)?;
+    }
+
+    Ok(())
+}
+
+/// Switches the channel end to the upgraded fields and sets it back to `OPEN`,
+/// then notifies the application.
+pub(super) fn open_upgrade<Ctx>(
+    ctx: &mut Ctx,
+    module: &mut dyn Module,
+    port_id: &PortId,
+    channel_id: &ChannelId,
+) -> Result<(), ContextError>
+where
+    Ctx: ExecutionContext,
+{
+    let chan_end_path = ChannelEndPath::new(port_id, channel_id);
+    let chan_end = ctx.channel_end(&chan_end_path)?;
+    let upgrade_path = ChannelUpgradePath::new(port_id, channel_id);
+    let upgrade = ctx.channel_upgrade(&upgrade_path)?;
+    let counterparty_upgrade = ctx.counterparty_channel_upgrade(&upgrade_path)?;
+    let fields = upgrade.fields;
+
+    let extras = module.on_chan_upgrade_open_execute(
+        port_id,
+        channel_id,
+        fields.ordering,
+        &fields.connection_hops,
+        &fields.version,
+    )?;
+
+    // state changes
+    {
+        // All packets were flushed on both ends, hence the sequences of an
+        // ordered channel start right after the last packets sent.
+        if chan_end.ordering == Order::Unordered
+            && matches!(fields.ordering, Order::Ordered | Order::OrderedAllowTimeout)
+        {
+            ctx.store_next_sequence_recv(
+                &SeqRecvPath::new(port_id, channel_id),
+                counterparty_upgrade.next_sequence_send,
+            )?;
+            ctx.store_next_sequence_ack(
+                &SeqAckPath::new(port_id, channel_id),
+                upgrade.next_sequence_send,
+            )?;
+        }
+
+        let chan_end = {
+            let mut chan_end = chan_end.clone();
+            chan_end.set_ordering(fields.ordering);
+            chan_end.set_connection_hops(fields.connection_hops.clone());
+            chan_end.set_version(fields.version.clone());
+            chan_end.set_state(State::Open);
+            chan_end
+        };
+        ctx.store_channel(&chan_end_path, chan_end)?;
+
+        ctx.delete_channel_upgrade(&upgrade_path)?;
+        ctx.delete_counterparty_channel_upgrade(&upgrade_path)?;
+    }
+
+    // emit events and logs
+    {
+        ctx.log_message("success: channel upgrade open".to_string())?;
+
+        let (port_id_on_b, chan_id_on_b) = counterparty_ids(&chan_end)?;
+
+        ctx.emit_ibc_event(IbcEvent::UpgradeOpenChannel(UpgradeOpen::new(
+            port_id.clone(),
+            channel_id.clone(),
+            port_id_on_b,
+            chan_id_on_b,
+            State::Open,
+            chan_end.upgrade_sequence(),
+            fields.connection_hops,
+            fields.version,
+            fields.ordering,
+        )))?;
+
+        for module_event in extras.events {
+            ctx.emit_ibc_event(IbcEvent::Module(module_event))?;
+        }
+
+        for log_message in extras.log {
+            ctx.log_message(log_message)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Returns the counterparty port and channel identifiers of an established
+/// channel end.
+pub(super) fn counterparty_ids(chan_end: &ChannelEnd) -> Result<(PortId, ChannelId), ContextError> {
+    let port_id = chan_end.counterparty().port_id.clone();
+    let channel_id = chan_end
+        .counterparty()
+        .channel_id
+        .clone()
+        .ok_or(ChannelError::MissingCounterparty)?;
+
+    Ok((port_id, channel_id))
+}
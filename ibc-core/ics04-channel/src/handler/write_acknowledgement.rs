@@ -0,0 +1,168 @@
+use ibc_core_channel_types::acknowledgement::Acknowledgement;
+use ibc_core_channel_types::channel::Order;
+use ibc_core_channel_types::commitment::compute_ack_commitment;
+use ibc_core_channel_types::error::PacketError;
+use ibc_core_channel_types::events::WriteAcknowledgement;
+use ibc_core_channel_types::packet::{Packet, Receipt};
+use ibc_core_handler_types::error::ContextError;
+use ibc_core_handler_types::events::{IbcEvent, MessageEvent};
+use ibc_core_host::types::path::{AckPath, ChannelEndPath, ReceiptPath, SeqRecvPath};
+use ibc_core_host::{ExecutionContext, ValidationContext};
+use ibc_core_router::module::Module;
+use ibc_primitives::prelude::*;
+
+/// Writes the acknowledgement of a received packet, including all necessary
+/// validation.
+///
+/// This is how a module which acknowledges packets asynchronously, i.e.
+/// whose `on_recv_packet_execute` returned no acknowledgement, writes the
+/// acknowledgement once it is known.
+///
+/// Equivalent to calling [`write_acknowledgement_validate`], followed by
+/// [`write_acknowledgement_execute`]
+pub fn write_acknowledgement(
+    ctx_b: &mut impl ExecutionContext,
+    packet: Packet,
+    acknowledgement: Acknowledgement,
+) -> Result<(), ContextError> {
+    write_acknowledgement_validate(ctx_b, &packet)?;
+    write_acknowledgement_execute(ctx_b, packet, acknowledgement)
+}
+
+/// Writes the acknowledgement of a received packet on behalf of `module`, the
+/// module (or stack of middleware) bound to the destination port of the
+/// packet.
+///
+/// The acknowledgement first goes through the outbound hooks of the module,
+/// which lets the middleware of the stack wrap it, before being written as
+/// with [`write_acknowledgement`].
+pub fn write_acknowledgement_via_module(
+    ctx_b: &mut impl ExecutionContext,
+    module: &mut dyn Module,
+    packet: Packet,
+    acknowledgement: Acknowledgement,
+) -> Result<(), ContextError> {
+    let validated_ack = module.write_acknowledgement_validate(&packet, acknowledgement.clone())?;
+    write_acknowledgement_validate(ctx_b, &packet)?;
+
+    let (extras, acknowledgement) =
+        module.write_acknowledgement_execute(&packet, acknowledgement)?;
+
+    // the acknowledgement that gets written must be the one that was validated
+    if acknowledgement != validated_ack {
+        return Err(PacketError::AcknowledgementMismatch {
+            sequence: packet.seq_on_a,
+        }
+        .into());
+    }
+
+    write_acknowledgement_execute(ctx_b, packet, acknowledgement)?;
+
+    for module_event in extras.events {
+        ctx_b.emit_ibc_event(IbcEvent::Module(module_event))?;
+    }
+
+    for log_message in extras.log {
+        ctx_b.log_message(log_message)?;
+    }
+
+    Ok(())
+}
+
+/// Validate that the acknowledgement of the given packet can be written, i.e.
+/// that the packet was received and is not acknowledged yet.
+pub fn write_acknowledgement_validate(
+    ctx_b: &impl ValidationContext,
+    packet: &Packet,
+) -> Result<(), ContextError> {
+    let chan_end_path_on_b = ChannelEndPath::new(&packet.port_id_on_b, &packet.chan_id_on_b);
+    let chan_end_on_b = ctx_b.channel_end(&chan_end_path_on_b)?;
+
+    chan_end_on_b.verify_not_closed()?;
+
+    let packet_received = match chan_end_on_b.ordering {
+        Order::Unordered => {
+            let receipt_path_on_b =
+                ReceiptPath::new(&packet.port_id_on_b, &packet.chan_id_on_b, packet.seq_on_a);
+            matches!(
+                ctx_b.get_packet_receipt(&receipt_path_on_b),
+                Ok(Receipt::Ok)
+            )
+        }
+        Order::Ordered => {
+            let seq_recv_path_on_b = SeqRecvPath::new(&packet.port_id_on_b, &packet.chan_id_on_b);
+            let next_seq_recv = ctx_b.get_next_sequence_recv(&seq_recv_path_on_b)?;
+
+            packet.seq_on_a < next_seq_recv
+        }
+        Order::OrderedAllowTimeout => {
+            let seq_recv_path_on_b = SeqRecvPath::new(&packet.port_id_on_b, &packet.chan_id_on_b);
+            let next_seq_recv = ctx_b.get_next_sequence_recv(&seq_recv_path_on_b)?;
+
+            // the sequence of a packet that timed out is skipped, and marked
+            // with a timeout receipt, without the packet being received
+            let receipt_path_on_b =
+                ReceiptPath::new(&packet.port_id_on_b, &packet.chan_id_on_b, packet.seq_on_a);
+            let timed_out = matches!(
+                ctx_b.get_packet_receipt(&receipt_path_on_b),
+                Ok(Receipt::Timeout)
+            );
+
+            packet.seq_on_a < next_seq_recv && !timed_out
+        }
+        Order::None => false,
+    };
+
+    if !packet_received {
+        return Err(PacketError::PacketReceiptNotFound {
+            sequence: packet.seq_on_a,
+        }
+        .into());
+    }
+
+    let ack_path_on_b = AckPath::new(&packet.port_id_on_b, &packet.chan_id_on_b, packet.seq_on_a);
+    if ctx_b.get_packet_acknowledgement(&ack_path_on_b).is_ok() {
+        return Err(PacketError::AcknowledgementExists {
+            sequence: packet.seq_on_a,
+        }
+        .into());
+    }
+
+    Ok(())
+}
+
+/// Write the acknowledgement of the packet without any validation.
+///
+/// A prior call to [`write_acknowledgement_validate`] MUST have succeeded.
+pub fn write_acknowledgement_execute<ExecCtx>(
+    ctx_b: &mut ExecCtx,
+    packet: Packet,
+    acknowledgement: Acknowledgement,
+) -> Result<(), ContextError>
+where
+    ExecCtx: ExecutionContext,
+{
+    let ack_path_on_b = AckPath::new(&packet.port_id_on_b, &packet.chan_id_on_b, packet.seq_on_a);
+    ctx_b.store_packet_acknowledgement(
+        &ack_path_on_b,
+        compute_ack_commitment::<ExecCtx::HostFunctions>(&acknowledgement),
+    )?;
+
+    // emit events and logs
+    {
+        let chan_end_path_on_b = ChannelEndPath::new(&packet.port_id_on_b, &packet.chan_id_on_b);
+        let chan_end_on_b = ctx_b.channel_end(&chan_end_path_on_b)?;
+        let conn_id_on_b = &chan_end_on_b.connection_hops()[0];
+
+        ctx_b.log_message("success: packet write acknowledgement".to_string())?;
+        let event = IbcEvent::WriteAcknowledgement(WriteAcknowledgement::new(
+            packet,
+            acknowledgement,
+            conn_id_on_b.clone(),
+        ));
+        ctx_b.emit_ibc_event(IbcEvent::Message(MessageEvent::Channel))?;
+        ctx_b.emit_ibc_event(event)?;
+    }
+
+    Ok(())
+}
@@ -13,6 +13,7 @@ use ibc_proto::ibc::core::channel::v1::{
 use ibc_proto::Protobuf;
 
 use crate::error::ChannelError;
+use crate::upgrade::UpgradeFields;
 use crate::Version;
 
 /// A [`ChannelEnd`] along with its ID and the port it is bound to
@@ -58,6 +59,7 @@ impl TryFrom<RawIdentifiedChannel> for IdentifiedChannelEnd {
             counterparty: value.counterparty,
             connection_hops: value.connection_hops,
             version: value.version,
+            upgrade_sequence: value.upgrade_sequence,
         };
 
         Ok(IdentifiedChannelEnd {
@@ -83,6 +85,7 @@ impl From<IdentifiedChannelEnd> for RawIdentifiedChannel {
             version: value.channel_end.version.to_string(),
             port_id: value.port_id.to_string(),
             channel_id: value.channel_id.to_string(),
+            upgrade_sequence: value.channel_end.upgrade_sequence,
         }
     }
 }
@@ -101,6 +104,7 @@ impl From<IdentifiedChannelEnd> for RawIdentifiedChannel {
     derive(borsh::BorshSerialize, borsh::BorshDeserialize)
 )]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct ChannelEnd {
     pub state: State,
@@ -108,14 +112,17 @@ pub struct ChannelEnd {
     pub remote: Counterparty,
     pub connection_hops: Vec<ConnectionId>,
     pub version: Version,
+    /// The latest upgrade attempt performed by this channel. A value of 0
+    /// indicates the channel has never been upgraded.
+    pub upgrade_sequence: u64,
 }
 
 impl Display for ChannelEnd {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), FmtError> {
         write!(
             f,
-            "ChannelEnd {{ state: {}, ordering: {}, remote: {}, connection_hops: {}, version: {} }}",
-            self.state, self.ordering, self.remote, PrettySlice(&self.connection_hops), self.version
+            "ChannelEnd {{ state: {}, ordering: {}, remote: {}, connection_hops: {}, version: {}, upgrade_sequence: {} }}",
+            self.state, self.ordering, self.remote, PrettySlice(&self.connection_hops), self.version, self.upgrade_sequence
         )
     }
 }
@@ -145,7 +152,11 @@ impl TryFrom<RawChannel> for ChannelEnd {
 
         let version = value.version.into();
 
-        ChannelEnd::new(chan_state, chan_ordering, remote, connection_hops, version)
+        let mut channel_end =
+            ChannelEnd::new(chan_state, chan_ordering, remote, connection_hops, version)?;
+        channel_end.set_upgrade_sequence(value.upgrade_sequence);
+
+        Ok(channel_end)
     }
 }
 
@@ -161,6 +172,7 @@ impl From<ChannelEnd> for RawChannel {
                 .map(|v| v.as_str().to_string())
                 .collect(),
             version: value.version.to_string(),
+            upgrade_sequence: value.upgrade_sequence,
         }
     }
 }
@@ -184,6 +196,7 @@ impl ChannelEnd {
             remote,
             connection_hops,
             version,
+            upgrade_sequence: 0,
         }
     }
 
@@ -214,6 +227,18 @@ impl ChannelEnd {
         self.remote.channel_id = Some(c);
     }
 
+    pub fn set_ordering(&mut self, o: Order) {
+        self.ordering = o;
+    }
+
+    pub fn set_connection_hops(&mut self, hops: Vec<ConnectionId>) {
+        self.connection_hops = hops;
+    }
+
+    pub fn set_upgrade_sequence(&mut self, sequence: u64) {
+        self.upgrade_sequence = sequence;
+    }
+
     /// Returns `true` if this `ChannelEnd` is in state [`State::Open`].
     pub fn is_open(&self) -> bool {
         self.state == State::Open
@@ -239,6 +264,20 @@ impl ChannelEnd {
         &self.version
     }
 
+    pub fn upgrade_sequence(&self) -> u64 {
+        self.upgrade_sequence
+    }
+
+    /// Returns the fields of this channel end that may be changed during a
+    /// channel upgrade.
+    pub fn upgrade_fields(&self) -> UpgradeFields {
+        UpgradeFields::new(
+            self.ordering,
+            self.connection_hops.clone(),
+            self.version.clone(),
+        )
+    }
+
     pub fn validate_basic(&self) -> Result<(), ChannelError> {
         if self.state == State::Uninitialized {
             return Err(ChannelError::InvalidState {
@@ -268,6 +307,17 @@ impl ChannelEnd {
         Ok(())
     }
 
+    /// Checks if the state of this channel end is one of the expected states.
+    pub fn verify_state_matches_any(&self, expected: &[State]) -> Result<(), ChannelError> {
+        if !expected.contains(&self.state) {
+            return Err(ChannelError::InvalidState {
+                expected: format!("{:?}", expected),
+                actual: self.state.to_string(),
+            });
+        }
+        Ok(())
+    }
+
     /// Checks if the state of this channel end is not closed.
     pub fn verify_not_closed(&self) -> Result<(), ChannelError> {
         if self.state.eq(&State::Closed) {
@@ -313,7 +363,7 @@ impl ChannelEnd {
 
 /// Checks if the `connection_hops` has a length of `expected`.
 pub(crate) fn verify_connection_hops_length(
-    connection_hops: &Vec<ConnectionId>,
+    connection_hops: &[ConnectionId],
     expected: u64,
 ) -> Result<(), ChannelError> {
     if connection_hops.len() as u64 != expected {
@@ -338,6 +388,7 @@ pub(crate) fn verify_connection_hops_length(
     derive(borsh::BorshSerialize, borsh::BorshDeserialize)
 )]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct Counterparty {
     pub port_id: PortId,
@@ -441,6 +492,9 @@ pub enum Order {
     #[default]
     Unordered = 1isize,
     Ordered = 2isize,
+    /// Packets are delivered in order, but a packet which timed out skips its
+    /// sequence on the receiving end instead of closing the channel.
+    OrderedAllowTimeout = 3isize,
 }
 
 impl Display for Order {
@@ -457,6 +511,7 @@ impl Order {
             Self::None => "ORDER_NONE_UNSPECIFIED",
             Self::Unordered => "ORDER_UNORDERED",
             Self::Ordered => "ORDER_ORDERED",
+            Self::OrderedAllowTimeout => "ORDER_ORDERED_ALLOW_TIMEOUT",
         }
     }
 
@@ -466,8 +521,9 @@ impl Order {
             0 => Ok(Self::None),
             1 => Ok(Self::Unordered),
             2 => Ok(Self::Ordered),
+            3 => Ok(Self::OrderedAllowTimeout),
             _ => Err(ChannelError::InvalidOrderType {
-                expected: "Must be one of 0, 1, 2".to_string(),
+                expected: "Must be one of 0, 1, 2, 3".to_string(),
                 actual: nr.to_string(),
             }),
         }
@@ -482,8 +538,10 @@ impl FromStr for Order {
             "uninitialized" => Ok(Self::None),
             "unordered" => Ok(Self::Unordered),
             "ordered" => Ok(Self::Ordered),
+            "ordered_allow_timeout" => Ok(Self::OrderedAllowTimeout),
             _ => Err(ChannelError::InvalidOrderType {
-                expected: "Must be one of 'uninitialized', 'unordered', 'ordered'".to_string(),
+                expected: "Must be one of 'uninitialized', 'unordered', 'ordered', 'ordered_allow_timeout'"
+                    .to_string(),
                 actual: s.to_string(),
             }),
         }
@@ -504,6 +562,7 @@ impl FromStr for Order {
     derive(borsh::BorshSerialize, borsh::BorshDeserialize)
 )]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub enum State {
     Uninitialized = 0isize,
@@ -511,6 +570,8 @@ pub enum State {
     TryOpen = 2isize,
     Open = 3isize,
     Closed = 4isize,
+    Flushing = 5isize,
+    FlushComplete = 6isize,
 }
 
 impl State {
@@ -522,6 +583,8 @@ impl State {
             Self::TryOpen => "TRYOPEN",
             Self::Open => "OPEN",
             Self::Closed => "CLOSED",
+            Self::Flushing => "FLUSHING",
+            Self::FlushComplete => "FLUSHCOMPLETE",
         }
     }
 
@@ -533,8 +596,10 @@ impl State {
             2 => Ok(Self::TryOpen),
             3 => Ok(Self::Open),
             4 => Ok(Self::Closed),
+            5 => Ok(Self::Flushing),
+            6 => Ok(Self::FlushComplete),
             _ => Err(ChannelError::InvalidState {
-                expected: "Must be one of: 0, 1, 2, 3, 4".to_string(),
+                expected: "Must be one of: 0, 1, 2, 3, 4, 5, 6".to_string(),
                 actual: s.to_string(),
             }),
         }
@@ -545,6 +610,12 @@ impl State {
         self == State::Open
     }
 
+    /// Returns whether or not this channel state is part of an ongoing
+    /// upgrade handshake, i.e. `Flushing` or `FlushComplete`.
+    pub fn is_upgrading(self) -> bool {
+        matches!(self, State::Flushing | State::FlushComplete)
+    }
+
     /// Returns whether or not the channel with this state
     /// has progressed less or the same than the argument.
     ///
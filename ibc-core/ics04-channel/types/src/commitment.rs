@@ -1,5 +1,6 @@
 //! Types and utilities related to packet commitments.
 
+use ibc_core_commitment_types::merkle::HostFunctionsProvider;
 use ibc_primitives::prelude::*;
 use ibc_primitives::Timestamp;
 
@@ -82,7 +83,9 @@ impl From<Vec<u8>> for AcknowledgementCommitment {
 /// `{revision_number: 0, revision_height: 0}` to be consistent with ibc-go,
 /// where this value is used to mean "no timeout height":
 /// <https://github.com/cosmos/ibc-go/blob/04791984b3d6c83f704c4f058e6ca0038d155d91/modules/core/04-channel/keeper/packet.go#L206>
-pub fn compute_packet_commitment(
+///
+/// The SHA-256 digests are computed with the given host functions `H`.
+pub fn compute_packet_commitment<H: HostFunctionsProvider>(
     packet_data: &[u8],
     timeout_height: &TimeoutHeight,
     timeout_timestamp: &Timestamp,
@@ -92,28 +95,26 @@ pub fn compute_packet_commitment(
     hash_input[..8].copy_from_slice(&timeout_timestamp.nanoseconds().to_be_bytes());
     hash_input[8..16].copy_from_slice(&timeout_height.commitment_revision_number().to_be_bytes());
     hash_input[16..24].copy_from_slice(&timeout_height.commitment_revision_height().to_be_bytes());
-    hash_input[24..].copy_from_slice(&hash(packet_data));
-
-    hash(&hash_input).to_vec().into()
-}
+    hash_input[24..].copy_from_slice(&H::sha2_256(packet_data));
 
-/// Compute the commitment for an acknowledgement.
-pub fn compute_ack_commitment(ack: &Acknowledgement) -> AcknowledgementCommitment {
-    hash(ack.as_ref()).to_vec().into()
+    H::sha2_256(&hash_input).to_vec().into()
 }
 
-/// Helper function to hash a byte slice using SHA256.
+/// Compute the commitment for an acknowledgement, hashing it with the given
+/// host functions `H`.
 ///
 /// Note that computing commitments with anything other than SHA256 will
 /// break the Merkle proofs of the IBC provable store.
-fn hash(data: &[u8]) -> [u8; 32] {
-    use sha2::Digest;
-
-    sha2::Sha256::digest(data).into()
+pub fn compute_ack_commitment<H: HostFunctionsProvider>(
+    ack: &Acknowledgement,
+) -> AcknowledgementCommitment {
+    H::sha2_256(ack.as_ref()).to_vec().into()
 }
 
 #[cfg(test)]
 mod test {
+    use ibc_core_commitment_types::merkle::HostFunctionsManager;
+
     use super::*;
 
     #[test]
@@ -123,7 +124,7 @@ mod test {
             0x45, 0x79, 0x4f, 0x05, 0x9e, 0x65, 0x91, 0x08, 0x16, 0x86, 0x61, 0x26, 0xdc, 0x36,
             0x4f, 0x84, 0xcc, 0x15,
         ];
-        let actual = compute_packet_commitment(
+        let actual = compute_packet_commitment::<HostFunctionsManager>(
             "packet data".as_bytes(),
             &TimeoutHeight::At(ibc_core_client_types::Height::new(42, 24).unwrap()),
             &Timestamp::from_nanoseconds(0x42).unwrap(),
@@ -139,7 +140,7 @@ mod test {
             0xf0, 0xc9, 0x90, 0xd8,
         ];
         let ack = Acknowledgement::try_from(vec![0, 1, 2, 3]).unwrap();
-        let actual = compute_ack_commitment(&ack);
+        let actual = compute_ack_commitment::<HostFunctionsManager>(&ack);
         assert_eq!(&expected[..], actual.as_ref())
     }
 }
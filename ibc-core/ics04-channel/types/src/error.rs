@@ -6,7 +6,7 @@ use ibc_core_connection_types::error as connection_error;
 use ibc_core_host_types::error::IdentifierError;
 use ibc_core_host_types::identifiers::{ChannelId, ConnectionId, PortId, Sequence};
 use ibc_primitives::prelude::*;
-use ibc_primitives::{ParseTimestampError, Timestamp};
+use ibc_primitives::{ParseTimestampError, Signer, Timestamp};
 
 use super::channel::Counterparty;
 use super::timeout::TimeoutHeight;
@@ -69,6 +69,44 @@ pub enum ChannelError {
     InvalidIdentifier(IdentifierError),
     /// channel counter overflow error
     CounterOverflow,
+    /// invalid channel upgrade: `{reason}`
+    InvalidUpgrade { reason: String },
+    /// channel upgrade for (`{port_id}`, `{channel_id}`) not found
+    UpgradeNotFound {
+        port_id: PortId,
+        channel_id: ChannelId,
+    },
+    /// counterparty upgrade for (`{port_id}`, `{channel_id}`) not found
+    CounterpartyUpgradeNotFound {
+        port_id: PortId,
+        channel_id: ChannelId,
+    },
+    /// upgrade error receipt for (`{port_id}`, `{channel_id}`) not found
+    UpgradeErrorReceiptNotFound {
+        port_id: PortId,
+        channel_id: ChannelId,
+    },
+    /// channel upgrade for (`{port_id}`, `{channel_id}`) is already in progress
+    UpgradeInProgress {
+        port_id: PortId,
+        channel_id: ChannelId,
+    },
+    /// signer `{signer}` is not the channel upgrade authority
+    UnauthorizedUpgrade { signer: Signer },
+    /// the proposed upgrade fields are identical to the current channel fields: `{fields}`
+    UpgradeFieldsUnchanged { fields: String },
+    /// incompatible counterparty upgrade: expected `{expected}`, actual `{actual}`
+    IncompatibleCounterpartyUpgrade { expected: String, actual: String },
+    /// invalid upgrade sequence: expected `{expected}`, actual `{actual}`
+    InvalidUpgradeSequence { expected: u64, actual: u64 },
+    /// channel upgrade has timed out
+    UpgradeTimedOut,
+    /// channel upgrade timeout has not been reached
+    UpgradeTimeoutNotReached,
+    /// Error verifying channel upgrade: `{0}`
+    VerifyUpgradeFailed(client_error::ClientError),
+    /// Error verifying channel upgrade error receipt: `{0}`
+    VerifyUpgradeErrorReceiptFailed(client_error::ClientError),
     /// other error: `{description}`
     Other { description: String },
 }
@@ -116,6 +154,8 @@ pub enum PacketError {
     AcknowledgementExists { sequence: Sequence },
     /// Acknowledgment cannot be empty
     InvalidAcknowledgement,
+    /// Acknowledgement of the packet `{sequence}` differs from the validated one
+    AcknowledgementMismatch { sequence: Sequence },
     /// Acknowledgment status cannot be empty
     EmptyAcknowledgementStatus,
     /// Acknowledgment for the packet `{sequence}` not found
@@ -164,6 +204,11 @@ pub enum PacketError {
         port_id: PortId,
         channel_id: ChannelId,
     },
+    /// Packet `{sequence}` was sent by the counterparty after it started flushing (next sequence send `{counterparty_next_sequence_send}`)
+    PacketSentAfterFlushStarted {
+        sequence: Sequence,
+        counterparty_next_sequence_send: Sequence,
+    },
     /// other error: `{description}`
     Other { description: String },
 }
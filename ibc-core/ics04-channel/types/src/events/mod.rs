@@ -2,6 +2,8 @@
 
 mod channel_attributes;
 mod packet_attributes;
+mod upgrade;
+mod upgrade_attributes;
 
 use ibc_core_host_types::identifiers::{ChannelId, ConnectionId, PortId, Sequence};
 use ibc_primitives::prelude::*;
@@ -18,6 +20,7 @@ use self::packet_attributes::{
     PacketConnectionIdAttribute, PacketDataAttribute, SequenceAttribute, SrcChannelIdAttribute,
     SrcPortIdAttribute, TimeoutHeightAttribute, TimeoutTimestampAttribute,
 };
+pub use self::upgrade::*;
 use super::acknowledgement::Acknowledgement;
 use super::channel::Order;
 use super::timeout::TimeoutHeight;
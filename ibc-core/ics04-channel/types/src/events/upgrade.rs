@@ -0,0 +1,753 @@
+//! Types for the IBC events emitted during the channel upgrade handshake.
+
+use ibc_core_host_types::identifiers::{ChannelId, ConnectionId, PortId};
+use ibc_primitives::prelude::*;
+use ibc_primitives::Timestamp;
+use tendermint::abci;
+
+use super::channel_attributes::{
+    ChannelIdAttribute, CounterpartyChannelIdAttribute, CounterpartyPortIdAttribute,
+    PortIdAttribute,
+};
+use super::upgrade_attributes::{
+    ChannelStateAttribute, UpgradeConnectionHopsAttribute, UpgradeErrorReceiptAttribute,
+    UpgradeOrderingAttribute, UpgradeSequenceAttribute, UpgradeTimeoutHeightAttribute,
+    UpgradeTimeoutTimestampAttribute, UpgradeVersionAttribute,
+};
+use crate::channel::{Order, State};
+use crate::timeout::TimeoutHeight;
+use crate::Version;
+
+/// Channel upgrade event types
+const CHANNEL_UPGRADE_INIT_EVENT: &str = "channel_upgrade_init";
+const CHANNEL_UPGRADE_TRY_EVENT: &str = "channel_upgrade_try";
+const CHANNEL_UPGRADE_ACK_EVENT: &str = "channel_upgrade_ack";
+const CHANNEL_UPGRADE_CONFIRM_EVENT: &str = "channel_upgrade_confirm";
+const CHANNEL_UPGRADE_OPEN_EVENT: &str = "channel_upgrade_open";
+const CHANNEL_UPGRADE_TIMEOUT_EVENT: &str = "channel_upgrade_timeout";
+const CHANNEL_UPGRADE_CANCELLED_EVENT: &str = "channel_upgrade_cancelled";
+const CHANNEL_UPGRADE_ERROR_EVENT: &str = "channel_upgrade_error";
+
+/// Emitted when a channel upgrade is initiated on chain A.
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UpgradeInit {
+    port_id_attr_on_a: PortIdAttribute,
+    chan_id_attr_on_a: ChannelIdAttribute,
+    port_id_attr_on_b: CounterpartyPortIdAttribute,
+    chan_id_attr_on_b: CounterpartyChannelIdAttribute,
+    upgrade_sequence_attr: UpgradeSequenceAttribute,
+    upgrade_connection_hops_attr: UpgradeConnectionHopsAttribute,
+    upgrade_version_attr: UpgradeVersionAttribute,
+    upgrade_ordering_attr: UpgradeOrderingAttribute,
+}
+
+impl UpgradeInit {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        port_id_on_a: PortId,
+        chan_id_on_a: ChannelId,
+        port_id_on_b: PortId,
+        chan_id_on_b: ChannelId,
+        upgrade_sequence: u64,
+        upgrade_connection_hops: Vec<ConnectionId>,
+        upgrade_version: Version,
+        upgrade_ordering: Order,
+    ) -> Self {
+        Self {
+            port_id_attr_on_a: port_id_on_a.into(),
+            chan_id_attr_on_a: chan_id_on_a.into(),
+            port_id_attr_on_b: port_id_on_b.into(),
+            chan_id_attr_on_b: chan_id_on_b.into(),
+            upgrade_sequence_attr: upgrade_sequence.into(),
+            upgrade_connection_hops_attr: upgrade_connection_hops.into(),
+            upgrade_version_attr: upgrade_version.into(),
+            upgrade_ordering_attr: upgrade_ordering.into(),
+        }
+    }
+    pub fn port_id_on_a(&self) -> &PortId {
+        &self.port_id_attr_on_a.port_id
+    }
+    pub fn chan_id_on_a(&self) -> &ChannelId {
+        &self.chan_id_attr_on_a.channel_id
+    }
+    pub fn port_id_on_b(&self) -> &PortId {
+        &self.port_id_attr_on_b.counterparty_port_id
+    }
+    pub fn chan_id_on_b(&self) -> &ChannelId {
+        &self.chan_id_attr_on_b.counterparty_channel_id
+    }
+    pub fn upgrade_sequence(&self) -> u64 {
+        self.upgrade_sequence_attr.upgrade_sequence
+    }
+    pub fn upgrade_connection_hops(&self) -> &[ConnectionId] {
+        &self.upgrade_connection_hops_attr.upgrade_connection_hops
+    }
+    pub fn upgrade_version(&self) -> &Version {
+        &self.upgrade_version_attr.upgrade_version
+    }
+    pub fn upgrade_ordering(&self) -> Order {
+        self.upgrade_ordering_attr.upgrade_ordering
+    }
+
+    pub fn event_type(&self) -> &str {
+        CHANNEL_UPGRADE_INIT_EVENT
+    }
+}
+
+impl From<UpgradeInit> for abci::Event {
+    fn from(u: UpgradeInit) -> Self {
+        abci::Event {
+            kind: CHANNEL_UPGRADE_INIT_EVENT.to_string(),
+            attributes: vec![
+                u.port_id_attr_on_a.into(),
+                u.chan_id_attr_on_a.into(),
+                u.port_id_attr_on_b.into(),
+                u.chan_id_attr_on_b.into(),
+                u.upgrade_sequence_attr.into(),
+                u.upgrade_connection_hops_attr.into(),
+                u.upgrade_version_attr.into(),
+                u.upgrade_ordering_attr.into(),
+            ],
+        }
+    }
+}
+
+/// Emitted when chain B agrees to a channel upgrade proposed by chain A.
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UpgradeTry {
+    port_id_attr_on_b: PortIdAttribute,
+    chan_id_attr_on_b: ChannelIdAttribute,
+    port_id_attr_on_a: CounterpartyPortIdAttribute,
+    chan_id_attr_on_a: CounterpartyChannelIdAttribute,
+    upgrade_sequence_attr: UpgradeSequenceAttribute,
+    upgrade_connection_hops_attr: UpgradeConnectionHopsAttribute,
+    upgrade_version_attr: UpgradeVersionAttribute,
+    upgrade_ordering_attr: UpgradeOrderingAttribute,
+}
+
+impl UpgradeTry {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        port_id_on_b: PortId,
+        chan_id_on_b: ChannelId,
+        port_id_on_a: PortId,
+        chan_id_on_a: ChannelId,
+        upgrade_sequence: u64,
+        upgrade_connection_hops: Vec<ConnectionId>,
+        upgrade_version: Version,
+        upgrade_ordering: Order,
+    ) -> Self {
+        Self {
+            port_id_attr_on_b: port_id_on_b.into(),
+            chan_id_attr_on_b: chan_id_on_b.into(),
+            port_id_attr_on_a: port_id_on_a.into(),
+            chan_id_attr_on_a: chan_id_on_a.into(),
+            upgrade_sequence_attr: upgrade_sequence.into(),
+            upgrade_connection_hops_attr: upgrade_connection_hops.into(),
+            upgrade_version_attr: upgrade_version.into(),
+            upgrade_ordering_attr: upgrade_ordering.into(),
+        }
+    }
+    pub fn port_id_on_b(&self) -> &PortId {
+        &self.port_id_attr_on_b.port_id
+    }
+    pub fn chan_id_on_b(&self) -> &ChannelId {
+        &self.chan_id_attr_on_b.channel_id
+    }
+    pub fn port_id_on_a(&self) -> &PortId {
+        &self.port_id_attr_on_a.counterparty_port_id
+    }
+    pub fn chan_id_on_a(&self) -> &ChannelId {
+        &self.chan_id_attr_on_a.counterparty_channel_id
+    }
+    pub fn upgrade_sequence(&self) -> u64 {
+        self.upgrade_sequence_attr.upgrade_sequence
+    }
+    pub fn upgrade_connection_hops(&self) -> &[ConnectionId] {
+        &self.upgrade_connection_hops_attr.upgrade_connection_hops
+    }
+    pub fn upgrade_version(&self) -> &Version {
+        &self.upgrade_version_attr.upgrade_version
+    }
+    pub fn upgrade_ordering(&self) -> Order {
+        self.upgrade_ordering_attr.upgrade_ordering
+    }
+
+    pub fn event_type(&self) -> &str {
+        CHANNEL_UPGRADE_TRY_EVENT
+    }
+}
+
+impl From<UpgradeTry> for abci::Event {
+    fn from(u: UpgradeTry) -> Self {
+        abci::Event {
+            kind: CHANNEL_UPGRADE_TRY_EVENT.to_string(),
+            attributes: vec![
+                u.port_id_attr_on_b.into(),
+                u.chan_id_attr_on_b.into(),
+                u.port_id_attr_on_a.into(),
+                u.chan_id_attr_on_a.into(),
+                u.upgrade_sequence_attr.into(),
+                u.upgrade_connection_hops_attr.into(),
+                u.upgrade_version_attr.into(),
+                u.upgrade_ordering_attr.into(),
+            ],
+        }
+    }
+}
+
+/// Emitted when chain A acknowledges the upgrade agreed to by chain B.
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UpgradeAck {
+    port_id_attr_on_a: PortIdAttribute,
+    chan_id_attr_on_a: ChannelIdAttribute,
+    port_id_attr_on_b: CounterpartyPortIdAttribute,
+    chan_id_attr_on_b: CounterpartyChannelIdAttribute,
+    upgrade_sequence_attr: UpgradeSequenceAttribute,
+    upgrade_connection_hops_attr: UpgradeConnectionHopsAttribute,
+    upgrade_version_attr: UpgradeVersionAttribute,
+    upgrade_ordering_attr: UpgradeOrderingAttribute,
+}
+
+impl UpgradeAck {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        port_id_on_a: PortId,
+        chan_id_on_a: ChannelId,
+        port_id_on_b: PortId,
+        chan_id_on_b: ChannelId,
+        upgrade_sequence: u64,
+        upgrade_connection_hops: Vec<ConnectionId>,
+        upgrade_version: Version,
+        upgrade_ordering: Order,
+    ) -> Self {
+        Self {
+            port_id_attr_on_a: port_id_on_a.into(),
+            chan_id_attr_on_a: chan_id_on_a.into(),
+            port_id_attr_on_b: port_id_on_b.into(),
+            chan_id_attr_on_b: chan_id_on_b.into(),
+            upgrade_sequence_attr: upgrade_sequence.into(),
+            upgrade_connection_hops_attr: upgrade_connection_hops.into(),
+            upgrade_version_attr: upgrade_version.into(),
+            upgrade_ordering_attr: upgrade_ordering.into(),
+        }
+    }
+    pub fn port_id_on_a(&self) -> &PortId {
+        &self.port_id_attr_on_a.port_id
+    }
+    pub fn chan_id_on_a(&self) -> &ChannelId {
+        &self.chan_id_attr_on_a.channel_id
+    }
+    pub fn port_id_on_b(&self) -> &PortId {
+        &self.port_id_attr_on_b.counterparty_port_id
+    }
+    pub fn chan_id_on_b(&self) -> &ChannelId {
+        &self.chan_id_attr_on_b.counterparty_channel_id
+    }
+    pub fn upgrade_sequence(&self) -> u64 {
+        self.upgrade_sequence_attr.upgrade_sequence
+    }
+    pub fn upgrade_connection_hops(&self) -> &[ConnectionId] {
+        &self.upgrade_connection_hops_attr.upgrade_connection_hops
+    }
+    pub fn upgrade_version(&self) -> &Version {
+        &self.upgrade_version_attr.upgrade_version
+    }
+    pub fn upgrade_ordering(&self) -> Order {
+        self.upgrade_ordering_attr.upgrade_ordering
+    }
+
+    pub fn event_type(&self) -> &str {
+        CHANNEL_UPGRADE_ACK_EVENT
+    }
+}
+
+impl From<UpgradeAck> for abci::Event {
+    fn from(u: UpgradeAck) -> Self {
+        abci::Event {
+            kind: CHANNEL_UPGRADE_ACK_EVENT.to_string(),
+            attributes: vec![
+                u.port_id_attr_on_a.into(),
+                u.chan_id_attr_on_a.into(),
+                u.port_id_attr_on_b.into(),
+                u.chan_id_attr_on_b.into(),
+                u.upgrade_sequence_attr.into(),
+                u.upgrade_connection_hops_attr.into(),
+                u.upgrade_version_attr.into(),
+                u.upgrade_ordering_attr.into(),
+            ],
+        }
+    }
+}
+
+/// Emitted when chain B confirms that chain A has started flushing its in-flight packets.
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UpgradeConfirm {
+    port_id_attr_on_b: PortIdAttribute,
+    chan_id_attr_on_b: ChannelIdAttribute,
+    port_id_attr_on_a: CounterpartyPortIdAttribute,
+    chan_id_attr_on_a: CounterpartyChannelIdAttribute,
+    channel_state_attr: ChannelStateAttribute,
+    upgrade_sequence_attr: UpgradeSequenceAttribute,
+}
+
+impl UpgradeConfirm {
+    pub fn new(
+        port_id_on_b: PortId,
+        chan_id_on_b: ChannelId,
+        port_id_on_a: PortId,
+        chan_id_on_a: ChannelId,
+        channel_state: State,
+        upgrade_sequence: u64,
+    ) -> Self {
+        Self {
+            port_id_attr_on_b: port_id_on_b.into(),
+            chan_id_attr_on_b: chan_id_on_b.into(),
+            port_id_attr_on_a: port_id_on_a.into(),
+            chan_id_attr_on_a: chan_id_on_a.into(),
+            channel_state_attr: channel_state.into(),
+            upgrade_sequence_attr: upgrade_sequence.into(),
+        }
+    }
+    pub fn port_id_on_b(&self) -> &PortId {
+        &self.port_id_attr_on_b.port_id
+    }
+    pub fn chan_id_on_b(&self) -> &ChannelId {
+        &self.chan_id_attr_on_b.channel_id
+    }
+    pub fn port_id_on_a(&self) -> &PortId {
+        &self.port_id_attr_on_a.counterparty_port_id
+    }
+    pub fn chan_id_on_a(&self) -> &ChannelId {
+        &self.chan_id_attr_on_a.counterparty_channel_id
+    }
+    pub fn channel_state(&self) -> State {
+        self.channel_state_attr.channel_state
+    }
+    pub fn upgrade_sequence(&self) -> u64 {
+        self.upgrade_sequence_attr.upgrade_sequence
+    }
+
+    pub fn event_type(&self) -> &str {
+        CHANNEL_UPGRADE_CONFIRM_EVENT
+    }
+}
+
+impl From<UpgradeConfirm> for abci::Event {
+    fn from(u: UpgradeConfirm) -> Self {
+        abci::Event {
+            kind: CHANNEL_UPGRADE_CONFIRM_EVENT.to_string(),
+            attributes: vec![
+                u.port_id_attr_on_b.into(),
+                u.chan_id_attr_on_b.into(),
+                u.port_id_attr_on_a.into(),
+                u.chan_id_attr_on_a.into(),
+                u.channel_state_attr.into(),
+                u.upgrade_sequence_attr.into(),
+            ],
+        }
+    }
+}
+
+/// Emitted when the upgraded channel is reopened with its new parameters.
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UpgradeOpen {
+    port_id_attr_on_a: PortIdAttribute,
+    chan_id_attr_on_a: ChannelIdAttribute,
+    port_id_attr_on_b: CounterpartyPortIdAttribute,
+    chan_id_attr_on_b: CounterpartyChannelIdAttribute,
+    channel_state_attr: ChannelStateAttribute,
+    upgrade_sequence_attr: UpgradeSequenceAttribute,
+    upgrade_connection_hops_attr: UpgradeConnectionHopsAttribute,
+    upgrade_version_attr: UpgradeVersionAttribute,
+    upgrade_ordering_attr: UpgradeOrderingAttribute,
+}
+
+impl UpgradeOpen {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        port_id_on_a: PortId,
+        chan_id_on_a: ChannelId,
+        port_id_on_b: PortId,
+        chan_id_on_b: ChannelId,
+        channel_state: State,
+        upgrade_sequence: u64,
+        upgrade_connection_hops: Vec<ConnectionId>,
+        upgrade_version: Version,
+        upgrade_ordering: Order,
+    ) -> Self {
+        Self {
+            port_id_attr_on_a: port_id_on_a.into(),
+            chan_id_attr_on_a: chan_id_on_a.into(),
+            port_id_attr_on_b: port_id_on_b.into(),
+            chan_id_attr_on_b: chan_id_on_b.into(),
+            channel_state_attr: channel_state.into(),
+            upgrade_sequence_attr: upgrade_sequence.into(),
+            upgrade_connection_hops_attr: upgrade_connection_hops.into(),
+            upgrade_version_attr: upgrade_version.into(),
+            upgrade_ordering_attr: upgrade_ordering.into(),
+        }
+    }
+    pub fn port_id_on_a(&self) -> &PortId {
+        &self.port_id_attr_on_a.port_id
+    }
+    pub fn chan_id_on_a(&self) -> &ChannelId {
+        &self.chan_id_attr_on_a.channel_id
+    }
+    pub fn port_id_on_b(&self) -> &PortId {
+        &self.port_id_attr_on_b.counterparty_port_id
+    }
+    pub fn chan_id_on_b(&self) -> &ChannelId {
+        &self.chan_id_attr_on_b.counterparty_channel_id
+    }
+    pub fn channel_state(&self) -> State {
+        self.channel_state_attr.channel_state
+    }
+    pub fn upgrade_sequence(&self) -> u64 {
+        self.upgrade_sequence_attr.upgrade_sequence
+    }
+    pub fn upgrade_connection_hops(&self) -> &[ConnectionId] {
+        &self.upgrade_connection_hops_attr.upgrade_connection_hops
+    }
+    pub fn upgrade_version(&self) -> &Version {
+        &self.upgrade_version_attr.upgrade_version
+    }
+    pub fn upgrade_ordering(&self) -> Order {
+        self.upgrade_ordering_attr.upgrade_ordering
+    }
+
+    pub fn event_type(&self) -> &str {
+        CHANNEL_UPGRADE_OPEN_EVENT
+    }
+}
+
+impl From<UpgradeOpen> for abci::Event {
+    fn from(u: UpgradeOpen) -> Self {
+        abci::Event {
+            kind: CHANNEL_UPGRADE_OPEN_EVENT.to_string(),
+            attributes: vec![
+                u.port_id_attr_on_a.into(),
+                u.chan_id_attr_on_a.into(),
+                u.port_id_attr_on_b.into(),
+                u.chan_id_attr_on_b.into(),
+                u.channel_state_attr.into(),
+                u.upgrade_sequence_attr.into(),
+                u.upgrade_connection_hops_attr.into(),
+                u.upgrade_version_attr.into(),
+                u.upgrade_ordering_attr.into(),
+            ],
+        }
+    }
+}
+
+/// Emitted when a channel upgrade is aborted because it timed out on the counterparty.
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UpgradeTimeout {
+    port_id_attr_on_a: PortIdAttribute,
+    chan_id_attr_on_a: ChannelIdAttribute,
+    port_id_attr_on_b: CounterpartyPortIdAttribute,
+    chan_id_attr_on_b: CounterpartyChannelIdAttribute,
+    upgrade_sequence_attr: UpgradeSequenceAttribute,
+    upgrade_timeout_height_attr: UpgradeTimeoutHeightAttribute,
+    upgrade_timeout_timestamp_attr: UpgradeTimeoutTimestampAttribute,
+}
+
+impl UpgradeTimeout {
+    pub fn new(
+        port_id_on_a: PortId,
+        chan_id_on_a: ChannelId,
+        port_id_on_b: PortId,
+        chan_id_on_b: ChannelId,
+        upgrade_sequence: u64,
+        upgrade_timeout_height: TimeoutHeight,
+        upgrade_timeout_timestamp: Timestamp,
+    ) -> Self {
+        Self {
+            port_id_attr_on_a: port_id_on_a.into(),
+            chan_id_attr_on_a: chan_id_on_a.into(),
+            port_id_attr_on_b: port_id_on_b.into(),
+            chan_id_attr_on_b: chan_id_on_b.into(),
+            upgrade_sequence_attr: upgrade_sequence.into(),
+            upgrade_timeout_height_attr: upgrade_timeout_height.into(),
+            upgrade_timeout_timestamp_attr: upgrade_timeout_timestamp.into(),
+        }
+    }
+    pub fn port_id_on_a(&self) -> &PortId {
+        &self.port_id_attr_on_a.port_id
+    }
+    pub fn chan_id_on_a(&self) -> &ChannelId {
+        &self.chan_id_attr_on_a.channel_id
+    }
+    pub fn port_id_on_b(&self) -> &PortId {
+        &self.port_id_attr_on_b.counterparty_port_id
+    }
+    pub fn chan_id_on_b(&self) -> &ChannelId {
+        &self.chan_id_attr_on_b.counterparty_channel_id
+    }
+    pub fn upgrade_sequence(&self) -> u64 {
+        self.upgrade_sequence_attr.upgrade_sequence
+    }
+    pub fn upgrade_timeout_height(&self) -> TimeoutHeight {
+        self.upgrade_timeout_height_attr.upgrade_timeout_height
+    }
+    pub fn upgrade_timeout_timestamp(&self) -> Timestamp {
+        self.upgrade_timeout_timestamp_attr
+            .upgrade_timeout_timestamp
+    }
+
+    pub fn event_type(&self) -> &str {
+        CHANNEL_UPGRADE_TIMEOUT_EVENT
+    }
+}
+
+impl From<UpgradeTimeout> for abci::Event {
+    fn from(u: UpgradeTimeout) -> Self {
+        abci::Event {
+            kind: CHANNEL_UPGRADE_TIMEOUT_EVENT.to_string(),
+            attributes: vec![
+                u.port_id_attr_on_a.into(),
+                u.chan_id_attr_on_a.into(),
+                u.port_id_attr_on_b.into(),
+                u.chan_id_attr_on_b.into(),
+                u.upgrade_sequence_attr.into(),
+                u.upgrade_timeout_height_attr.into(),
+                u.upgrade_timeout_timestamp_attr.into(),
+            ],
+        }
+    }
+}
+
+/// Emitted when a channel upgrade is cancelled.
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UpgradeCancelled {
+    port_id_attr_on_a: PortIdAttribute,
+    chan_id_attr_on_a: ChannelIdAttribute,
+    port_id_attr_on_b: CounterpartyPortIdAttribute,
+    chan_id_attr_on_b: CounterpartyChannelIdAttribute,
+    upgrade_sequence_attr: UpgradeSequenceAttribute,
+}
+
+impl UpgradeCancelled {
+    pub fn new(
+        port_id_on_a: PortId,
+        chan_id_on_a: ChannelId,
+        port_id_on_b: PortId,
+        chan_id_on_b: ChannelId,
+        upgrade_sequence: u64,
+    ) -> Self {
+        Self {
+            port_id_attr_on_a: port_id_on_a.into(),
+            chan_id_attr_on_a: chan_id_on_a.into(),
+            port_id_attr_on_b: port_id_on_b.into(),
+            chan_id_attr_on_b: chan_id_on_b.into(),
+            upgrade_sequence_attr: upgrade_sequence.into(),
+        }
+    }
+    pub fn port_id_on_a(&self) -> &PortId {
+        &self.port_id_attr_on_a.port_id
+    }
+    pub fn chan_id_on_a(&self) -> &ChannelId {
+        &self.chan_id_attr_on_a.channel_id
+    }
+    pub fn port_id_on_b(&self) -> &PortId {
+        &self.port_id_attr_on_b.counterparty_port_id
+    }
+    pub fn chan_id_on_b(&self) -> &ChannelId {
+        &self.chan_id_attr_on_b.counterparty_channel_id
+    }
+    pub fn upgrade_sequence(&self) -> u64 {
+        self.upgrade_sequence_attr.upgrade_sequence
+    }
+
+    pub fn event_type(&self) -> &str {
+        CHANNEL_UPGRADE_CANCELLED_EVENT
+    }
+}
+
+impl From<UpgradeCancelled> for abci::Event {
+    fn from(u: UpgradeCancelled) -> Self {
+        abci::Event {
+            kind: CHANNEL_UPGRADE_CANCELLED_EVENT.to_string(),
+            attributes: vec![
+                u.port_id_attr_on_a.into(),
+                u.chan_id_attr_on_a.into(),
+                u.port_id_attr_on_b.into(),
+                u.chan_id_attr_on_b.into(),
+                u.upgrade_sequence_attr.into(),
+            ],
+        }
+    }
+}
+
+/// Emitted when an error receipt is written for an aborted channel upgrade.
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UpgradeError {
+    port_id_attr_on_a: PortIdAttribute,
+    chan_id_attr_on_a: ChannelIdAttribute,
+    port_id_attr_on_b: CounterpartyPortIdAttribute,
+    chan_id_attr_on_b: CounterpartyChannelIdAttribute,
+    upgrade_sequence_attr: UpgradeSequenceAttribute,
+    upgrade_error_receipt_attr: UpgradeErrorReceiptAttribute,
+}
+
+impl UpgradeError {
+    pub fn new(
+        port_id_on_a: PortId,
+        chan_id_on_a: ChannelId,
+        port_id_on_b: PortId,
+        chan_id_on_b: ChannelId,
+        upgrade_sequence: u64,
+        upgrade_error_receipt: String,
+    ) -> Self {
+        Self {
+            port_id_attr_on_a: port_id_on_a.into(),
+            chan_id_attr_on_a: chan_id_on_a.into(),
+            port_id_attr_on_b: port_id_on_b.into(),
+            chan_id_attr_on_b: chan_id_on_b.into(),
+            upgrade_sequence_attr: upgrade_sequence.into(),
+            upgrade_error_receipt_attr: upgrade_error_receipt.into(),
+        }
+    }
+    pub fn port_id_on_a(&self) -> &PortId {
+        &self.port_id_attr_on_a.port_id
+    }
+    pub fn chan_id_on_a(&self) -> &ChannelId {
+        &self.chan_id_attr_on_a.channel_id
+    }
+    pub fn port_id_on_b(&self) -> &PortId {
+        &self.port_id_attr_on_b.counterparty_port_id
+    }
+    pub fn chan_id_on_b(&self) -> &ChannelId {
+        &self.chan_id_attr_on_b.counterparty_channel_id
+    }
+    pub fn upgrade_sequence(&self) -> u64 {
+        self.upgrade_sequence_attr.upgrade_sequence
+    }
+    pub fn upgrade_error_receipt(&self) -> &str {
+        &self.upgrade_error_receipt_attr.upgrade_error_receipt
+    }
+
+    pub fn event_type(&self) -> &str {
+        CHANNEL_UPGRADE_ERROR_EVENT
+    }
+}
+
+impl From<UpgradeError> for abci::Event {
+    fn from(u: UpgradeError) -> Self {
+        abci::Event {
+            kind: CHANNEL_UPGRADE_ERROR_EVENT.to_string(),
+            attributes: vec![
+                u.port_id_attr_on_a.into(),
+                u.chan_id_attr_on_a.into(),
+                u.port_id_attr_on_b.into(),
+                u.chan_id_attr_on_b.into(),
+                u.upgrade_sequence_attr.into(),
+                u.upgrade_error_receipt_attr.into(),
+            ],
+        }
+    }
+}
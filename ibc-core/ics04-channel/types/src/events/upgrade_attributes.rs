@@ -0,0 +1,239 @@
+//! This module holds all the abci event attributes for IBC events emitted
+//! during the channel upgrade handshake.
+use derive_more::From;
+use ibc_core_host_types::identifiers::ConnectionId;
+use ibc_primitives::prelude::*;
+use ibc_primitives::Timestamp;
+use tendermint::abci;
+
+use crate::channel::{Order, State};
+use crate::timeout::TimeoutHeight;
+use crate::Version;
+
+const UPGRADE_SEQUENCE_ATTRIBUTE_KEY: &str = "upgrade_sequence";
+const UPGRADE_CONNECTION_HOPS_ATTRIBUTE_KEY: &str = "upgrade_connection_hops";
+const UPGRADE_VERSION_ATTRIBUTE_KEY: &str = "upgrade_version";
+const UPGRADE_ORDERING_ATTRIBUTE_KEY: &str = "upgrade_ordering";
+const CHANNEL_STATE_ATTRIBUTE_KEY: &str = "channel_state";
+const UPGRADE_TIMEOUT_HEIGHT_ATTRIBUTE_KEY: &str = "upgrade_timeout_height";
+const UPGRADE_TIMEOUT_TIMESTAMP_ATTRIBUTE_KEY: &str = "upgrade_timeout_timestamp";
+const UPGRADE_ERROR_RECEIPT_ATTRIBUTE_KEY: &str = "upgrade_error_receipt";
+
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, From, PartialEq, Eq)]
+pub struct UpgradeSequenceAttribute {
+    pub upgrade_sequence: u64,
+}
+
+impl From<UpgradeSequenceAttribute> for abci::EventAttribute {
+    fn from(attr: UpgradeSequenceAttribute) -> Self {
+        (
+            UPGRADE_SEQUENCE_ATTRIBUTE_KEY,
+            attr.upgrade_sequence.to_string(),
+        )
+            .into()
+    }
+}
+
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, From, PartialEq, Eq)]
+pub struct UpgradeConnectionHopsAttribute {
+    pub upgrade_connection_hops: Vec<ConnectionId>,
+}
+
+impl From<UpgradeConnectionHopsAttribute> for abci::EventAttribute {
+    fn from(attr: UpgradeConnectionHopsAttribute) -> Self {
+        let hops = attr
+            .upgrade_connection_hops
+            .iter()
+            .map(|conn_id| conn_id.as_str())
+            .collect::<Vec<_>>()
+            .join(",");
+        (UPGRADE_CONNECTION_HOPS_ATTRIBUTE_KEY, hops).into()
+    }
+}
+
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, From, PartialEq, Eq)]
+pub struct UpgradeVersionAttribute {
+    pub upgrade_version: Version,
+}
+
+impl From<UpgradeVersionAttribute> for abci::EventAttribute {
+    fn from(attr: UpgradeVersionAttribute) -> Self {
+        (UPGRADE_VERSION_ATTRIBUTE_KEY, attr.upgrade_version.as_str()).into()
+    }
+}
+
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, From, PartialEq, Eq)]
+pub struct UpgradeOrderingAttribute {
+    pub upgrade_ordering: Order,
+}
+
+impl From<UpgradeOrderingAttribute> for abci::EventAttribute {
+    fn from(attr: UpgradeOrderingAttribute) -> Self {
+        (
+            UPGRADE_ORDERING_ATTRIBUTE_KEY,
+            attr.upgrade_ordering.as_str(),
+        )
+            .into()
+    }
+}
+
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, From, PartialEq, Eq)]
+pub struct ChannelStateAttribute {
+    pub channel_state: State,
+}
+
+impl From<ChannelStateAttribute> for abci::EventAttribute {
+    fn from(attr: ChannelStateAttribute) -> Self {
+        (CHANNEL_STATE_ATTRIBUTE_KEY, attr.channel_state.as_string()).into()
+    }
+}
+
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, From, PartialEq, Eq)]
+pub struct UpgradeTimeoutHeightAttribute {
+    pub upgrade_timeout_height: TimeoutHeight,
+}
+
+impl From<UpgradeTimeoutHeightAttribute> for abci::EventAttribute {
+    fn from(attr: UpgradeTimeoutHeightAttribute) -> Self {
+        match attr.upgrade_timeout_height {
+            TimeoutHeight::Never => (UPGRADE_TIMEOUT_HEIGHT_ATTRIBUTE_KEY, "0-0").into(),
+            TimeoutHeight::At(height) => {
+                (UPGRADE_TIMEOUT_HEIGHT_ATTRIBUTE_KEY, height.to_string()).into()
+            }
+        }
+    }
+}
+
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, From, PartialEq, Eq)]
+pub struct UpgradeTimeoutTimestampAttribute {
+    pub upgrade_timeout_timestamp: Timestamp,
+}
+
+impl From<UpgradeTimeoutTimestampAttribute> for abci::EventAttribute {
+    fn from(attr: UpgradeTimeoutTimestampAttribute) -> Self {
+        (
+            UPGRADE_TIMEOUT_TIMESTAMP_ATTRIBUTE_KEY,
+            attr.upgrade_timeout_timestamp.nanoseconds().to_string(),
+        )
+            .into()
+    }
+}
+
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, From, PartialEq, Eq)]
+pub struct UpgradeErrorReceiptAttribute {
+    pub upgrade_error_receipt: String,
+}
+
+impl From<UpgradeErrorReceiptAttribute> for abci::EventAttribute {
+    fn from(attr: UpgradeErrorReceiptAttribute) -> Self {
+        (
+            UPGRADE_ERROR_RECEIPT_ATTRIBUTE_KEY,
+            attr.upgrade_error_receipt,
+        )
+            .into()
+    }
+}
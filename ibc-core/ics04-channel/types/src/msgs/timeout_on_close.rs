@@ -27,6 +27,8 @@ pub struct MsgTimeoutOnClose {
     pub proof_close_on_b: CommitmentProofBytes,
     pub proof_height_on_b: Height,
     pub signer: Signer,
+    /// The upgrade sequence of the channel end on chain B.
+    pub counterparty_upgrade_sequence: u64,
 }
 
 impl Protobuf<RawMsgTimeoutOnClose> for MsgTimeoutOnClose {}
@@ -58,6 +60,7 @@ impl TryFrom<RawMsgTimeoutOnClose> for MsgTimeoutOnClose {
                 .and_then(|raw_height| raw_height.try_into().ok())
                 .ok_or(PacketError::MissingHeight)?,
             signer: raw_msg.signer.into(),
+            counterparty_upgrade_sequence: raw_msg.counterparty_upgrade_sequence,
         })
     }
 }
@@ -71,6 +74,7 @@ impl From<MsgTimeoutOnClose> for RawMsgTimeoutOnClose {
             proof_height: Some(domain_msg.proof_height_on_b.into()),
             next_sequence_recv: domain_msg.next_seq_recv_on_b.into(),
             signer: domain_msg.signer.to_string(),
+            counterparty_upgrade_sequence: domain_msg.counterparty_upgrade_sequence,
         }
     }
 }
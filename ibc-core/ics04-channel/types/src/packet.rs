@@ -4,7 +4,9 @@ use ibc_core_host_types::identifiers::{ChannelId, PortId, Sequence};
 use ibc_primitives::prelude::*;
 use ibc_primitives::Expiry::Expired;
 use ibc_primitives::Timestamp;
-use ibc_proto::ibc::core::channel::v1::{Packet as RawPacket, PacketState as RawPacketState};
+use ibc_proto::ibc::core::channel::v1::{
+    Packet as RawPacket, PacketId as RawPacketId, PacketState as RawPacketState,
+};
 
 use super::timeout::TimeoutHeight;
 use crate::error::PacketError;
@@ -19,7 +21,8 @@ pub enum PacketMsgType {
     TimeoutOnClose,
 }
 
-/// Packet receipt, used over unordered channels.
+/// Packet receipt, used over unordered channels, and over ordered channels
+/// allowing timeouts to record the packets which timed out.
 #[cfg_attr(
     feature = "parity-scale-codec",
     derive(
@@ -33,9 +36,23 @@ pub enum PacketMsgType {
     derive(borsh::BorshSerialize, borsh::BorshDeserialize)
 )]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub enum Receipt {
     Ok,
+    /// The packet timed out before being received on a channel of order
+    /// `OrderedAllowTimeout`.
+    Timeout,
+}
+
+impl Receipt {
+    /// Returns the value committed to the store for the receipt, which is
+    /// proven to time out packets on channels of order `OrderedAllowTimeout`.
+    pub fn as_bytes(&self) -> &'static [u8] {
+        match self {
+            Self::Ok => &[1],
+            Self::Timeout => &[2],
+        }
+    }
 }
 
 impl core::fmt::Display for PacketMsgType {
@@ -136,7 +153,9 @@ impl Packet {
     /// Note: a timed-out packet should result in a
     /// [`MsgTimeout`](crate::msgs::MsgTimeout),
     /// instead of the common-case where it results in
-    /// [`MsgRecvPacket`](crate::msgs::MsgRecvPacket).
+    /// [`MsgRecvPacket`](crate::msgs::MsgRecvPacket). On channels of order
+    /// `OrderedAllowTimeout`, the timed-out packet is first received on the
+    /// destination chain to skip its sequence.
     pub fn timed_out(&self, dst_chain_ts: &Timestamp, dst_chain_height: Height) -> bool {
         let height_timed_out = self.timeout_height_on_b.has_expired(dst_chain_height);
 
@@ -309,3 +328,68 @@ impl From<PacketState> for RawPacketState {
         }
     }
 }
+
+/// Uniquely identifies a packet sent over a channel by its source port,
+/// source channel and sequence number.
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct PacketId {
+    pub port_id: PortId,
+    pub chan_id: ChannelId,
+    pub seq: Sequence,
+}
+
+impl PacketId {
+    pub fn new(port_id: PortId, chan_id: ChannelId, seq: Sequence) -> Self {
+        Self {
+            port_id,
+            chan_id,
+            seq,
+        }
+    }
+}
+
+impl core::fmt::Display for PacketId {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> Result<(), core::fmt::Error> {
+        write!(f, "{}/{}/{}", self.port_id, self.chan_id, self.seq)
+    }
+}
+
+impl TryFrom<RawPacketId> for PacketId {
+    type Error = PacketError;
+
+    fn try_from(raw_packet_id: RawPacketId) -> Result<Self, Self::Error> {
+        if Sequence::from(raw_packet_id.sequence).is_zero() {
+            return Err(PacketError::ZeroPacketSequence);
+        }
+
+        Ok(PacketId {
+            port_id: raw_packet_id.port_id.parse()?,
+            chan_id: raw_packet_id.channel_id.parse()?,
+            seq: Sequence::from(raw_packet_id.sequence),
+        })
+    }
+}
+
+impl From<PacketId> for RawPacketId {
+    fn from(packet_id: PacketId) -> Self {
+        Self {
+            port_id: packet_id.port_id.to_string(),
+            channel_id: packet_id.chan_id.to_string(),
+            sequence: packet_id.seq.value(),
+        }
+    }
+}
@@ -0,0 +1,357 @@
+//! Defines the domain types used during the channel upgrade handshake, as
+//! described in ICS-04.
+
+use core::fmt::{Display, Error as FmtError, Formatter};
+use core::str::FromStr;
+
+use ibc_core_client_types::Height;
+use ibc_core_host_types::identifiers::{ConnectionId, Sequence};
+use ibc_primitives::prelude::*;
+use ibc_primitives::utils::PrettySlice;
+use ibc_primitives::Timestamp;
+use ibc_proto::ibc::core::channel::v1::{
+    ErrorReceipt as RawErrorReceipt, Timeout as RawTimeout, Upgrade as RawUpgrade,
+    UpgradeFields as RawUpgradeFields,
+};
+use ibc_proto::Protobuf;
+
+use crate::channel::{verify_connection_hops_length, Order};
+use crate::error::{ChannelError, PacketError};
+use crate::timeout::TimeoutHeight;
+use crate::Version;
+
+/// The fields of a [`ChannelEnd`](crate::channel::ChannelEnd) which may be
+/// changed during a channel upgrade.
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UpgradeFields {
+    pub ordering: Order,
+    pub connection_hops: Vec<ConnectionId>,
+    pub version: Version,
+}
+
+impl UpgradeFields {
+    pub fn new(ordering: Order, connection_hops: Vec<ConnectionId>, version: Version) -> Self {
+        Self {
+            ordering,
+            connection_hops,
+            version,
+        }
+    }
+
+    /// Performs the stateless checks on the proposed upgrade fields.
+    ///
+    /// Note: Current IBC version only supports one connection hop.
+    pub fn validate_basic(&self) -> Result<(), ChannelError> {
+        if self.ordering == Order::None {
+            return Err(ChannelError::InvalidOrderType {
+                expected: "Channel ordering cannot be None".to_string(),
+                actual: self.ordering.to_string(),
+            });
+        }
+
+        verify_connection_hops_length(&self.connection_hops, 1)?;
+
+        if self.version.as_str().trim().is_empty() {
+            return Err(ChannelError::InvalidUpgrade {
+                reason: "proposed upgrade version cannot be empty".to_string(),
+            });
+        }
+
+        Ok(())
+    }
+}
+
+impl Display for UpgradeFields {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), FmtError> {
+        write!(
+            f,
+            "UpgradeFields {{ ordering: {}, connection_hops: {}, version: {} }}",
+            self.ordering,
+            PrettySlice(&self.connection_hops),
+            self.version
+        )
+    }
+}
+
+impl Protobuf<RawUpgradeFields> for UpgradeFields {}
+
+impl TryFrom<RawUpgradeFields> for UpgradeFields {
+    type Error = ChannelError;
+
+    fn try_from(raw: RawUpgradeFields) -> Result<Self, Self::Error> {
+        let connection_hops = raw
+            .connection_hops
+            .iter()
+            .map(|conn_id| ConnectionId::from_str(conn_id.as_str()))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(UpgradeFields {
+            ordering: Order::from_i32(raw.ordering)?,
+            connection_hops,
+            version: raw.version.into(),
+        })
+    }
+}
+
+impl From<UpgradeFields> for RawUpgradeFields {
+    fn from(value: UpgradeFields) -> Self {
+        RawUpgradeFields {
+            ordering: value.ordering as i32,
+            connection_hops: value
+                .connection_hops
+                .iter()
+                .map(|v| v.as_str().to_string())
+                .collect(),
+            version: value.version.to_string(),
+        }
+    }
+}
+
+/// The absolute timeout of a channel upgrade, expressed on the counterparty
+/// chain. Either of the height or the timestamp can be left unset, in which
+/// case it is ignored.
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct UpgradeTimeout {
+    pub height: TimeoutHeight,
+    pub timestamp: Timestamp,
+}
+
+impl UpgradeTimeout {
+    pub fn new(height: TimeoutHeight, timestamp: Timestamp) -> Self {
+        Self { height, timestamp }
+    }
+
+    /// Returns `true` if either the height or the timestamp is set.
+    pub fn is_set(&self) -> bool {
+        self.height.is_set() || self.timestamp.is_set()
+    }
+
+    /// Returns `true` if the upgrade timed out at the given counterparty
+    /// height and timestamp.
+    pub fn has_expired(&self, height: Height, timestamp: Timestamp) -> bool {
+        let height_expired = match self.height {
+            TimeoutHeight::At(timeout_height) => height >= timeout_height,
+            TimeoutHeight::Never => false,
+        };
+
+        let timestamp_expired = self.timestamp.is_set()
+            && timestamp.is_set()
+            && timestamp.nanoseconds() >= self.timestamp.nanoseconds();
+
+        height_expired || timestamp_expired
+    }
+}
+
+impl Display for UpgradeTimeout {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), FmtError> {
+        write!(
+            f,
+            "UpgradeTimeout {{ height: {}, timestamp: {} }}",
+            self.height, self.timestamp
+        )
+    }
+}
+
+impl Protobuf<RawTimeout> for UpgradeTimeout {}
+
+impl TryFrom<RawTimeout> for UpgradeTimeout {
+    type Error = ChannelError;
+
+    fn try_from(raw: RawTimeout) -> Result<Self, Self::Error> {
+        let height = raw
+            .height
+            .try_into()
+            .map_err(|_| ChannelError::InvalidUpgrade {
+                reason: "invalid upgrade timeout height".to_string(),
+            })?;
+
+        let timestamp = Timestamp::from_nanoseconds(raw.timestamp)
+            .map_err(PacketError::InvalidPacketTimestamp)
+            .map_err(|e| ChannelError::InvalidUpgrade {
+                reason: e.to_string(),
+            })?;
+
+        Ok(UpgradeTimeout { height, timestamp })
+    }
+}
+
+impl From<UpgradeTimeout> for RawTimeout {
+    fn from(value: UpgradeTimeout) -> Self {
+        RawTimeout {
+            height: value.height.into(),
+            timestamp: value.timestamp.nanoseconds(),
+        }
+    }
+}
+
+/// Contains the relevant information of an attempted channel upgrade: the
+/// proposed changes to the channel end, the timeout of the upgrade attempt and
+/// the next sequence to be sent on the channel at the time the upgrade
+/// started flushing.
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Upgrade {
+    pub fields: UpgradeFields,
+    pub timeout: UpgradeTimeout,
+    pub next_sequence_send: Sequence,
+}
+
+impl Upgrade {
+    pub fn new(
+        fields: UpgradeFields,
+        timeout: UpgradeTimeout,
+        next_sequence_send: Sequence,
+    ) -> Self {
+        Self {
+            fields,
+            timeout,
+            next_sequence_send,
+        }
+    }
+}
+
+impl Display for Upgrade {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), FmtError> {
+        write!(
+            f,
+            "Upgrade {{ fields: {}, timeout: {}, next_sequence_send: {} }}",
+            self.fields, self.timeout, self.next_sequence_send
+        )
+    }
+}
+
+impl Protobuf<RawUpgrade> for Upgrade {}
+
+impl TryFrom<RawUpgrade> for Upgrade {
+    type Error = ChannelError;
+
+    fn try_from(raw: RawUpgrade) -> Result<Self, Self::Error> {
+        Ok(Upgrade {
+            fields: raw
+                .fields
+                .ok_or(ChannelError::InvalidUpgrade {
+                    reason: "missing upgrade fields".to_string(),
+                })?
+                .try_into()?,
+            timeout: raw
+                .timeout
+                .map(TryInto::try_into)
+                .transpose()?
+                .unwrap_or_default(),
+            next_sequence_send: raw.next_sequence_send.into(),
+        })
+    }
+}
+
+impl From<Upgrade> for RawUpgrade {
+    fn from(value: Upgrade) -> Self {
+        RawUpgrade {
+            fields: Some(value.fields.into()),
+            timeout: Some(value.timeout.into()),
+            next_sequence_send: value.next_sequence_send.into(),
+        }
+    }
+}
+
+/// Records the upgrade sequence and the reason of a failed upgrade attempt.
+///
+/// When a channel upgrade handshake is aborted, both chains are expected to
+/// move on to the next upgrade sequence.
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ErrorReceipt {
+    pub sequence: u64,
+    pub message: String,
+}
+
+impl ErrorReceipt {
+    pub fn new(sequence: u64, message: String) -> Self {
+        Self { sequence, message }
+    }
+}
+
+impl Display for ErrorReceipt {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), FmtError> {
+        write!(
+            f,
+            "ErrorReceipt {{ sequence: {}, message: {} }}",
+            self.sequence, self.message
+        )
+    }
+}
+
+impl Protobuf<RawErrorReceipt> for ErrorReceipt {}
+
+impl TryFrom<RawErrorReceipt> for ErrorReceipt {
+    type Error = ChannelError;
+
+    fn try_from(raw: RawErrorReceipt) -> Result<Self, Self::Error> {
+        Ok(ErrorReceipt {
+            sequence: raw.sequence,
+            message: raw.message,
+        })
+    }
+}
+
+impl From<ErrorReceipt> for RawErrorReceipt {
+    fn from(value: ErrorReceipt) -> Self {
+        RawErrorReceipt {
+            sequence: value.sequence,
+            message: value.message,
+        }
+    }
+}
@@ -19,11 +19,12 @@ pub trait ValidateSelfClientContext {
         &self,
         client_state_of_host_on_counterparty: Any,
     ) -> Result<(), ContextError> {
-        let tm_client_state = ClientState::try_from(client_state_of_host_on_counterparty)
-            .map_err(|_| ConnectionError::InvalidClientState {
-                reason: "client must be a tendermint client".to_string(),
-            })
-            .map_err(ContextError::ConnectionError)?;
+        let tm_client_state: ClientState =
+            ClientState::try_from(client_state_of_host_on_counterparty)
+                .map_err(|_| ConnectionError::InvalidClientState {
+                    reason: "client must be a tendermint client".to_string(),
+                })
+                .map_err(ContextError::ConnectionError)?;
 
         let tm_client_state_inner = tm_client_state.inner();
 
@@ -3,21 +3,26 @@ use core::time::Duration;
 use ibc_core_channel_types::channel::ChannelEnd;
 use ibc_core_channel_types::commitment::{AcknowledgementCommitment, PacketCommitment};
 use ibc_core_channel_types::packet::Receipt;
-use ibc_core_client_context::client_state::ClientState;
+use ibc_core_channel_types::upgrade::{ErrorReceipt, Upgrade};
+use ibc_core_client_context::client_state::{
+    ClientState, ClientStateCommon, ClientStateValidation,
+};
 use ibc_core_client_context::consensus_state::ConsensusState;
 use ibc_core_client_context::{ClientExecutionContext, ClientValidationContext};
-use ibc_core_client_types::Height;
+use ibc_core_client_types::{AllowedClients, Height, Status, StatusDetails};
 use ibc_core_commitment_types::commitment::CommitmentPrefix;
+use ibc_core_commitment_types::merkle::HostFunctionsProvider;
 use ibc_core_connection_types::version::{
     get_compatible_versions, pick_version, Version as ConnectionVersion,
 };
 use ibc_core_connection_types::ConnectionEnd;
 use ibc_core_handler_types::error::ContextError;
 use ibc_core_handler_types::events::IbcEvent;
-use ibc_core_host_types::identifiers::{ClientId, ConnectionId, Sequence};
+use ibc_core_host_types::identifiers::{ChannelId, ClientId, ConnectionId, PortId, Sequence};
 use ibc_core_host_types::path::{
-    AckPath, ChannelEndPath, ClientConnectionPath, ClientConsensusStatePath, CommitmentPath,
-    ConnectionPath, ReceiptPath, SeqAckPath, SeqRecvPath, SeqSendPath,
+    AckPath, ChannelEndPath, ChannelUpgradeErrorPath, ChannelUpgradePath, ClientConnectionPath,
+    ClientConsensusStatePath, CommitmentPath, ConnectionPath, ReceiptPath, SeqAckPath, SeqRecvPath,
+    SeqSendPath,
 };
 use ibc_primitives::prelude::*;
 use ibc_primitives::proto::Any;
@@ -33,6 +38,12 @@ pub trait ValidationContext {
     type E: ClientExecutionContext;
     type AnyConsensusState: ConsensusState;
     type AnyClientState: ClientState<Self::V, Self::E>;
+    /// The hashing primitives used to compute packet and acknowledgement
+    /// commitments. Use [`HostFunctionsManager`] unless the host provides
+    /// faster ones, e.g. as precompiles.
+    ///
+    /// [`HostFunctionsManager`]: ibc_core_commitment_types::merkle::HostFunctionsManager
+    type HostFunctions: HostFunctionsProvider;
 
     /// Retrieve the context that implements all clients' `ValidationContext`.
     fn get_client_validation_context(&self) -> &Self::V;
@@ -73,6 +84,51 @@ pub trait ValidationContext {
     /// `ExecutionContext::increase_client_counter`.
     fn client_counter(&self) -> Result<u64, ContextError>;
 
+    /// Returns the client types which can be created and interacted with on
+    /// the host, as last set by `ExecutionContext::store_allowed_clients`.
+    ///
+    /// Hosts which do not restrict client types return
+    /// `AllowedClients::allow_all()`.
+    fn allowed_clients(&self) -> Result<AllowedClients, ContextError>;
+
+    /// Returns the status of the given client, which is
+    /// [`Status::Unauthorized`] if its client type is not allowed on the host.
+    fn client_status(
+        &self,
+        client_state: &Self::AnyClientState,
+        client_id: &ClientId,
+    ) -> Result<Status, ContextError> {
+        if !self
+            .allowed_clients()?
+            .is_allowed(&client_state.client_type())
+        {
+            return Ok(Status::Unauthorized);
+        }
+
+        Ok(client_state.status(self.get_client_validation_context(), client_id)?)
+    }
+
+    /// Returns the status of the given client, detailing why it is not
+    /// active or when it expires, as reported by
+    /// [`ClientStateValidation::status_details`].
+    fn client_status_details(
+        &self,
+        client_state: &Self::AnyClientState,
+        client_id: &ClientId,
+    ) -> Result<StatusDetails, ContextError> {
+        let mut status_details =
+            client_state.status_details(self.get_client_validation_context(), client_id)?;
+
+        if !self
+            .allowed_clients()?
+            .is_allowed(&client_state.client_type())
+        {
+            status_details.status = Status::Unauthorized;
+        }
+
+        Ok(status_details)
+    }
+
     /// Returns the ConnectionEnd for the given identifier `conn_id`.
     fn connection_end(&self, conn_id: &ConnectionId) -> Result<ConnectionEnd, ContextError>;
 
@@ -148,6 +204,67 @@ pub trait ValidationContext {
     /// `ExecutionContext::increase_channel_counter`.
     fn channel_counter(&self) -> Result<u64, ContextError>;
 
+    /// Returns the ongoing upgrade of the channel end for the given store path
+    fn channel_upgrade(&self, upgrade_path: &ChannelUpgradePath) -> Result<Upgrade, ContextError>;
+
+    /// Returns the upgrade of the counterparty channel end, as stored by the
+    /// `ChanUpgradeAck` or `ChanUpgradeConfirm` handlers while in-flight packets
+    /// are being flushed.
+    ///
+    /// Note: this is private state and is not required to be provable.
+    fn counterparty_channel_upgrade(
+        &self,
+        upgrade_path: &ChannelUpgradePath,
+    ) -> Result<Upgrade, ContextError>;
+
+    /// Returns the error receipt of the last failed upgrade of the channel end
+    /// for the given store path
+    fn channel_upgrade_error_receipt(
+        &self,
+        upgrade_error_path: &ChannelUpgradeErrorPath,
+    ) -> Result<ErrorReceipt, ContextError>;
+
+    /// Returns `true` if there is at least one packet commitment stored for the
+    /// given channel, i.e. a packet sent on the channel was neither acknowledged
+    /// nor timed out yet.
+    fn has_inflight_packets(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<bool, ContextError> {
+        self.has_inflight_packets_except(port_id, channel_id, &[])
+    }
+
+    /// Returns `true` if there is at least one packet commitment stored for the
+    /// given channel, other than the commitments of the `excluded` sequences.
+    ///
+    /// The sequences are those of the commitments which are deleted, but not
+    /// written to the store yet, e.g. within a batch of messages.
+    fn has_inflight_packets_except(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        excluded: &[Sequence],
+    ) -> Result<bool, ContextError>;
+
+    /// Returns the relative timeout given to the counterparty to complete a
+    /// channel upgrade once flushing has started.
+    ///
+    /// The default value of 10 minutes matches the one used by ibc-go.
+    fn channel_upgrade_timeout(&self) -> Duration {
+        Duration::from_secs(600)
+    }
+
+    /// Returns `true` if `signer` is the governance authority of the host,
+    /// which alone may initiate a channel upgrade, and may cancel one without
+    /// the error receipt of the counterparty.
+    ///
+    /// By default, the host has no such authority, hence its channels cannot
+    /// be upgraded.
+    fn is_channel_upgrade_authority(&self, _signer: &Signer) -> bool {
+        false
+    }
+
     /// Returns the maximum expected time per block
     fn max_expected_time_per_block(&self) -> Duration;
 
@@ -174,6 +291,13 @@ pub trait ExecutionContext: ValidationContext {
     /// Should never fail.
     fn increase_client_counter(&mut self) -> Result<(), ContextError>;
 
+    /// Stores the client types which can be created and interacted with on
+    /// the host.
+    fn store_allowed_clients(
+        &mut self,
+        allowed_clients: AllowedClients,
+    ) -> Result<(), ContextError>;
+
     /// Stores the given connection_end at path
     fn store_connection(
         &mut self,
@@ -251,6 +375,41 @@ pub trait ExecutionContext: ValidationContext {
         seq: Sequence,
     ) -> Result<(), ContextError>;
 
+    /// Stores the given upgrade of the channel end at the given store path
+    fn store_channel_upgrade(
+        &mut self,
+        upgrade_path: &ChannelUpgradePath,
+        upgrade: Upgrade,
+    ) -> Result<(), ContextError>;
+
+    /// Deletes the upgrade of the channel end at the given store path
+    fn delete_channel_upgrade(
+        &mut self,
+        upgrade_path: &ChannelUpgradePath,
+    ) -> Result<(), ContextError>;
+
+    /// Stores the given upgrade of the counterparty channel end for the
+    /// channel end at the given store path
+    fn store_counterparty_channel_upgrade(
+        &mut self,
+        upgrade_path: &ChannelUpgradePath,
+        upgrade: Upgrade,
+    ) -> Result<(), ContextError>;
+
+    /// Deletes the upgrade of the counterparty channel end stored for the
+    /// channel end at the given store path
+    fn delete_counterparty_channel_upgrade(
+        &mut self,
+        upgrade_path: &ChannelUpgradePath,
+    ) -> Result<(), ContextError>;
+
+    /// Stores the given upgrade error receipt at the given store path
+    fn store_channel_upgrade_error_receipt(
+        &mut self,
+        upgrade_error_path: &ChannelUpgradeErrorPath,
+        error_receipt: ErrorReceipt,
+    ) -> Result<(), ContextError>;
+
     /// Called upon channel identifier creation (Init or Try message processing).
     /// Increases the counter which keeps track of how many channels have been created.
     /// Should never fail.
@@ -6,6 +6,8 @@ use ibc_primitives::prelude::*;
 use crate::error::IdentifierError;
 use crate::validate::{validate_client_identifier, validate_client_type};
 
+const LOCALHOST_CLIENT_ID: &str = "09-localhost";
+
 #[cfg_attr(
     feature = "parity-scale-codec",
     derive(
@@ -57,6 +59,17 @@ impl ClientId {
         Self(client_id)
     }
 
+    /// Infallible creation of the identifier of the localhost client, of
+    /// which a host has at most one instance.
+    pub fn localhost() -> Self {
+        Self(LOCALHOST_CLIENT_ID.to_string())
+    }
+
+    /// Returns `true` if this is the identifier of the localhost client.
+    pub fn is_localhost(&self) -> bool {
+        self.0 == LOCALHOST_CLIENT_ID
+    }
+
     /// Get this identifier as a borrowed `&str`
     pub fn as_str(&self) -> &str {
         &self.0
@@ -8,6 +8,7 @@ use crate::error::IdentifierError;
 use crate::validate::validate_connection_identifier;
 
 const CONNECTION_ID_PREFIX: &str = "connection";
+const LOCALHOST_CONNECTION_ID: &str = "connection-localhost";
 
 #[cfg_attr(
     feature = "parity-scale-codec",
@@ -47,6 +48,18 @@ impl ConnectionId {
         CONNECTION_ID_PREFIX
     }
 
+    /// Infallible creation of the identifier of the sentinel connection of
+    /// the localhost client, which connects a host to itself.
+    pub fn localhost() -> Self {
+        Self(LOCALHOST_CONNECTION_ID.to_string())
+    }
+
+    /// Returns `true` if this is the identifier of the sentinel localhost
+    /// connection.
+    pub fn is_localhost(&self) -> bool {
+        self.0 == LOCALHOST_CONNECTION_ID
+    }
+
     /// Get this identifier as a borrowed `&str`
     pub fn as_str(&self) -> &str {
         &self.0
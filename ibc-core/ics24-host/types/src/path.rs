@@ -18,6 +18,10 @@ const UPGRADED_IBC_STATE: &str = "upgradedIBCState";
 const UPGRADED_CLIENT_STATE: &str = "upgradedClient";
 /// - The key identifying the upgraded consensus state
 const UPGRADED_CLIENT_CONSENSUS_STATE: &str = "upgradedConsState";
+/// ICS04 channel upgrade related path constants.
+const CHANNEL_UPGRADES: &str = "channelUpgrades";
+const UPGRADES: &str = "upgrades";
+const UPGRADE_ERROR: &str = "upgradeError";
 
 /// The Path enum abstracts out the different sub-paths.
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, From, Display)]
@@ -35,6 +39,8 @@ pub enum Path {
     Ack(AckPath),
     Receipt(ReceiptPath),
     UpgradeClient(UpgradeClientPath),
+    ChannelUpgrade(ChannelUpgradePath),
+    ChannelUpgradeError(ChannelUpgradeErrorPath),
 }
 
 #[cfg_attr(
@@ -365,6 +371,55 @@ pub enum UpgradeClientPath {
     UpgradedClientConsensusState(u64),
 }
 
+/// A path that stores the ongoing upgrade of a channel end.
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Display)]
+#[display(fmt = "{CHANNEL_UPGRADES}/{UPGRADES}/ports/{_0}/channels/{_1}")]
+pub struct ChannelUpgradePath(pub PortId, pub ChannelId);
+
+impl ChannelUpgradePath {
+    pub fn new(port_id: &PortId, channel_id: &ChannelId) -> ChannelUpgradePath {
+        ChannelUpgradePath(port_id.clone(), channel_id.clone())
+    }
+}
+
+/// A path that stores the error receipt of the last failed upgrade of a
+/// channel end.
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Display)]
+#[display(fmt = "{CHANNEL_UPGRADES}/{UPGRADE_ERROR}/ports/{_0}/channels/{_1}")]
+pub struct ChannelUpgradeErrorPath(pub PortId, pub ChannelId);
+
+impl ChannelUpgradeErrorPath {
+    pub fn new(port_id: &PortId, channel_id: &ChannelId) -> ChannelUpgradeErrorPath {
+        ChannelUpgradeErrorPath(port_id.clone(), channel_id.clone())
+    }
+}
+
 #[cfg_attr(
     feature = "parity-scale-codec",
     derive(
@@ -423,6 +478,7 @@ impl FromStr for Path {
             .or_else(|| parse_acks(&components))
             .or_else(|| parse_receipts(&components))
             .or_else(|| parse_upgrades(&components))
+            .or_else(|| parse_channel_upgrades(&components))
             .ok_or(PathError::ParseFailure {
                 path: s.to_string(),
             })
@@ -834,6 +890,42 @@ fn parse_upgrades(components: &[&str]) -> Option<Path> {
     }
 }
 
+fn parse_channel_upgrades(components: &[&str]) -> Option<Path> {
+    if components.len() != 6 {
+        return None;
+    }
+
+    let first = match components.first() {
+        Some(f) => *f,
+        None => return None,
+    };
+
+    if first != CHANNEL_UPGRADES {
+        return None;
+    }
+
+    let port = parse_ports(&components[2..=3]);
+    let channel = parse_channels(&components[4..=5]);
+
+    let port_id = if let Some(Path::Ports(PortPath(port_id))) = port {
+        port_id
+    } else {
+        return None;
+    };
+
+    let channel_id = if let Some(SubPath::Channels(channel_id)) = channel {
+        channel_id
+    } else {
+        return None;
+    };
+
+    match components[1] {
+        UPGRADES => Some(ChannelUpgradePath(port_id, channel_id).into()),
+        UPGRADE_ERROR => Some(ChannelUpgradeErrorPath(port_id, channel_id).into()),
+        _ => None,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use core::str::FromStr;
@@ -1228,4 +1320,56 @@ mod tests {
             Path::UpgradeClient(UpgradeClientPath::UpgradedClientConsensusState(0)),
         );
     }
+
+    #[test]
+    fn test_parse_channel_upgrades_fn() {
+        let path = "channelUpgrades/upgrades/ports/transfer/channels/channel-0";
+        let components: Vec<&str> = path.split('/').collect();
+
+        assert_eq!(
+            parse_channel_upgrades(&components),
+            Some(Path::ChannelUpgrade(ChannelUpgradePath(
+                PortId::transfer(),
+                ChannelId::default()
+            ))),
+        );
+
+        let path = "channelUpgrades/upgradeError/ports/transfer/channels/channel-0";
+        let components: Vec<&str> = path.split('/').collect();
+
+        assert_eq!(
+            parse_channel_upgrades(&components),
+            Some(Path::ChannelUpgradeError(ChannelUpgradeErrorPath(
+                PortId::transfer(),
+                ChannelId::default()
+            ))),
+        );
+    }
+
+    #[test]
+    fn channel_upgrade_path_parses() {
+        let path = "channelUpgrades/upgrades/ports/transfer/channels/channel-0";
+        let path = Path::from_str(path);
+
+        assert!(path.is_ok());
+        assert_eq!(
+            path.unwrap(),
+            Path::ChannelUpgrade(ChannelUpgradePath(PortId::transfer(), ChannelId::default())),
+        );
+    }
+
+    #[test]
+    fn channel_upgrade_error_path_parses() {
+        let path = "channelUpgrades/upgradeError/ports/transfer/channels/channel-0";
+        let path = Path::from_str(path);
+
+        assert!(path.is_ok());
+        assert_eq!(
+            path.unwrap(),
+            Path::ChannelUpgradeError(ChannelUpgradeErrorPath(
+                PortId::transfer(),
+                ChannelId::default()
+            )),
+        );
+    }
 }
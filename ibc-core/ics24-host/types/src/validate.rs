@@ -132,6 +132,13 @@ mod tests {
         assert!(id.is_err())
     }
 
+    #[test]
+    fn parse_localhost_identifiers() {
+        // the identifiers of the localhost client and of its sentinel connection are valid
+        assert!(validate_client_identifier("09-localhost").is_ok());
+        assert!(validate_connection_identifier("connection-localhost").is_ok());
+    }
+
     #[test]
     fn parse_connection_id_max() {
         // invalid max connection id (test string length is 65)
@@ -0,0 +1,660 @@
+//! Provides [`CachedContext`], a transactional overlay over a host's
+//! [`ExecutionContext`], used by
+//! [`dispatch_batch`](crate::entrypoint::dispatch_batch) to apply the messages
+//! of a transaction atomically.
+
+use core::time::Duration;
+
+use ibc_core_channel::types::channel::ChannelEnd;
+use ibc_core_channel::types::commitment::{AcknowledgementCommitment, PacketCommitment};
+use ibc_core_channel::types::error::{ChannelError, PacketError};
+use ibc_core_channel::types::packet::Receipt;
+use ibc_core_channel::types::upgrade::{ErrorReceipt, Upgrade};
+use ibc_core_client::context::ClientExecutionContext;
+use ibc_core_client::types::{AllowedClients, Height};
+use ibc_core_commitment_types::commitment::CommitmentPrefix;
+use ibc_core_connection::types::version::Version as ConnectionVersion;
+use ibc_core_connection::types::ConnectionEnd;
+use ibc_core_handler_types::error::ContextError;
+use ibc_core_handler_types::events::IbcEvent;
+use ibc_core_host::types::identifiers::{ChannelId, ClientId, ConnectionId, PortId, Sequence};
+use ibc_core_host::types::path::{
+    AckPath, ChannelEndPath, ChannelUpgradeErrorPath, ChannelUpgradePath, ClientConnectionPath,
+    ClientConsensusStatePath, CommitmentPath, ConnectionPath, ReceiptPath, SeqAckPath, SeqRecvPath,
+    SeqSendPath,
+};
+use ibc_core_host::{ExecutionContext, ValidationContext};
+use ibc_core_router::router::Router;
+use ibc_primitives::prelude::*;
+use ibc_primitives::proto::Any;
+use ibc_primitives::{Signer, Timestamp};
+
+/// Client execution contexts whose writes can be reverted.
+///
+/// Light clients write their states through the host's own
+/// [`ClientExecutionContext`] and read them back through client-specific
+/// contexts, so these writes cannot be buffered by [`CachedContext`]. Instead,
+/// [`dispatch_batch`](crate::entrypoint::dispatch_batch) takes a checkpoint of
+/// the client store before processing a batch and reverts to it if any message
+/// fails.
+pub trait ClientStoreCheckpoint: ClientExecutionContext {
+    /// The saved state of the client store.
+    type Checkpoint;
+
+    /// Saves the current state of the client store.
+    fn checkpoint(&self) -> Self::Checkpoint;
+
+    /// Discards every client store write made since `checkpoint` was taken.
+    fn revert(&mut self, checkpoint: Self::Checkpoint);
+}
+
+/// Routers whose application writes can be reverted.
+///
+/// Applications write their states through their own contexts, which are
+/// reached through the router, so these writes cannot be buffered by
+/// [`CachedContext`] either. Instead,
+/// [`dispatch_batch`](crate::entrypoint::dispatch_batch) takes a checkpoint of
+/// the stores of all the routed applications before processing a batch and
+/// reverts to it if any message fails.
+pub trait ModuleStoreCheckpoint: Router {
+    /// The saved state of the application stores.
+    type Checkpoint;
+
+    /// Saves the current state of the application stores.
+    fn checkpoint(&self) -> Self::Checkpoint;
+
+    /// Discards every application store write made since `checkpoint` was
+    /// taken.
+    fn revert(&mut self, checkpoint: Self::Checkpoint);
+}
+
+/// An overlay over a host [`ExecutionContext`] buffering all IBC core store
+/// writes and emitted events.
+///
+/// Reads are served from the buffer first and fall through to the host. The
+/// buffered writes only reach the host once [`CachedContext::commit`] is
+/// called; dropping the overlay discards them.
+///
+/// Note: writes to the client store go straight to the host's
+/// [`ClientExecutionContext`] (see [`ClientStoreCheckpoint`]), and so do the
+/// writes of the applications reached through the router (see
+/// [`ModuleStoreCheckpoint`]).
+#[derive(Debug)]
+pub struct CachedContext<'a, Ctx> {
+    inner: &'a mut Ctx,
+    client_counter_increments: u64,
+    allowed_clients: Option<AllowedClients>,
+    connections: BTreeMap<ConnectionPath, ConnectionEnd>,
+    client_connections: BTreeMap<ClientConnectionPath, ConnectionId>,
+    connection_counter_increments: u64,
+    channels: BTreeMap<ChannelEndPath, ChannelEnd>,
+    channel_counter_increments: u64,
+    next_sequence_send: BTreeMap<SeqSendPath, Sequence>,
+    next_sequence_recv: BTreeMap<SeqRecvPath, Sequence>,
+    next_sequence_ack: BTreeMap<SeqAckPath, Sequence>,
+    /// `None` marks a commitment deleted within the batch.
+    packet_commitments: BTreeMap<CommitmentPath, Option<PacketCommitment>>,
+    packet_receipts: BTreeMap<ReceiptPath, Receipt>,
+    /// `None` marks an acknowledgement deleted within the batch.
+    packet_acknowledgements: BTreeMap<AckPath, Option<AcknowledgementCommitment>>,
+    /// `None` marks an upgrade deleted within the batch.
+    channel_upgrades: BTreeMap<ChannelUpgradePath, Option<Upgrade>>,
+    /// `None` marks an upgrade deleted within the batch.
+    counterparty_channel_upgrades: BTreeMap<ChannelUpgradePath, Option<Upgrade>>,
+    channel_upgrade_error_receipts: BTreeMap<ChannelUpgradeErrorPath, ErrorReceipt>,
+    events: Vec<IbcEvent>,
+    logs: Vec<String>,
+}
+
+impl<'a, Ctx> CachedContext<'a, Ctx>
+where
+    Ctx: ExecutionContext,
+{
+    /// Creates an empty overlay over the given host context.
+    pub fn new(inner: &'a mut Ctx) -> Self {
+        Self {
+            inner,
+            client_counter_increments: 0,
+            allowed_clients: None,
+            connections: BTreeMap::new(),
+            client_connections: BTreeMap::new(),
+            connection_counter_increments: 0,
+            channels: BTreeMap::new(),
+            channel_counter_increments: 0,
+            next_sequence_send: BTreeMap::new(),
+            next_sequence_recv: BTreeMap::new(),
+            next_sequence_ack: BTreeMap::new(),
+            packet_commitments: BTreeMap::new(),
+            packet_receipts: BTreeMap::new(),
+            packet_acknowledgements: BTreeMap::new(),
+            channel_upgrades: BTreeMap::new(),
+            counterparty_channel_upgrades: BTreeMap::new(),
+            channel_upgrade_error_receipts: BTreeMap::new(),
+            events: Vec::new(),
+            logs: Vec::new(),
+        }
+    }
+
+    /// Returns the events emitted so far, which are only passed on to the
+    /// host on commit.
+    pub fn pending_events(&self) -> &[IbcEvent] {
+        &self.events
+    }
+
+    /// Writes all buffered state to the host, then emits the buffered events
+    /// and log messages in the order they were produced.
+    ///
+    /// The writes are applied one by one, and those already applied are not
+    /// undone if a later one fails. Hosts must therefore make sure that the
+    /// store writes of the [`ExecutionContext`] cannot fail partway through a
+    /// commit, e.g. by only failing on unrecoverable storage errors.
+    pub fn commit(self) -> Result<(), ContextError> {
+        let inner = self.inner;
+
+        for _ in 0..self.client_counter_increments {
+            inner.increase_client_counter()?;
+        }
+        for _ in 0..self.connection_counter_increments {
+            inner.increase_connection_counter()?;
+        }
+        for _ in 0..self.channel_counter_increments {
+            inner.increase_channel_counter()?;
+        }
+        if let Some(allowed_clients) = self.allowed_clients {
+            inner.store_allowed_clients(allowed_clients)?;
+        }
+        for (path, connection_end) in self.connections {
+            inner.store_connection(&path, connection_end)?;
+        }
+        for (path, conn_id) in self.client_connections {
+            inner.store_connection_to_client(&path, conn_id)?;
+        }
+        for (path, channel_end) in self.channels {
+            inner.store_channel(&path, channel_end)?;
+        }
+        for (path, seq) in self.next_sequence_send {
+            inner.store_next_sequence_send(&path, seq)?;
+        }
+        for (path, seq) in self.next_sequence_recv {
+            inner.store_next_sequence_recv(&path, seq)?;
+        }
+        for (path, seq) in self.next_sequence_ack {
+            inner.store_next_sequence_ack(&path, seq)?;
+        }
+        for (path, commitment) in self.packet_commitments {
+            match commitment {
+                Some(commitment) => inner.store_packet_commitment(&path, commitment)?,
+                None => inner.delete_packet_commitment(&path)?,
+            }
+        }
+        for (path, receipt) in self.packet_receipts {
+            inner.store_packet_receipt(&path, receipt)?;
+        }
+        for (path, ack_commitment) in self.packet_acknowledgements {
+            match ack_commitment {
+                Some(ack_commitment) => {
+                    inner.store_packet_acknowledgement(&path, ack_commitment)?
+                }
+                None => inner.delete_packet_acknowledgement(&path)?,
+            }
+        }
+        for (path, upgrade) in self.channel_upgrades {
+            match upgrade {
+                Some(upgrade) => inner.store_channel_upgrade(&path, upgrade)?,
+                None => inner.delete_channel_upgrade(&path)?,
+            }
+        }
+        for (path, upgrade) in self.counterparty_channel_upgrades {
+            match upgrade {
+                Some(upgrade) => inner.store_counterparty_channel_upgrade(&path, upgrade)?,
+                None => inner.delete_counterparty_channel_upgrade(&path)?,
+            }
+        }
+        for (path, error_receipt) in self.channel_upgrade_error_receipts {
+            inner.store_channel_upgrade_error_receipt(&path, error_receipt)?;
+        }
+        for event in self.events {
+            inner.emit_ibc_event(event)?;
+        }
+        for message in self.logs {
+            inner.log_message(message)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<'a, Ctx> ValidationContext for CachedContext<'a, Ctx>
+where
+    Ctx: ExecutionContext,
+{
+    type V = Ctx::V;
+    type E = Ctx::E;
+    type AnyConsensusState = Ctx::AnyConsensusState;
+    type AnyClientState = Ctx::AnyClientState;
+    type HostFunctions = Ctx::HostFunctions;
+
+    fn get_client_validation_context(&self) -> &Self::V {
+        self.inner.get_client_validation_context()
+    }
+
+    fn client_state(&self, client_id: &ClientId) -> Result<Self::AnyClientState, ContextError> {
+        self.inner.client_state(client_id)
+    }
+
+    fn decode_client_state(&self, client_state: Any) -> Result<Self::AnyClientState, ContextError> {
+        self.inner.decode_client_state(client_state)
+    }
+
+    fn consensus_state(
+        &self,
+        client_cons_state_path: &ClientConsensusStatePath,
+    ) -> Result<Self::AnyConsensusState, ContextError> {
+        self.inner.consensus_state(client_cons_state_path)
+    }
+
+    fn host_height(&self) -> Result<Height, ContextError> {
+        self.inner.host_height()
+    }
+
+    fn host_timestamp(&self) -> Result<Timestamp, ContextError> {
+        self.inner.host_timestamp()
+    }
+
+    fn host_consensus_state(
+        &self,
+        height: &Height,
+    ) -> Result<Self::AnyConsensusState, ContextError> {
+        self.inner.host_consensus_state(height)
+    }
+
+    fn client_counter(&self) -> Result<u64, ContextError> {
+        Ok(self.inner.client_counter()? + self.client_counter_increments)
+    }
+
+    fn allowed_clients(&self) -> Result<AllowedClients, ContextError> {
+        match &self.allowed_clients {
+            Some(allowed_clients) => Ok(allowed_clients.clone()),
+            None => self.inner.allowed_clients(),
+        }
+    }
+
+    fn connection_end(&self, conn_id: &ConnectionId) -> Result<ConnectionEnd, ContextError> {
+        match self.connections.get(&ConnectionPath::new(conn_id)) {
+            Some(connection_end) => Ok(connection_end.clone()),
+            None => self.inner.connection_end(conn_id),
+        }
+    }
+
+    fn validate_self_client(
+        &self,
+        client_state_of_host_on_counterparty: Any,
+    ) -> Result<(), ContextError> {
+        self.inner
+            .validate_self_client(client_state_of_host_on_counterparty)
+    }
+
+    fn commitment_prefix(&self) -> CommitmentPrefix {
+        self.inner.commitment_prefix()
+    }
+
+    fn connection_counter(&self) -> Result<u64, ContextError> {
+        Ok(self.inner.connection_counter()? + self.connection_counter_increments)
+    }
+
+    fn get_compatible_versions(&self) -> Vec<ConnectionVersion> {
+        self.inner.get_compatible_versions()
+    }
+
+    fn pick_version(
+        &self,
+        counterparty_candidate_versions: &[ConnectionVersion],
+    ) -> Result<ConnectionVersion, ContextError> {
+        self.inner.pick_version(counterparty_candidate_versions)
+    }
+
+    fn channel_end(&self, channel_end_path: &ChannelEndPath) -> Result<ChannelEnd, ContextError> {
+        match self.channels.get(channel_end_path) {
+            Some(channel_end) => Ok(channel_end.clone()),
+            None => self.inner.channel_end(channel_end_path),
+        }
+    }
+
+    fn get_next_sequence_send(
+        &self,
+        seq_send_path: &SeqSendPath,
+    ) -> Result<Sequence, ContextError> {
+        match self.next_sequence_send.get(seq_send_path) {
+            Some(seq) => Ok(*seq),
+            None => self.inner.get_next_sequence_send(seq_send_path),
+        }
+    }
+
+    fn get_next_sequence_recv(
+        &self,
+        seq_recv_path: &SeqRecvPath,
+    ) -> Result<Sequence, ContextError> {
+        match self.next_sequence_recv.get(seq_recv_path) {
+            Some(seq) => Ok(*seq),
+            None => self.inner.get_next_sequence_recv(seq_recv_path),
+        }
+    }
+
+    fn get_next_sequence_ack(&self, seq_ack_path: &SeqAckPath) -> Result<Sequence, ContextError> {
+        match self.next_sequence_ack.get(seq_ack_path) {
+            Some(seq) => Ok(*seq),
+            None => self.inner.get_next_sequence_ack(seq_ack_path),
+        }
+    }
+
+    fn get_packet_commitment(
+        &self,
+        commitment_path: &CommitmentPath,
+    ) -> Result<PacketCommitment, ContextError> {
+        match self.packet_commitments.get(commitment_path) {
+            Some(Some(commitment)) => Ok(commitment.clone()),
+            Some(None) => Err(PacketError::PacketCommitmentNotFound {
+                sequence: commitment_path.sequence,
+            }
+            .into()),
+            None => self.inner.get_packet_commitment(commitment_path),
+        }
+    }
+
+    fn get_packet_receipt(&self, receipt_path: &ReceiptPath) -> Result<Receipt, ContextError> {
+        match self.packet_receipts.get(receipt_path) {
+            Some(receipt) => Ok(receipt.clone()),
+            None => self.inner.get_packet_receipt(receipt_path),
+        }
+    }
+
+    fn get_packet_acknowledgement(
+        &self,
+        ack_path: &AckPath,
+    ) -> Result<AcknowledgementCommitment, ContextError> {
+        match self.packet_acknowledgements.get(ack_path) {
+            Some(Some(ack_commitment)) => Ok(ack_commitment.clone()),
+            Some(None) => Err(PacketError::PacketAcknowledgementNotFound {
+                sequence: ack_path.sequence,
+            }
+            .into()),
+            None => self.inner.get_packet_acknowledgement(ack_path),
+        }
+    }
+
+    fn channel_counter(&self) -> Result<u64, ContextError> {
+        Ok(self.inner.channel_counter()? + self.channel_counter_increments)
+    }
+
+    fn channel_upgrade(&self, upgrade_path: &ChannelUpgradePath) -> Result<Upgrade, ContextError> {
+        match self.channel_upgrades.get(upgrade_path) {
+            Some(Some(upgrade)) => Ok(upgrade.clone()),
+            Some(None) => Err(ChannelError::UpgradeNotFound {
+                port_id: upgrade_path.0.clone(),
+                channel_id: upgrade_path.1.clone(),
+            }
+            .into()),
+            None => self.inner.channel_upgrade(upgrade_path),
+        }
+    }
+
+    fn counterparty_channel_upgrade(
+        &self,
+        upgrade_path: &ChannelUpgradePath,
+    ) -> Result<Upgrade, ContextError> {
+        match self.counterparty_channel_upgrades.get(upgrade_path) {
+            Some(Some(upgrade)) => Ok(upgrade.clone()),
+            Some(None) => Err(ChannelError::CounterpartyUpgradeNotFound {
+                port_id: upgrade_path.0.clone(),
+                channel_id: upgrade_path.1.clone(),
+            }
+            .into()),
+            None => self.inner.counterparty_channel_upgrade(upgrade_path),
+        }
+    }
+
+    fn channel_upgrade_error_receipt(
+        &self,
+        upgrade_error_path: &ChannelUpgradeErrorPath,
+    ) -> Result<ErrorReceipt, ContextError> {
+        match self.channel_upgrade_error_receipts.get(upgrade_error_path) {
+            Some(error_receipt) => Ok(error_receipt.clone()),
+            None => self.inner.channel_upgrade_error_receipt(upgrade_error_path),
+        }
+    }
+
+    /// Reports in-flight packets if a commitment was stored on the channel
+    /// within the batch, and otherwise defers to the host, excluding the
+    /// commitments deleted within the batch.
+    fn has_inflight_packets_except(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        excluded: &[Sequence],
+    ) -> Result<bool, ContextError> {
+        let mut deleted_in_batch = excluded.to_vec();
+
+        for (path, commitment) in self.packet_commitments.iter() {
+            if &path.port_id != port_id || &path.channel_id != channel_id {
+                continue;
+            }
+
+            match commitment {
+                Some(_) if !excluded.contains(&path.sequence) => return Ok(true),
+                Some(_) => {}
+                None => deleted_in_batch.push(path.sequence),
+            }
+        }
+
+        self.inner
+            .has_inflight_packets_except(port_id, channel_id, &deleted_in_batch)
+    }
+
+    fn channel_upgrade_timeout(&self) -> Duration {
+        self.inner.channel_upgrade_timeout()
+    }
+
+    fn is_channel_upgrade_authority(&self, signer: &Signer) -> bool {
+        self.inner.is_channel_upgrade_authority(signer)
+    }
+
+    fn max_expected_time_per_block(&self) -> Duration {
+        self.inner.max_expected_time_per_block()
+    }
+
+    fn block_delay(&self, delay_period_time: &Duration) -> u64 {
+        self.inner.block_delay(delay_period_time)
+    }
+
+    fn validate_message_signer(&self, signer: &Signer) -> Result<(), ContextError> {
+        self.inner.validate_message_signer(signer)
+    }
+}
+
+impl<'a, Ctx> ExecutionContext for CachedContext<'a, Ctx>
+where
+    Ctx: ExecutionContext,
+{
+    fn get_client_execution_context(&mut self) -> &mut Self::E {
+        self.inner.get_client_execution_context()
+    }
+
+    fn increase_client_counter(&mut self) -> Result<(), ContextError> {
+        self.client_counter_increments += 1;
+        Ok(())
+    }
+
+    fn store_allowed_clients(
+        &mut self,
+        allowed_clients: AllowedClients,
+    ) -> Result<(), ContextError> {
+        self.allowed_clients = Some(allowed_clients);
+        Ok(())
+    }
+
+    fn store_connection(
+        &mut self,
+        connection_path: &ConnectionPath,
+        connection_end: ConnectionEnd,
+    ) -> Result<(), ContextError> {
+        self.connections
+            .insert(connection_path.clone(), connection_end);
+        Ok(())
+    }
+
+    fn store_connection_to_client(
+        &mut self,
+        client_connection_path: &ClientConnectionPath,
+        conn_id: ConnectionId,
+    ) -> Result<(), ContextError> {
+        self.client_connections
+            .insert(client_connection_path.clone(), conn_id);
+        Ok(())
+    }
+
+    fn increase_connection_counter(&mut self) -> Result<(), ContextError> {
+        self.connection_counter_increments += 1;
+        Ok(())
+    }
+
+    fn store_packet_commitment(
+        &mut self,
+        commitment_path: &CommitmentPath,
+        commitment: PacketCommitment,
+    ) -> Result<(), ContextError> {
+        self.packet_commitments
+            .insert(commitment_path.clone(), Some(commitment));
+        Ok(())
+    }
+
+    fn delete_packet_commitment(
+        &mut self,
+        commitment_path: &CommitmentPath,
+    ) -> Result<(), ContextError> {
+        self.packet_commitments
+            .insert(commitment_path.clone(), None);
+        Ok(())
+    }
+
+    fn store_packet_receipt(
+        &mut self,
+        receipt_path: &ReceiptPath,
+        receipt: Receipt,
+    ) -> Result<(), ContextError> {
+        self.packet_receipts.insert(receipt_path.clone(), receipt);
+        Ok(())
+    }
+
+    fn store_packet_acknowledgement(
+        &mut self,
+        ack_path: &AckPath,
+        ack_commitment: AcknowledgementCommitment,
+    ) -> Result<(), ContextError> {
+        self.packet_acknowledgements
+            .insert(ack_path.clone(), Some(ack_commitment));
+        Ok(())
+    }
+
+    fn delete_packet_acknowledgement(&mut self, ack_path: &AckPath) -> Result<(), ContextError> {
+        self.packet_acknowledgements.insert(ack_path.clone(), None);
+        Ok(())
+    }
+
+    fn store_channel(
+        &mut self,
+        channel_end_path: &ChannelEndPath,
+        channel_end: ChannelEnd,
+    ) -> Result<(), ContextError> {
+        self.channels.insert(channel_end_path.clone(), channel_end);
+        Ok(())
+    }
+
+    fn store_next_sequence_send(
+        &mut self,
+        seq_send_path: &SeqSendPath,
+        seq: Sequence,
+    ) -> Result<(), ContextError> {
+        self.next_sequence_send.insert(seq_send_path.clone(), seq);
+        Ok(())
+    }
+
+    fn store_next_sequence_recv(
+        &mut self,
+        seq_recv_path: &SeqRecvPath,
+        seq: Sequence,
+    ) -> Result<(), ContextError> {
+        self.next_sequence_recv.insert(seq_recv_path.clone(), seq);
+        Ok(())
+    }
+
+    fn store_next_sequence_ack(
+        &mut self,
+        seq_ack_path: &SeqAckPath,
+        seq: Sequence,
+    ) -> Result<(), ContextError> {
+        self.next_sequence_ack.insert(seq_ack_path.clone(), seq);
+        Ok(())
+    }
+
+    fn store_channel_upgrade(
+        &mut self,
+        upgrade_path: &ChannelUpgradePath,
+        upgrade: Upgrade,
+    ) -> Result<(), ContextError> {
+        self.channel_upgrades
+            .insert(upgrade_path.clone(), Some(upgrade));
+        Ok(())
+    }
+
+    fn delete_channel_upgrade(
+        &mut self,
+        upgrade_path: &ChannelUpgradePath,
+    ) -> Result<(), ContextError> {
+        self.channel_upgrades.insert(upgrade_path.clone(), None);
+        Ok(())
+    }
+
+    fn store_counterparty_channel_upgrade(
+        &mut self,
+        upgrade_path: &ChannelUpgradePath,
+        upgrade: Upgrade,
+    ) -> Result<(), ContextError> {
+        self.counterparty_channel_upgrades
+            .insert(upgrade_path.clone(), Some(upgrade));
+        Ok(())
+    }
+
+    fn delete_counterparty_channel_upgrade(
+        &mut self,
+        upgrade_path: &ChannelUpgradePath,
+    ) -> Result<(), ContextError> {
+        self.counterparty_channel_upgrades
+            .insert(upgrade_path.clone(), None);
+        Ok(())
+    }
+
+    fn store_channel_upgrade_error_receipt(
+        &mut self,
+        upgrade_error_path: &ChannelUpgradeErrorPath,
+        error_receipt: ErrorReceipt,
+    ) -> Result<(), ContextError> {
+        self.channel_upgrade_error_receipts
+            .insert(upgrade_error_path.clone(), error_receipt);
+        Ok(())
+    }
+
+    fn increase_channel_counter(&mut self) -> Result<(), ContextError> {
+        self.channel_counter_increments += 1;
+        Ok(())
+    }
+
+    fn emit_ibc_event(&mut self, event: IbcEvent) -> Result<(), ContextError> {
+        self.events.push(event);
+        Ok(())
+    }
+
+    fn log_message(&mut self, message: String) -> Result<(), ContextError> {
+        self.logs.push(message);
+        Ok(())
+    }
+}
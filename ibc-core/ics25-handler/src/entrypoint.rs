@@ -3,13 +3,20 @@ use ibc_core_channel::handler::{
     chan_close_confirm_validate, chan_close_init_execute, chan_close_init_validate,
     chan_open_ack_execute, chan_open_ack_validate, chan_open_confirm_execute,
     chan_open_confirm_validate, chan_open_init_execute, chan_open_init_validate,
-    chan_open_try_execute, chan_open_try_validate, recv_packet_execute, recv_packet_validate,
-    timeout_packet_execute, timeout_packet_validate, TimeoutMsgType,
+    chan_open_try_execute, chan_open_try_validate, chan_upgrade_ack_execute,
+    chan_upgrade_ack_validate, chan_upgrade_cancel_execute, chan_upgrade_cancel_validate,
+    chan_upgrade_confirm_execute, chan_upgrade_confirm_validate, chan_upgrade_init_execute,
+    chan_upgrade_init_validate, chan_upgrade_open_execute, chan_upgrade_open_validate,
+    chan_upgrade_timeout_execute, chan_upgrade_timeout_validate, chan_upgrade_try_execute,
+    chan_upgrade_try_validate, recv_packet_execute, recv_packet_validate, timeout_packet_execute,
+    timeout_packet_validate, TimeoutMsgType,
 };
 use ibc_core_channel::types::msgs::{
     channel_msg_to_port_id, packet_msg_to_port_id, ChannelMsg, PacketMsg,
 };
-use ibc_core_client::handler::{create_client, update_client, upgrade_client};
+use ibc_core_client::handler::{
+    create_client, recover_client, update_client, update_params, upgrade_client,
+};
 use ibc_core_client::types::msgs::{ClientMsg, MsgUpdateOrMisbehaviour};
 use ibc_core_connection::handler::{
     conn_open_ack, conn_open_confirm, conn_open_init, conn_open_try,
@@ -20,6 +27,9 @@ use ibc_core_handler_types::msgs::MsgEnvelope;
 use ibc_core_host::{ExecutionContext, ValidationContext};
 use ibc_core_router::router::Router;
 use ibc_core_router::types::error::RouterError;
+use ibc_primitives::prelude::*;
+
+use crate::cache::{CachedContext, ClientStoreCheckpoint, ModuleStoreCheckpoint};
 
 /// Entrypoint which performs both validation and message execution
 pub fn dispatch(
@@ -31,6 +41,53 @@ pub fn dispatch(
     execute(ctx, router, msg)
 }
 
+/// Entrypoint which atomically dispatches all the messages of a transaction
+///
+/// The messages are dispatched in order on top of a [`CachedContext`], so that
+/// each message observes the state transitions of the previous ones. The
+/// buffered writes and events are committed to `ctx` only if every message
+/// succeeds. Otherwise, they are discarded, the client store and the stores of
+/// the applications reached through the `router` are reverted to the state
+/// they had before the batch, and the first error is returned.
+///
+/// The client and application stores are also reverted if committing the
+/// buffered writes fails, which hosts must however rule out (see
+/// [`CachedContext::commit`]).
+pub fn dispatch_batch<Ctx, R>(
+    ctx: &mut Ctx,
+    router: &mut R,
+    msgs: Vec<MsgEnvelope>,
+) -> Result<(), ContextError>
+where
+    Ctx: ExecutionContext,
+    Ctx::E: ClientStoreCheckpoint,
+    R: ModuleStoreCheckpoint,
+{
+    let client_checkpoint = ctx.get_client_execution_context().checkpoint();
+    let module_checkpoint = router.checkpoint();
+
+    let mut cached_ctx = CachedContext::new(ctx);
+
+    let result = msgs
+        .into_iter()
+        .try_for_each(|msg| dispatch(&mut cached_ctx, router, msg));
+
+    let result = match result {
+        Ok(()) => cached_ctx.commit(),
+        Err(e) => {
+            drop(cached_ctx);
+            Err(e)
+        }
+    };
+
+    if result.is_err() {
+        ctx.get_client_execution_context().revert(client_checkpoint);
+        router.revert(module_checkpoint);
+    }
+
+    result
+}
+
 /// Entrypoint which only performs message validation
 ///
 /// If a transaction contains `n` messages `m_1` ... `m_n`, then
@@ -53,6 +110,8 @@ where
                 update_client::validate(ctx, MsgUpdateOrMisbehaviour::Misbehaviour(msg))
             }
             ClientMsg::UpgradeClient(msg) => upgrade_client::validate(ctx, msg),
+            ClientMsg::RecoverClient(msg) => recover_client::validate(ctx, msg),
+            ClientMsg::UpdateParams(msg) => update_params::validate(ctx, msg),
         },
         MsgEnvelope::Connection(msg) => match msg {
             ConnectionMsg::OpenInit(msg) => conn_open_init::validate(ctx, msg),
@@ -78,6 +137,13 @@ where
                 ChannelMsg::OpenConfirm(msg) => chan_open_confirm_validate(ctx, module, msg),
                 ChannelMsg::CloseInit(msg) => chan_close_init_validate(ctx, module, msg),
                 ChannelMsg::CloseConfirm(msg) => chan_close_confirm_validate(ctx, module, msg),
+                ChannelMsg::UpgradeInit(msg) => chan_upgrade_init_validate(ctx, module, msg),
+                ChannelMsg::UpgradeTry(msg) => chan_upgrade_try_validate(ctx, module, msg),
+                ChannelMsg::UpgradeAck(msg) => chan_upgrade_ack_validate(ctx, module, msg),
+                ChannelMsg::UpgradeConfirm(msg) => chan_upgrade_confirm_validate(ctx, module, msg),
+                ChannelMsg::UpgradeOpen(msg) => chan_upgrade_open_validate(ctx, module, msg),
+                ChannelMsg::UpgradeTimeout(msg) => chan_upgrade_timeout_validate(ctx, msg),
+                ChannelMsg::UpgradeCancel(msg) => chan_upgrade_cancel_validate(ctx, msg),
             }
         }
         MsgEnvelope::Packet(msg) => {
@@ -124,6 +190,8 @@ where
                 update_client::execute(ctx, MsgUpdateOrMisbehaviour::Misbehaviour(msg))
             }
             ClientMsg::UpgradeClient(msg) => upgrade_client::execute(ctx, msg),
+            ClientMsg::RecoverClient(msg) => recover_client::execute(ctx, msg),
+            ClientMsg::UpdateParams(msg) => update_params::execute(ctx, msg),
         },
         MsgEnvelope::Connection(msg) => match msg {
             ConnectionMsg::OpenInit(msg) => conn_open_init::execute(ctx, msg),
@@ -149,6 +217,13 @@ where
                 ChannelMsg::OpenConfirm(msg) => chan_open_confirm_execute(ctx, module, msg),
                 ChannelMsg::CloseInit(msg) => chan_close_init_execute(ctx, module, msg),
                 ChannelMsg::CloseConfirm(msg) => chan_close_confirm_execute(ctx, module, msg),
+                ChannelMsg::UpgradeInit(msg) => chan_upgrade_init_execute(ctx, module, msg),
+                ChannelMsg::UpgradeTry(msg) => chan_upgrade_try_execute(ctx, module, msg),
+                ChannelMsg::UpgradeAck(msg) => chan_upgrade_ack_execute(ctx, module, msg),
+                ChannelMsg::UpgradeConfirm(msg) => chan_upgrade_confirm_execute(ctx, module, msg),
+                ChannelMsg::UpgradeOpen(msg) => chan_upgrade_open_execute(ctx, module, msg),
+                ChannelMsg::UpgradeTimeout(msg) => chan_upgrade_timeout_execute(ctx, msg),
+                ChannelMsg::UpgradeCancel(msg) => chan_upgrade_cancel_execute(ctx, msg),
             }
         }
         MsgEnvelope::Packet(msg) => {
@@ -8,7 +8,9 @@
 //! to the context (e.g. [`ExecutionContext`](ibc_core_host::ExecutionContext))
 //! while processing `M`. If the transaction containing `M` consists of multiple
 //! messages, then typically the state modifications from all messages is
-//! expected to be rolled back as well.
+//! expected to be rolled back as well. Hosts may instead use
+//! [`dispatch_batch`](entrypoint::dispatch_batch), which only commits the state
+//! modifications of a transaction once all of its messages succeeded.
 #![no_std]
 #![forbid(unsafe_code)]
 #![cfg_attr(not(test), deny(clippy::unwrap_used))]
@@ -24,6 +26,7 @@
 #[cfg(any(test, feature = "std"))]
 extern crate std;
 
+pub mod cache;
 pub mod entrypoint;
 
 /// Re-export IBC handler types from `ibc-core-handler-types` crate.
@@ -69,6 +69,8 @@ pub enum IbcEvent {
     CreateClient(ClientEvents::CreateClient),
     UpdateClient(ClientEvents::UpdateClient),
     UpgradeClient(ClientEvents::UpgradeClient),
+    RecoverClient(ClientEvents::RecoverClient),
+    UpdateClientParams(ClientEvents::UpdateClientParams),
     ClientMisbehaviour(ClientEvents::ClientMisbehaviour),
 
     OpenInitConnection(ConnectionEvents::OpenInit),
@@ -82,6 +84,14 @@ pub enum IbcEvent {
     OpenConfirmChannel(ChannelEvents::OpenConfirm),
     CloseInitChannel(ChannelEvents::CloseInit),
     CloseConfirmChannel(ChannelEvents::CloseConfirm),
+    UpgradeInitChannel(ChannelEvents::UpgradeInit),
+    UpgradeTryChannel(ChannelEvents::UpgradeTry),
+    UpgradeAckChannel(ChannelEvents::UpgradeAck),
+    UpgradeConfirmChannel(ChannelEvents::UpgradeConfirm),
+    UpgradeOpenChannel(ChannelEvents::UpgradeOpen),
+    UpgradeTimeoutChannel(ChannelEvents::UpgradeTimeout),
+    UpgradeCancelledChannel(ChannelEvents::UpgradeCancelled),
+    UpgradeErrorChannel(ChannelEvents::UpgradeError),
 
     SendPacket(ChannelEvents::SendPacket),
     ReceivePacket(ChannelEvents::ReceivePacket),
@@ -102,6 +112,8 @@ impl TryFrom<IbcEvent> for abci::Event {
             IbcEvent::CreateClient(event) => event.into(),
             IbcEvent::UpdateClient(event) => event.into(),
             IbcEvent::UpgradeClient(event) => event.into(),
+            IbcEvent::RecoverClient(event) => event.into(),
+            IbcEvent::UpdateClientParams(event) => event.into(),
             IbcEvent::ClientMisbehaviour(event) => event.into(),
             IbcEvent::OpenInitConnection(event) => event.into(),
             IbcEvent::OpenTryConnection(event) => event.into(),
@@ -113,6 +125,14 @@ impl TryFrom<IbcEvent> for abci::Event {
             IbcEvent::OpenConfirmChannel(event) => event.into(),
             IbcEvent::CloseInitChannel(event) => event.into(),
             IbcEvent::CloseConfirmChannel(event) => event.into(),
+            IbcEvent::UpgradeInitChannel(event) => event.into(),
+            IbcEvent::UpgradeTryChannel(event) => event.into(),
+            IbcEvent::UpgradeAckChannel(event) => event.into(),
+            IbcEvent::UpgradeConfirmChannel(event) => event.into(),
+            IbcEvent::UpgradeOpenChannel(event) => event.into(),
+            IbcEvent::UpgradeTimeoutChannel(event) => event.into(),
+            IbcEvent::UpgradeCancelledChannel(event) => event.into(),
+            IbcEvent::UpgradeErrorChannel(event) => event.into(),
             IbcEvent::SendPacket(event) => event.try_into().map_err(Error::Channel)?,
             IbcEvent::ReceivePacket(event) => event.try_into().map_err(Error::Channel)?,
             IbcEvent::WriteAcknowledgement(event) => event.try_into().map_err(Error::Channel)?,
@@ -135,6 +155,8 @@ impl IbcEvent {
             IbcEvent::UpdateClient(event) => event.event_type(),
             IbcEvent::ClientMisbehaviour(event) => event.event_type(),
             IbcEvent::UpgradeClient(event) => event.event_type(),
+            IbcEvent::RecoverClient(event) => event.event_type(),
+            IbcEvent::UpdateClientParams(event) => event.event_type(),
             IbcEvent::OpenInitConnection(event) => event.event_type(),
             IbcEvent::OpenTryConnection(event) => event.event_type(),
             IbcEvent::OpenAckConnection(event) => event.event_type(),
@@ -145,6 +167,14 @@ impl IbcEvent {
             IbcEvent::OpenConfirmChannel(event) => event.event_type(),
             IbcEvent::CloseInitChannel(event) => event.event_type(),
             IbcEvent::CloseConfirmChannel(event) => event.event_type(),
+            IbcEvent::UpgradeInitChannel(event) => event.event_type(),
+            IbcEvent::UpgradeTryChannel(event) => event.event_type(),
+            IbcEvent::UpgradeAckChannel(event) => event.event_type(),
+            IbcEvent::UpgradeConfirmChannel(event) => event.event_type(),
+            IbcEvent::UpgradeOpenChannel(event) => event.event_type(),
+            IbcEvent::UpgradeTimeoutChannel(event) => event.event_type(),
+            IbcEvent::UpgradeCancelledChannel(event) => event.event_type(),
+            IbcEvent::UpgradeErrorChannel(event) => event.event_type(),
             IbcEvent::SendPacket(event) => event.event_type(),
             IbcEvent::ReceivePacket(event) => event.event_type(),
             IbcEvent::WriteAcknowledgement(event) => event.event_type(),
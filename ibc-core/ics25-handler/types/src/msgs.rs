@@ -1,14 +1,19 @@
 use ibc_core_channel_types::msgs::{
     ChannelMsg, MsgAcknowledgement, MsgChannelCloseConfirm, MsgChannelCloseInit, MsgChannelOpenAck,
-    MsgChannelOpenConfirm, MsgChannelOpenInit, MsgChannelOpenTry, MsgRecvPacket, MsgTimeout,
-    MsgTimeoutOnClose, PacketMsg, ACKNOWLEDGEMENT_TYPE_URL, CHAN_CLOSE_CONFIRM_TYPE_URL,
-    CHAN_CLOSE_INIT_TYPE_URL, CHAN_OPEN_ACK_TYPE_URL, CHAN_OPEN_CONFIRM_TYPE_URL,
-    CHAN_OPEN_INIT_TYPE_URL, CHAN_OPEN_TRY_TYPE_URL, RECV_PACKET_TYPE_URL,
-    TIMEOUT_ON_CLOSE_TYPE_URL, TIMEOUT_TYPE_URL,
+    MsgChannelOpenConfirm, MsgChannelOpenInit, MsgChannelOpenTry, MsgChannelUpgradeAck,
+    MsgChannelUpgradeCancel, MsgChannelUpgradeConfirm, MsgChannelUpgradeInit,
+    MsgChannelUpgradeOpen, MsgChannelUpgradeTimeout, MsgChannelUpgradeTry, MsgRecvPacket,
+    MsgTimeout, MsgTimeoutOnClose, PacketMsg, ACKNOWLEDGEMENT_TYPE_URL,
+    CHAN_CLOSE_CONFIRM_TYPE_URL, CHAN_CLOSE_INIT_TYPE_URL, CHAN_OPEN_ACK_TYPE_URL,
+    CHAN_OPEN_CONFIRM_TYPE_URL, CHAN_OPEN_INIT_TYPE_URL, CHAN_OPEN_TRY_TYPE_URL,
+    CHAN_UPGRADE_ACK_TYPE_URL, CHAN_UPGRADE_CANCEL_TYPE_URL, CHAN_UPGRADE_CONFIRM_TYPE_URL,
+    CHAN_UPGRADE_INIT_TYPE_URL, CHAN_UPGRADE_OPEN_TYPE_URL, CHAN_UPGRADE_TIMEOUT_TYPE_URL,
+    CHAN_UPGRADE_TRY_TYPE_URL, RECV_PACKET_TYPE_URL, TIMEOUT_ON_CLOSE_TYPE_URL, TIMEOUT_TYPE_URL,
 };
 use ibc_core_client_types::msgs::{
-    ClientMsg, MsgCreateClient, MsgSubmitMisbehaviour, MsgUpdateClient, MsgUpgradeClient,
-    CREATE_CLIENT_TYPE_URL, SUBMIT_MISBEHAVIOUR_TYPE_URL, UPDATE_CLIENT_TYPE_URL,
+    ClientMsg, MsgCreateClient, MsgRecoverClient, MsgSubmitMisbehaviour, MsgUpdateClient,
+    MsgUpdateParams, MsgUpgradeClient, CREATE_CLIENT_TYPE_URL, RECOVER_CLIENT_TYPE_URL,
+    SUBMIT_MISBEHAVIOUR_TYPE_URL, UPDATE_CLIENT_TYPE_URL, UPDATE_PARAMS_TYPE_URL,
     UPGRADE_CLIENT_TYPE_URL,
 };
 use ibc_core_connection_types::msgs::{
@@ -75,6 +80,22 @@ impl TryFrom<Any> for MsgEnvelope {
                     })?;
                 Ok(MsgEnvelope::Client(ClientMsg::Misbehaviour(domain_msg)))
             }
+            RECOVER_CLIENT_TYPE_URL => {
+                let domain_msg = MsgRecoverClient::decode_vec(&any_msg.value).map_err(|e| {
+                    RouterError::MalformedMessageBytes {
+                        reason: e.to_string(),
+                    }
+                })?;
+                Ok(MsgEnvelope::Client(ClientMsg::RecoverClient(domain_msg)))
+            }
+            UPDATE_PARAMS_TYPE_URL => {
+                let domain_msg = MsgUpdateParams::decode_vec(&any_msg.value).map_err(|e| {
+                    RouterError::MalformedMessageBytes {
+                        reason: e.to_string(),
+                    }
+                })?;
+                Ok(MsgEnvelope::Client(ClientMsg::UpdateParams(domain_msg)))
+            }
 
             // ICS03
             CONN_OPEN_INIT_TYPE_URL => {
@@ -165,6 +186,67 @@ impl TryFrom<Any> for MsgEnvelope {
                     })?;
                 Ok(MsgEnvelope::Channel(ChannelMsg::CloseConfirm(domain_msg)))
             }
+            CHAN_UPGRADE_INIT_TYPE_URL => {
+                let domain_msg =
+                    MsgChannelUpgradeInit::decode_vec(&any_msg.value).map_err(|e| {
+                        RouterError::MalformedMessageBytes {
+                            reason: e.to_string(),
+                        }
+                    })?;
+                Ok(MsgEnvelope::Channel(ChannelMsg::UpgradeInit(domain_msg)))
+            }
+            CHAN_UPGRADE_TRY_TYPE_URL => {
+                let domain_msg = MsgChannelUpgradeTry::decode_vec(&any_msg.value).map_err(|e| {
+                    RouterError::MalformedMessageBytes {
+                        reason: e.to_string(),
+                    }
+                })?;
+                Ok(MsgEnvelope::Channel(ChannelMsg::UpgradeTry(domain_msg)))
+            }
+            CHAN_UPGRADE_ACK_TYPE_URL => {
+                let domain_msg = MsgChannelUpgradeAck::decode_vec(&any_msg.value).map_err(|e| {
+                    RouterError::MalformedMessageBytes {
+                        reason: e.to_string(),
+                    }
+                })?;
+                Ok(MsgEnvelope::Channel(ChannelMsg::UpgradeAck(domain_msg)))
+            }
+            CHAN_UPGRADE_CONFIRM_TYPE_URL => {
+                let domain_msg =
+                    MsgChannelUpgradeConfirm::decode_vec(&any_msg.value).map_err(|e| {
+                        RouterError::MalformedMessageBytes {
+                            reason: e.to_string(),
+                        }
+                    })?;
+                Ok(MsgEnvelope::Channel(ChannelMsg::UpgradeConfirm(domain_msg)))
+            }
+            CHAN_UPGRADE_OPEN_TYPE_URL => {
+                let domain_msg =
+                    MsgChannelUpgradeOpen::decode_vec(&any_msg.value).map_err(|e| {
+                        RouterError::MalformedMessageBytes {
+                            reason: e.to_string(),
+                        }
+                    })?;
+                Ok(MsgEnvelope::Channel(ChannelMsg::UpgradeOpen(domain_msg)))
+            }
+            CHAN_UPGRADE_TIMEOUT_TYPE_URL => {
+                let domain_msg =
+                    MsgChannelUpgradeTimeout::decode_vec(&any_msg.value).map_err(|e| {
+                        RouterError::MalformedMessageBytes {
+                            reason: e.to_string(),
+                        }
+                    })?;
+                Ok(MsgEnvelope::Channel(ChannelMsg::UpgradeTimeout(domain_msg)))
+            }
+            CHAN_UPGRADE_CANCEL_TYPE_URL => {
+                let domain_msg =
+                    MsgChannelUpgradeCancel::decode_vec(&any_msg.value).map_err(|e| {
+                        RouterError::MalformedMessageBytes {
+                            reason: e.to_string(),
+                        }
+                    })?;
+                Ok(MsgEnvelope::Channel(ChannelMsg::UpgradeCancel(domain_msg)))
+            }
             // ICS04 packet messages
             RECV_PACKET_TYPE_URL => {
                 let domain_msg = MsgRecvPacket::decode_vec(&any_msg.value).map_err(|e| {
@@ -0,0 +1,833 @@
+//! Defines the [`Middleware`] trait, which allows stacking IBC middleware
+//! (e.g. fees, rate limits or packet forwarding) on top of an application,
+//! and the [`IbcModuleWrapper`] that composes a middleware with the next
+//! module of the stack into a single [`Module`].
+use core::fmt::Debug;
+
+use ibc_core_channel_types::acknowledgement::Acknowledgement;
+use ibc_core_channel_types::channel::{Counterparty, Order};
+use ibc_core_channel_types::error::{ChannelError, PacketError};
+use ibc_core_channel_types::packet::Packet;
+use ibc_core_channel_types::Version;
+use ibc_core_host_types::identifiers::{ChannelId, ConnectionId, PortId};
+use ibc_core_router_types::module::ModuleExtras;
+use ibc_primitives::prelude::*;
+use ibc_primitives::Signer;
+
+use crate::module::Module;
+
+/// An IBC middleware, which sits between core IBC and the next module `N` of
+/// the stack (either the application itself or another middleware).
+///
+/// Every callback receives a handle to the next module and, by default,
+/// simply forwards the call to it. A middleware therefore only overrides the
+/// callbacks it is interested in, and decides for each of them whether and
+/// how to call into the next module.
+///
+/// The `send_packet_*` and `write_acknowledgement_*` hooks are the outbound
+/// counterpart of the callbacks, similar to ibc-go's `ICS4Wrapper`: they are
+/// invoked on packets and acknowledgements written by the modules below this
+/// middleware, on their way out to core IBC.
+///
+/// A middleware is turned into a [`Module`] by wrapping it together with the
+/// next module in an [`IbcModuleWrapper`], e.g. with [`Middleware::wrap`].
+pub trait Middleware<N: Module>: Debug {
+    /// Stacks this middleware on top of `next`.
+    fn wrap(self, next: N) -> IbcModuleWrapper<Self, N>
+    where
+        Self: Sized,
+    {
+        IbcModuleWrapper::new(self, next)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn on_chan_open_init_validate(
+        &self,
+        next: &N,
+        order: Order,
+        connection_hops: &[ConnectionId],
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        counterparty: &Counterparty,
+        version: &Version,
+    ) -> Result<Version, ChannelError> {
+        next.on_chan_open_init_validate(
+            order,
+            connection_hops,
+            port_id,
+            channel_id,
+            counterparty,
+            version,
+        )
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn on_chan_open_init_execute(
+        &mut self,
+        next: &mut N,
+        order: Order,
+        connection_hops: &[ConnectionId],
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        counterparty: &Counterparty,
+        version: &Version,
+    ) -> Result<(ModuleExtras, Version), ChannelError> {
+        next.on_chan_open_init_execute(
+            order,
+            connection_hops,
+            port_id,
+            channel_id,
+            counterparty,
+            version,
+        )
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn on_chan_open_try_validate(
+        &self,
+        next: &N,
+        order: Order,
+        connection_hops: &[ConnectionId],
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        counterparty: &Counterparty,
+        counterparty_version: &Version,
+    ) -> Result<Version, ChannelError> {
+        next.on_chan_open_try_validate(
+            order,
+            connection_hops,
+            port_id,
+            channel_id,
+            counterparty,
+            counterparty_version,
+        )
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn on_chan_open_try_execute(
+        &mut self,
+        next: &mut N,
+        order: Order,
+        connection_hops: &[ConnectionId],
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        counterparty: &Counterparty,
+        counterparty_version: &Version,
+    ) -> Result<(ModuleExtras, Version), ChannelError> {
+        next.on_chan_open_try_execute(
+            order,
+            connection_hops,
+            port_id,
+            channel_id,
+            counterparty,
+            counterparty_version,
+        )
+    }
+
+    fn on_chan_open_ack_validate(
+        &self,
+        next: &N,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        counterparty_version: &Version,
+    ) -> Result<(), ChannelError> {
+        next.on_chan_open_ack_validate(port_id, channel_id, counterparty_version)
+    }
+
+    fn on_chan_open_ack_execute(
+        &mut self,
+        next: &mut N,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        counterparty_version: &Version,
+    ) -> Result<ModuleExtras, ChannelError> {
+        next.on_chan_open_ack_execute(port_id, channel_id, counterparty_version)
+    }
+
+    fn on_chan_open_confirm_validate(
+        &self,
+        next: &N,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<(), ChannelError> {
+        next.on_chan_open_confirm_validate(port_id, channel_id)
+    }
+
+    fn on_chan_open_confirm_execute(
+        &mut self,
+        next: &mut N,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<ModuleExtras, ChannelError> {
+        next.on_chan_open_confirm_execute(port_id, channel_id)
+    }
+
+    fn on_chan_close_init_validate(
+        &self,
+        next: &N,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<(), ChannelError> {
+        next.on_chan_close_init_validate(port_id, channel_id)
+    }
+
+    fn on_chan_close_init_execute(
+        &mut self,
+        next: &mut N,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<ModuleExtras, ChannelError> {
+        next.on_chan_close_init_execute(port_id, channel_id)
+    }
+
+    fn on_chan_close_confirm_validate(
+        &self,
+        next: &N,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<(), ChannelError> {
+        next.on_chan_close_confirm_validate(port_id, channel_id)
+    }
+
+    fn on_chan_close_confirm_execute(
+        &mut self,
+        next: &mut N,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<ModuleExtras, ChannelError> {
+        next.on_chan_close_confirm_execute(port_id, channel_id)
+    }
+
+    fn on_chan_upgrade_init_validate(
+        &self,
+        next: &N,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        proposed_order: Order,
+        proposed_connection_hops: &[ConnectionId],
+        proposed_version: &Version,
+    ) -> Result<Version, ChannelError> {
+        next.on_chan_upgrade_init_validate(
+            port_id,
+            channel_id,
+            proposed_order,
+            proposed_connection_hops,
+            proposed_version,
+        )
+    }
+
+    fn on_chan_upgrade_init_execute(
+        &mut self,
+        next: &mut N,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        proposed_order: Order,
+        proposed_connection_hops: &[ConnectionId],
+        proposed_version: &Version,
+    ) -> Result<(ModuleExtras, Version), ChannelError> {
+        next.on_chan_upgrade_init_execute(
+            port_id,
+            channel_id,
+            proposed_order,
+            proposed_connection_hops,
+            proposed_version,
+        )
+    }
+
+    fn on_chan_upgrade_try_validate(
+        &self,
+        next: &N,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        proposed_order: Order,
+        proposed_connection_hops: &[ConnectionId],
+        counterparty_version: &Version,
+    ) -> Result<Version, ChannelError> {
+        next.on_chan_upgrade_try_validate(
+            port_id,
+            channel_id,
+            proposed_order,
+            proposed_connection_hops,
+            counterparty_version,
+        )
+    }
+
+    fn on_chan_upgrade_try_execute(
+        &mut self,
+        next: &mut N,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        proposed_order: Order,
+        proposed_connection_hops: &[ConnectionId],
+        counterparty_version: &Version,
+    ) -> Result<(ModuleExtras, Version), ChannelError> {
+        next.on_chan_upgrade_try_execute(
+            port_id,
+            channel_id,
+            proposed_order,
+            proposed_connection_hops,
+            counterparty_version,
+        )
+    }
+
+    fn on_chan_upgrade_ack_validate(
+        &self,
+        next: &N,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        counterparty_version: &Version,
+    ) -> Result<(), ChannelError> {
+        next.on_chan_upgrade_ack_validate(port_id, channel_id, counterparty_version)
+    }
+
+    fn on_chan_upgrade_ack_execute(
+        &mut self,
+        next: &mut N,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        counterparty_version: &Version,
+    ) -> Result<ModuleExtras, ChannelError> {
+        next.on_chan_upgrade_ack_execute(port_id, channel_id, counterparty_version)
+    }
+
+    fn on_chan_upgrade_open_validate(
+        &self,
+        next: &N,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        order: Order,
+        connection_hops: &[ConnectionId],
+        version: &Version,
+    ) -> Result<(), ChannelError> {
+        next.on_chan_upgrade_open_validate(port_id, channel_id, order, connection_hops, version)
+    }
+
+    fn on_chan_upgrade_open_execute(
+        &mut self,
+        next: &mut N,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        order: Order,
+        connection_hops: &[ConnectionId],
+        version: &Version,
+    ) -> Result<ModuleExtras, ChannelError> {
+        next.on_chan_upgrade_open_execute(port_id, channel_id, order, connection_hops, version)
+    }
+
+    fn on_recv_packet_execute(
+        &mut self,
+        next: &mut N,
+        packet: &Packet,
+        relayer: &Signer,
+    ) -> (ModuleExtras, Option<Acknowledgement>) {
+        next.on_recv_packet_execute(packet, relayer)
+    }
+
+    fn on_acknowledgement_packet_validate(
+        &self,
+        next: &N,
+        packet: &Packet,
+        acknowledgement: &Acknowledgement,
+        relayer: &Signer,
+    ) -> Result<(), PacketError> {
+        next.on_acknowledgement_packet_validate(packet, acknowledgement, relayer)
+    }
+
+    fn on_acknowledgement_packet_execute(
+        &mut self,
+        next: &mut N,
+        packet: &Packet,
+        acknowledgement: &Acknowledgement,
+        relayer: &Signer,
+    ) -> (ModuleExtras, Result<(), PacketError>) {
+        next.on_acknowledgement_packet_execute(packet, acknowledgement, relayer)
+    }
+
+    fn on_timeout_packet_validate(
+        &self,
+        next: &N,
+        packet: &Packet,
+        relayer: &Signer,
+    ) -> Result<(), PacketError> {
+        next.on_timeout_packet_validate(packet, relayer)
+    }
+
+    fn on_timeout_packet_execute(
+        &mut self,
+        next: &mut N,
+        packet: &Packet,
+        relayer: &Signer,
+    ) -> (ModuleExtras, Result<(), PacketError>) {
+        next.on_timeout_packet_execute(packet, relayer)
+    }
+
+    /// Intercepts a packet sent by the modules below this middleware. An
+    /// error aborts the send.
+    fn send_packet_validate(&self, _packet: &Packet) -> Result<(), PacketError> {
+        Ok(())
+    }
+
+    fn send_packet_execute(&mut self, _packet: &Packet) -> Result<ModuleExtras, PacketError> {
+        Ok(ModuleExtras::empty())
+    }
+
+    /// Intercepts an acknowledgement written by the modules below this
+    /// middleware, and returns the acknowledgement to pass down towards core
+    /// IBC.
+    fn write_acknowledgement_validate(
+        &self,
+        _packet: &Packet,
+        acknowledgement: Acknowledgement,
+    ) -> Result<Acknowledgement, PacketError> {
+        Ok(acknowledgement)
+    }
+
+    fn write_acknowledgement_execute(
+        &mut self,
+        _packet: &Packet,
+        acknowledgement: Acknowledgement,
+    ) -> Result<(ModuleExtras, Acknowledgement), PacketError> {
+        Ok((ModuleExtras::empty(), acknowledgement))
+    }
+}
+
+/// A [`Module`] made of a middleware `M` stacked on top of the next module
+/// `N`. Since `N` can itself be an `IbcModuleWrapper`, wrappers nest to form
+/// a stack of any depth, whose outermost layer is registered with the router.
+#[derive(Clone, Debug)]
+pub struct IbcModuleWrapper<M, N> {
+    middleware: M,
+    next: N,
+}
+
+impl<M, N> IbcModuleWrapper<M, N> {
+    pub fn new(middleware: M, next: N) -> Self {
+        Self { middleware, next }
+    }
+
+    pub fn middleware(&self) -> &M {
+        &self.middleware
+    }
+
+    pub fn middleware_mut(&mut self) -> &mut M {
+        &mut self.middleware
+    }
+
+    /// Returns the next module of the stack.
+    pub fn next(&self) -> &N {
+        &self.next
+    }
+
+    pub fn next_mut(&mut self) -> &mut N {
+        &mut self.next
+    }
+
+    pub fn into_inner(self) -> (M, N) {
+        (self.middleware, self.next)
+    }
+}
+
+impl<M, N> Module for IbcModuleWrapper<M, N>
+where
+    M: Middleware<N>,
+    N: Module,
+{
+    fn on_chan_open_init_validate(
+        &self,
+        order: Order,
+        connection_hops: &[ConnectionId],
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        counterparty: &Counterparty,
+        version: &Version,
+    ) -> Result<Version, ChannelError> {
+        self.middleware.on_chan_open_init_validate(
+            &self.next,
+            order,
+            connection_hops,
+            port_id,
+            channel_id,
+            counterparty,
+            version,
+        )
+    }
+
+    fn on_chan_open_init_execute(
+        &mut self,
+        order: Order,
+        connection_hops: &[ConnectionId],
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        counterparty: &Counterparty,
+        version: &Version,
+    ) -> Result<(ModuleExtras, Version), ChannelError> {
+        self.middleware.on_chan_open_init_execute(
+            &mut self.next,
+            order,
+            connection_hops,
+            port_id,
+            channel_id,
+            counterparty,
+            version,
+        )
+    }
+
+    fn on_chan_open_try_validate(
+        &self,
+        order: Order,
+        connection_hops: &[ConnectionId],
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        counterparty: &Counterparty,
+        counterparty_version: &Version,
+    ) -> Result<Version, ChannelError> {
+        self.middleware.on_chan_open_try_validate(
+            &self.next,
+            order,
+            connection_hops,
+            port_id,
+            channel_id,
+            counterparty,
+            counterparty_version,
+        )
+    }
+
+    fn on_chan_open_try_execute(
+        &mut self,
+        order: Order,
+        connection_hops: &[ConnectionId],
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        counterparty: &Counterparty,
+        counterparty_version: &Version,
+    ) -> Result<(ModuleExtras, Version), ChannelError> {
+        self.middleware.on_chan_open_try_execute(
+            &mut self.next,
+            order,
+            connection_hops,
+            port_id,
+            channel_id,
+            counterparty,
+            counterparty_version,
+        )
+    }
+
+    fn on_chan_open_ack_validate(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        counterparty_version: &Version,
+    ) -> Result<(), ChannelError> {
+        self.middleware.on_chan_open_ack_validate(
+            &self.next,
+            port_id,
+            channel_id,
+            counterparty_version,
+        )
+    }
+
+    fn on_chan_open_ack_execute(
+        &mut self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        counterparty_version: &Version,
+    ) -> Result<ModuleExtras, ChannelError> {
+        self.middleware.on_chan_open_ack_execute(
+            &mut self.next,
+            port_id,
+            channel_id,
+            counterparty_version,
+        )
+    }
+
+    fn on_chan_open_confirm_validate(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<(), ChannelError> {
+        self.middleware
+            .on_chan_open_confirm_validate(&self.next, port_id, channel_id)
+    }
+
+    fn on_chan_open_confirm_execute(
+        &mut self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<ModuleExtras, ChannelError> {
+        self.middleware
+            .on_chan_open_confirm_execute(&mut self.next, port_id, channel_id)
+    }
+
+    fn on_chan_close_init_validate(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<(), ChannelError> {
+        self.middleware
+            .on_chan_close_init_validate(&self.next, port_id, channel_id)
+    }
+
+    fn on_chan_close_init_execute(
+        &mut self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<ModuleExtras, ChannelError> {
+        self.middleware
+            .on_chan_close_init_execute(&mut self.next, port_id, channel_id)
+    }
+
+    fn on_chan_close_confirm_validate(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<(), ChannelError> {
+        self.middleware
+            .on_chan_close_confirm_validate(&self.next, port_id, channel_id)
+    }
+
+    fn on_chan_close_confirm_execute(
+        &mut self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<ModuleExtras, ChannelError> {
+        self.middleware
+            .on_chan_close_confirm_execute(&mut self.next, port_id, channel_id)
+    }
+
+    fn on_chan_upgrade_init_validate(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        proposed_order: Order,
+        proposed_connection_hops: &[ConnectionId],
+        proposed_version: &Version,
+    ) -> Result<Version, ChannelError> {
+        self.middleware.on_chan_upgrade_init_validate(
+            &self.next,
+            port_id,
+            channel_id,
+            proposed_order,
+            proposed_connection_hops,
+            proposed_version,
+        )
+    }
+
+    fn on_chan_upgrade_init_execute(
+        &mut self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        proposed_order: Order,
+        proposed_connection_hops: &[ConnectionId],
+        proposed_version: &Version,
+    ) -> Result<(ModuleExtras, Version), ChannelError> {
+        self.middleware.on_chan_upgrade_init_execute(
+            &mut self.next,
+            port_id,
+            channel_id,
+            proposed_order,
+            proposed_connection_hops,
+            proposed_version,
+        )
+    }
+
+    fn on_chan_upgrade_try_validate(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        proposed_order: Order,
+        proposed_connection_hops: &[ConnectionId],
+        counterparty_version: &Version,
+    ) -> Result<Version, ChannelError> {
+        self.middleware.on_chan_upgrade_try_validate(
+            &self.next,
+            port_id,
+            channel_id,
+            proposed_order,
+            proposed_connection_hops,
+            counterparty_version,
+        )
+    }
+
+    fn on_chan_upgrade_try_execute(
+        &mut self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        proposed_order: Order,
+        proposed_connection_hops: &[ConnectionId],
+        counterparty_version: &Version,
+    ) -> Result<(ModuleExtras, Version), ChannelError> {
+        self.middleware.on_chan_upgrade_try_execute(
+            &mut self.next,
+            port_id,
+            channel_id,
+            proposed_order,
+            proposed_connection_hops,
+            counterparty_version,
+        )
+    }
+
+    fn on_chan_upgrade_ack_validate(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        counterparty_version: &Version,
+    ) -> Result<(), ChannelError> {
+        self.middleware.on_chan_upgrade_ack_validate(
+            &self.next,
+            port_id,
+            channel_id,
+            counterparty_version,
+        )
+    }
+
+    fn on_chan_upgrade_ack_execute(
+        &mut self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        counterparty_version: &Version,
+    ) -> Result<ModuleExtras, ChannelError> {
+        self.middleware.on_chan_upgrade_ack_execute(
+            &mut self.next,
+            port_id,
+            channel_id,
+            counterparty_version,
+        )
+    }
+
+    fn on_chan_upgrade_open_validate(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        order: Order,
+        connection_hops: &[ConnectionId],
+        version: &Version,
+    ) -> Result<(), ChannelError> {
+        self.middleware.on_chan_upgrade_open_validate(
+            &self.next,
+            port_id,
+            channel_id,
+            order,
+            connection_hops,
+            version,
+        )
+    }
+
+    fn on_chan_upgrade_open_execute(
+        &mut self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        order: Order,
+        connection_hops: &[ConnectionId],
+        version: &Version,
+    ) -> Result<ModuleExtras, ChannelError> {
+        self.middleware.on_chan_upgrade_open_execute(
+            &mut self.next,
+            port_id,
+            channel_id,
+            order,
+            connection_hops,
+            version,
+        )
+    }
+
+    fn on_recv_packet_execute(
+        &mut self,
+        packet: &Packet,
+        relayer: &Signer,
+    ) -> (ModuleExtras, Option<Acknowledgement>) {
+        self.middleware
+            .on_recv_packet_execute(&mut self.next, packet, relayer)
+    }
+
+    fn on_acknowledgement_packet_validate(
+        &self,
+        packet: &Packet,
+        acknowledgement: &Acknowledgement,
+        relayer: &Signer,
+    ) -> Result<(), PacketError> {
+        self.middleware.on_acknowledgement_packet_validate(
+            &self.next,
+            packet,
+            acknowledgement,
+            relayer,
+        )
+    }
+
+    fn on_acknowledgement_packet_execute(
+        &mut self,
+        packet: &Packet,
+        acknowledgement: &Acknowledgement,
+        relayer: &Signer,
+    ) -> (ModuleExtras, Result<(), PacketError>) {
+        self.middleware.on_acknowledgement_packet_execute(
+            &mut self.next,
+            packet,
+            acknowledgement,
+            relayer,
+        )
+    }
+
+    fn on_timeout_packet_validate(
+        &self,
+        packet: &Packet,
+        relayer: &Signer,
+    ) -> Result<(), PacketError> {
+        self.middleware
+            .on_timeout_packet_validate(&self.next, packet, relayer)
+    }
+
+    fn on_timeout_packet_execute(
+        &mut self,
+        packet: &Packet,
+        relayer: &Signer,
+    ) -> (ModuleExtras, Result<(), PacketError>) {
+        self.middleware
+            .on_timeout_packet_execute(&mut self.next, packet, relayer)
+    }
+
+    // The outbound hooks run from the innermost layer of the stack outwards,
+    // i.e. in the order a packet or acknowledgement travels towards core IBC.
+
+    fn send_packet_validate(&self, packet: &Packet) -> Result<(), PacketError> {
+        self.next.send_packet_validate(packet)?;
+        self.middleware.send_packet_validate(packet)
+    }
+
+    fn send_packet_execute(&mut self, packet: &Packet) -> Result<ModuleExtras, PacketError> {
+        let mut extras = self.next.send_packet_execute(packet)?;
+        let ModuleExtras { events, log } = self.middleware.send_packet_execute(packet)?;
+        extras.events.extend(events);
+        extras.log.extend(log);
+
+        Ok(extras)
+    }
+
+    fn write_acknowledgement_validate(
+        &self,
+        packet: &Packet,
+        acknowledgement: Acknowledgement,
+    ) -> Result<Acknowledgement, PacketError> {
+        let acknowledgement = self
+            .next
+            .write_acknowledgement_validate(packet, acknowledgement)?;
+        self.middleware
+            .write_acknowledgement_validate(packet, acknowledgement)
+    }
+
+    fn write_acknowledgement_execute(
+        &mut self,
+        packet: &Packet,
+        acknowledgement: Acknowledgement,
+    ) -> Result<(ModuleExtras, Acknowledgement), PacketError> {
+        let (mut extras, acknowledgement) = self
+            .next
+            .write_acknowledgement_execute(packet, acknowledgement)?;
+        let (ModuleExtras { events, log }, acknowledgement) = self
+            .middleware
+            .write_acknowledgement_execute(packet, acknowledgement)?;
+        extras.events.extend(events);
+        extras.log.extend(log);
+
+        Ok((extras, acknowledgement))
+    }
+}
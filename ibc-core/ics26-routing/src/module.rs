@@ -118,16 +118,123 @@ pub trait Module: Debug {
         Ok(ModuleExtras::empty())
     }
 
+    /// Called when a channel upgrade is initiated on this end. Returns the
+    /// version the application proposes for the upgraded channel.
+    ///
+    /// By default, applications do not support channel upgrades.
+    fn on_chan_upgrade_init_validate(
+        &self,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+        _proposed_order: Order,
+        _proposed_connection_hops: &[ConnectionId],
+        _proposed_version: &Version,
+    ) -> Result<Version, ChannelError> {
+        Err(ChannelError::InvalidUpgrade {
+            reason: "the application does not support channel upgrades".to_string(),
+        })
+    }
+
+    fn on_chan_upgrade_init_execute(
+        &mut self,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+        _proposed_order: Order,
+        _proposed_connection_hops: &[ConnectionId],
+        _proposed_version: &Version,
+    ) -> Result<(ModuleExtras, Version), ChannelError> {
+        Err(ChannelError::InvalidUpgrade {
+            reason: "the application does not support channel upgrades".to_string(),
+        })
+    }
+
+    /// Called when the counterparty initiated a channel upgrade. Returns the
+    /// version the application agrees to for the upgraded channel.
+    ///
+    /// By default, applications do not support channel upgrades.
+    fn on_chan_upgrade_try_validate(
+        &self,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+        _proposed_order: Order,
+        _proposed_connection_hops: &[ConnectionId],
+        _counterparty_version: &Version,
+    ) -> Result<Version, ChannelError> {
+        Err(ChannelError::InvalidUpgrade {
+            reason: "the application does not support channel upgrades".to_string(),
+        })
+    }
+
+    fn on_chan_upgrade_try_execute(
+        &mut self,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+        _proposed_order: Order,
+        _proposed_connection_hops: &[ConnectionId],
+        _counterparty_version: &Version,
+    ) -> Result<(ModuleExtras, Version), ChannelError> {
+        Err(ChannelError::InvalidUpgrade {
+            reason: "the application does not support channel upgrades".to_string(),
+        })
+    }
+
+    fn on_chan_upgrade_ack_validate(
+        &self,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+        _counterparty_version: &Version,
+    ) -> Result<(), ChannelError> {
+        Ok(())
+    }
+
+    fn on_chan_upgrade_ack_execute(
+        &mut self,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+        _counterparty_version: &Version,
+    ) -> Result<ModuleExtras, ChannelError> {
+        Ok(ModuleExtras::empty())
+    }
+
+    fn on_chan_upgrade_open_validate(
+        &self,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+        _order: Order,
+        _connection_hops: &[ConnectionId],
+        _version: &Version,
+    ) -> Result<(), ChannelError> {
+        Ok(())
+    }
+
+    /// Called once the channel is reopened with its upgraded parameters.
+    fn on_chan_upgrade_open_execute(
+        &mut self,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+        _order: Order,
+        _connection_hops: &[ConnectionId],
+        _version: &Version,
+    ) -> Result<ModuleExtras, ChannelError> {
+        Ok(ModuleExtras::empty())
+    }
+
     // Note: no `on_recv_packet_validate()`
     // the `onRecvPacket` callback always succeeds
     // if any error occurs, than an "error acknowledgement"
     // must be returned
 
+    /// Processes a received packet and returns its acknowledgement, which
+    /// core IBC writes right away.
+    ///
+    /// An application whose result is only known in a later block (e.g. a
+    /// packet forwarded to another chain) returns `None` instead, and is
+    /// then responsible for writing the acknowledgement later on.
     fn on_recv_packet_execute(
         &mut self,
         packet: &Packet,
         relayer: &Signer,
-    ) -> (ModuleExtras, Acknowledgement);
+    ) -> (ModuleExtras, Option<Acknowledgement>);
 
     fn on_acknowledgement_packet_validate(
         &self,
@@ -158,4 +265,39 @@ pub trait Module: Debug {
         packet: &Packet,
         relayer: &Signer,
     ) -> (ModuleExtras, Result<(), PacketError>);
+
+    /// Outbound hook invoked on a packet sent by the application before it
+    /// is committed by core IBC.
+    ///
+    /// Together with the `write_acknowledgement_*` hooks, this is the
+    /// equivalent of ibc-go's `ICS4Wrapper`: it lets the middleware stacked
+    /// on top of an application (see [`Middleware`](crate::middleware::Middleware))
+    /// intercept what the application writes on its way out. Applications
+    /// do not need to override it.
+    fn send_packet_validate(&self, _packet: &Packet) -> Result<(), PacketError> {
+        Ok(())
+    }
+
+    fn send_packet_execute(&mut self, _packet: &Packet) -> Result<ModuleExtras, PacketError> {
+        Ok(ModuleExtras::empty())
+    }
+
+    /// Outbound hook invoked on an acknowledgement written by the
+    /// application before it is committed by core IBC. Returns the
+    /// acknowledgement to be committed, which middleware may have wrapped.
+    fn write_acknowledgement_validate(
+        &self,
+        _packet: &Packet,
+        acknowledgement: Acknowledgement,
+    ) -> Result<Acknowledgement, PacketError> {
+        Ok(acknowledgement)
+    }
+
+    fn write_acknowledgement_execute(
+        &mut self,
+        _packet: &Packet,
+        acknowledgement: Acknowledgement,
+    ) -> Result<(ModuleExtras, Acknowledgement), PacketError> {
+        Ok((ModuleExtras::empty(), acknowledgement))
+    }
 }
@@ -1,7 +1,9 @@
 //! Defines the `Router`, which binds modules to ports
 
 use ibc_core_host_types::identifiers::PortId;
+use ibc_core_router_types::error::RouterError;
 use ibc_core_router_types::module::ModuleId;
+use ibc_primitives::prelude::*;
 
 use crate::module::Module;
 
@@ -16,3 +18,94 @@ pub trait Router {
     /// Return the module_id associated with a given port_id
     fn lookup_module(&self, port_id: &PortId) -> Option<ModuleId>;
 }
+
+/// A ready-made [`Router`] that owns its modules, built with a
+/// [`RouterBuilder`].
+///
+/// Each route is a [`Module`], which may be a whole stack of middleware
+/// composed with [`IbcModuleWrapper`](crate::middleware::IbcModuleWrapper).
+#[derive(Debug, Default)]
+pub struct ModuleRouter {
+    modules: BTreeMap<ModuleId, Box<dyn Module>>,
+    port_to_module: BTreeMap<PortId, ModuleId>,
+}
+
+impl ModuleRouter {
+    pub fn builder() -> RouterBuilder {
+        RouterBuilder::new()
+    }
+}
+
+impl Router for ModuleRouter {
+    fn get_route(&self, module_id: &ModuleId) -> Option<&dyn Module> {
+        self.modules.get(module_id).map(AsRef::as_ref)
+    }
+
+    fn get_route_mut(&mut self, module_id: &ModuleId) -> Option<&mut dyn Module> {
+        match self.modules.get_mut(module_id) {
+            Some(module) => Some(module.as_mut()),
+            None => None,
+        }
+    }
+
+    fn lookup_module(&self, port_id: &PortId) -> Option<ModuleId> {
+        self.port_to_module.get(port_id).cloned()
+    }
+}
+
+/// Builds a [`ModuleRouter`] by registering modules and binding them to
+/// ports.
+#[derive(Debug, Default)]
+pub struct RouterBuilder {
+    router: ModuleRouter,
+}
+
+impl RouterBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers `module` under `module_id` and binds it to `port_id`.
+    pub fn add_route(
+        mut self,
+        port_id: PortId,
+        module_id: ModuleId,
+        module: impl Module + 'static,
+    ) -> Result<Self, RouterError> {
+        if self.router.modules.contains_key(&module_id) {
+            return Err(RouterError::DuplicateModule { module_id });
+        }
+        self = self.bind_port_unchecked(port_id, module_id.clone())?;
+        self.router.modules.insert(module_id, Box::new(module));
+
+        Ok(self)
+    }
+
+    /// Binds an additional port to an already registered module.
+    pub fn bind_port(self, port_id: PortId, module_id: ModuleId) -> Result<Self, RouterError> {
+        if !self.router.modules.contains_key(&module_id) {
+            return Err(RouterError::ModuleNotFound);
+        }
+        self.bind_port_unchecked(port_id, module_id)
+    }
+
+    pub fn build(self) -> ModuleRouter {
+        self.router
+    }
+
+    fn bind_port_unchecked(
+        mut self,
+        port_id: PortId,
+        module_id: ModuleId,
+    ) -> Result<Self, RouterError> {
+        if let Some(bound_module_id) = self.router.port_to_module.get(&port_id) {
+            return Err(RouterError::PortAlreadyBound {
+                port_id,
+                module_id: bound_module_id.clone(),
+            });
+        }
+        self.router.port_to_module.insert(port_id, module_id);
+
+        Ok(self)
+    }
+}
@@ -2,6 +2,8 @@ use displaydoc::Display;
 use ibc_core_host_types::identifiers::PortId;
 use ibc_primitives::prelude::*;
 
+use crate::module::ModuleId;
+
 /// Error type for the router module.
 #[derive(Debug, Display)]
 pub enum RouterError {
@@ -13,6 +15,13 @@ pub enum RouterError {
     UnknownPort { port_id: PortId },
     /// module not found
     ModuleNotFound,
+    /// module `{module_id}` is already registered
+    DuplicateModule { module_id: ModuleId },
+    /// port `{port_id}` is already bound to module `{module_id}`
+    PortAlreadyBound {
+        port_id: PortId,
+        module_id: ModuleId,
+    },
 }
 
 #[cfg(feature = "std")]
@@ -43,6 +43,13 @@ pub(crate) fn impl_ClientStateExecution(
         imports,
     );
 
+    let check_substitute_and_update_state_impl = delegate_substitute_call_in_match(
+        client_state_enum_name,
+        enum_variants.iter(),
+        opts,
+        imports,
+    );
+
     // The imports we need for the generated code.
     let Any = imports.any();
     let ClientId = imports.client_id();
@@ -109,6 +116,18 @@ pub(crate) fn impl_ClientStateExecution(
                     #(#update_state_with_upgrade_client_impl),*
                 }
             }
+
+            fn check_substitute_and_update_state(
+                &self,
+                ctx: &mut #ClientExecutionContext,
+                subject_client_id: &#ClientId,
+                substitute_client_id: &#ClientId,
+                substitute_client_state: Self,
+            ) -> core::result::Result<(), #ClientError> {
+                match (self, substitute_client_state) {
+                    #(#check_substitute_and_update_state_impl),*
+                }
+            }
         }
 
     }
@@ -138,3 +157,40 @@ fn delegate_call_in_match(
         })
         .collect()
 }
+
+/// Delegates `check_substitute_and_update_state` to the variant of the subject
+/// client state, provided the substitute client state is of the same variant.
+fn delegate_substitute_call_in_match(
+    enum_name: &Ident,
+    enum_variants: Iter<'_, Variant>,
+    opts: &Opts,
+    imports: &Imports,
+) -> Vec<TokenStream> {
+    let ClientStateCommon = imports.client_state_common();
+    let ClientStateExecution = imports.client_state_execution();
+    let ClientError = imports.client_error();
+
+    let mut match_arms: Vec<TokenStream> = enum_variants
+        .map(|variant| {
+            let HostClientState = enum_name;
+            let Tendermint = &variant.ident;
+            let TmClientState = get_enum_variant_type_path(variant);
+            let ClientExecutionContext = &opts.client_execution_context;
+
+            quote! {
+                (#HostClientState::#Tendermint(cs), #HostClientState::#Tendermint(substitute)) => <#TmClientState as #ClientStateExecution<#ClientExecutionContext>>::check_substitute_and_update_state(cs, ctx, subject_client_id, substitute_client_id, substitute)
+            }
+        })
+        .collect();
+
+    // The catch-all arm is unreachable for single-variant enums.
+    if match_arms.len() > 1 {
+        match_arms.push(quote! {
+            (_, substitute) => Err(#ClientError::ClientArgsTypeMismatch {
+                client_type: #ClientStateCommon::client_type(&substitute),
+            })
+        });
+    }
+
+    match_arms
+}
@@ -37,13 +37,43 @@ pub(crate) fn impl_ClientStateValidation(
         imports,
     );
 
+    let status_details_impl = delegate_call_in_match(
+        client_state_enum_name,
+        enum_variants.iter(),
+        opts,
+        quote! { status_details(cs, ctx, client_id) },
+        imports,
+    );
+
+    let verify_membership_with_context_impl = delegate_call_in_match(
+        client_state_enum_name,
+        enum_variants.iter(),
+        opts,
+        quote! { verify_membership_with_context(cs, ctx, client_id, prefix, proof, root, proof_height, path, value) },
+        imports,
+    );
+
+    let verify_non_membership_with_context_impl = delegate_call_in_match(
+        client_state_enum_name,
+        enum_variants.iter(),
+        opts,
+        quote! { verify_non_membership_with_context(cs, ctx, client_id, prefix, proof, root, proof_height, path) },
+        imports,
+    );
+
     // The imports we need for the generated code.
     let Any = imports.any();
+    let CommitmentRoot = imports.commitment_root();
+    let CommitmentPrefix = imports.commitment_prefix();
+    let CommitmentProofBytes = imports.commitment_proof_bytes();
+    let Path = imports.path();
     let ClientId = imports.client_id();
     let ClientError = imports.client_error();
     let ClientStateValidation = imports.client_state_validation();
     let Status = imports.status();
+    let StatusDetails = imports.status_details();
     let UpdateKind = imports.update_kind();
+    let Height = imports.height();
 
     // The types we need for the generated code.
     let HostClientState = client_state_enum_name;
@@ -92,6 +122,47 @@ pub(crate) fn impl_ClientStateValidation(
                 }
 
             }
+
+            fn status_details(
+                &self,
+                ctx: &#ClientValidationContext,
+                client_id: &#ClientId,
+            ) -> core::result::Result<#StatusDetails, #ClientError> {
+                match self {
+                    #(#status_details_impl),*
+                }
+            }
+
+            fn verify_membership_with_context(
+                &self,
+                ctx: &#ClientValidationContext,
+                client_id: &#ClientId,
+                prefix: &#CommitmentPrefix,
+                proof: &#CommitmentProofBytes,
+                root: &#CommitmentRoot,
+                proof_height: #Height,
+                path: #Path,
+                value: Vec<u8>,
+            ) -> core::result::Result<(), #ClientError> {
+                match self {
+                    #(#verify_membership_with_context_impl),*
+                }
+            }
+
+            fn verify_non_membership_with_context(
+                &self,
+                ctx: &#ClientValidationContext,
+                client_id: &#ClientId,
+                prefix: &#CommitmentPrefix,
+                proof: &#CommitmentProofBytes,
+                root: &#CommitmentRoot,
+                proof_height: #Height,
+                path: #Path,
+            ) -> core::result::Result<(), #ClientError> {
+                match self {
+                    #(#verify_non_membership_with_context_impl),*
+                }
+            }
         }
 
     }
@@ -111,6 +111,11 @@ impl Imports {
         let prefix = self.prefix();
         quote! {#prefix::client::types::Status}
     }
+
+    pub fn status_details(&self) -> TokenStream {
+        let prefix = self.prefix();
+        quote! {#prefix::client::types::StatusDetails}
+    }
 }
 
 /// Retrieves the field of a given enum variant. Outputs an error message if the enum variant
@@ -0,0 +1,2 @@
+pub mod nft_transfer;
+pub mod transfer;
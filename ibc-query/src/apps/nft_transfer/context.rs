@@ -0,0 +1,18 @@
+//! Required traits for blanket implementations of [`gRPC query services`](crate::apps::nft_transfer).
+
+use ibc::apps::nft_transfer::context::{NftClassTraceContext, NftTransferValidationContext};
+use ibc::apps::nft_transfer::types::error::NftTransferError;
+use ibc::core::host::types::identifiers::{ChannelId, PortId};
+use ibc::core::primitives::Signer;
+
+/// Context to be implemented by the host that provides the ICS-721 gRPC query
+/// services.
+pub trait NftTransferQueryContext: NftTransferValidationContext + NftClassTraceContext {
+    /// Returns the address of the account escrowing the NFTs sent over the
+    /// given channel.
+    fn escrow_address(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<Signer, NftTransferError>;
+}
@@ -0,0 +1,10 @@
+//! Provides the gRPC query services of the
+//! [ICS-721](https://github.com/cosmos/ibc/blob/main/spec/app/ics-721-nft-transfer/README.md)
+//! NFT transfer application.
+mod context;
+mod query;
+mod service;
+
+pub use context::*;
+pub use query::*;
+pub use service::*;
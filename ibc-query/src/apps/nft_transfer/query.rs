@@ -0,0 +1,127 @@
+//! Provides utility functions for querying the state of the ICS-721 NFT
+//! transfer application.
+
+use alloc::string::ToString;
+use core::str::FromStr;
+
+use ibc::apps::nft_transfer::types::PrefixedClassId;
+use ibc::core::host::types::identifiers::{ChannelId, PortId};
+use ibc_proto::ibc::applications::nft_transfer::v1::{
+    Params, QueryClassHashRequest, QueryClassHashResponse, QueryClassTraceRequest,
+    QueryClassTraceResponse, QueryClassTracesRequest, QueryClassTracesResponse,
+    QueryEscrowAddressRequest, QueryEscrowAddressResponse, QueryParamsRequest, QueryParamsResponse,
+};
+
+use super::NftTransferQueryContext;
+use crate::error::QueryError;
+use crate::pagination::paginate;
+
+/// The prefix of the class IDs of relayed NFTs, followed by their hash.
+const IBC_CLASS_PREFIX: &str = "ibc/";
+
+/// Queries for the class trace stored under the given hash, which may be
+/// prefixed with `ibc/`.
+pub fn query_class_trace<I>(
+    ctx: &I,
+    request: &QueryClassTraceRequest,
+) -> Result<QueryClassTraceResponse, QueryError>
+where
+    I: NftTransferQueryContext,
+{
+    let hash = request
+        .hash
+        .strip_prefix(IBC_CLASS_PREFIX)
+        .unwrap_or(&request.hash);
+
+    let class_id = ctx
+        .class_trace(hash)?
+        .ok_or_else(|| QueryError::ClassTraceNotFound {
+            hash: hash.to_string(),
+        })?;
+
+    Ok(QueryClassTraceResponse {
+        class_trace: Some(class_id.into()),
+    })
+}
+
+/// Queries for a page of the stored class traces, ordered by hash.
+pub fn query_class_traces<I>(
+    ctx: &I,
+    request: &QueryClassTracesRequest,
+) -> Result<QueryClassTracesResponse, QueryError>
+where
+    I: NftTransferQueryContext,
+{
+    let mut class_traces = ctx.class_traces()?;
+    class_traces.sort_by(|(hash_a, _), (hash_b, _)| hash_a.cmp(hash_b));
+
+    let (class_traces, pagination) = paginate(
+        class_traces
+            .into_iter()
+            .map(|(hash, class_id)| (hash.into_bytes(), class_id.into()))
+            .collect(),
+        request.pagination.as_ref(),
+    )?;
+
+    Ok(QueryClassTracesResponse {
+        class_traces,
+        pagination: Some(pagination),
+    })
+}
+
+/// Queries for the hash of the given class trace, whose class must exist on
+/// the host.
+pub fn query_class_hash<I>(
+    ctx: &I,
+    request: &QueryClassHashRequest,
+) -> Result<QueryClassHashResponse, QueryError>
+where
+    I: NftTransferQueryContext,
+{
+    let class_id = PrefixedClassId::from_str(&request.trace)?;
+
+    ctx.get_nft_class(&class_id)?;
+
+    let hash = ctx
+        .class_hash_string(&class_id)
+        .ok_or_else(|| QueryError::ClassTraceNotFound {
+            hash: request.trace.clone(),
+        })?;
+
+    Ok(QueryClassHashResponse { hash })
+}
+
+/// Queries for the address of the account escrowing the NFTs sent over the
+/// given channel.
+pub fn query_escrow_address<I>(
+    ctx: &I,
+    request: &QueryEscrowAddressRequest,
+) -> Result<QueryEscrowAddressResponse, QueryError>
+where
+    I: NftTransferQueryContext,
+{
+    let port_id = PortId::from_str(&request.port_id)?;
+    let channel_id = ChannelId::from_str(&request.channel_id)?;
+
+    let escrow_address = ctx.escrow_address(&port_id, &channel_id)?;
+
+    Ok(QueryEscrowAddressResponse {
+        escrow_address: escrow_address.to_string(),
+    })
+}
+
+/// Queries for whether the host sends and receives NFTs.
+pub fn query_params<I>(
+    ctx: &I,
+    _request: &QueryParamsRequest,
+) -> Result<QueryParamsResponse, QueryError>
+where
+    I: NftTransferQueryContext,
+{
+    Ok(QueryParamsResponse {
+        params: Some(Params {
+            send_enabled: ctx.can_send_nft().is_ok(),
+            receive_enabled: ctx.can_receive_nft().is_ok(),
+        }),
+    })
+}
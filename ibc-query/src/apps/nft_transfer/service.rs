@@ -0,0 +1,89 @@
+//! [`NftTransferQueryService`](NftTransferQueryService) takes a generic `I` to store `ctx` that implements [`NftTransferQueryContext`](NftTransferQueryContext).
+//! `I` must be a type where writes from one thread are readable from another.
+//! This means using `Arc<Mutex<_>>` or `Arc<RwLock<_>>` in most cases.
+
+use alloc::boxed::Box;
+
+use ibc_proto::ibc::applications::nft_transfer::v1::query_server::Query as NftTransferQuery;
+use ibc_proto::ibc::applications::nft_transfer::v1::{
+    QueryClassHashRequest, QueryClassHashResponse, QueryClassTraceRequest, QueryClassTraceResponse,
+    QueryClassTracesRequest, QueryClassTracesResponse, QueryEscrowAddressRequest,
+    QueryEscrowAddressResponse, QueryParamsRequest, QueryParamsResponse,
+};
+use tonic::{Request, Response, Status};
+
+use super::{
+    query_class_hash, query_class_trace, query_class_traces, query_escrow_address, query_params,
+    NftTransferQueryContext,
+};
+
+/// The generic `I` must be a type where writes from one thread are readable from another.
+/// This means using `Arc<Mutex<_>>` or `Arc<RwLock<_>>` in most cases.
+pub struct NftTransferQueryService<I>
+where
+    I: NftTransferQueryContext + Send + Sync + 'static,
+{
+    ctx: I,
+}
+
+impl<I> NftTransferQueryService<I>
+where
+    I: NftTransferQueryContext + Send + Sync + 'static,
+{
+    /// The parameter `ctx` must be a type where writes from one thread are readable from another.
+    /// This means using `Arc<Mutex<_>>` or `Arc<RwLock<_>>` in most cases.
+    pub fn new(ctx: I) -> Self {
+        Self { ctx }
+    }
+}
+
+#[tonic::async_trait]
+impl<I> NftTransferQuery for NftTransferQueryService<I>
+where
+    I: NftTransferQueryContext + Send + Sync + 'static,
+{
+    async fn class_trace(
+        &self,
+        request: Request<QueryClassTraceRequest>,
+    ) -> Result<Response<QueryClassTraceResponse>, Status> {
+        let response = query_class_trace(&self.ctx, request.get_ref())?;
+
+        Ok(Response::new(response))
+    }
+
+    async fn class_traces(
+        &self,
+        request: Request<QueryClassTracesRequest>,
+    ) -> Result<Response<QueryClassTracesResponse>, Status> {
+        let response = query_class_traces(&self.ctx, request.get_ref())?;
+
+        Ok(Response::new(response))
+    }
+
+    async fn class_hash(
+        &self,
+        request: Request<QueryClassHashRequest>,
+    ) -> Result<Response<QueryClassHashResponse>, Status> {
+        let response = query_class_hash(&self.ctx, request.get_ref())?;
+
+        Ok(Response::new(response))
+    }
+
+    async fn escrow_address(
+        &self,
+        request: Request<QueryEscrowAddressRequest>,
+    ) -> Result<Response<QueryEscrowAddressResponse>, Status> {
+        let response = query_escrow_address(&self.ctx, request.get_ref())?;
+
+        Ok(Response::new(response))
+    }
+
+    async fn params(
+        &self,
+        request: Request<QueryParamsRequest>,
+    ) -> Result<Response<QueryParamsResponse>, Status> {
+        let response = query_params(&self.ctx, request.get_ref())?;
+
+        Ok(Response::new(response))
+    }
+}
@@ -0,0 +1,20 @@
+//! Required traits for blanket implementations of [`gRPC query services`](crate::apps::transfer).
+
+use ibc::apps::transfer::context::{DenomTraceValidationContext, TokenTransferValidationContext};
+use ibc::apps::transfer::types::error::TokenTransferError;
+use ibc::core::host::types::identifiers::{ChannelId, PortId};
+use ibc::core::primitives::Signer;
+
+/// Context to be implemented by the host that provides the ICS-20 gRPC query
+/// services.
+pub trait TransferQueryContext:
+    TokenTransferValidationContext + DenomTraceValidationContext
+{
+    /// Returns the address of the account escrowing the tokens sent over the
+    /// given channel.
+    fn escrow_address(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<Signer, TokenTransferError>;
+}
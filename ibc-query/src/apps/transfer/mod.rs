@@ -0,0 +1,10 @@
+//! Provides the gRPC query services of the
+//! [ICS-20](https://github.com/cosmos/ibc/blob/main/spec/app/ics-020-fungible-token-transfer/README.md)
+//! token transfer application.
+mod context;
+mod query;
+mod service;
+
+pub use context::*;
+pub use query::*;
+pub use service::*;
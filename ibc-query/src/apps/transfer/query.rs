@@ -0,0 +1,148 @@
+//! Provides utility functions for querying the state of the ICS-20 token
+//! transfer application.
+
+use alloc::string::ToString;
+use core::str::FromStr;
+
+use ibc::apps::transfer::types::{PrefixedCoin, PrefixedDenom};
+use ibc::core::host::types::identifiers::{ChannelId, PortId};
+use ibc_proto::ibc::applications::transfer::v1::{
+    Params, QueryDenomHashRequest, QueryDenomHashResponse, QueryDenomTraceRequest,
+    QueryDenomTraceResponse, QueryDenomTracesRequest, QueryDenomTracesResponse,
+    QueryEscrowAddressRequest, QueryEscrowAddressResponse, QueryParamsRequest, QueryParamsResponse,
+    QueryTotalEscrowForDenomRequest, QueryTotalEscrowForDenomResponse,
+};
+
+use super::TransferQueryContext;
+use crate::error::QueryError;
+use crate::pagination::paginate;
+
+/// The prefix of the denominations of vouchers, followed by their hash.
+const IBC_DENOM_PREFIX: &str = "ibc/";
+
+/// Queries for the denomination trace stored under the given hash, which may
+/// be prefixed with `ibc/`.
+pub fn query_denom_trace<I>(
+    ctx: &I,
+    request: &QueryDenomTraceRequest,
+) -> Result<QueryDenomTraceResponse, QueryError>
+where
+    I: TransferQueryContext,
+{
+    let hash = request
+        .hash
+        .strip_prefix(IBC_DENOM_PREFIX)
+        .unwrap_or(&request.hash);
+
+    let denom = ctx
+        .denom_trace(hash)?
+        .ok_or_else(|| QueryError::DenomTraceNotFound {
+            hash: hash.to_string(),
+        })?;
+
+    Ok(QueryDenomTraceResponse {
+        denom_trace: Some(denom.into()),
+    })
+}
+
+/// Queries for a page of the stored denomination traces, ordered by hash.
+pub fn query_denom_traces<I>(
+    ctx: &I,
+    request: &QueryDenomTracesRequest,
+) -> Result<QueryDenomTracesResponse, QueryError>
+where
+    I: TransferQueryContext,
+{
+    let mut denom_traces = ctx.denom_traces()?;
+    denom_traces.sort_by(|(hash_a, _), (hash_b, _)| hash_a.cmp(hash_b));
+
+    let (denom_traces, pagination) = paginate(
+        denom_traces
+            .into_iter()
+            .map(|(hash, denom)| (hash.into_bytes(), denom.into()))
+            .collect(),
+        request.pagination.as_ref(),
+    )?;
+
+    Ok(QueryDenomTracesResponse {
+        denom_traces,
+        pagination: Some(pagination),
+    })
+}
+
+/// Queries for whether the host sends and receives tokens.
+pub fn query_params<I>(
+    ctx: &I,
+    _request: &QueryParamsRequest,
+) -> Result<QueryParamsResponse, QueryError>
+where
+    I: TransferQueryContext,
+{
+    Ok(QueryParamsResponse {
+        params: Some(Params {
+            send_enabled: ctx.can_send_coins().is_ok(),
+            receive_enabled: ctx.can_receive_coins().is_ok(),
+        }),
+    })
+}
+
+/// Queries for the hash of the given denomination trace, which must be
+/// stored.
+pub fn query_denom_hash<I>(
+    ctx: &I,
+    request: &QueryDenomHashRequest,
+) -> Result<QueryDenomHashResponse, QueryError>
+where
+    I: TransferQueryContext,
+{
+    let denom = PrefixedDenom::from_str(&request.trace)?;
+
+    let hash = ctx
+        .denom_hash_string(&denom)
+        .ok_or_else(|| QueryError::DenomTraceNotFound {
+            hash: request.trace.clone(),
+        })?;
+
+    if ctx.denom_trace(&hash)?.is_none() {
+        return Err(QueryError::DenomTraceNotFound { hash });
+    }
+
+    Ok(QueryDenomHashResponse { hash })
+}
+
+/// Queries for the address of the account escrowing the tokens sent over the
+/// given channel.
+pub fn query_escrow_address<I>(
+    ctx: &I,
+    request: &QueryEscrowAddressRequest,
+) -> Result<QueryEscrowAddressResponse, QueryError>
+where
+    I: TransferQueryContext,
+{
+    let port_id = PortId::from_str(&request.port_id)?;
+    let channel_id = ChannelId::from_str(&request.channel_id)?;
+
+    let escrow_address = ctx.escrow_address(&port_id, &channel_id)?;
+
+    Ok(QueryEscrowAddressResponse {
+        escrow_address: escrow_address.to_string(),
+    })
+}
+
+/// Queries for the total amount of the given denomination escrowed by the
+/// host, as tracked by the ICS-20 handlers.
+pub fn query_total_escrow_for_denom<I>(
+    ctx: &I,
+    request: &QueryTotalEscrowForDenomRequest,
+) -> Result<QueryTotalEscrowForDenomResponse, QueryError>
+where
+    I: TransferQueryContext,
+{
+    let denom = PrefixedDenom::from_str(&request.denom)?;
+
+    let amount = ctx.total_escrow_for_denom(&denom)?;
+
+    Ok(QueryTotalEscrowForDenomResponse {
+        amount: Some(PrefixedCoin { denom, amount }.into()),
+    })
+}
@@ -0,0 +1,99 @@
+//! [`TransferQueryService`](TransferQueryService) takes a generic `I` to store `ctx` that implements [`TransferQueryContext`](TransferQueryContext).
+//! `I` must be a type where writes from one thread are readable from another.
+//! This means using `Arc<Mutex<_>>` or `Arc<RwLock<_>>` in most cases.
+
+use alloc::boxed::Box;
+
+use ibc_proto::ibc::applications::transfer::v1::query_server::Query as TransferQuery;
+use ibc_proto::ibc::applications::transfer::v1::{
+    QueryDenomHashRequest, QueryDenomHashResponse, QueryDenomTraceRequest, QueryDenomTraceResponse,
+    QueryDenomTracesRequest, QueryDenomTracesResponse, QueryEscrowAddressRequest,
+    QueryEscrowAddressResponse, QueryParamsRequest, QueryParamsResponse,
+    QueryTotalEscrowForDenomRequest, QueryTotalEscrowForDenomResponse,
+};
+use tonic::{Request, Response, Status};
+
+use super::{
+    query_denom_hash, query_denom_trace, query_denom_traces, query_escrow_address, query_params,
+    query_total_escrow_for_denom, TransferQueryContext,
+};
+
+/// The generic `I` must be a type where writes from one thread are readable from another.
+/// This means using `Arc<Mutex<_>>` or `Arc<RwLock<_>>` in most cases.
+pub struct TransferQueryService<I>
+where
+    I: TransferQueryContext + Send + Sync + 'static,
+{
+    ctx: I,
+}
+
+impl<I> TransferQueryService<I>
+where
+    I: TransferQueryContext + Send + Sync + 'static,
+{
+    /// The parameter `ctx` must be a type where writes from one thread are readable from another.
+    /// This means using `Arc<Mutex<_>>` or `Arc<RwLock<_>>` in most cases.
+    pub fn new(ctx: I) -> Self {
+        Self { ctx }
+    }
+}
+
+#[tonic::async_trait]
+impl<I> TransferQuery for TransferQueryService<I>
+where
+    I: TransferQueryContext + Send + Sync + 'static,
+{
+    async fn denom_traces(
+        &self,
+        request: Request<QueryDenomTracesRequest>,
+    ) -> Result<Response<QueryDenomTracesResponse>, Status> {
+        let response = query_denom_traces(&self.ctx, request.get_ref())?;
+
+        Ok(Response::new(response))
+    }
+
+    async fn denom_trace(
+        &self,
+        request: Request<QueryDenomTraceRequest>,
+    ) -> Result<Response<QueryDenomTraceResponse>, Status> {
+        let response = query_denom_trace(&self.ctx, request.get_ref())?;
+
+        Ok(Response::new(response))
+    }
+
+    async fn params(
+        &self,
+        request: Request<QueryParamsRequest>,
+    ) -> Result<Response<QueryParamsResponse>, Status> {
+        let response = query_params(&self.ctx, request.get_ref())?;
+
+        Ok(Response::new(response))
+    }
+
+    async fn denom_hash(
+        &self,
+        request: Request<QueryDenomHashRequest>,
+    ) -> Result<Response<QueryDenomHashResponse>, Status> {
+        let response = query_denom_hash(&self.ctx, request.get_ref())?;
+
+        Ok(Response::new(response))
+    }
+
+    async fn escrow_address(
+        &self,
+        request: Request<QueryEscrowAddressRequest>,
+    ) -> Result<Response<QueryEscrowAddressResponse>, Status> {
+        let response = query_escrow_address(&self.ctx, request.get_ref())?;
+
+        Ok(Response::new(response))
+    }
+
+    async fn total_escrow_for_denom(
+        &self,
+        request: Request<QueryTotalEscrowForDenomRequest>,
+    ) -> Result<Response<QueryTotalEscrowForDenomResponse>, Status> {
+        let response = query_total_escrow_for_denom(&self.ctx, request.get_ref())?;
+
+        Ok(Response::new(response))
+    }
+}
@@ -6,14 +6,15 @@ use core::str::FromStr;
 use ibc::core::client::types::Height;
 use ibc::core::host::types::identifiers::{ChannelId, ConnectionId, PortId, Sequence};
 use ibc::core::host::types::path::{
-    AckPath, ChannelEndPath, ClientConsensusStatePath, ClientStatePath, CommitmentPath, Path,
-    ReceiptPath, SeqRecvPath, SeqSendPath,
+    AckPath, ChannelEndPath, ChannelUpgradeErrorPath, ChannelUpgradePath, ClientConsensusStatePath,
+    ClientStatePath, CommitmentPath, Path, ReceiptPath, SeqRecvPath, SeqSendPath,
 };
 use ibc::core::host::ValidationContext;
 use ibc_proto::google::protobuf::Any;
 use ibc_proto::ibc::core::channel::v1::{
-    QueryChannelClientStateRequest, QueryChannelClientStateResponse,
-    QueryChannelConsensusStateRequest, QueryChannelConsensusStateResponse, QueryChannelRequest,
+    Params, QueryChannelClientStateRequest, QueryChannelClientStateResponse,
+    QueryChannelConsensusStateRequest, QueryChannelConsensusStateResponse,
+    QueryChannelParamsRequest, QueryChannelParamsResponse, QueryChannelRequest,
     QueryChannelResponse, QueryChannelsRequest, QueryChannelsResponse,
     QueryConnectionChannelsRequest, QueryConnectionChannelsResponse,
     QueryNextSequenceReceiveRequest, QueryNextSequenceReceiveResponse,
@@ -23,8 +24,10 @@ use ibc_proto::ibc::core::channel::v1::{
     QueryPacketCommitmentResponse, QueryPacketCommitmentsRequest, QueryPacketCommitmentsResponse,
     QueryPacketReceiptRequest, QueryPacketReceiptResponse, QueryUnreceivedAcksRequest,
     QueryUnreceivedAcksResponse, QueryUnreceivedPacketsRequest, QueryUnreceivedPacketsResponse,
+    QueryUpgradeErrorRequest, QueryUpgradeErrorResponse, QueryUpgradeRequest, QueryUpgradeResponse,
+    Timeout as RawTimeout,
 };
-use ibc_proto::ibc::core::client::v1::IdentifiedClientState;
+use ibc_proto::ibc::core::client::v1::{Height as RawHeight, IdentifiedClientState};
 
 use crate::core::context::{ProvableContext, QueryContext};
 use crate::error::QueryError;
@@ -522,3 +525,94 @@ where
         proof_height: Some(current_height.into()),
     })
 }
+
+/// Queries for the ongoing upgrade of a channel and returns it with the
+/// associated proof.
+pub fn query_upgrade<I>(
+    ibc_ctx: &I,
+    request: &QueryUpgradeRequest,
+) -> Result<QueryUpgradeResponse, QueryError>
+where
+    I: ValidationContext + ProvableContext,
+{
+    let channel_id = ChannelId::from_str(request.channel_id.as_str())?;
+
+    let port_id = PortId::from_str(request.port_id.as_str())?;
+
+    let upgrade_path = ChannelUpgradePath::new(&port_id, &channel_id);
+
+    let upgrade = ibc_ctx.channel_upgrade(&upgrade_path)?;
+
+    let current_height = ibc_ctx.host_height()?;
+
+    let proof = ibc_ctx
+        .get_proof(current_height, &Path::ChannelUpgrade(upgrade_path))
+        .ok_or(QueryError::ProofNotFound {
+            description: format!("Upgrade proof not found for channel {}", channel_id),
+        })?;
+
+    Ok(QueryUpgradeResponse {
+        upgrade: Some(upgrade.into()),
+        proof,
+        proof_height: Some(current_height.into()),
+    })
+}
+
+/// Queries for the error receipt of the last failed upgrade of a channel and
+/// returns it with the associated proof.
+pub fn query_upgrade_error<I>(
+    ibc_ctx: &I,
+    request: &QueryUpgradeErrorRequest,
+) -> Result<QueryUpgradeErrorResponse, QueryError>
+where
+    I: ValidationContext + ProvableContext,
+{
+    let channel_id = ChannelId::from_str(request.channel_id.as_str())?;
+
+    let port_id = PortId::from_str(request.port_id.as_str())?;
+
+    let upgrade_error_path = ChannelUpgradeErrorPath::new(&port_id, &channel_id);
+
+    let error_receipt = ibc_ctx.channel_upgrade_error_receipt(&upgrade_error_path)?;
+
+    let current_height = ibc_ctx.host_height()?;
+
+    let proof = ibc_ctx
+        .get_proof(
+            current_height,
+            &Path::ChannelUpgradeError(upgrade_error_path),
+        )
+        .ok_or(QueryError::ProofNotFound {
+            description: format!(
+                "Upgrade error receipt proof not found for channel {}",
+                channel_id
+            ),
+        })?;
+
+    Ok(QueryUpgradeErrorResponse {
+        error_receipt: Some(error_receipt.into()),
+        proof,
+        proof_height: Some(current_height.into()),
+    })
+}
+
+/// Queries for the parameters of the channel module, i.e. the relative
+/// timeout given to the counterparty to complete a channel upgrade.
+pub fn query_channel_params<I>(
+    ibc_ctx: &I,
+    _request: &QueryChannelParamsRequest,
+) -> Result<QueryChannelParamsResponse, QueryError>
+where
+    I: ValidationContext,
+{
+    let upgrade_timeout = ibc_ctx.channel_upgrade_timeout();
+
+    Ok(QueryChannelParamsResponse {
+        params: Some(Params {
+            upgrade_timeout: Some(RawTimeout {
+                height: Some(RawHeight::default()),
+                timestamp: upgrade_timeout.as_nanos() as u64,
+            }),
+        }),
+    })
+}
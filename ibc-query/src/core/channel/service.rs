@@ -10,7 +10,8 @@ use ibc_proto::google::protobuf::Any;
 use ibc_proto::ibc::core::channel::v1::query_server::Query as ChannelQuery;
 use ibc_proto::ibc::core::channel::v1::{
     QueryChannelClientStateRequest, QueryChannelClientStateResponse,
-    QueryChannelConsensusStateRequest, QueryChannelConsensusStateResponse, QueryChannelRequest,
+    QueryChannelConsensusStateRequest, QueryChannelConsensusStateResponse,
+    QueryChannelParamsRequest, QueryChannelParamsResponse, QueryChannelRequest,
     QueryChannelResponse, QueryChannelsRequest, QueryChannelsResponse,
     QueryConnectionChannelsRequest, QueryConnectionChannelsResponse,
     QueryNextSequenceReceiveRequest, QueryNextSequenceReceiveResponse,
@@ -20,15 +21,16 @@ use ibc_proto::ibc::core::channel::v1::{
     QueryPacketCommitmentResponse, QueryPacketCommitmentsRequest, QueryPacketCommitmentsResponse,
     QueryPacketReceiptRequest, QueryPacketReceiptResponse, QueryUnreceivedAcksRequest,
     QueryUnreceivedAcksResponse, QueryUnreceivedPacketsRequest, QueryUnreceivedPacketsResponse,
+    QueryUpgradeErrorRequest, QueryUpgradeErrorResponse, QueryUpgradeRequest, QueryUpgradeResponse,
 };
 use tonic::{Request, Response, Status};
 
 use super::{
-    query_channel, query_channel_client_state, query_channel_consensus_state, query_channels,
-    query_connection_channels, query_next_sequence_receive, query_next_sequence_send,
-    query_packet_acknowledgement, query_packet_acknowledgements, query_packet_commitment,
-    query_packet_commitments, query_packet_receipt, query_unreceived_acks,
-    query_unreceived_packets,
+    query_channel, query_channel_client_state, query_channel_consensus_state, query_channel_params,
+    query_channels, query_connection_channels, query_next_sequence_receive,
+    query_next_sequence_send, query_packet_acknowledgement, query_packet_acknowledgements,
+    query_packet_commitment, query_packet_commitments, query_packet_receipt, query_unreceived_acks,
+    query_unreceived_packets, query_upgrade, query_upgrade_error,
 };
 use crate::core::context::QueryContext;
 
@@ -193,4 +195,31 @@ where
 
         Ok(Response::new(response))
     }
+
+    async fn upgrade_error(
+        &self,
+        request: Request<QueryUpgradeErrorRequest>,
+    ) -> Result<Response<QueryUpgradeErrorResponse>, Status> {
+        let response = query_upgrade_error(&self.ibc_context, request.get_ref())?;
+
+        Ok(Response::new(response))
+    }
+
+    async fn upgrade(
+        &self,
+        request: Request<QueryUpgradeRequest>,
+    ) -> Result<Response<QueryUpgradeResponse>, Status> {
+        let response = query_upgrade(&self.ibc_context, request.get_ref())?;
+
+        Ok(Response::new(response))
+    }
+
+    async fn channel_params(
+        &self,
+        request: Request<QueryChannelParamsRequest>,
+    ) -> Result<Response<QueryChannelParamsResponse>, Status> {
+        let response = query_channel_params(&self.ibc_context, request.get_ref())?;
+
+        Ok(Response::new(response))
+    }
 }
@@ -3,9 +3,8 @@
 use alloc::format;
 use core::str::FromStr;
 
-use ibc::core::client::context::client_state::ClientStateValidation;
 use ibc::core::client::types::error::ClientError;
-use ibc::core::client::types::Height;
+use ibc::core::client::types::{Height, StatusDetails};
 use ibc::core::host::types::identifiers::ClientId;
 use ibc::core::host::types::path::{
     ClientConsensusStatePath, ClientStatePath, Path, UpgradeClientPath,
@@ -193,14 +192,29 @@ where
     let client_id = ClientId::from_str(request.client_id.as_str())?;
 
     let client_state = ibc_ctx.client_state(&client_id)?;
-    let client_validation_ctx = ibc_ctx.get_client_validation_context();
-    let client_status = client_state.status(client_validation_ctx, &client_id)?;
+    let client_status = ibc_ctx.client_status(&client_state, &client_id)?;
 
     Ok(QueryClientStatusResponse {
         status: format!("{client_status}"),
     })
 }
 
+/// Queries for the status of a given client, detailing why it is not active
+/// or when it expires.
+pub fn query_client_status_details<I>(
+    ibc_ctx: &I,
+    request: &QueryClientStatusRequest,
+) -> Result<StatusDetails, QueryError>
+where
+    I: ValidationContext,
+{
+    let client_id = ClientId::from_str(request.client_id.as_str())?;
+
+    let client_state = ibc_ctx.client_state(&client_id)?;
+
+    Ok(ibc_ctx.client_status_details(&client_state, &client_id)?)
+}
+
 /// Queries for the upgraded client state.
 pub fn query_upgraded_client_state<U>(
     upgrade_ctx: &U,
@@ -4,6 +4,7 @@
 
 use alloc::boxed::Box;
 
+use ibc::core::client::types::StatusDetails;
 use ibc::core::host::ValidationContext;
 use ibc::core::primitives::prelude::*;
 use ibc::cosmos_host::upgrade_proposal::UpgradeValidationContext;
@@ -21,9 +22,9 @@ use ibc_proto::ibc::core::client::v1::{
 use tonic::{Request, Response, Status};
 
 use super::{
-    query_client_state, query_client_states, query_client_status, query_consensus_state,
-    query_consensus_state_heights, query_consensus_states, query_upgraded_client_state,
-    query_upgraded_consensus_state,
+    query_client_state, query_client_states, query_client_status, query_client_status_details,
+    query_consensus_state, query_consensus_state_heights, query_consensus_states,
+    query_upgraded_client_state, query_upgraded_consensus_state,
 };
 use crate::core::context::QueryContext;
 
@@ -61,6 +62,17 @@ where
             upgrade_context,
         }
     }
+
+    /// Returns the status of a client, detailing why it is not active or when
+    /// it expires. Unlike the other queries, it has no gRPC counterpart.
+    pub async fn client_status_details(
+        &self,
+        request: Request<QueryClientStatusRequest>,
+    ) -> Result<Response<StatusDetails>, Status> {
+        let response = query_client_status_details(&self.ibc_context, request.get_ref())?;
+
+        Ok(Response::new(response))
+    }
 }
 
 #[tonic::async_trait]
@@ -1,6 +1,9 @@
+use alloc::format;
 use alloc::string::{String, ToString};
 
 use displaydoc::Display;
+use ibc::apps::nft_transfer::types::error::NftTransferError;
+use ibc::apps::transfer::types::error::TokenTransferError;
 use ibc::core::client::types::error::ClientError;
 use ibc::core::handler::types::error::ContextError;
 use ibc::core::host::types::error::IdentifierError;
@@ -16,6 +19,16 @@ pub enum QueryError {
     IdentifierError(IdentifierError),
     /// Proof not found: {description}
     ProofNotFound { description: String },
+    /// Token transfer error: {0}
+    TokenTransferError(TokenTransferError),
+    /// Denom trace not found: {hash}
+    DenomTraceNotFound { hash: String },
+    /// Invalid pagination: {description}
+    InvalidPagination { description: String },
+    /// NFT transfer error: {0}
+    NftTransferError(NftTransferError),
+    /// Class trace not found: {hash}
+    ClassTraceNotFound { hash: String },
 }
 
 impl From<QueryError> for Status {
@@ -25,6 +38,15 @@ impl From<QueryError> for Status {
             QueryError::ClientError(e) => Status::internal(e.to_string()),
             QueryError::IdentifierError(e) => Status::internal(e.to_string()),
             QueryError::ProofNotFound { description } => Status::not_found(description),
+            QueryError::TokenTransferError(e) => Status::internal(e.to_string()),
+            QueryError::DenomTraceNotFound { hash } => {
+                Status::not_found(format!("Denom trace not found: {hash}"))
+            }
+            QueryError::InvalidPagination { description } => Status::invalid_argument(description),
+            QueryError::NftTransferError(e) => Status::internal(e.to_string()),
+            QueryError::ClassTraceNotFound { hash } => {
+                Status::not_found(format!("Class trace not found: {hash}"))
+            }
         }
     }
 }
@@ -46,3 +68,15 @@ impl From<IdentifierError> for QueryError {
         QueryError::IdentifierError(e)
     }
 }
+
+impl From<TokenTransferError> for QueryError {
+    fn from(e: TokenTransferError) -> Self {
+        QueryError::TokenTransferError(e)
+    }
+}
+
+impl From<NftTransferError> for QueryError {
+    fn from(e: NftTransferError) -> Self {
+        QueryError::NftTransferError(e)
+    }
+}
@@ -14,6 +14,12 @@
 //!     [`ClientQuery::upgraded_client_state`](ibc_proto::ibc::core::client::v1::query_server::Query::upgraded_client_state)
 //!     and
 //!     [`ClientQuery::upgraded_client_state`](ibc_proto::ibc::core::client::v1::query_server::Query::upgraded_consensus_state)
+//! - [`TransferQueryContext`](crate::apps::transfer::TransferQueryContext)
+//!   - Only for the ICS-20
+//!     [`TransferQueryService`](crate::apps::transfer::TransferQueryService)
+//! - [`NftTransferQueryContext`](crate::apps::nft_transfer::NftTransferQueryContext)
+//!   - Only for the ICS-721
+//!     [`NftTransferQueryService`](crate::apps::nft_transfer::NftTransferQueryService)
 //!
 //! Example
 //! ```rust,ignore
@@ -66,5 +72,7 @@
 
 extern crate alloc;
 
+pub mod apps;
 pub mod core;
 pub mod error;
+pub mod pagination;
@@ -0,0 +1,74 @@
+//! Provides the pagination of the results of the list queries, following the
+//! semantics of the Cosmos SDK [`PageRequest`].
+
+use alloc::string::ToString;
+use alloc::vec::Vec;
+
+use ibc_proto::cosmos::base::query::v1beta1::{PageRequest, PageResponse};
+
+use crate::error::QueryError;
+
+/// The number of results returned when the request does not set a limit, as
+/// in the Cosmos SDK.
+pub const DEFAULT_PAGE_LIMIT: u64 = 100;
+
+/// Returns the page of `items` selected by `request`, along with the
+/// pagination details of the response.
+///
+/// `items` must be sorted by their keys, in ascending order. The page starts
+/// either at the item with the given key, or after skipping the given number
+/// of items, but not both.
+pub fn paginate<T>(
+    items: Vec<(Vec<u8>, T)>,
+    request: Option<&PageRequest>,
+) -> Result<(Vec<T>, PageResponse), QueryError> {
+    let request = request.cloned().unwrap_or_default();
+
+    if !request.key.is_empty() && request.offset > 0 {
+        return Err(QueryError::InvalidPagination {
+            description: "either offset or key is expected, got both".to_string(),
+        });
+    }
+
+    let limit = match request.limit {
+        0 => DEFAULT_PAGE_LIMIT,
+        limit => limit,
+    };
+    let limit = usize::try_from(limit).unwrap_or(usize::MAX);
+
+    let total = if request.count_total && request.key.is_empty() {
+        items.len() as u64
+    } else {
+        0
+    };
+
+    let mut items = items;
+    if request.reverse {
+        items.reverse();
+    }
+
+    let start = if request.key.is_empty() {
+        usize::try_from(request.offset).unwrap_or(usize::MAX)
+    } else {
+        items
+            .iter()
+            .position(|(key, _)| {
+                if request.reverse {
+                    key <= &request.key
+                } else {
+                    key >= &request.key
+                }
+            })
+            .unwrap_or(items.len())
+    };
+
+    let mut remaining = items.into_iter().skip(start);
+    let page = remaining
+        .by_ref()
+        .take(limit)
+        .map(|(_, item)| item)
+        .collect();
+    let next_key = remaining.next().map(|(key, _)| key).unwrap_or_default();
+
+    Ok((page, PageResponse { next_key, total }))
+}
@@ -0,0 +1,120 @@
+use ibc::core::channel::types::proto::v1::{
+    MsgChannelUpgradeAck as RawMsgChannelUpgradeAck, Timeout as RawTimeout, Upgrade as RawUpgrade,
+};
+use ibc::core::client::types::proto::v1::Height;
+use ibc::core::host::types::identifiers::{ChannelId, PortId};
+use ibc::core::primitives::prelude::*;
+
+use super::{dummy_proof, dummy_raw_upgrade_fields};
+use crate::fixtures::core::signer::dummy_bech32_account;
+
+/// Returns a dummy `RawUpgrade` with the given version and timeout timestamp,
+/// for testing purposes only!
+pub fn dummy_raw_upgrade(version: &str, timeout_timestamp: u64) -> RawUpgrade {
+    RawUpgrade {
+        fields: Some(dummy_raw_upgrade_fields(version)),
+        timeout: Some(RawTimeout {
+            height: Some(Height {
+                revision_number: 0,
+                revision_height: 0,
+            }),
+            timestamp: timeout_timestamp,
+        }),
+        next_sequence_send: 1,
+    }
+}
+
+/// Returns a dummy `RawMsgChannelUpgradeAck`, for testing purposes only!
+pub fn dummy_raw_msg_chan_upgrade_ack(
+    version: &str,
+    timeout_timestamp: u64,
+    proof_height: u64,
+) -> RawMsgChannelUpgradeAck {
+    RawMsgChannelUpgradeAck {
+        port_id: PortId::transfer().to_string(),
+        channel_id: ChannelId::default().to_string(),
+        counterparty_upgrade: Some(dummy_raw_upgrade(version, timeout_timestamp)),
+        proof_channel: dummy_proof(),
+        proof_upgrade: dummy_proof(),
+        proof_height: Some(Height {
+            revision_number: 0,
+            revision_height: proof_height,
+        }),
+        signer: dummy_bech32_account(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ibc::core::channel::types::msgs::MsgChannelUpgradeAck;
+
+    use super::*;
+
+    #[test]
+    fn parse_channel_upgrade_ack_msg() {
+        struct Test {
+            name: String,
+            raw: RawMsgChannelUpgradeAck,
+            want_pass: bool,
+        }
+
+        let default_raw_msg = dummy_raw_msg_chan_upgrade_ack("ics20-2", u64::MAX, 10);
+
+        let tests: Vec<Test> = vec![
+            Test {
+                name: "Good parameters".to_string(),
+                raw: default_raw_msg.clone(),
+                want_pass: true,
+            },
+            Test {
+                name: "Missing counterparty upgrade".to_string(),
+                raw: RawMsgChannelUpgradeAck {
+                    counterparty_upgrade: None,
+                    ..default_raw_msg.clone()
+                },
+                want_pass: false,
+            },
+            Test {
+                name: "Empty proof of the channel".to_string(),
+                raw: RawMsgChannelUpgradeAck {
+                    proof_channel: vec![],
+                    ..default_raw_msg.clone()
+                },
+                want_pass: false,
+            },
+            Test {
+                name: "Missing proof height".to_string(),
+                raw: RawMsgChannelUpgradeAck {
+                    proof_height: None,
+                    ..default_raw_msg
+                },
+                want_pass: false,
+            },
+        ]
+        .into_iter()
+        .collect();
+
+        for test in tests {
+            let res_msg = MsgChannelUpgradeAck::try_from(test.raw.clone());
+
+            assert_eq!(
+                test.want_pass,
+                res_msg.is_ok(),
+                "MsgChannelUpgradeAck::try_from failed for test {}, \nraw msg {:?} with error {:?}",
+                test.name,
+                test.raw,
+                res_msg.err(),
+            );
+        }
+    }
+
+    #[test]
+    fn to_and_from() {
+        let raw = dummy_raw_msg_chan_upgrade_ack("ics20-2", u64::MAX, 10);
+        let msg = MsgChannelUpgradeAck::try_from(raw.clone()).unwrap();
+        let raw_back = RawMsgChannelUpgradeAck::from(msg.clone());
+        let msg_back = MsgChannelUpgradeAck::try_from(raw_back.clone()).unwrap();
+        assert_eq!(raw, raw_back);
+        assert_eq!(msg, msg_back);
+    }
+}
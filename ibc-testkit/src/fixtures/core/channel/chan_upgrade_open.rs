@@ -0,0 +1,95 @@
+use ibc::core::channel::types::channel::State;
+use ibc::core::channel::types::proto::v1::MsgChannelUpgradeOpen as RawMsgChannelUpgradeOpen;
+use ibc::core::client::types::proto::v1::Height;
+use ibc::core::host::types::identifiers::{ChannelId, PortId};
+use ibc::core::primitives::prelude::*;
+
+use super::dummy_proof;
+use crate::fixtures::core::signer::dummy_bech32_account;
+
+/// Returns a dummy `RawMsgChannelUpgradeOpen`, for testing purposes only!
+pub fn dummy_raw_msg_chan_upgrade_open(
+    chan_state_on_b: State,
+    upgrade_sequence_on_b: u64,
+    proof_height: u64,
+) -> RawMsgChannelUpgradeOpen {
+    RawMsgChannelUpgradeOpen {
+        port_id: PortId::transfer().to_string(),
+        channel_id: ChannelId::default().to_string(),
+        counterparty_channel_state: chan_state_on_b as i32,
+        counterparty_upgrade_sequence: upgrade_sequence_on_b,
+        proof_channel: dummy_proof(),
+        proof_height: Some(Height {
+            revision_number: 0,
+            revision_height: proof_height,
+        }),
+        signer: dummy_bech32_account(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ibc::core::channel::types::msgs::MsgChannelUpgradeOpen;
+
+    use super::*;
+
+    #[test]
+    fn parse_channel_upgrade_open_msg() {
+        struct Test {
+            name: String,
+            raw: RawMsgChannelUpgradeOpen,
+            want_pass: bool,
+        }
+
+        let default_raw_msg = dummy_raw_msg_chan_upgrade_open(State::FlushComplete, 1, 10);
+
+        let tests: Vec<Test> = vec![
+            Test {
+                name: "Good parameters".to_string(),
+                raw: default_raw_msg.clone(),
+                want_pass: true,
+            },
+            Test {
+                name: "Counterparty channel still flushing".to_string(),
+                raw: RawMsgChannelUpgradeOpen {
+                    counterparty_channel_state: State::Flushing as i32,
+                    ..default_raw_msg.clone()
+                },
+                want_pass: false,
+            },
+            Test {
+                name: "Missing proof height".to_string(),
+                raw: RawMsgChannelUpgradeOpen {
+                    proof_height: None,
+                    ..default_raw_msg
+                },
+                want_pass: false,
+            },
+        ]
+        .into_iter()
+        .collect();
+
+        for test in tests {
+            let res_msg = MsgChannelUpgradeOpen::try_from(test.raw.clone());
+
+            assert_eq!(
+                test.want_pass,
+                res_msg.is_ok(),
+                "MsgChannelUpgradeOpen::try_from failed for test {}, \nraw msg {:?} with error {:?}",
+                test.name,
+                test.raw,
+                res_msg.err(),
+            );
+        }
+    }
+
+    #[test]
+    fn to_and_from() {
+        let raw = dummy_raw_msg_chan_upgrade_open(State::Open, 1, 10);
+        let msg = MsgChannelUpgradeOpen::try_from(raw.clone()).unwrap();
+        let raw_back = RawMsgChannelUpgradeOpen::from(msg.clone());
+        let msg_back = MsgChannelUpgradeOpen::try_from(raw_back.clone()).unwrap();
+        assert_eq!(raw, raw_back);
+        assert_eq!(msg, msg_back);
+    }
+}
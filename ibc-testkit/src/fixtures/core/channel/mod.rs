@@ -5,6 +5,13 @@ mod chan_open_ack;
 mod chan_open_confirm;
 mod chan_open_init;
 mod chan_open_try;
+mod chan_upgrade_ack;
+mod chan_upgrade_cancel;
+mod chan_upgrade_confirm;
+mod chan_upgrade_init;
+mod chan_upgrade_open;
+mod chan_upgrade_timeout;
+mod chan_upgrade_try;
 mod packet;
 mod recv_packet;
 mod timeout;
@@ -17,6 +24,13 @@ pub use chan_open_ack::*;
 pub use chan_open_confirm::*;
 pub use chan_open_init::*;
 pub use chan_open_try::*;
+pub use chan_upgrade_ack::*;
+pub use chan_upgrade_cancel::*;
+pub use chan_upgrade_confirm::*;
+pub use chan_upgrade_init::*;
+pub use chan_upgrade_open::*;
+pub use chan_upgrade_timeout::*;
+pub use chan_upgrade_try::*;
 use ibc::core::channel::types::proto::v1::{
     Channel as RawChannel, Counterparty as RawCounterparty,
 };
@@ -48,6 +62,7 @@ pub fn dummy_raw_channel_end(state: i32, channel_id: Option<u64>) -> RawChannel
         counterparty: Some(dummy_raw_counterparty_chan(channel_id)),
         connection_hops: vec![ConnectionId::default().to_string()],
         version: "".to_string(), // The version is not validated.
+        upgrade_sequence: 0,
     }
 }
 
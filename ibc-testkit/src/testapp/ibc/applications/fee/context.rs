@@ -0,0 +1,170 @@
+use ibc::apps::fee::context::{FeeExecutionContext, FeeValidationContext};
+use ibc::apps::fee::types::error::FeeError;
+use ibc::apps::fee::types::{IdentifiedPacketFees, PacketFee};
+use ibc::apps::transfer::types::RawCoin;
+use ibc::core::channel::types::packet::PacketId;
+use ibc::core::host::types::identifiers::{ChannelId, PortId};
+use ibc::core::primitives::prelude::*;
+use ibc::core::primitives::Signer;
+
+use super::types::MockFeeContext;
+
+impl FeeValidationContext for MockFeeContext {
+    type AccountId = Signer;
+
+    fn is_fee_enabled(&self, port_id: &PortId, channel_id: &ChannelId) -> bool {
+        self.fee_enabled_channels
+            .contains(&(port_id.clone(), channel_id.clone()))
+    }
+
+    fn payee(&self, relayer: &Signer, channel_id: &ChannelId) -> Option<Signer> {
+        self.payees
+            .get(&(relayer.clone(), channel_id.clone()))
+            .cloned()
+    }
+
+    fn counterparty_payee(&self, relayer: &Signer, channel_id: &ChannelId) -> Option<Signer> {
+        self.counterparty_payees
+            .get(&(relayer.clone(), channel_id.clone()))
+            .cloned()
+    }
+
+    fn packet_fees(&self, packet_id: &PacketId) -> Result<Vec<PacketFee>, FeeError> {
+        Ok(self.packet_fees.get(packet_id).cloned().unwrap_or_default())
+    }
+
+    fn forward_relayer(&self, packet_id: &PacketId) -> Option<Signer> {
+        self.forward_relayers.get(packet_id).cloned()
+    }
+
+    fn identified_packet_fees_for_channel(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<Vec<IdentifiedPacketFees>, FeeError> {
+        Ok(self
+            .packet_fees
+            .iter()
+            .filter(|(packet_id, _)| {
+                &packet_id.port_id == port_id && &packet_id.chan_id == channel_id
+            })
+            .map(|(packet_id, packet_fees)| {
+                IdentifiedPacketFees::new(packet_id.clone(), packet_fees.clone())
+            })
+            .collect())
+    }
+
+    fn escrow_fee_validate(
+        &self,
+        payer: &Self::AccountId,
+        coins: &[RawCoin],
+    ) -> Result<(), FeeError> {
+        if !self.has_funds(payer, coins) {
+            return Err(FeeError::Other(format!("insufficient funds in {payer}")));
+        }
+        Ok(())
+    }
+}
+
+impl FeeExecutionContext for MockFeeContext {
+    fn enable_fee(&mut self, port_id: &PortId, channel_id: &ChannelId) -> Result<(), FeeError> {
+        self.fee_enabled_channels
+            .insert((port_id.clone(), channel_id.clone()));
+        Ok(())
+    }
+
+    fn disable_fee(&mut self, port_id: &PortId, channel_id: &ChannelId) -> Result<(), FeeError> {
+        self.fee_enabled_channels
+            .remove(&(port_id.clone(), channel_id.clone()));
+        Ok(())
+    }
+
+    fn store_payee(
+        &mut self,
+        relayer: &Signer,
+        channel_id: &ChannelId,
+        payee: &Signer,
+    ) -> Result<(), FeeError> {
+        self.payees
+            .insert((relayer.clone(), channel_id.clone()), payee.clone());
+        Ok(())
+    }
+
+    fn store_counterparty_payee(
+        &mut self,
+        relayer: &Signer,
+        channel_id: &ChannelId,
+        counterparty_payee: &Signer,
+    ) -> Result<(), FeeError> {
+        self.counterparty_payees.insert(
+            (relayer.clone(), channel_id.clone()),
+            counterparty_payee.clone(),
+        );
+        Ok(())
+    }
+
+    fn store_packet_fees(
+        &mut self,
+        packet_id: &PacketId,
+        packet_fees: Vec<PacketFee>,
+    ) -> Result<(), FeeError> {
+        self.packet_fees.insert(packet_id.clone(), packet_fees);
+        Ok(())
+    }
+
+    fn delete_packet_fees(&mut self, packet_id: &PacketId) -> Result<(), FeeError> {
+        self.packet_fees.remove(packet_id);
+        Ok(())
+    }
+
+    fn store_forward_relayer(
+        &mut self,
+        packet_id: &PacketId,
+        forward_relayer: &Signer,
+    ) -> Result<(), FeeError> {
+        self.forward_relayers
+            .insert(packet_id.clone(), forward_relayer.clone());
+        Ok(())
+    }
+
+    fn delete_forward_relayer(&mut self, packet_id: &PacketId) -> Result<(), FeeError> {
+        self.forward_relayers.remove(packet_id);
+        Ok(())
+    }
+
+    fn escrow_fee_execute(
+        &mut self,
+        payer: &Self::AccountId,
+        coins: &[RawCoin],
+    ) -> Result<(), FeeError> {
+        self.escrow_fee_validate(payer, coins)?;
+
+        let escrow = Self::escrow_account();
+        for coin in coins {
+            self.debit(payer, coin)
+                .ok_or_else(|| FeeError::Other(format!("insufficient funds in {payer}")))?;
+            self.credit(&escrow, coin);
+        }
+        Ok(())
+    }
+
+    fn distribute_fee_execute(
+        &mut self,
+        receiver: &Self::AccountId,
+        coins: &[RawCoin],
+    ) -> Result<(), FeeError> {
+        let escrow = Self::escrow_account();
+        if !self.has_funds(&escrow, coins) {
+            return Err(FeeError::Other(
+                "insufficient funds in fee escrow".to_string(),
+            ));
+        }
+
+        for coin in coins {
+            self.debit(&escrow, coin)
+                .ok_or_else(|| FeeError::Other("insufficient funds in fee escrow".to_string()))?;
+            self.credit(receiver, coin);
+        }
+        Ok(())
+    }
+}
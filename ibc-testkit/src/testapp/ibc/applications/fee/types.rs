@@ -0,0 +1,81 @@
+use alloc::collections::{BTreeMap, BTreeSet};
+
+use ibc::apps::fee::types::PacketFee;
+use ibc::apps::transfer::types::{Amount, RawCoin};
+use ibc::core::channel::types::packet::PacketId;
+use ibc::core::host::types::identifiers::{ChannelId, PortId};
+use ibc::core::primitives::prelude::*;
+use ibc::core::primitives::Signer;
+
+/// The account holding the escrowed fees in [`MockFeeContext`].
+pub const FEE_ESCROW_ACCOUNT: &str = "fee-escrow";
+
+/// An in-memory implementation of the fee middleware context, which keeps
+/// track of the balances of the accounts involved in paying fees.
+#[derive(Debug, Default)]
+pub struct MockFeeContext {
+    pub fee_enabled_channels: BTreeSet<(PortId, ChannelId)>,
+    pub payees: BTreeMap<(Signer, ChannelId), Signer>,
+    pub counterparty_payees: BTreeMap<(Signer, ChannelId), Signer>,
+    pub packet_fees: BTreeMap<PacketId, Vec<PacketFee>>,
+    pub forward_relayers: BTreeMap<PacketId, Signer>,
+    pub balances: BTreeMap<(Signer, String), Amount>,
+}
+
+impl MockFeeContext {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Enables fees on the given channel.
+    pub fn with_fee_enabled(mut self, port_id: PortId, channel_id: ChannelId) -> Self {
+        self.fee_enabled_channels.insert((port_id, channel_id));
+        self
+    }
+
+    /// Credits the given coins to the account.
+    pub fn with_balance(mut self, account: Signer, coins: &[RawCoin]) -> Self {
+        for coin in coins {
+            self.credit(&account, coin);
+        }
+        self
+    }
+
+    /// Returns the balance of the account in the given denomination.
+    pub fn balance(&self, account: &Signer, denom: &str) -> Amount {
+        self.balances
+            .get(&(account.clone(), denom.to_string()))
+            .copied()
+            .unwrap_or_else(|| Amount::from(0))
+    }
+
+    pub(crate) fn escrow_account() -> Signer {
+        FEE_ESCROW_ACCOUNT.to_string().into()
+    }
+
+    pub(crate) fn credit(&mut self, account: &Signer, coin: &RawCoin) {
+        let balance = self
+            .balances
+            .entry((account.clone(), coin.denom.clone()))
+            .or_insert_with(|| Amount::from(0));
+        *balance = balance
+            .checked_add(coin.amount)
+            .expect("balance never overflows in tests");
+    }
+
+    pub(crate) fn has_funds(&self, account: &Signer, coins: &[RawCoin]) -> bool {
+        coins.iter().all(|coin| {
+            self.balance(account, &coin.denom)
+                .checked_sub(coin.amount)
+                .is_some()
+        })
+    }
+
+    pub(crate) fn debit(&mut self, account: &Signer, coin: &RawCoin) -> Option<()> {
+        let balance = self
+            .balances
+            .get_mut(&(account.clone(), coin.denom.clone()))?;
+        *balance = balance.checked_sub(coin.amount)?;
+        Some(())
+    }
+}
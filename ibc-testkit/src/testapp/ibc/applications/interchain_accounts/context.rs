@@ -0,0 +1,145 @@
+use ibc::apps::interchain_accounts::context::{
+    InterchainAccountExecutionContext, InterchainAccountValidationContext,
+};
+use ibc::apps::interchain_accounts::controller::context::{
+    ControllerExecutionContext, ControllerValidationContext,
+};
+use ibc::apps::interchain_accounts::host::context::{HostExecutionContext, HostValidationContext};
+use ibc::apps::interchain_accounts::types::error::InterchainAccountsError;
+use ibc::core::host::types::identifiers::{ChannelId, ConnectionId, PortId};
+use ibc::core::primitives::prelude::*;
+use ibc::core::primitives::Signer;
+use ibc_proto::google::protobuf::Any;
+
+use super::types::MockIcaContext;
+
+impl InterchainAccountValidationContext for MockIcaContext {
+    fn channel_connection_id(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<ConnectionId, InterchainAccountsError> {
+        self.channel_connections
+            .get(&(port_id.clone(), channel_id.clone()))
+            .cloned()
+            .ok_or_else(|| {
+                InterchainAccountsError::Other(format!(
+                    "no connection for port {port_id} and channel {channel_id}"
+                ))
+            })
+    }
+
+    fn get_active_channel_id(
+        &self,
+        connection_id: &ConnectionId,
+        port_id: &PortId,
+    ) -> Option<ChannelId> {
+        self.active_channels
+            .get(&(connection_id.clone(), port_id.clone()))
+            .cloned()
+    }
+
+    fn get_interchain_account_address(
+        &self,
+        connection_id: &ConnectionId,
+        port_id: &PortId,
+    ) -> Option<Signer> {
+        self.account_addresses
+            .get(&(connection_id.clone(), port_id.clone()))
+            .cloned()
+    }
+}
+
+impl InterchainAccountExecutionContext for MockIcaContext {
+    fn store_active_channel_id(
+        &mut self,
+        connection_id: ConnectionId,
+        port_id: PortId,
+        channel_id: ChannelId,
+    ) -> Result<(), InterchainAccountsError> {
+        self.active_channels
+            .insert((connection_id, port_id), channel_id);
+        Ok(())
+    }
+
+    fn store_interchain_account_address(
+        &mut self,
+        connection_id: ConnectionId,
+        port_id: PortId,
+        address: Signer,
+    ) -> Result<(), InterchainAccountsError> {
+        self.account_addresses
+            .insert((connection_id, port_id), address);
+        Ok(())
+    }
+}
+
+impl ControllerValidationContext for MockIcaContext {
+    fn is_controller_enabled(&self) -> bool {
+        self.controller_enabled
+    }
+}
+
+impl ControllerExecutionContext for MockIcaContext {}
+
+impl HostValidationContext for MockIcaContext {
+    fn is_host_enabled(&self) -> bool {
+        self.host_enabled
+    }
+
+    fn allow_messages(&self) -> Vec<String> {
+        self.allow_messages.clone()
+    }
+
+    fn generate_account_address(
+        &self,
+        connection_id: &ConnectionId,
+        port_id: &PortId,
+    ) -> Result<Signer, InterchainAccountsError> {
+        let address = Signer::from(format!("ica-{connection_id}-{port_id}"));
+        if self.accounts.contains(&address) {
+            return Err(InterchainAccountsError::Other(format!(
+                "account {address} already exists"
+            )));
+        }
+        Ok(address)
+    }
+
+    fn validate_message(
+        &self,
+        account: &Signer,
+        _msg: &Any,
+    ) -> Result<(), InterchainAccountsError> {
+        if !self.accounts.contains(account) {
+            return Err(InterchainAccountsError::Other(format!(
+                "unknown account {account}"
+            )));
+        }
+        Ok(())
+    }
+}
+
+impl HostExecutionContext for MockIcaContext {
+    fn create_account(&mut self, address: &Signer) -> Result<(), InterchainAccountsError> {
+        self.accounts.insert(address.clone());
+        Ok(())
+    }
+
+    fn execute_message(
+        &mut self,
+        account: &Signer,
+        msg: &Any,
+    ) -> Result<Any, InterchainAccountsError> {
+        if self.failing_messages.contains(&msg.type_url) {
+            return Err(InterchainAccountsError::MessageExecutionFailed {
+                type_url: msg.type_url.clone(),
+                reason: "mock failure".to_string(),
+            });
+        }
+        self.executed_messages.push((account.clone(), msg.clone()));
+        Ok(Any {
+            type_url: format!("{}Response", msg.type_url),
+            value: vec![],
+        })
+    }
+}
@@ -0,0 +1,87 @@
+use alloc::collections::{BTreeMap, BTreeSet};
+
+use ibc::apps::interchain_accounts::host::context::ALLOW_ALL_MESSAGES;
+use ibc::core::host::types::identifiers::{ChannelId, ConnectionId, PortId};
+use ibc::core::primitives::prelude::*;
+use ibc::core::primitives::Signer;
+use ibc_proto::google::protobuf::Any;
+
+/// An in-memory implementation of the interchain accounts contexts, which can
+/// serve both as the controller and as the host context.
+///
+/// Messages are executed by recording them, and respond with an empty message
+/// whose type URL is the one of the executed message suffixed with
+/// `Response`.
+#[derive(Debug)]
+pub struct MockIcaContext {
+    pub controller_enabled: bool,
+    pub host_enabled: bool,
+    pub channel_connections: BTreeMap<(PortId, ChannelId), ConnectionId>,
+    pub active_channels: BTreeMap<(ConnectionId, PortId), ChannelId>,
+    pub account_addresses: BTreeMap<(ConnectionId, PortId), Signer>,
+    pub accounts: BTreeSet<Signer>,
+    pub allow_messages: Vec<String>,
+    /// The type URLs of the messages whose execution fails.
+    pub failing_messages: BTreeSet<String>,
+    pub executed_messages: Vec<(Signer, Any)>,
+}
+
+impl Default for MockIcaContext {
+    fn default() -> Self {
+        Self {
+            controller_enabled: true,
+            host_enabled: true,
+            channel_connections: BTreeMap::new(),
+            active_channels: BTreeMap::new(),
+            account_addresses: BTreeMap::new(),
+            accounts: BTreeSet::new(),
+            allow_messages: vec![ALLOW_ALL_MESSAGES.to_string()],
+            failing_messages: BTreeSet::new(),
+            executed_messages: Vec::new(),
+        }
+    }
+}
+
+impl MockIcaContext {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records that the given channel is built upon the connection.
+    pub fn with_channel_connection(
+        mut self,
+        port_id: PortId,
+        channel_id: ChannelId,
+        connection_id: ConnectionId,
+    ) -> Self {
+        self.channel_connections
+            .insert((port_id, channel_id), connection_id);
+        self
+    }
+
+    /// Registers the interchain account of the controller port over the
+    /// connection.
+    pub fn with_account(
+        mut self,
+        connection_id: ConnectionId,
+        port_id: PortId,
+        address: Signer,
+    ) -> Self {
+        self.accounts.insert(address.clone());
+        self.account_addresses
+            .insert((connection_id, port_id), address);
+        self
+    }
+
+    /// Replaces the allow list of the host.
+    pub fn with_allow_messages(mut self, allow_messages: Vec<String>) -> Self {
+        self.allow_messages = allow_messages;
+        self
+    }
+
+    /// Makes the execution of the messages of the given type fail.
+    pub fn with_failing_message(mut self, type_url: &str) -> Self {
+        self.failing_messages.insert(type_url.to_string());
+        self
+    }
+}
@@ -1,2 +1,9 @@
+pub mod fee;
+#[cfg(feature = "serde")]
+pub mod interchain_accounts;
 pub mod nft_transfer;
+#[cfg(feature = "serde")]
+pub mod packet_forward;
+#[cfg(feature = "serde")]
+pub mod rate_limit;
 pub mod transfer;
@@ -1,9 +1,10 @@
 use ibc::apps::nft_transfer::context::{
-    NftClassContext, NftContext, NftTransferExecutionContext, NftTransferValidationContext,
+    NftClassContext, NftClassTraceContext, NftContext, NftReceiveHook, NftTransferExecutionContext,
+    NftTransferValidationContext,
 };
 use ibc::apps::nft_transfer::types::error::NftTransferError;
 use ibc::apps::nft_transfer::types::{
-    ClassData, ClassId, ClassUri, Memo, PrefixedClassId, TokenData, TokenId, TokenUri,
+    ClassData, ClassId, ClassUri, Memo, PrefixedClassId, TokenData, TokenId, TokenIds, TokenUri,
 };
 use ibc::core::host::types::identifiers::{ChannelId, PortId};
 use ibc::core::primitives::prelude::*;
@@ -43,6 +44,13 @@ impl NftClassContext for DummyNftClass {
     }
 }
 
+impl DummyNftTransferModule {
+    fn set_owner(&mut self, class_id: &PrefixedClassId, token_id: &TokenId, owner: Signer) {
+        self.owners
+            .insert((class_id.to_string(), token_id.to_string()), owner);
+    }
+}
+
 impl NftTransferValidationContext for DummyNftTransferModule {
     type AccountId = Signer;
     type Nft = DummyNft;
@@ -129,57 +137,115 @@ impl NftTransferValidationContext for DummyNftTransferModule {
     }
 }
 
+impl NftClassTraceContext for DummyNftTransferModule {
+    fn class_trace(&self, hash: &str) -> Result<Option<PrefixedClassId>, NftTransferError> {
+        Ok(self.class_traces.get(hash).cloned())
+    }
+
+    fn class_traces(&self) -> Result<Vec<(String, PrefixedClassId)>, NftTransferError> {
+        Ok(self
+            .class_traces
+            .iter()
+            .map(|(hash, class_id)| (hash.clone(), class_id.clone()))
+            .collect())
+    }
+
+    fn store_class_trace(
+        &mut self,
+        hash: String,
+        class_id: PrefixedClassId,
+    ) -> Result<(), NftTransferError> {
+        self.class_traces.insert(hash, class_id);
+        Ok(())
+    }
+}
+
+impl NftReceiveHook for DummyNftTransferModule {
+    fn on_nft_received(
+        &mut self,
+        _receiver: &Self::AccountId,
+        _class_id: &PrefixedClassId,
+        _token_ids: &TokenIds,
+        memo: &Memo,
+    ) -> Result<(), NftTransferError> {
+        if self.reject_receive {
+            return Err(NftTransferError::ReceiveRejected {
+                reason: "receive hook vetoed the NFTs".to_string(),
+            });
+        }
+
+        self.received_memos.push(memo.clone());
+        Ok(())
+    }
+}
+
 impl NftTransferExecutionContext for DummyNftTransferModule {
     fn create_or_update_class_execute(
         &self,
-        _class_id: &PrefixedClassId,
+        class_id: &PrefixedClassId,
         _class_uri: Option<&ClassUri>,
         _class_data: Option<&ClassData>,
     ) -> Result<(), NftTransferError> {
+        self.classes.borrow_mut().insert(class_id.to_string());
         Ok(())
     }
 
     fn escrow_nft_execute(
         &mut self,
         _from_account: &Self::AccountId,
-        _port_id: &PortId,
-        _channel_id: &ChannelId,
-        _class_id: &PrefixedClassId,
-        _token_id: &TokenId,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        class_id: &PrefixedClassId,
+        token_id: &TokenId,
         _memo: &Memo,
     ) -> Result<(), NftTransferError> {
+        self.set_owner(
+            class_id,
+            token_id,
+            Self::escrow_account(port_id, channel_id),
+        );
         Ok(())
     }
 
     fn unescrow_nft_execute(
         &mut self,
-        _to_account: &Self::AccountId,
+        to_account: &Self::AccountId,
         _port_id: &PortId,
         _channel_id: &ChannelId,
-        _class_id: &PrefixedClassId,
-        _token_id: &TokenId,
+        class_id: &PrefixedClassId,
+        token_id: &TokenId,
     ) -> Result<(), NftTransferError> {
+        self.set_owner(class_id, token_id, to_account.clone());
         Ok(())
     }
 
     fn mint_nft_execute(
         &mut self,
-        _account: &Self::AccountId,
-        _class_id: &PrefixedClassId,
-        _token_id: &TokenId,
+        account: &Self::AccountId,
+        class_id: &PrefixedClassId,
+        token_id: &TokenId,
         _token_uri: Option<&TokenUri>,
         _token_data: Option<&TokenData>,
     ) -> Result<(), NftTransferError> {
+        if self.owner(class_id, token_id).is_some() {
+            return Err(NftTransferError::Other(format!(
+                "NFT {class_id}/{token_id} already exists"
+            )));
+        }
+
+        self.set_owner(class_id, token_id, account.clone());
         Ok(())
     }
 
     fn burn_nft_execute(
         &mut self,
         _account: &Self::AccountId,
-        _class_id: &PrefixedClassId,
-        _token_id: &TokenId,
+        class_id: &PrefixedClassId,
+        token_id: &TokenId,
         _memo: &Memo,
     ) -> Result<(), NftTransferError> {
+        self.owners
+            .remove(&(class_id.to_string(), token_id.to_string()));
         Ok(())
     }
 }
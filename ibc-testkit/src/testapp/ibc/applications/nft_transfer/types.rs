@@ -1,7 +1,27 @@
-use ibc::apps::nft_transfer::types::{ClassData, ClassId, ClassUri, TokenData, TokenId, TokenUri};
+use alloc::collections::{BTreeMap, BTreeSet};
+use core::cell::RefCell;
 
-#[derive(Debug)]
-pub struct DummyNftTransferModule;
+use ibc::apps::nft_transfer::types::{
+    ClassData, ClassId, ClassUri, Memo, PrefixedClassId, TokenData, TokenId, TokenUri,
+};
+use ibc::core::host::types::identifiers::{ChannelId, PortId};
+use ibc::core::primitives::prelude::*;
+use ibc::core::primitives::Signer;
+
+#[derive(Debug, Default)]
+pub struct DummyNftTransferModule {
+    /// The traces of the classes of the minted NFTs, by hash.
+    pub class_traces: BTreeMap<String, PrefixedClassId>,
+    /// The owners of the NFTs, by class ID and token ID.
+    pub owners: BTreeMap<(String, String), Signer>,
+    /// The memos of the packets whose NFTs were received.
+    pub received_memos: Vec<Memo>,
+    /// Whether the receive hook vetoes the receive of NFTs.
+    pub reject_receive: bool,
+    /// The IDs of the created or updated classes. Behind a `RefCell`, as
+    /// classes are created through a shared reference.
+    pub classes: RefCell<BTreeSet<String>>,
+}
 
 #[derive(Debug)]
 pub struct DummyNft {
@@ -50,12 +70,17 @@ impl Default for DummyNftClass {
 
 impl DummyNftTransferModule {
     pub fn new() -> Self {
-        Self
+        Self::default()
     }
-}
 
-impl Default for DummyNftTransferModule {
-    fn default() -> Self {
-        Self::new()
+    /// Returns the owner of the NFT, if it exists.
+    pub fn owner(&self, class_id: &PrefixedClassId, token_id: &TokenId) -> Option<&Signer> {
+        self.owners
+            .get(&(class_id.to_string(), token_id.to_string()))
+    }
+
+    /// Returns the account holding the NFTs escrowed on the given channel.
+    pub fn escrow_account(port_id: &PortId, channel_id: &ChannelId) -> Signer {
+        format!("escrow/{port_id}/{channel_id}").into()
     }
 }
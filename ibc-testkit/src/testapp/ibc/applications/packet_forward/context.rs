@@ -0,0 +1,153 @@
+use ibc::apps::packet_forward::context::{
+    PacketForwardExecutionContext, PacketForwardValidationContext,
+};
+use ibc::apps::packet_forward::types::error::PacketForwardError;
+use ibc::apps::packet_forward::types::InFlightPacket;
+use ibc::core::channel::context::{SendPacketExecutionContext, SendPacketValidationContext};
+use ibc::core::channel::handler::write_acknowledgement;
+use ibc::core::channel::types::acknowledgement::Acknowledgement;
+use ibc::core::channel::types::channel::ChannelEnd;
+use ibc::core::channel::types::commitment::PacketCommitment;
+use ibc::core::channel::types::packet::{Packet, PacketId};
+use ibc::core::client::types::Status;
+use ibc::core::commitment_types::merkle::HostFunctionsManager;
+use ibc::core::connection::types::ConnectionEnd;
+use ibc::core::handler::types::error::ContextError;
+use ibc::core::handler::types::events::IbcEvent;
+use ibc::core::host::types::identifiers::{ChannelId, ClientId, ConnectionId, Sequence};
+use ibc::core::host::types::path::{
+    ChannelEndPath, ClientConsensusStatePath, CommitmentPath, SeqSendPath,
+};
+use ibc::core::host::{ExecutionContext, ValidationContext};
+use ibc::core::primitives::prelude::*;
+use ibc::core::primitives::{Signer, Timestamp};
+
+use super::types::MockPacketForwardContext;
+use crate::testapp::ibc::clients::{AnyClientState, AnyConsensusState};
+use crate::testapp::ibc::core::types::MockContext;
+
+impl SendPacketValidationContext for MockPacketForwardContext {
+    type V = MockContext;
+    type E = MockContext;
+    type AnyConsensusState = AnyConsensusState;
+    type AnyClientState = AnyClientState;
+    type HostFunctions = HostFunctionsManager;
+
+    fn get_client_validation_context(&self) -> &Self::V {
+        &self.ibc
+    }
+
+    fn channel_end(&self, channel_end_path: &ChannelEndPath) -> Result<ChannelEnd, ContextError> {
+        ValidationContext::channel_end(&self.ibc, channel_end_path)
+    }
+
+    fn connection_end(&self, connection_id: &ConnectionId) -> Result<ConnectionEnd, ContextError> {
+        ValidationContext::connection_end(&self.ibc, connection_id)
+    }
+
+    fn client_state(&self, client_id: &ClientId) -> Result<Self::AnyClientState, ContextError> {
+        ValidationContext::client_state(&self.ibc, client_id)
+    }
+
+    fn client_status(
+        &self,
+        client_state: &Self::AnyClientState,
+        client_id: &ClientId,
+    ) -> Result<Status, ContextError> {
+        ValidationContext::client_status(&self.ibc, client_state, client_id)
+    }
+
+    fn client_consensus_state(
+        &self,
+        client_cons_state_path: &ClientConsensusStatePath,
+    ) -> Result<Self::AnyConsensusState, ContextError> {
+        ValidationContext::consensus_state(&self.ibc, client_cons_state_path)
+    }
+
+    fn get_next_sequence_send(
+        &self,
+        seq_send_path: &SeqSendPath,
+    ) -> Result<Sequence, ContextError> {
+        ValidationContext::get_next_sequence_send(&self.ibc, seq_send_path)
+    }
+}
+
+impl SendPacketExecutionContext for MockPacketForwardContext {
+    fn store_next_sequence_send(
+        &mut self,
+        seq_send_path: &SeqSendPath,
+        seq: Sequence,
+    ) -> Result<(), ContextError> {
+        ExecutionContext::store_next_sequence_send(&mut self.ibc, seq_send_path, seq)
+    }
+
+    fn store_packet_commitment(
+        &mut self,
+        commitment_path: &CommitmentPath,
+        commitment: PacketCommitment,
+    ) -> Result<(), ContextError> {
+        ExecutionContext::store_packet_commitment(&mut self.ibc, commitment_path, commitment)
+    }
+
+    fn emit_ibc_event(&mut self, event: IbcEvent) -> Result<(), ContextError> {
+        ExecutionContext::emit_ibc_event(&mut self.ibc, event)
+    }
+
+    fn log_message(&mut self, message: String) -> Result<(), ContextError> {
+        ExecutionContext::log_message(&mut self.ibc, message)
+    }
+}
+
+impl PacketForwardValidationContext for MockPacketForwardContext {
+    fn intermediate_receiver(
+        &self,
+        channel_id: &ChannelId,
+        original_sender: &Signer,
+    ) -> Result<Signer, PacketForwardError> {
+        Ok(format!("pfm/{channel_id}/{original_sender}").into())
+    }
+
+    fn host_timestamp(&self) -> Result<Timestamp, PacketForwardError> {
+        Ok(ValidationContext::host_timestamp(&self.ibc)?)
+    }
+
+    fn in_flight_packet(
+        &self,
+        packet_id: &PacketId,
+    ) -> Result<Option<InFlightPacket>, PacketForwardError> {
+        Ok(self.in_flight_packets.get(packet_id).cloned())
+    }
+}
+
+impl PacketForwardExecutionContext for MockPacketForwardContext {
+    fn store_in_flight_packet(
+        &mut self,
+        packet_id: PacketId,
+        in_flight_packet: InFlightPacket,
+    ) -> Result<(), PacketForwardError> {
+        self.in_flight_packets.insert(packet_id, in_flight_packet);
+        Ok(())
+    }
+
+    fn delete_in_flight_packet(&mut self, packet_id: &PacketId) -> Result<(), PacketForwardError> {
+        self.in_flight_packets.remove(packet_id);
+        Ok(())
+    }
+
+    fn write_acknowledgement(
+        &mut self,
+        packet: &Packet,
+        acknowledgement: Acknowledgement,
+    ) -> Result<(), PacketForwardError> {
+        if let Some(deferred_acks) = self.deferred_acks.as_mut() {
+            deferred_acks.push((packet.clone(), acknowledgement));
+            return Ok(());
+        }
+
+        Ok(write_acknowledgement(
+            &mut self.ibc,
+            packet.clone(),
+            acknowledgement,
+        )?)
+    }
+}
@@ -0,0 +1,39 @@
+use alloc::collections::BTreeMap;
+
+use ibc::apps::packet_forward::types::InFlightPacket;
+use ibc::core::channel::types::acknowledgement::Acknowledgement;
+use ibc::core::channel::types::packet::{Packet, PacketId};
+use ibc::core::primitives::prelude::*;
+
+use crate::testapp::ibc::core::types::MockContext;
+
+/// An in-memory implementation of the packet-forward middleware context,
+/// which sends the forwarded packets and writes the acknowledgements of the
+/// original packets on the wrapped [`MockContext`].
+///
+/// With [`MockPacketForwardContext::with_deferred_acks`], the
+/// acknowledgements are queued in `deferred_acks` instead, for the test to
+/// write them through the middleware stacked on top of this one.
+#[derive(Debug, Default)]
+pub struct MockPacketForwardContext {
+    pub ibc: MockContext,
+    pub in_flight_packets: BTreeMap<PacketId, InFlightPacket>,
+    pub deferred_acks: Option<Vec<(Packet, Acknowledgement)>>,
+}
+
+impl MockPacketForwardContext {
+    pub fn new(ibc: MockContext) -> Self {
+        Self {
+            ibc,
+            in_flight_packets: BTreeMap::new(),
+            deferred_acks: None,
+        }
+    }
+
+    /// Queues the acknowledgements of the original packets instead of
+    /// writing them.
+    pub fn with_deferred_acks(mut self) -> Self {
+        self.deferred_acks = Some(Vec::new());
+        self
+    }
+}
@@ -0,0 +1,103 @@
+use ibc::apps::rate_limit::context::{RateLimitExecutionContext, RateLimitValidationContext};
+use ibc::apps::rate_limit::types::error::RateLimitError;
+use ibc::apps::rate_limit::types::{RateLimit, RateLimitPath, RecordedFlow};
+use ibc::apps::transfer::types::{Amount, PrefixedDenom, VERSION};
+use ibc::core::channel::types::Version;
+use ibc::core::host::types::identifiers::{ChannelId, PortId, Sequence};
+use ibc::core::primitives::prelude::*;
+use ibc::core::primitives::{Signer, Timestamp};
+
+use super::types::MockRateLimitContext;
+
+impl RateLimitValidationContext for MockRateLimitContext {
+    fn authority(&self) -> Result<Signer, RateLimitError> {
+        Ok(self.authority.clone())
+    }
+
+    fn host_timestamp(&self) -> Result<Timestamp, RateLimitError> {
+        Ok(self.host_timestamp)
+    }
+
+    fn denom_supply(&self, denom: &PrefixedDenom) -> Result<Amount, RateLimitError> {
+        Ok(self
+            .supplies
+            .get(denom)
+            .copied()
+            .unwrap_or_else(|| Amount::from(0)))
+    }
+
+    fn rate_limits(&self, path: &RateLimitPath) -> Result<Vec<RateLimit>, RateLimitError> {
+        Ok(self
+            .rate_limits
+            .get(path)
+            .map(|rate_limits| rate_limits.values().cloned().collect())
+            .unwrap_or_default())
+    }
+
+    /// The mock only rate-limits `ics20-1` channels.
+    fn channel_version(
+        &self,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+    ) -> Result<Version, RateLimitError> {
+        Ok(Version::new(VERSION.to_string()))
+    }
+
+    fn pending_send_flows(
+        &self,
+        channel_id: &ChannelId,
+        sequence: Sequence,
+    ) -> Result<Vec<RecordedFlow>, RateLimitError> {
+        Ok(self
+            .pending_send_flows
+            .get(&(channel_id.clone(), sequence))
+            .cloned()
+            .unwrap_or_default())
+    }
+}
+
+impl RateLimitExecutionContext for MockRateLimitContext {
+    fn store_rate_limit(
+        &mut self,
+        path: RateLimitPath,
+        rate_limit: RateLimit,
+    ) -> Result<(), RateLimitError> {
+        self.rate_limits
+            .entry(path)
+            .or_default()
+            .insert(rate_limit.quota.name.clone(), rate_limit);
+        Ok(())
+    }
+
+    fn delete_rate_limit(
+        &mut self,
+        path: &RateLimitPath,
+        quota_name: &str,
+    ) -> Result<(), RateLimitError> {
+        if let Some(rate_limits) = self.rate_limits.get_mut(path) {
+            rate_limits.remove(quota_name);
+        }
+        Ok(())
+    }
+
+    fn store_pending_send_flows(
+        &mut self,
+        channel_id: ChannelId,
+        sequence: Sequence,
+        flows: Vec<RecordedFlow>,
+    ) -> Result<(), RateLimitError> {
+        self.pending_send_flows
+            .insert((channel_id, sequence), flows);
+        Ok(())
+    }
+
+    fn delete_pending_send_flows(
+        &mut self,
+        channel_id: &ChannelId,
+        sequence: Sequence,
+    ) -> Result<(), RateLimitError> {
+        self.pending_send_flows
+            .remove(&(channel_id.clone(), sequence));
+        Ok(())
+    }
+}
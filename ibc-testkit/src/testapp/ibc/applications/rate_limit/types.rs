@@ -0,0 +1,43 @@
+use alloc::collections::BTreeMap;
+
+use ibc::apps::rate_limit::types::{RateLimit, RateLimitPath, RecordedFlow};
+use ibc::apps::transfer::types::{Amount, PrefixedDenom};
+use ibc::core::host::types::identifiers::{ChannelId, Sequence};
+use ibc::core::primitives::prelude::*;
+use ibc::core::primitives::{Signer, Timestamp};
+
+/// An in-memory implementation of the rate-limit middleware context, whose
+/// host time and denomination supplies are set by the tests.
+#[derive(Debug)]
+pub struct MockRateLimitContext {
+    pub authority: Signer,
+    pub host_timestamp: Timestamp,
+    pub supplies: BTreeMap<PrefixedDenom, Amount>,
+    pub rate_limits: BTreeMap<RateLimitPath, BTreeMap<String, RateLimit>>,
+    pub pending_send_flows: BTreeMap<(ChannelId, Sequence), Vec<RecordedFlow>>,
+}
+
+impl MockRateLimitContext {
+    pub fn new(authority: Signer) -> Self {
+        Self {
+            authority,
+            host_timestamp: Timestamp::now(),
+            supplies: BTreeMap::new(),
+            rate_limits: BTreeMap::new(),
+            pending_send_flows: BTreeMap::new(),
+        }
+    }
+
+    /// Sets the supply of the denomination.
+    pub fn with_supply(mut self, denom: PrefixedDenom, supply: Amount) -> Self {
+        self.supplies.insert(denom, supply);
+        self
+    }
+
+    /// Returns the rate limit with the given quota name on the path.
+    pub fn rate_limit(&self, path: &RateLimitPath, quota_name: &str) -> Option<&RateLimit> {
+        self.rate_limits
+            .get(path)
+            .and_then(|rate_limits| rate_limits.get(quota_name))
+    }
+}
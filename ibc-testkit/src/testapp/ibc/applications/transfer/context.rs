@@ -1,10 +1,15 @@
-use ibc::apps::transfer::context::{TokenTransferExecutionContext, TokenTransferValidationContext};
+use ibc::apps::transfer::context::{
+    DenomTraceExecutionContext, DenomTraceValidationContext, TokenTransferExecutionContext,
+    TokenTransferValidationContext, TotalEscrowInvariantContext,
+};
 use ibc::apps::transfer::types::error::TokenTransferError;
-use ibc::apps::transfer::types::{Memo, PrefixedCoin};
+use ibc::apps::transfer::types::{Amount, Memo, PrefixedCoin, PrefixedDenom, VERSION};
+use ibc::core::channel::types::Version;
 use ibc::core::host::types::identifiers::{ChannelId, PortId};
+use ibc::core::primitives::prelude::*;
 use ibc::core::primitives::Signer;
 
-use super::types::DummyTransferModule;
+use super::types::{DummyTransferModule, MockTransferModule};
 
 impl TokenTransferValidationContext for DummyTransferModule {
     type AccountId = Signer;
@@ -13,6 +18,14 @@ impl TokenTransferValidationContext for DummyTransferModule {
         Ok(PortId::transfer())
     }
 
+    fn channel_version(
+        &self,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+    ) -> Result<Version, TokenTransferError> {
+        Ok(Version::new(VERSION.to_string()))
+    }
+
     fn can_send_coins(&self) -> Result<(), TokenTransferError> {
         Ok(())
     }
@@ -57,6 +70,30 @@ impl TokenTransferValidationContext for DummyTransferModule {
     ) -> Result<(), TokenTransferError> {
         Ok(())
     }
+
+    fn total_escrow_for_denom(&self, _denom: &PrefixedDenom) -> Result<Amount, TokenTransferError> {
+        Ok(Amount::from(0))
+    }
+}
+
+impl DenomTraceValidationContext for DummyTransferModule {
+    fn denom_trace(&self, _hash: &str) -> Result<Option<PrefixedDenom>, TokenTransferError> {
+        Ok(None)
+    }
+
+    fn denom_traces(&self) -> Result<Vec<(String, PrefixedDenom)>, TokenTransferError> {
+        Ok(Vec::new())
+    }
+}
+
+impl DenomTraceExecutionContext for DummyTransferModule {
+    fn store_denom_trace(
+        &mut self,
+        _hash: String,
+        _denom: PrefixedDenom,
+    ) -> Result<(), TokenTransferError> {
+        Ok(())
+    }
 }
 
 impl TokenTransferExecutionContext for DummyTransferModule {
@@ -97,4 +134,244 @@ impl TokenTransferExecutionContext for DummyTransferModule {
     ) -> Result<(), TokenTransferError> {
         Ok(())
     }
+
+    fn store_total_escrow_for_denom(
+        &mut self,
+        _denom: PrefixedDenom,
+        _amount: Amount,
+    ) -> Result<(), TokenTransferError> {
+        Ok(())
+    }
+}
+
+impl MockTransferModule {
+    fn has_funds(&self, account: &Signer, coin: &PrefixedCoin) -> bool {
+        self.balance(account, &coin.denom.to_string())
+            .checked_sub(coin.amount)
+            .is_some()
+    }
+
+    fn debit(&mut self, account: &Signer, coin: &PrefixedCoin) -> Option<()> {
+        let balance = self
+            .balances
+            .get_mut(&(account.clone(), coin.denom.to_string()))?;
+        *balance = balance.checked_sub(coin.amount)?;
+        Some(())
+    }
+
+    fn ensure_funds(
+        &self,
+        account: &Signer,
+        coin: &PrefixedCoin,
+    ) -> Result<(), TokenTransferError> {
+        if self.has_funds(account, coin) {
+            Ok(())
+        } else {
+            Err(TokenTransferError::InsufficientFunds {
+                send_attempt: coin.to_string(),
+                available_funds: self.balance(account, &coin.denom.to_string()).to_string(),
+            })
+        }
+    }
+
+    fn transfer(
+        &mut self,
+        from: &Signer,
+        to: &Signer,
+        coin: &PrefixedCoin,
+    ) -> Result<(), TokenTransferError> {
+        self.ensure_funds(from, coin)?;
+        self.debit(from, coin);
+        self.credit(to, coin);
+        Ok(())
+    }
+}
+
+impl TokenTransferValidationContext for MockTransferModule {
+    type AccountId = Signer;
+
+    fn get_port(&self) -> Result<PortId, TokenTransferError> {
+        Ok(PortId::transfer())
+    }
+
+    fn channel_version(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<Version, TokenTransferError> {
+        Ok(self
+            .channel_versions
+            .get(&(port_id.clone(), channel_id.clone()))
+            .cloned()
+            .unwrap_or_else(|| Version::new(VERSION.to_string())))
+    }
+
+    fn can_send_coins(&self) -> Result<(), TokenTransferError> {
+        Ok(())
+    }
+
+    fn can_receive_coins(&self) -> Result<(), TokenTransferError> {
+        Ok(())
+    }
+
+    fn escrow_coins_validate(
+        &self,
+        from_account: &Self::AccountId,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+        coin: &PrefixedCoin,
+        _memo: &Memo,
+    ) -> Result<(), TokenTransferError> {
+        self.ensure_funds(from_account, coin)
+    }
+
+    fn unescrow_coins_validate(
+        &self,
+        _to_account: &Self::AccountId,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        coin: &PrefixedCoin,
+    ) -> Result<(), TokenTransferError> {
+        self.ensure_funds(&Self::escrow_account(port_id, channel_id), coin)
+    }
+
+    fn mint_coins_validate(
+        &self,
+        _account: &Self::AccountId,
+        _coin: &PrefixedCoin,
+    ) -> Result<(), TokenTransferError> {
+        Ok(())
+    }
+
+    fn burn_coins_validate(
+        &self,
+        account: &Self::AccountId,
+        coin: &PrefixedCoin,
+        _memo: &Memo,
+    ) -> Result<(), TokenTransferError> {
+        self.ensure_funds(account, coin)
+    }
+
+    fn total_escrow_for_denom(&self, denom: &PrefixedDenom) -> Result<Amount, TokenTransferError> {
+        Ok(self
+            .total_escrows
+            .get(&denom.to_string())
+            .copied()
+            .unwrap_or_else(|| Amount::from(0)))
+    }
+
+    fn denom_hash_string(&self, denom: &PrefixedDenom) -> Option<String> {
+        if denom.trace_path.is_empty() {
+            None
+        } else {
+            Some(denom.trace_hash())
+        }
+    }
+}
+
+impl TotalEscrowInvariantContext for MockTransferModule {
+    fn total_escrows(&self) -> Result<Vec<(PrefixedDenom, Amount)>, TokenTransferError> {
+        self.total_escrows
+            .iter()
+            .map(|(denom, amount)| Ok((denom.parse()?, *amount)))
+            .collect()
+    }
+
+    fn escrowed_balance(&self, denom: &PrefixedDenom) -> Result<Amount, TokenTransferError> {
+        let denom = denom.to_string();
+
+        self.balances
+            .iter()
+            .filter(|((account, balance_denom), _)| {
+                account.as_ref().starts_with("escrow/") && *balance_denom == denom
+            })
+            .try_fold(Amount::from(0), |escrowed, (_, amount)| {
+                escrowed.checked_add(*amount).ok_or_else(|| {
+                    TokenTransferError::TotalEscrowOverflow {
+                        denom: denom.clone(),
+                    }
+                })
+            })
+    }
+}
+
+impl DenomTraceValidationContext for MockTransferModule {
+    fn denom_trace(&self, hash: &str) -> Result<Option<PrefixedDenom>, TokenTransferError> {
+        Ok(self.denom_traces.get(hash).cloned())
+    }
+
+    fn denom_traces(&self) -> Result<Vec<(String, PrefixedDenom)>, TokenTransferError> {
+        Ok(self
+            .denom_traces
+            .iter()
+            .map(|(hash, denom)| (hash.clone(), denom.clone()))
+            .collect())
+    }
+}
+
+impl DenomTraceExecutionContext for MockTransferModule {
+    fn store_denom_trace(
+        &mut self,
+        hash: String,
+        denom: PrefixedDenom,
+    ) -> Result<(), TokenTransferError> {
+        self.denom_traces.insert(hash, denom);
+        Ok(())
+    }
+}
+
+impl TokenTransferExecutionContext for MockTransferModule {
+    fn escrow_coins_execute(
+        &mut self,
+        from_account: &Self::AccountId,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        coin: &PrefixedCoin,
+        _memo: &Memo,
+    ) -> Result<(), TokenTransferError> {
+        self.transfer(
+            from_account,
+            &Self::escrow_account(port_id, channel_id),
+            coin,
+        )
+    }
+
+    fn unescrow_coins_execute(
+        &mut self,
+        to_account: &Self::AccountId,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        coin: &PrefixedCoin,
+    ) -> Result<(), TokenTransferError> {
+        self.transfer(&Self::escrow_account(port_id, channel_id), to_account, coin)
+    }
+
+    fn mint_coins_execute(
+        &mut self,
+        account: &Self::AccountId,
+        coin: &PrefixedCoin,
+    ) -> Result<(), TokenTransferError> {
+        self.credit(account, coin);
+        Ok(())
+    }
+
+    fn burn_coins_execute(
+        &mut self,
+        account: &Self::AccountId,
+        coin: &PrefixedCoin,
+        _memo: &Memo,
+    ) -> Result<(), TokenTransferError> {
+        self.ensure_funds(account, coin)?;
+        self.debit(account, coin);
+        Ok(())
+    }
+
+    fn store_total_escrow_for_denom(
+        &mut self,
+        denom: PrefixedDenom,
+        amount: Amount,
+    ) -> Result<(), TokenTransferError> {
+        self.total_escrows.insert(denom.to_string(), amount);
+        Ok(())
+    }
 }
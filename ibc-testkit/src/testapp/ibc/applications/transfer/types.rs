@@ -1,4 +1,12 @@
-#[derive(Debug)]
+use alloc::collections::BTreeMap;
+
+use ibc::apps::transfer::types::{Amount, PrefixedCoin, PrefixedDenom};
+use ibc::core::channel::types::Version;
+use ibc::core::host::types::identifiers::{ChannelId, PortId};
+use ibc::core::primitives::prelude::*;
+use ibc::core::primitives::Signer;
+
+#[derive(Clone, Debug)]
 pub struct DummyTransferModule;
 
 impl DummyTransferModule {
@@ -12,3 +20,65 @@ impl Default for DummyTransferModule {
         Self::new()
     }
 }
+
+/// A token transfer application keeping track of balances in memory, which
+/// runs the actual ICS-20 callbacks on the packets it handles.
+#[derive(Clone, Debug, Default)]
+pub struct MockTransferModule {
+    pub balances: BTreeMap<(Signer, String), Amount>,
+    /// The traces of the denominations of the minted vouchers, by hash.
+    pub denom_traces: BTreeMap<String, PrefixedDenom>,
+    /// The total amount of each native denomination held in escrow.
+    pub total_escrows: BTreeMap<String, Amount>,
+    /// The versions of the channels, which are `ics20-1` unless set
+    /// otherwise.
+    pub channel_versions: BTreeMap<(PortId, ChannelId), Version>,
+}
+
+impl MockTransferModule {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Credits the given coins to the account.
+    pub fn with_balance(mut self, account: Signer, coins: &[PrefixedCoin]) -> Self {
+        for coin in coins {
+            self.credit(&account, coin);
+        }
+        self
+    }
+
+    /// Sets the version of the given channel.
+    pub fn with_channel_version(
+        mut self,
+        port_id: PortId,
+        channel_id: ChannelId,
+        version: Version,
+    ) -> Self {
+        self.channel_versions.insert((port_id, channel_id), version);
+        self
+    }
+
+    /// Returns the balance of the account in the given denomination.
+    pub fn balance(&self, account: &Signer, denom: &str) -> Amount {
+        self.balances
+            .get(&(account.clone(), denom.to_string()))
+            .copied()
+            .unwrap_or_else(|| Amount::from(0))
+    }
+
+    /// Returns the account holding the tokens escrowed on the given channel.
+    pub fn escrow_account(port_id: &PortId, channel_id: &ChannelId) -> Signer {
+        format!("escrow/{port_id}/{channel_id}").into()
+    }
+
+    pub(crate) fn credit(&mut self, account: &Signer, coin: &PrefixedCoin) {
+        let balance = self
+            .balances
+            .entry((account.clone(), coin.denom.to_string()))
+            .or_insert_with(|| Amount::from(0));
+        *balance = balance
+            .checked_add(coin.amount)
+            .expect("balance never overflows in tests");
+    }
+}
@@ -6,7 +6,7 @@ use ibc::core::client::context::client_state::{
 };
 use ibc::core::client::context::{ClientExecutionContext, ClientValidationContext};
 use ibc::core::client::types::error::{ClientError, UpgradeClientError};
-use ibc::core::client::types::{Height, Status, UpdateKind};
+use ibc::core::client::types::{Height, Status, StatusDetails, UpdateKind};
 use ibc::core::commitment_types::commitment::{
     CommitmentPrefix, CommitmentProofBytes, CommitmentRoot,
 };
@@ -80,7 +80,13 @@ impl TryFrom<RawMockClientState> for MockClientState {
     type Error = ClientError;
 
     fn try_from(raw: RawMockClientState) -> Result<Self, Self::Error> {
-        Ok(Self::new(raw.header.expect("Never fails").try_into()?))
+        let client_state = Self::new(raw.header.expect("Never fails").try_into()?);
+
+        if raw.frozen {
+            return Ok(client_state.with_frozen_height(Height::min(0)));
+        }
+
+        Ok(client_state)
     }
 }
 
@@ -91,6 +97,8 @@ impl From<MockClientState> for RawMockClientState {
                 height: Some(value.header.height().into()),
                 timestamp: value.header.timestamp.nanoseconds(),
             }),
+            trusting_period: 0,
+            frozen: value.is_frozen(),
         }
     }
 }
@@ -300,6 +308,29 @@ where
 
         Ok(Status::Active)
     }
+
+    fn status_details(&self, ctx: &V, client_id: &ClientId) -> Result<StatusDetails, ClientError> {
+        let status = self.status(ctx, client_id)?;
+
+        let latest_consensus_timestamp = match ctx.consensus_state(&ClientConsensusStatePath::new(
+            client_id.clone(),
+            self.latest_height().revision_number(),
+            self.latest_height().revision_height(),
+        )) {
+            Ok(cs) => {
+                let latest_consensus_state: MockConsensusState = cs.try_into()?;
+                Some(latest_consensus_state.timestamp())
+            }
+            Err(_) => None,
+        };
+
+        // The mock client never expires.
+        Ok(StatusDetails {
+            latest_consensus_timestamp,
+            frozen_height: self.frozen_height,
+            ..status.into()
+        })
+    }
 }
 
 impl<E> ClientStateExecution<E> for MockClientState
@@ -400,6 +431,53 @@ where
 
         Ok(latest_height)
     }
+
+    fn check_substitute_and_update_state(
+        &self,
+        ctx: &mut E,
+        subject_client_id: &ClientId,
+        substitute_client_id: &ClientId,
+        substitute_client_state: Self,
+    ) -> Result<(), ClientError> {
+        let latest_height = substitute_client_state.latest_height();
+
+        let substitute_consensus_state: MockConsensusState =
+            <E as MockClientContext>::consensus_state(
+                ctx,
+                &ClientConsensusStatePath::new(
+                    substitute_client_id.clone(),
+                    latest_height.revision_number(),
+                    latest_height.revision_height(),
+                ),
+            )?
+            .try_into()
+            .map_err(|err| ClientError::Other {
+                description: err.to_string(),
+            })?;
+
+        let new_client_state = MockClientState::new(substitute_client_state.header);
+
+        ctx.store_consensus_state(
+            ClientConsensusStatePath::new(
+                subject_client_id.clone(),
+                latest_height.revision_number(),
+                latest_height.revision_height(),
+            ),
+            substitute_consensus_state.into(),
+        )?;
+        ctx.store_client_state(
+            ClientStatePath::new(subject_client_id),
+            new_client_state.into(),
+        )?;
+
+        let host_timestamp = ctx.host_timestamp()?;
+        let host_height = ctx.host_height()?;
+
+        ctx.store_update_time(subject_client_id.clone(), latest_height, host_timestamp)?;
+        ctx.store_update_height(subject_client_id.clone(), latest_height, host_height)?;
+
+        Ok(())
+    }
 }
 
 impl From<MockConsensusState> for MockClientState {
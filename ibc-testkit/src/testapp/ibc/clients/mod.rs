@@ -1,11 +1,26 @@
 pub mod mock;
+pub mod wasm;
 
 use derive_more::{From, TryInto};
+use ibc::clients::localhost::client_state::ClientState as LocalhostClientState;
+use ibc::clients::localhost::consensus_state::ConsensusState as LocalhostConsensusState;
+use ibc::clients::localhost::types::{
+    LOCALHOST_CLIENT_STATE_TYPE_URL, LOCALHOST_CONSENSUS_STATE_TYPE_URL,
+};
+use ibc::clients::solomachine::client_state::ClientState as SolomachineClientState;
+use ibc::clients::solomachine::consensus_state::ConsensusState as SolomachineConsensusState;
+use ibc::clients::solomachine::types::{
+    SOLOMACHINE_CLIENT_STATE_TYPE_URL, SOLOMACHINE_CONSENSUS_STATE_TYPE_URL,
+};
 use ibc::clients::tendermint::client_state::ClientState as TmClientState;
 use ibc::clients::tendermint::consensus_state::ConsensusState as TmConsensusState;
 use ibc::clients::tendermint::types::{
     TENDERMINT_CLIENT_STATE_TYPE_URL, TENDERMINT_CONSENSUS_STATE_TYPE_URL,
 };
+use ibc::clients::wasm::client_state::ClientState as WasmClientState;
+use ibc::clients::wasm::consensus_state::ConsensusState as WasmConsensusState;
+use ibc::clients::wasm::types::client_state::WASM_CLIENT_STATE_TYPE_URL;
+use ibc::clients::wasm::types::consensus_state::WASM_CONSENSUS_STATE_TYPE_URL;
 use ibc::core::client::types::error::ClientError;
 use ibc::core::primitives::prelude::*;
 use ibc::derive::{ClientState, ConsensusState};
@@ -24,6 +39,9 @@ use crate::testapp::ibc::core::types::MockContext;
 #[execution(MockContext)]
 pub enum AnyClientState {
     Tendermint(TmClientState),
+    Localhost(LocalhostClientState),
+    Solomachine(SolomachineClientState),
+    Wasm(WasmClientState),
     Mock(MockClientState),
 }
 
@@ -35,6 +53,12 @@ impl TryFrom<Any> for AnyClientState {
     fn try_from(raw: Any) -> Result<Self, Self::Error> {
         if raw.type_url == TENDERMINT_CLIENT_STATE_TYPE_URL {
             Ok(TmClientState::try_from(raw)?.into())
+        } else if raw.type_url == LOCALHOST_CLIENT_STATE_TYPE_URL {
+            LocalhostClientState::try_from(raw).map(Into::into)
+        } else if raw.type_url == SOLOMACHINE_CLIENT_STATE_TYPE_URL {
+            SolomachineClientState::try_from(raw).map(Into::into)
+        } else if raw.type_url == WASM_CLIENT_STATE_TYPE_URL {
+            WasmClientState::try_from(raw).map(Into::into)
         } else if raw.type_url == MOCK_CLIENT_STATE_TYPE_URL {
             MockClientState::try_from(raw).map(Into::into)
         } else {
@@ -49,6 +73,9 @@ impl From<AnyClientState> for Any {
     fn from(host_client_state: AnyClientState) -> Self {
         match host_client_state {
             AnyClientState::Tendermint(cs) => cs.into(),
+            AnyClientState::Localhost(cs) => cs.into(),
+            AnyClientState::Solomachine(cs) => cs.into(),
+            AnyClientState::Wasm(cs) => cs.into(),
             AnyClientState::Mock(cs) => cs.into(),
         }
     }
@@ -57,6 +84,9 @@ impl From<AnyClientState> for Any {
 #[derive(Debug, Clone, From, TryInto, PartialEq, ConsensusState)]
 pub enum AnyConsensusState {
     Tendermint(TmConsensusState),
+    Localhost(LocalhostConsensusState),
+    Solomachine(SolomachineConsensusState),
+    Wasm(WasmConsensusState),
     Mock(MockConsensusState),
 }
 
@@ -68,6 +98,12 @@ impl TryFrom<Any> for AnyConsensusState {
     fn try_from(raw: Any) -> Result<Self, Self::Error> {
         if raw.type_url == TENDERMINT_CONSENSUS_STATE_TYPE_URL {
             Ok(TmConsensusState::try_from(raw)?.into())
+        } else if raw.type_url == LOCALHOST_CONSENSUS_STATE_TYPE_URL {
+            LocalhostConsensusState::try_from(raw).map(Into::into)
+        } else if raw.type_url == SOLOMACHINE_CONSENSUS_STATE_TYPE_URL {
+            SolomachineConsensusState::try_from(raw).map(Into::into)
+        } else if raw.type_url == WASM_CONSENSUS_STATE_TYPE_URL {
+            WasmConsensusState::try_from(raw).map(Into::into)
         } else if raw.type_url == MOCK_CONSENSUS_STATE_TYPE_URL {
             MockConsensusState::try_from(raw).map(Into::into)
         } else {
@@ -82,6 +118,9 @@ impl From<AnyConsensusState> for Any {
     fn from(host_consensus_state: AnyConsensusState) -> Self {
         match host_consensus_state {
             AnyConsensusState::Tendermint(cs) => cs.into(),
+            AnyConsensusState::Localhost(cs) => cs.into(),
+            AnyConsensusState::Solomachine(cs) => cs.into(),
+            AnyConsensusState::Wasm(cs) => cs.into(),
             AnyConsensusState::Mock(cs) => cs.into(),
         }
     }
@@ -0,0 +1,278 @@
+//! An in-process light client contract for testing the ICS-08 Wasm client.
+//!
+//! [`MockContext`] acts as the Wasm engine and runs every stored contract as
+//! this mock contract, which wraps the mock light client: the `data` of the
+//! Wasm client states, consensus states and client messages holds the
+//! `Any`-encoded [`MockClientState`], [`MockConsensusState`] and
+//! [`MockHeader`] or [`Misbehaviour`].
+
+use ibc::clients::wasm::client_state::ClientState as WasmClientState;
+use ibc::clients::wasm::consensus_state::ConsensusState as WasmConsensusState;
+use ibc::clients::wasm::context::{ValidationContext as WasmValidationContext, WasmEngine};
+use ibc::clients::wasm::types::contract::{
+    ContractResult, InstantiateMsg, QueryMsg, SudoMsg, VerifyMembershipMsg, VerifyNonMembershipMsg,
+};
+use ibc::clients::wasm::types::Bytes;
+use ibc::core::client::context::ClientExecutionContext;
+use ibc::core::client::types::error::ClientError;
+use ibc::core::client::types::Height;
+use ibc::core::host::types::identifiers::ClientId;
+use ibc::core::host::types::path::{ClientConsensusStatePath, ClientStatePath};
+use ibc::core::host::ValidationContext;
+use ibc::core::primitives::prelude::*;
+use ibc::primitives::proto::{Any, Protobuf};
+use sha2::{Digest, Sha256};
+
+use crate::testapp::ibc::clients::mock::client_state::MockClientState;
+use crate::testapp::ibc::clients::mock::consensus_state::MockConsensusState;
+use crate::testapp::ibc::clients::mock::header::MockHeader;
+use crate::testapp::ibc::clients::mock::misbehaviour::Misbehaviour;
+use crate::testapp::ibc::clients::AnyConsensusState;
+use crate::testapp::ibc::core::types::MockContext;
+
+/// Encodes a mock light client type as the `data` of a Wasm type.
+pub fn encode_contract_data<T>(value: T) -> Bytes
+where
+    T: Protobuf<Any> + TryFrom<Any>,
+    Any: From<T>,
+    <T as TryFrom<Any>>::Error: core::fmt::Display,
+{
+    Protobuf::<Any>::encode_vec(value)
+}
+
+fn decode_contract_data<T>(data: &[u8]) -> Result<T, ClientError>
+where
+    T: Protobuf<Any> + TryFrom<Any>,
+    Any: From<T>,
+    <T as TryFrom<Any>>::Error: core::fmt::Display,
+{
+    Protobuf::<Any>::decode_vec(data).map_err(|e| ClientError::Other {
+        description: e.to_string(),
+    })
+}
+
+impl MockContext {
+    fn mock_client_state(&self, client_id: &ClientId) -> Result<MockClientState, ClientError> {
+        let client_state = self
+            .wasm_client_state(client_id)
+            .map_err(|e| ClientError::Other {
+                description: e.to_string(),
+            })?;
+
+        decode_contract_data(&client_state.data)
+    }
+
+    fn mock_consensus_state(
+        &self,
+        client_id: &ClientId,
+        height: &Height,
+    ) -> Result<MockConsensusState, ClientError> {
+        let consensus_state = ValidationContext::consensus_state(
+            self,
+            &ClientConsensusStatePath::new(
+                client_id.clone(),
+                height.revision_number(),
+                height.revision_height(),
+            ),
+        )
+        .map_err(|e| ClientError::Other {
+            description: e.to_string(),
+        })?;
+
+        match consensus_state {
+            AnyConsensusState::Wasm(cs) => decode_contract_data(&cs.inner().data),
+            _ => Err(ClientError::Other {
+                description: format!("client {client_id} is not a Wasm client"),
+            }),
+        }
+    }
+
+    fn store_mock_client_state(
+        &mut self,
+        client_id: &ClientId,
+        checksum: &[u8],
+        client_state: MockClientState,
+    ) -> Result<(), ClientError> {
+        let latest_height = client_state.latest_height();
+        let wasm_client_state = WasmClientState::new(
+            encode_contract_data(client_state),
+            checksum.to_vec(),
+            latest_height,
+        );
+
+        self.store_client_state(ClientStatePath::new(client_id), wasm_client_state.into())?;
+
+        Ok(())
+    }
+
+    fn verify_mock_proof(&self, client_id: &ClientId, height: &Height) -> Result<(), ClientError> {
+        // Like the mock client, the mock contract accepts any proof, as long
+        // as the client has a consensus state at the proof height.
+        self.mock_consensus_state(client_id, height).map(|_| ())
+    }
+}
+
+impl WasmEngine for MockContext {
+    fn store_code(&mut self, wasm_byte_code: &[u8]) -> Result<Bytes, ClientError> {
+        Ok(Sha256::digest(wasm_byte_code).to_vec())
+    }
+
+    fn instantiate(
+        &mut self,
+        _client_id: &ClientId,
+        _checksum: &[u8],
+        msg: InstantiateMsg,
+    ) -> Result<(), ClientError> {
+        decode_contract_data::<MockClientState>(&msg.client_state)?;
+        decode_contract_data::<MockConsensusState>(&msg.consensus_state)?;
+
+        Ok(())
+    }
+
+    fn sudo(
+        &mut self,
+        client_id: &ClientId,
+        checksum: &[u8],
+        msg: SudoMsg,
+    ) -> Result<ContractResult, ClientError> {
+        match msg {
+            SudoMsg::UpdateState(msg) => {
+                let header: MockHeader = decode_contract_data(&msg.client_message)?;
+                let height = header.height();
+
+                self.store_mock_client_state(client_id, checksum, MockClientState::new(header))?;
+                self.store_consensus_state(
+                    ClientConsensusStatePath::new(
+                        client_id.clone(),
+                        height.revision_number(),
+                        height.revision_height(),
+                    ),
+                    WasmConsensusState::new(encode_contract_data(MockConsensusState::new(header)))
+                        .into(),
+                )?;
+
+                Ok(ContractResult::heights(vec![height]))
+            }
+            SudoMsg::UpdateStateOnMisbehaviour(_) => {
+                let client_state = self.mock_client_state(client_id)?;
+
+                self.store_mock_client_state(
+                    client_id,
+                    checksum,
+                    client_state.with_frozen_height(Height::min(0)),
+                )?;
+
+                Ok(ContractResult::default())
+            }
+            SudoMsg::VerifyMembership(_) | SudoMsg::VerifyNonMembership(_) => {
+                self.sudo_read_only(client_id, checksum, msg)
+            }
+            SudoMsg::VerifyUpgradeAndUpdateState(_) | SudoMsg::MigrateClientStore(_) => {
+                Err(ClientError::Other {
+                    description: "not supported by the mock contract".into(),
+                })
+            }
+        }
+    }
+
+    fn sudo_read_only(
+        &self,
+        client_id: &ClientId,
+        _checksum: &[u8],
+        msg: SudoMsg,
+    ) -> Result<ContractResult, ClientError> {
+        match msg {
+            SudoMsg::VerifyMembership(VerifyMembershipMsg { height, .. })
+            | SudoMsg::VerifyNonMembership(VerifyNonMembershipMsg { height, .. }) => {
+                self.verify_mock_proof(client_id, &height)?;
+
+                Ok(ContractResult::default())
+            }
+            _ => Err(ClientError::Other {
+                description: "only proof verifications can be run read-only".into(),
+            }),
+        }
+    }
+
+    fn query(
+        &self,
+        client_id: &ClientId,
+        _checksum: &[u8],
+        msg: QueryMsg,
+    ) -> Result<ContractResult, ClientError> {
+        match msg {
+            QueryMsg::Status(_) => {
+                let client_state = self.mock_client_state(client_id)?;
+                let status = if client_state.is_frozen() {
+                    "Frozen"
+                } else {
+                    "Active"
+                };
+
+                Ok(ContractResult::status(status))
+            }
+            QueryMsg::TimestampAtHeight(msg) => {
+                let consensus_state = self.mock_consensus_state(client_id, &msg.height)?;
+
+                Ok(ContractResult::timestamp(
+                    consensus_state.timestamp().nanoseconds(),
+                ))
+            }
+            QueryMsg::VerifyClientMessage(msg) => {
+                if let Ok(header) = decode_contract_data::<MockHeader>(&msg.client_message) {
+                    let latest_height = self.mock_client_state(client_id)?.latest_height();
+                    if latest_height >= header.height() {
+                        return Err(ClientError::LowHeaderHeight {
+                            header_height: header.height(),
+                            latest_height,
+                        });
+                    }
+                } else {
+                    decode_contract_data::<Misbehaviour>(&msg.client_message)?;
+                }
+
+                Ok(ContractResult::default())
+            }
+            QueryMsg::CheckForMisbehaviour(msg) => Ok(ContractResult::found_misbehaviour(
+                decode_contract_data::<Misbehaviour>(&msg.client_message).is_ok(),
+            )),
+        }
+    }
+
+    fn migrate(
+        &mut self,
+        client_id: &ClientId,
+        _checksum: &[u8],
+        _msg: &[u8],
+    ) -> Result<(), ClientError> {
+        self.mock_client_state(client_id).map(|_| ())
+    }
+
+    fn migrate_client_store(
+        &mut self,
+        subject_client_id: &ClientId,
+        substitute_client_id: &ClientId,
+        checksum: &[u8],
+    ) -> Result<ContractResult, ClientError> {
+        let substitute_client_state = self.mock_client_state(substitute_client_id)?;
+        let height = substitute_client_state.latest_height();
+        let substitute_consensus_state =
+            self.mock_consensus_state(substitute_client_id, &height)?;
+
+        self.store_mock_client_state(
+            subject_client_id,
+            checksum,
+            MockClientState::new(substitute_client_state.header),
+        )?;
+        self.store_consensus_state(
+            ClientConsensusStatePath::new(
+                subject_client_id.clone(),
+                height.revision_number(),
+                height.revision_height(),
+            ),
+            WasmConsensusState::new(encode_contract_data(substitute_consensus_state)).into(),
+        )?;
+
+        Ok(ContractResult::heights(vec![height]))
+    }
+}
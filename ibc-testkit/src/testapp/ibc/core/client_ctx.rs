@@ -1,18 +1,35 @@
 use alloc::collections::BTreeMap;
 use alloc::vec::Vec;
+use alloc::{format, vec};
 
+use ibc::clients::localhost::context::{
+    CommonContext as LocalhostCommonContext, ValidationContext as LocalhostValidationContext,
+};
+use ibc::clients::solomachine::context::{
+    CommonContext as SolomachineCommonContext, ValidationContext as SolomachineValidationContext,
+};
+use ibc::clients::solomachine::types::error::Error as SolomachineError;
 use ibc::clients::tendermint::context::{
-    CommonContext as TmCommonContext, ValidationContext as TmValidationContext,
+    CommonContext as TmCommonContext, PruningPolicy, ValidationContext as TmValidationContext,
 };
+use ibc::clients::wasm::context::{
+    CommonContext as WasmCommonContext, ExecutionContext as WasmExecutionContext,
+    ValidationContext as WasmValidationContext,
+};
+use ibc::clients::wasm::types::client_state::ClientState as WasmClientStateType;
+use ibc::clients::wasm::types::Bytes;
 use ibc::core::client::context::{ClientExecutionContext, ClientValidationContext};
 use ibc::core::client::types::error::ClientError;
 use ibc::core::client::types::Height;
+use ibc::core::handler::cache::ClientStoreCheckpoint;
 use ibc::core::handler::types::error::ContextError;
 use ibc::core::host::types::identifiers::{ChannelId, ClientId, PortId};
-use ibc::core::host::types::path::{ClientConsensusStatePath, ClientStatePath};
+use ibc::core::host::types::path::{ClientConsensusStatePath, ClientStatePath, Path};
 use ibc::core::host::ValidationContext;
-use ibc::core::primitives::Timestamp;
+use ibc::core::primitives::{Signer, Timestamp, ToVec};
+use ibc::primitives::proto::{Any, Protobuf};
 
+use crate::fixtures::core::signer::dummy_account_id;
 use crate::testapp::ibc::clients::mock::client_state::MockClientContext;
 use crate::testapp::ibc::clients::{AnyClientState, AnyConsensusState};
 use crate::testapp::ibc::core::types::MockContext;
@@ -83,6 +100,39 @@ impl TmCommonContext for MockContext {
 
         Ok(heights)
     }
+
+    fn first_consensus_state_height(
+        &self,
+        client_id: &ClientId,
+    ) -> Result<Option<Height>, ContextError> {
+        let ibc_store = self.ibc_store.lock();
+        let client_record =
+            ibc_store
+                .clients
+                .get(client_id)
+                .ok_or_else(|| ClientError::ClientStateNotFound {
+                    client_id: client_id.clone(),
+                })?;
+
+        Ok(client_record.consensus_states.keys().next().cloned())
+    }
+
+    fn consensus_state_count(&self, client_id: &ClientId) -> Result<u64, ContextError> {
+        let ibc_store = self.ibc_store.lock();
+        let client_record =
+            ibc_store
+                .clients
+                .get(client_id)
+                .ok_or_else(|| ClientError::ClientStateNotFound {
+                    client_id: client_id.clone(),
+                })?;
+
+        Ok(client_record.consensus_states.len() as u64)
+    }
+
+    fn pruning_policy(&self) -> PruningPolicy {
+        self.ibc_store.lock().tm_pruning_policy
+    }
 }
 
 impl TmValidationContext for MockContext {
@@ -155,6 +205,142 @@ impl TmValidationContext for MockContext {
     }
 }
 
+impl LocalhostCommonContext for MockContext {
+    fn host_timestamp(&self) -> Result<Timestamp, ContextError> {
+        ValidationContext::host_timestamp(self)
+    }
+
+    fn host_height(&self) -> Result<Height, ContextError> {
+        ValidationContext::host_height(self)
+    }
+}
+
+impl SolomachineCommonContext for MockContext {
+    fn host_timestamp(&self) -> Result<Timestamp, ContextError> {
+        ValidationContext::host_timestamp(self)
+    }
+
+    fn host_height(&self) -> Result<Height, ContextError> {
+        ValidationContext::host_height(self)
+    }
+}
+
+impl SolomachineValidationContext for MockContext {
+    /// The mock context does not ship with a secp256k1 implementation, so
+    /// solo machine tests sign with ed25519 keys.
+    fn verify_secp256k1_signature(
+        &self,
+        _public_key: &[u8],
+        _message: &[u8],
+        _signature: &[u8],
+    ) -> Result<(), ClientError> {
+        Err(SolomachineError::SignatureVerification {
+            reason: "secp256k1 signatures are not supported by the mock context".into(),
+        })?
+    }
+
+    fn verify_ed25519_signature(
+        &self,
+        public_key: &[u8],
+        message: &[u8],
+        signature: &[u8],
+    ) -> Result<(), ClientError> {
+        let signature_verification_error = |e: ed25519_consensus::Error| -> ClientError {
+            SolomachineError::SignatureVerification {
+                reason: format!("{e:?}"),
+            }
+            .into()
+        };
+
+        let verification_key = ed25519_consensus::VerificationKey::try_from(public_key)
+            .map_err(signature_verification_error)?;
+        let signature = ed25519_consensus::Signature::try_from(signature)
+            .map_err(signature_verification_error)?;
+
+        verification_key
+            .verify(&signature, message)
+            .map_err(signature_verification_error)
+    }
+}
+
+impl WasmCommonContext for MockContext {
+    fn host_timestamp(&self) -> Result<Timestamp, ContextError> {
+        ValidationContext::host_timestamp(self)
+    }
+
+    fn host_height(&self) -> Result<Height, ContextError> {
+        ValidationContext::host_height(self)
+    }
+}
+
+impl WasmValidationContext for MockContext {
+    fn has_checksum(&self, checksum: &[u8]) -> Result<bool, ContextError> {
+        Ok(self.ibc_store.lock().wasm_checksums.contains(checksum))
+    }
+
+    /// Light client contracts are managed by the dummy account.
+    fn authority(&self) -> Signer {
+        dummy_account_id()
+    }
+
+    fn wasm_client_state(&self, client_id: &ClientId) -> Result<WasmClientStateType, ContextError> {
+        match ValidationContext::client_state(self, client_id)? {
+            AnyClientState::Wasm(client_state) => Ok(client_state.inner().clone()),
+            _ => Err(ClientError::Other {
+                description: format!("client {client_id} is not a Wasm client"),
+            })?,
+        }
+    }
+}
+
+impl WasmExecutionContext for MockContext {
+    fn store_checksum(&mut self, checksum: Bytes) -> Result<(), ContextError> {
+        self.ibc_store.lock().wasm_checksums.insert(checksum);
+        Ok(())
+    }
+
+    fn delete_checksum(&mut self, checksum: &[u8]) -> Result<(), ContextError> {
+        self.ibc_store.lock().wasm_checksums.remove(checksum);
+        Ok(())
+    }
+}
+
+impl LocalhostValidationContext for MockContext {
+    /// Encodes the stored values the same way the IBC handlers encode the
+    /// values they verify. Any lookup error is treated as the value being
+    /// absent, since the mock getters only fail when nothing is stored.
+    fn stored_value(&self, path: &Path) -> Result<Option<Vec<u8>>, ContextError> {
+        let value = match path {
+            Path::ClientState(path) => self
+                .client_state(&path.0)
+                .ok()
+                .map(|cs| Any::from(cs).to_vec()),
+            Path::ClientConsensusState(path) => ValidationContext::consensus_state(self, path)
+                .ok()
+                .map(|cs| Any::from(cs).to_vec()),
+            Path::Connection(path) => self.connection_end(&path.0).ok().map(|c| c.encode_vec()),
+            Path::ChannelEnd(path) => self.channel_end(path).ok().map(|c| c.encode_vec()),
+            Path::SeqSend(path) => self.get_next_sequence_send(path).ok().map(|s| s.to_vec()),
+            Path::SeqRecv(path) => self.get_next_sequence_recv(path).ok().map(|s| s.to_vec()),
+            Path::SeqAck(path) => self.get_next_sequence_ack(path).ok().map(|s| s.to_vec()),
+            Path::Commitment(path) => self.get_packet_commitment(path).ok().map(|c| c.into_vec()),
+            Path::Ack(path) => self
+                .get_packet_acknowledgement(path)
+                .ok()
+                .map(|a| a.into_vec()),
+            Path::Receipt(path) => self.get_packet_receipt(path).ok().map(|_| vec![1]),
+            Path::ChannelUpgrade(path) => self.channel_upgrade(path).ok().map(|u| u.encode_vec()),
+            Path::ChannelUpgradeError(path) => self
+                .channel_upgrade_error_receipt(path)
+                .ok()
+                .map(|e| e.encode_vec()),
+            Path::ClientConnection(_) | Path::Ports(_) | Path::UpgradeClient(_) => None,
+        };
+
+        Ok(value)
+    }
+}
+
 impl ClientValidationContext for MockContext {
     fn client_update_time(
         &self,
@@ -193,6 +379,10 @@ impl ClientValidationContext for MockContext {
             })?,
         }
     }
+
+    fn is_client_authority(&self, signer: &Signer) -> bool {
+        self.ibc_store.lock().client_authority.as_ref() == Some(signer)
+    }
 }
 
 impl ClientExecutionContext for MockContext {
@@ -331,3 +521,34 @@ impl ClientExecutionContext for MockContext {
         Ok(())
     }
 }
+
+/// A copy of the client related parts of the
+/// [`MockIbcStore`](crate::testapp::ibc::core::types::MockIbcStore).
+#[derive(Clone, Debug)]
+pub struct MockClientStoreCheckpoint {
+    clients: BTreeMap<ClientId, MockClientRecord>,
+    client_processed_times: BTreeMap<(ClientId, Height), Timestamp>,
+    client_processed_heights: BTreeMap<(ClientId, Height), Height>,
+}
+
+impl ClientStoreCheckpoint for MockContext {
+    type Checkpoint = MockClientStoreCheckpoint;
+
+    fn checkpoint(&self) -> Self::Checkpoint {
+        let ibc_store = self.ibc_store.lock();
+
+        MockClientStoreCheckpoint {
+            clients: ibc_store.clients.clone(),
+            client_processed_times: ibc_store.client_processed_times.clone(),
+            client_processed_heights: ibc_store.client_processed_heights.clone(),
+        }
+    }
+
+    fn revert(&mut self, checkpoint: Self::Checkpoint) {
+        let mut ibc_store = self.ibc_store.lock();
+
+        ibc_store.clients = checkpoint.clients;
+        ibc_store.client_processed_times = checkpoint.client_processed_times;
+        ibc_store.client_processed_heights = checkpoint.client_processed_heights;
+    }
+}
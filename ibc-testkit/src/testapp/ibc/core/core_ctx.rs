@@ -3,22 +3,27 @@
 use core::ops::Add;
 use core::time::Duration;
 
+use ibc::clients::solomachine::client_state::ClientState as SolomachineClientState;
 use ibc::clients::tendermint::client_state::ClientState;
+use ibc::clients::wasm::client_state::ClientState as WasmClientState;
 use ibc::core::channel::types::channel::ChannelEnd;
 use ibc::core::channel::types::commitment::{AcknowledgementCommitment, PacketCommitment};
 use ibc::core::channel::types::error::{ChannelError, PacketError};
 use ibc::core::channel::types::packet::Receipt;
+use ibc::core::channel::types::upgrade::{ErrorReceipt, Upgrade};
 use ibc::core::client::types::error::ClientError;
-use ibc::core::client::types::Height;
+use ibc::core::client::types::{AllowedClients, Height};
 use ibc::core::commitment_types::commitment::CommitmentPrefix;
+use ibc::core::commitment_types::merkle::HostFunctionsManager;
 use ibc::core::connection::types::error::ConnectionError;
 use ibc::core::connection::types::ConnectionEnd;
 use ibc::core::handler::types::error::ContextError;
 use ibc::core::handler::types::events::IbcEvent;
-use ibc::core::host::types::identifiers::{ClientId, ConnectionId, Sequence};
+use ibc::core::host::types::identifiers::{ChannelId, ClientId, ConnectionId, PortId, Sequence};
 use ibc::core::host::types::path::{
-    AckPath, ChannelEndPath, ClientConnectionPath, ClientConsensusStatePath, CommitmentPath,
-    ConnectionPath, ReceiptPath, SeqAckPath, SeqRecvPath, SeqSendPath,
+    AckPath, ChannelEndPath, ChannelUpgradeErrorPath, ChannelUpgradePath, ClientConnectionPath,
+    ClientConsensusStatePath, CommitmentPath, ConnectionPath, ReceiptPath, SeqAckPath, SeqRecvPath,
+    SeqSendPath,
 };
 use ibc::core::host::{ExecutionContext, ValidationContext};
 use ibc::core::primitives::prelude::*;
@@ -34,6 +39,7 @@ impl ValidationContext for MockContext {
     type E = Self;
     type AnyConsensusState = AnyConsensusState;
     type AnyClientState = AnyClientState;
+    type HostFunctions = HostFunctionsManager;
 
     fn client_state(&self, client_id: &ClientId) -> Result<Self::AnyClientState, ContextError> {
         match self.ibc_store.lock().clients.get(client_id) {
@@ -56,6 +62,10 @@ impl ValidationContext for MockContext {
         if let Ok(client_state) = ClientState::try_from(client_state.clone()) {
             client_state.inner().validate().map_err(ClientError::from)?;
             Ok(client_state.into())
+        } else if let Ok(client_state) = SolomachineClientState::try_from(client_state.clone()) {
+            Ok(client_state.into())
+        } else if let Ok(client_state) = WasmClientState::try_from(client_state.clone()) {
+            Ok(client_state.into())
         } else if let Ok(client_state) = MockClientState::try_from(client_state.clone()) {
             Ok(client_state.into())
         } else {
@@ -118,6 +128,10 @@ impl ValidationContext for MockContext {
         Ok(self.ibc_store.lock().client_ids_counter)
     }
 
+    fn allowed_clients(&self) -> Result<AllowedClients, ContextError> {
+        Ok(self.ibc_store.lock().allowed_clients.clone())
+    }
+
     fn connection_end(&self, cid: &ConnectionId) -> Result<ConnectionEnd, ContextError> {
         match self.ibc_store.lock().connections.get(cid) {
             Some(connection_end) => Ok(connection_end.clone()),
@@ -336,6 +350,87 @@ impl ValidationContext for MockContext {
         Ok(self.ibc_store.lock().channel_ids_counter)
     }
 
+    fn channel_upgrade(&self, upgrade_path: &ChannelUpgradePath) -> Result<Upgrade, ContextError> {
+        let port_id = &upgrade_path.0;
+        let channel_id = &upgrade_path.1;
+
+        match self
+            .ibc_store
+            .lock()
+            .channel_upgrades
+            .get(port_id)
+            .and_then(|map| map.get(channel_id))
+        {
+            Some(upgrade) => Ok(upgrade.clone()),
+            None => Err(ChannelError::UpgradeNotFound {
+                port_id: port_id.clone(),
+                channel_id: channel_id.clone(),
+            }),
+        }
+        .map_err(ContextError::ChannelError)
+    }
+
+    fn counterparty_channel_upgrade(
+        &self,
+        upgrade_path: &ChannelUpgradePath,
+    ) -> Result<Upgrade, ContextError> {
+        let port_id = &upgrade_path.0;
+        let channel_id = &upgrade_path.1;
+
+        match self
+            .ibc_store
+            .lock()
+            .counterparty_channel_upgrades
+            .get(port_id)
+            .and_then(|map| map.get(channel_id))
+        {
+            Some(upgrade) => Ok(upgrade.clone()),
+            None => Err(ChannelError::CounterpartyUpgradeNotFound {
+                port_id: port_id.clone(),
+                channel_id: channel_id.clone(),
+            }),
+        }
+        .map_err(ContextError::ChannelError)
+    }
+
+    fn channel_upgrade_error_receipt(
+        &self,
+        upgrade_error_path: &ChannelUpgradeErrorPath,
+    ) -> Result<ErrorReceipt, ContextError> {
+        let port_id = &upgrade_error_path.0;
+        let channel_id = &upgrade_error_path.1;
+
+        match self
+            .ibc_store
+            .lock()
+            .channel_upgrade_errors
+            .get(port_id)
+            .and_then(|map| map.get(channel_id))
+        {
+            Some(error_receipt) => Ok(error_receipt.clone()),
+            None => Err(ChannelError::UpgradeErrorReceiptNotFound {
+                port_id: port_id.clone(),
+                channel_id: channel_id.clone(),
+            }),
+        }
+        .map_err(ContextError::ChannelError)
+    }
+
+    fn has_inflight_packets_except(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        excluded: &[Sequence],
+    ) -> Result<bool, ContextError> {
+        Ok(self
+            .ibc_store
+            .lock()
+            .packet_commitment
+            .get(port_id)
+            .and_then(|map| map.get(channel_id))
+            .map_or(false, |map| map.keys().any(|seq| !excluded.contains(seq))))
+    }
+
     fn max_expected_time_per_block(&self) -> Duration {
         self.block_time
     }
@@ -344,6 +439,10 @@ impl ValidationContext for MockContext {
         Ok(())
     }
 
+    fn is_channel_upgrade_authority(&self, signer: &Signer) -> bool {
+        self.ibc_store.lock().channel_upgrade_authority.as_ref() == Some(signer)
+    }
+
     fn get_client_validation_context(&self) -> &Self::V {
         self
     }
@@ -359,6 +458,14 @@ impl ExecutionContext for MockContext {
         Ok(())
     }
 
+    fn store_allowed_clients(
+        &mut self,
+        allowed_clients: AllowedClients,
+    ) -> Result<(), ContextError> {
+        self.ibc_store.lock().allowed_clients = allowed_clients;
+        Ok(())
+    }
+
     fn store_connection(
         &mut self,
         connection_path: &ConnectionPath,
@@ -537,6 +644,81 @@ impl ExecutionContext for MockContext {
         Ok(())
     }
 
+    fn store_channel_upgrade(
+        &mut self,
+        upgrade_path: &ChannelUpgradePath,
+        upgrade: Upgrade,
+    ) -> Result<(), ContextError> {
+        let port_id = upgrade_path.0.clone();
+        let channel_id = upgrade_path.1.clone();
+
+        self.ibc_store
+            .lock()
+            .channel_upgrades
+            .entry(port_id)
+            .or_default()
+            .insert(channel_id, upgrade);
+        Ok(())
+    }
+
+    fn delete_channel_upgrade(
+        &mut self,
+        upgrade_path: &ChannelUpgradePath,
+    ) -> Result<(), ContextError> {
+        self.ibc_store
+            .lock()
+            .channel_upgrades
+            .get_mut(&upgrade_path.0)
+            .and_then(|map| map.remove(&upgrade_path.1));
+        Ok(())
+    }
+
+    fn store_counterparty_channel_upgrade(
+        &mut self,
+        upgrade_path: &ChannelUpgradePath,
+        upgrade: Upgrade,
+    ) -> Result<(), ContextError> {
+        let port_id = upgrade_path.0.clone();
+        let channel_id = upgrade_path.1.clone();
+
+        self.ibc_store
+            .lock()
+            .counterparty_channel_upgrades
+            .entry(port_id)
+            .or_default()
+            .insert(channel_id, upgrade);
+        Ok(())
+    }
+
+    fn delete_counterparty_channel_upgrade(
+        &mut self,
+        upgrade_path: &ChannelUpgradePath,
+    ) -> Result<(), ContextError> {
+        self.ibc_store
+            .lock()
+            .counterparty_channel_upgrades
+            .get_mut(&upgrade_path.0)
+            .and_then(|map| map.remove(&upgrade_path.1));
+        Ok(())
+    }
+
+    fn store_channel_upgrade_error_receipt(
+        &mut self,
+        upgrade_error_path: &ChannelUpgradeErrorPath,
+        error_receipt: ErrorReceipt,
+    ) -> Result<(), ContextError> {
+        let port_id = upgrade_error_path.0.clone();
+        let channel_id = upgrade_error_path.1.clone();
+
+        self.ibc_store
+            .lock()
+            .channel_upgrade_errors
+            .entry(port_id)
+            .or_default()
+            .insert(channel_id, error_receipt);
+        Ok(())
+    }
+
     fn increase_channel_counter(&mut self) -> Result<(), ContextError> {
         self.ibc_store.lock().channel_ids_counter += 1;
         Ok(())
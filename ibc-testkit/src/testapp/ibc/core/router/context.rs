@@ -1,15 +1,17 @@
+use alloc::collections::BTreeMap;
 use alloc::sync::Arc;
 
+use ibc::core::handler::cache::ModuleStoreCheckpoint;
 use ibc::core::host::types::identifiers::PortId;
 use ibc::core::router::module::Module;
 use ibc::core::router::router::Router;
 use ibc::core::router::types::module::ModuleId;
 
-use super::types::MockRouter;
+use super::types::{MockModule, MockRouter};
 
 impl Router for MockRouter {
     fn get_route(&self, module_id: &ModuleId) -> Option<&dyn Module> {
-        self.router.get(module_id).map(Arc::as_ref)
+        self.router.get(module_id).map(|module| module.as_module())
     }
     fn get_route_mut(&mut self, module_id: &ModuleId) -> Option<&mut dyn Module> {
         // NOTE: The following:
@@ -20,7 +22,7 @@ impl Router for MockRouter {
 
         match self.router.get_mut(module_id) {
             Some(arc_mod) => match Arc::get_mut(arc_mod) {
-                Some(m) => Some(m),
+                Some(m) => Some(m.as_module_mut()),
                 None => None,
             },
             None => None,
@@ -31,3 +33,19 @@ impl Router for MockRouter {
         self.port_to_module.get(port_id).cloned()
     }
 }
+
+impl ModuleStoreCheckpoint for MockRouter {
+    /// Copies of all the routed modules, which hold their own state.
+    type Checkpoint = BTreeMap<ModuleId, Arc<dyn MockModule>>;
+
+    fn checkpoint(&self) -> Self::Checkpoint {
+        self.router
+            .iter()
+            .map(|(module_id, module)| (module_id.clone(), module.clone_module()))
+            .collect()
+    }
+
+    fn revert(&mut self, checkpoint: Self::Checkpoint) {
+        self.router = checkpoint;
+    }
+}
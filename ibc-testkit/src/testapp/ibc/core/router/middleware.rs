@@ -0,0 +1,116 @@
+use ibc::core::channel::types::acknowledgement::Acknowledgement;
+use ibc::core::channel::types::error::PacketError;
+use ibc::core::channel::types::packet::Packet;
+use ibc::core::host::types::identifiers::Sequence;
+use ibc::core::primitives::prelude::*;
+use ibc::core::primitives::Signer;
+use ibc::core::router::middleware::Middleware;
+use ibc::core::router::module::Module;
+use ibc::core::router::types::module::ModuleExtras;
+
+/// A middleware that records the packets going through it, and appends its
+/// `tag` to the acknowledgements written by the modules below it.
+#[derive(Clone, Debug, Default)]
+pub struct DummyMiddleware {
+    pub tag: u8,
+    /// Whether to reject the packets sent by the modules below it.
+    pub reject_sends: bool,
+    /// Whether to hold the acknowledgements of the packets received by the
+    /// modules below it, which are then to be written asynchronously.
+    pub hold_acks: bool,
+    pub held_acks: Vec<Acknowledgement>,
+    pub received_packets: Vec<Sequence>,
+    pub sent_packets: Vec<Sequence>,
+}
+
+impl DummyMiddleware {
+    pub fn new(tag: u8) -> Self {
+        Self {
+            tag,
+            ..Default::default()
+        }
+    }
+
+    pub fn rejecting_sends(self) -> Self {
+        Self {
+            reject_sends: true,
+            ..self
+        }
+    }
+
+    pub fn holding_acks(self) -> Self {
+        Self {
+            hold_acks: true,
+            ..self
+        }
+    }
+
+    fn tag_acknowledgement(
+        &self,
+        acknowledgement: Acknowledgement,
+    ) -> Result<Acknowledgement, PacketError> {
+        let mut bytes = acknowledgement.as_bytes().to_vec();
+        bytes.push(self.tag);
+
+        bytes.try_into()
+    }
+}
+
+impl<N: Module> Middleware<N> for DummyMiddleware {
+    fn on_recv_packet_execute(
+        &mut self,
+        next: &mut N,
+        packet: &Packet,
+        relayer: &Signer,
+    ) -> (ModuleExtras, Option<Acknowledgement>) {
+        self.received_packets.push(packet.seq_on_a);
+
+        let (extras, acknowledgement) = next.on_recv_packet_execute(packet, relayer);
+
+        if !self.hold_acks {
+            return (extras, acknowledgement);
+        }
+
+        self.held_acks.extend(acknowledgement);
+
+        (extras, None)
+    }
+
+    fn send_packet_validate(&self, _packet: &Packet) -> Result<(), PacketError> {
+        if self.reject_sends {
+            return Err(PacketError::AppModule {
+                description: format!("middleware {} rejects packets", self.tag),
+            });
+        }
+
+        Ok(())
+    }
+
+    fn send_packet_execute(&mut self, packet: &Packet) -> Result<ModuleExtras, PacketError> {
+        self.sent_packets.push(packet.seq_on_a);
+
+        Ok(ModuleExtras {
+            events: Vec::new(),
+            log: vec![format!("middleware {} sent packet", self.tag)],
+        })
+    }
+
+    fn write_acknowledgement_validate(
+        &self,
+        _packet: &Packet,
+        acknowledgement: Acknowledgement,
+    ) -> Result<Acknowledgement, PacketError> {
+        self.tag_acknowledgement(acknowledgement)
+    }
+
+    fn write_acknowledgement_execute(
+        &mut self,
+        _packet: &Packet,
+        acknowledgement: Acknowledgement,
+    ) -> Result<(ModuleExtras, Acknowledgement), PacketError> {
+        Ok((
+            ModuleExtras::empty(),
+            self.tag_acknowledgement(acknowledgement)?,
+        ))
+    }
+}
@@ -1,4 +1,6 @@
 mod context;
+mod middleware;
 mod types;
 
+pub use middleware::*;
 pub use types::*;
@@ -1,5 +1,6 @@
 use alloc::collections::BTreeMap;
 use alloc::sync::Arc;
+use core::any::Any;
 
 use ibc::apps::transfer::types::MODULE_ID_STR;
 use ibc::core::host::types::identifiers::PortId;
@@ -9,9 +10,42 @@ use ibc::core::router::types::module::ModuleId;
 
 use crate::testapp::ibc::applications::transfer::types::DummyTransferModule;
 
+/// A [`Module`] routed by the [`MockRouter`], which clones it to take
+/// checkpoints of the application stores.
+pub trait MockModule: Module {
+    fn clone_module(&self) -> Arc<dyn MockModule>;
+
+    fn as_module(&self) -> &dyn Module;
+
+    fn as_module_mut(&mut self) -> &mut dyn Module;
+
+    fn as_any(&self) -> &dyn Any;
+}
+
+impl<M> MockModule for M
+where
+    M: Module + Clone + 'static,
+{
+    fn clone_module(&self) -> Arc<dyn MockModule> {
+        Arc::new(self.clone())
+    }
+
+    fn as_module(&self) -> &dyn Module {
+        self
+    }
+
+    fn as_module_mut(&mut self) -> &mut dyn Module {
+        self
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
 #[derive(Default)]
 pub struct MockRouter {
-    pub router: BTreeMap<ModuleId, Arc<dyn Module>>,
+    pub router: BTreeMap<ModuleId, Arc<dyn MockModule>>,
 
     /// Maps ports to the the module that owns it
     pub port_to_module: BTreeMap<PortId, ModuleId>,
@@ -37,7 +71,7 @@ impl MockRouter {
     pub fn add_route(
         &mut self,
         module_id: ModuleId,
-        module: impl Module + 'static,
+        module: impl Module + Clone + 'static,
     ) -> Result<(), String> {
         match self.router.insert(module_id, Arc::new(module)) {
             None => Ok(()),
@@ -48,4 +82,11 @@ impl MockRouter {
     pub fn scope_port_to_module(&mut self, port_id: PortId, module_id: ModuleId) {
         self.port_to_module.insert(port_id, module_id);
     }
+
+    /// Returns the module of the given type routed under `module_id`.
+    pub fn module<M: Module + 'static>(&self, module_id: &ModuleId) -> Option<&M> {
+        self.router
+            .get(module_id)
+            .and_then(|module| module.as_any().downcast_ref())
+    }
 }
@@ -1,6 +1,7 @@
 //! Implementation of a global context mock. Used in testing handlers of all IBC modules.
 
 use alloc::collections::btree_map::BTreeMap;
+use alloc::collections::btree_set::BTreeSet;
 use alloc::sync::Arc;
 use core::cmp::min;
 use core::fmt::Debug;
@@ -8,11 +9,13 @@ use core::ops::{Add, Sub};
 use core::time::Duration;
 
 use ibc::clients::tendermint::client_state::ClientState as TmClientState;
+use ibc::clients::tendermint::context::PruningPolicy;
 use ibc::clients::tendermint::types::TENDERMINT_CLIENT_TYPE;
 use ibc::core::channel::types::channel::ChannelEnd;
 use ibc::core::channel::types::commitment::{AcknowledgementCommitment, PacketCommitment};
 use ibc::core::channel::types::packet::Receipt;
-use ibc::core::client::types::Height;
+use ibc::core::channel::types::upgrade::{ErrorReceipt, Upgrade};
+use ibc::core::client::types::{AllowedClients, Height};
 use ibc::core::connection::types::ConnectionEnd;
 use ibc::core::entrypoint::dispatch;
 use ibc::core::handler::types::events::IbcEvent;
@@ -22,7 +25,7 @@ use ibc::core::host::types::identifiers::{
 };
 use ibc::core::host::ValidationContext;
 use ibc::core::primitives::prelude::*;
-use ibc::core::primitives::Timestamp;
+use ibc::core::primitives::{Signer, Timestamp};
 use ibc::core::router::router::Router;
 use parking_lot::Mutex;
 use tendermint_testgen::Validator as TestgenValidator;
@@ -57,6 +60,13 @@ pub struct MockIbcStore {
     /// `client_counter` methods.
     pub client_ids_counter: u64,
 
+    /// The client types which can be created and interacted with.
+    pub allowed_clients: AllowedClients,
+
+    /// The policy followed to prune the consensus states of the Tendermint
+    /// clients.
+    pub tm_pruning_policy: PruningPolicy,
+
     /// Association between client ids and connection ids.
     pub client_connections: BTreeMap<ClientId, ConnectionId>,
 
@@ -92,6 +102,26 @@ pub struct MockIbcStore {
     /// Used by unordered channel
     pub packet_receipt: PortChannelIdMap<BTreeMap<Sequence, Receipt>>,
 
+    /// Ongoing upgrades of the channel ends.
+    pub channel_upgrades: PortChannelIdMap<Upgrade>,
+
+    /// Upgrades of the counterparty channel ends, stored while flushing.
+    pub counterparty_channel_upgrades: PortChannelIdMap<Upgrade>,
+
+    /// Error receipts of the last failed channel upgrades.
+    pub channel_upgrade_errors: PortChannelIdMap<ErrorReceipt>,
+
+    /// The governance authority allowed to initiate and cancel channel
+    /// upgrades, if any.
+    pub channel_upgrade_authority: Option<Signer>,
+
+    /// The governance authority allowed to perform privileged client
+    /// operations, if any.
+    pub client_authority: Option<Signer>,
+
+    /// Checksums of the allowed Wasm light client contracts.
+    pub wasm_checksums: BTreeSet<Vec<u8>>,
+
     /// Emitted IBC events in order
     pub events: Vec<IbcEvent>,
 
@@ -569,6 +599,35 @@ impl MockContext {
         self
     }
 
+    pub fn with_channel_upgrade(
+        self,
+        port_id: PortId,
+        chan_id: ChannelId,
+        upgrade: Upgrade,
+    ) -> Self {
+        self.ibc_store
+            .lock()
+            .channel_upgrades
+            .entry(port_id)
+            .or_default()
+            .insert(chan_id, upgrade);
+        self
+    }
+
+    /// Sets the governance authority allowed to perform privileged client
+    /// operations.
+    pub fn with_client_authority(self, authority: Signer) -> Self {
+        self.ibc_store.lock().client_authority = Some(authority);
+        self
+    }
+
+    /// Sets the governance authority allowed to initiate and cancel channel
+    /// upgrades.
+    pub fn with_channel_upgrade_authority(self, authority: Signer) -> Self {
+        self.ibc_store.lock().channel_upgrade_authority = Some(authority);
+        self
+    }
+
     pub fn with_send_sequence(
         self,
         port_id: PortId,
@@ -653,6 +712,24 @@ impl MockContext {
         self
     }
 
+    pub fn with_packet_receipt(
+        self,
+        port_id: PortId,
+        chan_id: ChannelId,
+        seq: Sequence,
+        receipt: Receipt,
+    ) -> Self {
+        let mut packet_receipt = self.ibc_store.lock().packet_receipt.clone();
+        packet_receipt
+            .entry(port_id)
+            .or_default()
+            .entry(chan_id)
+            .or_default()
+            .insert(seq, receipt);
+        self.ibc_store.lock().packet_receipt = packet_receipt;
+        self
+    }
+
     /// Accessor for a block of the local (host) chain from this context.
     /// Returns `None` if the block at the requested height does not exist.
     pub fn host_block(&self, target_height: &Height) -> Option<&HostBlock> {
@@ -935,7 +1012,7 @@ mod tests {
 
     #[test]
     fn test_router() {
-        #[derive(Debug, Default)]
+        #[derive(Clone, Debug, Default)]
         struct FooModule {
             counter: u64,
         }
@@ -993,12 +1070,12 @@ mod tests {
                 &mut self,
                 _packet: &Packet,
                 _relayer: &Signer,
-            ) -> (ModuleExtras, Acknowledgement) {
+            ) -> (ModuleExtras, Option<Acknowledgement>) {
                 self.counter += 1;
 
                 (
                     ModuleExtras::empty(),
-                    Acknowledgement::try_from(vec![1u8]).expect("Never fails"),
+                    Some(Acknowledgement::try_from(vec![1u8]).expect("Never fails")),
                 )
             }
 
@@ -1037,7 +1114,7 @@ mod tests {
             }
         }
 
-        #[derive(Debug, Default)]
+        #[derive(Clone, Debug, Default)]
         struct BarModule;
 
         impl Module for BarModule {
@@ -1093,10 +1170,10 @@ mod tests {
                 &mut self,
                 _packet: &Packet,
                 _relayer: &Signer,
-            ) -> (ModuleExtras, Acknowledgement) {
+            ) -> (ModuleExtras, Option<Acknowledgement>) {
                 (
                     ModuleExtras::empty(),
-                    Acknowledgement::try_from(vec![1u8]).expect("Never fails"),
+                    Some(Acknowledgement::try_from(vec![1u8]).expect("Never fails")),
                 )
             }
 
@@ -1153,7 +1230,7 @@ mod tests {
             (module_id, result)
         };
 
-        let _results = vec![
+        let _results = [
             on_recv_packet_result("foomodule"),
             on_recv_packet_result("barmodule"),
         ];
@@ -0,0 +1,470 @@
+use ibc::apps::fee::handler::{
+    pay_packet_fee, pay_packet_fee_async, register_counterparty_payee, register_payee,
+};
+use ibc::apps::fee::module::{FeeMiddleware, FeeModule};
+use ibc::apps::fee::types::msgs::{
+    MsgPayPacketFee, MsgPayPacketFeeAsync, MsgRegisterCounterpartyPayee, MsgRegisterPayee,
+};
+use ibc::apps::fee::types::{Fee, IncentivizedAcknowledgement, Metadata, PacketFee};
+use ibc::apps::transfer::types::{Amount, RawCoin};
+use ibc::core::channel::types::acknowledgement::Acknowledgement;
+use ibc::core::channel::types::channel::{ChannelEnd, Counterparty, Order, State};
+use ibc::core::channel::types::commitment::PacketCommitment;
+use ibc::core::channel::types::packet::{Packet, PacketId};
+use ibc::core::channel::types::Version;
+use ibc::core::host::types::identifiers::{ChannelId, ConnectionId, PortId, Sequence};
+use ibc::core::primitives::prelude::*;
+use ibc::core::primitives::Signer;
+use ibc::core::router::middleware::Middleware;
+use ibc::core::router::module::Module;
+use ibc_testkit::fixtures::core::channel::dummy_raw_packet;
+use ibc_testkit::testapp::ibc::applications::fee::types::{MockFeeContext, FEE_ESCROW_ACCOUNT};
+use ibc_testkit::testapp::ibc::applications::transfer::types::DummyTransferModule;
+use ibc_testkit::testapp::ibc::core::types::MockContext;
+use rstest::*;
+
+const DENOM: &str = "stake";
+
+fn coins(amount: u64) -> Vec<RawCoin> {
+    vec![RawCoin {
+        denom: DENOM.to_string(),
+        amount: amount.into(),
+    }]
+}
+
+fn signer(address: &str) -> Signer {
+    address.to_string().into()
+}
+
+fn balance(fee_ctx: &MockFeeContext, address: &str) -> Amount {
+    fee_ctx.balance(&signer(address), DENOM)
+}
+
+struct Fixture {
+    ctx: MockContext,
+    port_id: PortId,
+    chan_id: ChannelId,
+    fee: Fee,
+    packet: Packet,
+}
+
+#[fixture]
+fn fixture() -> Fixture {
+    let port_id = PortId::transfer();
+    let chan_id = ChannelId::default();
+
+    let chan_end = ChannelEnd::new(
+        State::Open,
+        Order::Unordered,
+        Counterparty::new(port_id.clone(), Some(chan_id.clone())),
+        vec![ConnectionId::default()],
+        Version::from(Metadata::new(&Version::new("ics20-1".to_string()))),
+    )
+    .unwrap();
+
+    let ctx = MockContext::default()
+        .with_channel(port_id.clone(), chan_id.clone(), chan_end)
+        .with_send_sequence(port_id.clone(), chan_id.clone(), 2.into())
+        .with_packet_commitment(
+            port_id.clone(),
+            chan_id.clone(),
+            1.into(),
+            PacketCommitment::from(vec![1]),
+        );
+
+    let packet: Packet = dummy_raw_packet(10, 0).try_into().unwrap();
+
+    Fixture {
+        ctx,
+        port_id,
+        chan_id,
+        fee: Fee::new(coins(10), coins(20), coins(30)),
+        packet,
+    }
+}
+
+fn fee_middleware(
+    port_id: &PortId,
+    chan_id: &ChannelId,
+) -> FeeModule<MockFeeContext, DummyTransferModule> {
+    let fee_ctx = MockFeeContext::new()
+        .with_fee_enabled(port_id.clone(), chan_id.clone())
+        .with_balance(signer("payer"), &coins(100));
+    FeeMiddleware::new(fee_ctx).wrap(DummyTransferModule::new())
+}
+
+fn escrow_packet_fee(
+    module: &mut FeeModule<MockFeeContext, DummyTransferModule>,
+    packet_id: PacketId,
+    fee: Fee,
+) {
+    let msg = MsgPayPacketFeeAsync {
+        packet_id,
+        packet_fee: PacketFee::new(fee, signer("payer"), vec![]),
+    };
+    let mut ctx = MockContext::default()
+        .with_send_sequence(
+            msg.packet_id.port_id.clone(),
+            msg.packet_id.chan_id.clone(),
+            2.into(),
+        )
+        .with_packet_commitment(
+            msg.packet_id.port_id.clone(),
+            msg.packet_id.chan_id.clone(),
+            msg.packet_id.seq,
+            PacketCommitment::from(vec![1]),
+        );
+    pay_packet_fee_async(&mut ctx, module.middleware_mut().ctx_mut(), msg).unwrap();
+}
+
+#[rstest]
+fn test_chan_open_init_wraps_app_version() {
+    let port_id = PortId::transfer();
+    let chan_id = ChannelId::default();
+    let mut module = FeeMiddleware::new(MockFeeContext::new()).wrap(DummyTransferModule::new());
+
+    let fee_version: Version = Metadata::new(&Version::new("ics20-1".to_string())).into();
+
+    let (_, version) = module
+        .on_chan_open_init_execute(
+            Order::Unordered,
+            &[ConnectionId::default()],
+            &port_id,
+            &chan_id,
+            &Counterparty::new(port_id.clone(), None),
+            &fee_version,
+        )
+        .unwrap();
+
+    assert_eq!(version, fee_version);
+    assert!(module
+        .middleware()
+        .ctx()
+        .fee_enabled_channels
+        .contains(&(port_id, chan_id)));
+}
+
+#[rstest]
+fn test_chan_open_init_passes_through_app_version() {
+    let port_id = PortId::transfer();
+    let chan_id = ChannelId::default();
+    let mut module = FeeMiddleware::new(MockFeeContext::new()).wrap(DummyTransferModule::new());
+
+    let app_version = Version::new("ics20-1".to_string());
+
+    let (_, version) = module
+        .on_chan_open_init_execute(
+            Order::Unordered,
+            &[ConnectionId::default()],
+            &port_id,
+            &chan_id,
+            &Counterparty::new(port_id.clone(), None),
+            &app_version,
+        )
+        .unwrap();
+
+    assert_eq!(version, app_version);
+    assert!(module.middleware().ctx().fee_enabled_channels.is_empty());
+}
+
+#[rstest]
+fn test_chan_open_try_rejects_unknown_fee_version() {
+    let port_id = PortId::transfer();
+    let module = FeeMiddleware::new(MockFeeContext::new()).wrap(DummyTransferModule::new());
+
+    let counterparty_version =
+        Version::new(r#"{"fee_version":"ics29-2","app_version":"ics20-1"}"#.to_string());
+
+    let res = module.on_chan_open_try_validate(
+        Order::Unordered,
+        &[ConnectionId::default()],
+        &port_id,
+        &ChannelId::default(),
+        &Counterparty::new(port_id.clone(), Some(ChannelId::default())),
+        &counterparty_version,
+    );
+
+    assert!(res.is_err());
+}
+
+#[rstest]
+fn test_pay_packet_fee(fixture: Fixture) {
+    let Fixture {
+        mut ctx,
+        port_id,
+        chan_id,
+        fee,
+        ..
+    } = fixture;
+
+    let mut fee_ctx = MockFeeContext::new()
+        .with_fee_enabled(port_id.clone(), chan_id.clone())
+        .with_balance(signer("payer"), &coins(100));
+
+    let msg = MsgPayPacketFee {
+        fee: fee.clone(),
+        port_id_on_a: port_id.clone(),
+        chan_id_on_a: chan_id.clone(),
+        signer: signer("payer"),
+        relayers: vec![],
+    };
+
+    pay_packet_fee(&mut ctx, &mut fee_ctx, msg).unwrap();
+
+    // the fee is escrowed for the next packet to be sent
+    let packet_id = PacketId::new(port_id, chan_id, Sequence::from(2));
+    assert_eq!(
+        fee_ctx.packet_fees.get(&packet_id).unwrap(),
+        &vec![PacketFee::new(fee, signer("payer"), vec![])]
+    );
+    assert_eq!(balance(&fee_ctx, "payer"), 40.into());
+    assert_eq!(balance(&fee_ctx, FEE_ESCROW_ACCOUNT), 60.into());
+    assert!(!ctx.get_events().is_empty());
+}
+
+#[rstest]
+fn test_pay_packet_fee_fails_when_fee_not_enabled(fixture: Fixture) {
+    let Fixture {
+        mut ctx,
+        port_id,
+        chan_id,
+        fee,
+        ..
+    } = fixture;
+
+    let mut fee_ctx = MockFeeContext::new().with_balance(signer("payer"), &coins(100));
+
+    let msg = MsgPayPacketFee {
+        fee,
+        port_id_on_a: port_id,
+        chan_id_on_a: chan_id,
+        signer: signer("payer"),
+        relayers: vec![],
+    };
+
+    assert!(pay_packet_fee(&mut ctx, &mut fee_ctx, msg).is_err());
+    assert!(fee_ctx.packet_fees.is_empty());
+}
+
+#[rstest]
+fn test_pay_packet_fee_fails_with_insufficient_funds(fixture: Fixture) {
+    let Fixture {
+        mut ctx,
+        port_id,
+        chan_id,
+        fee,
+        ..
+    } = fixture;
+
+    let mut fee_ctx = MockFeeContext::new()
+        .with_fee_enabled(port_id.clone(), chan_id.clone())
+        .with_balance(signer("payer"), &coins(59));
+
+    let msg = MsgPayPacketFee {
+        fee,
+        port_id_on_a: port_id,
+        chan_id_on_a: chan_id,
+        signer: signer("payer"),
+        relayers: vec![],
+    };
+
+    assert!(pay_packet_fee(&mut ctx, &mut fee_ctx, msg).is_err());
+    assert_eq!(balance(&fee_ctx, "payer"), 59.into());
+}
+
+#[rstest]
+#[case::in_flight(1, true)]
+#[case::not_yet_sent(2, false)]
+fn test_pay_packet_fee_async(fixture: Fixture, #[case] sequence: u64, #[case] want_pass: bool) {
+    let Fixture {
+        mut ctx,
+        port_id,
+        chan_id,
+        fee,
+        ..
+    } = fixture;
+
+    let mut fee_ctx = MockFeeContext::new()
+        .with_fee_enabled(port_id.clone(), chan_id.clone())
+        .with_balance(signer("payer"), &coins(100));
+
+    let msg = MsgPayPacketFeeAsync {
+        packet_id: PacketId::new(port_id, chan_id, sequence.into()),
+        packet_fee: PacketFee::new(fee, signer("payer"), vec![]),
+    };
+
+    let res = pay_packet_fee_async(&mut ctx, &mut fee_ctx, msg);
+
+    assert_eq!(res.is_ok(), want_pass, "unexpected result: {res:?}");
+}
+
+#[rstest]
+fn test_register_payees(fixture: Fixture) {
+    let Fixture {
+        mut ctx,
+        port_id,
+        chan_id,
+        ..
+    } = fixture;
+
+    let mut fee_ctx = MockFeeContext::new().with_fee_enabled(port_id.clone(), chan_id.clone());
+
+    let msg = MsgRegisterPayee {
+        port_id: port_id.clone(),
+        chan_id: chan_id.clone(),
+        relayer: signer("relayer"),
+        payee: signer("payee"),
+    };
+    register_payee(&mut ctx, &mut fee_ctx, msg).unwrap();
+
+    let msg = MsgRegisterCounterpartyPayee {
+        port_id,
+        chan_id: chan_id.clone(),
+        relayer: signer("relayer"),
+        counterparty_payee: signer("counterparty-payee"),
+    };
+    register_counterparty_payee(&mut ctx, &mut fee_ctx, msg).unwrap();
+
+    assert_eq!(
+        fee_ctx.payees.get(&(signer("relayer"), chan_id.clone())),
+        Some(&signer("payee"))
+    );
+    assert_eq!(
+        fee_ctx
+            .counterparty_payees
+            .get(&(signer("relayer"), chan_id)),
+        Some(&signer("counterparty-payee"))
+    );
+}
+
+#[rstest]
+fn test_recv_packet_wraps_ack(fixture: Fixture) {
+    let Fixture {
+        port_id,
+        chan_id,
+        packet,
+        ..
+    } = fixture;
+
+    let mut module = fee_middleware(&port_id, &chan_id);
+    module
+        .middleware_mut()
+        .ctx_mut()
+        .counterparty_payees
+        .insert((signer("relayer"), chan_id), signer("forward-payee"));
+
+    let (_, ack) = module.on_recv_packet_execute(&packet, &signer("relayer"));
+
+    let ack = IncentivizedAcknowledgement::try_from(&ack.unwrap()).unwrap();
+    assert_eq!(ack.forward_relayer_address, signer("forward-payee"));
+    assert_eq!(
+        ack.app_acknowledgement,
+        Acknowledgement::try_from(vec![1u8]).unwrap()
+    );
+}
+
+#[rstest]
+fn test_acknowledgement_distributes_fees(fixture: Fixture) {
+    let Fixture {
+        port_id,
+        chan_id,
+        fee,
+        packet,
+        ..
+    } = fixture;
+
+    let mut module = fee_middleware(&port_id, &chan_id);
+    module
+        .middleware_mut()
+        .ctx_mut()
+        .payees
+        .insert((signer("relayer"), chan_id.clone()), signer("payee"));
+
+    let packet_id = PacketId::new(port_id, chan_id, packet.seq_on_a);
+    escrow_packet_fee(&mut module, packet_id.clone(), fee);
+
+    let app_ack = Acknowledgement::try_from(vec![1u8]).unwrap();
+    let ack: Acknowledgement =
+        IncentivizedAcknowledgement::new(app_ack, signer("forward-relayer")).into();
+
+    module
+        .on_acknowledgement_packet_validate(&packet, &ack, &signer("relayer"))
+        .unwrap();
+    let (extras, res) = module.on_acknowledgement_packet_execute(&packet, &ack, &signer("relayer"));
+    res.unwrap();
+
+    let fee_ctx = module.middleware().ctx();
+    assert_eq!(balance(fee_ctx, "forward-relayer"), 10.into());
+    assert_eq!(balance(fee_ctx, "payee"), 20.into());
+    assert_eq!(balance(fee_ctx, "payer"), 70.into());
+    assert_eq!(balance(fee_ctx, FEE_ESCROW_ACCOUNT), 0.into());
+    assert!(fee_ctx.packet_fees.get(&packet_id).is_none());
+    assert_eq!(extras.events.len(), 3);
+}
+
+#[rstest]
+fn test_acknowledgement_rejects_non_incentivized_ack(fixture: Fixture) {
+    let Fixture {
+        port_id,
+        chan_id,
+        packet,
+        ..
+    } = fixture;
+
+    let module = fee_middleware(&port_id, &chan_id);
+
+    let ack = Acknowledgement::try_from(vec![1u8]).unwrap();
+
+    assert!(module
+        .on_acknowledgement_packet_validate(&packet, &ack, &signer("relayer"))
+        .is_err());
+}
+
+#[rstest]
+fn test_timeout_distributes_fees(fixture: Fixture) {
+    let Fixture {
+        port_id,
+        chan_id,
+        fee,
+        packet,
+        ..
+    } = fixture;
+
+    let mut module = fee_middleware(&port_id, &chan_id);
+
+    let packet_id = PacketId::new(port_id, chan_id, packet.seq_on_a);
+    escrow_packet_fee(&mut module, packet_id.clone(), fee);
+
+    let (_, res) = module.on_timeout_packet_execute(&packet, &signer("relayer"));
+    res.unwrap();
+
+    let fee_ctx = module.middleware().ctx();
+    assert_eq!(balance(fee_ctx, "relayer"), 30.into());
+    assert_eq!(balance(fee_ctx, "payer"), 70.into());
+    assert_eq!(balance(fee_ctx, FEE_ESCROW_ACCOUNT), 0.into());
+    assert!(fee_ctx.packet_fees.get(&packet_id).is_none());
+}
+
+#[rstest]
+fn test_channel_closure_refunds_fees(fixture: Fixture) {
+    let Fixture {
+        port_id,
+        chan_id,
+        fee,
+        packet,
+        ..
+    } = fixture;
+
+    let mut module = fee_middleware(&port_id, &chan_id);
+
+    let packet_id = PacketId::new(port_id.clone(), chan_id.clone(), packet.seq_on_a);
+    escrow_packet_fee(&mut module, packet_id, fee);
+    assert_eq!(balance(module.middleware().ctx(), "payer"), 40.into());
+
+    module
+        .on_chan_close_init_execute(&port_id, &chan_id)
+        .unwrap();
+
+    assert_eq!(balance(module.middleware().ctx(), "payer"), 100.into());
+    assert!(module.middleware().ctx().packet_fees.is_empty());
+}
@@ -0,0 +1,376 @@
+use core::time::Duration;
+
+use ibc::apps::interchain_accounts::controller::handler::{
+    register_interchain_account, register_interchain_account_validate, send_tx,
+};
+use ibc::apps::interchain_accounts::controller::module::ControllerModule;
+use ibc::apps::interchain_accounts::host::module::HostModule;
+use ibc::apps::interchain_accounts::types::msgs::{MsgRegisterInterchainAccount, MsgSendTx};
+use ibc::apps::interchain_accounts::types::{
+    controller_port_id, host_port_id, CosmosTx, InterchainAccountPacketData, Metadata, TxMsgData,
+};
+use ibc::clients::tendermint::types::client_type as tm_client_type;
+use ibc::core::channel::types::acknowledgement::AcknowledgementStatus;
+use ibc::core::channel::types::channel::{ChannelEnd, Counterparty, Order, State};
+use ibc::core::channel::types::packet::Packet;
+use ibc::core::channel::types::timeout::TimeoutHeight;
+use ibc::core::channel::types::Version;
+use ibc::core::client::types::Height;
+use ibc::core::commitment_types::commitment::CommitmentPrefix;
+use ibc::core::connection::types::version::get_compatible_versions;
+use ibc::core::connection::types::{
+    ConnectionEnd, Counterparty as ConnectionCounterparty, State as ConnectionState,
+};
+use ibc::core::host::types::identifiers::{ChannelId, ConnectionId, PortId};
+use ibc::core::host::types::path::{ChannelEndPath, SeqSendPath};
+use ibc::core::host::ValidationContext;
+use ibc::core::primitives::prelude::*;
+use ibc::core::primitives::{Signer, Timestamp};
+use ibc::core::router::module::Module;
+use ibc_proto::google::protobuf::Any;
+use ibc_testkit::testapp::ibc::applications::interchain_accounts::types::MockIcaContext;
+use ibc_testkit::testapp::ibc::core::types::{MockClientConfig, MockContext};
+use rstest::*;
+
+const MSG_SEND_TYPE_URL: &str = "/cosmos.bank.v1beta1.MsgSend";
+const MSG_DELEGATE_TYPE_URL: &str = "/cosmos.staking.v1beta1.MsgDelegate";
+
+fn owner() -> Signer {
+    "owner".to_string().into()
+}
+
+fn controller_port() -> PortId {
+    controller_port_id(&owner()).unwrap()
+}
+
+fn controller_conn_id() -> ConnectionId {
+    ConnectionId::new(0)
+}
+
+fn host_conn_id() -> ConnectionId {
+    ConnectionId::new(1)
+}
+
+fn metadata_with_address(address: &str) -> Version {
+    let mut metadata = Metadata::new(controller_conn_id(), host_conn_id());
+    metadata.address = address.to_string();
+    metadata.into()
+}
+
+fn msg(type_url: &str) -> Any {
+    Any {
+        type_url: type_url.to_string(),
+        value: vec![1, 2, 3],
+    }
+}
+
+fn msg_response(type_url: &str) -> Any {
+    Any {
+        type_url: format!("{type_url}Response"),
+        value: vec![],
+    }
+}
+
+/// A controller chain with an open connection to the host.
+fn controller_ctx() -> MockContext {
+    let client_id = tm_client_type().build_client_id(0);
+
+    let conn_end = ConnectionEnd::new(
+        ConnectionState::Open,
+        client_id.clone(),
+        ConnectionCounterparty::new(
+            client_id.clone(),
+            Some(host_conn_id()),
+            CommitmentPrefix::try_from(b"ibc".to_vec()).unwrap(),
+        ),
+        get_compatible_versions(),
+        Duration::from_secs(0),
+    )
+    .unwrap();
+
+    MockContext::default()
+        .with_client_config(
+            MockClientConfig::builder()
+                .client_id(client_id)
+                .latest_height(Height::new(0, 10).unwrap())
+                .build(),
+        )
+        .with_connection(controller_conn_id(), conn_end)
+}
+
+fn ica_channel_end(state: State, version: Version) -> ChannelEnd {
+    ChannelEnd::new(
+        state,
+        Order::Ordered,
+        Counterparty::new(host_port_id(), Some(ChannelId::new(0))),
+        vec![controller_conn_id()],
+        version,
+    )
+    .unwrap()
+}
+
+fn register_msg(ordering: Order) -> MsgRegisterInterchainAccount {
+    MsgRegisterInterchainAccount {
+        owner: owner(),
+        conn_id_on_a: controller_conn_id(),
+        version: Version::empty(),
+        ordering,
+    }
+}
+
+/// A packet received by the host, carrying a transaction made of the given
+/// messages.
+fn host_packet(messages: Vec<Any>) -> Packet {
+    let packet_data = InterchainAccountPacketData::execute_tx(CosmosTx::new(messages), "".into());
+
+    Packet {
+        seq_on_a: 1.into(),
+        port_id_on_a: controller_port(),
+        chan_id_on_a: ChannelId::new(0),
+        port_id_on_b: host_port_id(),
+        chan_id_on_b: ChannelId::new(0),
+        data: packet_data.to_packet_bytes(),
+        timeout_height_on_b: TimeoutHeight::no_timeout(),
+        timeout_timestamp_on_b: Timestamp::none(),
+    }
+}
+
+/// A host with the interchain account of the owner registered.
+fn host_module(ica_ctx: MockIcaContext) -> HostModule<MockIcaContext> {
+    HostModule::new(
+        ica_ctx
+            .with_channel_connection(host_port_id(), ChannelId::new(0), host_conn_id())
+            .with_account(host_conn_id(), controller_port(), "ica".to_string().into()),
+    )
+}
+
+#[rstest]
+fn test_register_interchain_account() {
+    let mut ctx = controller_ctx();
+    let mut module = ControllerModule::new(MockIcaContext::new());
+
+    let chan_id =
+        register_interchain_account(&mut ctx, &mut module, register_msg(Order::Ordered)).unwrap();
+
+    let chan_end = ctx
+        .channel_end(&ChannelEndPath::new(&controller_port(), &chan_id))
+        .unwrap();
+
+    assert_eq!(chan_end.state(), &State::Init);
+    assert_eq!(chan_end.ordering(), &Order::Ordered);
+    assert_eq!(chan_end.counterparty().port_id(), &host_port_id());
+    assert_eq!(
+        Metadata::try_from(chan_end.version()).unwrap(),
+        Metadata::new(controller_conn_id(), host_conn_id())
+    );
+}
+
+#[rstest]
+fn test_register_interchain_account_requires_ordered_channel() {
+    let mut ctx = controller_ctx();
+    let mut module = ControllerModule::new(MockIcaContext::new());
+
+    let res = register_interchain_account(&mut ctx, &mut module, register_msg(Order::Unordered));
+
+    assert!(res.is_err(), "interchain accounts channels must be ordered");
+}
+
+#[rstest]
+fn test_register_interchain_account_with_open_active_channel() {
+    let chan_id = ChannelId::new(0);
+    let ctx = controller_ctx().with_channel(
+        controller_port(),
+        chan_id.clone(),
+        ica_channel_end(State::Open, metadata_with_address("ica")),
+    );
+    let mut ica_ctx = MockIcaContext::new();
+    ica_ctx
+        .active_channels
+        .insert((controller_conn_id(), controller_port()), chan_id);
+    let module = ControllerModule::new(ica_ctx);
+
+    let res = register_interchain_account_validate(
+        &ctx,
+        module.ctx(),
+        &module,
+        register_msg(Order::Ordered),
+    );
+
+    assert!(res.is_err(), "the active channel is still open");
+}
+
+#[rstest]
+fn test_controller_chan_open_ack_stores_account() {
+    let chan_id = ChannelId::new(0);
+    let mut module = ControllerModule::new(MockIcaContext::new().with_channel_connection(
+        controller_port(),
+        chan_id.clone(),
+        controller_conn_id(),
+    ));
+
+    let res =
+        module.on_chan_open_ack_validate(&controller_port(), &chan_id, &metadata_with_address(""));
+    assert!(res.is_err(), "the host must provide the account address");
+
+    module
+        .on_chan_open_ack_execute(&controller_port(), &chan_id, &metadata_with_address("ica"))
+        .unwrap();
+
+    let ica_ctx = module.ctx();
+    assert_eq!(
+        ica_ctx.active_channels[&(controller_conn_id(), controller_port())],
+        chan_id
+    );
+    assert_eq!(
+        ica_ctx.account_addresses[&(controller_conn_id(), controller_port())],
+        "ica".to_string().into()
+    );
+}
+
+#[rstest]
+fn test_host_chan_open_try_creates_account() {
+    let chan_id = ChannelId::new(0);
+    let mut module = HostModule::new(MockIcaContext::new());
+    let counterparty = Counterparty::new(controller_port(), Some(ChannelId::new(0)));
+    let proposal: Version = Metadata::new(controller_conn_id(), host_conn_id()).into();
+
+    let res = module.on_chan_open_try_validate(
+        Order::Ordered,
+        &[ConnectionId::new(2)],
+        &host_port_id(),
+        &chan_id,
+        &counterparty,
+        &proposal,
+    );
+    assert!(res.is_err(), "the metadata must match the host connection");
+
+    let (_, version) = module
+        .on_chan_open_try_execute(
+            Order::Ordered,
+            &[host_conn_id()],
+            &host_port_id(),
+            &chan_id,
+            &counterparty,
+            &proposal,
+        )
+        .unwrap();
+
+    let address: Signer = "ica-connection-1-icacontroller-owner".to_string().into();
+    assert_eq!(version, metadata_with_address(address.as_ref()));
+
+    let ica_ctx = module.ctx();
+    assert!(ica_ctx.accounts.contains(&address));
+    assert_eq!(
+        ica_ctx.account_addresses[&(host_conn_id(), controller_port())],
+        address
+    );
+    assert_eq!(
+        ica_ctx.active_channels[&(host_conn_id(), controller_port())],
+        chan_id
+    );
+}
+
+#[rstest]
+fn test_send_tx() {
+    let chan_id = ChannelId::new(0);
+    let mut ctx = controller_ctx()
+        .with_channel(
+            controller_port(),
+            chan_id.clone(),
+            ica_channel_end(State::Open, metadata_with_address("ica")),
+        )
+        .with_send_sequence(controller_port(), chan_id.clone(), 1.into());
+    let mut ica_ctx = MockIcaContext::new();
+
+    let msg = MsgSendTx {
+        owner: owner(),
+        conn_id_on_a: controller_conn_id(),
+        packet_data: InterchainAccountPacketData::execute_tx(
+            CosmosTx::new(vec![msg(MSG_SEND_TYPE_URL)]),
+            "".into(),
+        ),
+        relative_timeout: Duration::from_secs(600),
+    };
+
+    let res = send_tx(&mut ctx, &ica_ctx, msg.clone());
+    assert!(res.is_err(), "no active channel is set");
+
+    ica_ctx
+        .active_channels
+        .insert((controller_conn_id(), controller_port()), chan_id.clone());
+
+    let seq = send_tx(&mut ctx, &ica_ctx, msg).unwrap();
+
+    assert_eq!(seq, 1.into());
+    assert_eq!(
+        ctx.get_next_sequence_send(&SeqSendPath::new(&controller_port(), &chan_id))
+            .unwrap(),
+        2.into()
+    );
+}
+
+#[rstest]
+fn test_host_executes_tx() {
+    let mut module = host_module(MockIcaContext::new());
+    let messages = vec![msg(MSG_SEND_TYPE_URL), msg(MSG_DELEGATE_TYPE_URL)];
+
+    let (extras, ack) =
+        module.on_recv_packet_execute(&host_packet(messages), &"relayer".to_string().into());
+
+    let expected_ack = TxMsgData::new(vec![
+        msg_response(MSG_SEND_TYPE_URL),
+        msg_response(MSG_DELEGATE_TYPE_URL),
+    ])
+    .into_acknowledgement();
+    assert_eq!(ack, Some(expected_ack.into()));
+    assert_eq!(module.ctx().executed_messages.len(), 2);
+    assert_eq!(extras.events.len(), 1);
+}
+
+#[rstest]
+#[case::disallowed_message(
+    MockIcaContext::new().with_allow_messages(vec![MSG_SEND_TYPE_URL.to_string()]),
+    vec![msg(MSG_SEND_TYPE_URL), msg(MSG_DELEGATE_TYPE_URL)],
+)]
+#[case::empty_tx(MockIcaContext::new(), vec![])]
+fn test_host_rejects_tx(#[case] ica_ctx: MockIcaContext, #[case] messages: Vec<Any>) {
+    let mut module = host_module(ica_ctx);
+
+    let (_, ack) =
+        module.on_recv_packet_execute(&host_packet(messages), &"relayer".to_string().into());
+
+    let ack_status: AcknowledgementStatus = serde_json::from_slice(ack.unwrap().as_ref()).unwrap();
+    assert!(!ack_status.is_successful());
+    assert!(
+        module.ctx().executed_messages.is_empty(),
+        "no message is executed when one is rejected"
+    );
+}
+
+#[rstest]
+fn test_host_rejects_tx_when_disabled() {
+    let mut ica_ctx = MockIcaContext::new();
+    ica_ctx.host_enabled = false;
+    let mut module = host_module(ica_ctx);
+
+    let (_, ack) = module.on_recv_packet_execute(
+        &host_packet(vec![msg(MSG_SEND_TYPE_URL)]),
+        &"relayer".to_string().into(),
+    );
+
+    let ack_status: AcknowledgementStatus = serde_json::from_slice(ack.unwrap().as_ref()).unwrap();
+    assert!(!ack_status.is_successful());
+}
+
+#[rstest]
+fn test_channel_closing_is_not_allowed() {
+    let controller = ControllerModule::new(MockIcaContext::new());
+    let host = HostModule::new(MockIcaContext::new());
+
+    assert!(controller
+        .on_chan_close_init_validate(&controller_port(), &ChannelId::new(0))
+        .is_err());
+    assert!(host
+        .on_chan_close_init_validate(&host_port_id(), &ChannelId::new(0))
+        .is_err());
+}
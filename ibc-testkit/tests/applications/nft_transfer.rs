@@ -1,13 +1,27 @@
+use ibc::apps::nft_transfer::context::NftClassTraceContext;
+use ibc::apps::nft_transfer::handler::send_nft_transfer_validate;
 use ibc::apps::nft_transfer::module::{
     on_chan_open_init_execute, on_chan_open_init_validate, on_chan_open_try_execute,
-    on_chan_open_try_validate,
+    on_chan_open_try_validate, on_recv_packet_execute,
 };
-use ibc::apps::nft_transfer::types::VERSION;
+use ibc::apps::nft_transfer::types::error::NftTransferError;
+use ibc::apps::nft_transfer::types::msgs::transfer::MsgTransfer;
+use ibc::apps::nft_transfer::types::packet::PacketData;
+use ibc::apps::nft_transfer::types::{
+    Memo, PrefixedClassId, TokenId, TokenIds, DEFAULT_MAX_BATCH_SIZE, VERSION,
+};
+use ibc::core::channel::types::acknowledgement::AcknowledgementStatus;
 use ibc::core::channel::types::channel::{Counterparty, Order};
+use ibc::core::channel::types::packet::Packet;
+use ibc::core::channel::types::timeout::TimeoutHeight;
 use ibc::core::channel::types::Version;
 use ibc::core::host::types::identifiers::{ChannelId, ConnectionId, PortId};
 use ibc::core::primitives::prelude::*;
+use ibc::core::primitives::Timestamp;
+use ibc_testkit::fixtures::core::channel::dummy_raw_packet;
+use ibc_testkit::fixtures::core::signer::dummy_account_id;
 use ibc_testkit::testapp::ibc::applications::nft_transfer::types::DummyNftTransferModule;
+use ibc_testkit::testapp::ibc::core::types::MockContext;
 
 fn get_defaults() -> (
     DummyNftTransferModule,
@@ -24,7 +38,7 @@ fn get_defaults() -> (
     let counterparty = Counterparty::new(port_id.clone(), Some(channel_id.clone()));
 
     (
-        DummyNftTransferModule,
+        DummyNftTransferModule::new(),
         order,
         connection_hops,
         port_id,
@@ -133,3 +147,179 @@ fn test_on_chan_open_try_counterparty_incorrect_version() {
 
     assert!(res.is_err());
 }
+
+fn token_ids(ids: &[&str]) -> TokenIds {
+    TokenIds(ids.iter().map(|id| id.parse().unwrap()).collect())
+}
+
+fn dummy_packet_data(class_id: &str, token_ids: TokenIds, memo: &str) -> PacketData {
+    PacketData {
+        class_id: class_id.parse().unwrap(),
+        class_uri: None,
+        class_data: None,
+        token_ids,
+        token_uris: None,
+        token_data: None,
+        sender: dummy_account_id(),
+        receiver: dummy_account_id(),
+        memo: Some(memo.to_string().into()),
+    }
+}
+
+/// A packet received over `channel-0`, whose counterparty is also
+/// `channel-0`.
+fn dummy_nft_packet(data: &PacketData) -> Packet {
+    let mut packet: Packet = dummy_raw_packet(10, 0).try_into().unwrap();
+    packet.data = serde_json::to_vec(data).unwrap();
+    packet
+}
+
+/// The class of the NFTs minted on receive of the NFTs of `myclass`.
+fn voucher_class_id() -> PrefixedClassId {
+    "transfer/channel-0/myclass".parse().unwrap()
+}
+
+fn token_id(id: &str) -> TokenId {
+    id.parse().unwrap()
+}
+
+fn recv_is_successful(module: &mut DummyNftTransferModule, data: &PacketData) -> bool {
+    let (_, ack) = on_recv_packet_execute(module, &dummy_nft_packet(data));
+    serde_json::from_slice::<AcknowledgementStatus>(ack.as_ref())
+        .unwrap()
+        .is_successful()
+}
+
+#[test]
+fn test_on_recv_packet_stores_class_trace() {
+    let class_id = voucher_class_id();
+    let hash = class_id.trace_hash();
+
+    let mut module = DummyNftTransferModule::new();
+
+    for ids in [&["token_0", "token_1"][..], &["token_2"]] {
+        let data = dummy_packet_data("myclass", token_ids(ids), "");
+        assert!(recv_is_successful(&mut module, &data));
+    }
+
+    assert_eq!(module.class_trace(&hash).unwrap(), Some(class_id.clone()));
+    assert_eq!(module.class_traces().unwrap().len(), 1);
+    assert!(module.classes.borrow().contains(&class_id.to_string()));
+}
+
+#[test]
+fn test_on_recv_packet_calls_receive_hook() {
+    let mut module = DummyNftTransferModule::new();
+
+    let data = dummy_packet_data("myclass", token_ids(&["token_0", "token_1"]), "hello");
+    assert!(recv_is_successful(&mut module, &data));
+
+    assert_eq!(module.received_memos, vec![Memo::from("hello".to_string())]);
+    for id in ["token_0", "token_1"] {
+        assert_eq!(
+            module.owner(&voucher_class_id(), &token_id(id)),
+            Some(&dummy_account_id())
+        );
+    }
+}
+
+#[test]
+fn test_on_recv_packet_vetoed_burns_minted_nfts() {
+    let mut module = DummyNftTransferModule::new();
+    module.reject_receive = true;
+
+    let data = dummy_packet_data("myclass", token_ids(&["token_0", "token_1"]), "");
+    assert!(!recv_is_successful(&mut module, &data));
+
+    assert!(module.owners.is_empty());
+    assert!(module.class_traces.is_empty());
+    assert!(module.classes.borrow().is_empty());
+}
+
+#[test]
+fn test_on_recv_packet_vetoed_escrows_back_nfts() {
+    let escrow_account =
+        DummyNftTransferModule::escrow_account(&PortId::transfer(), &ChannelId::default());
+    let class_id: PrefixedClassId = "myclass".parse().unwrap();
+
+    let mut module = DummyNftTransferModule::new();
+    for id in ["token_0", "token_1"] {
+        module.owners.insert(
+            (class_id.to_string(), id.to_string()),
+            escrow_account.clone(),
+        );
+    }
+    module.reject_receive = true;
+
+    // The counterparty sends back the NFTs escrowed on this chain.
+    let data = dummy_packet_data(
+        "transfer/channel-0/myclass",
+        token_ids(&["token_0", "token_1"]),
+        "",
+    );
+    assert!(!recv_is_successful(&mut module, &data));
+
+    for id in ["token_0", "token_1"] {
+        assert_eq!(
+            module.owner(&class_id, &token_id(id)),
+            Some(&escrow_account)
+        );
+    }
+}
+
+#[test]
+fn test_on_recv_packet_partial_failure_rolls_back_batch() {
+    let owner = "someone".to_string().into();
+
+    let mut module = DummyNftTransferModule::new();
+    // `token_1` already exists, hence it cannot be minted.
+    module.owners.insert(
+        (voucher_class_id().to_string(), "token_1".to_string()),
+        owner,
+    );
+
+    let data = dummy_packet_data("myclass", token_ids(&["token_0", "token_1"]), "");
+    assert!(!recv_is_successful(&mut module, &data));
+
+    assert_eq!(
+        module.owner(&voucher_class_id(), &token_id("token_0")),
+        None
+    );
+    assert!(module.received_memos.is_empty());
+    assert!(module.classes.borrow().is_empty());
+}
+
+#[test]
+fn test_on_recv_packet_invalid_batch() {
+    let mut module = DummyNftTransferModule::new();
+
+    let data = dummy_packet_data("myclass", token_ids(&["token_0", "token_0"]), "");
+    assert!(!recv_is_successful(&mut module, &data));
+
+    let ids: Vec<String> = (0..=DEFAULT_MAX_BATCH_SIZE)
+        .map(|i| format!("token_{i}"))
+        .collect();
+    let ids: Vec<&str> = ids.iter().map(String::as_str).collect();
+    let data = dummy_packet_data("myclass", token_ids(&ids), "");
+    assert!(!recv_is_successful(&mut module, &data));
+
+    assert!(module.owners.is_empty());
+}
+
+#[test]
+fn test_send_nft_transfer_duplicated_token_ids() {
+    let msg = MsgTransfer {
+        port_id_on_a: PortId::transfer(),
+        chan_id_on_a: ChannelId::default(),
+        packet_data: dummy_packet_data("myclass", token_ids(&["token_0", "token_0"]), ""),
+        timeout_height_on_b: TimeoutHeight::Never,
+        timeout_timestamp_on_b: Timestamp::none(),
+    };
+
+    let res =
+        send_nft_transfer_validate(&MockContext::default(), &DummyNftTransferModule::new(), msg);
+    assert!(
+        matches!(res, Err(NftTransferError::DuplicatedTokenIds)),
+        "unexpected result: {res:?}"
+    );
+}
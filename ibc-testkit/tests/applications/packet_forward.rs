@@ -0,0 +1,473 @@
+use core::time::Duration;
+
+use ibc::apps::fee::module::FeeMiddleware;
+use ibc::apps::fee::types::IncentivizedAcknowledgement;
+use ibc::apps::packet_forward::module::{
+    PacketForwardConfig, PacketForwardMiddleware, PacketForwardModule,
+};
+use ibc::apps::packet_forward::types::{DEFAULT_FORWARD_RETRIES, DEFAULT_FORWARD_TIMEOUT};
+use ibc::apps::transfer::types::packet::PacketData;
+use ibc::apps::transfer::types::{ack_success_b64, Amount, Memo, PrefixedCoin, VERSION};
+use ibc::core::channel::handler::write_acknowledgement_via_module;
+use ibc::core::channel::types::acknowledgement::{
+    Acknowledgement, AcknowledgementStatus, StatusValue,
+};
+use ibc::core::channel::types::channel::{ChannelEnd, Counterparty, Order, State};
+use ibc::core::channel::types::commitment::compute_ack_commitment;
+use ibc::core::channel::types::packet::{Packet, PacketId, Receipt};
+use ibc::core::channel::types::timeout::TimeoutHeight;
+use ibc::core::channel::types::Version;
+use ibc::core::client::types::Height;
+use ibc::core::commitment_types::merkle::HostFunctionsManager;
+use ibc::core::connection::types::version::get_compatible_versions;
+use ibc::core::connection::types::{
+    ConnectionEnd, Counterparty as ConnectionCounterparty, State as ConnectionState,
+};
+use ibc::core::handler::types::events::IbcEvent;
+use ibc::core::host::types::identifiers::{ChannelId, ClientId, ConnectionId, PortId, Sequence};
+use ibc::core::host::types::path::AckPath;
+use ibc::core::host::ValidationContext;
+use ibc::core::primitives::prelude::*;
+use ibc::core::primitives::{Signer, Timestamp, ZERO_DURATION};
+use ibc::core::router::middleware::Middleware;
+use ibc::core::router::module::Module;
+use ibc_testkit::testapp::ibc::applications::fee::types::MockFeeContext;
+use ibc_testkit::testapp::ibc::applications::packet_forward::types::MockPacketForwardContext;
+use ibc_testkit::testapp::ibc::applications::transfer::types::MockTransferModule;
+use ibc_testkit::testapp::ibc::core::types::{MockClientConfig, MockContext};
+
+type PfmModule = PacketForwardModule<MockPacketForwardContext, MockTransferModule>;
+
+const BASE_DENOM: &str = "uatom";
+/// The denomination on this chain of the tokens received over `channel-0`.
+const RECEIVED_DENOM: &str = "transfer/channel-0/uatom";
+
+fn signer(address: &str) -> Signer {
+    address.to_string().into()
+}
+
+fn intermediate_receiver() -> Signer {
+    signer("pfm/channel-0/alice")
+}
+
+fn escrow_account() -> Signer {
+    MockTransferModule::escrow_account(&PortId::transfer(), &ChannelId::new(1))
+}
+
+/// The chain receives packets over `channel-0` and forwards them over
+/// `channel-1`.
+fn pfm_context() -> MockPacketForwardContext {
+    let chan_end = |counterparty_chan_id: u64| {
+        ChannelEnd::new(
+            State::Open,
+            Order::Unordered,
+            Counterparty::new(
+                PortId::transfer(),
+                Some(ChannelId::new(counterparty_chan_id)),
+            ),
+            vec![ConnectionId::default()],
+            Version::new(VERSION.to_string()),
+        )
+        .unwrap()
+    };
+
+    let conn_end = ConnectionEnd::new(
+        ConnectionState::Open,
+        ClientId::default(),
+        ConnectionCounterparty::new(
+            ClientId::default(),
+            Some(ConnectionId::default()),
+            Default::default(),
+        ),
+        get_compatible_versions(),
+        ZERO_DURATION,
+    )
+    .unwrap();
+
+    let ctx = MockContext::default()
+        .with_client_config(
+            MockClientConfig::builder()
+                .latest_height(Height::new(0, 5).unwrap())
+                .build(),
+        )
+        .with_connection(ConnectionId::default(), conn_end)
+        .with_channel(PortId::transfer(), ChannelId::new(0), chan_end(5))
+        .with_channel(PortId::transfer(), ChannelId::new(1), chan_end(7))
+        .with_send_sequence(PortId::transfer(), ChannelId::new(1), 1.into())
+        // the packets forwarded in the tests are received with sequence 1
+        .with_packet_receipt(PortId::transfer(), ChannelId::new(0), 1.into(), Receipt::Ok);
+
+    MockPacketForwardContext::new(ctx)
+}
+
+fn pfm_module(config: PacketForwardConfig) -> PfmModule {
+    PacketForwardMiddleware::with_config(pfm_context(), config).wrap(MockTransferModule::new())
+}
+
+/// A packet of 100 `uatom` sent by `alice` from the previous chain.
+fn received_packet(memo: &str) -> Packet {
+    let data = PacketData {
+        token: PrefixedCoin {
+            denom: BASE_DENOM.parse().unwrap(),
+            amount: Amount::from(100),
+        },
+        sender: signer("alice"),
+        receiver: signer("bob"),
+        memo: Memo::from(memo.to_string()),
+    };
+
+    Packet {
+        seq_on_a: 1.into(),
+        port_id_on_a: PortId::transfer(),
+        chan_id_on_a: ChannelId::new(5),
+        port_id_on_b: PortId::transfer(),
+        chan_id_on_b: ChannelId::new(0),
+        data: serde_json::to_vec(&data).unwrap(),
+        timeout_height_on_b: TimeoutHeight::Never,
+        timeout_timestamp_on_b: Timestamp::none(),
+    }
+}
+
+fn forward_memo() -> String {
+    r#"{"forward":{"receiver":"carol","port":"transfer","channel":"channel-1"}}"#.to_string()
+}
+
+/// Rebuilds the packets sent over `channel-1` from their events.
+fn forwarded_packets(module: &PfmModule) -> Vec<Packet> {
+    module
+        .middleware()
+        .ctx()
+        .ibc
+        .get_events()
+        .into_iter()
+        .filter_map(|event| match event {
+            IbcEvent::SendPacket(e) => Some(Packet {
+                seq_on_a: *e.seq_on_a(),
+                port_id_on_a: e.port_id_on_a().clone(),
+                chan_id_on_a: e.chan_id_on_a().clone(),
+                port_id_on_b: e.port_id_on_b().clone(),
+                chan_id_on_b: e.chan_id_on_b().clone(),
+                data: e.packet_data().to_vec(),
+                timeout_height_on_b: *e.timeout_height_on_b(),
+                timeout_timestamp_on_b: *e.timeout_timestamp_on_b(),
+            }),
+            _ => None,
+        })
+        .collect()
+}
+
+fn written_ack(module: &PfmModule, packet: &Packet) -> Option<Vec<u8>> {
+    let ack_path = AckPath::new(&packet.port_id_on_b, &packet.chan_id_on_b, packet.seq_on_a);
+    module
+        .middleware()
+        .ctx()
+        .ibc
+        .get_packet_acknowledgement(&ack_path)
+        .ok()
+        .map(|commitment| commitment.into_vec())
+}
+
+fn balance(module: &PfmModule, account: &Signer) -> Amount {
+    module.next().balance(account, RECEIVED_DENOM)
+}
+
+/// Receives a packet to be forwarded, and returns the forwarded packet.
+fn forward(module: &mut PfmModule) -> Packet {
+    let packet = received_packet(&forward_memo());
+
+    let (_, ack) = module.on_recv_packet_execute(&packet, &signer("relayer"));
+    assert_eq!(ack, None);
+
+    let forwarded_packets = forwarded_packets(module);
+    assert_eq!(forwarded_packets.len(), 1);
+    forwarded_packets[0].clone()
+}
+
+#[test]
+fn test_config_defaults() {
+    let config = PacketForwardConfig::default();
+
+    assert_eq!(config.retries_on_timeout, DEFAULT_FORWARD_RETRIES);
+    assert_eq!(config.forward_timeout, DEFAULT_FORWARD_TIMEOUT);
+}
+
+#[test]
+fn test_recv_packet_without_forward_memo() {
+    let mut module = pfm_module(PacketForwardConfig::default());
+    let packet = received_packet("a plain memo");
+
+    let (_, ack) = module.on_recv_packet_execute(&packet, &signer("relayer"));
+
+    let expected_ack: Acknowledgement = AcknowledgementStatus::success(ack_success_b64()).into();
+    assert_eq!(ack, Some(expected_ack));
+    assert_eq!(balance(&module, &signer("bob")), Amount::from(100));
+    assert!(forwarded_packets(&module).is_empty());
+    assert!(module.middleware().ctx().in_flight_packets.is_empty());
+}
+
+#[test]
+fn test_recv_packet_with_invalid_forward_memo() {
+    let mut module = pfm_module(PacketForwardConfig::default());
+    let packet = received_packet(r#"{"forward":{"receiver":"carol","port":"transfer"}}"#);
+
+    let (_, ack) = module.on_recv_packet_execute(&packet, &signer("relayer"));
+
+    let ack = serde_json::from_slice::<AcknowledgementStatus>(ack.unwrap().as_ref()).unwrap();
+    assert!(!ack.is_successful());
+    assert_eq!(balance(&module, &signer("bob")), Amount::from(0));
+    assert!(forwarded_packets(&module).is_empty());
+}
+
+#[test]
+fn test_recv_packet_forwards_tokens() {
+    let mut module = pfm_module(PacketForwardConfig::default());
+
+    let forwarded_packet = forward(&mut module);
+
+    assert_eq!(forwarded_packet.chan_id_on_a, ChannelId::new(1));
+    assert_eq!(forwarded_packet.chan_id_on_b, ChannelId::new(7));
+    assert_eq!(forwarded_packet.seq_on_a, Sequence::from(1));
+
+    let data = serde_json::from_slice::<PacketData>(&forwarded_packet.data).unwrap();
+    assert_eq!(data.token.denom.to_string(), RECEIVED_DENOM);
+    assert_eq!(data.sender, intermediate_receiver());
+    assert_eq!(data.receiver, signer("carol"));
+    assert_eq!(data.memo.as_ref(), "");
+
+    assert_eq!(balance(&module, &intermediate_receiver()), Amount::from(0));
+    assert_eq!(balance(&module, &escrow_account()), Amount::from(100));
+
+    let in_flight_packets = &module.middleware().ctx().in_flight_packets;
+    let in_flight_packet = in_flight_packets
+        .get(&PacketId::new(
+            PortId::transfer(),
+            ChannelId::new(1),
+            1.into(),
+        ))
+        .unwrap();
+    assert_eq!(
+        in_flight_packet.original_packet,
+        received_packet(&forward_memo())
+    );
+    assert_eq!(in_flight_packet.retries_remaining, DEFAULT_FORWARD_RETRIES);
+    assert_eq!(in_flight_packet.timeout, DEFAULT_FORWARD_TIMEOUT);
+
+    assert!(written_ack(&module, &received_packet(&forward_memo())).is_none());
+}
+
+#[test]
+fn test_failed_forward_escrows_tokens_back() {
+    let escrow_account =
+        MockTransferModule::escrow_account(&PortId::transfer(), &ChannelId::new(0));
+    let native_coin = PrefixedCoin {
+        denom: BASE_DENOM.parse().unwrap(),
+        amount: Amount::from(100),
+    };
+    let mut transfer_module =
+        MockTransferModule::new().with_balance(escrow_account.clone(), &[native_coin.clone()]);
+    transfer_module
+        .total_escrows
+        .insert(BASE_DENOM.to_string(), native_coin.amount);
+    let mut module = PacketForwardMiddleware::new(pfm_context()).wrap(transfer_module);
+
+    // the vouchers of native tokens are sent back, to be forwarded over a
+    // channel which does not exist
+    let mut packet = received_packet(
+        r#"{"forward":{"receiver":"carol","port":"transfer","channel":"channel-9"}}"#,
+    );
+    let mut data = serde_json::from_slice::<PacketData>(&packet.data).unwrap();
+    data.token.denom = "transfer/channel-5/uatom".parse().unwrap();
+    packet.data = serde_json::to_vec(&data).unwrap();
+
+    let (_, ack) = module.on_recv_packet_execute(&packet, &signer("relayer"));
+
+    let ack = serde_json::from_slice::<AcknowledgementStatus>(ack.unwrap().as_ref()).unwrap();
+    assert!(!ack.is_successful());
+    assert_eq!(
+        module.next().balance(&escrow_account, BASE_DENOM),
+        Amount::from(100)
+    );
+    assert_eq!(
+        module.next().total_escrows.get(BASE_DENOM),
+        Some(&Amount::from(100))
+    );
+}
+
+#[test]
+fn test_forward_memo_overrides_config() {
+    let mut module = pfm_module(PacketForwardConfig::default());
+    let packet = received_packet(
+        r#"{"forward":{"receiver":"carol","port":"transfer","channel":"channel-1","timeout":"10m","retries":3,"next":{"forward":{"receiver":"dave","port":"transfer","channel":"channel-9"}}}}"#,
+    );
+
+    let (_, ack) = module.on_recv_packet_execute(&packet, &signer("relayer"));
+    assert_eq!(ack, None);
+
+    let forwarded_packet = forwarded_packets(&module).remove(0);
+    let data = serde_json::from_slice::<PacketData>(&forwarded_packet.data).unwrap();
+    assert_eq!(
+        data.memo.as_ref(),
+        r#"{"forward":{"receiver":"dave","port":"transfer","channel":"channel-9"}}"#
+    );
+
+    let in_flight_packet = module
+        .middleware()
+        .ctx()
+        .in_flight_packets
+        .values()
+        .next()
+        .unwrap();
+    assert_eq!(in_flight_packet.retries_remaining, 3);
+    assert_eq!(in_flight_packet.timeout, Duration::from_secs(600));
+}
+
+#[test]
+fn test_successful_ack_is_relayed_back() {
+    let mut module = pfm_module(PacketForwardConfig::default());
+    let forwarded_packet = forward(&mut module);
+
+    let ack: Acknowledgement = AcknowledgementStatus::success(ack_success_b64()).into();
+    module
+        .on_acknowledgement_packet_validate(&forwarded_packet, &ack, &signer("relayer"))
+        .unwrap();
+    let (_, res) =
+        module.on_acknowledgement_packet_execute(&forwarded_packet, &ack, &signer("relayer"));
+    res.unwrap();
+
+    assert!(module.middleware().ctx().in_flight_packets.is_empty());
+    assert_eq!(balance(&module, &escrow_account()), Amount::from(100));
+    assert_eq!(
+        written_ack(&module, &received_packet(&forward_memo())),
+        Some(compute_ack_commitment::<HostFunctionsManager>(&ack).into_vec())
+    );
+}
+
+#[test]
+fn test_error_ack_refunds_and_is_relayed_back() {
+    let mut module = pfm_module(PacketForwardConfig::default());
+    let forwarded_packet = forward(&mut module);
+
+    let ack: Acknowledgement =
+        AcknowledgementStatus::error(StatusValue::new("receive failed").unwrap()).into();
+    module
+        .on_acknowledgement_packet_validate(&forwarded_packet, &ack, &signer("relayer"))
+        .unwrap();
+    let (_, res) =
+        module.on_acknowledgement_packet_execute(&forwarded_packet, &ack, &signer("relayer"));
+    res.unwrap();
+
+    assert!(module.middleware().ctx().in_flight_packets.is_empty());
+    // the received vouchers are burnt, so that the previous chain refunds
+    // the original sender
+    assert_eq!(balance(&module, &escrow_account()), Amount::from(0));
+    assert_eq!(balance(&module, &intermediate_receiver()), Amount::from(0));
+    assert_eq!(
+        written_ack(&module, &received_packet(&forward_memo())),
+        Some(compute_ack_commitment::<HostFunctionsManager>(&ack).into_vec())
+    );
+}
+
+#[test]
+fn test_timeout_retries_forward() {
+    let mut module = pfm_module(PacketForwardConfig::default());
+    let forwarded_packet = forward(&mut module);
+
+    module
+        .on_timeout_packet_validate(&forwarded_packet, &signer("relayer"))
+        .unwrap();
+    let (_, res) = module.on_timeout_packet_execute(&forwarded_packet, &signer("relayer"));
+    res.unwrap();
+
+    let forwarded_packets = forwarded_packets(&module);
+    assert_eq!(forwarded_packets.len(), 2);
+    assert_eq!(forwarded_packets[1].seq_on_a, Sequence::from(2));
+    assert_eq!(forwarded_packets[1].data, forwarded_packet.data);
+
+    let in_flight_packets = &module.middleware().ctx().in_flight_packets;
+    assert_eq!(in_flight_packets.len(), 1);
+    let in_flight_packet = in_flight_packets
+        .get(&PacketId::new(
+            PortId::transfer(),
+            ChannelId::new(1),
+            2.into(),
+        ))
+        .unwrap();
+    assert_eq!(in_flight_packet.retries_remaining, 0);
+
+    assert_eq!(balance(&module, &escrow_account()), Amount::from(100));
+    assert!(written_ack(&module, &received_packet(&forward_memo())).is_none());
+}
+
+#[test]
+fn test_timeout_without_retries_fails_forward() {
+    let mut module = pfm_module(PacketForwardConfig {
+        retries_on_timeout: 0,
+        ..Default::default()
+    });
+    let forwarded_packet = forward(&mut module);
+
+    module
+        .on_timeout_packet_validate(&forwarded_packet, &signer("relayer"))
+        .unwrap();
+    let (_, res) = module.on_timeout_packet_execute(&forwarded_packet, &signer("relayer"));
+    res.unwrap();
+
+    assert_eq!(forwarded_packets(&module).len(), 1);
+    assert!(module.middleware().ctx().in_flight_packets.is_empty());
+    assert_eq!(balance(&module, &escrow_account()), Amount::from(0));
+    assert_eq!(balance(&module, &intermediate_receiver()), Amount::from(0));
+    assert!(written_ack(&module, &received_packet(&forward_memo())).is_some());
+}
+
+#[test]
+fn test_fee_middleware_wraps_relayed_back_ack() {
+    let mut fee_ctx = MockFeeContext::new().with_fee_enabled(PortId::transfer(), ChannelId::new(0));
+    fee_ctx.counterparty_payees.insert(
+        (signer("relayer"), ChannelId::new(0)),
+        signer("forward-payee"),
+    );
+    let pfm = PacketForwardMiddleware::new(pfm_context().with_deferred_acks())
+        .wrap(MockTransferModule::new());
+    let mut stack = FeeMiddleware::new(fee_ctx).wrap(pfm);
+
+    let packet = received_packet(&forward_memo());
+    let received_packet_id = PacketId::new(PortId::transfer(), ChannelId::new(0), 1.into());
+
+    let (_, ack) = stack.on_recv_packet_execute(&packet, &signer("relayer"));
+    assert_eq!(ack, None);
+    assert_eq!(
+        stack
+            .middleware()
+            .ctx()
+            .forward_relayers
+            .get(&received_packet_id),
+        Some(&signer("forward-payee"))
+    );
+
+    let forwarded_packet = forwarded_packets(stack.next()).remove(0);
+    let ack: Acknowledgement = AcknowledgementStatus::success(ack_success_b64()).into();
+    let (_, res) =
+        stack.on_acknowledgement_packet_execute(&forwarded_packet, &ack, &signer("relayer"));
+    res.unwrap();
+
+    // the acknowledgement relayed back by the packet-forward middleware is
+    // written through the whole stack, so that the fee middleware wraps it
+    let pfm_ctx = stack.next_mut().middleware_mut().ctx_mut();
+    let mut deferred_acks = pfm_ctx.deferred_acks.take().unwrap();
+    assert_eq!(deferred_acks.len(), 1);
+    let (original_packet, relayed_ack) = deferred_acks.remove(0);
+    assert_eq!(original_packet, packet);
+    assert_eq!(relayed_ack, ack);
+
+    let mut ibc = pfm_ctx.ibc.clone();
+    write_acknowledgement_via_module(&mut ibc, &mut stack, original_packet, relayed_ack).unwrap();
+
+    let expected_ack: Acknowledgement =
+        IncentivizedAcknowledgement::new(ack, signer("forward-payee")).into();
+    let ack_path = AckPath::new(&packet.port_id_on_b, &packet.chan_id_on_b, packet.seq_on_a);
+    assert_eq!(
+        ibc.get_packet_acknowledgement(&ack_path).unwrap(),
+        compute_ack_commitment::<HostFunctionsManager>(&expected_ack)
+    );
+    assert!(stack.middleware().ctx().forward_relayers.is_empty());
+}
@@ -0,0 +1,521 @@
+use core::time::Duration;
+
+use ibc::apps::rate_limit::handler::{add_rate_limit, remove_rate_limit, send_transfer};
+use ibc::apps::rate_limit::module::{
+    on_acknowledgement_packet_execute, on_timeout_packet_execute, RateLimitMiddleware,
+    RateLimitModule,
+};
+use ibc::apps::rate_limit::types::error::RateLimitError;
+use ibc::apps::rate_limit::types::msgs::{MsgAddRateLimit, MsgRemoveRateLimit};
+use ibc::apps::rate_limit::types::{Quota, QuotaLimit, RateLimitPath};
+use ibc::apps::transfer::types::msgs::transfer::MsgTransfer;
+use ibc::apps::transfer::types::packet::PacketData;
+use ibc::apps::transfer::types::{ack_success_b64, Amount, Memo, PrefixedCoin, VERSION};
+use ibc::core::channel::handler::send_packet_via_module;
+use ibc::core::channel::types::acknowledgement::{
+    Acknowledgement, AcknowledgementStatus, StatusValue,
+};
+use ibc::core::channel::types::channel::{ChannelEnd, Counterparty, Order, State};
+use ibc::core::channel::types::packet::Packet;
+use ibc::core::channel::types::timeout::TimeoutHeight;
+use ibc::core::channel::types::Version;
+use ibc::core::client::types::Height;
+use ibc::core::connection::types::version::get_compatible_versions;
+use ibc::core::connection::types::{
+    ConnectionEnd, Counterparty as ConnectionCounterparty, State as ConnectionState,
+};
+use ibc::core::handler::types::events::IbcEvent;
+use ibc::core::host::types::identifiers::{ChannelId, ClientId, ConnectionId, PortId};
+use ibc::core::primitives::prelude::*;
+use ibc::core::primitives::{Signer, Timestamp, ZERO_DURATION};
+use ibc::core::router::middleware::Middleware;
+use ibc::core::router::module::Module;
+use ibc_testkit::testapp::ibc::applications::rate_limit::types::MockRateLimitContext;
+use ibc_testkit::testapp::ibc::applications::transfer::types::MockTransferModule;
+use ibc_testkit::testapp::ibc::core::types::{MockClientConfig, MockContext};
+
+type RlModule = RateLimitModule<MockRateLimitContext, MockTransferModule>;
+
+const BASE_DENOM: &str = "uatom";
+/// The denomination on this chain of the tokens received over `channel-0`.
+const RECEIVED_DENOM: &str = "transfer/channel-0/uatom";
+const QUOTA_NAME: &str = "hourly";
+const WINDOW: Duration = Duration::from_secs(60 * 60);
+
+fn signer(address: &str) -> Signer {
+    address.to_string().into()
+}
+
+fn authority() -> Signer {
+    signer("authority")
+}
+
+fn quota(max_send: QuotaLimit, max_recv: QuotaLimit) -> Quota {
+    Quota {
+        name: QUOTA_NAME.to_string(),
+        duration: WINDOW,
+        max_send,
+        max_recv,
+    }
+}
+
+/// The path of the tokens received over `channel-0`.
+fn recv_path() -> RateLimitPath {
+    RateLimitPath::new(ChannelId::new(0), RECEIVED_DENOM.parse().unwrap())
+}
+
+/// The path of the native tokens sent over `channel-1`.
+fn send_path() -> RateLimitPath {
+    RateLimitPath::new(ChannelId::new(1), BASE_DENOM.parse().unwrap())
+}
+
+/// A rate-limit context with a supply of 1000 of both denominations, and
+/// the given quota set on the path.
+fn rate_limit_ctx(path: RateLimitPath, quota: Quota) -> MockRateLimitContext {
+    let mut ctx = MockRateLimitContext::new(authority())
+        .with_supply(BASE_DENOM.parse().unwrap(), Amount::from(1000))
+        .with_supply(RECEIVED_DENOM.parse().unwrap(), Amount::from(1000));
+
+    add_rate_limit(
+        &mut ctx,
+        MsgAddRateLimit {
+            signer: authority(),
+            path,
+            quota,
+        },
+    )
+    .unwrap();
+
+    ctx
+}
+
+/// A packet of `amount` uatom sent by `alice` from the counterparty chain
+/// over `channel-0`.
+fn received_packet(amount: u64) -> Packet {
+    let data = PacketData {
+        token: PrefixedCoin {
+            denom: BASE_DENOM.parse().unwrap(),
+            amount: Amount::from(amount),
+        },
+        sender: signer("alice"),
+        receiver: signer("bob"),
+        memo: Memo::from(String::new()),
+    };
+
+    Packet {
+        seq_on_a: 1.into(),
+        port_id_on_a: PortId::transfer(),
+        chan_id_on_a: ChannelId::new(5),
+        port_id_on_b: PortId::transfer(),
+        chan_id_on_b: ChannelId::new(0),
+        data: serde_json::to_vec(&data).unwrap(),
+        timeout_height_on_b: TimeoutHeight::Never,
+        timeout_timestamp_on_b: Timestamp::none(),
+    }
+}
+
+fn is_successful(ack: &Acknowledgement) -> bool {
+    serde_json::from_slice::<AcknowledgementStatus>(ack.as_ref())
+        .unwrap()
+        .is_successful()
+}
+
+fn recv(module: &mut RlModule, amount: u64) -> Acknowledgement {
+    let (_, ack) = module.on_recv_packet_execute(&received_packet(amount), &signer("relayer"));
+    ack.expect("the rate-limit middleware acknowledges synchronously")
+}
+
+/// The sending chain, whose `channel-1` is open, with 1000 uatom owned by
+/// `alice`.
+struct Sender {
+    ibc: MockContext,
+    transfer: MockTransferModule,
+    rate_limit: MockRateLimitContext,
+}
+
+impl Sender {
+    fn new(quota: Quota) -> Self {
+        let chan_end = ChannelEnd::new(
+            State::Open,
+            Order::Unordered,
+            Counterparty::new(PortId::transfer(), Some(ChannelId::new(7))),
+            vec![ConnectionId::default()],
+            Version::new(VERSION.to_string()),
+        )
+        .unwrap();
+
+        let conn_end = ConnectionEnd::new(
+            ConnectionState::Open,
+            ClientId::default(),
+            ConnectionCounterparty::new(
+                ClientId::default(),
+                Some(ConnectionId::default()),
+                Default::default(),
+            ),
+            get_compatible_versions(),
+            ZERO_DURATION,
+        )
+        .unwrap();
+
+        let ibc = MockContext::default()
+            .with_client_config(
+                MockClientConfig::builder()
+                    .latest_height(Height::new(0, 5).unwrap())
+                    .build(),
+            )
+            .with_connection(ConnectionId::default(), conn_end)
+            .with_channel(PortId::transfer(), ChannelId::new(1), chan_end)
+            .with_send_sequence(PortId::transfer(), ChannelId::new(1), 1.into());
+
+        let transfer = MockTransferModule::new().with_balance(
+            signer("alice"),
+            &[PrefixedCoin {
+                denom: BASE_DENOM.parse().unwrap(),
+                amount: Amount::from(1000),
+            }],
+        );
+
+        Self {
+            ibc,
+            transfer,
+            rate_limit: rate_limit_ctx(send_path(), quota),
+        }
+    }
+
+    fn send(&mut self, amount: u64) -> Result<(), RateLimitError> {
+        let msg = MsgTransfer {
+            port_id_on_a: PortId::transfer(),
+            chan_id_on_a: ChannelId::new(1),
+            packet_data: PacketData {
+                token: PrefixedCoin {
+                    denom: BASE_DENOM.parse().unwrap(),
+                    amount: Amount::from(amount),
+                },
+                sender: signer("alice"),
+                receiver: signer("carol"),
+                memo: Memo::from(String::new()),
+            },
+            timeout_height_on_b: TimeoutHeight::Never,
+            timeout_timestamp_on_b: (Timestamp::now() + WINDOW).unwrap(),
+        };
+
+        send_transfer(&mut self.ibc, &mut self.transfer, &mut self.rate_limit, msg)
+    }
+
+    /// A packet of `amount` uatom sent by `alice` over `channel-1`.
+    fn packet(sequence: u64, amount: u64) -> Packet {
+        let data = PacketData {
+            token: PrefixedCoin {
+                denom: BASE_DENOM.parse().unwrap(),
+                amount: Amount::from(amount),
+            },
+            sender: signer("alice"),
+            receiver: signer("carol"),
+            memo: Memo::from(String::new()),
+        };
+
+        Packet {
+            seq_on_a: sequence.into(),
+            port_id_on_a: PortId::transfer(),
+            chan_id_on_a: ChannelId::new(1),
+            port_id_on_b: PortId::transfer(),
+            chan_id_on_b: ChannelId::new(7),
+            data: serde_json::to_vec(&data).unwrap(),
+            timeout_height_on_b: TimeoutHeight::Never,
+            timeout_timestamp_on_b: (Timestamp::now() + WINDOW).unwrap(),
+        }
+    }
+
+    /// Rebuilds the packets sent over `channel-1` from their events.
+    fn sent_packets(&self) -> Vec<Packet> {
+        self.ibc
+            .get_events()
+            .into_iter()
+            .filter_map(|event| match event {
+                IbcEvent::SendPacket(e) => Some(Packet {
+                    seq_on_a: *e.seq_on_a(),
+                    port_id_on_a: e.port_id_on_a().clone(),
+                    chan_id_on_a: e.chan_id_on_a().clone(),
+                    port_id_on_b: e.port_id_on_b().clone(),
+                    chan_id_on_b: e.chan_id_on_b().clone(),
+                    data: e.packet_data().to_vec(),
+                    timeout_height_on_b: *e.timeout_height_on_b(),
+                    timeout_timestamp_on_b: *e.timeout_timestamp_on_b(),
+                }),
+                _ => None,
+            })
+            .collect()
+    }
+
+    fn outflow(&self) -> Amount {
+        self.rate_limit
+            .rate_limit(&send_path(), QUOTA_NAME)
+            .unwrap()
+            .flow
+            .outflow
+    }
+}
+
+#[test]
+fn test_add_and_remove_rate_limit() {
+    let mut ctx = MockRateLimitContext::new(authority());
+    let quota = quota(QuotaLimit::Percent(10), QuotaLimit::Percent(10));
+
+    let msg = MsgAddRateLimit {
+        signer: signer("mallory"),
+        path: send_path(),
+        quota: quota.clone(),
+    };
+    assert!(matches!(
+        add_rate_limit(&mut ctx, msg),
+        Err(RateLimitError::Unauthorized { .. })
+    ));
+
+    let msg = MsgAddRateLimit {
+        signer: authority(),
+        path: send_path(),
+        quota,
+    };
+    add_rate_limit(&mut ctx, msg.clone()).unwrap();
+    assert!(ctx.rate_limit(&send_path(), QUOTA_NAME).is_some());
+    assert!(matches!(
+        add_rate_limit(&mut ctx, msg),
+        Err(RateLimitError::RateLimitExists { .. })
+    ));
+
+    let msg = MsgRemoveRateLimit {
+        signer: authority(),
+        path: send_path(),
+        quota_name: QUOTA_NAME.to_string(),
+    };
+    remove_rate_limit(&mut ctx, msg.clone()).unwrap();
+    assert!(ctx.rate_limit(&send_path(), QUOTA_NAME).is_none());
+    assert!(matches!(
+        remove_rate_limit(&mut ctx, msg),
+        Err(RateLimitError::RateLimitNotFound { .. })
+    ));
+}
+
+#[test]
+fn test_add_invalid_rate_limit() {
+    let mut ctx = MockRateLimitContext::new(authority());
+
+    let msg = MsgAddRateLimit {
+        signer: authority(),
+        path: send_path(),
+        quota: quota(QuotaLimit::Percent(150), QuotaLimit::Percent(10)),
+    };
+
+    assert!(matches!(
+        add_rate_limit(&mut ctx, msg),
+        Err(RateLimitError::InvalidQuota { .. })
+    ));
+}
+
+#[test]
+fn test_send_over_quota_is_rejected() {
+    let mut sender = Sender::new(quota(
+        QuotaLimit::Absolute(Amount::from(100)),
+        QuotaLimit::Percent(100),
+    ));
+
+    sender.send(60).unwrap();
+    assert!(matches!(
+        sender.send(60),
+        Err(RateLimitError::QuotaExceeded { .. })
+    ));
+
+    assert_eq!(sender.sent_packets().len(), 1);
+    assert_eq!(sender.outflow(), Amount::from(60));
+    assert_eq!(
+        sender.transfer.balance(&signer("alice"), BASE_DENOM),
+        Amount::from(940)
+    );
+}
+
+#[test]
+fn test_timeout_undoes_outflow() {
+    let mut sender = Sender::new(quota(QuotaLimit::Percent(10), QuotaLimit::Percent(100)));
+
+    sender.send(100).unwrap();
+    assert!(sender.send(1).is_err());
+
+    let packet = sender.sent_packets()[0].clone();
+    let (_, result) = on_timeout_packet_execute(
+        &mut sender.rate_limit,
+        &mut sender.transfer,
+        &packet,
+        &signer("relayer"),
+    );
+    result.unwrap();
+
+    assert_eq!(sender.outflow(), Amount::from(0));
+    assert_eq!(
+        sender.transfer.balance(&signer("alice"), BASE_DENOM),
+        Amount::from(1000)
+    );
+    sender.send(100).unwrap();
+}
+
+#[test]
+fn test_error_ack_undoes_outflow() {
+    let mut sender = Sender::new(quota(QuotaLimit::Percent(10), QuotaLimit::Percent(100)));
+
+    sender.send(40).unwrap();
+    sender.send(60).unwrap();
+    let packets = sender.sent_packets();
+
+    let ack: Acknowledgement = AcknowledgementStatus::success(ack_success_b64()).into();
+    let (_, result) = on_acknowledgement_packet_execute(
+        &mut sender.rate_limit,
+        &mut sender.transfer,
+        &packets[0],
+        &ack,
+        &signer("relayer"),
+    );
+    result.unwrap();
+    assert_eq!(sender.outflow(), Amount::from(100));
+
+    let ack: Acknowledgement =
+        AcknowledgementStatus::error(StatusValue::new("failed").unwrap()).into();
+    let (_, result) = on_acknowledgement_packet_execute(
+        &mut sender.rate_limit,
+        &mut sender.transfer,
+        &packets[1],
+        &ack,
+        &signer("relayer"),
+    );
+    result.unwrap();
+    assert_eq!(sender.outflow(), Amount::from(40));
+}
+
+#[test]
+fn test_send_packet_hooks_enforce_outflow() {
+    let Sender {
+        mut ibc,
+        rate_limit,
+        ..
+    } = Sender::new(quota(
+        QuotaLimit::Absolute(Amount::from(100)),
+        QuotaLimit::Percent(100),
+    ));
+    let mut module = RateLimitMiddleware::new(rate_limit).wrap(MockTransferModule::new());
+
+    send_packet_via_module(&mut ibc, &mut module, Sender::packet(1, 60)).unwrap();
+    assert!(send_packet_via_module(&mut ibc, &mut module, Sender::packet(2, 60)).is_err());
+
+    let rate_limit = module
+        .middleware()
+        .ctx()
+        .rate_limit(&send_path(), QUOTA_NAME)
+        .unwrap();
+    assert_eq!(rate_limit.flow.outflow, Amount::from(60));
+    assert_eq!(module.middleware().ctx().pending_send_flows.len(), 1);
+}
+
+#[test]
+fn test_timeout_only_undoes_recorded_outflow() {
+    let mut sender = Sender::new(quota(
+        QuotaLimit::Absolute(Amount::from(100)),
+        QuotaLimit::Percent(100),
+    ));
+
+    sender.send(100).unwrap();
+    let packet = sender.sent_packets()[0].clone();
+
+    // the packet was counted during a window which has ended
+    sender.rate_limit.host_timestamp = (sender.rate_limit.host_timestamp + WINDOW).unwrap();
+    sender.send(100).unwrap();
+
+    let (_, result) = on_timeout_packet_execute(
+        &mut sender.rate_limit,
+        &mut sender.transfer,
+        &packet,
+        &signer("relayer"),
+    );
+    result.unwrap();
+
+    assert_eq!(sender.outflow(), Amount::from(100));
+    assert!(sender.send(1).is_err());
+}
+
+#[test]
+fn test_window_expiry_resets_flows() {
+    let mut sender = Sender::new(quota(
+        QuotaLimit::Absolute(Amount::from(100)),
+        QuotaLimit::Percent(100),
+    ));
+
+    sender.send(100).unwrap();
+    assert!(sender.send(100).is_err());
+
+    sender.rate_limit.host_timestamp = (sender.rate_limit.host_timestamp + WINDOW).unwrap();
+
+    sender.send(100).unwrap();
+    assert_eq!(sender.outflow(), Amount::from(100));
+}
+
+#[test]
+fn test_recv_over_quota_gets_error_ack() {
+    // 10% of the supply of 1000
+    let ctx = rate_limit_ctx(
+        recv_path(),
+        quota(QuotaLimit::Percent(100), QuotaLimit::Percent(10)),
+    );
+    let mut module = RateLimitMiddleware::new(ctx).wrap(MockTransferModule::new());
+
+    assert!(is_successful(&recv(&mut module, 70)));
+    assert!(!is_successful(&recv(&mut module, 70)));
+
+    assert_eq!(
+        module.next().balance(&signer("bob"), RECEIVED_DENOM),
+        Amount::from(70)
+    );
+    let rate_limit = module
+        .middleware()
+        .ctx()
+        .rate_limit(&recv_path(), QUOTA_NAME)
+        .unwrap();
+    assert_eq!(rate_limit.flow.inflow, Amount::from(70));
+}
+
+#[test]
+fn test_recv_without_rate_limit() {
+    let ctx = MockRateLimitContext::new(authority());
+    let mut module = RateLimitMiddleware::new(ctx).wrap(MockTransferModule::new());
+
+    assert!(is_successful(&recv(&mut module, 5000)));
+    assert_eq!(
+        module.next().balance(&signer("bob"), RECEIVED_DENOM),
+        Amount::from(5000)
+    );
+}
+
+#[test]
+fn test_written_error_ack_undoes_inflow() {
+    let ctx = rate_limit_ctx(
+        recv_path(),
+        quota(
+            QuotaLimit::Percent(100),
+            QuotaLimit::Absolute(Amount::from(100)),
+        ),
+    );
+    let mut module = RateLimitMiddleware::new(ctx).wrap(MockTransferModule::new());
+    let packet = received_packet(100);
+
+    assert!(is_successful(&recv(&mut module, 100)));
+
+    let ack: Acknowledgement =
+        AcknowledgementStatus::error(StatusValue::new("failed").unwrap()).into();
+    let (_, written_ack) = module
+        .write_acknowledgement_execute(&packet, ack.clone())
+        .unwrap();
+    assert_eq!(written_ack, ack);
+
+    let rate_limit = module
+        .middleware()
+        .ctx()
+        .rate_limit(&recv_path(), QUOTA_NAME)
+        .unwrap();
+    assert_eq!(rate_limit.flow.inflow, Amount::from(0));
+}
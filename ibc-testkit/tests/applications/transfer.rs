@@ -1,14 +1,38 @@
+use ibc::apps::transfer::context::{DenomTraceValidationContext, TokenTransferValidationContext};
+use ibc::apps::transfer::handler::{check_total_escrow_invariant, send_transfer};
 use ibc::apps::transfer::module::{
     on_chan_open_init_execute, on_chan_open_init_validate, on_chan_open_try_execute,
-    on_chan_open_try_validate,
+    on_chan_open_try_validate, on_recv_packet_execute, on_timeout_packet_execute,
 };
-use ibc::apps::transfer::types::VERSION;
-use ibc::core::channel::types::channel::{Counterparty, Order};
+use ibc::apps::transfer::types::error::TokenTransferError;
+use ibc::apps::transfer::types::msgs::transfer::MsgTransferV2;
+use ibc::apps::transfer::types::packet::{Forwarding, FungibleTokenPacketDataV2};
+use ibc::apps::transfer::types::{
+    Amount, BaseCoin, PrefixedCoin, PrefixedDenom, TracePrefix, U256, VERSION, VERSION_V2,
+};
+use ibc::core::channel::types::acknowledgement::AcknowledgementStatus;
+use ibc::core::channel::types::channel::{ChannelEnd, Counterparty, Order, State};
+use ibc::core::channel::types::packet::Packet;
+use ibc::core::channel::types::timeout::TimeoutHeight;
 use ibc::core::channel::types::Version;
-use ibc::core::host::types::identifiers::{ChannelId, ConnectionId, PortId};
+use ibc::core::client::types::Height;
+use ibc::core::connection::types::version::get_compatible_versions;
+use ibc::core::connection::types::{
+    ConnectionEnd, Counterparty as ConnectionCounterparty, State as ConnectionState,
+};
+use ibc::core::handler::types::events::IbcEvent;
+use ibc::core::host::types::identifiers::{ChannelId, ClientId, ConnectionId, PortId, Sequence};
+use ibc::core::host::types::path::CommitmentPath;
+use ibc::core::host::ValidationContext;
 use ibc::core::primitives::prelude::*;
+use ibc::core::primitives::{Timestamp, ZERO_DURATION};
 use ibc::cosmos_host::utils::cosmos_adr028_escrow_address;
-use ibc_testkit::testapp::ibc::applications::transfer::types::DummyTransferModule;
+use ibc_testkit::fixtures::core::channel::dummy_raw_packet;
+use ibc_testkit::fixtures::core::signer::dummy_account_id;
+use ibc_testkit::testapp::ibc::applications::transfer::types::{
+    DummyTransferModule, MockTransferModule,
+};
+use ibc_testkit::testapp::ibc::core::types::{MockClientConfig, MockContext};
 use subtle_encoding::bech32;
 
 fn get_defaults() -> (
@@ -166,3 +190,521 @@ fn test_on_chan_open_try_counterparty_incorrect_version() {
 
     assert!(res.is_err());
 }
+
+/// If the relayer passed in `ics20-2`, then return `ics20-2`
+#[test]
+fn test_on_chan_open_init_ics20_v2_version() {
+    let (mut ctx, order, connection_hops, port_id, channel_id, counterparty) = get_defaults();
+
+    let in_version = Version::new(VERSION_V2.to_string());
+
+    let res = on_chan_open_init_validate(
+        &ctx,
+        order,
+        &connection_hops,
+        &port_id,
+        &channel_id,
+        &counterparty,
+        &in_version,
+    );
+    assert!(res.is_ok());
+
+    let (_, out_version) = on_chan_open_init_execute(
+        &mut ctx,
+        order,
+        &connection_hops,
+        &port_id,
+        &channel_id,
+        &counterparty,
+        &in_version,
+    )
+    .unwrap();
+
+    assert_eq!(out_version, in_version);
+}
+
+/// If the counterparty supports `ics20-2`, then return `ics20-2`
+#[test]
+fn test_on_chan_open_try_counterparty_v2_version() {
+    let (mut ctx, order, connection_hops, port_id, channel_id, counterparty) = get_defaults();
+
+    let counterparty_version = Version::new(VERSION_V2.to_string());
+
+    let res = on_chan_open_try_validate(
+        &ctx,
+        order,
+        &connection_hops,
+        &port_id,
+        &channel_id,
+        &counterparty,
+        &counterparty_version,
+    );
+    assert!(res.is_ok());
+
+    let (_, out_version) = on_chan_open_try_execute(
+        &mut ctx,
+        order,
+        &connection_hops,
+        &port_id,
+        &channel_id,
+        &counterparty,
+        &counterparty_version,
+    )
+    .unwrap();
+
+    assert_eq!(out_version, counterparty_version);
+}
+
+fn dummy_tokens() -> Vec<PrefixedCoin> {
+    vec![
+        BaseCoin {
+            denom: "uatom".parse().unwrap(),
+            amount: U256::from(10).into(),
+        }
+        .into(),
+        PrefixedCoin {
+            denom: "transfer/channel-0/uosmo".parse().unwrap(),
+            amount: U256::from(20).into(),
+        },
+    ]
+}
+
+fn dummy_packet_data_v2(forwarding: Option<Forwarding>) -> FungibleTokenPacketDataV2 {
+    FungibleTokenPacketDataV2 {
+        tokens: dummy_tokens(),
+        sender: dummy_account_id(),
+        receiver: dummy_account_id(),
+        memo: "".to_string().into(),
+        forwarding,
+    }
+}
+
+/// Builds a context with an open transfer channel of the given version.
+fn transfer_ctx(chan_version: &str) -> MockContext {
+    let chan_end_on_a = ChannelEnd::new(
+        State::Open,
+        Order::Unordered,
+        Counterparty::new(PortId::transfer(), Some(ChannelId::default())),
+        vec![ConnectionId::default()],
+        Version::new(chan_version.to_string()),
+    )
+    .unwrap();
+
+    let conn_end_on_a = ConnectionEnd::new(
+        ConnectionState::Open,
+        ClientId::default(),
+        ConnectionCounterparty::new(
+            ClientId::default(),
+            Some(ConnectionId::default()),
+            Default::default(),
+        ),
+        get_compatible_versions(),
+        ZERO_DURATION,
+    )
+    .unwrap();
+
+    MockContext::default()
+        .with_client_config(
+            MockClientConfig::builder()
+                .latest_height(Height::new(0, 5).unwrap())
+                .build(),
+        )
+        .with_connection(ConnectionId::default(), conn_end_on_a)
+        .with_channel(PortId::transfer(), ChannelId::default(), chan_end_on_a)
+        .with_send_sequence(PortId::transfer(), ChannelId::default(), 1.into())
+}
+
+fn dummy_msg_transfer_v2() -> MsgTransferV2 {
+    MsgTransferV2 {
+        port_id_on_a: PortId::transfer(),
+        chan_id_on_a: ChannelId::default(),
+        packet_data: dummy_packet_data_v2(None),
+        timeout_height_on_b: TimeoutHeight::At(Height::new(0, 10).unwrap()),
+        timeout_timestamp_on_b: Timestamp::none(),
+    }
+}
+
+#[test]
+fn test_send_transfer_v2() {
+    let mut ctx = transfer_ctx(VERSION_V2);
+
+    let res = send_transfer(&mut ctx, &mut DummyTransferModule, dummy_msg_transfer_v2());
+    assert!(res.is_ok(), "unexpected result: {res:?}");
+
+    let commitment_path = CommitmentPath::new(
+        &PortId::transfer(),
+        &ChannelId::default(),
+        Sequence::from(1),
+    );
+    assert!(ctx.get_packet_commitment(&commitment_path).is_ok());
+
+    let transfer_events = ctx
+        .get_events()
+        .into_iter()
+        .filter(|event| matches!(event, IbcEvent::Module(e) if e.kind == "ibc_transfer"))
+        .count();
+    assert_eq!(transfer_events, 2);
+}
+
+#[test]
+fn test_send_transfer_v2_over_v1_channel() {
+    let mut ctx = transfer_ctx(VERSION);
+
+    let res = send_transfer(&mut ctx, &mut DummyTransferModule, dummy_msg_transfer_v2());
+    assert!(
+        matches!(res, Err(TokenTransferError::NotV1Compatible)),
+        "unexpected result: {res:?}"
+    );
+
+    // A single token is still sent as an `ics20-1` packet.
+    let mut msg = dummy_msg_transfer_v2();
+    msg.packet_data.tokens.truncate(1);
+    let res = send_transfer(&mut ctx, &mut DummyTransferModule, msg);
+    assert!(res.is_ok(), "unexpected result: {res:?}");
+}
+
+fn dummy_transfer_packet(data: Vec<u8>) -> Packet {
+    let mut packet: Packet = dummy_raw_packet(10, 0).try_into().unwrap();
+    packet.data = data;
+    packet
+}
+
+/// Returns a transfer module whose channel of the dummy packets negotiated
+/// `ics20-2`.
+fn mock_transfer_module_v2() -> MockTransferModule {
+    MockTransferModule::new().with_channel_version(
+        PortId::transfer(),
+        ChannelId::default(),
+        Version::new(VERSION_V2.to_string()),
+    )
+}
+
+fn recv_ack_status(packet: &Packet) -> (AcknowledgementStatus, Vec<String>) {
+    // the `uosmo` vouchers of the dummy tokens are sent back to their source,
+    // which unescrows them
+    let escrowed_coin = PrefixedCoin {
+        denom: "uosmo".parse().unwrap(),
+        amount: Amount::from(20),
+    };
+    let escrow_account =
+        MockTransferModule::escrow_account(&packet.port_id_on_b, &packet.chan_id_on_b);
+    let mut module =
+        mock_transfer_module_v2().with_balance(escrow_account, &[escrowed_coin.clone()]);
+    module
+        .total_escrows
+        .insert(escrowed_coin.denom.to_string(), escrowed_coin.amount);
+
+    let (extras, ack) = on_recv_packet_execute(&mut module, packet);
+    let ack_status = serde_json::from_slice::<AcknowledgementStatus>(ack.as_ref()).unwrap();
+    let event_kinds = extras.events.into_iter().map(|e| e.kind).collect();
+    (ack_status, event_kinds)
+}
+
+#[test]
+fn test_on_recv_packet_v2() {
+    let data = serde_json::to_vec(&dummy_packet_data_v2(None)).unwrap();
+
+    let (ack_status, event_kinds) = recv_ack_status(&dummy_transfer_packet(data));
+
+    assert!(ack_status.is_successful(), "{ack_status:?}");
+    assert_eq!(
+        event_kinds
+            .iter()
+            .filter(|kind| *kind == "fungible_token_packet")
+            .count(),
+        2
+    );
+}
+
+#[test]
+fn test_on_recv_packet_v2_over_v1_channel() {
+    let data = serde_json::to_vec(&dummy_packet_data_v2(None)).unwrap();
+
+    let (_, ack) =
+        on_recv_packet_execute(&mut MockTransferModule::new(), &dummy_transfer_packet(data));
+    let ack_status = serde_json::from_slice::<AcknowledgementStatus>(ack.as_ref()).unwrap();
+
+    assert!(!ack_status.is_successful(), "{ack_status:?}");
+}
+
+#[test]
+fn test_on_recv_packet_v2_with_duplicate_denoms() {
+    let mut data = dummy_packet_data_v2(None);
+    data.tokens = vec![data.tokens[0].clone(), data.tokens[0].clone()];
+    let packet = dummy_transfer_packet(serde_json::to_vec(&data).unwrap());
+
+    let mut module = mock_transfer_module_v2();
+    let (_, ack) = on_recv_packet_execute(&mut module, &packet);
+    let ack_status = serde_json::from_slice::<AcknowledgementStatus>(ack.as_ref()).unwrap();
+
+    assert!(!ack_status.is_successful(), "{ack_status:?}");
+    assert!(module.balances.is_empty());
+}
+
+#[test]
+fn test_on_recv_packet_v2_with_forwarding() {
+    let forwarding = Forwarding {
+        hops: vec![TracePrefix::new(PortId::transfer(), ChannelId::new(1))],
+        destination_memo: "".to_string().into(),
+    };
+    let data = serde_json::to_vec(&dummy_packet_data_v2(Some(forwarding))).unwrap();
+
+    let (ack_status, _) = recv_ack_status(&dummy_transfer_packet(data));
+
+    assert!(!ack_status.is_successful(), "{ack_status:?}");
+}
+
+#[test]
+fn test_on_recv_packet_stores_denom_trace() {
+    let mut data = dummy_packet_data_v2(None);
+    data.tokens.truncate(1);
+    let packet = dummy_transfer_packet(serde_json::to_vec(&data).unwrap());
+
+    let voucher_denom: PrefixedDenom =
+        format!("{}/{}/uatom", packet.port_id_on_b, packet.chan_id_on_b)
+            .parse()
+            .unwrap();
+    let hash = voucher_denom.trace_hash();
+
+    let mut module = mock_transfer_module_v2();
+
+    for _ in 0..2 {
+        let (_, ack) = on_recv_packet_execute(&mut module, &packet);
+        let ack_status = serde_json::from_slice::<AcknowledgementStatus>(ack.as_ref()).unwrap();
+        assert!(ack_status.is_successful(), "{ack_status:?}");
+    }
+
+    assert_eq!(module.denom_trace(&hash).unwrap(), Some(voucher_denom));
+    assert_eq!(module.denom_traces().unwrap().len(), 1);
+}
+
+#[test]
+fn test_total_escrow_for_denom() {
+    let mut ctx = transfer_ctx(VERSION_V2);
+    let mut module = mock_transfer_module_v2().with_balance(dummy_account_id(), &dummy_tokens());
+    let native_denom: PrefixedDenom = "uatom".parse().unwrap();
+    let voucher_denom: PrefixedDenom = "transfer/channel-0/uosmo".parse().unwrap();
+
+    send_transfer(&mut ctx, &mut module, dummy_msg_transfer_v2()).unwrap();
+
+    // Only the native tokens are escrowed, the vouchers are burnt.
+    assert_eq!(
+        module.total_escrow_for_denom(&native_denom).unwrap(),
+        Amount::from(10)
+    );
+    assert_eq!(
+        module.total_escrow_for_denom(&voucher_denom).unwrap(),
+        Amount::from(0)
+    );
+    assert!(check_total_escrow_invariant(&module).is_ok());
+
+    let total_escrow_events = ctx
+        .get_events()
+        .into_iter()
+        .filter(|event| matches!(event, IbcEvent::Module(e) if e.kind == "total_escrow"))
+        .count();
+    assert_eq!(total_escrow_events, 1);
+
+    // The escrowed tokens are refunded when the packet times out.
+    let packet = ctx
+        .get_events()
+        .into_iter()
+        .find_map(|event| match event {
+            IbcEvent::SendPacket(e) => Some(Packet {
+                seq_on_a: *e.seq_on_a(),
+                port_id_on_a: e.port_id_on_a().clone(),
+                chan_id_on_a: e.chan_id_on_a().clone(),
+                port_id_on_b: e.port_id_on_b().clone(),
+                chan_id_on_b: e.chan_id_on_b().clone(),
+                data: e.packet_data().to_vec(),
+                timeout_height_on_b: *e.timeout_height_on_b(),
+                timeout_timestamp_on_b: *e.timeout_timestamp_on_b(),
+            }),
+            _ => None,
+        })
+        .unwrap();
+
+    let (extras, res) = on_timeout_packet_execute(&mut module, &packet, &dummy_account_id());
+    assert!(res.is_ok(), "unexpected result: {res:?}");
+    assert!(extras.events.iter().any(|e| e.kind == "total_escrow"));
+
+    assert_eq!(
+        module.total_escrow_for_denom(&native_denom).unwrap(),
+        Amount::from(0)
+    );
+    assert!(check_total_escrow_invariant(&module).is_ok());
+}
+
+#[test]
+fn test_on_recv_packet_decreases_total_escrow() {
+    let escrow_account =
+        MockTransferModule::escrow_account(&PortId::transfer(), &ChannelId::new(0));
+    let native_coin = PrefixedCoin {
+        denom: "uatom".parse().unwrap(),
+        amount: Amount::from(10),
+    };
+
+    let mut module = mock_transfer_module_v2().with_balance(escrow_account, &[native_coin.clone()]);
+    module
+        .total_escrows
+        .insert(native_coin.denom.to_string(), native_coin.amount);
+
+    // The counterparty sends back the vouchers of the escrowed tokens.
+    let mut data = dummy_packet_data_v2(None);
+    data.tokens = vec![PrefixedCoin {
+        denom: "transfer/channel-0/uatom".parse().unwrap(),
+        amount: Amount::from(4),
+    }];
+    let packet = dummy_transfer_packet(serde_json::to_vec(&data).unwrap());
+
+    let (extras, ack) = on_recv_packet_execute(&mut module, &packet);
+    let ack_status = serde_json::from_slice::<AcknowledgementStatus>(ack.as_ref()).unwrap();
+    assert!(ack_status.is_successful(), "{ack_status:?}");
+    assert!(extras.events.iter().any(|e| e.kind == "total_escrow"));
+
+    assert_eq!(
+        module.total_escrow_for_denom(&native_coin.denom).unwrap(),
+        Amount::from(6)
+    );
+    assert!(check_total_escrow_invariant(&module).is_ok());
+}
+
+#[test]
+fn test_on_recv_packet_fails_on_untracked_escrow() {
+    let escrow_account =
+        MockTransferModule::escrow_account(&PortId::transfer(), &ChannelId::new(0));
+    let native_coin = PrefixedCoin {
+        denom: "uatom".parse().unwrap(),
+        amount: Amount::from(10),
+    };
+
+    // the escrowed tokens are not accounted for in the total escrow
+    let mut module = mock_transfer_module_v2().with_balance(escrow_account, &[native_coin]);
+
+    let mut data = dummy_packet_data_v2(None);
+    data.tokens = vec![PrefixedCoin {
+        denom: "transfer/channel-0/uatom".parse().unwrap(),
+        amount: Amount::from(4),
+    }];
+    let packet = dummy_transfer_packet(serde_json::to_vec(&data).unwrap());
+
+    let (_, ack) = on_recv_packet_execute(&mut module, &packet);
+    let ack_status = serde_json::from_slice::<AcknowledgementStatus>(ack.as_ref()).unwrap();
+    assert!(!ack_status.is_successful(), "{ack_status:?}");
+}
+
+#[test]
+fn test_total_escrow_invariant_broken() {
+    let mut module = MockTransferModule::new();
+    module
+        .total_escrows
+        .insert("uatom".to_string(), Amount::from(10));
+
+    let res = check_total_escrow_invariant(&module);
+    assert!(
+        matches!(
+            res,
+            Err(TokenTransferError::TotalEscrowInvariantBroken { .. })
+        ),
+        "unexpected result: {res:?}"
+    );
+}
+
+#[test]
+fn test_on_recv_packet_reverts_partially_received_tokens() {
+    let escrow_account =
+        MockTransferModule::escrow_account(&PortId::transfer(), &ChannelId::new(0));
+    let tracked_coin = PrefixedCoin {
+        denom: "uatom".parse().unwrap(),
+        amount: Amount::from(10),
+    };
+    let untracked_coin = PrefixedCoin {
+        denom: "uosmo".parse().unwrap(),
+        amount: Amount::from(10),
+    };
+
+    // only the `uatom` escrow is accounted for in the total escrow, hence
+    // unescrowing the `uosmo` tokens fails after the `uatom` tokens are
+    // unescrowed
+    let mut module = mock_transfer_module_v2().with_balance(
+        escrow_account.clone(),
+        &[tracked_coin.clone(), untracked_coin],
+    );
+    module
+        .total_escrows
+        .insert(tracked_coin.denom.to_string(), tracked_coin.amount);
+
+    let mut data = dummy_packet_data_v2(None);
+    data.tokens = vec![
+        PrefixedCoin {
+            denom: "transfer/channel-0/uatom".parse().unwrap(),
+            amount: Amount::from(4),
+        },
+        PrefixedCoin {
+            denom: "transfer/channel-0/uosmo".parse().unwrap(),
+            amount: Amount::from(4),
+        },
+    ];
+    let packet = dummy_transfer_packet(serde_json::to_vec(&data).unwrap());
+
+    let (_, ack) = on_recv_packet_execute(&mut module, &packet);
+    let ack_status = serde_json::from_slice::<AcknowledgementStatus>(ack.as_ref()).unwrap();
+    assert!(!ack_status.is_successful(), "{ack_status:?}");
+
+    // the unescrowed `uatom` tokens are escrowed back
+    assert_eq!(
+        module.balance(&dummy_account_id(), "uatom"),
+        Amount::from(0)
+    );
+    assert_eq!(module.balance(&escrow_account, "uatom"), Amount::from(10));
+    assert_eq!(
+        module.total_escrow_for_denom(&tracked_coin.denom).unwrap(),
+        Amount::from(10)
+    );
+}
+
+#[test]
+fn test_on_timeout_packet_reverts_partially_refunded_tokens() {
+    let escrow_account =
+        MockTransferModule::escrow_account(&PortId::transfer(), &ChannelId::default());
+    let tracked_coin = PrefixedCoin {
+        denom: "uatom".parse().unwrap(),
+        amount: Amount::from(10),
+    };
+    let untracked_coin = PrefixedCoin {
+        denom: "uosmo".parse().unwrap(),
+        amount: Amount::from(10),
+    };
+
+    // only the `uatom` escrow is accounted for in the total escrow, hence
+    // refunding the `uosmo` tokens fails after the `uatom` tokens are
+    // refunded
+    let mut module = mock_transfer_module_v2().with_balance(
+        escrow_account.clone(),
+        &[tracked_coin.clone(), untracked_coin.clone()],
+    );
+    module
+        .total_escrows
+        .insert(tracked_coin.denom.to_string(), tracked_coin.amount);
+
+    let mut data = dummy_packet_data_v2(None);
+    data.tokens = vec![tracked_coin.clone(), untracked_coin];
+    let packet = dummy_transfer_packet(serde_json::to_vec(&data).unwrap());
+
+    let (_, res) = on_timeout_packet_execute(&mut module, &packet, &dummy_account_id());
+    assert!(
+        matches!(res, Err(TokenTransferError::TotalEscrowUnderflow { .. })),
+        "unexpected result: {res:?}"
+    );
+
+    // the refunded `uatom` tokens are escrowed back
+    assert_eq!(
+        module.balance(&dummy_account_id(), "uatom"),
+        Amount::from(0)
+    );
+    assert_eq!(module.balance(&escrow_account, "uatom"), Amount::from(10));
+    assert_eq!(
+        module.total_escrow_for_denom(&tracked_coin.denom).unwrap(),
+        Amount::from(10)
+    );
+}
@@ -0,0 +1,268 @@
+use ibc::clients::localhost::client_state::{create_localhost_client, ClientState};
+use ibc::clients::localhost::consensus_state::ConsensusState;
+use ibc::clients::localhost::types::SENTINEL_PROOF;
+use ibc::core::channel::types::channel::{ChannelEnd, Order, State as ChannelState};
+use ibc::core::channel::types::msgs::{
+    ChannelMsg, MsgChannelOpenAck, MsgChannelOpenConfirm, MsgChannelOpenInit, MsgChannelOpenTry,
+    MsgRecvPacket, PacketMsg,
+};
+use ibc::core::channel::types::packet::Packet;
+use ibc::core::channel::types::timeout::TimeoutHeight;
+use ibc::core::channel::types::Version as ChannelVersion;
+use ibc::core::client::context::client_state::ClientStateCommon;
+use ibc::core::client::types::msgs::{ClientMsg, MsgCreateClient, MsgUpdateClient};
+use ibc::core::client::types::Height;
+use ibc::core::commitment_types::commitment::CommitmentProofBytes;
+use ibc::core::connection::localhost::create_localhost_connection;
+use ibc::core::connection::types::msgs::ConnectionMsg;
+use ibc::core::connection::types::State as ConnectionState;
+use ibc::core::entrypoint::{dispatch, validate};
+use ibc::core::handler::types::msgs::MsgEnvelope;
+use ibc::core::host::types::identifiers::{ChannelId, ClientId, ConnectionId, PortId, Sequence};
+use ibc::core::host::types::path::{ChannelEndPath, ClientConsensusStatePath, ReceiptPath};
+use ibc::core::host::ValidationContext;
+use ibc::core::primitives::prelude::*;
+use ibc::core::primitives::Timestamp;
+use ibc::primitives::proto::Any;
+use ibc_testkit::fixtures::core::connection::dummy_msg_conn_open_init;
+use ibc_testkit::fixtures::core::signer::dummy_account_id;
+use ibc_testkit::testapp::ibc::clients::AnyClientState;
+use ibc_testkit::testapp::ibc::core::router::MockRouter;
+use ibc_testkit::testapp::ibc::core::types::MockContext;
+use test_log::test;
+
+fn localhost_context() -> MockContext {
+    let mut ctx = MockContext::default();
+
+    create_localhost_client(&mut ctx).expect("localhost client creation succeeds");
+    create_localhost_connection(&mut ctx).expect("localhost connection creation succeeds");
+
+    ctx
+}
+
+fn sentinel_proof() -> CommitmentProofBytes {
+    CommitmentProofBytes::try_from(SENTINEL_PROOF.to_vec()).expect("no error")
+}
+
+fn localhost_height(ctx: &MockContext) -> Height {
+    ctx.client_state(&ClientId::localhost())
+        .expect("localhost client exists")
+        .latest_height()
+}
+
+/// Opens a channel from `transfer/channel-0` to `transfer/channel-1`, both
+/// ends living on the same chain.
+fn open_localhost_channel(ctx: &mut MockContext, router: &mut MockRouter) {
+    let version = ChannelVersion::new("ics20-1".to_string());
+    let chan_id_on_a = ChannelId::new(0);
+    let chan_id_on_b = ChannelId::new(1);
+
+    let msg_init = MsgChannelOpenInit {
+        port_id_on_a: PortId::transfer(),
+        connection_hops_on_a: vec![ConnectionId::localhost()],
+        port_id_on_b: PortId::transfer(),
+        ordering: Order::Unordered,
+        signer: dummy_account_id(),
+        version_proposal: version.clone(),
+    };
+    dispatch(ctx, router, ChannelMsg::from(msg_init).into()).expect("chan_open_init succeeds");
+
+    #[allow(deprecated)]
+    let msg_try = MsgChannelOpenTry {
+        port_id_on_b: PortId::transfer(),
+        connection_hops_on_b: vec![ConnectionId::localhost()],
+        port_id_on_a: PortId::transfer(),
+        chan_id_on_a: chan_id_on_a.clone(),
+        version_supported_on_a: version.clone(),
+        proof_chan_end_on_a: sentinel_proof(),
+        proof_height_on_a: localhost_height(ctx),
+        ordering: Order::Unordered,
+        signer: dummy_account_id(),
+        version_proposal: ChannelVersion::empty(),
+    };
+    dispatch(ctx, router, ChannelMsg::from(msg_try).into()).expect("chan_open_try succeeds");
+
+    let msg_ack = MsgChannelOpenAck {
+        port_id_on_a: PortId::transfer(),
+        chan_id_on_a: chan_id_on_a.clone(),
+        chan_id_on_b: chan_id_on_b.clone(),
+        version_on_b: version,
+        proof_chan_end_on_b: sentinel_proof(),
+        proof_height_on_b: localhost_height(ctx),
+        signer: dummy_account_id(),
+    };
+    dispatch(ctx, router, ChannelMsg::from(msg_ack).into()).expect("chan_open_ack succeeds");
+
+    let msg_confirm = MsgChannelOpenConfirm {
+        port_id_on_b: PortId::transfer(),
+        chan_id_on_b,
+        proof_chan_end_on_a: sentinel_proof(),
+        proof_height_on_a: localhost_height(ctx),
+        signer: dummy_account_id(),
+    };
+    dispatch(ctx, router, ChannelMsg::from(msg_confirm).into())
+        .expect("chan_open_confirm succeeds");
+}
+
+#[test]
+fn test_localhost_client_and_connection_creation() {
+    let ctx = localhost_context();
+    let host_height = ctx.latest_height();
+
+    let client_state = ctx.client_state(&ClientId::localhost()).unwrap();
+    assert!(matches!(client_state, AnyClientState::Localhost(_)));
+    assert_eq!(client_state.latest_height(), host_height);
+
+    let consensus_state_path = ClientConsensusStatePath::new(
+        ClientId::localhost(),
+        host_height.revision_number(),
+        host_height.revision_height(),
+    );
+    assert!(ctx.consensus_state(&consensus_state_path).is_ok());
+
+    let conn_end = ctx.connection_end(&ConnectionId::localhost()).unwrap();
+    assert_eq!(conn_end.state(), &ConnectionState::Open);
+    assert_eq!(conn_end.client_id(), &ClientId::localhost());
+    assert_eq!(
+        conn_end.counterparty().connection_id(),
+        Some(&ConnectionId::localhost())
+    );
+}
+
+#[test]
+fn test_localhost_client_cannot_be_created_by_message() {
+    let mut ctx = MockContext::default();
+    let mut router = MockRouter::new_with_transfer();
+
+    let host_height = ctx.latest_height();
+    let msg = MsgCreateClient::new(
+        ClientState::new(host_height).into(),
+        ConsensusState::new(Timestamp::now()).into(),
+        dummy_account_id(),
+    );
+
+    let res = dispatch(&mut ctx, &mut router, ClientMsg::from(msg).into());
+
+    assert!(
+        res.is_err(),
+        "localhost client must not be created by a message"
+    );
+}
+
+#[test]
+fn test_localhost_connection_handshake_rejected() {
+    let ctx = localhost_context();
+    let router = MockRouter::new_with_transfer();
+
+    let mut msg = dummy_msg_conn_open_init();
+    msg.client_id_on_a = ClientId::localhost();
+
+    let res = validate(&ctx, &router, ConnectionMsg::from(msg).into());
+
+    assert!(
+        res.is_err(),
+        "connection handshakes over localhost are rejected"
+    );
+}
+
+#[test]
+fn test_localhost_update_client() {
+    let mut ctx = localhost_context();
+    let mut router = MockRouter::new_with_transfer();
+    let old_height = localhost_height(&ctx);
+
+    ctx.advance_host_chain_height();
+    let new_height = ctx.latest_height();
+
+    let msg = MsgUpdateClient {
+        client_id: ClientId::localhost(),
+        client_message: Any::from(ClientState::new(new_height)),
+        signer: dummy_account_id(),
+    };
+    dispatch(&mut ctx, &mut router, ClientMsg::from(msg).into()).expect("update succeeds");
+
+    assert_eq!(localhost_height(&ctx), new_height);
+
+    let old_consensus_state_path = ClientConsensusStatePath::new(
+        ClientId::localhost(),
+        old_height.revision_number(),
+        old_height.revision_height(),
+    );
+    let new_consensus_state_path = ClientConsensusStatePath::new(
+        ClientId::localhost(),
+        new_height.revision_number(),
+        new_height.revision_height(),
+    );
+    assert!(ctx.consensus_state(&old_consensus_state_path).is_err());
+    assert!(ctx.consensus_state(&new_consensus_state_path).is_ok());
+}
+
+#[test]
+fn test_localhost_channel_handshake() {
+    let mut ctx = localhost_context();
+    let mut router = MockRouter::new_with_transfer();
+
+    open_localhost_channel(&mut ctx, &mut router);
+
+    let chan_end_on_a: ChannelEnd = ctx
+        .channel_end(&ChannelEndPath::new(
+            &PortId::transfer(),
+            &ChannelId::new(0),
+        ))
+        .unwrap();
+    let chan_end_on_b: ChannelEnd = ctx
+        .channel_end(&ChannelEndPath::new(
+            &PortId::transfer(),
+            &ChannelId::new(1),
+        ))
+        .unwrap();
+
+    assert_eq!(chan_end_on_a.state(), &ChannelState::Open);
+    assert_eq!(chan_end_on_b.state(), &ChannelState::Open);
+    assert_eq!(
+        chan_end_on_a.counterparty().channel_id(),
+        Some(&ChannelId::new(1))
+    );
+}
+
+#[test]
+fn test_localhost_recv_packet() {
+    let mut ctx = localhost_context();
+    let mut router = MockRouter::new_with_transfer();
+
+    open_localhost_channel(&mut ctx, &mut router);
+
+    let packet = Packet {
+        seq_on_a: Sequence::from(1),
+        port_id_on_a: PortId::transfer(),
+        chan_id_on_a: ChannelId::new(0),
+        port_id_on_b: PortId::transfer(),
+        chan_id_on_b: ChannelId::new(1),
+        data: b"localhost".to_vec(),
+        timeout_height_on_b: TimeoutHeight::no_timeout(),
+        timeout_timestamp_on_b: (Timestamp::now() + core::time::Duration::from_secs(3600)).unwrap(),
+    };
+
+    let msg = MsgRecvPacket {
+        packet: packet.clone(),
+        proof_commitment_on_a: sentinel_proof(),
+        proof_height_on_a: localhost_height(&ctx),
+        signer: dummy_account_id(),
+    };
+    let envelope: MsgEnvelope = PacketMsg::from(msg.clone()).into();
+
+    // Nothing has been committed on the sending end yet.
+    assert!(dispatch(&mut ctx, &mut router, envelope.clone()).is_err());
+
+    ibc::core::channel::handler::send_packet(&mut ctx, packet).expect("send_packet succeeds");
+
+    // A proof other than the sentinel is rejected.
+    let mut bad_msg = msg;
+    bad_msg.proof_commitment_on_a = CommitmentProofBytes::try_from(vec![0x02]).unwrap();
+    assert!(validate(&ctx, &router, PacketMsg::from(bad_msg).into()).is_err());
+
+    dispatch(&mut ctx, &mut router, envelope).expect("recv_packet succeeds");
+
+    let receipt_path = ReceiptPath::new(&PortId::transfer(), &ChannelId::new(1), 1.into());
+    assert!(ctx.get_packet_receipt(&receipt_path).is_ok());
+}
@@ -0,0 +1,3 @@
+pub mod localhost;
+pub mod solomachine;
+pub mod wasm;
@@ -0,0 +1,323 @@
+use ed25519_consensus::SigningKey;
+use ibc::clients::solomachine::client_state::ClientState;
+use ibc::clients::solomachine::consensus_state::ConsensusState;
+use ibc::clients::solomachine::types::{
+    merkle_path_bytes, Header, HeaderData, Misbehaviour, PublicKey, SignBytes, SignatureAndData,
+    SignatureData, TimestampedSignatureData, SENTINEL_HEADER_PATH,
+};
+use ibc::core::channel::types::channel::{ChannelEnd, Counterparty, Order, State as ChannelState};
+use ibc::core::channel::types::msgs::{ChannelMsg, MsgChannelOpenTry};
+use ibc::core::channel::types::Version as ChannelVersion;
+use ibc::core::client::context::client_state::ClientStateCommon;
+use ibc::core::client::types::msgs::{
+    ClientMsg, MsgCreateClient, MsgSubmitMisbehaviour, MsgUpdateClient,
+};
+use ibc::core::client::types::Height;
+use ibc::core::commitment_types::commitment::{CommitmentPrefix, CommitmentProofBytes};
+use ibc::core::connection::types::version::get_compatible_versions;
+use ibc::core::connection::types::{
+    ConnectionEnd, Counterparty as ConnectionCounterparty, State as ConnectionState,
+};
+use ibc::core::entrypoint::{dispatch, validate};
+use ibc::core::host::types::identifiers::{ChannelId, ClientId, ConnectionId, PortId};
+use ibc::core::host::types::path::{ChannelEndPath, ClientConsensusStatePath, Path};
+use ibc::core::host::ValidationContext;
+use ibc::core::primitives::prelude::*;
+use ibc::core::primitives::{Timestamp, ZERO_DURATION};
+use ibc::primitives::proto::Protobuf;
+use ibc_testkit::fixtures::core::connection::dummy_raw_counterparty_conn;
+use ibc_testkit::fixtures::core::signer::dummy_account_id;
+use ibc_testkit::testapp::ibc::clients::AnyClientState;
+use ibc_testkit::testapp::ibc::core::router::MockRouter;
+use ibc_testkit::testapp::ibc::core::types::MockContext;
+use test_log::test;
+
+const DIVERSIFIER: &str = "solo machine";
+const CONSENSUS_TIMESTAMP: u64 = 1_000;
+
+fn signing_key(seed: u8) -> SigningKey {
+    SigningKey::from([seed; 32])
+}
+
+fn public_key(signing_key: &SigningKey) -> PublicKey {
+    PublicKey::Ed25519(signing_key.verification_key().to_bytes().to_vec())
+}
+
+fn sign(signing_key: &SigningKey, sign_bytes: SignBytes) -> SignatureData {
+    SignatureData::Single {
+        mode: 1,
+        signature: signing_key
+            .sign(&sign_bytes.encode_vec())
+            .to_bytes()
+            .to_vec(),
+    }
+}
+
+fn solomachine_client_id() -> ClientId {
+    ClientId::new("06-solomachine", 0).expect("no error")
+}
+
+fn solomachine_client_state(ctx: &MockContext) -> ClientState {
+    match ctx
+        .client_state(&solomachine_client_id())
+        .expect("client exists")
+    {
+        AnyClientState::Solomachine(client_state) => client_state,
+        _ => panic!("unexpected client state type"),
+    }
+}
+
+/// Creates a solo machine client at sequence 1, signing with `signing_key`.
+fn solomachine_context(signing_key: &SigningKey) -> (MockContext, MockRouter) {
+    let mut ctx = MockContext::default();
+    let mut router = MockRouter::new_with_transfer();
+
+    let consensus_state = ConsensusState::new(
+        public_key(signing_key),
+        DIVERSIFIER.to_string(),
+        Timestamp::from_nanoseconds(CONSENSUS_TIMESTAMP).expect("no error"),
+    )
+    .expect("no error");
+    let client_state =
+        ClientState::new(1, false, consensus_state.inner().clone()).expect("no error");
+
+    let msg = MsgCreateClient::new(
+        client_state.into(),
+        consensus_state.into(),
+        dummy_account_id(),
+    );
+    dispatch(&mut ctx, &mut router, ClientMsg::from(msg).into()).expect("create client succeeds");
+
+    (ctx, router)
+}
+
+fn header(signing_key: &SigningKey, sequence: u64, new_signing_key: &SigningKey) -> Header {
+    let timestamp = CONSENSUS_TIMESTAMP + 1;
+    let header_data = HeaderData {
+        new_public_key: public_key(new_signing_key),
+        new_diversifier: DIVERSIFIER.to_string(),
+    };
+    let signature = sign(
+        signing_key,
+        SignBytes {
+            sequence,
+            timestamp,
+            diversifier: DIVERSIFIER.to_string(),
+            path: SENTINEL_HEADER_PATH.as_bytes().to_vec(),
+            data: header_data.clone().encode_vec(),
+        },
+    );
+
+    Header {
+        timestamp,
+        signature,
+        new_public_key: header_data.new_public_key,
+        new_diversifier: header_data.new_diversifier,
+    }
+}
+
+#[test]
+fn test_solomachine_create_client() {
+    let (ctx, _) = solomachine_context(&signing_key(1));
+
+    let client_state = solomachine_client_state(&ctx);
+    assert_eq!(client_state.latest_height(), Height::new(0, 1).unwrap());
+
+    let consensus_state_path = ClientConsensusStatePath::new(solomachine_client_id(), 0, 1);
+    assert!(ctx.consensus_state(&consensus_state_path).is_ok());
+}
+
+#[test]
+fn test_solomachine_create_client_consensus_state_mismatch() {
+    let mut ctx = MockContext::default();
+    let mut router = MockRouter::new_with_transfer();
+
+    let timestamp = Timestamp::from_nanoseconds(CONSENSUS_TIMESTAMP).unwrap();
+    let consensus_state = ConsensusState::new(
+        public_key(&signing_key(1)),
+        DIVERSIFIER.to_string(),
+        timestamp,
+    )
+    .unwrap();
+    let other_consensus_state = ConsensusState::new(
+        public_key(&signing_key(2)),
+        DIVERSIFIER.to_string(),
+        timestamp,
+    )
+    .unwrap();
+
+    let msg = MsgCreateClient::new(
+        ClientState::new(1, false, consensus_state.inner().clone())
+            .unwrap()
+            .into(),
+        other_consensus_state.into(),
+        dummy_account_id(),
+    );
+
+    assert!(dispatch(&mut ctx, &mut router, ClientMsg::from(msg).into()).is_err());
+}
+
+#[test]
+fn test_solomachine_update_client() {
+    let key = signing_key(1);
+    let new_key = signing_key(2);
+    let (mut ctx, mut router) = solomachine_context(&key);
+
+    // A header signed by another key is rejected
+    let msg = MsgUpdateClient {
+        client_id: solomachine_client_id(),
+        client_message: header(&new_key, 1, &new_key).into(),
+        signer: dummy_account_id(),
+    };
+    assert!(validate(&ctx, &router, ClientMsg::from(msg).into()).is_err());
+
+    let msg = MsgUpdateClient {
+        client_id: solomachine_client_id(),
+        client_message: header(&key, 1, &new_key).into(),
+        signer: dummy_account_id(),
+    };
+    dispatch(&mut ctx, &mut router, ClientMsg::from(msg).into()).expect("update succeeds");
+
+    let client_state = solomachine_client_state(&ctx);
+    assert_eq!(client_state.latest_height(), Height::new(0, 2).unwrap());
+    assert_eq!(
+        client_state.inner().consensus_state().public_key,
+        public_key(&new_key)
+    );
+
+    let consensus_state_path = ClientConsensusStatePath::new(solomachine_client_id(), 0, 2);
+    assert!(ctx.consensus_state(&consensus_state_path).is_ok());
+
+    // The previous header cannot be replayed at the new sequence
+    let msg = MsgUpdateClient {
+        client_id: solomachine_client_id(),
+        client_message: header(&key, 1, &new_key).into(),
+        signer: dummy_account_id(),
+    };
+    assert!(validate(&ctx, &router, ClientMsg::from(msg).into()).is_err());
+}
+
+#[test]
+fn test_solomachine_misbehaviour_freezes_client() {
+    let key = signing_key(1);
+    let (mut ctx, mut router) = solomachine_context(&key);
+
+    let signature_and_data = |data: &[u8]| {
+        let timestamp = CONSENSUS_TIMESTAMP + 1;
+        let path = b"/ibc/channelEnds%2Fports%2Ftransfer%2Fchannels%2Fchannel-0".to_vec();
+        let signature = sign(
+            &key,
+            SignBytes {
+                sequence: 1,
+                timestamp,
+                diversifier: DIVERSIFIER.to_string(),
+                path: path.clone(),
+                data: data.to_vec(),
+            },
+        );
+        SignatureAndData {
+            signature,
+            path,
+            data: data.to_vec(),
+            timestamp,
+        }
+    };
+
+    let misbehaviour = Misbehaviour {
+        sequence: 1,
+        signature_one: signature_and_data(b"one"),
+        signature_two: signature_and_data(b"two"),
+    };
+
+    #[allow(deprecated)]
+    let msg = MsgSubmitMisbehaviour {
+        client_id: solomachine_client_id(),
+        misbehaviour: misbehaviour.into(),
+        signer: dummy_account_id(),
+    };
+    dispatch(&mut ctx, &mut router, ClientMsg::from(msg).into()).expect("misbehaviour succeeds");
+
+    assert!(solomachine_client_state(&ctx).inner().is_frozen());
+}
+
+#[test]
+fn test_solomachine_chan_open_try() {
+    let key = signing_key(1);
+    let (ctx, mut router) = solomachine_context(&key);
+
+    let conn_id_on_b = ConnectionId::new(0);
+    let conn_counterparty =
+        ConnectionCounterparty::try_from(dummy_raw_counterparty_conn(Some(0))).unwrap();
+    let conn_end_on_b = ConnectionEnd::new(
+        ConnectionState::Open,
+        solomachine_client_id(),
+        conn_counterparty,
+        get_compatible_versions(),
+        ZERO_DURATION,
+    )
+    .unwrap();
+    let mut ctx = ctx.with_connection(conn_id_on_b.clone(), conn_end_on_b);
+
+    let version = ChannelVersion::new("ics20-1".to_string());
+    let chan_id_on_a = ChannelId::new(0);
+
+    // The channel end the solo machine attests to.
+    let chan_end_on_a = ChannelEnd::new(
+        ChannelState::Init,
+        Order::Unordered,
+        Counterparty::new(PortId::transfer(), None),
+        vec![ConnectionId::new(0)],
+        version.clone(),
+    )
+    .unwrap();
+    let path = Path::ChannelEnd(ChannelEndPath::new(&PortId::transfer(), &chan_id_on_a));
+    let prefix = CommitmentPrefix::try_from(b"ibc".to_vec()).unwrap();
+
+    let timestamp = CONSENSUS_TIMESTAMP + 1;
+    let sign_bytes = SignBytes {
+        sequence: 1,
+        timestamp,
+        diversifier: DIVERSIFIER.to_string(),
+        path: merkle_path_bytes(&prefix, &path.to_string()),
+        data: chan_end_on_a.encode_vec(),
+    };
+    let proof = |signature_data: SignatureData| {
+        CommitmentProofBytes::try_from(
+            TimestampedSignatureData {
+                signature_data,
+                timestamp,
+            }
+            .encode_vec(),
+        )
+        .unwrap()
+    };
+
+    #[allow(deprecated)]
+    let msg = MsgChannelOpenTry {
+        port_id_on_b: PortId::transfer(),
+        connection_hops_on_b: vec![conn_id_on_b],
+        port_id_on_a: PortId::transfer(),
+        chan_id_on_a,
+        version_supported_on_a: version,
+        proof_chan_end_on_a: proof(sign(&key, sign_bytes.clone())),
+        proof_height_on_a: Height::new(0, 1).unwrap(),
+        ordering: Order::Unordered,
+        signer: dummy_account_id(),
+        version_proposal: ChannelVersion::empty(),
+    };
+
+    // A signature by another key is rejected
+    let mut bad_msg = msg.clone();
+    bad_msg.proof_chan_end_on_a = proof(sign(&signing_key(2), sign_bytes));
+    assert!(validate(&ctx, &router, ChannelMsg::from(bad_msg).into()).is_err());
+
+    dispatch(&mut ctx, &mut router, ChannelMsg::from(msg).into()).expect("chan_open_try succeeds");
+
+    let chan_end_on_b = ctx
+        .channel_end(&ChannelEndPath::new(
+            &PortId::transfer(),
+            &ChannelId::new(0),
+        ))
+        .unwrap();
+    assert_eq!(chan_end_on_b.state(), &ChannelState::TryOpen);
+}
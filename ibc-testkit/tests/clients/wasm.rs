@@ -0,0 +1,317 @@
+use ibc::clients::wasm::client_state::ClientState;
+use ibc::clients::wasm::consensus_state::ConsensusState;
+use ibc::clients::wasm::context::ValidationContext as WasmValidationContext;
+use ibc::clients::wasm::handler::{
+    migrate_contract, remove_checksum, store_code, MAX_WASM_BYTE_CODE_SIZE,
+};
+use ibc::clients::wasm::types::client_message::ClientMessage;
+use ibc::clients::wasm::types::msgs::migrate_contract::MsgMigrateContract;
+use ibc::clients::wasm::types::msgs::remove_checksum::MsgRemoveChecksum;
+use ibc::clients::wasm::types::msgs::store_code::MsgStoreCode;
+use ibc::clients::wasm::types::Bytes;
+use ibc::core::client::context::client_state::{ClientStateCommon, ClientStateValidation};
+use ibc::core::client::context::ClientValidationContext;
+use ibc::core::client::types::msgs::{
+    ClientMsg, MsgCreateClient, MsgSubmitMisbehaviour, MsgUpdateClient,
+};
+use ibc::core::client::types::{Height, Status};
+use ibc::core::commitment_types::commitment::{
+    CommitmentPrefix, CommitmentProofBytes, CommitmentRoot,
+};
+use ibc::core::entrypoint::dispatch;
+use ibc::core::handler::types::error::ContextError;
+use ibc::core::host::types::identifiers::{ClientId, ConnectionId};
+use ibc::core::host::types::path::{ConnectionPath, Path};
+use ibc::core::host::ValidationContext;
+use ibc::core::primitives::prelude::*;
+use ibc::core::primitives::Signer;
+use ibc::primitives::proto::Any;
+use ibc_testkit::fixtures::core::signer::dummy_account_id;
+use ibc_testkit::testapp::ibc::clients::mock::client_state::MockClientState;
+use ibc_testkit::testapp::ibc::clients::mock::consensus_state::MockConsensusState;
+use ibc_testkit::testapp::ibc::clients::mock::header::MockHeader;
+use ibc_testkit::testapp::ibc::clients::mock::misbehaviour::Misbehaviour;
+use ibc_testkit::testapp::ibc::clients::wasm::encode_contract_data;
+use ibc_testkit::testapp::ibc::clients::AnyClientState;
+use ibc_testkit::testapp::ibc::core::router::MockRouter;
+use ibc_testkit::testapp::ibc::core::types::MockContext;
+use test_log::test;
+
+const CONTRACT_CODE: &[u8] = b"mock light client contract";
+const MIGRATED_CONTRACT_CODE: &[u8] = b"migrated mock light client contract";
+
+fn wasm_client_id() -> ClientId {
+    ClientId::new("08-wasm", 0).expect("no error")
+}
+
+fn unauthorized_signer() -> Signer {
+    "cosmos1unauthorized".to_string().into()
+}
+
+fn store_contract(ctx: &mut MockContext, code: &[u8]) -> Bytes {
+    let msg = MsgStoreCode {
+        signer: dummy_account_id(),
+        wasm_byte_code: code.to_vec(),
+    };
+    store_code::validate(ctx, msg.clone()).expect("store code validation succeeds");
+    store_code::execute(ctx, msg).expect("store code execution succeeds")
+}
+
+fn wasm_header(height: Height) -> Any {
+    ClientMessage {
+        data: encode_contract_data(MockHeader::new(height).with_current_timestamp()),
+    }
+    .into()
+}
+
+fn wasm_client_state(ctx: &MockContext) -> ClientState {
+    match ctx.client_state(&wasm_client_id()).expect("client exists") {
+        AnyClientState::Wasm(client_state) => client_state,
+        _ => panic!("unexpected client type"),
+    }
+}
+
+/// Creates a Wasm client, backed by the mock contract, at height 1-5.
+fn wasm_client_context() -> (MockContext, MockRouter, Bytes) {
+    let mut ctx = MockContext::default();
+    let mut router = MockRouter::new_with_transfer();
+    let checksum = store_contract(&mut ctx, CONTRACT_CODE);
+
+    create_wasm_client(&mut ctx, &mut router, checksum.clone()).expect("client creation succeeds");
+
+    (ctx, router, checksum)
+}
+
+fn create_wasm_client(
+    ctx: &mut MockContext,
+    router: &mut MockRouter,
+    checksum: Bytes,
+) -> Result<(), ContextError> {
+    let header = MockHeader::new(Height::new(1, 5).expect("no error")).with_current_timestamp();
+
+    let client_state = ClientState::new(
+        encode_contract_data(MockClientState::new(header)),
+        checksum,
+        header.height(),
+    );
+    let consensus_state =
+        ConsensusState::new(encode_contract_data(MockConsensusState::new(header)));
+
+    let msg = MsgCreateClient::new(
+        client_state.into(),
+        consensus_state.into(),
+        dummy_account_id(),
+    );
+
+    dispatch(ctx, router, ClientMsg::from(msg).into())
+}
+
+#[test]
+fn test_store_code() {
+    let mut ctx = MockContext::default();
+
+    let checksum = store_contract(&mut ctx, CONTRACT_CODE);
+
+    assert_eq!(checksum.len(), 32);
+    assert!(ctx.has_checksum(&checksum).unwrap());
+
+    // The same code cannot be stored twice.
+    let msg = MsgStoreCode {
+        signer: dummy_account_id(),
+        wasm_byte_code: CONTRACT_CODE.to_vec(),
+    };
+    assert!(store_code::execute(&mut ctx, msg).is_err());
+}
+
+#[test]
+fn test_store_code_rejects_invalid_messages() {
+    let ctx = MockContext::default();
+
+    let unauthorized = MsgStoreCode {
+        signer: unauthorized_signer(),
+        wasm_byte_code: CONTRACT_CODE.to_vec(),
+    };
+    assert!(store_code::validate(&ctx, unauthorized).is_err());
+
+    let empty = MsgStoreCode {
+        signer: dummy_account_id(),
+        wasm_byte_code: vec![],
+    };
+    assert!(store_code::validate(&ctx, empty).is_err());
+
+    let too_large = MsgStoreCode {
+        signer: dummy_account_id(),
+        wasm_byte_code: vec![0; MAX_WASM_BYTE_CODE_SIZE + 1],
+    };
+    assert!(store_code::validate(&ctx, too_large).is_err());
+}
+
+#[test]
+fn test_create_wasm_client() {
+    let (ctx, _, checksum) = wasm_client_context();
+
+    let client_state = wasm_client_state(&ctx);
+    assert_eq!(client_state.checksum(), checksum.as_slice());
+    assert_eq!(client_state.latest_height(), Height::new(1, 5).unwrap());
+
+    let status = client_state
+        .status(&ctx, &wasm_client_id())
+        .expect("status query succeeds");
+    assert_eq!(status, Status::Active);
+}
+
+#[test]
+fn test_create_wasm_client_with_unknown_checksum() {
+    let mut ctx = MockContext::default();
+    let mut router = MockRouter::new_with_transfer();
+
+    let res = create_wasm_client(&mut ctx, &mut router, vec![7; 32]);
+
+    assert!(res.is_err(), "the contract must have been stored");
+}
+
+#[test]
+fn test_update_wasm_client() {
+    let (mut ctx, mut router, _) = wasm_client_context();
+    let new_height = Height::new(1, 10).unwrap();
+
+    let msg = MsgUpdateClient {
+        client_id: wasm_client_id(),
+        client_message: wasm_header(new_height),
+        signer: dummy_account_id(),
+    };
+    dispatch(&mut ctx, &mut router, ClientMsg::from(msg).into()).expect("update succeeds");
+
+    assert_eq!(wasm_client_state(&ctx).latest_height(), new_height);
+    assert!(ctx
+        .client_update_time(&wasm_client_id(), &new_height)
+        .is_ok());
+
+    // The contract rejects headers which are not higher than the latest height.
+    let msg = MsgUpdateClient {
+        client_id: wasm_client_id(),
+        client_message: wasm_header(Height::new(1, 7).unwrap()),
+        signer: dummy_account_id(),
+    };
+    assert!(dispatch(&mut ctx, &mut router, ClientMsg::from(msg).into()).is_err());
+}
+
+#[test]
+fn test_wasm_client_misbehaviour() {
+    let (mut ctx, mut router, _) = wasm_client_context();
+    let header = MockHeader::new(Height::new(1, 6).unwrap()).with_current_timestamp();
+
+    let misbehaviour = ClientMessage {
+        data: encode_contract_data(Misbehaviour {
+            client_id: wasm_client_id(),
+            header1: header,
+            header2: header,
+        }),
+    };
+    let msg = MsgSubmitMisbehaviour {
+        client_id: wasm_client_id(),
+        misbehaviour: misbehaviour.into(),
+        signer: dummy_account_id(),
+    };
+    dispatch(&mut ctx, &mut router, ClientMsg::from(msg).into()).expect("misbehaviour succeeds");
+
+    let status = wasm_client_state(&ctx)
+        .status(&ctx, &wasm_client_id())
+        .unwrap();
+    assert_eq!(status, Status::Frozen);
+}
+
+#[test]
+fn test_wasm_client_verify_membership() {
+    let (ctx, _, _) = wasm_client_context();
+    let client_state = AnyClientState::from(wasm_client_state(&ctx));
+
+    let prefix = CommitmentPrefix::try_from(b"ibc".to_vec()).unwrap();
+    let proof = CommitmentProofBytes::try_from(vec![1]).unwrap();
+    let root = CommitmentRoot::from_bytes(&[]);
+    let path = Path::Connection(ConnectionPath::new(&ConnectionId::new(0)));
+
+    client_state
+        .verify_membership_with_context(
+            &ctx,
+            &wasm_client_id(),
+            &prefix,
+            &proof,
+            &root,
+            Height::new(1, 5).unwrap(),
+            path.clone(),
+            vec![1],
+        )
+        .expect("the contract accepts proofs at a known height");
+
+    // The client has no consensus state at this height.
+    assert!(client_state
+        .verify_non_membership_with_context(
+            &ctx,
+            &wasm_client_id(),
+            &prefix,
+            &proof,
+            &root,
+            Height::new(1, 4).unwrap(),
+            path,
+        )
+        .is_err());
+}
+
+#[test]
+fn test_migrate_contract() {
+    let (mut ctx, _, checksum) = wasm_client_context();
+    let new_checksum = store_contract(&mut ctx, MIGRATED_CONTRACT_CODE);
+
+    let msg = MsgMigrateContract {
+        signer: dummy_account_id(),
+        client_id: wasm_client_id(),
+        checksum: new_checksum.clone(),
+        msg: vec![],
+    };
+
+    let mut unauthorized = msg.clone();
+    unauthorized.signer = unauthorized_signer();
+    assert!(migrate_contract::validate(&ctx, unauthorized).is_err());
+
+    let mut same_checksum = msg.clone();
+    same_checksum.checksum = checksum;
+    assert!(migrate_contract::validate(&ctx, same_checksum).is_err());
+
+    let mut unknown_checksum = msg.clone();
+    unknown_checksum.checksum = vec![7; 32];
+    assert!(migrate_contract::validate(&ctx, unknown_checksum).is_err());
+
+    migrate_contract::validate(&ctx, msg.clone()).expect("migration validation succeeds");
+    migrate_contract::execute(&mut ctx, msg).expect("migration execution succeeds");
+
+    let client_state = wasm_client_state(&ctx);
+    assert_eq!(client_state.checksum(), new_checksum.as_slice());
+    assert_eq!(client_state.latest_height(), Height::new(1, 5).unwrap());
+}
+
+#[test]
+fn test_remove_checksum() {
+    let (mut ctx, _, checksum) = wasm_client_context();
+
+    let msg = MsgRemoveChecksum {
+        signer: dummy_account_id(),
+        checksum: checksum.clone(),
+    };
+
+    let mut unauthorized = msg.clone();
+    unauthorized.signer = unauthorized_signer();
+    assert!(remove_checksum::validate(&ctx, unauthorized).is_err());
+
+    remove_checksum::validate(&ctx, msg.clone()).expect("removal validation succeeds");
+    remove_checksum::execute(&mut ctx, msg.clone()).expect("removal execution succeeds");
+
+    assert!(!ctx.has_checksum(&checksum).unwrap());
+    assert!(remove_checksum::validate(&ctx, msg).is_err());
+
+    // Clients of a removed contract cannot be used anymore.
+    let status = wasm_client_state(&ctx)
+        .status(&ctx, &wasm_client_id())
+        .unwrap();
+    assert_eq!(status, Status::Unauthorized);
+}
@@ -0,0 +1,302 @@
+use ibc::apps::transfer::types::packet::PacketData;
+use ibc::apps::transfer::types::{Amount, Memo, PrefixedCoin, MODULE_ID_STR, VERSION};
+use ibc::core::channel::types::channel::{ChannelEnd, Counterparty, Order, State};
+use ibc::core::channel::types::commitment::PacketCommitment;
+use ibc::core::channel::types::msgs::{MsgRecvPacket, PacketMsg};
+use ibc::core::channel::types::Version;
+use ibc::core::client::context::ClientExecutionContext;
+use ibc::core::client::types::msgs::{ClientMsg, MsgCreateClient};
+use ibc::core::client::types::Height;
+use ibc::core::connection::types::msgs::ConnectionMsg;
+use ibc::core::connection::types::version::get_compatible_versions;
+use ibc::core::connection::types::{
+    ConnectionEnd, Counterparty as ConnectionCounterparty, State as ConnectionState,
+};
+use ibc::core::entrypoint::dispatch_batch;
+use ibc::core::handler::cache::CachedContext;
+use ibc::core::handler::types::events::IbcEvent;
+use ibc::core::handler::types::msgs::MsgEnvelope;
+use ibc::core::host::types::identifiers::{ChannelId, ClientId, ConnectionId, PortId};
+use ibc::core::host::types::path::{CommitmentPath, ReceiptPath};
+use ibc::core::host::{ExecutionContext, ValidationContext};
+use ibc::core::primitives::prelude::*;
+use ibc::core::primitives::{Signer, Timestamp, ZERO_DURATION};
+use ibc::core::router::types::module::ModuleId;
+use ibc_testkit::fixtures::core::channel::dummy_raw_msg_recv_packet;
+use ibc_testkit::fixtures::core::connection::{
+    dummy_msg_conn_open_init, dummy_msg_conn_open_init_with_client_id,
+};
+use ibc_testkit::fixtures::core::signer::dummy_account_id;
+use ibc_testkit::testapp::ibc::applications::transfer::types::MockTransferModule;
+use ibc_testkit::testapp::ibc::clients::mock::client_state::{
+    client_type as mock_client_type, MockClientState,
+};
+use ibc_testkit::testapp::ibc::clients::mock::consensus_state::MockConsensusState;
+use ibc_testkit::testapp::ibc::clients::mock::header::MockHeader;
+use ibc_testkit::testapp::ibc::core::router::MockRouter;
+use ibc_testkit::testapp::ibc::core::types::{MockClientConfig, MockContext};
+use test_log::test;
+
+fn msg_create_client() -> MsgEnvelope {
+    let header = MockHeader::new(Height::new(0, 42).unwrap()).with_current_timestamp();
+
+    let msg = MsgCreateClient::new(
+        MockClientState::new(header).into(),
+        MockConsensusState::new(header).into(),
+        dummy_account_id(),
+    );
+
+    ClientMsg::from(msg).into()
+}
+
+fn msg_conn_open_init(client_id: ClientId) -> MsgEnvelope {
+    let msg = dummy_msg_conn_open_init_with_client_id(dummy_msg_conn_open_init(), client_id);
+
+    ConnectionMsg::from(msg).into()
+}
+
+/// The voucher denomination of the tokens received in [`msg_recv_transfer`].
+const VOUCHER_DENOM: &str = "transfer/channel-0/uatom";
+
+fn receiver() -> Signer {
+    "bob".to_string().into()
+}
+
+/// Returns a context able to receive packets over `channel-0`, and a router
+/// running the ICS-20 callbacks over an in-memory bank.
+fn transfer_context() -> (MockContext, MockRouter) {
+    let client_height = Height::new(0, 10).unwrap();
+
+    let chan_end = ChannelEnd::new(
+        State::Open,
+        Order::Unordered,
+        Counterparty::new(PortId::transfer(), Some(ChannelId::default())),
+        vec![ConnectionId::default()],
+        Version::new(VERSION.to_string()),
+    )
+    .unwrap();
+
+    let conn_end = ConnectionEnd::new(
+        ConnectionState::Open,
+        ClientId::default(),
+        ConnectionCounterparty::new(
+            ClientId::default(),
+            Some(ConnectionId::default()),
+            Default::default(),
+        ),
+        get_compatible_versions(),
+        ZERO_DURATION,
+    )
+    .unwrap();
+
+    let mut ctx = MockContext::default()
+        .with_client_config(
+            MockClientConfig::builder()
+                .latest_height(client_height)
+                .build(),
+        )
+        .with_connection(ConnectionId::default(), conn_end)
+        .with_channel(PortId::transfer(), ChannelId::default(), chan_end);
+
+    ctx.get_client_execution_context()
+        .store_update_time(
+            ClientId::default(),
+            client_height,
+            Timestamp::from_nanoseconds(1000).unwrap(),
+        )
+        .unwrap();
+    ctx.get_client_execution_context()
+        .store_update_height(
+            ClientId::default(),
+            client_height,
+            Height::new(0, 5).unwrap(),
+        )
+        .unwrap();
+
+    let module_id = ModuleId::new(MODULE_ID_STR.to_string());
+    let mut router = MockRouter::default();
+    router.scope_port_to_module(PortId::transfer(), module_id.clone());
+    router
+        .add_route(module_id, MockTransferModule::new())
+        .unwrap();
+
+    (ctx, router)
+}
+
+/// Receives 100 `uatom` for `bob`, minted as vouchers on this chain.
+fn msg_recv_transfer() -> MsgRecvPacket {
+    let mut msg = MsgRecvPacket::try_from(dummy_raw_msg_recv_packet(10)).unwrap();
+
+    let data = PacketData {
+        token: PrefixedCoin {
+            denom: "uatom".parse().unwrap(),
+            amount: Amount::from(100),
+        },
+        sender: "alice".to_string().into(),
+        receiver: receiver(),
+        memo: Memo::from(String::new()),
+    };
+    msg.packet.data = serde_json::to_vec(&data).unwrap();
+
+    msg
+}
+
+fn transfer_module(router: &MockRouter) -> &MockTransferModule {
+    router
+        .module(&ModuleId::new(MODULE_ID_STR.to_string()))
+        .unwrap()
+}
+
+#[test]
+fn test_dispatch_batch_ok() {
+    let mut ctx = MockContext::default();
+    let mut router = MockRouter::new_with_transfer();
+
+    let client_id = mock_client_type().build_client_id(ctx.client_counter().unwrap());
+
+    // The connection is opened on the client created by the first message.
+    let msgs = vec![msg_create_client(), msg_conn_open_init(client_id.clone())];
+
+    let res = dispatch_batch(&mut ctx, &mut router, msgs);
+
+    assert!(res.is_ok(), "batch happy path: {res:?}");
+    assert!(ctx.client_state(&client_id).is_ok());
+    assert_eq!(ctx.client_counter().unwrap(), 1);
+    assert_eq!(ctx.connection_counter().unwrap(), 1);
+    assert!(ctx.connection_end(&ConnectionId::new(0)).is_ok());
+
+    let events = ctx.get_events();
+    assert_eq!(events.len(), 4);
+    assert!(matches!(events[1], IbcEvent::CreateClient(_)));
+    assert!(matches!(events[3], IbcEvent::OpenInitConnection(_)));
+}
+
+#[test]
+fn test_dispatch_batch_rolls_back_on_failure() {
+    let mut ctx = MockContext::default();
+    let mut router = MockRouter::new_with_transfer();
+
+    let client_id = mock_client_type().build_client_id(ctx.client_counter().unwrap());
+
+    // The last message refers to a client that does not exist.
+    let msgs = vec![
+        msg_create_client(),
+        msg_conn_open_init(client_id.clone()),
+        msg_conn_open_init(ClientId::default()),
+    ];
+
+    let res = dispatch_batch(&mut ctx, &mut router, msgs);
+
+    assert!(res.is_err(), "batch must fail");
+    assert!(ctx.client_state(&client_id).is_err());
+    assert_eq!(ctx.client_counter().unwrap(), 0);
+    assert_eq!(ctx.connection_counter().unwrap(), 0);
+    assert!(ctx.connection_end(&ConnectionId::new(0)).is_err());
+    assert!(ctx.get_events().is_empty());
+    assert!(ctx.get_logs().is_empty());
+}
+
+#[test]
+fn test_dispatch_batch_reverts_application_state() {
+    let (mut ctx, mut router) = transfer_context();
+
+    let msg = msg_recv_transfer();
+    let receipt_path = ReceiptPath::new(
+        &msg.packet.port_id_on_b,
+        &msg.packet.chan_id_on_b,
+        msg.packet.seq_on_a,
+    );
+
+    // The last message refers to a client that does not exist.
+    let msgs = vec![
+        PacketMsg::from(msg.clone()).into(),
+        msg_conn_open_init(ClientId::new("07-tendermint", 42).unwrap()),
+    ];
+
+    let res = dispatch_batch(&mut ctx, &mut router, msgs);
+
+    assert!(res.is_err(), "batch must fail");
+    assert!(ctx.get_packet_receipt(&receipt_path).is_err());
+    assert_eq!(
+        transfer_module(&router).balance(&receiver(), VOUCHER_DENOM),
+        Amount::from(0),
+        "the vouchers minted within the failed batch must be reverted"
+    );
+
+    // The packet can then be received once and for all.
+    let res = dispatch_batch(&mut ctx, &mut router, vec![PacketMsg::from(msg).into()]);
+
+    assert!(res.is_ok(), "batch happy path: {res:?}");
+    assert!(ctx.get_packet_receipt(&receipt_path).is_ok());
+    assert_eq!(
+        transfer_module(&router).balance(&receiver(), VOUCHER_DENOM),
+        Amount::from(100)
+    );
+}
+
+#[test]
+fn test_cached_context_excludes_deleted_inflight_packets() {
+    let port_id = PortId::transfer();
+    let chan_id = ChannelId::default();
+    let commitment = PacketCommitment::from(vec![1u8]);
+
+    let mut ctx = MockContext::default()
+        .with_packet_commitment(
+            port_id.clone(),
+            chan_id.clone(),
+            1.into(),
+            commitment.clone(),
+        )
+        .with_packet_commitment(
+            port_id.clone(),
+            chan_id.clone(),
+            2.into(),
+            commitment.clone(),
+        );
+
+    let mut cached_ctx = CachedContext::new(&mut ctx);
+
+    cached_ctx
+        .delete_packet_commitment(&CommitmentPath::new(&port_id, &chan_id, 1.into()))
+        .unwrap();
+    assert!(cached_ctx.has_inflight_packets(&port_id, &chan_id).unwrap());
+
+    cached_ctx
+        .delete_packet_commitment(&CommitmentPath::new(&port_id, &chan_id, 2.into()))
+        .unwrap();
+    assert!(!cached_ctx.has_inflight_packets(&port_id, &chan_id).unwrap());
+
+    cached_ctx
+        .store_packet_commitment(
+            &CommitmentPath::new(&port_id, &chan_id, 3.into()),
+            commitment,
+        )
+        .unwrap();
+    assert!(cached_ctx.has_inflight_packets(&port_id, &chan_id).unwrap());
+}
+
+#[test]
+fn test_cached_context_buffers_writes() {
+    let mut ctx = MockContext::default();
+
+    {
+        let mut cached_ctx = CachedContext::new(&mut ctx);
+
+        cached_ctx.increase_connection_counter().unwrap();
+        cached_ctx.log_message("discarded".to_string()).unwrap();
+
+        assert_eq!(cached_ctx.connection_counter().unwrap(), 1);
+    }
+
+    assert_eq!(ctx.connection_counter().unwrap(), 0);
+    assert!(ctx.get_logs().is_empty());
+
+    let mut cached_ctx = CachedContext::new(&mut ctx);
+
+    cached_ctx.increase_connection_counter().unwrap();
+    cached_ctx.log_message("committed".to_string()).unwrap();
+    cached_ctx.commit().unwrap();
+
+    assert_eq!(ctx.connection_counter().unwrap(), 1);
+    assert_eq!(ctx.get_logs(), vec!["committed".to_string()]);
+}
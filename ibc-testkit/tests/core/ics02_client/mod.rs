@@ -1,5 +1,7 @@
 #[cfg(feature = "serde")]
 pub mod create_client;
+pub mod recover_client;
 pub mod update_client;
+pub mod update_params;
 #[cfg(feature = "serde")]
 pub mod upgrade_client;
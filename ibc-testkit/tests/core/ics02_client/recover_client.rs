@@ -0,0 +1,173 @@
+use ibc::clients::tendermint::types::client_type as tm_client_type;
+use ibc::core::client::context::client_state::ClientStateValidation;
+use ibc::core::client::types::msgs::{ClientMsg, MsgRecoverClient};
+use ibc::core::client::types::Height;
+use ibc::core::entrypoint::{execute, validate};
+use ibc::core::handler::types::events::{IbcEvent, MessageEvent};
+use ibc::core::handler::types::msgs::MsgEnvelope;
+use ibc::core::host::types::identifiers::ClientId;
+use ibc::core::host::ValidationContext;
+use ibc::core::primitives::{downcast, Signer};
+use ibc_testkit::fixtures::core::signer::dummy_account_id;
+use ibc_testkit::fixtures::{Expect, Fixture};
+use ibc_testkit::testapp::ibc::clients::mock::client_state::client_type as mock_client_type;
+use ibc_testkit::testapp::ibc::core::router::MockRouter;
+use ibc_testkit::testapp::ibc::core::types::{MockClientConfig, MockContext};
+use test_log::test;
+
+enum Subject {
+    Expired,
+    Active,
+    ExpiredAboveSubstitute,
+}
+
+enum Substitute {
+    Mock,
+    Tendermint,
+}
+
+fn subject_client_id() -> ClientId {
+    mock_client_type().build_client_id(0)
+}
+
+fn substitute_client_id() -> ClientId {
+    mock_client_type().build_client_id(1)
+}
+
+fn msg_recover_client_fixture(
+    subject: Subject,
+    substitute: Substitute,
+) -> Fixture<MsgRecoverClient> {
+    let substitute_height = Height::new(1, 20).unwrap();
+
+    // A mock client without a consensus state at its latest height is expired.
+    let (subject_height, subject_cs_heights) = match subject {
+        Subject::Expired => (
+            Height::new(1, 10).unwrap(),
+            vec![Height::new(1, 5).unwrap()],
+        ),
+        Subject::Active => (Height::new(1, 10).unwrap(), vec![]),
+        Subject::ExpiredAboveSubstitute => (
+            Height::new(1, 30).unwrap(),
+            vec![Height::new(1, 5).unwrap()],
+        ),
+    };
+
+    let substitute_client_type = match substitute {
+        Substitute::Mock => mock_client_type(),
+        Substitute::Tendermint => tm_client_type(),
+    };
+
+    let ctx = MockContext::default()
+        .with_client_authority(dummy_account_id())
+        .with_client_config(
+            MockClientConfig::builder()
+                .client_id(subject_client_id())
+                .latest_height(subject_height)
+                .consensus_state_heights(subject_cs_heights)
+                .build(),
+        )
+        .with_client_config(
+            MockClientConfig::builder()
+                .client_id(substitute_client_id())
+                .client_type(substitute_client_type)
+                .latest_height(substitute_height)
+                .build(),
+        );
+
+    let msg = MsgRecoverClient {
+        subject_client_id: subject_client_id(),
+        substitute_client_id: substitute_client_id(),
+        signer: dummy_account_id(),
+    };
+
+    Fixture { ctx, msg }
+}
+
+fn recover_client_validate(fxt: &Fixture<MsgRecoverClient>, expect: Expect) {
+    let Fixture { ctx, msg } = fxt;
+    let router = MockRouter::new_with_transfer();
+    let msg_envelope = MsgEnvelope::from(ClientMsg::from(msg.clone()));
+    let res = validate(ctx, &router, msg_envelope);
+    let err_msg = fxt.generate_error_msg(&expect, "validation", &res);
+
+    match expect {
+        Expect::Failure(_) => {
+            assert!(res.is_err(), "{err_msg}");
+        }
+        Expect::Success => {
+            assert!(res.is_ok(), "{err_msg}");
+        }
+    }
+}
+
+fn recover_client_execute(fxt: &mut Fixture<MsgRecoverClient>, expect: Expect) {
+    let mut router = MockRouter::new_with_transfer();
+    let msg_envelope = MsgEnvelope::from(ClientMsg::from(fxt.msg.clone()));
+    let res = execute(&mut fxt.ctx, &mut router, msg_envelope);
+    let err_msg = fxt.generate_error_msg(&expect, "execution", &res);
+
+    match expect {
+        Expect::Failure(_) => {
+            assert!(res.is_err(), "{err_msg}");
+        }
+        Expect::Success => {
+            assert!(res.is_ok(), "{err_msg}");
+
+            let ibc_events = fxt.ctx.get_events();
+            assert!(matches!(
+                ibc_events[0],
+                IbcEvent::Message(MessageEvent::Client)
+            ));
+            let recover_client_event =
+                downcast!(&ibc_events[1] => IbcEvent::RecoverClient).unwrap();
+            assert_eq!(
+                recover_client_event.subject_client_id(),
+                &fxt.msg.subject_client_id
+            );
+            assert_eq!(recover_client_event.client_type(), &mock_client_type());
+
+            let subject_client_state = fxt.ctx.client_state(&fxt.msg.subject_client_id).unwrap();
+            let substitute_client_state =
+                fxt.ctx.client_state(&fxt.msg.substitute_client_id).unwrap();
+            assert_eq!(subject_client_state, substitute_client_state);
+
+            let status = subject_client_state
+                .status(&fxt.ctx, &fxt.msg.subject_client_id)
+                .unwrap();
+            assert!(status.is_active(), "client_state status: {status}");
+        }
+    }
+}
+
+#[test]
+fn msg_recover_client_healthy() {
+    let mut fxt = msg_recover_client_fixture(Subject::Expired, Substitute::Mock);
+    recover_client_validate(&fxt, Expect::Success);
+    recover_client_execute(&mut fxt, Expect::Success);
+}
+
+#[test]
+fn recover_active_client() {
+    let fxt = msg_recover_client_fixture(Subject::Active, Substitute::Mock);
+    recover_client_validate(&fxt, Expect::Failure(None));
+}
+
+#[test]
+fn recover_client_with_low_substitute_height() {
+    let fxt = msg_recover_client_fixture(Subject::ExpiredAboveSubstitute, Substitute::Mock);
+    recover_client_validate(&fxt, Expect::Failure(None));
+}
+
+#[test]
+fn recover_client_with_mismatched_client_type() {
+    let fxt = msg_recover_client_fixture(Subject::Expired, Substitute::Tendermint);
+    recover_client_validate(&fxt, Expect::Failure(None));
+}
+
+#[test]
+fn recover_client_with_unauthorized_signer() {
+    let mut fxt = msg_recover_client_fixture(Subject::Expired, Substitute::Mock);
+    fxt.msg.signer = Signer::from("unauthorized".to_string());
+    recover_client_validate(&fxt, Expect::Failure(None));
+}
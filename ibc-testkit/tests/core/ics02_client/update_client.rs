@@ -1,16 +1,23 @@
+use core::fmt::Debug;
+use core::marker::PhantomData;
 use core::str::FromStr;
+use core::sync::atomic::{AtomicUsize, Ordering};
 use core::time::Duration;
 
 use ibc::clients::tendermint::client_state::ClientState;
+use ibc::clients::tendermint::context::PruningPolicy;
 use ibc::clients::tendermint::types::proto::v1::{ClientState as RawTmClientState, Fraction};
 use ibc::clients::tendermint::types::{
-    client_type as tm_client_type, ClientState as TmClientState, Header as TmHeader,
-    Misbehaviour as TmMisbehaviour,
+    client_type as tm_client_type, detect_light_block_misbehaviour, detect_misbehaviour,
+    ClientState as TmClientState, Header as TmHeader, HeaderBundle, HostFunctions,
+    Misbehaviour as TmMisbehaviour, MisbehaviourEvidence, MisbehaviourKind, ProdHostFunctions,
 };
 use ibc::core::client::context::client_state::{ClientStateCommon, ClientStateValidation};
 use ibc::core::client::context::ClientValidationContext;
+use ibc::core::client::types::error::ClientError;
 use ibc::core::client::types::msgs::{ClientMsg, MsgSubmitMisbehaviour, MsgUpdateClient};
 use ibc::core::client::types::Height;
+use ibc::core::commitment_types::merkle::HostFunctionsProvider;
 use ibc::core::commitment_types::specs::ProofSpecs;
 use ibc::core::entrypoint::{execute, validate};
 use ibc::core::handler::types::events::{IbcEvent, MessageEvent};
@@ -23,15 +30,18 @@ use ibc::primitives::proto::Any;
 use ibc::primitives::ToVec;
 use ibc_testkit::fixtures::core::context::MockContextConfig;
 use ibc_testkit::fixtures::core::signer::dummy_account_id;
-use ibc_testkit::hosts::block::{HostBlock, HostType};
+use ibc_testkit::hosts::block::{HostBlock, HostType, SyntheticTmBlock};
 use ibc_testkit::testapp::ibc::clients::mock::client_state::{
     client_type as mock_client_type, MockClientState,
 };
 use ibc_testkit::testapp::ibc::clients::mock::header::MockHeader;
 use ibc_testkit::testapp::ibc::clients::mock::misbehaviour::Misbehaviour as MockMisbehaviour;
-use ibc_testkit::testapp::ibc::clients::AnyConsensusState;
+use ibc_testkit::testapp::ibc::clients::{AnyClientState, AnyConsensusState};
 use ibc_testkit::testapp::ibc::core::router::MockRouter;
 use ibc_testkit::testapp::ibc::core::types::{MockClientConfig, MockContext};
+use tendermint::crypto::signature::{Error as SignatureError, Verifier as SignatureVerifier};
+use tendermint::{PublicKey, Signature};
+use tendermint_light_client_verifier::types::LightBlock;
 use tendermint_testgen::Validator as TestgenValidator;
 use test_log::test;
 
@@ -178,6 +188,197 @@ fn test_consensus_state_pruning() {
     );
 }
 
+/// Returns `host` with a Tendermint client of `client_chain_id` at
+/// `client_height`, whose latest consensus state has `client_timestamp`.
+fn with_tm_client(
+    host: MockContext,
+    client_id: &ClientId,
+    client_chain_id: ChainId,
+    client_height: Height,
+    client_timestamp: Timestamp,
+) -> MockContext {
+    host.with_client_config(
+        MockClientConfig::builder()
+            .client_chain_id(client_chain_id)
+            .client_id(client_id.clone())
+            .latest_height(client_height)
+            .latest_timestamp(client_timestamp)
+            .client_type(tm_client_type())
+            .build(),
+    )
+}
+
+/// Returns the host of a client tracking the chain `mockgaiaB-1`.
+fn tm_counterparty_host() -> MockContext {
+    MockContextConfig::builder()
+        .host_id(ChainId::new("mockgaiaA-1").unwrap())
+        .latest_height(Height::new(1, 1).unwrap())
+        .build()
+}
+
+/// Returns a synthetic Tendermint host at `client_height`, hosting a client
+/// of itself at that height.
+fn tm_self_client_fixture(client_id: &ClientId, client_height: Height) -> MockContext {
+    let chain_id = ChainId::new("mockgaiaA-1").unwrap();
+
+    let host = MockContextConfig::builder()
+        .host_id(chain_id.clone())
+        .host_type(HostType::SyntheticTendermint)
+        .latest_height(client_height)
+        .latest_timestamp(Timestamp::now())
+        .max_history_size(u64::MAX)
+        .build();
+
+    with_tm_client(host, client_id, chain_id, client_height, Timestamp::now())
+}
+
+/// Updates the Tendermint client with the host block preceding the latest
+/// one, trusting the consensus state at `trusted_height`, and returns the
+/// height of the installed consensus state.
+fn update_tm_client_to_previous_block(
+    ctx: &mut MockContext,
+    router: &mut MockRouter,
+    client_id: &ClientId,
+    trusted_height: Height,
+) -> Height {
+    let update_height = ctx.latest_height();
+
+    ctx.advance_host_chain_height();
+
+    let mut block = ctx.host_block(&update_height).unwrap().clone();
+
+    block.set_trusted_height(trusted_height);
+
+    let msg = MsgUpdateClient {
+        client_id: client_id.clone(),
+        client_message: block.into(),
+        signer: dummy_account_id(),
+    };
+
+    let msg_envelope = MsgEnvelope::from(ClientMsg::from(msg));
+
+    validate(ctx, router, msg_envelope.clone()).expect("validation happy path");
+    execute(ctx, router, msg_envelope).expect("execution happy path");
+
+    update_height
+}
+
+/// Returns whether the consensus state of the client at `height` is stored.
+fn is_consensus_state_stored(ctx: &MockContext, client_id: &ClientId, height: Height) -> bool {
+    let client_cons_state_path = ClientConsensusStatePath::new(
+        client_id.clone(),
+        height.revision_number(),
+        height.revision_height(),
+    );
+    let is_stored = ctx.consensus_state(&client_cons_state_path).is_ok();
+
+    // The update metadata of a pruned consensus state must be pruned as well.
+    if !is_stored {
+        assert!(ctx.client_update_height(client_id, &height).is_err());
+        assert!(ctx.client_update_time(client_id, &height).is_err());
+    }
+
+    is_stored
+}
+
+/// Tests that the Tendermint client retains at most the number of
+/// consensus states set by the host's pruning policy, pruning the oldest
+/// ones even though they have not expired yet.
+#[test]
+fn test_consensus_state_pruning_max_consensus_states() {
+    let client_height = Height::new(1, 1).unwrap();
+    let client_id = tm_client_type().build_client_id(0);
+
+    let mut ctx = tm_self_client_fixture(&client_id, client_height);
+    let mut router = MockRouter::new_with_transfer();
+
+    ctx.ibc_store.lock().tm_pruning_policy = PruningPolicy {
+        max_consensus_states: Some(2),
+        ..Default::default()
+    };
+
+    // Make sure the first update is above the client's latest height.
+    ctx.advance_host_chain_height();
+
+    let mut trusted_height = client_height;
+    for _ in 0..4 {
+        trusted_height =
+            update_tm_client_to_previous_block(&mut ctx, &mut router, &client_id, trusted_height);
+    }
+
+    assert_eq!(trusted_height, Height::new(1, 5).unwrap());
+
+    for revision_height in 1..=3 {
+        let height = Height::new(1, revision_height).unwrap();
+        assert!(!is_consensus_state_stored(&ctx, &client_id, height));
+    }
+    for revision_height in 4..=5 {
+        let height = Height::new(1, revision_height).unwrap();
+        assert!(is_consensus_state_stored(&ctx, &client_id, height));
+    }
+}
+
+/// Tests that the Tendermint client prunes the consensus states older than
+/// the maximum age set by the host's pruning policy, without pruning more
+/// of them per update than the policy allows.
+#[test]
+fn test_consensus_state_pruning_max_age_and_max_pruned_per_update() {
+    let client_height = Height::new(1, 1).unwrap();
+    let client_id = tm_client_type().build_client_id(0);
+
+    let mut ctx = tm_self_client_fixture(&client_id, client_height);
+    let mut router = MockRouter::new_with_transfer();
+
+    ctx.advance_host_chain_height();
+
+    // Without a pruning policy, no consensus state is pruned before it
+    // expires.
+    let mut trusted_height = client_height;
+    for _ in 0..3 {
+        trusted_height =
+            update_tm_client_to_previous_block(&mut ctx, &mut router, &client_id, trusted_height);
+    }
+
+    for revision_height in 1..=4 {
+        let height = Height::new(1, revision_height).unwrap();
+        assert!(is_consensus_state_stored(&ctx, &client_id, height));
+    }
+
+    // Every block is 3 seconds apart, so all but the latest consensus
+    // states are older than the maximum age, but only one of them may be
+    // pruned per update.
+    ctx.ibc_store.lock().tm_pruning_policy = PruningPolicy {
+        max_pruned_per_update: Some(1),
+        max_age: Some(Duration::from_secs(1)),
+        ..Default::default()
+    };
+
+    trusted_height =
+        update_tm_client_to_previous_block(&mut ctx, &mut router, &client_id, trusted_height);
+
+    assert!(!is_consensus_state_stored(
+        &ctx,
+        &client_id,
+        Height::new(1, 1).unwrap()
+    ));
+    for revision_height in 2..=5 {
+        let height = Height::new(1, revision_height).unwrap();
+        assert!(is_consensus_state_stored(&ctx, &client_id, height));
+    }
+
+    update_tm_client_to_previous_block(&mut ctx, &mut router, &client_id, trusted_height);
+
+    assert!(!is_consensus_state_stored(
+        &ctx,
+        &client_id,
+        Height::new(1, 2).unwrap()
+    ));
+    for revision_height in 3..=6 {
+        let height = Height::new(1, revision_height).unwrap();
+        assert!(is_consensus_state_stored(&ctx, &client_id, height));
+    }
+}
+
 #[test]
 fn test_update_nonexisting_client() {
     let client_id = ClientId::from_str("mockclient1").unwrap();
@@ -263,6 +464,132 @@ fn test_update_synthetic_tendermint_client_adjacent_ok() {
     assert_eq!(client_state.latest_height(), latest_header_height);
 }
 
+/// Host functions verifying signatures with the signature verifier `V`.
+#[derive(Clone, Debug, Default, PartialEq)]
+struct TestHostFunctions<V>(PhantomData<V>);
+
+impl<V> HostFunctionsProvider for TestHostFunctions<V> {
+    fn sha2_256(message: &[u8]) -> [u8; 32] {
+        ProdHostFunctions::sha2_256(message)
+    }
+
+    fn sha2_512(message: &[u8]) -> [u8; 64] {
+        ProdHostFunctions::sha2_512(message)
+    }
+
+    fn sha2_512_truncated(message: &[u8]) -> [u8; 32] {
+        ProdHostFunctions::sha2_512_truncated(message)
+    }
+
+    fn keccak_256(message: &[u8]) -> [u8; 32] {
+        ProdHostFunctions::keccak_256(message)
+    }
+
+    fn ripemd160(message: &[u8]) -> [u8; 20] {
+        ProdHostFunctions::ripemd160(message)
+    }
+
+    fn blake2b_512(message: &[u8]) -> [u8; 64] {
+        ProdHostFunctions::blake2b_512(message)
+    }
+
+    fn blake2s_256(message: &[u8]) -> [u8; 32] {
+        ProdHostFunctions::blake2s_256(message)
+    }
+
+    fn blake3(message: &[u8]) -> [u8; 32] {
+        ProdHostFunctions::blake3(message)
+    }
+}
+
+impl<V> HostFunctions for TestHostFunctions<V>
+where
+    V: SignatureVerifier + Clone + Debug + Default + PartialEq + Send + Sync,
+{
+    type Sha256 = <ProdHostFunctions as HostFunctions>::Sha256;
+    type SignatureVerifier = V;
+}
+
+static VERIFIED_SIGNATURES: AtomicUsize = AtomicUsize::new(0);
+
+/// Counts the signatures it verifies with the default verifier.
+#[derive(Clone, Debug, Default, PartialEq)]
+struct CountingVerifier;
+
+impl SignatureVerifier for CountingVerifier {
+    fn verify(pubkey: PublicKey, msg: &[u8], signature: &Signature) -> Result<(), SignatureError> {
+        VERIFIED_SIGNATURES.fetch_add(1, Ordering::SeqCst);
+        <ProdHostFunctions as HostFunctions>::SignatureVerifier::verify(pubkey, msg, signature)
+    }
+}
+
+/// Rejects every signature.
+#[derive(Clone, Debug, Default, PartialEq)]
+struct RejectingVerifier;
+
+impl SignatureVerifier for RejectingVerifier {
+    fn verify(
+        _pubkey: PublicKey,
+        _msg: &[u8],
+        _signature: &Signature,
+    ) -> Result<(), SignatureError> {
+        Err(SignatureError::VerificationFailed)
+    }
+}
+
+/// Verifies an adjacent header of a synthetic Tendermint chain with a client
+/// state relying on the host functions `H`.
+fn verify_synthetic_tendermint_header<H: HostFunctions>() -> Result<(), ClientError> {
+    let client_id = tm_client_type().build_client_id(0);
+    let client_height = Height::new(1, 20).unwrap();
+    let update_height = Height::new(1, 21).unwrap();
+    let chain_id_b = ChainId::new("mockgaiaB-1").unwrap();
+
+    let ctx = MockContextConfig::builder()
+        .host_id(ChainId::new("mockgaiaA-1").unwrap())
+        .latest_height(Height::new(1, 1).unwrap())
+        .build()
+        .with_client_config(
+            MockClientConfig::builder()
+                .client_chain_id(chain_id_b.clone())
+                .client_id(client_id.clone())
+                .client_type(tm_client_type())
+                .latest_height(client_height)
+                .build(),
+        );
+
+    let ctx_b = MockContextConfig::builder()
+        .host_id(chain_id_b)
+        .host_type(HostType::SyntheticTendermint)
+        .latest_height(update_height)
+        .build();
+
+    let mut block = ctx_b.host_block(&update_height).unwrap().clone();
+    block.set_trusted_height(client_height);
+    let header = TmHeader::try_from(Any::from(block)).unwrap();
+
+    let client_state: Any = ctx.client_state(&client_id).unwrap().into();
+    let client_state = ClientState::<H>::try_from(client_state).unwrap();
+
+    client_state.verify_header(&ctx, &client_id, header)
+}
+
+#[test]
+fn test_update_synthetic_tendermint_client_with_host_functions() {
+    let verified_signatures = VERIFIED_SIGNATURES.load(Ordering::SeqCst);
+
+    verify_synthetic_tendermint_header::<TestHostFunctions<CountingVerifier>>().unwrap();
+
+    assert!(VERIFIED_SIGNATURES.load(Ordering::SeqCst) > verified_signatures);
+}
+
+#[test]
+fn test_update_synthetic_tendermint_client_with_rejecting_host_functions() {
+    let res = verify_synthetic_tendermint_header::<TestHostFunctions<RejectingVerifier>>();
+
+    assert!(res.is_err());
+}
+
 #[test]
 fn test_update_synthetic_tendermint_client_validator_change_ok() {
     let client_id = tm_client_type().build_client_id(0);
@@ -439,6 +766,199 @@ fn test_update_synthetic_tendermint_client_validator_change_fail() {
     assert!(res.is_err());
 }
 
+/// Returns a context hosting a Tendermint client at `client_height`, as well
+/// as the context of the counterparty chain, whose validator set is entirely
+/// replaced within the three blocks following `client_height`.
+fn tm_header_bundle_fixture(
+    client_id: &ClientId,
+    client_height: Height,
+) -> (MockContext, MockContext) {
+    let chain_id_b = ChainId::new("mockgaiaB-1").unwrap();
+
+    let ctx_b_val_history = vec![
+        // validator set of height-20
+        vec![
+            TestgenValidator::new("1").voting_power(50),
+            TestgenValidator::new("2").voting_power(50),
+        ],
+        // validator set of height-21
+        vec![
+            TestgenValidator::new("1").voting_power(50),
+            TestgenValidator::new("2").voting_power(50),
+        ],
+        // validator set of height-22
+        vec![
+            TestgenValidator::new("2").voting_power(50),
+            TestgenValidator::new("3").voting_power(50),
+        ],
+        // validator set of height-23
+        vec![
+            TestgenValidator::new("3").voting_power(50),
+            TestgenValidator::new("4").voting_power(50),
+        ],
+        // validator set of height-24
+        vec![
+            TestgenValidator::new("3").voting_power(50),
+            TestgenValidator::new("4").voting_power(50),
+        ],
+    ];
+
+    let ctx_b = MockContextConfig::builder()
+        .host_id(chain_id_b.clone())
+        .host_type(HostType::SyntheticTendermint)
+        .latest_height(client_height.add(ctx_b_val_history.len() as u64 - 2))
+        .max_history_size(ctx_b_val_history.len() as u64 - 1)
+        .validator_set_history(ctx_b_val_history)
+        .build();
+
+    // client state initialized with client_height, and
+    // [{id: 1, power: 50}, {id: 2, power: 50}] for validator set and next validator set.
+    let client_timestamp = ctx_b.host_block(&client_height).unwrap().timestamp();
+    let ctx_a = with_tm_client(
+        tm_counterparty_host(),
+        client_id,
+        chain_id_b,
+        client_height,
+        client_timestamp,
+    );
+
+    (ctx_a, ctx_b)
+}
+
+/// Returns the header of `ctx` at `height`, trusting the block at
+/// `trusted_height`.
+fn tm_header_trusting(ctx: &MockContext, height: Height, trusted_height: Height) -> TmHeader {
+    let mut block = ctx.host_block(&height).unwrap().clone();
+    block.set_trusted_height(trusted_height);
+
+    let trusted_next_validator_set = match ctx.host_block(&trusted_height).expect("no error") {
+        HostBlock::SyntheticTendermint(header) => header.light_block.next_validators.clone(),
+        _ => panic!("unexpected host block type"),
+    };
+
+    block.set_trusted_next_validators_set(trusted_next_validator_set);
+
+    block.try_into_tm_block().unwrap().into()
+}
+
+#[test]
+fn test_update_synthetic_tendermint_client_header_bundle_ok() {
+    let client_id = tm_client_type().build_client_id(0);
+    let client_height = Height::new(1, 20).unwrap();
+
+    let (mut ctx_a, ctx_b) = tm_header_bundle_fixture(&client_id, client_height);
+    let mut router_a = MockRouter::new_with_transfer();
+
+    let update_heights: Vec<Height> = (1..=3).map(|i| client_height.add(i)).collect();
+    let latest_header_height = update_heights[2];
+
+    // None of the trusted validators signed the latest header, which thus
+    // cannot be verified on its own.
+    let msg = MsgUpdateClient {
+        client_id: client_id.clone(),
+        client_message: tm_header_trusting(&ctx_b, latest_header_height, client_height).into(),
+        signer: dummy_account_id(),
+    };
+    let res = validate(&ctx_a, &router_a, MsgEnvelope::from(ClientMsg::from(msg)));
+    assert!(res.is_err());
+
+    let mut trusted_height = client_height;
+    let headers = update_heights
+        .iter()
+        .map(|&height| {
+            let header = tm_header_trusting(&ctx_b, height, trusted_height);
+            trusted_height = height;
+            header
+        })
+        .collect();
+    let header_bundle: Any = HeaderBundle::new(headers).unwrap().into();
+
+    let msg = MsgUpdateClient {
+        client_id: client_id.clone(),
+        client_message: header_bundle.clone(),
+        signer: dummy_account_id(),
+    };
+    let msg_envelope = MsgEnvelope::from(ClientMsg::from(msg));
+
+    let res = validate(&ctx_a, &router_a, msg_envelope.clone());
+    assert!(res.is_ok(), "result: {res:?}");
+
+    let res = execute(&mut ctx_a, &mut router_a, msg_envelope);
+    assert!(res.is_ok(), "result: {res:?}");
+
+    let client_state = ctx_a.client_state(&client_id).unwrap();
+    assert!(client_state.status(&ctx_a, &client_id).unwrap().is_active());
+    assert_eq!(client_state.latest_height(), latest_header_height);
+
+    for height in &update_heights {
+        let client_cons_state_path = ClientConsensusStatePath::new(
+            client_id.clone(),
+            height.revision_number(),
+            height.revision_height(),
+        );
+        let consensus_state = ctx_a.consensus_state(&client_cons_state_path).unwrap();
+        let host_block = ctx_b.host_block(height).unwrap().clone();
+
+        assert_eq!(consensus_state, AnyConsensusState::from(host_block));
+        assert!(ctx_a.client_update_height(&client_id, height).is_ok());
+    }
+
+    let ibc_events = ctx_a.get_events();
+    let update_client_event = downcast!(&ibc_events[1] => IbcEvent::UpdateClient).unwrap();
+
+    assert_eq!(update_client_event.consensus_height(), &update_heights[0]);
+    assert_eq!(update_client_event.consensus_heights(), &update_heights);
+    assert_eq!(update_client_event.header(), &header_bundle.to_vec());
+}
+
+#[test]
+fn test_update_synthetic_tendermint_client_header_bundle_fail() {
+    let client_id = tm_client_type().build_client_id(0);
+    let client_height = Height::new(1, 20).unwrap();
+
+    let (ctx_a, ctx_b) = tm_header_bundle_fixture(&client_id, client_height);
+    let router_a = MockRouter::new_with_transfer();
+
+    let first_height = client_height.add(1);
+    let second_height = client_height.add(2);
+
+    // The second header claims the trusted validators of the client, rather
+    // than the next validators of the first header.
+    let mut second_header = tm_header_trusting(&ctx_b, second_height, client_height);
+    second_header.trusted_height = first_height;
+
+    let header_bundle = HeaderBundle::new(vec![
+        tm_header_trusting(&ctx_b, first_height, client_height),
+        second_header,
+    ])
+    .unwrap();
+
+    let msg = MsgUpdateClient {
+        client_id,
+        client_message: header_bundle.into(),
+        signer: dummy_account_id(),
+    };
+    let res = validate(&ctx_a, &router_a, MsgEnvelope::from(ClientMsg::from(msg)));
+
+    assert!(res.is_err());
+}
+
+#[test]
+fn test_tendermint_header_bundle_must_be_sequential() {
+    let client_height = Height::new(1, 20).unwrap();
+
+    let (_, ctx_b) = tm_header_bundle_fixture(&tm_client_type().build_client_id(0), client_height);
+
+    let first_header = tm_header_trusting(&ctx_b, client_height.add(1), client_height);
+    let second_header = tm_header_trusting(&ctx_b, client_height.add(2), client_height);
+
+    assert!(HeaderBundle::new(vec![]).is_err());
+    assert!(HeaderBundle::new(vec![first_header.clone(), first_header.clone()]).is_err());
+    assert!(HeaderBundle::new(vec![second_header.clone(), first_header.clone()]).is_err());
+    assert!(HeaderBundle::new(vec![first_header.clone(), second_header.clone()]).is_err());
+    assert!(HeaderBundle::new(vec![first_header]).is_ok());
+}
+
 #[test]
 fn test_update_synthetic_tendermint_client_non_adjacent_ok() {
     let client_id = tm_client_type().build_client_id(0);
@@ -928,6 +1448,220 @@ fn test_misbehaviour_synthetic_tendermint_bft_time() {
     ensure_misbehaviour(&ctx_a, &client_id, &tm_client_type());
 }
 
+/// Generates the header of a synthetic Tendermint block at `height` with the
+/// given timestamp, trusting the block at `trusted_height`.
+fn generate_tm_header(
+    chain_id: &ChainId,
+    height: Height,
+    timestamp: Timestamp,
+    trusted_height: Height,
+) -> TmHeader {
+    let mut tm_block =
+        HostBlock::generate_tm_block(chain_id.clone(), height.revision_height(), timestamp);
+    tm_block.trusted_height = trusted_height;
+    tm_block.into()
+}
+
+fn submit_detected_misbehaviour(
+    ctx: &mut MockContext,
+    client_id: &ClientId,
+    evidence: MisbehaviourEvidence,
+) {
+    let mut router = MockRouter::new_with_transfer();
+
+    let msg = MsgSubmitMisbehaviour {
+        client_id: client_id.clone(),
+        misbehaviour: evidence.into_misbehaviour().into(),
+        signer: dummy_account_id(),
+    };
+    let msg_envelope = MsgEnvelope::from(ClientMsg::from(msg));
+
+    let res = validate(ctx, &router, msg_envelope.clone());
+    assert!(res.is_ok(), "result: {res:?}");
+    let res = execute(ctx, &mut router, msg_envelope);
+    assert!(res.is_ok(), "result: {res:?}");
+    ensure_misbehaviour(ctx, client_id, &tm_client_type());
+}
+
+#[test]
+fn test_detect_misbehaviour_synthetic_tendermint_duplicate_height() {
+    let client_id = tm_client_type().build_client_id(0);
+    let client_height = Height::new(1, 20).unwrap();
+    let misbehaviour_height = Height::new(1, 21).unwrap();
+    let chain_id_b = ChainId::new("mockgaiaB-1").unwrap();
+
+    let mut ctx_a = with_tm_client(
+        tm_counterparty_host(),
+        &client_id,
+        chain_id_b.clone(),
+        client_height,
+        Timestamp::now(),
+    );
+
+    let ctx_b = MockContextConfig::builder()
+        .host_id(chain_id_b.clone())
+        .host_type(HostType::SyntheticTendermint)
+        .latest_height(misbehaviour_height)
+        .build();
+
+    // The headers of chain-B as seen by a first full node.
+    let headers_1: Vec<TmHeader> = [client_height, misbehaviour_height]
+        .iter()
+        .map(|height| {
+            let mut block = ctx_b.host_block(height).unwrap().clone();
+            block.set_trusted_height(client_height);
+            block.try_into_tm_block().unwrap().into()
+        })
+        .collect();
+
+    // No misbehaviour is detected among the headers of a single chain.
+    assert!(detect_misbehaviour(&client_id, &headers_1, &headers_1[1..]).is_none());
+
+    // The headers of a fork of chain-B as seen by a second full node.
+    let headers_2 = vec![generate_tm_header(
+        &chain_id_b,
+        misbehaviour_height,
+        Timestamp::now(),
+        client_height,
+    )];
+
+    // Conflicting headers within the same set are not compared.
+    let conflicting_headers = [headers_1[1].clone(), headers_2[0].clone()];
+    assert!(detect_misbehaviour(&client_id, &conflicting_headers, &headers_1[..1]).is_none());
+
+    let evidence = detect_misbehaviour(&client_id, &headers_1, &headers_2).unwrap();
+
+    assert_eq!(evidence.kind(), MisbehaviourKind::DuplicateHeight);
+    assert_eq!(evidence.misbehaviour().client_id(), &client_id);
+    assert_eq!(evidence.misbehaviour().header1(), &headers_1[1]);
+    assert_eq!(evidence.misbehaviour().header2(), &headers_2[0]);
+
+    submit_detected_misbehaviour(&mut ctx_a, &client_id, evidence);
+}
+
+#[test]
+fn test_detect_misbehaviour_synthetic_tendermint_bft_time_violation() {
+    let client_id = tm_client_type().build_client_id(0);
+    let client_height = Height::new(1, 20).unwrap();
+    let chain_id_b = ChainId::new("mockgaiaB-1").unwrap();
+
+    let mut ctx_a = with_tm_client(
+        tm_counterparty_host(),
+        &client_id,
+        chain_id_b.clone(),
+        client_height,
+        Timestamp::now(),
+    );
+
+    let now = Timestamp::now();
+    let later = (now + Duration::from_secs(1)).unwrap();
+
+    let lower_header = generate_tm_header(&chain_id_b, client_height.add(1), later, client_height);
+    let higher_header = generate_tm_header(&chain_id_b, client_height.add(2), now, client_height);
+
+    let evidence = detect_misbehaviour(
+        &client_id,
+        core::slice::from_ref(&lower_header),
+        core::slice::from_ref(&higher_header),
+    )
+    .unwrap();
+
+    // The highest header comes first, whichever set it belongs to.
+    assert_eq!(evidence.kind(), MisbehaviourKind::BftTimeViolation);
+    assert_eq!(evidence.misbehaviour().header1(), &higher_header);
+    assert_eq!(evidence.misbehaviour().header2(), &lower_header);
+
+    submit_detected_misbehaviour(&mut ctx_a, &client_id, evidence);
+}
+
+#[test]
+fn test_detect_light_block_misbehaviour_synthetic_tendermint() {
+    let client_id = tm_client_type().build_client_id(0);
+    let client_height = Height::new(1, 20).unwrap();
+    let misbehaviour_height = Height::new(1, 21).unwrap();
+    let chain_id_b = ChainId::new("mockgaiaB-1").unwrap();
+
+    let mut ctx_a = with_tm_client(
+        tm_counterparty_host(),
+        &client_id,
+        chain_id_b.clone(),
+        client_height,
+        Timestamp::now(),
+    );
+
+    let ctx_b = MockContextConfig::builder()
+        .host_id(chain_id_b.clone())
+        .host_type(HostType::SyntheticTendermint)
+        .latest_height(misbehaviour_height)
+        .build();
+
+    let into_light_block = |block: SyntheticTmBlock| {
+        let light_block = block.light_block;
+        LightBlock::new(
+            light_block.signed_header,
+            light_block.validators,
+            light_block.next_validators,
+            light_block.provider,
+        )
+    };
+    let host_light_block = |height: &Height| match ctx_b.host_block(height).unwrap().clone() {
+        HostBlock::SyntheticTendermint(block) => into_light_block(*block),
+        _ => panic!("unexpected host block type"),
+    };
+
+    let trusted_light_block = host_light_block(&client_height);
+
+    // The light blocks of chain-B and of a fork of it, as fetched from two
+    // different full nodes.
+    let light_blocks_1 = vec![
+        trusted_light_block.clone(),
+        host_light_block(&misbehaviour_height),
+    ];
+    let fork_light_block = |height: &Height| {
+        into_light_block(HostBlock::generate_tm_block(
+            chain_id_b.clone(),
+            height.revision_height(),
+            Timestamp::now(),
+        ))
+    };
+    let light_blocks_2 = vec![
+        fork_light_block(&client_height),
+        fork_light_block(&misbehaviour_height),
+    ];
+
+    // The blocks up to the trusted height cannot be verified against it, and
+    // are skipped even though they conflict.
+    assert!(detect_light_block_misbehaviour(
+        &client_id,
+        client_height,
+        &trusted_light_block.next_validators,
+        &light_blocks_1[..1],
+        &light_blocks_2[..1],
+    )
+    .is_none());
+
+    let evidence = detect_light_block_misbehaviour(
+        &client_id,
+        client_height,
+        &trusted_light_block.next_validators,
+        &light_blocks_1,
+        &light_blocks_2,
+    )
+    .unwrap();
+
+    assert_eq!(evidence.kind(), MisbehaviourKind::DuplicateHeight);
+    assert_eq!(
+        evidence.misbehaviour().header1().height(),
+        misbehaviour_height
+    );
+    assert_eq!(
+        evidence.misbehaviour().header2().trusted_height,
+        client_height
+    );
+
+    submit_detected_misbehaviour(&mut ctx_a, &client_id, evidence);
+}
+
 #[test]
 fn test_expired_client() {
     let chain_id_b = ChainId::new("mockgaiaB-1").unwrap();
@@ -967,6 +1701,99 @@ fn test_expired_client() {
     assert!(client_state.status(&ctx, &client_id).unwrap().is_expired());
 }
 
+#[test]
+fn test_tm_client_status_details() {
+    let chain_id_b = ChainId::new("mockgaiaB-1").unwrap();
+    let client_height = Height::new(1, 18).unwrap();
+    let client_id = tm_client_type().build_client_id(0);
+
+    let timestamp = Timestamp::now();
+    let trusting_period = Duration::from_secs(64);
+
+    let mut ctx = MockContextConfig::builder()
+        .host_id(ChainId::new("mockgaiaA-1").unwrap())
+        .latest_height(Height::new(1, 1).unwrap())
+        .latest_timestamp(timestamp)
+        .build()
+        .with_client_config(
+            MockClientConfig::builder()
+                .client_chain_id(chain_id_b)
+                .client_id(client_id.clone())
+                .latest_height(client_height)
+                .client_type(tm_client_type())
+                .latest_timestamp(timestamp)
+                .trusting_period(trusting_period)
+                .build(),
+        );
+
+    let expiry_deadline = (timestamp + trusting_period).unwrap();
+
+    let client_state = ctx.client_state(&client_id).unwrap();
+    let status_details = ctx
+        .client_status_details(&client_state, &client_id)
+        .unwrap();
+
+    assert!(status_details.status.is_active());
+    assert_eq!(status_details.latest_consensus_timestamp, Some(timestamp));
+    assert_eq!(status_details.expiry_deadline, Some(expiry_deadline));
+    assert_eq!(
+        status_details.time_until_expiry,
+        expiry_deadline.duration_since(&ctx.host_timestamp().unwrap())
+    );
+    assert_eq!(status_details.frozen_height, None);
+    assert!(!status_details.allow_update_after_expiry);
+    assert!(!status_details.allow_update_after_misbehaviour);
+
+    while ctx.host_timestamp().expect("no error") <= expiry_deadline {
+        ctx.advance_host_chain_height();
+    }
+
+    let status_details = ctx
+        .client_status_details(&client_state, &client_id)
+        .unwrap();
+
+    assert!(status_details.status.is_expired());
+    assert_eq!(status_details.expiry_deadline, Some(expiry_deadline));
+    assert_eq!(status_details.time_until_expiry, None);
+}
+
+#[test]
+fn test_mock_client_status_details() {
+    let client_id = ClientId::default();
+    let client_height = Height::new(0, 42).unwrap();
+    let timestamp = Timestamp::now();
+
+    let ctx = MockContext::default().with_client_config(
+        MockClientConfig::builder()
+            .client_id(client_id.clone())
+            .latest_height(client_height)
+            .latest_timestamp(timestamp)
+            .build(),
+    );
+
+    let client_state = ctx.client_state(&client_id).unwrap();
+    let status_details = ctx
+        .client_status_details(&client_state, &client_id)
+        .unwrap();
+
+    assert!(status_details.status.is_active());
+    assert_eq!(status_details.latest_consensus_timestamp, Some(timestamp));
+    // The mock client never expires.
+    assert_eq!(status_details.expiry_deadline, None);
+    assert_eq!(status_details.time_until_expiry, None);
+
+    let frozen_height = Height::new(0, 43).unwrap();
+    let frozen_client_state: AnyClientState = MockClientState::new(MockHeader::new(client_height))
+        .with_frozen_height(frozen_height)
+        .into();
+    let status_details = ctx
+        .client_status_details(&frozen_client_state, &client_id)
+        .unwrap();
+
+    assert!(status_details.status.is_frozen());
+    assert_eq!(status_details.frozen_height, Some(frozen_height));
+}
+
 #[test]
 fn test_client_update_max_clock_drift() {
     let chain_id_b = ChainId::new("mockgaiaB-1").unwrap();
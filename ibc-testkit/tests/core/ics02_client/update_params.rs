@@ -0,0 +1,154 @@
+use ibc::clients::tendermint::types::client_type as tm_client_type;
+use ibc::core::client::types::error::ClientError;
+use ibc::core::client::types::msgs::{
+    ClientMsg, MsgCreateClient, MsgUpdateClient, MsgUpdateParams,
+};
+use ibc::core::client::types::{AllowedClients, Height, Params, Status};
+use ibc::core::entrypoint::{execute, validate};
+use ibc::core::handler::types::error::ContextError;
+use ibc::core::handler::types::events::{IbcEvent, MessageEvent};
+use ibc::core::handler::types::msgs::MsgEnvelope;
+use ibc::core::host::types::identifiers::ClientId;
+use ibc::core::host::ValidationContext;
+use ibc::core::primitives::prelude::*;
+use ibc::core::primitives::{downcast, Signer};
+use ibc_testkit::fixtures::core::signer::dummy_account_id;
+use ibc_testkit::testapp::ibc::clients::mock::client_state::{
+    client_type as mock_client_type, MockClientState,
+};
+use ibc_testkit::testapp::ibc::clients::mock::consensus_state::MockConsensusState;
+use ibc_testkit::testapp::ibc::clients::mock::header::MockHeader;
+use ibc_testkit::testapp::ibc::core::router::MockRouter;
+use ibc_testkit::testapp::ibc::core::types::{MockClientConfig, MockContext};
+use test_log::test;
+
+/// Restricts the allowed clients of the given context to Tendermint clients.
+fn allow_tm_clients_only(ctx: &mut MockContext, router: &mut MockRouter) {
+    let msg = MsgUpdateParams {
+        signer: dummy_account_id(),
+        params: Params::new(AllowedClients::new(vec![tm_client_type().to_string()]).unwrap()),
+    };
+    let msg_envelope = MsgEnvelope::from(ClientMsg::from(msg));
+
+    assert!(validate(ctx, router, msg_envelope.clone()).is_ok());
+    assert!(execute(ctx, router, msg_envelope).is_ok());
+}
+
+#[test]
+fn test_update_params_ok() {
+    let mut ctx = MockContext::default().with_client_authority(dummy_account_id());
+    let mut router = MockRouter::new_with_transfer();
+
+    assert_eq!(ctx.allowed_clients().unwrap(), AllowedClients::allow_all());
+
+    allow_tm_clients_only(&mut ctx, &mut router);
+
+    let allowed_clients = ctx.allowed_clients().unwrap();
+    assert!(allowed_clients.is_allowed(&tm_client_type()));
+    assert!(!allowed_clients.is_allowed(&mock_client_type()));
+
+    let ibc_events = ctx.get_events();
+    assert!(matches!(
+        ibc_events[0],
+        IbcEvent::Message(MessageEvent::Client)
+    ));
+    let update_params_event = downcast!(&ibc_events[1] => IbcEvent::UpdateClientParams).unwrap();
+    assert_eq!(
+        update_params_event.allowed_clients(),
+        allowed_clients.as_slice()
+    );
+}
+
+#[test]
+fn test_update_params_unauthorized_signer() {
+    let ctx = MockContext::default().with_client_authority(dummy_account_id());
+    let router = MockRouter::new_with_transfer();
+
+    let msg = MsgUpdateParams {
+        signer: Signer::from("unauthorized".to_string()),
+        params: Params::new(AllowedClients::new(vec![tm_client_type().to_string()]).unwrap()),
+    };
+
+    let res = validate(&ctx, &router, MsgEnvelope::from(ClientMsg::from(msg)));
+
+    assert!(
+        matches!(
+            res,
+            Err(ContextError::ClientError(
+                ClientError::UnauthorizedSigner { .. }
+            ))
+        ),
+        "unexpected result: {res:?}"
+    );
+    assert_eq!(ctx.allowed_clients().unwrap(), AllowedClients::allow_all());
+}
+
+#[test]
+fn test_create_disallowed_client() {
+    let mut ctx = MockContext::default().with_client_authority(dummy_account_id());
+    let mut router = MockRouter::new_with_transfer();
+
+    allow_tm_clients_only(&mut ctx, &mut router);
+
+    let header = MockHeader::new(Height::new(0, 42).unwrap());
+    let msg = MsgCreateClient::new(
+        MockClientState::new(header).into(),
+        MockConsensusState::new(header).into(),
+        dummy_account_id(),
+    );
+
+    let res = validate(&ctx, &router, MsgEnvelope::from(ClientMsg::from(msg)));
+
+    assert!(
+        matches!(
+            res,
+            Err(ContextError::ClientError(
+                ClientError::ClientTypeNotAllowed { .. }
+            ))
+        ),
+        "unexpected result: {res:?}"
+    );
+}
+
+#[test]
+fn test_update_disallowed_client() {
+    let client_id = ClientId::default();
+
+    let mut ctx = MockContext::default()
+        .with_client_authority(dummy_account_id())
+        .with_client_config(
+            MockClientConfig::builder()
+                .client_id(client_id.clone())
+                .latest_height(Height::new(0, 42).unwrap())
+                .build(),
+        );
+    let mut router = MockRouter::new_with_transfer();
+
+    allow_tm_clients_only(&mut ctx, &mut router);
+
+    let client_state = ctx.client_state(&client_id).unwrap();
+    assert_eq!(
+        ctx.client_status(&client_state, &client_id).unwrap(),
+        Status::Unauthorized
+    );
+
+    let msg = MsgUpdateClient {
+        client_id,
+        client_message: MockHeader::new(Height::new(0, 46).unwrap())
+            .with_current_timestamp()
+            .into(),
+        signer: dummy_account_id(),
+    };
+
+    let res = validate(&ctx, &router, MsgEnvelope::from(ClientMsg::from(msg)));
+
+    assert!(
+        matches!(
+            res,
+            Err(ContextError::ClientError(ClientError::ClientNotActive {
+                status: Status::Unauthorized
+            }))
+        ),
+        "unexpected result: {res:?}"
+    );
+}
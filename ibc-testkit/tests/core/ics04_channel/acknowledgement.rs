@@ -1,18 +1,23 @@
 use ibc::core::channel::types::channel::{ChannelEnd, Counterparty, Order, State};
 use ibc::core::channel::types::commitment::{compute_packet_commitment, PacketCommitment};
+use ibc::core::channel::types::error::PacketError;
 use ibc::core::channel::types::msgs::{MsgAcknowledgement, PacketMsg};
+use ibc::core::channel::types::upgrade::{Upgrade, UpgradeTimeout};
 use ibc::core::channel::types::Version;
 use ibc::core::client::context::ClientExecutionContext;
 use ibc::core::client::types::Height;
+use ibc::core::commitment_types::merkle::HostFunctionsManager;
 use ibc::core::connection::types::version::get_compatible_versions;
 use ibc::core::connection::types::{
     ConnectionEnd, Counterparty as ConnectionCounterparty, State as ConnectionState,
 };
 use ibc::core::entrypoint::{execute, validate};
+use ibc::core::handler::types::error::ContextError;
 use ibc::core::handler::types::events::{IbcEvent, MessageEvent};
 use ibc::core::handler::types::msgs::MsgEnvelope;
-use ibc::core::host::types::identifiers::{ChannelId, ClientId, ConnectionId, PortId};
-use ibc::core::host::ExecutionContext;
+use ibc::core::host::types::identifiers::{ChannelId, ClientId, ConnectionId, PortId, Sequence};
+use ibc::core::host::types::path::{ChannelEndPath, ChannelUpgradePath, SeqAckPath};
+use ibc::core::host::{ExecutionContext, ValidationContext};
 use ibc::core::primitives::*;
 use ibc_testkit::fixtures::core::channel::dummy_raw_msg_acknowledgement;
 use ibc_testkit::testapp::ibc::core::router::MockRouter;
@@ -49,7 +54,7 @@ fn fixture() -> Fixture {
 
     let packet = msg.packet.clone();
 
-    let packet_commitment = compute_packet_commitment(
+    let packet_commitment = compute_packet_commitment::<HostFunctionsManager>(
         &packet.data,
         &packet.timeout_height_on_b,
         &packet.timeout_timestamp_on_b,
@@ -279,3 +284,223 @@ fn ack_ordered_chan_execute(fixture: Fixture) {
     ));
     assert!(matches!(ibc_events[1], IbcEvent::AcknowledgePacket(_)));
 }
+
+#[rstest]
+fn ack_ordered_allow_timeout_chan_execute(fixture: Fixture) {
+    let Fixture {
+        ctx,
+        mut router,
+        msg,
+        packet_commitment,
+        conn_end_on_a,
+        mut chan_end_on_a_ordered,
+        ..
+    } = fixture;
+    chan_end_on_a_ordered.ordering = Order::OrderedAllowTimeout;
+
+    let mut ctx = ctx
+        .with_channel(
+            PortId::transfer(),
+            ChannelId::default(),
+            chan_end_on_a_ordered,
+        )
+        .with_connection(ConnectionId::default(), conn_end_on_a)
+        .with_packet_commitment(
+            msg.packet.port_id_on_a.clone(),
+            msg.packet.chan_id_on_a.clone(),
+            msg.packet.seq_on_a,
+            packet_commitment,
+        )
+        .with_ack_sequence(
+            msg.packet.port_id_on_a.clone(),
+            msg.packet.chan_id_on_a.clone(),
+            msg.packet.seq_on_a,
+        );
+
+    let seq_ack_path = SeqAckPath::new(&msg.packet.port_id_on_a, &msg.packet.chan_id_on_a);
+    let next_seq_ack = msg.packet.seq_on_a.increment();
+    let msg_envelope = MsgEnvelope::from(PacketMsg::from(msg));
+
+    let res = execute(&mut ctx, &mut router, msg_envelope);
+
+    assert!(res.is_ok());
+
+    assert_eq!(
+        ctx.get_next_sequence_ack(&seq_ack_path).unwrap(),
+        next_seq_ack
+    );
+}
+
+#[rstest]
+fn ack_fail_ordered_allow_timeout_chan_out_of_order(fixture: Fixture) {
+    let Fixture {
+        ctx,
+        router,
+        msg,
+        packet_commitment,
+        conn_end_on_a,
+        mut chan_end_on_a_ordered,
+        ..
+    } = fixture;
+    chan_end_on_a_ordered.ordering = Order::OrderedAllowTimeout;
+
+    let ctx = ctx
+        .with_channel(
+            PortId::transfer(),
+            ChannelId::default(),
+            chan_end_on_a_ordered,
+        )
+        .with_connection(ConnectionId::default(), conn_end_on_a)
+        .with_packet_commitment(
+            msg.packet.port_id_on_a.clone(),
+            msg.packet.chan_id_on_a.clone(),
+            msg.packet.seq_on_a,
+            packet_commitment,
+        )
+        .with_ack_sequence(
+            msg.packet.port_id_on_a.clone(),
+            msg.packet.chan_id_on_a.clone(),
+            msg.packet.seq_on_a.increment(),
+        );
+
+    let msg_envelope = MsgEnvelope::from(PacketMsg::from(msg));
+
+    let res = validate(&ctx, &router, msg_envelope);
+
+    assert!(
+        matches!(
+            res,
+            Err(ContextError::PacketError(
+                PacketError::InvalidPacketSequence { .. }
+            ))
+        ),
+        "Validation fails because the packet is not the next one to acknowledge: {res:?}"
+    );
+}
+
+/// Returns a context where the unordered channel is flushing, with the packet
+/// of the message and `other_sequences` still in flight.
+fn flushing_context(
+    fixture: Fixture,
+    other_sequences: &[Sequence],
+) -> (MockContext, MockRouter, MsgAcknowledgement) {
+    let Fixture {
+        ctx,
+        router,
+        msg,
+        packet_commitment,
+        conn_end_on_a,
+        mut chan_end_on_a_unordered,
+        ..
+    } = fixture;
+
+    chan_end_on_a_unordered.set_state(State::Flushing);
+
+    let packet = &msg.packet;
+    let mut ctx = ctx
+        .with_channel(
+            packet.port_id_on_a.clone(),
+            packet.chan_id_on_a.clone(),
+            chan_end_on_a_unordered.clone(),
+        )
+        .with_connection(ConnectionId::default(), conn_end_on_a);
+
+    for seq in core::iter::once(&packet.seq_on_a).chain(other_sequences) {
+        ctx = ctx.with_packet_commitment(
+            packet.port_id_on_a.clone(),
+            packet.chan_id_on_a.clone(),
+            *seq,
+            packet_commitment.clone(),
+        );
+    }
+
+    // The upgrade of the counterparty as stored by `ChanUpgradeAck`.
+    ctx.store_counterparty_channel_upgrade(
+        &ChannelUpgradePath::new(&packet.port_id_on_a, &packet.chan_id_on_a),
+        Upgrade::new(
+            chan_end_on_a_unordered.upgrade_fields(),
+            UpgradeTimeout::default(),
+            Sequence::from(1),
+        ),
+    )
+    .unwrap();
+
+    (ctx, router, msg)
+}
+
+#[rstest]
+fn ack_flushing_chan_execute_completes_flush(fixture: Fixture) {
+    let (mut ctx, mut router, msg) = flushing_context(fixture, &[]);
+
+    let chan_end_path_on_a =
+        ChannelEndPath::new(&msg.packet.port_id_on_a, &msg.packet.chan_id_on_a);
+
+    let msg_envelope = MsgEnvelope::from(PacketMsg::from(msg));
+
+    let res = execute(&mut ctx, &mut router, msg_envelope);
+
+    assert!(res.is_ok(), "Execution happy path. err: {res:?}");
+
+    // The last in-flight packet was acknowledged.
+    let chan_end_on_a = ctx.channel_end(&chan_end_path_on_a).unwrap();
+
+    assert_eq!(chan_end_on_a.state(), &State::FlushComplete);
+}
+
+#[rstest]
+fn ack_flushing_chan_execute_keeps_flushing(fixture: Fixture) {
+    let other_sequence = fixture.msg.packet.seq_on_a.increment();
+    let (mut ctx, mut router, msg) = flushing_context(fixture, &[other_sequence]);
+
+    let chan_end_path_on_a =
+        ChannelEndPath::new(&msg.packet.port_id_on_a, &msg.packet.chan_id_on_a);
+
+    let msg_envelope = MsgEnvelope::from(PacketMsg::from(msg));
+
+    let res = execute(&mut ctx, &mut router, msg_envelope);
+
+    assert!(res.is_ok(), "Execution happy path. err: {res:?}");
+
+    // Another packet is still in flight.
+    let chan_end_on_a = ctx.channel_end(&chan_end_path_on_a).unwrap();
+
+    assert_eq!(chan_end_on_a.state(), &State::Flushing);
+}
+
+#[rstest]
+fn ack_fail_flush_complete_chan(fixture: Fixture) {
+    let Fixture {
+        ctx,
+        router,
+        msg,
+        packet_commitment,
+        conn_end_on_a,
+        mut chan_end_on_a_unordered,
+        ..
+    } = fixture;
+
+    chan_end_on_a_unordered.set_state(State::FlushComplete);
+
+    let ctx = ctx
+        .with_channel(
+            PortId::transfer(),
+            ChannelId::default(),
+            chan_end_on_a_unordered,
+        )
+        .with_connection(ConnectionId::default(), conn_end_on_a)
+        .with_packet_commitment(
+            msg.packet.port_id_on_a.clone(),
+            msg.packet.chan_id_on_a.clone(),
+            msg.packet.seq_on_a,
+            packet_commitment,
+        );
+
+    let msg_envelope = MsgEnvelope::from(PacketMsg::from(msg));
+
+    let res = validate(&ctx, &router, msg_envelope);
+
+    assert!(
+        res.is_err(),
+        "Validation fails because the channel is done flushing"
+    )
+}
@@ -1,9 +1,12 @@
 use ibc::clients::tendermint::types::client_type as tm_client_type;
+use ibc::core::channel::types::channel::Order;
 use ibc::core::channel::types::msgs::{ChannelMsg, MsgChannelOpenInit};
 use ibc::core::client::types::Height;
+use ibc::core::connection::types::error::ConnectionError;
 use ibc::core::connection::types::version::get_compatible_versions;
 use ibc::core::connection::types::{ConnectionEnd, State as ConnectionState};
 use ibc::core::entrypoint::{execute, validate};
+use ibc::core::handler::types::error::ContextError;
 use ibc::core::handler::types::events::{IbcEvent, MessageEvent};
 use ibc::core::handler::types::msgs::MsgEnvelope;
 use ibc::core::host::types::identifiers::ConnectionId;
@@ -121,3 +124,23 @@ fn chan_open_init_fail_no_connection(fixture: Fixture) {
         "Validation fails because no connection exists in the context"
     )
 }
+
+#[rstest]
+fn chan_open_init_fail_ordered_allow_timeout_not_supported(fixture: Fixture) {
+    let Fixture { ctx, router, .. } = fixture;
+
+    let mut msg = MsgChannelOpenInit::try_from(dummy_raw_msg_chan_open_init(None)).unwrap();
+    msg.ordering = Order::OrderedAllowTimeout;
+
+    let res = validate(&ctx, &router, MsgEnvelope::from(ChannelMsg::from(msg)));
+
+    assert!(
+        matches!(
+            res,
+            Err(ContextError::ConnectionError(
+                ConnectionError::FeatureNotSupported { .. }
+            ))
+        ),
+        "Validation fails because the connection does not opt into the ordering: {res:?}"
+    )
+}
@@ -0,0 +1,262 @@
+use ibc::core::channel::types::channel::{ChannelEnd, Counterparty, Order, State};
+use ibc::core::channel::types::msgs::{ChannelMsg, MsgChannelUpgradeConfirm};
+use ibc::core::channel::types::upgrade::{Upgrade, UpgradeFields, UpgradeTimeout};
+use ibc::core::channel::types::Version;
+use ibc::core::client::types::Height;
+use ibc::core::connection::types::version::get_compatible_versions;
+use ibc::core::connection::types::{
+    ConnectionEnd, Counterparty as ConnectionCounterparty, State as ConnectionState,
+};
+use ibc::core::entrypoint::{execute, validate};
+use ibc::core::handler::types::events::{IbcEvent, MessageEvent};
+use ibc::core::handler::types::msgs::MsgEnvelope;
+use ibc::core::host::types::identifiers::{ClientId, ConnectionId, Sequence};
+use ibc::core::host::types::path::{ChannelEndPath, ChannelUpgradeErrorPath, ChannelUpgradePath};
+use ibc::core::host::ValidationContext;
+use ibc::core::primitives::*;
+use ibc_testkit::fixtures::core::channel::dummy_raw_msg_chan_upgrade_confirm;
+use ibc_testkit::fixtures::core::connection::dummy_raw_counterparty_conn;
+use ibc_testkit::testapp::ibc::clients::mock::client_state::client_type as mock_client_type;
+use ibc_testkit::testapp::ibc::core::router::MockRouter;
+use ibc_testkit::testapp::ibc::core::types::{MockClientConfig, MockContext};
+use rstest::*;
+use test_log::test;
+
+pub struct Fixture {
+    pub context: MockContext,
+    pub router: MockRouter,
+    pub msg: MsgChannelUpgradeConfirm,
+}
+
+fn build_fixture(chan_state_on_a: State, timeout_timestamp_on_a: u64) -> Fixture {
+    let proof_height = 10;
+    let router = MockRouter::new_with_transfer();
+
+    let client_id_on_b: ClientId = mock_client_type().build_client_id(45);
+    let conn_id_on_b = ConnectionId::default();
+    let conn_end_on_b = ConnectionEnd::new(
+        ConnectionState::Open,
+        client_id_on_b.clone(),
+        ConnectionCounterparty::try_from(dummy_raw_counterparty_conn(Some(0))).unwrap(),
+        get_compatible_versions(),
+        ZERO_DURATION,
+    )
+    .unwrap();
+
+    let msg = MsgChannelUpgradeConfirm::try_from(dummy_raw_msg_chan_upgrade_confirm(
+        chan_state_on_a,
+        timeout_timestamp_on_a,
+        proof_height,
+    ))
+    .unwrap();
+
+    let chan_end_on_b = {
+        let mut chan_end = ChannelEnd::new(
+            State::Flushing,
+            Order::Unordered,
+            Counterparty::new(msg.port_id_on_b.clone(), Some(msg.chan_id_on_b.clone())),
+            vec![conn_id_on_b.clone()],
+            Version::new("ics20-1".to_string()),
+        )
+        .unwrap();
+        chan_end.set_upgrade_sequence(1);
+        chan_end
+    };
+
+    // The upgrade as stored by `ChanUpgradeTry`.
+    let upgrade_on_b = Upgrade::new(
+        UpgradeFields::new(
+            Order::Unordered,
+            vec![conn_id_on_b.clone()],
+            Version::new("ics20-2".to_string()),
+        ),
+        UpgradeTimeout::default(),
+        Sequence::from(1),
+    );
+
+    let context = MockContext::default()
+        .with_client_config(
+            MockClientConfig::builder()
+                .client_id(client_id_on_b)
+                .latest_height(Height::new(0, proof_height).unwrap())
+                .build(),
+        )
+        .with_connection(conn_id_on_b, conn_end_on_b)
+        .with_channel(
+            msg.port_id_on_b.clone(),
+            msg.chan_id_on_b.clone(),
+            chan_end_on_b,
+        )
+        .with_channel_upgrade(
+            msg.port_id_on_b.clone(),
+            msg.chan_id_on_b.clone(),
+            upgrade_on_b,
+        );
+
+    Fixture {
+        context,
+        router,
+        msg,
+    }
+}
+
+#[fixture]
+fn fixture() -> Fixture {
+    build_fixture(State::Flushing, u64::MAX)
+}
+
+#[rstest]
+fn chan_upgrade_confirm_validate_happy_path(fixture: Fixture) {
+    let Fixture {
+        context,
+        router,
+        msg,
+    } = fixture;
+
+    let msg_envelope = MsgEnvelope::from(ChannelMsg::from(msg));
+
+    let res = validate(&context, &router, msg_envelope);
+
+    assert!(res.is_ok(), "Validation happy path. Error: {res:?}")
+}
+
+#[rstest]
+fn chan_upgrade_confirm_execute_happy_path(fixture: Fixture) {
+    let Fixture {
+        mut context,
+        mut router,
+        msg,
+    } = fixture;
+
+    let msg_envelope = MsgEnvelope::from(ChannelMsg::from(msg.clone()));
+
+    let res = execute(&mut context, &mut router, msg_envelope);
+
+    assert!(res.is_ok(), "Execution happy path. Error: {res:?}");
+
+    let ibc_events = context.get_events();
+
+    assert_eq!(ibc_events.len(), 2);
+    assert!(matches!(
+        ibc_events[0],
+        IbcEvent::Message(MessageEvent::Channel)
+    ));
+    assert!(matches!(ibc_events[1], IbcEvent::UpgradeConfirmChannel(_)));
+
+    let chan_end_on_b = context
+        .channel_end(&ChannelEndPath::new(&msg.port_id_on_b, &msg.chan_id_on_b))
+        .unwrap();
+
+    // No packets are in flight, but the counterparty is still flushing.
+    assert_eq!(chan_end_on_b.state(), &State::FlushComplete);
+
+    let counterparty_upgrade = context
+        .counterparty_channel_upgrade(&ChannelUpgradePath::new(
+            &msg.port_id_on_b,
+            &msg.chan_id_on_b,
+        ))
+        .unwrap();
+
+    assert_eq!(counterparty_upgrade, msg.upgrade_on_a);
+}
+
+#[rstest]
+fn chan_upgrade_confirm_execute_opens_flushed_channel() {
+    let Fixture {
+        mut context,
+        mut router,
+        msg,
+    } = build_fixture(State::FlushComplete, u64::MAX);
+
+    let msg_envelope = MsgEnvelope::from(ChannelMsg::from(msg.clone()));
+
+    let res = validate(&context, &router, msg_envelope.clone());
+
+    assert!(res.is_ok(), "Validation happy path. Error: {res:?}");
+
+    let res = execute(&mut context, &mut router, msg_envelope);
+
+    assert!(res.is_ok(), "Execution happy path. Error: {res:?}");
+
+    let ibc_events = context.get_events();
+
+    assert!(matches!(
+        ibc_events.last(),
+        Some(IbcEvent::UpgradeOpenChannel(_))
+    ));
+
+    let chan_end_on_b = context
+        .channel_end(&ChannelEndPath::new(&msg.port_id_on_b, &msg.chan_id_on_b))
+        .unwrap();
+
+    // Both ends are done flushing, hence the upgrade completes right away.
+    assert_eq!(chan_end_on_b.state(), &State::Open);
+    assert_eq!(
+        chan_end_on_b.version(),
+        &Version::new("ics20-2".to_string())
+    );
+
+    assert!(context
+        .channel_upgrade(&ChannelUpgradePath::new(
+            &msg.port_id_on_b,
+            &msg.chan_id_on_b
+        ))
+        .is_err());
+}
+
+#[rstest]
+fn chan_upgrade_confirm_aborts_timed_out_counterparty_upgrade() {
+    let Fixture {
+        mut context,
+        mut router,
+        msg,
+    } = build_fixture(State::Flushing, 1);
+
+    let msg_envelope = MsgEnvelope::from(ChannelMsg::from(msg.clone()));
+
+    let res = validate(&context, &router, msg_envelope.clone());
+
+    assert!(
+        res.is_ok(),
+        "Validation succeeds to abort the upgrade. Error: {res:?}"
+    );
+
+    let res = execute(&mut context, &mut router, msg_envelope);
+
+    assert!(res.is_ok(), "Execution aborts the upgrade. Error: {res:?}");
+
+    let ibc_events = context.get_events();
+
+    assert_eq!(ibc_events.len(), 2);
+    assert!(matches!(
+        ibc_events[0],
+        IbcEvent::Message(MessageEvent::Channel)
+    ));
+    assert!(matches!(ibc_events[1], IbcEvent::UpgradeErrorChannel(_)));
+
+    let chan_end_on_b = context
+        .channel_end(&ChannelEndPath::new(&msg.port_id_on_b, &msg.chan_id_on_b))
+        .unwrap();
+
+    assert_eq!(chan_end_on_b.state(), &State::Open);
+    assert_eq!(
+        chan_end_on_b.version(),
+        &Version::new("ics20-1".to_string())
+    );
+
+    assert!(context
+        .channel_upgrade(&ChannelUpgradePath::new(
+            &msg.port_id_on_b,
+            &msg.chan_id_on_b
+        ))
+        .is_err());
+
+    let error_receipt = context
+        .channel_upgrade_error_receipt(&ChannelUpgradeErrorPath::new(
+            &msg.port_id_on_b,
+            &msg.chan_id_on_b,
+        ))
+        .unwrap();
+
+    assert_eq!(error_receipt.sequence, 1);
+}
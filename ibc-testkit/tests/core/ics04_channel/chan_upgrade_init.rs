@@ -0,0 +1,310 @@
+use ibc::core::channel::types::channel::{ChannelEnd, Counterparty, Order, State};
+use ibc::core::channel::types::msgs::{ChannelMsg, MsgChannelUpgradeInit};
+use ibc::core::channel::types::Version;
+use ibc::core::connection::types::version::get_compatible_versions;
+use ibc::core::connection::types::{
+    ConnectionEnd, Counterparty as ConnectionCounterparty, State as ConnectionState,
+};
+use ibc::core::entrypoint::{execute, validate};
+use ibc::core::handler::types::events::{IbcEvent, MessageEvent};
+use ibc::core::handler::types::msgs::MsgEnvelope;
+use ibc::core::host::types::identifiers::{ClientId, ConnectionId};
+use ibc::core::host::types::path::{ChannelEndPath, ChannelUpgradePath};
+use ibc::core::host::ValidationContext;
+use ibc::core::primitives::*;
+use ibc_testkit::fixtures::core::channel::dummy_raw_msg_chan_upgrade_init;
+use ibc_testkit::fixtures::core::connection::dummy_raw_counterparty_conn;
+use ibc_testkit::testapp::ibc::clients::mock::client_state::client_type as mock_client_type;
+use ibc_testkit::testapp::ibc::core::router::MockRouter;
+use ibc_testkit::testapp::ibc::core::types::{MockClientConfig, MockContext};
+use rstest::*;
+use test_log::test;
+
+pub struct Fixture {
+    pub context: MockContext,
+    pub router: MockRouter,
+    pub msg: MsgChannelUpgradeInit,
+    pub client_id_on_a: ClientId,
+    pub conn_id_on_a: ConnectionId,
+    pub conn_end_on_a: ConnectionEnd,
+    pub chan_end_on_a: ChannelEnd,
+}
+
+#[fixture]
+fn fixture() -> Fixture {
+    let router = MockRouter::new_with_transfer();
+
+    let client_id_on_a = mock_client_type().build_client_id(45);
+    let conn_id_on_a = ConnectionId::default();
+    let conn_end_on_a = ConnectionEnd::new(
+        ConnectionState::Open,
+        client_id_on_a.clone(),
+        ConnectionCounterparty::try_from(dummy_raw_counterparty_conn(Some(0))).unwrap(),
+        get_compatible_versions(),
+        ZERO_DURATION,
+    )
+    .unwrap();
+
+    let msg = MsgChannelUpgradeInit::try_from(dummy_raw_msg_chan_upgrade_init("ics20-2")).unwrap();
+
+    let context = MockContext::default().with_channel_upgrade_authority(msg.signer.clone());
+
+    let chan_end_on_a = ChannelEnd::new(
+        State::Open,
+        Order::Unordered,
+        Counterparty::new(msg.port_id_on_a.clone(), Some(msg.chan_id_on_a.clone())),
+        vec![conn_id_on_a.clone()],
+        Version::new("ics20-1".to_string()),
+    )
+    .unwrap();
+
+    Fixture {
+        context,
+        router,
+        msg,
+        client_id_on_a,
+        conn_id_on_a,
+        conn_end_on_a,
+        chan_end_on_a,
+    }
+}
+
+fn setup_context(
+    context: MockContext,
+    msg: &MsgChannelUpgradeInit,
+    client_id_on_a: ClientId,
+    conn_id_on_a: ConnectionId,
+    conn_end_on_a: ConnectionEnd,
+    chan_end_on_a: ChannelEnd,
+) -> MockContext {
+    let host_height = context.host_height().unwrap();
+
+    context
+        .with_client_config(
+            MockClientConfig::builder()
+                .client_id(client_id_on_a)
+                .latest_height(host_height)
+                .build(),
+        )
+        .with_connection(conn_id_on_a, conn_end_on_a)
+        .with_channel(
+            msg.port_id_on_a.clone(),
+            msg.chan_id_on_a.clone(),
+            chan_end_on_a,
+        )
+}
+
+#[rstest]
+fn chan_upgrade_init_validate_happy_path(fixture: Fixture) {
+    let Fixture {
+        context,
+        router,
+        msg,
+        client_id_on_a,
+        conn_id_on_a,
+        conn_end_on_a,
+        chan_end_on_a,
+    } = fixture;
+
+    let context = setup_context(
+        context,
+        &msg,
+        client_id_on_a,
+        conn_id_on_a,
+        conn_end_on_a,
+        chan_end_on_a,
+    );
+
+    let msg_envelope = MsgEnvelope::from(ChannelMsg::from(msg));
+
+    let res = validate(&context, &router, msg_envelope);
+
+    assert!(res.is_ok(), "Validation happy path. Error: {res:?}")
+}
+
+#[rstest]
+fn chan_upgrade_init_execute_happy_path(fixture: Fixture) {
+    let Fixture {
+        context,
+        mut router,
+        msg,
+        client_id_on_a,
+        conn_id_on_a,
+        conn_end_on_a,
+        chan_end_on_a,
+    } = fixture;
+
+    let mut context = setup_context(
+        context,
+        &msg,
+        client_id_on_a,
+        conn_id_on_a,
+        conn_end_on_a,
+        chan_end_on_a,
+    );
+
+    let msg_envelope = MsgEnvelope::from(ChannelMsg::from(msg.clone()));
+
+    let res = execute(&mut context, &mut router, msg_envelope);
+
+    assert!(res.is_ok(), "Execution happy path. Error: {res:?}");
+
+    let ibc_events = context.get_events();
+
+    assert_eq!(ibc_events.len(), 2);
+    assert!(matches!(
+        ibc_events[0],
+        IbcEvent::Message(MessageEvent::Channel)
+    ));
+    assert!(matches!(ibc_events[1], IbcEvent::UpgradeInitChannel(_)));
+
+    let chan_end_on_a = context
+        .channel_end(&ChannelEndPath::new(&msg.port_id_on_a, &msg.chan_id_on_a))
+        .unwrap();
+
+    // The channel stays open until the counterparty accepts the upgrade.
+    assert_eq!(chan_end_on_a.state(), &State::Open);
+    assert_eq!(chan_end_on_a.upgrade_sequence(), 1);
+
+    let upgrade_on_a = context
+        .channel_upgrade(&ChannelUpgradePath::new(
+            &msg.port_id_on_a,
+            &msg.chan_id_on_a,
+        ))
+        .unwrap();
+
+    assert_eq!(upgrade_on_a.fields, msg.fields);
+    assert!(!upgrade_on_a.timeout.is_set());
+}
+
+#[rstest]
+fn chan_upgrade_init_fail_channel_not_open(fixture: Fixture) {
+    let Fixture {
+        context,
+        router,
+        msg,
+        client_id_on_a,
+        conn_id_on_a,
+        conn_end_on_a,
+        mut chan_end_on_a,
+    } = fixture;
+
+    chan_end_on_a.set_state(State::Flushing);
+
+    let context = setup_context(
+        context,
+        &msg,
+        client_id_on_a,
+        conn_id_on_a,
+        conn_end_on_a,
+        chan_end_on_a,
+    );
+
+    let msg_envelope = MsgEnvelope::from(ChannelMsg::from(msg));
+
+    let res = validate(&context, &router, msg_envelope);
+
+    assert!(
+        res.is_err(),
+        "Validation fails because the channel is already being upgraded"
+    )
+}
+
+#[rstest]
+fn chan_upgrade_init_fail_fields_unchanged(fixture: Fixture) {
+    let Fixture {
+        context,
+        router,
+        msg,
+        client_id_on_a,
+        conn_id_on_a,
+        conn_end_on_a,
+        mut chan_end_on_a,
+    } = fixture;
+
+    chan_end_on_a.set_version(msg.fields.version.clone());
+
+    let context = setup_context(
+        context,
+        &msg,
+        client_id_on_a,
+        conn_id_on_a,
+        conn_end_on_a,
+        chan_end_on_a,
+    );
+
+    let msg_envelope = MsgEnvelope::from(ChannelMsg::from(msg));
+
+    let res = validate(&context, &router, msg_envelope);
+
+    assert!(
+        res.is_err(),
+        "Validation fails because the proposed fields are the current ones"
+    )
+}
+
+#[rstest]
+fn chan_upgrade_init_fail_unauthorized_signer(fixture: Fixture) {
+    let Fixture {
+        context,
+        router,
+        msg,
+        client_id_on_a,
+        conn_id_on_a,
+        conn_end_on_a,
+        chan_end_on_a,
+    } = fixture;
+
+    let context = setup_context(
+        context.with_channel_upgrade_authority(Signer::from("authority".to_string())),
+        &msg,
+        client_id_on_a,
+        conn_id_on_a,
+        conn_end_on_a,
+        chan_end_on_a,
+    );
+
+    let msg_envelope = MsgEnvelope::from(ChannelMsg::from(msg));
+
+    let res = validate(&context, &router, msg_envelope);
+
+    assert!(
+        res.is_err(),
+        "Validation fails because only the authority can initiate an upgrade"
+    )
+}
+
+#[rstest]
+fn chan_upgrade_init_fail_upgrade_in_progress(fixture: Fixture) {
+    let Fixture {
+        context,
+        mut router,
+        msg,
+        client_id_on_a,
+        conn_id_on_a,
+        conn_end_on_a,
+        chan_end_on_a,
+    } = fixture;
+
+    let mut context = setup_context(
+        context,
+        &msg,
+        client_id_on_a,
+        conn_id_on_a,
+        conn_end_on_a,
+        chan_end_on_a,
+    );
+
+    let msg_envelope = MsgEnvelope::from(ChannelMsg::from(msg));
+
+    let res = execute(&mut context, &mut router, msg_envelope.clone());
+
+    assert!(res.is_ok(), "Execution happy path. Error: {res:?}");
+
+    let res = validate(&context, &router, msg_envelope);
+
+    assert!(
+        res.is_err(),
+        "Validation fails because the ongoing upgrade is not cancelled"
+    )
+}
@@ -0,0 +1,193 @@
+use ibc::core::channel::types::channel::{ChannelEnd, Counterparty, Order, State};
+use ibc::core::channel::types::msgs::{ChannelMsg, MsgChannelUpgradeTimeout};
+use ibc::core::channel::types::timeout::TimeoutHeight;
+use ibc::core::channel::types::upgrade::{Upgrade, UpgradeFields, UpgradeTimeout};
+use ibc::core::channel::types::Version;
+use ibc::core::client::types::Height;
+use ibc::core::connection::types::version::get_compatible_versions;
+use ibc::core::connection::types::{
+    ConnectionEnd, Counterparty as ConnectionCounterparty, State as ConnectionState,
+};
+use ibc::core::entrypoint::{execute, validate};
+use ibc::core::handler::types::events::{IbcEvent, MessageEvent};
+use ibc::core::handler::types::msgs::MsgEnvelope;
+use ibc::core::host::types::identifiers::{ClientId, ConnectionId, Sequence};
+use ibc::core::host::types::path::{ChannelEndPath, ChannelUpgradeErrorPath, ChannelUpgradePath};
+use ibc::core::host::ValidationContext;
+use ibc::core::primitives::*;
+use ibc_testkit::fixtures::core::channel::dummy_raw_msg_chan_upgrade_timeout;
+use ibc_testkit::fixtures::core::connection::dummy_raw_counterparty_conn;
+use ibc_testkit::testapp::ibc::clients::mock::client_state::client_type as mock_client_type;
+use ibc_testkit::testapp::ibc::core::router::MockRouter;
+use ibc_testkit::testapp::ibc::core::types::{MockClientConfig, MockContext};
+use rstest::*;
+use test_log::test;
+
+pub struct Fixture {
+    pub context: MockContext,
+    pub router: MockRouter,
+    pub msg: MsgChannelUpgradeTimeout,
+}
+
+fn build_fixture(timeout_height_on_b: u64) -> Fixture {
+    let proof_height = 10;
+    let router = MockRouter::new_with_transfer();
+
+    let client_id_on_a: ClientId = mock_client_type().build_client_id(45);
+    let conn_id_on_a = ConnectionId::default();
+    let conn_end_on_a = ConnectionEnd::new(
+        ConnectionState::Open,
+        client_id_on_a.clone(),
+        ConnectionCounterparty::try_from(dummy_raw_counterparty_conn(Some(0))).unwrap(),
+        get_compatible_versions(),
+        ZERO_DURATION,
+    )
+    .unwrap();
+
+    let msg = MsgChannelUpgradeTimeout::try_from(dummy_raw_msg_chan_upgrade_timeout(
+        State::Flushing,
+        1,
+        proof_height,
+    ))
+    .unwrap();
+
+    let chan_end_on_a = {
+        let mut chan_end = ChannelEnd::new(
+            State::Flushing,
+            Order::Unordered,
+            Counterparty::new(msg.port_id_on_a.clone(), Some(msg.chan_id_on_a.clone())),
+            vec![conn_id_on_a.clone()],
+            Version::new("ics20-1".to_string()),
+        )
+        .unwrap();
+        chan_end.set_upgrade_sequence(1);
+        chan_end
+    };
+
+    let upgrade_on_a = Upgrade::new(
+        UpgradeFields::new(
+            Order::Unordered,
+            vec![conn_id_on_a.clone()],
+            Version::new("ics20-2".to_string()),
+        ),
+        UpgradeTimeout::new(
+            TimeoutHeight::At(Height::new(0, timeout_height_on_b).unwrap()),
+            Timestamp::none(),
+        ),
+        Sequence::from(1),
+    );
+
+    let context = MockContext::default()
+        .with_client_config(
+            MockClientConfig::builder()
+                .client_id(client_id_on_a)
+                .latest_height(Height::new(0, proof_height).unwrap())
+                .build(),
+        )
+        .with_connection(conn_id_on_a, conn_end_on_a)
+        .with_channel(
+            msg.port_id_on_a.clone(),
+            msg.chan_id_on_a.clone(),
+            chan_end_on_a,
+        )
+        .with_channel_upgrade(
+            msg.port_id_on_a.clone(),
+            msg.chan_id_on_a.clone(),
+            upgrade_on_a,
+        );
+
+    Fixture {
+        context,
+        router,
+        msg,
+    }
+}
+
+#[fixture]
+fn fixture() -> Fixture {
+    build_fixture(5)
+}
+
+#[rstest]
+fn chan_upgrade_timeout_validate_happy_path(fixture: Fixture) {
+    let Fixture {
+        context,
+        router,
+        msg,
+    } = fixture;
+
+    let msg_envelope = MsgEnvelope::from(ChannelMsg::from(msg));
+
+    let res = validate(&context, &router, msg_envelope);
+
+    assert!(res.is_ok(), "Validation happy path. Error: {res:?}")
+}
+
+#[rstest]
+fn chan_upgrade_timeout_execute_happy_path(fixture: Fixture) {
+    let Fixture {
+        mut context,
+        mut router,
+        msg,
+    } = fixture;
+
+    let msg_envelope = MsgEnvelope::from(ChannelMsg::from(msg.clone()));
+
+    let res = execute(&mut context, &mut router, msg_envelope);
+
+    assert!(res.is_ok(), "Execution happy path. Error: {res:?}");
+
+    let ibc_events = context.get_events();
+
+    assert_eq!(ibc_events.len(), 3);
+    assert!(matches!(
+        ibc_events[0],
+        IbcEvent::Message(MessageEvent::Channel)
+    ));
+    assert!(matches!(ibc_events[1], IbcEvent::UpgradeErrorChannel(_)));
+    assert!(matches!(ibc_events[2], IbcEvent::UpgradeTimeoutChannel(_)));
+
+    let chan_end_on_a = context
+        .channel_end(&ChannelEndPath::new(&msg.port_id_on_a, &msg.chan_id_on_a))
+        .unwrap();
+
+    assert_eq!(chan_end_on_a.state(), &State::Open);
+    assert_eq!(
+        chan_end_on_a.version(),
+        &Version::new("ics20-1".to_string())
+    );
+
+    assert!(context
+        .channel_upgrade(&ChannelUpgradePath::new(
+            &msg.port_id_on_a,
+            &msg.chan_id_on_a
+        ))
+        .is_err());
+
+    let error_receipt = context
+        .channel_upgrade_error_receipt(&ChannelUpgradeErrorPath::new(
+            &msg.port_id_on_a,
+            &msg.chan_id_on_a,
+        ))
+        .unwrap();
+
+    assert_eq!(error_receipt.sequence, 1);
+}
+
+#[rstest]
+fn chan_upgrade_timeout_fail_timeout_not_reached() {
+    let Fixture {
+        context,
+        router,
+        msg,
+    } = build_fixture(20);
+
+    let msg_envelope = MsgEnvelope::from(ChannelMsg::from(msg));
+
+    let res = validate(&context, &router, msg_envelope);
+
+    assert!(
+        res.is_err(),
+        "Validation fails because the upgrade did not time out yet"
+    )
+}
@@ -0,0 +1,260 @@
+use ibc::core::channel::types::channel::{ChannelEnd, Counterparty, Order, State};
+use ibc::core::channel::types::msgs::{ChannelMsg, MsgChannelUpgradeTry};
+use ibc::core::channel::types::upgrade::{Upgrade, UpgradeTimeout};
+use ibc::core::channel::types::Version;
+use ibc::core::client::types::Height;
+use ibc::core::connection::types::version::get_compatible_versions;
+use ibc::core::connection::types::{
+    ConnectionEnd, Counterparty as ConnectionCounterparty, State as ConnectionState,
+};
+use ibc::core::entrypoint::{execute, validate};
+use ibc::core::handler::types::events::{IbcEvent, MessageEvent};
+use ibc::core::handler::types::msgs::MsgEnvelope;
+use ibc::core::host::types::identifiers::{ClientId, ConnectionId, Sequence};
+use ibc::core::host::types::path::{ChannelEndPath, ChannelUpgradeErrorPath, ChannelUpgradePath};
+use ibc::core::host::ValidationContext;
+use ibc::core::primitives::*;
+use ibc_testkit::fixtures::core::channel::dummy_raw_msg_chan_upgrade_try;
+use ibc_testkit::fixtures::core::connection::dummy_raw_counterparty_conn;
+use ibc_testkit::testapp::ibc::clients::mock::client_state::client_type as mock_client_type;
+use ibc_testkit::testapp::ibc::core::router::MockRouter;
+use ibc_testkit::testapp::ibc::core::types::{MockClientConfig, MockContext};
+use rstest::*;
+use test_log::test;
+
+pub struct Fixture {
+    pub context: MockContext,
+    pub router: MockRouter,
+    pub msg: MsgChannelUpgradeTry,
+    pub chan_end_on_b: ChannelEnd,
+}
+
+#[fixture]
+fn fixture() -> Fixture {
+    let proof_height = 10;
+    let router = MockRouter::new_with_transfer();
+
+    let client_id_on_b: ClientId = mock_client_type().build_client_id(45);
+    let conn_id_on_b = ConnectionId::default();
+    let conn_end_on_b = ConnectionEnd::new(
+        ConnectionState::Open,
+        client_id_on_b.clone(),
+        ConnectionCounterparty::try_from(dummy_raw_counterparty_conn(Some(0))).unwrap(),
+        get_compatible_versions(),
+        ZERO_DURATION,
+    )
+    .unwrap();
+
+    let msg =
+        MsgChannelUpgradeTry::try_from(dummy_raw_msg_chan_upgrade_try("ics20-2", 1, proof_height))
+            .unwrap();
+
+    let chan_end_on_b = ChannelEnd::new(
+        State::Open,
+        Order::Unordered,
+        Counterparty::new(msg.port_id_on_b.clone(), Some(msg.chan_id_on_b.clone())),
+        vec![conn_id_on_b.clone()],
+        Version::new("ics20-1".to_string()),
+    )
+    .unwrap();
+
+    let context = MockContext::default()
+        .with_client_config(
+            MockClientConfig::builder()
+                .client_id(client_id_on_b)
+                .latest_height(Height::new(0, proof_height).unwrap())
+                .build(),
+        )
+        .with_connection(conn_id_on_b, conn_end_on_b)
+        .with_send_sequence(
+            msg.port_id_on_b.clone(),
+            msg.chan_id_on_b.clone(),
+            Sequence::from(3),
+        );
+
+    Fixture {
+        context,
+        router,
+        msg,
+        chan_end_on_b,
+    }
+}
+
+#[rstest]
+fn chan_upgrade_try_validate_happy_path(fixture: Fixture) {
+    let Fixture {
+        context,
+        router,
+        msg,
+        chan_end_on_b,
+    } = fixture;
+
+    let context = context.with_channel(
+        msg.port_id_on_b.clone(),
+        msg.chan_id_on_b.clone(),
+        chan_end_on_b,
+    );
+
+    let msg_envelope = MsgEnvelope::from(ChannelMsg::from(msg));
+
+    let res = validate(&context, &router, msg_envelope);
+
+    assert!(res.is_ok(), "Validation happy path. Error: {res:?}")
+}
+
+#[rstest]
+fn chan_upgrade_try_execute_happy_path(fixture: Fixture) {
+    let Fixture {
+        context,
+        mut router,
+        msg,
+        chan_end_on_b,
+    } = fixture;
+
+    let mut context = context.with_channel(
+        msg.port_id_on_b.clone(),
+        msg.chan_id_on_b.clone(),
+        chan_end_on_b,
+    );
+
+    let msg_envelope = MsgEnvelope::from(ChannelMsg::from(msg.clone()));
+
+    let res = execute(&mut context, &mut router, msg_envelope);
+
+    assert!(res.is_ok(), "Execution happy path. Error: {res:?}");
+
+    let ibc_events = context.get_events();
+
+    assert_eq!(ibc_events.len(), 2);
+    assert!(matches!(
+        ibc_events[0],
+        IbcEvent::Message(MessageEvent::Channel)
+    ));
+    assert!(matches!(ibc_events[1], IbcEvent::UpgradeTryChannel(_)));
+
+    let chan_end_on_b = context
+        .channel_end(&ChannelEndPath::new(&msg.port_id_on_b, &msg.chan_id_on_b))
+        .unwrap();
+
+    assert_eq!(chan_end_on_b.state(), &State::Flushing);
+    assert_eq!(chan_end_on_b.upgrade_sequence(), msg.upgrade_sequence_on_a);
+
+    let upgrade_on_b = context
+        .channel_upgrade(&ChannelUpgradePath::new(
+            &msg.port_id_on_b,
+            &msg.chan_id_on_b,
+        ))
+        .unwrap();
+
+    assert_eq!(upgrade_on_b.fields.version, msg.upgrade_fields_on_a.version);
+    assert_eq!(upgrade_on_b.next_sequence_send, Sequence::from(3));
+    assert!(upgrade_on_b.timeout.is_set());
+}
+
+#[rstest]
+fn chan_upgrade_try_fail_outdated_upgrade_sequence(fixture: Fixture) {
+    let Fixture {
+        context,
+        router,
+        msg,
+        mut chan_end_on_b,
+    } = fixture;
+
+    chan_end_on_b.set_upgrade_sequence(5);
+
+    let context = context.with_channel(
+        msg.port_id_on_b.clone(),
+        msg.chan_id_on_b.clone(),
+        chan_end_on_b,
+    );
+
+    let msg_envelope = MsgEnvelope::from(ChannelMsg::from(msg));
+
+    let res = validate(&context, &router, msg_envelope);
+
+    assert!(
+        res.is_err(),
+        "Validation fails because the counterparty upgrade sequence is outdated"
+    )
+}
+
+#[rstest]
+fn chan_upgrade_try_aborts_incompatible_ordering(fixture: Fixture) {
+    let Fixture {
+        context,
+        mut router,
+        mut msg,
+        chan_end_on_b,
+    } = fixture;
+
+    let proposed_fields = {
+        let mut fields = chan_end_on_b.upgrade_fields();
+        fields.ordering = Order::Ordered;
+        fields
+    };
+
+    let mut context = context
+        .with_channel(
+            msg.port_id_on_b.clone(),
+            msg.chan_id_on_b.clone(),
+            chan_end_on_b,
+        )
+        .with_channel_upgrade(
+            msg.port_id_on_b.clone(),
+            msg.chan_id_on_b.clone(),
+            Upgrade::new(
+                proposed_fields,
+                UpgradeTimeout::default(),
+                Sequence::from(0),
+            ),
+        );
+
+    msg.upgrade_sequence_on_a = 0;
+
+    let msg_envelope = MsgEnvelope::from(ChannelMsg::from(msg.clone()));
+
+    let res = validate(&context, &router, msg_envelope.clone());
+
+    assert!(
+        res.is_ok(),
+        "Validation succeeds to abort the upgrade. Error: {res:?}"
+    );
+
+    let res = execute(&mut context, &mut router, msg_envelope);
+
+    assert!(res.is_ok(), "Execution aborts the upgrade. Error: {res:?}");
+
+    let ibc_events = context.get_events();
+
+    assert_eq!(ibc_events.len(), 2);
+    assert!(matches!(
+        ibc_events[0],
+        IbcEvent::Message(MessageEvent::Channel)
+    ));
+    assert!(matches!(ibc_events[1], IbcEvent::UpgradeErrorChannel(_)));
+
+    let chan_end_on_b = context
+        .channel_end(&ChannelEndPath::new(&msg.port_id_on_b, &msg.chan_id_on_b))
+        .unwrap();
+
+    assert_eq!(chan_end_on_b.state(), &State::Open);
+    assert_eq!(chan_end_on_b.ordering(), &Order::Unordered);
+
+    assert!(context
+        .channel_upgrade(&ChannelUpgradePath::new(
+            &msg.port_id_on_b,
+            &msg.chan_id_on_b
+        ))
+        .is_err());
+
+    // The error receipt is written at the sequence of the counterparty, which
+    // can cancel its upgrade with it.
+    let error_receipt = context
+        .channel_upgrade_error_receipt(&ChannelUpgradeErrorPath::new(
+            &msg.port_id_on_b,
+            &msg.chan_id_on_b,
+        ))
+        .unwrap();
+
+    assert_eq!(error_receipt.sequence, msg.upgrade_sequence_on_a);
+}
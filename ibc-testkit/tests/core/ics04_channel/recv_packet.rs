@@ -1,6 +1,8 @@
+use ibc::apps::transfer::types::MODULE_ID_STR;
 use ibc::core::channel::types::channel::{ChannelEnd, Counterparty, Order, State};
 use ibc::core::channel::types::msgs::{MsgRecvPacket, PacketMsg};
-use ibc::core::channel::types::packet::Packet;
+use ibc::core::channel::types::packet::{Packet, Receipt};
+use ibc::core::channel::types::upgrade::{Upgrade, UpgradeTimeout};
 use ibc::core::channel::types::Version;
 use ibc::core::client::context::ClientExecutionContext;
 use ibc::core::client::types::Height;
@@ -11,13 +13,17 @@ use ibc::core::connection::types::{
 use ibc::core::entrypoint::{execute, validate};
 use ibc::core::handler::types::events::{IbcEvent, MessageEvent};
 use ibc::core::handler::types::msgs::MsgEnvelope;
-use ibc::core::host::types::identifiers::{ChannelId, ClientId, ConnectionId, PortId};
-use ibc::core::host::ExecutionContext;
+use ibc::core::host::types::identifiers::{ChannelId, ClientId, ConnectionId, PortId, Sequence};
+use ibc::core::host::types::path::{AckPath, ChannelUpgradePath, ReceiptPath, SeqRecvPath};
+use ibc::core::host::{ExecutionContext, ValidationContext};
 use ibc::core::primitives::*;
+use ibc::core::router::middleware::Middleware;
+use ibc::core::router::types::module::ModuleId;
 use ibc_testkit::fixtures::core::channel::{dummy_msg_recv_packet, dummy_raw_msg_recv_packet};
 use ibc_testkit::fixtures::core::signer::dummy_account_id;
 use ibc_testkit::relayer::context::RelayerContext;
-use ibc_testkit::testapp::ibc::core::router::MockRouter;
+use ibc_testkit::testapp::ibc::applications::transfer::types::DummyTransferModule;
+use ibc_testkit::testapp::ibc::core::router::{DummyMiddleware, MockRouter};
 use ibc_testkit::testapp::ibc::core::types::{MockClientConfig, MockContext};
 use rstest::*;
 use test_log::test;
@@ -257,3 +263,279 @@ fn recv_packet_execute_happy_path(fixture: Fixture) {
     ));
     assert!(matches!(&ibc_events[3], &IbcEvent::WriteAcknowledgement(_)));
 }
+
+#[rstest]
+fn recv_packet_timeout_expired_ordered_allow_timeout(fixture: Fixture) {
+    let Fixture {
+        context,
+        mut router,
+        msg,
+        conn_end_on_b,
+        chan_end_on_b,
+        client_height,
+        host_height,
+        ..
+    } = fixture;
+
+    let packet_old = Packet {
+        seq_on_a: 1.into(),
+        port_id_on_a: PortId::transfer(),
+        chan_id_on_a: ChannelId::default(),
+        port_id_on_b: PortId::transfer(),
+        chan_id_on_b: ChannelId::default(),
+        data: Vec::new(),
+        timeout_height_on_b: client_height.into(),
+        timeout_timestamp_on_b: Timestamp::from_nanoseconds(1).unwrap(),
+    };
+
+    let msg_packet_old = dummy_msg_recv_packet(
+        packet_old.clone(),
+        msg.proof_commitment_on_a.clone(),
+        msg.proof_height_on_a,
+        dummy_account_id(),
+    );
+
+    let mut chan_end_on_b = chan_end_on_b;
+    chan_end_on_b.set_ordering(Order::OrderedAllowTimeout);
+
+    let mut context = context
+        .with_client_config(
+            MockClientConfig::builder()
+                .latest_height(client_height)
+                .build(),
+        )
+        .with_connection(ConnectionId::default(), conn_end_on_b)
+        .with_channel(PortId::transfer(), ChannelId::default(), chan_end_on_b)
+        .with_send_sequence(PortId::transfer(), ChannelId::default(), 1.into())
+        .with_recv_sequence(PortId::transfer(), ChannelId::default(), 1.into())
+        .with_height(host_height);
+
+    context
+        .get_client_execution_context()
+        .store_update_time(
+            ClientId::default(),
+            client_height,
+            Timestamp::from_nanoseconds(1000).unwrap(),
+        )
+        .unwrap();
+    context
+        .get_client_execution_context()
+        .store_update_height(
+            ClientId::default(),
+            client_height,
+            Height::new(0, 5).unwrap(),
+        )
+        .unwrap();
+
+    let msg_envelope = MsgEnvelope::from(PacketMsg::from(msg_packet_old));
+
+    let res = validate(&context, &router, msg_envelope.clone());
+
+    assert!(
+        res.is_ok(),
+        "channels allowing timeouts receive timed out packets. err: {res:?}"
+    );
+
+    let res = execute(&mut context, &mut router, msg_envelope);
+
+    assert!(res.is_ok());
+
+    let next_seq_recv = context
+        .get_next_sequence_recv(&SeqRecvPath::new(
+            &PortId::transfer(),
+            &ChannelId::default(),
+        ))
+        .unwrap();
+    assert_eq!(next_seq_recv, 2.into());
+
+    let receipt = context
+        .get_packet_receipt(&ReceiptPath::new(
+            &PortId::transfer(),
+            &ChannelId::default(),
+            packet_old.seq_on_a,
+        ))
+        .unwrap();
+    assert_eq!(receipt, Receipt::Timeout);
+
+    // The timed out packet is not passed to the module, hence no
+    // acknowledgement is written.
+    let ibc_events = context.get_events();
+
+    assert_eq!(ibc_events.len(), 2);
+    assert!(matches!(&ibc_events[1], &IbcEvent::ReceivePacket(_)));
+}
+
+#[rstest]
+fn recv_packet_execute_async_ack(fixture: Fixture) {
+    let Fixture {
+        context,
+        msg,
+        conn_end_on_b,
+        chan_end_on_b,
+        client_height,
+        ..
+    } = fixture;
+
+    // The middleware holds the acknowledgement of the transfer module, to be
+    // written later on.
+    let module_id = ModuleId::new(MODULE_ID_STR.to_string());
+    let mut router = MockRouter::default();
+    router.scope_port_to_module(PortId::transfer(), module_id.clone());
+    router
+        .add_route(
+            module_id,
+            DummyMiddleware::new(1)
+                .holding_acks()
+                .wrap(DummyTransferModule::new()),
+        )
+        .unwrap();
+
+    let mut ctx = context
+        .with_client_config(
+            MockClientConfig::builder()
+                .latest_height(client_height)
+                .build(),
+        )
+        .with_connection(ConnectionId::default(), conn_end_on_b)
+        .with_channel(PortId::transfer(), ChannelId::default(), chan_end_on_b);
+
+    let packet = msg.packet.clone();
+    let msg_env = MsgEnvelope::from(PacketMsg::from(msg));
+
+    let res = execute(&mut ctx, &mut router, msg_env);
+
+    assert!(res.is_ok());
+
+    assert!(ctx
+        .get_packet_receipt(&ReceiptPath::new(
+            &packet.port_id_on_b,
+            &packet.chan_id_on_b,
+            packet.seq_on_a,
+        ))
+        .is_ok());
+    assert!(ctx
+        .get_packet_acknowledgement(&AckPath::new(
+            &packet.port_id_on_b,
+            &packet.chan_id_on_b,
+            packet.seq_on_a,
+        ))
+        .is_err());
+
+    let ibc_events = ctx.get_events();
+
+    assert_eq!(ibc_events.len(), 2);
+    assert!(matches!(
+        &ibc_events[0],
+        &IbcEvent::Message(MessageEvent::Channel)
+    ));
+    assert!(matches!(&ibc_events[1], &IbcEvent::ReceivePacket(_)));
+}
+
+/// Returns a context where the channel is flushing, the counterparty having
+/// started to flush at `counterparty_next_sequence_send`.
+fn flushing_context(fixture: Fixture, counterparty_next_sequence_send: Sequence) -> Fixture {
+    let Fixture {
+        context,
+        router,
+        msg,
+        conn_end_on_b,
+        mut chan_end_on_b,
+        client_height,
+        host_height,
+    } = fixture;
+
+    chan_end_on_b.set_state(State::Flushing);
+
+    let packet = &msg.packet;
+    let mut context = context
+        .with_client_config(
+            MockClientConfig::builder()
+                .latest_height(client_height)
+                .build(),
+        )
+        .with_connection(ConnectionId::default(), conn_end_on_b.clone())
+        .with_channel(
+            packet.port_id_on_b.clone(),
+            packet.chan_id_on_b.clone(),
+            chan_end_on_b.clone(),
+        )
+        .with_height(host_height);
+
+    context
+        .get_client_execution_context()
+        .store_update_time(
+            ClientId::default(),
+            client_height,
+            Timestamp::from_nanoseconds(1000).unwrap(),
+        )
+        .unwrap();
+    context
+        .get_client_execution_context()
+        .store_update_height(
+            ClientId::default(),
+            client_height,
+            Height::new(0, 5).unwrap(),
+        )
+        .unwrap();
+
+    context
+        .store_counterparty_channel_upgrade(
+            &ChannelUpgradePath::new(&packet.port_id_on_b, &packet.chan_id_on_b),
+            Upgrade::new(
+                chan_end_on_b.upgrade_fields(),
+                UpgradeTimeout::default(),
+                counterparty_next_sequence_send,
+            ),
+        )
+        .unwrap();
+
+    Fixture {
+        context,
+        router,
+        msg,
+        conn_end_on_b,
+        chan_end_on_b,
+        client_height,
+        host_height,
+    }
+}
+
+#[rstest]
+fn recv_packet_flushing_chan_validate(fixture: Fixture) {
+    let counterparty_next_sequence_send = fixture.msg.packet.seq_on_a.increment();
+    let Fixture {
+        context,
+        router,
+        msg,
+        ..
+    } = flushing_context(fixture, counterparty_next_sequence_send);
+
+    let msg_envelope = MsgEnvelope::from(PacketMsg::from(msg));
+
+    let res = validate(&context, &router, msg_envelope);
+
+    assert!(
+        res.is_ok(),
+        "Packets sent before the counterparty started flushing are received. err: {res:?}"
+    )
+}
+
+#[rstest]
+fn recv_packet_fail_sent_after_flush_started(fixture: Fixture) {
+    let counterparty_next_sequence_send = fixture.msg.packet.seq_on_a;
+    let Fixture {
+        context,
+        router,
+        msg,
+        ..
+    } = flushing_context(fixture, counterparty_next_sequence_send);
+
+    let msg_envelope = MsgEnvelope::from(PacketMsg::from(msg));
+
+    let res = validate(&context, &router, msg_envelope);
+
+    assert!(
+        res.is_err(),
+        "Validation fails because the packet was sent after the counterparty started flushing"
+    )
+}
@@ -1,9 +1,11 @@
 use ibc::core::channel::types::channel::{ChannelEnd, Counterparty, Order, State};
 use ibc::core::channel::types::commitment::{compute_packet_commitment, PacketCommitment};
 use ibc::core::channel::types::msgs::{MsgTimeout, PacketMsg};
+use ibc::core::channel::types::upgrade::{Upgrade, UpgradeTimeout};
 use ibc::core::channel::types::Version;
 use ibc::core::client::context::ClientExecutionContext;
 use ibc::core::client::types::Height;
+use ibc::core::commitment_types::merkle::HostFunctionsManager;
 use ibc::core::connection::types::version::get_compatible_versions;
 use ibc::core::connection::types::{
     ConnectionEnd, Counterparty as ConnectionCounterparty, State as ConnectionState,
@@ -11,8 +13,11 @@ use ibc::core::connection::types::{
 use ibc::core::entrypoint::{execute, validate};
 use ibc::core::handler::types::events::{IbcEvent, MessageEvent};
 use ibc::core::handler::types::msgs::MsgEnvelope;
-use ibc::core::host::types::identifiers::{ChannelId, ClientId, ConnectionId, PortId};
-use ibc::core::host::ExecutionContext;
+use ibc::core::host::types::identifiers::{ChannelId, ClientId, ConnectionId, PortId, Sequence};
+use ibc::core::host::types::path::{
+    ChannelEndPath, ChannelUpgradeErrorPath, ChannelUpgradePath, SeqAckPath,
+};
+use ibc::core::host::{ExecutionContext, ValidationContext};
 use ibc::core::primitives::*;
 use ibc_testkit::fixtures::core::channel::dummy_raw_msg_timeout;
 use ibc_testkit::testapp::ibc::core::router::MockRouter;
@@ -56,7 +61,7 @@ fn fixture() -> Fixture {
 
     let packet = msg.packet.clone();
 
-    let packet_commitment = compute_packet_commitment(
+    let packet_commitment = compute_packet_commitment::<HostFunctionsManager>(
         &msg.packet.data,
         &msg.packet.timeout_height_on_b,
         &msg.packet.timeout_timestamp_on_b,
@@ -176,7 +181,7 @@ fn timeout_fail_proof_timeout_not_reached(fixture: Fixture) {
     let timeout_timestamp_on_b =
         (msg.packet.timeout_timestamp_on_b + core::time::Duration::new(10, 0)).unwrap();
     msg.packet.timeout_timestamp_on_b = timeout_timestamp_on_b;
-    let packet_commitment = compute_packet_commitment(
+    let packet_commitment = compute_packet_commitment::<HostFunctionsManager>(
         &msg.packet.data,
         &msg.packet.timeout_height_on_b,
         &msg.packet.timeout_timestamp_on_b,
@@ -453,3 +458,202 @@ fn timeout_ordered_chan_execute(fixture: Fixture) {
     ));
     assert!(matches!(ibc_events[3], IbcEvent::ChannelClosed(_)));
 }
+
+#[rstest]
+fn timeout_ordered_allow_timeout_chan_execute(fixture: Fixture) {
+    let Fixture {
+        ctx,
+        mut router,
+        msg,
+        packet_commitment,
+        conn_end_on_a,
+        chan_end_on_a_ordered,
+        ..
+    } = fixture;
+
+    let mut chan_end_on_a = chan_end_on_a_ordered;
+    chan_end_on_a.ordering = Order::OrderedAllowTimeout;
+
+    let mut ctx = ctx
+        .with_channel(PortId::transfer(), ChannelId::default(), chan_end_on_a)
+        .with_connection(ConnectionId::default(), conn_end_on_a)
+        .with_packet_commitment(
+            msg.packet.port_id_on_a.clone(),
+            msg.packet.chan_id_on_a.clone(),
+            msg.packet.seq_on_a,
+            packet_commitment,
+        )
+        .with_ack_sequence(
+            msg.packet.port_id_on_a.clone(),
+            msg.packet.chan_id_on_a.clone(),
+            msg.packet.seq_on_a,
+        );
+
+    let seq_ack_path = SeqAckPath::new(&msg.packet.port_id_on_a, &msg.packet.chan_id_on_a);
+    let next_seq_ack = msg.packet.seq_on_a.increment();
+    let msg_envelope = MsgEnvelope::from(PacketMsg::from(msg));
+
+    let res = execute(&mut ctx, &mut router, msg_envelope);
+
+    assert!(res.is_ok());
+
+    // The timeout does not close channels allowing timeouts, but advances
+    // their next sequence to acknowledge.
+    assert_eq!(
+        ctx.get_next_sequence_ack(&seq_ack_path).unwrap(),
+        next_seq_ack
+    );
+
+    let chan_end_on_a = ctx
+        .channel_end(&ChannelEndPath::new(
+            &PortId::transfer(),
+            &ChannelId::default(),
+        ))
+        .unwrap();
+    assert_eq!(chan_end_on_a.state(), &State::Open);
+
+    let ibc_events = ctx.get_events();
+
+    assert_eq!(ibc_events.len(), 2);
+    assert!(matches!(ibc_events[1], IbcEvent::TimeoutPacket(_)));
+}
+
+/// Returns a context where the given channel is flushing, with the packet of
+/// the message as the last one in flight.
+fn flushing_context(
+    fixture: Fixture,
+    mut chan_end_on_a: ChannelEnd,
+) -> (MockContext, MockRouter, MsgTimeout) {
+    let Fixture {
+        ctx,
+        router,
+        msg,
+        packet_commitment,
+        conn_end_on_a,
+        ..
+    } = fixture;
+
+    chan_end_on_a.set_state(State::Flushing);
+
+    let packet = &msg.packet;
+    let mut ctx = ctx
+        .with_channel(
+            packet.port_id_on_a.clone(),
+            packet.chan_id_on_a.clone(),
+            chan_end_on_a.clone(),
+        )
+        .with_connection(ConnectionId::default(), conn_end_on_a)
+        .with_packet_commitment(
+            packet.port_id_on_a.clone(),
+            packet.chan_id_on_a.clone(),
+            packet.seq_on_a,
+            packet_commitment,
+        );
+
+    // The upgrades as stored by `ChanUpgradeInit` and `ChanUpgradeAck`.
+    let upgrade = Upgrade::new(
+        chan_end_on_a.upgrade_fields(),
+        UpgradeTimeout::default(),
+        Sequence::from(1),
+    );
+    let upgrade_path_on_a = ChannelUpgradePath::new(&packet.port_id_on_a, &packet.chan_id_on_a);
+    ctx.store_channel_upgrade(&upgrade_path_on_a, upgrade.clone())
+        .unwrap();
+    ctx.store_counterparty_channel_upgrade(&upgrade_path_on_a, upgrade)
+        .unwrap();
+
+    (ctx, router, msg)
+}
+
+#[rstest]
+fn timeout_flushing_chan_execute_completes_flush(fixture: Fixture) {
+    let chan_end_on_a = fixture.chan_end_on_a_unordered.clone();
+    let (mut ctx, mut router, msg) = flushing_context(fixture, chan_end_on_a);
+
+    let msg_envelope = MsgEnvelope::from(PacketMsg::from(msg));
+
+    let res = execute(&mut ctx, &mut router, msg_envelope);
+
+    assert!(res.is_ok(), "Execution happy path. err: {res:?}");
+
+    // The last in-flight packet timed out.
+    let chan_end_on_a = ctx
+        .channel_end(&ChannelEndPath::new(
+            &PortId::transfer(),
+            &ChannelId::default(),
+        ))
+        .unwrap();
+    assert_eq!(chan_end_on_a.state(), &State::FlushComplete);
+}
+
+#[rstest]
+fn timeout_ordered_flushing_chan_execute_aborts_upgrade(fixture: Fixture) {
+    let chan_end_on_a = fixture.chan_end_on_a_ordered.clone();
+    let (mut ctx, mut router, msg) = flushing_context(fixture, chan_end_on_a);
+
+    let msg_envelope = MsgEnvelope::from(PacketMsg::from(msg));
+
+    let res = execute(&mut ctx, &mut router, msg_envelope);
+
+    assert!(res.is_ok(), "Execution happy path. err: {res:?}");
+
+    // The ordered channel is closed, hence the upgrade is aborted.
+    let chan_end_on_a = ctx
+        .channel_end(&ChannelEndPath::new(
+            &PortId::transfer(),
+            &ChannelId::default(),
+        ))
+        .unwrap();
+    assert_eq!(chan_end_on_a.state(), &State::Closed);
+
+    assert!(ctx
+        .channel_upgrade(&ChannelUpgradePath::new(
+            &PortId::transfer(),
+            &ChannelId::default(),
+        ))
+        .is_err());
+    assert!(ctx
+        .channel_upgrade_error_receipt(&ChannelUpgradeErrorPath::new(
+            &PortId::transfer(),
+            &ChannelId::default(),
+        ))
+        .is_ok());
+}
+
+#[rstest]
+fn timeout_fail_flush_complete_chan(fixture: Fixture) {
+    let Fixture {
+        ctx,
+        router,
+        msg,
+        packet_commitment,
+        conn_end_on_a,
+        mut chan_end_on_a_unordered,
+        ..
+    } = fixture;
+
+    chan_end_on_a_unordered.set_state(State::FlushComplete);
+
+    let ctx = ctx
+        .with_channel(
+            PortId::transfer(),
+            ChannelId::default(),
+            chan_end_on_a_unordered,
+        )
+        .with_connection(ConnectionId::default(), conn_end_on_a)
+        .with_packet_commitment(
+            msg.packet.port_id_on_a.clone(),
+            msg.packet.chan_id_on_a.clone(),
+            msg.packet.seq_on_a,
+            packet_commitment,
+        );
+
+    let msg_envelope = MsgEnvelope::from(PacketMsg::from(msg));
+
+    let res = validate(&ctx, &router, msg_envelope);
+
+    assert!(
+        res.is_err(),
+        "Validation fails because the channel is done flushing"
+    )
+}
@@ -4,6 +4,7 @@ use ibc::core::channel::types::msgs::{MsgTimeoutOnClose, PacketMsg};
 use ibc::core::channel::types::Version;
 use ibc::core::client::context::ClientExecutionContext;
 use ibc::core::client::types::Height;
+use ibc::core::commitment_types::merkle::HostFunctionsManager;
 use ibc::core::connection::types::version::get_compatible_versions;
 use ibc::core::connection::types::{
     ConnectionEnd, Counterparty as ConnectionCounterparty, State as ConnectionState,
@@ -46,7 +47,7 @@ fn fixture() -> Fixture {
 
     let packet = msg.packet.clone();
 
-    let packet_commitment = compute_packet_commitment(
+    let packet_commitment = compute_packet_commitment::<HostFunctionsManager>(
         &msg.packet.data,
         &msg.packet.timeout_height_on_b,
         &msg.packet.timeout_timestamp_on_b,
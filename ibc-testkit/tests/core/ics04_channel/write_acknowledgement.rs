@@ -0,0 +1,220 @@
+use ibc::core::channel::handler::{
+    write_acknowledgement, write_acknowledgement_validate, write_acknowledgement_via_module,
+};
+use ibc::core::channel::types::acknowledgement::Acknowledgement;
+use ibc::core::channel::types::channel::{ChannelEnd, Counterparty, Order, State};
+use ibc::core::channel::types::commitment::compute_ack_commitment;
+use ibc::core::channel::types::packet::{Packet, Receipt};
+use ibc::core::channel::types::Version;
+use ibc::core::commitment_types::merkle::HostFunctionsManager;
+use ibc::core::handler::types::events::{IbcEvent, MessageEvent};
+use ibc::core::host::types::identifiers::{ChannelId, ConnectionId, PortId};
+use ibc::core::host::types::path::AckPath;
+use ibc::core::host::ValidationContext;
+use ibc::core::router::middleware::Middleware;
+use ibc_testkit::fixtures::core::channel::dummy_raw_packet;
+use ibc_testkit::testapp::ibc::applications::transfer::types::DummyTransferModule;
+use ibc_testkit::testapp::ibc::core::router::DummyMiddleware;
+use ibc_testkit::testapp::ibc::core::types::MockContext;
+use rstest::*;
+
+pub struct Fixture {
+    pub context: MockContext,
+    pub packet: Packet,
+    pub ack: Acknowledgement,
+}
+
+fn chan_end_on_b(order: Order) -> ChannelEnd {
+    ChannelEnd::new(
+        State::Open,
+        order,
+        Counterparty::new(PortId::transfer(), Some(ChannelId::default())),
+        vec![ConnectionId::default()],
+        Version::new("ics20-1".to_string()),
+    )
+    .unwrap()
+}
+
+fn stored_ack(context: &MockContext, packet: &Packet) -> Option<Vec<u8>> {
+    context
+        .get_packet_acknowledgement(&AckPath::new(
+            &packet.port_id_on_b,
+            &packet.chan_id_on_b,
+            packet.seq_on_a,
+        ))
+        .ok()
+        .map(|commitment| commitment.into_vec())
+}
+
+#[fixture]
+fn fixture() -> Fixture {
+    let packet: Packet = dummy_raw_packet(10, 0).try_into().unwrap();
+
+    let context = MockContext::default()
+        .with_channel(
+            packet.port_id_on_b.clone(),
+            packet.chan_id_on_b.clone(),
+            chan_end_on_b(Order::Unordered),
+        )
+        .with_packet_receipt(
+            packet.port_id_on_b.clone(),
+            packet.chan_id_on_b.clone(),
+            packet.seq_on_a,
+            Receipt::Ok,
+        );
+
+    Fixture {
+        context,
+        packet,
+        ack: Acknowledgement::try_from(vec![1u8]).unwrap(),
+    }
+}
+
+#[rstest]
+fn write_acknowledgement_happy_path(fixture: Fixture) {
+    let Fixture {
+        mut context,
+        packet,
+        ack,
+    } = fixture;
+
+    let res = write_acknowledgement(&mut context, packet.clone(), ack.clone());
+
+    assert!(
+        res.is_ok(),
+        "Happy path: writing should succeed. err: {res:?}"
+    );
+    assert_eq!(
+        stored_ack(&context, &packet),
+        Some(compute_ack_commitment::<HostFunctionsManager>(&ack).into_vec())
+    );
+
+    let ibc_events = context.get_events();
+
+    assert_eq!(ibc_events.len(), 2);
+    assert!(matches!(
+        &ibc_events[0],
+        &IbcEvent::Message(MessageEvent::Channel)
+    ));
+    assert!(matches!(&ibc_events[1], &IbcEvent::WriteAcknowledgement(_)));
+}
+
+#[rstest]
+fn write_acknowledgement_fail_no_receipt(fixture: Fixture) {
+    let Fixture { packet, ack, .. } = fixture;
+
+    let mut context = MockContext::default().with_channel(
+        packet.port_id_on_b.clone(),
+        packet.chan_id_on_b.clone(),
+        chan_end_on_b(Order::Unordered),
+    );
+
+    let res = write_acknowledgement(&mut context, packet.clone(), ack);
+
+    assert!(
+        res.is_err(),
+        "Writing fails because the packet was not received"
+    );
+    assert!(stored_ack(&context, &packet).is_none());
+}
+
+#[rstest]
+fn write_acknowledgement_fail_ack_exists(fixture: Fixture) {
+    let Fixture {
+        mut context,
+        packet,
+        ack,
+    } = fixture;
+
+    write_acknowledgement(&mut context, packet.clone(), ack.clone()).unwrap();
+
+    let res = write_acknowledgement(&mut context, packet, ack);
+
+    assert!(
+        res.is_err(),
+        "Writing fails because the packet is already acknowledged"
+    );
+}
+
+#[rstest]
+#[case::received(2, true)]
+#[case::not_received(1, false)]
+fn write_acknowledgement_ordered_channel(
+    fixture: Fixture,
+    #[case] next_seq_recv: u64,
+    #[case] expect_ok: bool,
+) {
+    let Fixture { packet, .. } = fixture;
+
+    let context = MockContext::default()
+        .with_channel(
+            packet.port_id_on_b.clone(),
+            packet.chan_id_on_b.clone(),
+            chan_end_on_b(Order::Ordered),
+        )
+        .with_recv_sequence(
+            packet.port_id_on_b.clone(),
+            packet.chan_id_on_b.clone(),
+            next_seq_recv.into(),
+        );
+
+    let res = write_acknowledgement_validate(&context, &packet);
+
+    assert_eq!(res.is_ok(), expect_ok, "unexpected result: {res:?}");
+}
+
+#[rstest]
+#[case::received(None, true)]
+#[case::timed_out(Some(Receipt::Timeout), false)]
+fn write_acknowledgement_ordered_allow_timeout_channel(
+    fixture: Fixture,
+    #[case] receipt: Option<Receipt>,
+    #[case] expect_ok: bool,
+) {
+    let Fixture { packet, .. } = fixture;
+
+    let mut context = MockContext::default()
+        .with_channel(
+            packet.port_id_on_b.clone(),
+            packet.chan_id_on_b.clone(),
+            chan_end_on_b(Order::OrderedAllowTimeout),
+        )
+        .with_recv_sequence(
+            packet.port_id_on_b.clone(),
+            packet.chan_id_on_b.clone(),
+            (u64::from(packet.seq_on_a) + 1).into(),
+        );
+
+    if let Some(receipt) = receipt {
+        context = context.with_packet_receipt(
+            packet.port_id_on_b.clone(),
+            packet.chan_id_on_b.clone(),
+            packet.seq_on_a,
+            receipt,
+        );
+    }
+
+    let res = write_acknowledgement_validate(&context, &packet);
+
+    assert_eq!(res.is_ok(), expect_ok, "unexpected result: {res:?}");
+}
+
+#[rstest]
+fn write_acknowledgement_via_module_runs_outbound_hooks(fixture: Fixture) {
+    let Fixture {
+        mut context,
+        packet,
+        ack,
+    } = fixture;
+
+    let mut stack =
+        DummyMiddleware::new(2).wrap(DummyMiddleware::new(1).wrap(DummyTransferModule::new()));
+
+    write_acknowledgement_via_module(&mut context, &mut stack, packet.clone(), ack).unwrap();
+
+    let expected_ack = Acknowledgement::try_from(vec![1u8, 1, 2]).unwrap();
+    assert_eq!(
+        stored_ack(&context, &packet),
+        Some(compute_ack_commitment::<HostFunctionsManager>(&expected_ack).into_vec())
+    );
+}
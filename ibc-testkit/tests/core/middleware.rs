@@ -0,0 +1,259 @@
+use core::ops::Add;
+use core::time::Duration;
+
+use ibc::apps::transfer::types::MODULE_ID_STR;
+use ibc::core::channel::handler::send_packet_via_module;
+use ibc::core::channel::types::acknowledgement::Acknowledgement;
+use ibc::core::channel::types::channel::{ChannelEnd, Counterparty, Order, State};
+use ibc::core::channel::types::packet::Packet;
+use ibc::core::channel::types::Version;
+use ibc::core::client::types::Height;
+use ibc::core::connection::types::version::get_compatible_versions;
+use ibc::core::connection::types::{
+    ConnectionEnd, Counterparty as ConnectionCounterparty, State as ConnectionState,
+};
+use ibc::core::handler::types::events::IbcEvent;
+use ibc::core::host::types::identifiers::{ChannelId, ClientId, ConnectionId, PortId};
+use ibc::core::host::types::path::CommitmentPath;
+use ibc::core::host::ValidationContext;
+use ibc::core::primitives::prelude::*;
+use ibc::core::primitives::{Timestamp, ZERO_DURATION};
+use ibc::core::router::middleware::{IbcModuleWrapper, Middleware};
+use ibc::core::router::module::Module;
+use ibc::core::router::router::{ModuleRouter, Router};
+use ibc::core::router::types::error::RouterError;
+use ibc::core::router::types::module::ModuleId;
+use ibc_testkit::fixtures::core::channel::dummy_raw_packet;
+use ibc_testkit::testapp::ibc::applications::transfer::types::DummyTransferModule;
+use ibc_testkit::testapp::ibc::core::router::DummyMiddleware;
+use ibc_testkit::testapp::ibc::core::types::{MockClientConfig, MockContext};
+use rstest::*;
+
+type DummyStack =
+    IbcModuleWrapper<DummyMiddleware, IbcModuleWrapper<DummyMiddleware, DummyTransferModule>>;
+
+/// Stacks two middleware on top of the transfer module: the one tagged `2`
+/// is the outermost layer of the stack.
+fn dummy_stack(inner: DummyMiddleware, outer: DummyMiddleware) -> DummyStack {
+    outer.wrap(inner.wrap(DummyTransferModule::new()))
+}
+
+fn transfer_module_id() -> ModuleId {
+    ModuleId::new(MODULE_ID_STR.to_string())
+}
+
+#[fixture]
+fn packet() -> Packet {
+    let timestamp_future = Timestamp::now().add(Duration::from_secs(10)).unwrap();
+
+    dummy_raw_packet(10, timestamp_future.nanoseconds())
+        .try_into()
+        .unwrap()
+}
+
+#[fixture]
+fn context() -> MockContext {
+    let chan_end_on_a = ChannelEnd::new(
+        State::Open,
+        Order::Unordered,
+        Counterparty::new(PortId::transfer(), Some(ChannelId::default())),
+        vec![ConnectionId::default()],
+        Version::new("ics20-1".to_string()),
+    )
+    .unwrap();
+
+    let conn_end_on_a = ConnectionEnd::new(
+        ConnectionState::Open,
+        ClientId::default(),
+        ConnectionCounterparty::new(
+            ClientId::default(),
+            Some(ConnectionId::default()),
+            Default::default(),
+        ),
+        get_compatible_versions(),
+        ZERO_DURATION,
+    )
+    .unwrap();
+
+    MockContext::default()
+        .with_client_config(
+            MockClientConfig::builder()
+                .latest_height(Height::new(0, 5).unwrap())
+                .build(),
+        )
+        .with_connection(ConnectionId::default(), conn_end_on_a)
+        .with_channel(PortId::transfer(), ChannelId::default(), chan_end_on_a)
+        .with_send_sequence(PortId::transfer(), ChannelId::default(), 1.into())
+}
+
+#[rstest]
+fn stack_forwards_callbacks_to_app(packet: Packet) {
+    let mut stack = dummy_stack(DummyMiddleware::new(1), DummyMiddleware::new(2));
+
+    let version = stack
+        .on_chan_open_init_validate(
+            Order::Unordered,
+            &[ConnectionId::default()],
+            &PortId::transfer(),
+            &ChannelId::default(),
+            &Counterparty::new(PortId::transfer(), None),
+            &Version::new("ics20-1".to_string()),
+        )
+        .unwrap();
+    assert_eq!(version, Version::new("ics20-1".to_string()));
+
+    let (_, ack) = stack.on_recv_packet_execute(&packet, &"relayer".to_string().into());
+    assert_eq!(ack, Some(Acknowledgement::try_from(vec![1u8]).unwrap()));
+
+    assert_eq!(stack.middleware().received_packets, vec![packet.seq_on_a]);
+    assert_eq!(
+        stack.next().middleware().received_packets,
+        vec![packet.seq_on_a]
+    );
+}
+
+#[rstest]
+fn outbound_acknowledgement_goes_through_stack_from_app_outwards(packet: Packet) {
+    let mut stack = dummy_stack(DummyMiddleware::new(1), DummyMiddleware::new(2));
+    let app_ack = Acknowledgement::try_from(vec![0u8]).unwrap();
+    let expected_ack = Acknowledgement::try_from(vec![0u8, 1, 2]).unwrap();
+
+    let ack = stack
+        .write_acknowledgement_validate(&packet, app_ack.clone())
+        .unwrap();
+    assert_eq!(ack, expected_ack);
+
+    let (_, ack) = stack
+        .write_acknowledgement_execute(&packet, app_ack)
+        .unwrap();
+    assert_eq!(ack, expected_ack);
+}
+
+#[rstest]
+fn send_packet_via_module_runs_outbound_hooks(mut context: MockContext, packet: Packet) {
+    let mut stack = dummy_stack(DummyMiddleware::new(1), DummyMiddleware::new(2));
+
+    send_packet_via_module(&mut context, &mut stack, packet.clone()).unwrap();
+
+    assert_eq!(stack.middleware().sent_packets, vec![packet.seq_on_a]);
+    assert_eq!(
+        stack.next().middleware().sent_packets,
+        vec![packet.seq_on_a]
+    );
+    assert!(context
+        .get_packet_commitment(&CommitmentPath::new(
+            &packet.port_id_on_a,
+            &packet.chan_id_on_a,
+            packet.seq_on_a,
+        ))
+        .is_ok());
+    assert!(matches!(
+        context.get_events().last(),
+        Some(IbcEvent::SendPacket(_))
+    ));
+
+    let logs = context.get_logs();
+    assert_eq!(
+        &logs[logs.len() - 2..],
+        &[
+            "middleware 1 sent packet".to_string(),
+            "middleware 2 sent packet".to_string(),
+        ]
+    );
+}
+
+#[rstest]
+fn send_packet_via_module_rejected_by_middleware(mut context: MockContext, packet: Packet) {
+    let mut stack = dummy_stack(
+        DummyMiddleware::new(1).rejecting_sends(),
+        DummyMiddleware::new(2),
+    );
+
+    let res = send_packet_via_module(&mut context, &mut stack, packet.clone());
+
+    assert!(res.is_err());
+    assert!(stack.middleware().sent_packets.is_empty());
+    assert!(context
+        .get_packet_commitment(&CommitmentPath::new(
+            &packet.port_id_on_a,
+            &packet.chan_id_on_a,
+            packet.seq_on_a,
+        ))
+        .is_err());
+}
+
+#[test]
+fn router_builder_routes_to_stack() {
+    let mut router = ModuleRouter::builder()
+        .add_route(
+            PortId::transfer(),
+            transfer_module_id(),
+            dummy_stack(DummyMiddleware::new(1), DummyMiddleware::new(2)),
+        )
+        .unwrap()
+        .build();
+
+    assert_eq!(
+        router.lookup_module(&PortId::transfer()),
+        Some(transfer_module_id())
+    );
+    assert!(router.get_route(&transfer_module_id()).is_some());
+    assert!(router.get_route_mut(&transfer_module_id()).is_some());
+    assert!(router
+        .get_route(&ModuleId::new("unknown".to_string()))
+        .is_none());
+}
+
+#[test]
+fn router_builder_binds_additional_port() {
+    let port_id = PortId::new("transfer-2".to_string()).unwrap();
+
+    let router = ModuleRouter::builder()
+        .add_route(
+            PortId::transfer(),
+            transfer_module_id(),
+            DummyTransferModule::new(),
+        )
+        .unwrap()
+        .bind_port(port_id.clone(), transfer_module_id())
+        .unwrap()
+        .build();
+
+    assert_eq!(router.lookup_module(&port_id), Some(transfer_module_id()));
+}
+
+#[test]
+fn router_builder_rejects_conflicting_routes() {
+    let builder = ModuleRouter::builder()
+        .add_route(
+            PortId::transfer(),
+            transfer_module_id(),
+            DummyTransferModule::new(),
+        )
+        .unwrap();
+
+    let res = builder.add_route(
+        PortId::new("other".to_string()).unwrap(),
+        transfer_module_id(),
+        DummyTransferModule::new(),
+    );
+    assert!(matches!(res, Err(RouterError::DuplicateModule { .. })));
+
+    let builder = ModuleRouter::builder()
+        .add_route(
+            PortId::transfer(),
+            transfer_module_id(),
+            DummyTransferModule::new(),
+        )
+        .unwrap();
+
+    let res = builder.add_route(
+        PortId::transfer(),
+        ModuleId::new("other".to_string()),
+        DummyTransferModule::new(),
+    );
+    assert!(matches!(res, Err(RouterError::PortAlreadyBound { .. })));
+
+    let res = ModuleRouter::builder().bind_port(PortId::transfer(), transfer_module_id());
+    assert!(matches!(res, Err(RouterError::ModuleNotFound)));
+}
@@ -1,5 +1,8 @@
+pub mod dispatch_batch;
 pub mod ics02_client;
 pub mod ics03_connection;
 pub mod ics04_channel;
 #[cfg(feature = "serde")]
+pub mod middleware;
+#[cfg(feature = "serde")]
 pub mod router;
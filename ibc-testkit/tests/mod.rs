@@ -7,4 +7,5 @@
     rust_2018_idioms
 )]
 pub mod applications;
+pub mod clients;
 pub mod core;